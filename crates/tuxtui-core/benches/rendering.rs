@@ -37,6 +37,39 @@ fn bench_buffer_set_string(c: &mut Criterion) {
     group.finish();
 }
 
+/// Full-screen rewrites, which used to allocate a `String` per cell.
+fn bench_buffer_cells(c: &mut Criterion) {
+    let mut group = c.benchmark_group("buffer_cells");
+    let area = Rect::new(0, 0, 80, 50);
+
+    group.bench_function("fill_80x50", |b| {
+        let mut buffer = Buffer::empty(area);
+        b.iter(|| {
+            for y in 0..area.height {
+                for x in 0..area.width {
+                    buffer.set(x, y, black_box("é"), Style::default());
+                }
+            }
+        });
+    });
+
+    group.bench_function("clear_80x50", |b| {
+        let mut buffer = Buffer::empty(area);
+        b.iter(|| buffer.clear());
+    });
+
+    group.bench_function("resize_80x50", |b| {
+        let mut buffer = Buffer::empty(area);
+        let other = Rect::new(0, 0, 100, 60);
+        b.iter(|| {
+            buffer.resize(black_box(other));
+            buffer.resize(black_box(area));
+        });
+    });
+
+    group.finish();
+}
+
 fn bench_buffer_diff(c: &mut Criterion) {
     let mut group = c.benchmark_group("buffer_diff");
 
@@ -144,6 +177,7 @@ criterion_group!(
     benches,
     bench_buffer_creation,
     bench_buffer_set_string,
+    bench_buffer_cells,
     bench_buffer_diff,
     bench_layout_split,
     bench_text_width_calculation,
@@ -0,0 +1,440 @@
+//! Elm-style application architecture.
+//!
+//! An application is a [`Model`] that turns input events into messages,
+//! updates itself on each message and draws itself from its state. Updates
+//! return [`Command`]s to send further messages, run tasks in the background
+//! or quit, and [`Subscription`]s deliver messages on a timer.
+//!
+//! # Example
+//!
+//! ```
+//! use std::time::Duration;
+//! use tuxtui_core::app::{App, Command, Model, Subscription};
+//! use tuxtui_core::prelude::*;
+//!
+//! struct Counter {
+//!     count: u32,
+//! }
+//!
+//! enum Msg {
+//!     Increment,
+//!     Quit,
+//! }
+//!
+//! impl Model for Counter {
+//!     type Msg = Msg;
+//!
+//!     fn update(&mut self, msg: Msg) -> Command<Msg> {
+//!         match msg {
+//!             Msg::Increment => {
+//!                 self.count += 1;
+//!                 Command::none()
+//!             }
+//!             Msg::Quit => Command::quit(),
+//!         }
+//!     }
+//!
+//!     fn view(&self, frame: &mut Frame<'_>) {
+//!         let text = format!("count: {}", self.count);
+//!         frame.render_widget(text.as_str(), frame.area());
+//!     }
+//!
+//!     fn event(&self, event: Event) -> Option<Msg> {
+//!         match event {
+//!             Event::Key(key) if key.code == KeyCode::Char('+') => Some(Msg::Increment),
+//!             Event::Key(key) if key.code == KeyCode::Char('q') => Some(Msg::Quit),
+//!             _ => None,
+//!         }
+//!     }
+//!
+//!     fn subscriptions(&self) -> Vec<Subscription<Msg>> {
+//!         vec![Subscription::every("tick", Duration::from_secs(1), || Msg::Increment)]
+//!     }
+//! }
+//!
+//! let mut backend = TestBackend::new(10, 1);
+//! backend.push_event(KeyCode::Char('+'));
+//! backend.push_event(KeyCode::Char('q'));
+//! let mut terminal = Terminal::new(backend).unwrap();
+//! let counter = App::new(Counter { count: 0 }).run(&mut terminal).unwrap();
+//! assert_eq!(counter.count, 1);
+//! ```
+
+use crate::backend::Backend;
+use crate::event::Event;
+use crate::terminal::{Frame, FrameLimiter, Terminal};
+use alloc::boxed::Box;
+use alloc::collections::VecDeque;
+use alloc::vec::Vec;
+use core::fmt;
+use core::time::Duration;
+use std::collections::HashMap;
+use std::sync::mpsc::{self, Receiver, Sender};
+use std::time::Instant;
+
+/// The state of an application, updated by messages.
+pub trait Model {
+    /// The messages the application reacts to.
+    type Msg: Send + 'static;
+
+    /// Get the command to run when the application starts.
+    fn init(&mut self) -> Command<Self::Msg> {
+        Command::none()
+    }
+
+    /// Update the state for a message.
+    fn update(&mut self, msg: Self::Msg) -> Command<Self::Msg>;
+
+    /// Draw the state.
+    fn view(&self, frame: &mut Frame<'_>);
+
+    /// Turn an input event into a message, or ignore it.
+    fn event(&self, event: Event) -> Option<Self::Msg>;
+
+    /// Get the timers that should deliver messages in the current state.
+    ///
+    /// This is called after every update, so subscriptions can come and go
+    /// with the state. A timer keeps its schedule for as long as a
+    /// subscription with its ID is returned.
+    fn subscriptions(&self) -> Vec<Subscription<Self::Msg>> {
+        Vec::new()
+    }
+}
+
+/// Something for the [`App`] to do after an update.
+enum Action<Msg> {
+    Message(Msg),
+    Perform(Box<dyn FnOnce() -> Msg + Send>),
+    Quit,
+}
+
+/// Work returned by [`Model::update`] for the [`App`] to carry out.
+pub struct Command<Msg> {
+    actions: Vec<Action<Msg>>,
+}
+
+impl<Msg> fmt::Debug for Command<Msg> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Command")
+            .field("actions", &self.actions.len())
+            .finish()
+    }
+}
+
+impl<Msg> Default for Command<Msg> {
+    fn default() -> Self {
+        Self::none()
+    }
+}
+
+impl<Msg> Command<Msg> {
+    /// Do nothing.
+    #[must_use]
+    pub const fn none() -> Self {
+        Self {
+            actions: Vec::new(),
+        }
+    }
+
+    /// Send a message right after the current one.
+    #[must_use]
+    pub fn message(msg: Msg) -> Self {
+        Self {
+            actions: alloc::vec![Action::Message(msg)],
+        }
+    }
+
+    /// Run `task` on a background thread and send the message it returns.
+    #[must_use]
+    pub fn perform(task: impl FnOnce() -> Msg + Send + 'static) -> Self {
+        Self {
+            actions: alloc::vec![Action::Perform(Box::new(task))],
+        }
+    }
+
+    /// Stop the application.
+    #[must_use]
+    pub fn quit() -> Self {
+        Self {
+            actions: alloc::vec![Action::Quit],
+        }
+    }
+
+    /// Carry out several commands in order.
+    #[must_use]
+    pub fn batch(commands: impl IntoIterator<Item = Self>) -> Self {
+        Self {
+            actions: commands
+                .into_iter()
+                .flat_map(|command| command.actions)
+                .collect(),
+        }
+    }
+}
+
+/// A timer delivering a message at a fixed interval.
+pub struct Subscription<Msg> {
+    id: &'static str,
+    interval: Duration,
+    msg: Box<dyn Fn() -> Msg>,
+}
+
+impl<Msg> fmt::Debug for Subscription<Msg> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Subscription")
+            .field("id", &self.id)
+            .field("interval", &self.interval)
+            .finish_non_exhaustive()
+    }
+}
+
+impl<Msg> Subscription<Msg> {
+    /// Send the message made by `msg` every `interval`, starting one
+    /// interval from when the subscription first appears.
+    #[must_use]
+    pub fn every(id: &'static str, interval: Duration, msg: impl Fn() -> Msg + 'static) -> Self {
+        Self {
+            id,
+            interval,
+            msg: Box::new(msg),
+        }
+    }
+}
+
+/// Runs a [`Model`] on a terminal.
+///
+/// Each turn of the loop draws the model, then turns pending input events,
+/// finished tasks and due timers into messages and updates the model with
+/// them, until a [`Command::quit`].
+#[derive(Debug)]
+pub struct App<M> {
+    model: M,
+    fps: u32,
+}
+
+impl<M: Model> App<M> {
+    /// Create an application drawing at up to 60 frames per second.
+    #[must_use]
+    pub const fn new(model: M) -> Self {
+        Self { model, fps: 60 }
+    }
+
+    /// Set how many times per second the loop runs at most.
+    #[must_use]
+    pub const fn fps(mut self, fps: u32) -> Self {
+        self.fps = fps;
+        self
+    }
+
+    /// Run until the model quits, returning its final state.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if drawing or reading events fails.
+    pub fn run<B: Backend>(mut self, terminal: &mut Terminal<B>) -> Result<M, B::Error> {
+        let mut runtime = Runtime::new();
+        let mut limiter = FrameLimiter::new(self.fps);
+
+        if runtime.execute(self.model.init()) {
+            return Ok(self.model);
+        }
+        loop {
+            terminal.draw(|frame| self.model.view(frame))?;
+            limiter.wait();
+            if runtime.step(&mut self.model, terminal, Instant::now())? {
+                return Ok(self.model);
+            }
+        }
+    }
+}
+
+/// The messages waiting to be handled and the timers of an [`App`].
+struct Runtime<Msg> {
+    queue: VecDeque<Msg>,
+    timers: HashMap<&'static str, Instant>,
+    sender: Sender<Msg>,
+    receiver: Receiver<Msg>,
+}
+
+impl<Msg: Send + 'static> Runtime<Msg> {
+    fn new() -> Self {
+        let (sender, receiver) = mpsc::channel();
+        Self {
+            queue: VecDeque::new(),
+            timers: HashMap::new(),
+            sender,
+            receiver,
+        }
+    }
+
+    /// Turn pending input events, finished tasks and the timers due at
+    /// `now` into messages, and update the model with them. Returns `true`
+    /// if the application should quit.
+    fn step<M, B>(
+        &mut self,
+        model: &mut M,
+        terminal: &mut Terminal<B>,
+        now: Instant,
+    ) -> Result<bool, B::Error>
+    where
+        M: Model<Msg = Msg>,
+        B: Backend,
+    {
+        while let Some(event) = terminal.poll_event()? {
+            self.queue.extend(model.event(event));
+        }
+        self.queue.extend(self.receiver.try_iter());
+
+        let subscriptions = model.subscriptions();
+        self.timers
+            .retain(|id, _| subscriptions.iter().any(|sub| sub.id == *id));
+        for subscription in &subscriptions {
+            let next = self
+                .timers
+                .entry(subscription.id)
+                .or_insert(now + subscription.interval);
+            if now >= *next {
+                self.queue.push_back((subscription.msg)());
+                *next = now + subscription.interval;
+            }
+        }
+
+        while let Some(msg) = self.queue.pop_front() {
+            let command = model.update(msg);
+            if self.execute(command) {
+                return Ok(true);
+            }
+        }
+        Ok(false)
+    }
+
+    /// Carry out a command, returning `true` if the application should
+    /// quit.
+    fn execute(&mut self, command: Command<Msg>) -> bool {
+        for action in command.actions {
+            match action {
+                Action::Message(msg) => self.queue.push_back(msg),
+                Action::Perform(task) => {
+                    let sender = self.sender.clone();
+                    std::thread::spawn(move || {
+                        // The application may have quit before the task finished.
+                        let _ = sender.send(task());
+                    });
+                }
+                Action::Quit => return true,
+            }
+        }
+        false
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::backend::TestBackend;
+    use crate::event::KeyCode;
+    use alloc::string::String;
+
+    #[derive(Default)]
+    struct Loader {
+        log: Vec<String>,
+        ticks: u32,
+    }
+
+    enum Msg {
+        Load,
+        Loaded(u32),
+        Tick,
+    }
+
+    impl Model for Loader {
+        type Msg = Msg;
+
+        fn init(&mut self) -> Command<Msg> {
+            Command::message(Msg::Load)
+        }
+
+        fn update(&mut self, msg: Msg) -> Command<Msg> {
+            match msg {
+                Msg::Load => {
+                    self.log.push("load".into());
+                    Command::perform(|| Msg::Loaded(42))
+                }
+                Msg::Loaded(value) => {
+                    self.log.push(alloc::format!("loaded {value}"));
+                    Command::none()
+                }
+                Msg::Tick => {
+                    self.ticks += 1;
+                    if self.ticks == 2 {
+                        Command::batch([Command::message(Msg::Tick), Command::quit()])
+                    } else {
+                        Command::none()
+                    }
+                }
+            }
+        }
+
+        fn view(&self, frame: &mut Frame<'_>) {
+            let text = self.log.last().map_or("", String::as_str);
+            frame.render_widget(text, frame.area());
+        }
+
+        fn event(&self, event: Event) -> Option<Msg> {
+            match event {
+                Event::Key(key) if key.code == KeyCode::Char('l') => Some(Msg::Load),
+                _ => None,
+            }
+        }
+
+        fn subscriptions(&self) -> Vec<Subscription<Msg>> {
+            if self.log.len() < 3 {
+                return Vec::new();
+            }
+            alloc::vec![Subscription::every(
+                "tick",
+                Duration::from_millis(5),
+                || Msg::Tick
+            )]
+        }
+    }
+
+    #[test]
+    fn test_tasks_events_and_timers() {
+        let mut backend = TestBackend::new(10, 1);
+        backend.push_event(KeyCode::Char('l'));
+        let mut terminal = Terminal::new(backend).unwrap();
+        let mut model = Loader::default();
+        let mut runtime = Runtime::new();
+        let start = Instant::now();
+        let mut step_at = |model: &mut Loader, runtime: &mut Runtime<Msg>, ms| {
+            let now = start + Duration::from_millis(ms);
+            runtime.step(model, &mut terminal, now).unwrap()
+        };
+
+        assert!(!runtime.execute(model.init()));
+        assert!(!step_at(&mut model, &mut runtime, 0));
+        assert_eq!(model.log, ["load", "load"]);
+        // Wait for both tasks, rather than for the loop to pick them up.
+        for _ in 0..2 {
+            let msg = runtime.receiver.recv().unwrap();
+            runtime.queue.push_back(msg);
+        }
+        assert!(!step_at(&mut model, &mut runtime, 0));
+        assert_eq!(model.log[2..], ["loaded 42", "loaded 42"]);
+
+        // The timer starts with the subscription and fires every 5 ms.
+        assert!(!step_at(&mut model, &mut runtime, 1));
+        assert!(!step_at(&mut model, &mut runtime, 5));
+        assert_eq!(model.ticks, 0);
+        assert!(!step_at(&mut model, &mut runtime, 6));
+        assert_eq!(model.ticks, 1);
+        assert!(!step_at(&mut model, &mut runtime, 10));
+        assert!(step_at(&mut model, &mut runtime, 11));
+        // The message batched before quitting is never handled.
+        assert_eq!(model.ticks, 2);
+
+        terminal.draw(|frame| model.view(frame)).unwrap();
+        terminal.backend().assert_buffer_equals("loaded 42 ");
+    }
+}
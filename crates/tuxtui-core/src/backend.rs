@@ -1,10 +1,25 @@
 //! Platform-agnostic terminal backend trait.
 
 use crate::buffer::{Buffer, Cell};
+use crate::event::{Event, KeyCode};
 use crate::geometry::{Position, Rect};
-use crate::style::Style;
+use crate::style::{Color, Style};
+use crate::terminal::{Frame, Terminal};
+use crate::util::ColorSupport;
+use alloc::collections::VecDeque;
+use alloc::string::String;
 use core::fmt;
 
+#[cfg(feature = "std")]
+mod query;
+#[cfg(feature = "std")]
+mod recording;
+
+#[cfg(feature = "std")]
+pub use query::{PollRead, parse_color_report, query_color};
+#[cfg(feature = "std")]
+pub use recording::{RecordedFrame, Recording, RecordingBackend};
+
 /// A terminal backend abstraction.
 ///
 /// Backends implement low-level terminal operations like clearing, cursor
@@ -79,6 +94,73 @@ pub trait Backend {
     /// Leave alternate screen.
     fn leave_alternate_screen(&mut self) -> Result<(), Self::Error>;
 
+    /// Set the window title.
+    ///
+    /// Backends that cannot set the title ignore it.
+    fn set_title(&mut self, title: &str) -> Result<(), Self::Error> {
+        let _ = title;
+        Ok(())
+    }
+
+    /// Ring the terminal bell.
+    ///
+    /// Backends without a bell ignore it.
+    fn bell(&mut self) -> Result<(), Self::Error> {
+        Ok(())
+    }
+
+    /// Get the features supported by the terminal.
+    ///
+    /// Widgets and applications use this to degrade gracefully, for example
+    /// by skipping hyperlinks or inline images. The default reports only
+    /// basic 16-color output.
+    fn capabilities(&self) -> Capabilities {
+        Capabilities::default()
+    }
+
+    /// Get the terminal's default foreground color.
+    ///
+    /// Backends that cannot ask the terminal return `Ok(None)`.
+    fn foreground_color(&mut self) -> Result<Option<Color>, Self::Error> {
+        Ok(None)
+    }
+
+    /// Get the terminal's default background color.
+    ///
+    /// Applications use this to pick a light or dark theme, see
+    /// [`Theme::auto`](crate::theme::Theme::auto). Backends that cannot ask
+    /// the terminal return `Ok(None)`.
+    fn background_color(&mut self) -> Result<Option<Color>, Self::Error> {
+        Ok(None)
+    }
+
+    /// Get the next pending input event without blocking.
+    ///
+    /// Backends that do not read input always return `Ok(None)`.
+    fn poll_event(&mut self) -> Result<Option<Event>, Self::Error> {
+        Ok(None)
+    }
+
+    /// Write `lines` line feeds at the cursor, scrolling the screen up when
+    /// it is on the bottom row.
+    ///
+    /// Lines scrolled off the top go to the terminal's scrollback, which is
+    /// how an inline [`Terminal`] prints above its viewport. Backends that
+    /// cannot scroll ignore it.
+    fn append_lines(&mut self, lines: u16) -> Result<(), Self::Error> {
+        let _ = lines;
+        Ok(())
+    }
+
+    /// Check whether the backend draws on the terminal of this process.
+    ///
+    /// Only one fullscreen [`Terminal`] can use that terminal at a time, see
+    /// [`Terminal::try_new`]. Backends drawing elsewhere, such as in memory
+    /// or to a remote client, return `false`, the default.
+    fn is_process_terminal(&self) -> bool {
+        false
+    }
+
     /// Enable mouse capture (if supported).
     #[cfg(feature = "scrolling-regions")]
     fn set_scroll_region(&mut self, top: u16, bottom: u16) -> Result<(), Self::Error> {
@@ -93,6 +175,137 @@ pub trait Backend {
     }
 }
 
+/// Features supported by a terminal, as reported by [`Backend::capabilities`].
+///
+/// Detection is best effort: terminals rarely advertise what they support,
+/// so [`Capabilities::from_env`] relies on well-known environment variables.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Capabilities {
+    /// Color support level
+    pub color: ColorSupport,
+    /// Mouse reporting
+    pub mouse: bool,
+    /// OSC 8 hyperlinks
+    pub hyperlinks: bool,
+    /// Sixel graphics
+    pub sixel: bool,
+    /// Kitty graphics protocol
+    pub kitty_graphics: bool,
+    /// Synchronized output (mode 2026)
+    pub synchronized_output: bool,
+    /// Scroll regions (DECSTBM)
+    pub scroll_regions: bool,
+    /// Setting the window title
+    pub set_title: bool,
+    /// Colored underlines (SGR 58), used for `Style::underline_color` with
+    /// the `underline-color` feature
+    pub underline_color: bool,
+}
+
+impl Default for Capabilities {
+    fn default() -> Self {
+        Self {
+            color: ColorSupport::Ansi16,
+            mouse: false,
+            hyperlinks: false,
+            sixel: false,
+            kitty_graphics: false,
+            synchronized_output: false,
+            scroll_regions: false,
+            set_title: false,
+            underline_color: false,
+        }
+    }
+}
+
+impl Capabilities {
+    /// Get capabilities with every feature supported.
+    #[must_use]
+    pub const fn full() -> Self {
+        Self {
+            color: ColorSupport::TrueColor,
+            mouse: true,
+            hyperlinks: true,
+            sixel: true,
+            kitty_graphics: true,
+            synchronized_output: true,
+            scroll_regions: true,
+            set_title: true,
+            underline_color: true,
+        }
+    }
+
+    /// Check whether 24-bit RGB colors are supported.
+    #[must_use]
+    pub const fn has_truecolor(&self) -> bool {
+        matches!(self.color, ColorSupport::TrueColor)
+    }
+
+    /// Check whether any inline image protocol is supported.
+    #[must_use]
+    pub const fn has_graphics(&self) -> bool {
+        self.sixel || self.kitty_graphics
+    }
+
+    /// Detect the capabilities of the terminal from the environment.
+    ///
+    /// Looks at `TERM`, `COLORTERM`, `TERM_PROGRAM` and variables set by
+    /// specific terminals such as `KITTY_WINDOW_ID` or `WT_SESSION`.
+    #[cfg(feature = "std")]
+    #[must_use]
+    pub fn from_env() -> Self {
+        Self::detect(|name| std::env::var(name).ok())
+    }
+
+    /// Detect capabilities from environment variables read by `var`.
+    #[cfg(feature = "std")]
+    fn detect(var: impl Fn(&str) -> Option<String>) -> Self {
+        let term = var("TERM").unwrap_or_default();
+        if term.is_empty() || term == "dumb" {
+            return Self {
+                color: ColorSupport::None,
+                ..Self::default()
+            };
+        }
+        let program = var("TERM_PROGRAM").unwrap_or_default();
+        let kitty = term.contains("kitty") || var("KITTY_WINDOW_ID").is_some();
+        let wezterm = program == "WezTerm";
+        let iterm = program == "iTerm.app";
+        let ghostty = program == "ghostty" || term.contains("ghostty");
+        let foot = term.starts_with("foot");
+        let windows_terminal = var("WT_SESSION").is_some();
+        let vte = var("VTE_VERSION")
+            .and_then(|version| version.parse::<u32>().ok())
+            .unwrap_or(0);
+        let modern = kitty || wezterm || iterm || ghostty || foot;
+
+        let truecolor = var("COLORTERM").is_some_and(|c| c == "truecolor" || c == "24bit");
+        let color = if truecolor || modern || windows_terminal {
+            ColorSupport::TrueColor
+        } else if term.contains("256") {
+            ColorSupport::Ansi256
+        } else {
+            ColorSupport::Ansi16
+        };
+        let linux_console = term == "linux";
+
+        Self {
+            color,
+            mouse: !linux_console,
+            hyperlinks: modern || windows_terminal || vte >= 5000 || program == "vscode",
+            sixel: wezterm || iterm || foot || term.contains("mlterm"),
+            kitty_graphics: kitty || wezterm || ghostty,
+            synchronized_output: modern || windows_terminal || term.contains("alacritty"),
+            scroll_regions: true,
+            set_title: !linux_console,
+            underline_color: modern
+                || windows_terminal
+                || vte >= 5200
+                || term.contains("alacritty"),
+        }
+    }
+}
+
 /// A test backend for unit testing and snapshot testing.
 ///
 /// Records operations and maintains a virtual terminal buffer.
@@ -115,6 +328,11 @@ pub struct TestBackend {
     buffer: Buffer,
     cursor_visible: bool,
     cursor_position: Position,
+    events: VecDeque<Event>,
+    capabilities: Capabilities,
+    title: Option<String>,
+    bells: usize,
+    default_colors: (Option<Color>, Option<Color>),
 }
 
 impl TestBackend {
@@ -127,9 +345,48 @@ impl TestBackend {
             buffer: Buffer::empty(Rect::new(0, 0, width, height)),
             cursor_visible: true,
             cursor_position: Position::new(0, 0),
+            events: VecDeque::new(),
+            capabilities: Capabilities::full(),
+            title: None,
+            bells: 0,
+            default_colors: (None, None),
         }
     }
 
+    /// Set the capabilities reported by the backend.
+    ///
+    /// Every feature is supported by default; restrict them to test how an
+    /// application degrades on simpler terminals.
+    pub fn set_capabilities(&mut self, capabilities: Capabilities) {
+        self.capabilities = capabilities;
+    }
+
+    /// Set the default colors reported by [`Backend::foreground_color`] and
+    /// [`Backend::background_color`].
+    ///
+    /// Neither is reported by default.
+    pub const fn set_default_colors(
+        &mut self,
+        foreground: Option<Color>,
+        background: Option<Color>,
+    ) {
+        self.default_colors = (foreground, background);
+    }
+
+    /// Queue an event to be returned by [`Backend::poll_event`].
+    ///
+    /// A queued [`Event::Resize`] resizes the backend when it is polled,
+    /// like a real terminal that reports a resize after it happened.
+    pub fn push_event(&mut self, event: impl Into<Event>) {
+        self.events.push_back(event.into());
+    }
+
+    /// Get the number of queued events.
+    #[must_use]
+    pub fn pending_events(&self) -> usize {
+        self.events.len()
+    }
+
     /// Get the current buffer content.
     #[must_use]
     pub fn buffer(&self) -> &Buffer {
@@ -148,6 +405,18 @@ impl TestBackend {
         self.buffer.resize(Rect::new(0, 0, width, height));
     }
 
+    /// Get the last window title that was set.
+    #[must_use]
+    pub fn title(&self) -> Option<&str> {
+        self.title.as_deref()
+    }
+
+    /// Get the number of times the bell was rung.
+    #[must_use]
+    pub const fn bells(&self) -> usize {
+        self.bells
+    }
+
     /// Get the cursor visibility state.
     #[must_use]
     pub const fn is_cursor_visible(&self) -> bool {
@@ -156,13 +425,26 @@ impl TestBackend {
 
     /// Assert that the buffer contains the expected string at the given position.
     ///
+    /// Styles are ignored; use [`TestBackend::assert_buffer`] to compare them.
+    ///
     /// # Panics
     ///
     /// Panics if the buffer content doesn't match.
     pub fn assert_buffer_equals(&self, expected: &str) {
-        let actual = format!("{}", self.buffer);
+        let actual = alloc::format!("{}", self.buffer);
         assert_eq!(actual.trim(), expected.trim(), "Buffer mismatch");
     }
+
+    /// Assert that the buffer holds the symbols and styles of `expected`.
+    ///
+    /// # Panics
+    ///
+    /// Panics with a colored diff if the buffers differ.
+    #[cfg(feature = "std")]
+    #[track_caller]
+    pub fn assert_buffer(&self, expected: &Buffer) {
+        crate::assert_buffer_eq!(self.buffer, *expected);
+    }
 }
 
 impl Backend for TestBackend {
@@ -202,7 +484,7 @@ impl Backend for TestBackend {
     }
 
     fn draw_cell(&mut self, x: u16, y: u16, cell: &Cell) -> Result<(), Self::Error> {
-        self.buffer.set(x, y, cell.symbol.as_str(), cell.style);
+        self.buffer.set(x, y, cell.symbol(), cell.style);
         Ok(())
     }
 
@@ -233,6 +515,160 @@ impl Backend for TestBackend {
     fn leave_alternate_screen(&mut self) -> Result<(), Self::Error> {
         Ok(())
     }
+
+    fn set_title(&mut self, title: &str) -> Result<(), Self::Error> {
+        self.title = Some(String::from(title));
+        Ok(())
+    }
+
+    fn bell(&mut self) -> Result<(), Self::Error> {
+        self.bells += 1;
+        Ok(())
+    }
+
+    fn capabilities(&self) -> Capabilities {
+        self.capabilities
+    }
+
+    fn foreground_color(&mut self) -> Result<Option<Color>, Self::Error> {
+        Ok(self.default_colors.0)
+    }
+
+    fn background_color(&mut self) -> Result<Option<Color>, Self::Error> {
+        Ok(self.default_colors.1)
+    }
+
+    fn poll_event(&mut self) -> Result<Option<Event>, Self::Error> {
+        let event = self.events.pop_front();
+        if let Some(Event::Resize(width, height)) = event {
+            self.resize(width, height);
+        }
+        Ok(event)
+    }
+
+    fn append_lines(&mut self, lines: u16) -> Result<(), Self::Error> {
+        let bottom = self.height.saturating_sub(1);
+        let y = self.cursor_position.y.saturating_add(lines);
+        self.buffer.scroll_up(y.saturating_sub(bottom));
+        self.cursor_position.y = y.min(bottom);
+        Ok(())
+    }
+}
+
+/// Drives a [`Terminal`] on a [`TestBackend`] with scripted events.
+///
+/// Events are queued up front, then each [`step`](Self::step) handles one
+/// of them and draws a frame, so tests can check the screen after every
+/// interaction.
+///
+/// # Example
+///
+/// ```
+/// use tuxtui_core::backend::TestHarness;
+/// use tuxtui_core::event::{Event, KeyCode};
+///
+/// let mut harness = TestHarness::new(10, 1);
+/// harness.type_str("hi").push_event(KeyCode::Backspace);
+///
+/// let mut text = String::new();
+/// harness.run(
+///     &mut text,
+///     |text, event| match event {
+///         Event::Key(key) => match key.code {
+///             KeyCode::Char(c) => text.push(c),
+///             KeyCode::Backspace => drop(text.pop()),
+///             _ => {}
+///         },
+///         _ => {}
+///     },
+///     |frame, text| frame.render_widget(text.as_str(), frame.area()),
+/// );
+/// harness.backend().assert_buffer_equals("h");
+/// ```
+pub struct TestHarness {
+    terminal: Terminal<TestBackend>,
+}
+
+impl TestHarness {
+    /// Create a harness with a terminal of the given size.
+    #[must_use]
+    pub fn new(width: u16, height: u16) -> Self {
+        let terminal = Terminal::new(TestBackend::new(width, height))
+            .unwrap_or_else(|_| unreachable!("the test backend never fails"));
+        Self { terminal }
+    }
+
+    /// Queue an event.
+    pub fn push_event(&mut self, event: impl Into<Event>) -> &mut Self {
+        self.terminal.backend_mut().push_event(event);
+        self
+    }
+
+    /// Queue a key press for every character of `s`.
+    pub fn type_str(&mut self, s: &str) -> &mut Self {
+        for c in s.chars() {
+            self.push_event(KeyCode::Char(c));
+        }
+        self
+    }
+
+    /// Queue a resize of the terminal.
+    pub fn resize(&mut self, width: u16, height: u16) -> &mut Self {
+        self.push_event(Event::Resize(width, height))
+    }
+
+    /// Handle the next queued event with `update`, then draw with `view`.
+    ///
+    /// Returns `false` without drawing if no event is queued.
+    pub fn step<S>(
+        &mut self,
+        state: &mut S,
+        update: impl FnOnce(&mut S, Event),
+        view: impl FnOnce(&mut Frame<'_>, &S),
+    ) -> bool {
+        let Ok(Some(event)) = self.terminal.poll_event() else {
+            return false;
+        };
+        update(state, event);
+        self.draw(|frame| view(frame, state));
+        true
+    }
+
+    /// Step through all queued events, returning how many were handled.
+    pub fn run<S>(
+        &mut self,
+        state: &mut S,
+        mut update: impl FnMut(&mut S, Event),
+        mut view: impl FnMut(&mut Frame<'_>, &S),
+    ) -> usize {
+        let mut steps = 0;
+        while self.step(state, &mut update, &mut view) {
+            steps += 1;
+        }
+        steps
+    }
+
+    /// Draw a frame without handling an event.
+    pub fn draw(&mut self, view: impl FnOnce(&mut Frame<'_>)) {
+        let _ = self.terminal.draw(view);
+    }
+
+    /// Get the test backend, to inspect the screen.
+    #[must_use]
+    pub const fn backend(&self) -> &TestBackend {
+        self.terminal.backend()
+    }
+
+    /// Get the screen contents.
+    #[must_use]
+    pub fn buffer(&self) -> &Buffer {
+        self.backend().buffer()
+    }
+
+    /// Get mutable access to the terminal.
+    pub fn terminal_mut(&mut self) -> &mut Terminal<TestBackend> {
+        &mut self.terminal
+    }
 }
 
 /// Error type for test backend.
@@ -250,8 +686,7 @@ impl fmt::Display for TestBackendError {
     }
 }
 
-#[cfg(feature = "std")]
-impl std::error::Error for TestBackendError {}
+impl core::error::Error for TestBackendError {}
 
 #[cfg(test)]
 mod tests {
@@ -274,11 +709,126 @@ mod tests {
         assert_eq!(pos.y, 5);
     }
 
+    #[test]
+    fn test_backend_events() {
+        let mut backend = TestBackend::new(10, 5);
+        backend.push_event(KeyCode::Enter);
+        backend.push_event(Event::Resize(4, 2));
+        assert_eq!(backend.pending_events(), 2);
+
+        assert_eq!(backend.poll_event().unwrap(), Some(KeyCode::Enter.into()));
+        assert_eq!(backend.poll_event().unwrap(), Some(Event::Resize(4, 2)));
+        assert_eq!(backend.size().unwrap(), Rect::new(0, 0, 4, 2));
+        assert_eq!(backend.poll_event().unwrap(), None);
+    }
+
+    #[test]
+    fn test_backend_capabilities() {
+        let mut backend = TestBackend::new(10, 5);
+        assert!(backend.capabilities().has_truecolor());
+
+        backend.set_capabilities(Capabilities::default());
+        assert!(!backend.capabilities().has_graphics());
+        assert_eq!(backend.capabilities().color, ColorSupport::Ansi16);
+    }
+
+    #[test]
+    fn test_backend_title_and_bell() {
+        let mut terminal = Terminal::new(TestBackend::new(10, 5)).unwrap();
+        assert_eq!(terminal.backend().title(), None);
+
+        terminal.set_title("tuxtui").unwrap();
+        terminal.backend_mut().bell().unwrap();
+        assert_eq!(terminal.backend().title(), Some("tuxtui"));
+        assert_eq!(terminal.backend().bells(), 1);
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn test_capabilities_detection() {
+        let detect = |vars: &[(&str, &str)]| {
+            Capabilities::detect(|name| {
+                vars.iter()
+                    .find(|(key, _)| *key == name)
+                    .map(|(_, value)| (*value).into())
+            })
+        };
+
+        let dumb = detect(&[("TERM", "dumb")]);
+        assert_eq!(dumb.color, ColorSupport::None);
+        assert!(!dumb.mouse && !dumb.set_title);
+
+        let xterm = detect(&[("TERM", "xterm-256color")]);
+        assert_eq!(xterm.color, ColorSupport::Ansi256);
+        assert!(xterm.mouse && !xterm.hyperlinks && !xterm.has_graphics());
+
+        let kitty = detect(&[("TERM", "xterm-kitty"), ("KITTY_WINDOW_ID", "1")]);
+        assert!(kitty.has_truecolor() && kitty.kitty_graphics && kitty.hyperlinks);
+        assert!(!kitty.sixel);
+
+        let wezterm = detect(&[
+            ("TERM", "xterm-256color"),
+            ("TERM_PROGRAM", "WezTerm"),
+            ("COLORTERM", "truecolor"),
+        ]);
+        assert!(wezterm.sixel && wezterm.synchronized_output);
+        assert!(wezterm.underline_color && !xterm.underline_color);
+
+        let gnome = detect(&[("TERM", "xterm-256color"), ("VTE_VERSION", "5002")]);
+        assert!(gnome.hyperlinks && !gnome.underline_color);
+    }
+
+    #[test]
+    fn test_harness_steps_through_events() {
+        let mut harness = TestHarness::new(6, 2);
+        harness.type_str("ab").resize(3, 1);
+
+        let mut count = 0;
+        let view = |frame: &mut Frame<'_>, count: &usize| {
+            frame.render_widget(alloc::format!("n={count}").as_str(), frame.area());
+        };
+        let update = |count: &mut usize, event: Event| {
+            if matches!(event, Event::Key(_)) {
+                *count += 1;
+            }
+        };
+        assert!(harness.step(&mut count, update, view));
+        harness.backend().assert_buffer_equals("n=1");
+
+        assert_eq!(harness.run(&mut count, update, view), 2);
+        assert_eq!(harness.buffer().area, Rect::new(0, 0, 3, 1));
+        harness.backend().assert_buffer_equals("n=2");
+        assert!(!harness.step(&mut count, update, view));
+    }
+
     #[test]
     fn test_backend_clear() {
         let mut backend = TestBackend::new(10, 5);
         backend.buffer_mut().set(0, 0, "X", Style::default());
         backend.clear().unwrap();
-        assert_eq!(backend.buffer().get(0, 0).unwrap().symbol, " ");
+        assert_eq!(backend.buffer().get(0, 0).unwrap().symbol(), " ");
+    }
+
+    #[test]
+    fn test_removed_modifiers_reach_the_backend() {
+        use crate::style::Modifier;
+
+        let mut terminal = Terminal::new(TestBackend::new(4, 1)).unwrap();
+        terminal
+            .draw(|frame| {
+                let bold = Style::new().add_modifier(Modifier::BOLD | Modifier::ITALIC);
+                frame.buffer_mut().set_string(0, 0, "abcd", bold);
+                let area = Rect::new(2, 0, 2, 1);
+                frame
+                    .buffer_mut()
+                    .set_style(area, Style::new().remove_modifier(Modifier::BOLD));
+            })
+            .unwrap();
+
+        let buffer = terminal.backend().buffer();
+        let modifiers = |x| buffer.get(x, 0).unwrap().style.modifiers();
+        assert_eq!(modifiers(1), Modifier::BOLD | Modifier::ITALIC);
+        assert_eq!(modifiers(2), Modifier::ITALIC);
+        assert_eq!(modifiers(3), Modifier::ITALIC);
     }
 }
@@ -0,0 +1,185 @@
+//! Queries for the terminal's default colors (OSC 10 and 11).
+
+use crate::style::Color;
+use std::io::{self, Read, Write};
+use std::time::{Duration, Instant};
+
+/// A reader that can wait a limited time for input, such as a terminal.
+pub trait PollRead: Read {
+    /// Wait up to `timeout` for input, returning whether there is some to
+    /// read.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if waiting fails.
+    fn poll_read(&mut self, timeout: Duration) -> io::Result<bool>;
+}
+
+impl PollRead for &[u8] {
+    fn poll_read(&mut self, _timeout: Duration) -> io::Result<bool> {
+        Ok(!self.is_empty())
+    }
+}
+
+#[cfg(unix)]
+impl PollRead for std::fs::File {
+    fn poll_read(&mut self, timeout: Duration) -> io::Result<bool> {
+        use rustix::event::{PollFd, PollFlags, Timespec, poll};
+
+        let timeout = Timespec {
+            tv_sec: i64::try_from(timeout.as_secs()).unwrap_or(i64::MAX),
+            tv_nsec: timeout.subsec_nanos().into(),
+        };
+        let mut fds = [PollFd::new(self, PollFlags::IN)];
+        Ok(poll(&mut fds, Some(&timeout))? > 0)
+    }
+}
+
+/// Parse a color report such as `ESC ] 11 ; rgb:ffff/ffff/ffff BEL`.
+///
+/// Components may have one to four hex digits and are scaled to 8 bits.
+/// Anything before the report is ignored.
+///
+/// # Example
+///
+/// ```
+/// use tuxtui_core::backend::parse_color_report;
+/// use tuxtui_core::style::Color;
+///
+/// let report = "\x1b]11;rgb:1e1e/2020/ffff\x1b\\";
+/// assert_eq!(parse_color_report(report), Some(Color::Rgb(0x1e, 0x20, 0xff)));
+/// ```
+#[must_use]
+pub fn parse_color_report(report: &str) -> Option<Color> {
+    let start = report.find("\x1b]")?;
+    let body = &report[start + 2..];
+    let end = body.find(['\x07', '\x1b'])?;
+    let (_, spec) = body[..end].split_once(';')?;
+    let spec = spec.strip_prefix("rgb:")?;
+
+    let mut components = spec.split('/').map(|hex| {
+        let value = u32::from_str_radix(hex, 16).ok()?;
+        let max = match hex.len() {
+            1 => 0xf,
+            2 => 0xff,
+            3 => 0xfff,
+            4 => 0xffff,
+            _ => return None,
+        };
+        u8::try_from(value * 255 / max).ok()
+    });
+    let (r, g, b) = (
+        components.next()??,
+        components.next()??,
+        components.next()??,
+    );
+    components.next().is_none().then_some(Color::Rgb(r, g, b))
+}
+
+/// Ask the terminal for one of its dynamic colors, such as 10 for the
+/// default foreground or 11 for the default background.
+///
+/// The query is written to `writer` and the reply read from `reader`, which
+/// should be the terminal in raw mode. It is followed by a device attributes
+/// request that every terminal answers, so terminals that do not support
+/// the query are detected without waiting; `timeout` only guards against a
+/// reader that never answers. The reply is read on the calling thread, so
+/// nothing is left reading the terminal once this returns. Returns
+/// `Ok(None)` if the color is not reported.
+///
+/// # Errors
+///
+/// Returns an error if writing the query or reading the reply fails.
+pub fn query_color<W, R>(
+    writer: &mut W,
+    reader: &mut R,
+    code: u8,
+    timeout: Duration,
+) -> io::Result<Option<Color>>
+where
+    W: Write + ?Sized,
+    R: PollRead + ?Sized,
+{
+    write!(writer, "\x1b]{code};?\x1b\\\x1b[c")?;
+    writer.flush()?;
+
+    let deadline = Instant::now() + timeout;
+    let mut reply = Vec::new();
+    let mut byte = [0];
+    loop {
+        let remaining = deadline.saturating_duration_since(Instant::now());
+        if remaining.is_zero() {
+            return Ok(None);
+        }
+        match reader.poll_read(remaining) {
+            Ok(true) => {}
+            Ok(false) => return Ok(None),
+            Err(error) if error.kind() == io::ErrorKind::Interrupted => continue,
+            Err(error) => return Err(error),
+        }
+        match reader.read(&mut byte) {
+            Ok(0) => break,
+            Ok(_) => reply.push(byte[0]),
+            Err(error) if error.kind() == io::ErrorKind::Interrupted => continue,
+            Err(error) => return Err(error),
+        }
+        // The device attributes reply, `ESC [ ? ... c`, comes last.
+        if byte[0] == b'c' && reply.windows(3).any(|w| w == b"\x1b[?") {
+            break;
+        }
+    }
+    Ok(parse_color_report(&String::from_utf8_lossy(&reply)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_color_report() {
+        assert_eq!(
+            parse_color_report("\x1b]10;rgb:ffff/8080/0000\x07"),
+            Some(Color::Rgb(255, 128, 0))
+        );
+        assert_eq!(
+            parse_color_report("junk\x1b]11;rgb:f/8/0\x1b\\\x1b[?62c"),
+            Some(Color::Rgb(255, 136, 0))
+        );
+        assert_eq!(parse_color_report("\x1b[?62c"), None);
+        assert_eq!(parse_color_report("\x1b]11;rgb:ff/ff\x07"), None);
+        assert_eq!(parse_color_report("\x1b]11;rgb:ff/ff/ff/ff\x07"), None);
+        assert_eq!(parse_color_report("\x1b]11;rgb:fffff/0/0\x07"), None);
+    }
+
+    #[test]
+    fn test_query_color() {
+        let mut written = Vec::new();
+        let mut reply = b"\x1b]11;rgb:0000/0000/0000\x1b\\\x1b[?62;22c".as_slice();
+        let color = query_color(&mut written, &mut reply, 11, Duration::from_secs(1)).unwrap();
+        assert_eq!(color, Some(Color::Rgb(0, 0, 0)));
+        assert_eq!(written, b"\x1b]11;?\x1b\\\x1b[c");
+
+        let mut unsupported = b"\x1b[?62c".as_slice();
+        let color = query_color(&mut written, &mut unsupported, 10, Duration::from_secs(1));
+        assert_eq!(color.unwrap(), None);
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn test_query_color_times_out_without_reading_more() {
+        use std::os::unix::net::UnixStream;
+
+        let (terminal, mut other) = UnixStream::pair().unwrap();
+        let mut tty = std::fs::File::from(std::os::fd::OwnedFd::from(terminal));
+        let mut written = Vec::new();
+        let color = query_color(&mut written, &mut tty, 11, Duration::from_millis(10));
+        assert_eq!(color.unwrap(), None);
+
+        // Input typed after the timeout is left for the application.
+        other.write_all(b"q").unwrap();
+        assert!(tty.poll_read(Duration::from_secs(1)).unwrap());
+        let mut key = [0];
+        tty.read_exact(&mut key).unwrap();
+        assert_eq!(&key, b"q");
+    }
+}
@@ -0,0 +1,335 @@
+//! Recording of drawn frames, with export to asciicast.
+
+use super::{Backend, Capabilities};
+use crate::buffer::{Buffer, Cell};
+use crate::event::Event;
+use crate::geometry::{Position, Rect};
+use crate::style::{Color, Style};
+use crate::terminal::Terminal;
+use alloc::string::String;
+use alloc::vec::Vec;
+use core::fmt::Write;
+use core::time::Duration;
+use std::time::Instant;
+
+/// A frame captured by a [`RecordingBackend`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct RecordedFrame {
+    /// When the frame was flushed, since recording started
+    pub time: Duration,
+    /// The screen contents
+    pub buffer: Buffer,
+}
+
+/// The frames captured by a [`RecordingBackend`], in order.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct Recording {
+    /// The frames
+    pub frames: Vec<RecordedFrame>,
+}
+
+impl Recording {
+    /// Export as an [asciicast v2](https://docs.asciinema.org/manual/asciicast/v2/)
+    /// file, which asciinema and most cast players can play.
+    ///
+    /// The cast has the size of the first frame. Every frame redraws the
+    /// whole screen, and size changes become resize events.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use tuxtui_core::backend::{RecordingBackend, TestBackend};
+    /// use tuxtui_core::terminal::Terminal;
+    ///
+    /// let backend = RecordingBackend::new(TestBackend::new(2, 1));
+    /// let mut terminal = Terminal::new(backend).unwrap();
+    /// terminal.draw(|frame| frame.render_widget("hi", frame.area())).unwrap();
+    ///
+    /// let cast = terminal.backend().recording().to_asciicast();
+    /// let mut lines = cast.lines();
+    /// assert_eq!(lines.next(), Some(r#"{"version": 2, "width": 2, "height": 1}"#));
+    /// assert!(lines.last().unwrap().ends_with(r#""o", "\u001b[Hhi\u001b[0m"]"#));
+    /// ```
+    #[must_use]
+    pub fn to_asciicast(&self) -> String {
+        let mut size = self
+            .frames
+            .first()
+            .map_or_else(Rect::default, |frame| frame.buffer.area);
+        let mut out = String::new();
+        let _ = writeln!(
+            out,
+            r#"{{"version": 2, "width": {}, "height": {}}}"#,
+            size.width, size.height
+        );
+        for frame in &self.frames {
+            let time = frame.time.as_secs_f64();
+            let area = frame.buffer.area;
+            if area != size {
+                size = area;
+                let _ = writeln!(out, r#"[{time:.6}, "r", "{}x{}"]"#, area.width, area.height);
+            }
+            let screen = frame.buffer.to_ansi_string().replace('\n', "\r\n");
+            let _ = write!(out, r#"[{time:.6}, "o", ""#);
+            write_json_escaped(&mut out, "\x1b[H");
+            write_json_escaped(&mut out, &screen);
+            out.push_str("\"]\n");
+        }
+        out
+    }
+
+    /// Play the frames back on a terminal, keeping their timing.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if drawing fails.
+    pub fn replay<B: Backend>(&self, terminal: &mut Terminal<B>) -> Result<(), B::Error> {
+        let start = Instant::now();
+        for frame in &self.frames {
+            if let Some(wait) = frame.time.checked_sub(start.elapsed()) {
+                std::thread::sleep(wait);
+            }
+            terminal.draw(|f| f.buffer_mut().merge(&frame.buffer))?;
+        }
+        Ok(())
+    }
+}
+
+/// Write `s` escaped for a JSON string.
+fn write_json_escaped(out: &mut String, s: &str) {
+    for c in s.chars() {
+        match c {
+            '"' => out.push_str("\\\""),
+            '\\' => out.push_str("\\\\"),
+            '\n' => out.push_str("\\n"),
+            '\r' => out.push_str("\\r"),
+            c if c.is_control() => {
+                let _ = write!(out, "\\u{:04x}", u32::from(c));
+            }
+            c => out.push(c),
+        }
+    }
+}
+
+/// A backend that records every frame drawn through it.
+///
+/// It passes everything on to the wrapped backend, and keeps a copy of the
+/// screen that is captured with a timestamp whenever output is flushed and
+/// the screen changed. Use it for built-in session recording or to make
+/// demo casts from examples.
+///
+/// # Example
+///
+/// ```
+/// use tuxtui_core::backend::{RecordingBackend, TestBackend};
+/// use tuxtui_core::terminal::Terminal;
+///
+/// let backend = RecordingBackend::new(TestBackend::new(5, 1));
+/// let mut terminal = Terminal::new(backend).unwrap();
+/// terminal.draw(|frame| frame.render_widget("one", frame.area())).unwrap();
+/// terminal.draw(|frame| frame.render_widget("two", frame.area())).unwrap();
+///
+/// let frames = &terminal.backend().recording().frames;
+/// // The blank screen from creating the terminal comes first.
+/// assert_eq!(frames.len(), 3);
+/// assert_eq!(frames[2].buffer.get(0, 0).unwrap().symbol(), "t");
+/// ```
+#[derive(Debug)]
+pub struct RecordingBackend<B> {
+    inner: B,
+    screen: Buffer,
+    start: Instant,
+    recording: Recording,
+}
+
+impl<B: Backend> RecordingBackend<B> {
+    /// Start recording the output to `inner`.
+    pub fn new(inner: B) -> Self {
+        let area = inner.size().unwrap_or_default();
+        Self {
+            inner,
+            screen: Buffer::empty(area),
+            start: Instant::now(),
+            recording: Recording::default(),
+        }
+    }
+}
+
+impl<B> RecordingBackend<B> {
+    /// Get the wrapped backend.
+    pub const fn inner(&self) -> &B {
+        &self.inner
+    }
+
+    /// Get the wrapped backend mutably.
+    pub const fn inner_mut(&mut self) -> &mut B {
+        &mut self.inner
+    }
+
+    /// Get the frames recorded so far.
+    pub const fn recording(&self) -> &Recording {
+        &self.recording
+    }
+
+    /// Stop recording, returning the wrapped backend and the recording.
+    pub fn into_parts(self) -> (B, Recording) {
+        (self.inner, self.recording)
+    }
+}
+
+impl<B: Backend> Backend for RecordingBackend<B> {
+    type Error = B::Error;
+
+    fn size(&self) -> Result<Rect, Self::Error> {
+        self.inner.size()
+    }
+
+    fn clear(&mut self) -> Result<(), Self::Error> {
+        self.screen.clear();
+        self.inner.clear()
+    }
+
+    fn clear_region(&mut self, region: Rect) -> Result<(), Self::Error> {
+        self.screen.clear_region(region);
+        self.inner.clear_region(region)
+    }
+
+    fn hide_cursor(&mut self) -> Result<(), Self::Error> {
+        self.inner.hide_cursor()
+    }
+
+    fn show_cursor(&mut self) -> Result<(), Self::Error> {
+        self.inner.show_cursor()
+    }
+
+    fn get_cursor(&mut self) -> Result<Position, Self::Error> {
+        self.inner.get_cursor()
+    }
+
+    fn set_cursor(&mut self, x: u16, y: u16) -> Result<(), Self::Error> {
+        self.inner.set_cursor(x, y)
+    }
+
+    fn draw_cell(&mut self, x: u16, y: u16, cell: &Cell) -> Result<(), Self::Error> {
+        self.screen.set(x, y, cell.symbol(), cell.style);
+        self.inner.draw_cell(x, y, cell)
+    }
+
+    fn set_style(&mut self, style: Style) -> Result<(), Self::Error> {
+        self.inner.set_style(style)
+    }
+
+    fn reset_style(&mut self) -> Result<(), Self::Error> {
+        self.inner.reset_style()
+    }
+
+    /// Flush the wrapped backend and capture the screen if it changed.
+    fn flush(&mut self) -> Result<(), Self::Error> {
+        self.inner.flush()?;
+        let last = self.recording.frames.last();
+        if last.is_none_or(|frame| frame.buffer != self.screen) {
+            self.recording.frames.push(RecordedFrame {
+                time: self.start.elapsed(),
+                buffer: self.screen.clone(),
+            });
+        }
+        Ok(())
+    }
+
+    fn enable_raw_mode(&mut self) -> Result<(), Self::Error> {
+        self.inner.enable_raw_mode()
+    }
+
+    fn disable_raw_mode(&mut self) -> Result<(), Self::Error> {
+        self.inner.disable_raw_mode()
+    }
+
+    fn enter_alternate_screen(&mut self) -> Result<(), Self::Error> {
+        self.inner.enter_alternate_screen()
+    }
+
+    fn leave_alternate_screen(&mut self) -> Result<(), Self::Error> {
+        self.inner.leave_alternate_screen()
+    }
+
+    fn set_title(&mut self, title: &str) -> Result<(), Self::Error> {
+        self.inner.set_title(title)
+    }
+
+    fn bell(&mut self) -> Result<(), Self::Error> {
+        self.inner.bell()
+    }
+
+    fn capabilities(&self) -> Capabilities {
+        self.inner.capabilities()
+    }
+
+    fn foreground_color(&mut self) -> Result<Option<Color>, Self::Error> {
+        self.inner.foreground_color()
+    }
+
+    fn background_color(&mut self) -> Result<Option<Color>, Self::Error> {
+        self.inner.background_color()
+    }
+
+    /// Get the next event from the wrapped backend, following resizes.
+    fn poll_event(&mut self) -> Result<Option<Event>, Self::Error> {
+        let event = self.inner.poll_event()?;
+        if let Some(Event::Resize(width, height)) = event {
+            self.screen.resize(Rect::new(0, 0, width, height));
+        }
+        Ok(event)
+    }
+
+    /// Append lines on the wrapped backend, scrolling the screen as if the
+    /// cursor were on the bottom row.
+    fn append_lines(&mut self, lines: u16) -> Result<(), Self::Error> {
+        self.screen.scroll_up(lines);
+        self.inner.append_lines(lines)
+    }
+
+    fn is_process_terminal(&self) -> bool {
+        self.inner.is_process_terminal()
+    }
+
+    #[cfg(feature = "scrolling-regions")]
+    fn set_scroll_region(&mut self, top: u16, bottom: u16) -> Result<(), Self::Error> {
+        self.inner.set_scroll_region(top, bottom)
+    }
+
+    #[cfg(feature = "scrolling-regions")]
+    fn clear_scroll_region(&mut self) -> Result<(), Self::Error> {
+        self.inner.clear_scroll_region()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::backend::TestBackend;
+
+    #[test]
+    fn test_asciicast_and_replay() {
+        let mut backend = TestBackend::new(3, 1);
+        backend.push_event(Event::Resize(4, 2));
+        let mut terminal = Terminal::new(RecordingBackend::new(backend)).unwrap();
+        terminal.draw(|f| f.render_widget("a\"", f.area())).unwrap();
+        terminal.draw(|f| f.render_widget("a\"", f.area())).unwrap();
+        terminal.poll_event().unwrap();
+        terminal.draw(|f| f.render_widget("b", f.area())).unwrap();
+
+        let recording = terminal.backend().recording().clone();
+        assert_eq!(recording.frames.len(), 3);
+        let cast = recording.to_asciicast();
+        let lines: Vec<_> = cast.lines().collect();
+        assert_eq!(lines[0], r#"{"version": 2, "width": 3, "height": 1}"#);
+        assert!(lines[2].ends_with(r#""o", "\u001b[Ha\" \u001b[0m"]"#));
+        assert!(lines[3].ends_with(r#""r", "4x2"]"#));
+        let redraw = r#""o", "\u001b[Hb   \u001b[0m\r\n    \u001b[0m"]"#;
+        assert!(lines[4].ends_with(redraw));
+
+        let mut player = Terminal::new(TestBackend::new(4, 2)).unwrap();
+        recording.replay(&mut player).unwrap();
+        player.backend().assert_buffer_equals("b");
+    }
+}
@@ -1,35 +1,55 @@
 //! Double-buffered terminal cell storage with efficient diffing.
 
-use crate::geometry::Rect;
-use crate::style::Style;
+use crate::geometry::{Alignment, Position, Rect};
+use crate::settings::UiSettings;
+use crate::style::{Color, Modifier, Style};
+use crate::terminal::Widget;
+use crate::text::{Line, Text, Truncate};
+use crate::util::unicode::{self, WidthPolicy, grapheme_width};
+use alloc::borrow::Cow;
 use alloc::string::String;
 use alloc::vec::Vec;
+use compact_str::CompactString;
 use core::fmt;
-use unicode_width::UnicodeWidthStr;
 
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
 
+mod export;
+#[cfg(feature = "png")]
+mod png;
+mod snapshot;
+
+pub use snapshot::{BufferSnapshot, StyleChange};
+
 /// A single cell in the terminal buffer.
 ///
 /// Each cell stores a grapheme cluster, style, and skip flag for wide characters.
+/// Symbols of up to 24 bytes, which covers nearly every grapheme, are stored
+/// inline without a heap allocation.
+/// Transparent cells let the cell below show through when a buffer is
+/// [blended](Buffer::blend) onto another.
 #[derive(Debug, Clone, PartialEq, Eq)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct Cell {
     /// The symbol (grapheme cluster) to display
-    pub symbol: String,
+    symbol: CompactString,
     /// The style for this cell
     pub style: Style,
     /// Skip rendering flag (for wide character continuations)
     pub skip: bool,
+    /// Transparency flag, cleared when the cell is written or reset
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub transparent: bool,
 }
 
 impl Default for Cell {
     fn default() -> Self {
         Self {
-            symbol: String::from(" "),
+            symbol: CompactString::const_new(" "),
             style: Style::default(),
             skip: false,
+            transparent: false,
         }
     }
 }
@@ -46,25 +66,32 @@ impl Cell {
     /// let cell = Cell::new("x", Style::default());
     /// ```
     #[must_use]
-    pub fn new(symbol: impl Into<String>, style: Style) -> Self {
+    pub fn new(symbol: impl AsRef<str>, style: Style) -> Self {
         Self {
-            symbol: symbol.into(),
+            symbol: CompactString::new(symbol),
             style,
             skip: false,
+            transparent: false,
         }
     }
 
     /// Reset the cell to a space with default style.
     pub fn reset(&mut self) {
-        self.symbol.clear();
-        self.symbol.push(' ');
+        self.symbol = CompactString::const_new(" ");
         self.style = Style::default();
         self.skip = false;
+        self.transparent = false;
+    }
+
+    /// Get the symbol of this cell.
+    #[must_use]
+    pub fn symbol(&self) -> &str {
+        &self.symbol
     }
 
     /// Set the symbol for this cell.
-    pub fn set_symbol(&mut self, symbol: impl Into<String>) {
-        self.symbol = symbol.into();
+    pub fn set_symbol(&mut self, symbol: impl AsRef<str>) {
+        self.symbol = CompactString::new(symbol);
     }
 
     /// Set the style for this cell.
@@ -72,13 +99,124 @@ impl Cell {
         self.style = style;
     }
 
-    /// Get the display width of the symbol (1 or 2 for wide characters).
+    /// Get the display width of the symbol (1 or 2 for wide characters), as
+    /// measured by [`WidthPolicy::Unicode`].
+    ///
+    /// A buffer may measure with another policy; the cell's extent there is
+    /// given by the [`skip`](Self::skip) cells following it.
     #[must_use]
     pub fn width(&self) -> usize {
-        self.symbol.width()
+        grapheme_width(&self.symbol)
+    }
+}
+
+/// How control characters written to a [`Buffer`] are handled.
+///
+/// Control characters such as `\r` or `\x1b` move the cursor or start escape
+/// sequences when printed, which corrupts the display.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum ControlChars {
+    /// Remove control characters
+    Strip,
+    /// Show C0 controls and DEL as their Unicode control pictures (`␛`,
+    /// `␍`, ...) and C1 controls as `�`
+    #[default]
+    Replace,
+    /// Write control characters unchanged
+    PassThrough,
+}
+
+impl ControlChars {
+    /// Apply this policy to a string, borrowing it if nothing changes.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use tuxtui_core::buffer::ControlChars;
+    ///
+    /// assert_eq!(ControlChars::Replace.sanitize("a\x1b[2J"), "a␛[2J");
+    /// assert_eq!(ControlChars::Strip.sanitize("a\rb"), "ab");
+    /// ```
+    #[must_use]
+    pub fn sanitize(self, s: &str) -> Cow<'_, str> {
+        if self == Self::PassThrough || !s.chars().any(char::is_control) {
+            return Cow::Borrowed(s);
+        }
+        let mut sanitized = String::with_capacity(s.len());
+        for c in s.chars() {
+            match (self, c) {
+                (_, c) if !c.is_control() => sanitized.push(c),
+                (Self::Replace, '\u{0}'..='\u{1f}') => {
+                    sanitized.push(char::from_u32(0x2400 + c as u32).unwrap_or('�'));
+                }
+                (Self::Replace, '\u{7f}') => sanitized.push('\u{2421}'),
+                (Self::Replace, _) => sanitized.push('�'),
+                _ => {}
+            }
+        }
+        Cow::Owned(sanitized)
     }
 }
 
+/// How an overlay buffer is combined with the buffer below it.
+///
+/// Opaque overlay cells always replace the cells below; the mode decides
+/// what happens to cells under [transparent](Cell::transparent) ones.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum BlendMode {
+    /// Keep cells below transparent cells unchanged
+    #[default]
+    Normal,
+    /// Dim cells below transparent cells
+    Dim,
+    /// Blend the RGB colors of cells below transparent cells toward `color`
+    ///
+    /// An `alpha` of 0 keeps the original colors and 255 replaces them.
+    /// Cells whose colors cannot be blended are dimmed instead.
+    Tint {
+        /// The color to blend toward
+        color: Color,
+        /// The weight of `color`
+        alpha: u8,
+    },
+}
+
+impl BlendMode {
+    /// Apply this mode to the style of a cell seen through the overlay.
+    fn apply(self, style: Style) -> Style {
+        match self {
+            Self::Normal => style,
+            Self::Dim => style.add_modifier(Modifier::DIM),
+            Self::Tint { color, alpha } => {
+                let fg = style.fg.and_then(|fg| tint(fg, color, alpha));
+                let bg = style.bg.and_then(|bg| tint(bg, color, alpha));
+                let mut tinted = style;
+                tinted.fg = fg.or(style.fg);
+                tinted.bg = bg.or(style.bg);
+                if fg.is_none() || bg.is_none() {
+                    tinted = tinted.add_modifier(Modifier::DIM);
+                }
+                tinted
+            }
+        }
+    }
+}
+
+/// Blend two RGB colors, or return `None` if either is not RGB.
+fn tint(base: Color, color: Color, alpha: u8) -> Option<Color> {
+    let (Color::Rgb(r1, g1, b1), Color::Rgb(r2, g2, b2)) = (base, color) else {
+        return None;
+    };
+    let mix = |a: u8, b: u8| {
+        let mixed =
+            (u16::from(a) * u16::from(255 - alpha) + u16::from(b) * u16::from(alpha) + 127) / 255;
+        mixed as u8
+    };
+    Some(Color::Rgb(mix(r1, r2), mix(g1, g2), mix(b1, b2)))
+}
+
 /// A buffer representing the terminal screen.
 ///
 /// The buffer is a rectangular grid of [`Cell`]s that can be efficiently
@@ -101,6 +239,15 @@ pub struct Buffer {
     pub area: Rect,
     /// The cells in this buffer (row-major order)
     pub content: Vec<Cell>,
+    /// How control characters in written symbols are handled
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub control_chars: ControlChars,
+    /// The accessibility preferences widgets drawing here should honor
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub settings: UiSettings,
+    /// How the width of written symbols is measured
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub width_policy: WidthPolicy,
 }
 
 impl Buffer {
@@ -112,18 +259,87 @@ impl Buffer {
         let cell_count = area.area() as usize;
         Self {
             area,
-            content: vec![Cell::default(); cell_count],
+            content: alloc::vec![Cell::default(); cell_count],
+            control_chars: ControlChars::default(),
+            settings: UiSettings::new(),
+            width_policy: WidthPolicy::Unicode,
         }
     }
 
+    /// Create a buffer of transparent cells, for drawing overlays.
+    ///
+    /// Cells stay transparent until something is written to them.
+    #[must_use]
+    pub fn transparent(area: Rect) -> Self {
+        let cell = Cell {
+            transparent: true,
+            ..Cell::default()
+        };
+        Self::filled(area, &cell)
+    }
+
     /// Create a buffer filled with a specific cell.
     #[must_use]
     pub fn filled(area: Rect, cell: &Cell) -> Self {
         let cell_count = area.area() as usize;
         Self {
             area,
-            content: vec![cell.clone(); cell_count],
+            content: alloc::vec![cell.clone(); cell_count],
+            control_chars: ControlChars::default(),
+            settings: UiSettings::new(),
+            width_policy: WidthPolicy::Unicode,
+        }
+    }
+
+    /// Create a buffer at the origin holding `lines` in the default style.
+    ///
+    /// The buffer is as wide as the widest line and as tall as the number of
+    /// lines, which makes it handy for expected values in tests. Styles can
+    /// be added afterwards with [`Buffer::set_style`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use tuxtui_core::buffer::Buffer;
+    /// use tuxtui_core::geometry::Rect;
+    ///
+    /// let buffer = Buffer::from_lines(&["ab", "c"]);
+    /// assert_eq!(buffer.area, Rect::new(0, 0, 2, 2));
+    /// assert_eq!(buffer.to_string(), "ab\nc ");
+    /// ```
+    #[must_use]
+    pub fn from_lines(lines: &[&str]) -> Self {
+        let width = lines.iter().map(|line| unicode::width(line)).max();
+        let width = u16::try_from(width.unwrap_or(0)).unwrap_or(u16::MAX);
+        let height = u16::try_from(lines.len()).unwrap_or(u16::MAX);
+        let mut buffer = Self::empty(Rect::new(0, 0, width, height));
+        for (y, line) in (0..height).zip(lines) {
+            buffer.set_string(0, y, line, Style::default());
         }
+        buffer
+    }
+
+    /// Set how control characters are handled. Defaults to
+    /// [`ControlChars::Replace`].
+    #[must_use]
+    pub const fn with_control_chars(mut self, control_chars: ControlChars) -> Self {
+        self.control_chars = control_chars;
+        self
+    }
+
+    /// Set how the width of written symbols is measured. Defaults to
+    /// [`WidthPolicy::Unicode`].
+    #[must_use]
+    pub const fn with_width_policy(mut self, width_policy: WidthPolicy) -> Self {
+        self.width_policy = width_policy;
+        self
+    }
+
+    /// Set the accessibility preferences widgets should honor.
+    #[must_use]
+    pub const fn with_settings(mut self, settings: UiSettings) -> Self {
+        self.settings = settings;
+        self
     }
 
     /// Get the index into the content vector for the given coordinates.
@@ -159,9 +375,49 @@ impl Buffer {
         }
     }
 
+    /// Get a reference to the cell at `position`, or `None` outside the
+    /// buffer.
+    #[must_use]
+    pub fn cell(&self, position: Position) -> Option<&Cell> {
+        self.get(position.x, position.y)
+    }
+
+    /// Get a mutable reference to the cell at `position`, or `None` outside
+    /// the buffer.
+    pub fn cell_mut(&mut self, position: Position) -> Option<&mut Cell> {
+        self.get_mut(position.x, position.y)
+    }
+
+    /// Iterate over the cells with their positions, row by row.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use tuxtui_core::buffer::Buffer;
+    /// use tuxtui_core::geometry::Position;
+    ///
+    /// let buf = Buffer::from_lines(&["ab", "cd"]);
+    /// let (position, cell) = buf.cells().nth(2).unwrap();
+    /// assert_eq!(position, Position::new(0, 1));
+    /// assert_eq!(cell.symbol(), "c");
+    /// ```
+    pub fn cells(&self) -> impl Iterator<Item = (Position, &Cell)> {
+        let area = self.area;
+        self.content.iter().enumerate().map(move |(i, cell)| {
+            // The content is never longer than the area, so these fit.
+            let x = area.x + (i % usize::from(area.width)) as u16;
+            let y = area.y + (i / usize::from(area.width)) as u16;
+            (Position::new(x, y), cell)
+        })
+    }
+
     /// Set the symbol and style of a cell at the given coordinates.
     ///
-    /// Returns `true` if the cell was updated, `false` if out of bounds.
+    /// A symbol wider than one cell marks the cells it covers as
+    /// [`skip`](Cell::skip). Returns `true` if the cell was updated, or
+    /// `false` if it is out of bounds or the symbol has no width. A symbol
+    /// too wide for the rest of the row is not drawn: the cells up to the
+    /// edge are blanked instead and `false` is returned.
     ///
     /// # Example
     ///
@@ -173,32 +429,90 @@ impl Buffer {
     /// let mut buffer = Buffer::empty(Rect::new(0, 0, 10, 10));
     /// buffer.set(5, 5, "X", Style::default());
     /// ```
-    pub fn set(&mut self, x: u16, y: u16, symbol: impl Into<String>, style: Style) -> bool {
+    pub fn set(&mut self, x: u16, y: u16, symbol: impl AsRef<str>, style: Style) -> bool {
+        if self.index_of(x, y).is_none() {
+            return false;
+        }
+        let symbol = self.control_chars.sanitize(symbol.as_ref());
+        if symbol.is_empty() {
+            return false;
+        }
+        let width = self.width_policy.grapheme_width(&symbol);
+        if width == 0 {
+            return false;
+        }
+        let fits = usize::from(self.area.right() - x) >= width;
+        let width = if fits {
+            width
+        } else {
+            usize::from(self.area.right() - x)
+        };
+        for i in 0..width {
+            self.release(x.saturating_add(i as u16), y);
+        }
+        if !fits {
+            for i in 0..width {
+                if let Some(cell) = self.get_mut(x + i as u16, y) {
+                    cell.reset();
+                }
+            }
+            return false;
+        }
+
         if let Some(cell) = self.get_mut(x, y) {
-            let symbol = symbol.into();
-            let width = symbol.width();
-            cell.symbol = symbol;
+            cell.set_symbol(symbol);
             cell.style = style;
             cell.skip = false;
+            cell.transparent = false;
+        }
 
-            // Mark continuation cells for wide characters
-            if width > 1 {
-                for i in 1..width {
-                    if let Some(next_cell) = self.get_mut(x + i as u16, y) {
-                        next_cell.reset();
-                        next_cell.skip = true;
-                    }
-                }
+        // Mark continuation cells for wide characters
+        for i in 1..width {
+            if let Some(next_cell) = self.get_mut(x.saturating_add(i as u16), y) {
+                next_cell.reset();
+                next_cell.skip = true;
             }
-            true
-        } else {
-            false
         }
+        true
+    }
+
+    /// Blank any wide character covering the cell at (x, y), so that no
+    /// half of it is left behind when the cell is overwritten.
+    fn release(&mut self, x: u16, y: u16) {
+        let Some(cell) = self.get(x, y) else {
+            return;
+        };
+        let mut lead = x;
+        if cell.skip {
+            while lead > self.area.left() && self.get(lead, y).is_some_and(|c| c.skip) {
+                lead -= 1;
+            }
+        }
+        let width = self.extent(lead, y);
+        if lead + width <= x || (width <= 1 && !cell.skip) {
+            return;
+        }
+        for i in lead..lead.saturating_add(width).max(x + 1) {
+            if let Some(cell) = self.get_mut(i, y) {
+                cell.reset();
+            }
+        }
+    }
+
+    /// Get the number of cells covered by the symbol at (x, y): the cell
+    /// itself and the [`skip`](Cell::skip) cells following it.
+    fn extent(&self, x: u16, y: u16) -> u16 {
+        let mut end = x.saturating_add(1);
+        while end < self.area.right() && self.get(end, y).is_some_and(|c| c.skip) {
+            end += 1;
+        }
+        end - x
     }
 
     /// Set a string at the given position with a style.
     ///
-    /// Returns the x-coordinate after the last written character.
+    /// Returns the x-coordinate after the last written character. Graphemes
+    /// without width, such as a stray variation selector, are left out.
     ///
     /// # Example
     ///
@@ -212,15 +526,118 @@ impl Buffer {
     /// let end_x = buffer.set_string(0, 0, "Hello, world!", style);
     /// ```
     pub fn set_string(&mut self, x: u16, y: u16, string: &str, style: Style) -> u16 {
+        let string = self.control_chars.sanitize(string);
         let mut x = x;
-        for grapheme in unicode_segmentation::UnicodeSegmentation::graphemes(string, true) {
+        for grapheme in unicode_segmentation::UnicodeSegmentation::graphemes(string.as_ref(), true)
+        {
             if x >= self.area.right() {
                 break;
             }
             self.set(x, y, grapheme, style);
-            x += grapheme.width() as u16;
+            x = x.saturating_add(self.width_policy.grapheme_width(grapheme) as u16);
         }
-        x
+        x.min(self.area.right())
+    }
+
+    /// Set a string, expanding tabs to the next multiple of `tab_width`
+    /// columns from `x`.
+    ///
+    /// Returns the x-coordinate after the last written character.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use tuxtui_core::buffer::Buffer;
+    /// use tuxtui_core::geometry::Rect;
+    /// use tuxtui_core::style::Style;
+    ///
+    /// let mut buffer = Buffer::empty(Rect::new(0, 0, 20, 1));
+    /// let end_x = buffer.set_string_with_tabs(0, 0, "a\tb", Style::default(), 4);
+    /// assert_eq!(end_x, 5);
+    /// ```
+    pub fn set_string_with_tabs(
+        &mut self,
+        x: u16,
+        y: u16,
+        string: &str,
+        style: Style,
+        tab_width: usize,
+    ) -> u16 {
+        let expanded = crate::util::unicode::expand_tabs(string, tab_width, 0);
+        self.set_string(x, y, &expanded, style)
+    }
+
+    /// Set a line at the given position, clipped to `max_width` columns and
+    /// to the buffer.
+    ///
+    /// Each span is drawn with the line style patched by the span style.
+    /// Returns the position after the last written character.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use tuxtui_core::buffer::Buffer;
+    /// use tuxtui_core::geometry::Rect;
+    /// use tuxtui_core::style::{Color, Style};
+    /// use tuxtui_core::text::{Line, Span};
+    ///
+    /// let mut buffer = Buffer::empty(Rect::new(0, 0, 20, 1));
+    /// let line = Line::from(vec![Span::raw("Hello "), Span::styled("world", Style::default().fg(Color::Red))]);
+    /// assert_eq!(buffer.set_line(0, 0, &line, 8), (8, 0));
+    /// ```
+    pub fn set_line(&mut self, x: u16, y: u16, line: &Line<'_>, max_width: u16) -> (u16, u16) {
+        self.set_line_styled(x, y, line, max_width, Style::default())
+    }
+
+    fn set_line_styled(
+        &mut self,
+        x: u16,
+        y: u16,
+        line: &Line<'_>,
+        max_width: u16,
+        base_style: Style,
+    ) -> (u16, u16) {
+        let max_width = max_width.min(self.area.right().saturating_sub(x)) as usize;
+        let clipped;
+        let line = if line.width() > max_width {
+            clipped = line.clone().truncated_with(max_width, Truncate::Tail, "");
+            &clipped
+        } else {
+            line
+        };
+
+        let line = line.to_visual();
+        let line_style = base_style.patch(line.style);
+        let mut x = x;
+        for span in &line.spans {
+            x = self.set_string(x, y, &span.content, line_style.patch(span.style));
+        }
+        (x, y)
+    }
+
+    /// Set text in `area`, one line per row.
+    ///
+    /// Lines are aligned within the area using their own alignment and
+    /// clipped to it. Styles are layered text, then line, then span.
+    pub fn set_text(&mut self, area: Rect, text: &Text<'_>) {
+        for (y, line) in (area.top()..area.bottom()).zip(&text.lines) {
+            self.set_aligned_line(Rect::new(area.x, y, area.width, 1), line, text.style);
+        }
+    }
+
+    /// Set a line on the first row of `area`, using its alignment.
+    pub(crate) fn set_aligned_line(&mut self, area: Rect, line: &Line<'_>, base_style: Style) {
+        if area.area() == 0 {
+            return;
+        }
+        let free = area.width.saturating_sub(line.width() as u16);
+        let x = match line.alignment {
+            Alignment::Start => area.left(),
+            Alignment::Center => area.left() + free / 2,
+            Alignment::End => area.left() + free,
+        };
+        let max_width = area.right() - x;
+        self.set_line_styled(x, area.top(), line, max_width, base_style);
     }
 
     /// Set a styled string with mixed styles (via spans).
@@ -249,9 +666,44 @@ impl Buffer {
         }
     }
 
-    /// Set the style for subsequent operations (no-op for buffer).
-    pub fn set_style(&mut self, _style: Style) {
-        // Buffer doesn't have a global style, this is a no-op
+    /// Move the content up by `lines` rows, as a terminal scrolls, leaving
+    /// empty rows at the bottom.
+    pub fn scroll_up(&mut self, lines: u16) {
+        let lines = lines.min(self.area.height);
+        let width = usize::from(self.area.width);
+        let len = self.content.len();
+        self.content.drain(..usize::from(lines) * width);
+        self.content.resize(len, Cell::default());
+    }
+
+    /// Patch the style of every cell in `area` with `style`.
+    ///
+    /// Symbols are kept, so this can fill a background or highlight a row
+    /// before or after drawing its content.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use tuxtui_core::buffer::Buffer;
+    /// use tuxtui_core::geometry::Rect;
+    /// use tuxtui_core::style::{Color, Style};
+    ///
+    /// let mut buffer = Buffer::empty(Rect::new(0, 0, 10, 3));
+    /// buffer.set_string(0, 1, "row", Style::default().fg(Color::Red));
+    /// buffer.set_style(Rect::new(0, 1, 10, 1), Style::default().bg(Color::Blue));
+    ///
+    /// let cell = buffer.get(0, 1).unwrap();
+    /// assert_eq!(cell.style, Style::default().fg(Color::Red).bg(Color::Blue));
+    /// ```
+    pub fn set_style(&mut self, area: Rect, style: Style) {
+        let area = self.area.intersection(area);
+        for y in area.top()..area.bottom() {
+            for x in area.left()..area.right() {
+                if let Some(cell) = self.get_mut(x, y) {
+                    cell.style = cell.style.patch(style);
+                }
+            }
+        }
     }
 
     /// Resize the buffer to a new area.
@@ -262,7 +714,10 @@ impl Buffer {
             return;
         }
 
-        let mut new_buffer = Self::empty(area);
+        let mut new_buffer = Self::empty(area)
+            .with_control_chars(self.control_chars)
+            .with_settings(self.settings)
+            .with_width_policy(self.width_policy);
         let intersection = self.area.intersection(area);
 
         // Copy overlapping content
@@ -286,8 +741,72 @@ impl Buffer {
             for x in area.left()..area.right() {
                 if let Some(cell) = other.get(x, y) {
                     if !cell.skip {
-                        self.set(x, y, cell.symbol.as_str(), cell.style);
+                        self.set(x, y, cell.symbol(), cell.style);
+                    }
+                }
+            }
+        }
+    }
+
+    /// Get a view of `area` that clips all writes to it.
+    ///
+    /// The area is clipped to the buffer. Coordinates passed to the view are
+    /// relative to the top-left corner of the area.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use tuxtui_core::buffer::Buffer;
+    /// use tuxtui_core::geometry::Rect;
+    /// use tuxtui_core::style::Style;
+    ///
+    /// let mut buffer = Buffer::empty(Rect::new(0, 0, 10, 2));
+    /// let mut view = buffer.view_mut(Rect::new(2, 1, 4, 1));
+    /// view.set_string(0, 0, "clipped text", Style::default());
+    /// assert_eq!(buffer.get(2, 1).unwrap().symbol(), "c");
+    /// assert_eq!(buffer.get(6, 1).unwrap().symbol(), " ");
+    /// ```
+    pub fn view_mut(&mut self, area: Rect) -> BufferView<'_> {
+        let area = self.area.intersection(area);
+        BufferView { buffer: self, area }
+    }
+
+    /// Blend an overlay buffer onto this one.
+    ///
+    /// Opaque overlay cells replace the cells below them, while cells below
+    /// transparent overlay cells are kept and restyled according to `mode`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use tuxtui_core::buffer::{BlendMode, Buffer};
+    /// use tuxtui_core::geometry::Rect;
+    /// use tuxtui_core::style::{Modifier, Style};
+    ///
+    /// let mut screen = Buffer::empty(Rect::new(0, 0, 10, 3));
+    /// screen.set_string(0, 0, "background", Style::default());
+    ///
+    /// // A modal backdrop: only the popup itself is opaque.
+    /// let mut overlay = Buffer::transparent(screen.area);
+    /// overlay.set_string(3, 1, "ok", Style::default());
+    /// screen.blend(&overlay, BlendMode::Dim);
+    ///
+    /// assert!(screen.get(0, 0).unwrap().style.add_modifier.contains(Modifier::DIM));
+    /// assert_eq!(screen.get(3, 1).unwrap().symbol(), "o");
+    /// ```
+    pub fn blend(&mut self, other: &Self, mode: BlendMode) {
+        let area = self.area.intersection(other.area);
+        for y in area.top()..area.bottom() {
+            for x in area.left()..area.right() {
+                let Some(cell) = other.get(x, y) else {
+                    continue;
+                };
+                if cell.transparent {
+                    if let Some(below) = self.get_mut(x, y) {
+                        below.style = mode.apply(below.style);
                     }
+                } else if !cell.skip {
+                    self.set(x, y, cell.symbol(), cell.style);
                 }
             }
         }
@@ -295,70 +814,185 @@ impl Buffer {
 
     /// Compute the differences between this buffer and another.
     ///
-    /// Returns a vector of `Diff` operations representing the minimal changes.
+    /// Returns runs of consecutive changed cells in `other`. When the areas
+    /// differ, every row of `other` is returned so it can be fully redrawn.
+    /// Runs may include wide-character continuation cells (marked
+    /// [`skip`](Cell::skip)), which backends do not draw.
     #[must_use]
     pub fn diff<'a>(&'a self, other: &'a Self) -> Vec<Diff<'a>> {
         let mut diffs = Vec::new();
+        let area = other.area;
 
-        if self.area != other.area {
-            // If areas differ, return a full redraw
-            for y in other.area.top()..other.area.bottom() {
-                let mut start_x = None;
-                let mut current_style = None;
-
-                for x in other.area.left()..other.area.right() {
-                    if let Some(cell) = other.get(x, y) {
-                        if cell.skip {
-                            continue;
-                        }
-
-                        if start_x.is_none() {
-                            start_x = Some(x);
-                            current_style = Some(cell.style);
-                        }
-
-                        if Some(cell.style) != current_style {
-                            // Style changed, flush current run
-                            if let Some(sx) = start_x {
-                                diffs.push(Diff {
-                                    x: sx,
-                                    y,
-                                    cells: Vec::new(), // Simplified for now
-                                });
-                            }
-                            start_x = Some(x);
-                            current_style = Some(cell.style);
-                        }
-                    }
+        for y in area.top()..area.bottom() {
+            let row_start = (y - area.y) as usize * area.width as usize;
+            let row = &other.content[row_start..row_start + area.width as usize];
+
+            if self.area != area {
+                if !row.is_empty() {
+                    diffs.push(Diff {
+                        x: area.x,
+                        y,
+                        cells: row.iter().collect(),
+                    });
                 }
+                continue;
             }
-            return diffs;
-        }
 
-        // Row-by-row diff
-        for y in self.area.top()..self.area.bottom() {
-            let mut x = self.area.left();
-            while x < self.area.right() {
-                let old_cell = self.get(x, y);
-                let new_cell = other.get(x, y);
-
-                if old_cell != new_cell {
-                    if let Some(new_cell) = new_cell {
-                        diffs.push(Diff {
-                            x,
-                            y,
-                            cells: alloc::vec![new_cell],
-                        });
-                    }
+            let old_row = &self.content[row_start..row_start + area.width as usize];
+            let mut run: Option<Diff<'a>> = None;
+            for (i, (old, new)) in old_row.iter().zip(row).enumerate() {
+                if old == new {
+                    diffs.extend(run.take());
+                    continue;
                 }
-                x += 1;
+                run.get_or_insert_with(|| Diff {
+                    x: area.x + i as u16,
+                    y,
+                    cells: Vec::new(),
+                })
+                .cells
+                .push(new);
             }
+            diffs.extend(run);
         }
 
         diffs
     }
 }
 
+/// A mutable view of part of a [`Buffer`].
+///
+/// Writes through the view are clipped to its area and coordinates are
+/// relative to the area's top-left corner. Wide characters that would
+/// cross the right edge are not drawn.
+#[derive(Debug)]
+pub struct BufferView<'a> {
+    buffer: &'a mut Buffer,
+    area: Rect,
+}
+
+impl BufferView<'_> {
+    /// Get the area of the buffer covered by this view, in buffer coordinates.
+    #[must_use]
+    pub const fn area(&self) -> Rect {
+        self.area
+    }
+
+    /// Get the width of the view.
+    #[must_use]
+    pub const fn width(&self) -> u16 {
+        self.area.width
+    }
+
+    /// Get the height of the view.
+    #[must_use]
+    pub const fn height(&self) -> u16 {
+        self.area.height
+    }
+
+    /// Translate view coordinates to buffer coordinates, if inside the view.
+    const fn translate(&self, x: u16, y: u16) -> Option<(u16, u16)> {
+        if x < self.area.width && y < self.area.height {
+            Some((self.area.x + x, self.area.y + y))
+        } else {
+            None
+        }
+    }
+
+    /// Get a reference to the cell at the given view coordinates.
+    #[must_use]
+    pub fn get(&self, x: u16, y: u16) -> Option<&Cell> {
+        let (x, y) = self.translate(x, y)?;
+        self.buffer.get(x, y)
+    }
+
+    /// Get a mutable reference to the cell at the given view coordinates.
+    pub fn get_mut(&mut self, x: u16, y: u16) -> Option<&mut Cell> {
+        let (x, y) = self.translate(x, y)?;
+        self.buffer.get_mut(x, y)
+    }
+
+    /// Set the symbol and style of a cell at the given view coordinates.
+    ///
+    /// Returns `false` if the symbol does not fit in the view.
+    pub fn set(&mut self, x: u16, y: u16, symbol: impl AsRef<str>, style: Style) -> bool {
+        let symbol = symbol.as_ref();
+        let width = self.buffer.width_policy.grapheme_width(symbol) as u16;
+        match self.translate(x, y) {
+            Some((bx, by))
+                if u32::from(x) + u32::from(width.max(1)) <= u32::from(self.area.width) =>
+            {
+                self.buffer.set(bx, by, symbol, style)
+            }
+            _ => false,
+        }
+    }
+
+    /// Set a string at the given view coordinates, clipped to the view.
+    ///
+    /// Returns the view x-coordinate after the last written character.
+    pub fn set_string(&mut self, x: u16, y: u16, string: &str, style: Style) -> u16 {
+        let string = self.buffer.control_chars.sanitize(string);
+        let mut x = x;
+        for grapheme in unicode_segmentation::UnicodeSegmentation::graphemes(string.as_ref(), true)
+        {
+            let width = self.buffer.width_policy.grapheme_width(grapheme) as u16;
+            if width == 0 {
+                continue;
+            }
+            if u32::from(x) + u32::from(width) > u32::from(self.area.width)
+                || !self.set(x, y, grapheme, style)
+            {
+                break;
+            }
+            x += width;
+        }
+        x
+    }
+
+    /// Render a widget into the view.
+    ///
+    /// The widget is given the view's area and draws straight into the
+    /// underlying buffer, so it must keep its writes inside that area.
+    pub fn render_widget<W: Widget>(&mut self, widget: W) {
+        widget.render(self.area, self.buffer);
+    }
+}
+
+impl core::ops::Index<Position> for Buffer {
+    type Output = Cell;
+
+    /// Get the cell at `position`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `position` is outside the buffer.
+    fn index(&self, position: Position) -> &Cell {
+        match self.cell(position) {
+            Some(cell) => cell,
+            None => panic!(
+                "position {position:?} is outside the buffer {:?}",
+                self.area
+            ),
+        }
+    }
+}
+
+impl core::ops::IndexMut<Position> for Buffer {
+    /// Get the cell at `position` mutably.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `position` is outside the buffer.
+    fn index_mut(&mut self, position: Position) -> &mut Cell {
+        let area = self.area;
+        match self.cell_mut(position) {
+            Some(cell) => cell,
+            None => panic!("position {position:?} is outside the buffer {area:?}"),
+        }
+    }
+}
+
 impl fmt::Display for Buffer {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         for y in self.area.top()..self.area.bottom() {
@@ -380,11 +1014,11 @@ impl fmt::Display for Buffer {
 /// A diff operation representing changes between two buffers.
 #[derive(Debug, Clone)]
 pub struct Diff<'a> {
-    /// X coordinate
+    /// X coordinate of the first cell
     pub x: u16,
     /// Y coordinate
     pub y: u16,
-    /// Cells that changed at this position
+    /// Consecutive changed cells, starting at `x`
     pub cells: Vec<&'a Cell>,
 }
 
@@ -399,7 +1033,46 @@ mod tests {
         buffer.set(5, 5, "X", Style::default());
 
         let cell = buffer.get(5, 5).unwrap();
-        assert_eq!(cell.symbol, "X");
+        assert_eq!(cell.symbol(), "X");
+    }
+
+    #[test]
+    fn test_cells_by_position() {
+        let mut buffer = Buffer::empty(Rect::new(2, 1, 3, 2));
+        buffer.set_string(2, 1, "abc", Style::default());
+        buffer.set_string(2, 2, "def", Style::default());
+        buffer[Position::new(3, 2)].set_symbol("E");
+        assert_eq!(buffer[Position::new(2, 1)].symbol(), "a");
+        assert_eq!(
+            buffer.cell(Position::new(3, 2)).map(Cell::symbol),
+            Some("E")
+        );
+        assert_eq!(buffer.cell(Position::new(0, 0)), None);
+        assert!(buffer.cell_mut(Position::new(5, 1)).is_none());
+
+        let cells: Vec<_> = buffer
+            .cells()
+            .map(|(position, cell)| (position.x, position.y, cell.symbol()))
+            .collect();
+        assert_eq!(
+            cells,
+            [
+                (2, 1, "a"),
+                (3, 1, "b"),
+                (4, 1, "c"),
+                (2, 2, "d"),
+                (3, 2, "E"),
+                (4, 2, "f"),
+            ]
+        );
+        assert_eq!(Buffer::empty(Rect::new(0, 0, 0, 3)).cells().count(), 0);
+    }
+
+    #[test]
+    #[should_panic(expected = "outside the buffer")]
+    fn test_index_outside_panics() {
+        let buffer = Buffer::empty(Rect::new(0, 0, 2, 2));
+        let _ = &buffer[Position::new(2, 0)];
     }
 
     #[test]
@@ -408,8 +1081,284 @@ mod tests {
         let end_x = buffer.set_string(0, 0, "Hello", Style::default());
 
         assert_eq!(end_x, 5);
-        assert_eq!(buffer.get(0, 0).unwrap().symbol, "H");
-        assert_eq!(buffer.get(4, 0).unwrap().symbol, "o");
+        assert_eq!(buffer.get(0, 0).unwrap().symbol(), "H");
+        assert_eq!(buffer.get(4, 0).unwrap().symbol(), "o");
+    }
+
+    #[test]
+    fn test_control_chars() {
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 10, 1));
+        let end_x = buffer.set_string(0, 0, "a\r\x1b", Style::default());
+        assert_eq!(end_x, 3);
+        assert_eq!(buffer.get(1, 0).unwrap().symbol(), "␍");
+        assert_eq!(buffer.get(2, 0).unwrap().symbol(), "␛");
+
+        let mut buffer = buffer.with_control_chars(ControlChars::Strip);
+        assert_eq!(buffer.set_string(0, 0, "\x07b\u{9b}c", Style::default()), 2);
+        assert!(!buffer.set(5, 0, "\x1b", Style::default()));
+        assert_eq!(buffer.get(1, 0).unwrap().symbol(), "c");
+
+        buffer.resize(Rect::new(0, 0, 4, 1));
+        assert_eq!(buffer.control_chars, ControlChars::Strip);
+        assert_eq!(ControlChars::PassThrough.sanitize("\x1b"), "\x1b");
+    }
+
+    #[test]
+    fn test_blend_tints_below_transparent_cells() {
+        let mut base = Buffer::empty(Rect::new(0, 0, 3, 1));
+        let style = Style::default().fg(Color::Red).bg(Color::Rgb(200, 100, 0));
+        base.set_string(0, 0, "abc", style);
+
+        let mut overlay = Buffer::transparent(base.area);
+        overlay.get_mut(2, 0).unwrap().reset();
+        base.blend(
+            &overlay,
+            BlendMode::Tint {
+                color: Color::Rgb(0, 0, 0),
+                alpha: 128,
+            },
+        );
+
+        let cell = base.get(0, 0).unwrap();
+        assert_eq!(cell.symbol(), "a");
+        assert_eq!(cell.style.bg, Some(Color::Rgb(100, 50, 0)));
+        // The named foreground cannot be blended, so the cell is dimmed.
+        assert!(cell.style.add_modifier.contains(Modifier::DIM));
+        // A cleared overlay cell is opaque.
+        assert_eq!(base.get(2, 0).unwrap(), &Cell::default());
+    }
+
+    #[test]
+    fn test_view_clips_and_translates() {
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 6, 2));
+        let mut view = buffer.view_mut(Rect::new(1, 1, 3, 5));
+        assert_eq!(view.area(), Rect::new(1, 1, 3, 1));
+        assert_eq!(view.set_string(0, 0, "a你b", Style::default()), 3);
+        assert!(!view.set(2, 0, "你", Style::default()));
+        assert!(!view.set(0, 1, "x", Style::default()));
+        assert_eq!(buffer.get(1, 1).unwrap().symbol(), "a");
+        assert_eq!(buffer.get(2, 1).unwrap().symbol(), "你");
+        assert_eq!(buffer.get(4, 1).unwrap().symbol(), " ");
+    }
+
+    #[test]
+    fn test_view_at_the_largest_width() {
+        let mut buffer = Buffer::empty(Rect::new(0, 0, u16::MAX, 1));
+        let mut view = buffer.view_mut(buffer.area);
+        assert!(!view.set(u16::MAX - 1, 0, "你", Style::default()));
+        assert_eq!(
+            view.set_string(u16::MAX - 1, 0, "a你", Style::default()),
+            u16::MAX
+        );
+        assert_eq!(buffer.get(u16::MAX - 1, 0).unwrap().symbol(), "a");
+    }
+
+    #[test]
+    fn test_view_render_widget_stays_in_area() {
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 8, 2));
+        buffer.set_string(0, 0, "keep", Style::default());
+        buffer
+            .view_mut(Rect::new(0, 1, 3, 1))
+            .render_widget("overflow");
+        assert_eq!(buffer.get(2, 1).unwrap().symbol(), "e");
+        assert_eq!(buffer.get(3, 1).unwrap().symbol(), " ");
+        assert_eq!(buffer.get(0, 0).unwrap().symbol(), "k");
+
+        buffer.view_mut(Rect::new(4, 0, 3, 1)).render_widget("ab你");
+        assert_eq!(buffer.get(6, 0).unwrap().symbol(), " ");
+        assert_eq!(buffer.get(7, 0).unwrap().symbol(), " ");
+    }
+
+    #[test]
+    fn test_cell_symbols_are_inline() {
+        let mut cell = Cell::new("👨‍👩‍👧", Style::default());
+        assert_eq!(cell.symbol(), "👨‍👩‍👧");
+        assert!(!cell.symbol.is_heap_allocated());
+        cell.reset();
+        assert_eq!(cell.symbol(), " ");
+    }
+
+    /// Apply a diff to `screen` the way a terminal would.
+    fn apply(screen: &mut Buffer, diffs: &[Diff<'_>]) {
+        for diff in diffs {
+            for (i, cell) in diff.cells.iter().enumerate() {
+                if !cell.skip {
+                    screen.set(diff.x + i as u16, diff.y, cell.symbol(), cell.style);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_diff_groups_runs() {
+        let old = Buffer::empty(Rect::new(0, 0, 6, 1));
+        let mut new = old.clone();
+        new.set_string(1, 0, "ab", Style::default());
+        new.set(4, 0, "c", Style::default());
+
+        let diffs = old.diff(&new);
+        assert_eq!(diffs.len(), 2);
+        assert_eq!((diffs[0].x, diffs[0].cells.len()), (1, 2));
+        assert_eq!((diffs[1].x, diffs[1].cells.len()), (4, 1));
+    }
+
+    /// Graphemes that terminals and width tables disagree on.
+    const TRICKY_GRAPHEMES: [&str; 9] = [
+        "❤\u{fe0f}",
+        "#\u{fe0f}\u{20e3}",
+        "🇺🇸",
+        "🇺",
+        "👍🏽",
+        "👨\u{200d}👩\u{200d}👧",
+        "🏳\u{fe0f}\u{200d}🌈",
+        "e\u{301}",
+        "你",
+    ];
+
+    #[test]
+    fn test_tricky_graphemes() {
+        for policy in [WidthPolicy::Unicode, WidthPolicy::CodePoint] {
+            for grapheme in TRICKY_GRAPHEMES {
+                let width = policy.grapheme_width(grapheme) as u16;
+                let mut buffer = Buffer::empty(Rect::new(0, 0, 10, 1)).with_width_policy(policy);
+                let text = alloc::format!("{grapheme}ab");
+                assert_eq!(buffer.set_string(0, 0, &text, Style::default()), width + 2);
+                let skips: Vec<_> = (0..10).map(|x| buffer.get(x, 0).unwrap().skip).collect();
+                let expected: Vec<_> = (0..10).map(|x| x > 0 && x < width).collect();
+                assert_eq!(skips, expected, "{grapheme:?} {policy:?}");
+                assert_eq!(buffer.get(width, 0).unwrap().symbol(), "a");
+
+                // Overwriting the last cell of the cluster blanks all of it.
+                buffer.set(width - 1, 0, "x", Style::default());
+                let expected = alloc::format!("{}xab", " ".repeat(usize::from(width) - 1));
+                assert_eq!(buffer.to_string(), alloc::format!("{expected:<10}"));
+                assert!(buffer.content.iter().all(|cell| !cell.skip));
+            }
+        }
+    }
+
+    #[test]
+    fn test_graphemes_at_the_edge() {
+        let mut buffer = Buffer::from_lines(&["abcd"]);
+        assert_eq!(buffer.set_string(2, 0, "x你", Style::default()), 4);
+        assert_eq!(buffer.to_string(), "abx ");
+
+        let mut buffer = Buffer::from_lines(&["abcdef"]).with_width_policy(WidthPolicy::CodePoint);
+        assert!(!buffer.set(3, 0, "👨\u{200d}👩\u{200d}👧", Style::default()));
+        assert_eq!(buffer.to_string(), "abc   ");
+
+        // Stray zero-width characters take no cell and do not shift the text.
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 4, 1));
+        assert!(!buffer.set(0, 0, "\u{fe0f}", Style::default()));
+        assert_eq!(buffer.set_string(0, 0, "\u{fe0f}ab", Style::default()), 2);
+        assert_eq!(buffer.to_string(), "ab  ");
+    }
+
+    #[test]
+    fn test_diff_replaces_wide_char_with_narrow() {
+        let mut old = Buffer::empty(Rect::new(0, 0, 4, 1));
+        old.set(0, 0, "你", Style::default());
+        let mut new = Buffer::empty(old.area);
+        new.set(0, 0, "a", Style::default());
+
+        let mut screen = old.clone();
+        apply(&mut screen, &old.diff(&new));
+        assert_eq!(screen, new);
+    }
+
+    #[test]
+    fn test_diff_redraws_on_area_change() {
+        let old = Buffer::empty(Rect::new(0, 0, 2, 1));
+        let mut new = Buffer::empty(Rect::new(0, 0, 3, 2));
+        new.set_string(0, 1, "xyz", Style::default());
+
+        let diffs = old.diff(&new);
+        assert_eq!(diffs.len(), 2);
+        let mut screen = Buffer::empty(new.area);
+        apply(&mut screen, &diffs);
+        assert_eq!(screen, new);
+    }
+
+    proptest::proptest! {
+        #[test]
+        fn prop_diff_reproduces_target(
+            old_writes in proptest::collection::vec((0u16..8, 0u16..3, 0usize..5, 0u8..3), 0..30),
+            new_writes in proptest::collection::vec((0u16..8, 0u16..3, 0usize..5, 0u8..3), 0..30),
+        ) {
+            const SYMBOLS: [&str; 5] = ["a", "b", " ", "你", "🦀"];
+            let area = Rect::new(0, 0, 8, 3);
+            let paint = |writes: &[(u16, u16, usize, u8)]| {
+                let mut buffer = Buffer::empty(area);
+                for &(x, y, symbol, color) in writes {
+                    let style = Style::default().fg(Color::Indexed(color));
+                    buffer.set(x, y, SYMBOLS[symbol], style);
+                }
+                buffer
+            };
+            let old = paint(&old_writes);
+            let new = paint(&new_writes);
+
+            let mut screen = old.clone();
+            apply(&mut screen, &old.diff(&new));
+            for (drawn, expected) in screen.content.iter().zip(&new.content) {
+                proptest::prop_assert_eq!(drawn.skip, expected.skip);
+                if !expected.skip {
+                    proptest::prop_assert_eq!(drawn, expected);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_set_text_layers_styles_and_aligns() {
+        let red = Style::default().fg(Color::Red);
+        let bold = Style::default().add_modifier(Modifier::BOLD);
+        let mut text = Text::from(alloc::vec![
+            Line::from(alloc::vec![
+                crate::text::Span::raw("ab"),
+                crate::text::Span::styled("cd", red)
+            ]),
+            Line::from("right").alignment(Alignment::End),
+            Line::from("hidden"),
+        ]);
+        text.style = bold;
+
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 6, 3));
+        buffer.set_text(Rect::new(0, 0, 6, 2), &text);
+        assert_eq!(buffer.get(0, 0).unwrap().style, bold);
+        assert_eq!(buffer.get(2, 0).unwrap().style, bold.patch(red));
+        assert_eq!(buffer.get(1, 1).unwrap().symbol(), "r");
+        assert_eq!(buffer.get(0, 2).unwrap().symbol(), " ");
+
+        let end = buffer.set_line(4, 2, &Line::from("你好"), 5);
+        assert_eq!(end, (6, 2));
+        assert_eq!(buffer.get(4, 2).unwrap().symbol(), "你");
+    }
+
+    #[test]
+    fn test_set_style_patches_region() {
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 4, 2));
+        buffer.set_string(0, 0, "ab", Style::default().fg(Color::Red));
+        buffer.set_style(Rect::new(1, 0, 10, 1), Style::default().bg(Color::Blue));
+
+        assert_eq!(
+            buffer.get(0, 0).unwrap().style,
+            Style::default().fg(Color::Red)
+        );
+        let cell = buffer.get(1, 0).unwrap();
+        assert_eq!(cell.symbol(), "b");
+        assert_eq!(cell.style, Style::default().fg(Color::Red).bg(Color::Blue));
+        assert_eq!(buffer.get(3, 0).unwrap().style.bg, Some(Color::Blue));
+        assert_eq!(buffer.get(1, 1).unwrap().style, Style::default());
+    }
+
+    #[test]
+    fn test_scroll_up() {
+        let mut buffer = Buffer::from_lines(&["a", "b", "c"]);
+        buffer.scroll_up(2);
+        assert_eq!(buffer, Buffer::from_lines(&["c", " ", " "]));
+        buffer.scroll_up(5);
+        assert_eq!(buffer, Buffer::from_lines(&[" ", " ", " "]));
     }
 
     #[test]
@@ -419,7 +1368,7 @@ mod tests {
         buffer.clear();
 
         let cell = buffer.get(5, 5).unwrap();
-        assert_eq!(cell.symbol, " ");
+        assert_eq!(cell.symbol(), " ");
         assert_eq!(cell.style, Style::default());
     }
 
@@ -431,6 +1380,6 @@ mod tests {
         overlay.set(5, 5, "O", Style::default());
         base.merge(&overlay);
 
-        assert_eq!(base.get(5, 5).unwrap().symbol, "O");
+        assert_eq!(base.get(5, 5).unwrap().symbol(), "O");
     }
 }
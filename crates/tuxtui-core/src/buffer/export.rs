@@ -0,0 +1,255 @@
+//! Export of buffer contents as ANSI escape sequences and HTML.
+
+use super::Buffer;
+use crate::style::{Color, Modifier, Style};
+use alloc::string::String;
+use core::fmt::Write;
+
+/// SGR modifier codes, in the order they are emitted.
+const MODIFIER_CODES: [(Modifier, u8); 8] = [
+    (Modifier::BOLD, 1),
+    (Modifier::DIM, 2),
+    (Modifier::ITALIC, 3),
+    (Modifier::UNDERLINED, 4),
+    (Modifier::SLOW_BLINK, 5),
+    (Modifier::RAPID_BLINK, 6),
+    (Modifier::REVERSED, 7),
+    (Modifier::HIDDEN, 8),
+];
+
+/// Append the SGR parameters selecting `color`, using `base` 30 or 40.
+fn write_sgr_color(out: &mut String, color: Color, base: u8) {
+    let _ = match color {
+        Color::Reset => write!(out, ";{}", base + 9),
+        Color::Indexed(index) => write!(out, ";{};5;{index}", base + 8),
+        Color::Rgb(r, g, b) => write!(out, ";{};2;{r};{g};{b}", base + 8),
+        named => match named.named_index() {
+            Some(index @ 0..=7) => write!(out, ";{}", base + index),
+            Some(index) => write!(out, ";{}", base + 60 + index - 8),
+            None => Ok(()),
+        },
+    };
+}
+
+/// Append the SGR sequence that switches to `style` from the default.
+fn write_sgr(out: &mut String, style: Style) {
+    out.push_str("\x1b[0");
+    let modifiers = style.modifiers();
+    for (modifier, code) in MODIFIER_CODES {
+        if modifiers.contains(modifier) {
+            let _ = write!(out, ";{code}");
+        }
+    }
+    if modifiers.contains(Modifier::CROSSED_OUT) {
+        out.push_str(";9");
+    }
+    if let Some(fg) = style.fg {
+        write_sgr_color(out, fg, 30);
+    }
+    if let Some(bg) = style.bg {
+        write_sgr_color(out, bg, 40);
+    }
+    out.push('m');
+}
+
+/// Append the inline CSS for `style`.
+fn write_css(out: &mut String, style: Style) {
+    let modifiers = style.modifiers();
+    let (mut fg, mut bg) = (
+        style.fg.and_then(Color::to_rgb),
+        style.bg.and_then(Color::to_rgb),
+    );
+    if modifiers.contains(Modifier::REVERSED) {
+        (fg, bg) = (bg, fg);
+    }
+    if let Some((r, g, b)) = fg {
+        let _ = write!(out, "color:#{r:02x}{g:02x}{b:02x};");
+    }
+    if let Some((r, g, b)) = bg {
+        let _ = write!(out, "background-color:#{r:02x}{g:02x}{b:02x};");
+    }
+    if modifiers.contains(Modifier::BOLD) {
+        out.push_str("font-weight:bold;");
+    }
+    if modifiers.contains(Modifier::DIM) {
+        out.push_str("opacity:0.5;");
+    }
+    if modifiers.contains(Modifier::ITALIC) {
+        out.push_str("font-style:italic;");
+    }
+    match (
+        modifiers.contains(Modifier::UNDERLINED),
+        modifiers.contains(Modifier::CROSSED_OUT),
+    ) {
+        (true, true) => out.push_str("text-decoration:underline line-through;"),
+        (true, false) => out.push_str("text-decoration:underline;"),
+        (false, true) => out.push_str("text-decoration:line-through;"),
+        (false, false) => {}
+    }
+    if modifiers.contains(Modifier::HIDDEN) {
+        out.push_str("visibility:hidden;");
+    }
+}
+
+/// Append `s` with the HTML special characters escaped.
+fn write_escaped(out: &mut String, s: &str) {
+    for c in s.chars() {
+        match c {
+            '&' => out.push_str("&amp;"),
+            '<' => out.push_str("&lt;"),
+            '>' => out.push_str("&gt;"),
+            '"' => out.push_str("&quot;"),
+            c => out.push(c),
+        }
+    }
+}
+
+impl Buffer {
+    /// Get the rows of visible cells, skipping wide-character continuations.
+    fn styled_rows(&self) -> impl Iterator<Item = impl Iterator<Item = (&str, Style)>> {
+        let width = usize::from(self.area.width).max(1);
+        self.content.chunks(width).map(|row| {
+            row.iter()
+                .filter(|cell| !cell.skip)
+                .map(|cell| (cell.symbol(), cell.style))
+        })
+    }
+
+    /// Render the buffer as text with ANSI escape sequences.
+    ///
+    /// Rows are separated by newlines and every row ends with a style reset,
+    /// so the output can be printed to a terminal or stored as a golden file.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use tuxtui_core::buffer::Buffer;
+    /// use tuxtui_core::geometry::Rect;
+    /// use tuxtui_core::style::{Color, Style};
+    ///
+    /// let mut buffer = Buffer::empty(Rect::new(0, 0, 3, 1));
+    /// buffer.set_string(0, 0, "hi", Style::default().fg(Color::Red));
+    /// assert_eq!(buffer.to_ansi_string(), "\x1b[0;31mhi\x1b[0m \x1b[0m");
+    /// ```
+    #[must_use]
+    pub fn to_ansi_string(&self) -> String {
+        let mut out = String::new();
+        for (y, row) in self.styled_rows().enumerate() {
+            if y > 0 {
+                out.push('\n');
+            }
+            let mut current = Style::default();
+            for (symbol, style) in row {
+                if style != current {
+                    if style == Style::default() {
+                        out.push_str("\x1b[0m");
+                    } else {
+                        write_sgr(&mut out, style);
+                    }
+                    current = style;
+                }
+                out.push_str(symbol);
+            }
+            out.push_str("\x1b[0m");
+        }
+        out
+    }
+
+    /// Render the buffer as an HTML `<pre>` block with inline styles.
+    ///
+    /// Runs of equally styled cells share a `<span>`. Named and indexed
+    /// colors use the xterm default palette, and the terminal's default
+    /// colors are left to the surrounding page.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use tuxtui_core::buffer::Buffer;
+    /// use tuxtui_core::geometry::Rect;
+    /// use tuxtui_core::style::{Color, Style};
+    ///
+    /// let mut buffer = Buffer::empty(Rect::new(0, 0, 3, 1));
+    /// buffer.set_string(0, 0, "<b", Style::default().fg(Color::Rgb(255, 0, 0)));
+    /// assert_eq!(
+    ///     buffer.to_html(),
+    ///     "<pre class=\"tuxtui\"><span style=\"color:#ff0000;\">&lt;b</span> </pre>"
+    /// );
+    /// ```
+    #[must_use]
+    pub fn to_html(&self) -> String {
+        let mut out = String::from("<pre class=\"tuxtui\">");
+        for (y, row) in self.styled_rows().enumerate() {
+            if y > 0 {
+                out.push('\n');
+            }
+            let mut current = Style::default();
+            for (symbol, style) in row {
+                if style != current {
+                    if current != Style::default() {
+                        out.push_str("</span>");
+                    }
+                    if style != Style::default() {
+                        out.push_str("<span style=\"");
+                        write_css(&mut out, style);
+                        out.push_str("\">");
+                    }
+                    current = style;
+                }
+                write_escaped(&mut out, symbol);
+            }
+            if current != Style::default() {
+                out.push_str("</span>");
+            }
+        }
+        out.push_str("</pre>");
+        out
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::geometry::Rect;
+
+    #[test]
+    fn test_ansi_colors_and_modifiers() {
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 4, 2));
+        let style = Style::default()
+            .fg(Color::LightBlue)
+            .bg(Color::Indexed(42))
+            .add_modifier(Modifier::BOLD | Modifier::CROSSED_OUT);
+        buffer.set_string(0, 0, "ab", style);
+        buffer.set_string(1, 1, "你", Style::default().fg(Color::Rgb(1, 2, 3)));
+
+        assert_eq!(
+            buffer.to_ansi_string(),
+            "\x1b[0;1;9;94;48;5;42mab\x1b[0m  \x1b[0m\n \x1b[0;38;2;1;2;3m你\x1b[0m \x1b[0m"
+        );
+    }
+
+    #[test]
+    fn test_html_escapes_and_styles() {
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 3, 2));
+        buffer.set_string(
+            0,
+            0,
+            "a&",
+            Style::default().fg(Color::Red).bg(Color::Indexed(232)),
+        );
+        buffer.set_string(
+            0,
+            1,
+            "x",
+            Style::default()
+                .bg(Color::Indexed(196))
+                .add_modifier(Modifier::REVERSED | Modifier::UNDERLINED),
+        );
+
+        assert_eq!(
+            buffer.to_html(),
+            "<pre class=\"tuxtui\">\
+             <span style=\"color:#cd0000;background-color:#080808;\">a&amp;</span> \n\
+             <span style=\"color:#ff0000;text-decoration:underline;\">x</span>  </pre>"
+        );
+    }
+}
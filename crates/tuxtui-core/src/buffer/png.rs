@@ -0,0 +1,528 @@
+//! Rasterizing buffer contents to a PNG image with an embedded font.
+
+use super::Buffer;
+use crate::style::{Color, Modifier, Style};
+use alloc::vec::Vec;
+
+/// Width of a cell in pixels.
+const CELL_WIDTH: usize = 8;
+/// Height of a cell in pixels.
+const CELL_HEIGHT: usize = 16;
+
+type Rgb = (u8, u8, u8);
+
+/// The colors used for [`Color::Reset`].
+const DEFAULT_FG: Rgb = (229, 229, 229);
+const DEFAULT_BG: Rgb = (0, 0, 0);
+
+/// An 8x8 bitmap font for printable ASCII, from `!` to `~`, one byte per
+/// row with the leftmost pixel in the lowest bit (public domain font8x8).
+const FONT: [[u8; 8]; 94] = [
+    [0x18, 0x3C, 0x3C, 0x18, 0x18, 0x00, 0x18, 0x00],
+    [0x36, 0x36, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00],
+    [0x36, 0x36, 0x7F, 0x36, 0x7F, 0x36, 0x36, 0x00],
+    [0x0C, 0x3E, 0x03, 0x1E, 0x30, 0x1F, 0x0C, 0x00],
+    [0x00, 0x63, 0x33, 0x18, 0x0C, 0x66, 0x63, 0x00],
+    [0x1C, 0x36, 0x1C, 0x6E, 0x3B, 0x33, 0x6E, 0x00],
+    [0x06, 0x06, 0x03, 0x00, 0x00, 0x00, 0x00, 0x00],
+    [0x18, 0x0C, 0x06, 0x06, 0x06, 0x0C, 0x18, 0x00],
+    [0x06, 0x0C, 0x18, 0x18, 0x18, 0x0C, 0x06, 0x00],
+    [0x00, 0x66, 0x3C, 0xFF, 0x3C, 0x66, 0x00, 0x00],
+    [0x00, 0x0C, 0x0C, 0x3F, 0x0C, 0x0C, 0x00, 0x00],
+    [0x00, 0x00, 0x00, 0x00, 0x00, 0x0C, 0x0C, 0x06],
+    [0x00, 0x00, 0x00, 0x3F, 0x00, 0x00, 0x00, 0x00],
+    [0x00, 0x00, 0x00, 0x00, 0x00, 0x0C, 0x0C, 0x00],
+    [0x60, 0x30, 0x18, 0x0C, 0x06, 0x03, 0x01, 0x00],
+    [0x3E, 0x63, 0x73, 0x7B, 0x6F, 0x67, 0x3E, 0x00],
+    [0x0C, 0x0E, 0x0C, 0x0C, 0x0C, 0x0C, 0x3F, 0x00],
+    [0x1E, 0x33, 0x30, 0x1C, 0x06, 0x33, 0x3F, 0x00],
+    [0x1E, 0x33, 0x30, 0x1C, 0x30, 0x33, 0x1E, 0x00],
+    [0x38, 0x3C, 0x36, 0x33, 0x7F, 0x30, 0x78, 0x00],
+    [0x3F, 0x03, 0x1F, 0x30, 0x30, 0x33, 0x1E, 0x00],
+    [0x1C, 0x06, 0x03, 0x1F, 0x33, 0x33, 0x1E, 0x00],
+    [0x3F, 0x33, 0x30, 0x18, 0x0C, 0x0C, 0x0C, 0x00],
+    [0x1E, 0x33, 0x33, 0x1E, 0x33, 0x33, 0x1E, 0x00],
+    [0x1E, 0x33, 0x33, 0x3E, 0x30, 0x18, 0x0E, 0x00],
+    [0x00, 0x0C, 0x0C, 0x00, 0x00, 0x0C, 0x0C, 0x00],
+    [0x00, 0x0C, 0x0C, 0x00, 0x00, 0x0C, 0x0C, 0x06],
+    [0x18, 0x0C, 0x06, 0x03, 0x06, 0x0C, 0x18, 0x00],
+    [0x00, 0x00, 0x3F, 0x00, 0x00, 0x3F, 0x00, 0x00],
+    [0x06, 0x0C, 0x18, 0x30, 0x18, 0x0C, 0x06, 0x00],
+    [0x1E, 0x33, 0x30, 0x18, 0x0C, 0x00, 0x0C, 0x00],
+    [0x3E, 0x63, 0x7B, 0x7B, 0x7B, 0x03, 0x1E, 0x00],
+    [0x0C, 0x1E, 0x33, 0x33, 0x3F, 0x33, 0x33, 0x00],
+    [0x3F, 0x66, 0x66, 0x3E, 0x66, 0x66, 0x3F, 0x00],
+    [0x3C, 0x66, 0x03, 0x03, 0x03, 0x66, 0x3C, 0x00],
+    [0x1F, 0x36, 0x66, 0x66, 0x66, 0x36, 0x1F, 0x00],
+    [0x7F, 0x46, 0x16, 0x1E, 0x16, 0x46, 0x7F, 0x00],
+    [0x7F, 0x46, 0x16, 0x1E, 0x16, 0x06, 0x0F, 0x00],
+    [0x3C, 0x66, 0x03, 0x03, 0x73, 0x66, 0x7C, 0x00],
+    [0x33, 0x33, 0x33, 0x3F, 0x33, 0x33, 0x33, 0x00],
+    [0x1E, 0x0C, 0x0C, 0x0C, 0x0C, 0x0C, 0x1E, 0x00],
+    [0x78, 0x30, 0x30, 0x30, 0x33, 0x33, 0x1E, 0x00],
+    [0x67, 0x66, 0x36, 0x1E, 0x36, 0x66, 0x67, 0x00],
+    [0x0F, 0x06, 0x06, 0x06, 0x46, 0x66, 0x7F, 0x00],
+    [0x63, 0x77, 0x7F, 0x7F, 0x6B, 0x63, 0x63, 0x00],
+    [0x63, 0x67, 0x6F, 0x7B, 0x73, 0x63, 0x63, 0x00],
+    [0x1C, 0x36, 0x63, 0x63, 0x63, 0x36, 0x1C, 0x00],
+    [0x3F, 0x66, 0x66, 0x3E, 0x06, 0x06, 0x0F, 0x00],
+    [0x1E, 0x33, 0x33, 0x33, 0x3B, 0x1E, 0x38, 0x00],
+    [0x3F, 0x66, 0x66, 0x3E, 0x36, 0x66, 0x67, 0x00],
+    [0x1E, 0x33, 0x07, 0x0E, 0x38, 0x33, 0x1E, 0x00],
+    [0x3F, 0x2D, 0x0C, 0x0C, 0x0C, 0x0C, 0x1E, 0x00],
+    [0x33, 0x33, 0x33, 0x33, 0x33, 0x33, 0x3F, 0x00],
+    [0x33, 0x33, 0x33, 0x33, 0x33, 0x1E, 0x0C, 0x00],
+    [0x63, 0x63, 0x63, 0x6B, 0x7F, 0x77, 0x63, 0x00],
+    [0x63, 0x63, 0x36, 0x1C, 0x1C, 0x36, 0x63, 0x00],
+    [0x33, 0x33, 0x33, 0x1E, 0x0C, 0x0C, 0x1E, 0x00],
+    [0x7F, 0x63, 0x31, 0x18, 0x4C, 0x66, 0x7F, 0x00],
+    [0x1E, 0x06, 0x06, 0x06, 0x06, 0x06, 0x1E, 0x00],
+    [0x03, 0x06, 0x0C, 0x18, 0x30, 0x60, 0x40, 0x00],
+    [0x1E, 0x18, 0x18, 0x18, 0x18, 0x18, 0x1E, 0x00],
+    [0x08, 0x1C, 0x36, 0x63, 0x00, 0x00, 0x00, 0x00],
+    [0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0xFF],
+    [0x0C, 0x0C, 0x18, 0x00, 0x00, 0x00, 0x00, 0x00],
+    [0x00, 0x00, 0x1E, 0x30, 0x3E, 0x33, 0x6E, 0x00],
+    [0x07, 0x06, 0x06, 0x3E, 0x66, 0x66, 0x3B, 0x00],
+    [0x00, 0x00, 0x1E, 0x33, 0x03, 0x33, 0x1E, 0x00],
+    [0x38, 0x30, 0x30, 0x3E, 0x33, 0x33, 0x6E, 0x00],
+    [0x00, 0x00, 0x1E, 0x33, 0x3F, 0x03, 0x1E, 0x00],
+    [0x1C, 0x36, 0x06, 0x0F, 0x06, 0x06, 0x0F, 0x00],
+    [0x00, 0x00, 0x6E, 0x33, 0x33, 0x3E, 0x30, 0x1F],
+    [0x07, 0x06, 0x36, 0x6E, 0x66, 0x66, 0x67, 0x00],
+    [0x0C, 0x00, 0x0E, 0x0C, 0x0C, 0x0C, 0x1E, 0x00],
+    [0x30, 0x00, 0x30, 0x30, 0x30, 0x33, 0x33, 0x1E],
+    [0x07, 0x06, 0x66, 0x36, 0x1E, 0x36, 0x67, 0x00],
+    [0x0E, 0x0C, 0x0C, 0x0C, 0x0C, 0x0C, 0x1E, 0x00],
+    [0x00, 0x00, 0x33, 0x7F, 0x7F, 0x6B, 0x63, 0x00],
+    [0x00, 0x00, 0x1F, 0x33, 0x33, 0x33, 0x33, 0x00],
+    [0x00, 0x00, 0x1E, 0x33, 0x33, 0x33, 0x1E, 0x00],
+    [0x00, 0x00, 0x3B, 0x66, 0x66, 0x3E, 0x06, 0x0F],
+    [0x00, 0x00, 0x6E, 0x33, 0x33, 0x3E, 0x30, 0x78],
+    [0x00, 0x00, 0x3B, 0x6E, 0x66, 0x06, 0x0F, 0x00],
+    [0x00, 0x00, 0x3E, 0x03, 0x1E, 0x30, 0x1F, 0x00],
+    [0x08, 0x0C, 0x3E, 0x0C, 0x0C, 0x2C, 0x18, 0x00],
+    [0x00, 0x00, 0x33, 0x33, 0x33, 0x33, 0x6E, 0x00],
+    [0x00, 0x00, 0x33, 0x33, 0x33, 0x1E, 0x0C, 0x00],
+    [0x00, 0x00, 0x63, 0x6B, 0x7F, 0x7F, 0x36, 0x00],
+    [0x00, 0x00, 0x63, 0x36, 0x1C, 0x36, 0x63, 0x00],
+    [0x00, 0x00, 0x33, 0x33, 0x33, 0x3E, 0x30, 0x1F],
+    [0x00, 0x00, 0x3F, 0x19, 0x0C, 0x26, 0x3F, 0x00],
+    [0x38, 0x0C, 0x0C, 0x07, 0x0C, 0x0C, 0x38, 0x00],
+    [0x18, 0x18, 0x18, 0x00, 0x18, 0x18, 0x18, 0x00],
+    [0x07, 0x0C, 0x0C, 0x38, 0x0C, 0x0C, 0x07, 0x00],
+    [0x6E, 0x3B, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00],
+];
+
+/// Box-drawing characters with the weight of their arms, in the order up,
+/// right, down, left: 1 is light, 2 heavy and 3 double.
+const BOX: [(char, [u8; 4]); 40] = [
+    ('─', [0, 1, 0, 1]),
+    ('━', [0, 2, 0, 2]),
+    ('│', [1, 0, 1, 0]),
+    ('┃', [2, 0, 2, 0]),
+    ('┌', [0, 1, 1, 0]),
+    ('┏', [0, 2, 2, 0]),
+    ('╭', [0, 1, 1, 0]),
+    ('┐', [0, 0, 1, 1]),
+    ('┓', [0, 0, 2, 2]),
+    ('╮', [0, 0, 1, 1]),
+    ('└', [1, 1, 0, 0]),
+    ('┗', [2, 2, 0, 0]),
+    ('╰', [1, 1, 0, 0]),
+    ('┘', [1, 0, 0, 1]),
+    ('┛', [2, 0, 0, 2]),
+    ('╯', [1, 0, 0, 1]),
+    ('├', [1, 1, 1, 0]),
+    ('┣', [2, 2, 2, 0]),
+    ('┤', [1, 0, 1, 1]),
+    ('┫', [2, 0, 2, 2]),
+    ('┬', [0, 1, 1, 1]),
+    ('┳', [0, 2, 2, 2]),
+    ('┴', [1, 1, 0, 1]),
+    ('┻', [2, 2, 0, 2]),
+    ('┼', [1, 1, 1, 1]),
+    ('╋', [2, 2, 2, 2]),
+    ('═', [0, 3, 0, 3]),
+    ('║', [3, 0, 3, 0]),
+    ('╔', [0, 3, 3, 0]),
+    ('╗', [0, 0, 3, 3]),
+    ('╚', [3, 3, 0, 0]),
+    ('╝', [3, 0, 0, 3]),
+    ('╠', [3, 3, 3, 0]),
+    ('╣', [3, 0, 3, 3]),
+    ('╦', [0, 3, 3, 3]),
+    ('╩', [3, 3, 0, 3]),
+    ('╬', [3, 3, 3, 3]),
+    ('╴', [0, 0, 0, 1]),
+    ('╵', [1, 0, 0, 0]),
+    ('╶', [0, 1, 0, 0]),
+];
+
+/// Quadrant blocks from U+2596, as bits for the upper left, upper right,
+/// lower left and lower right quarters.
+const QUADRANTS: [u8; 10] = [
+    0b0010, 0b0001, 0b1000, 0b1011, 0b1001, 0b1110, 0b1101, 0b0100, 0b0110, 0b0111,
+];
+
+/// An RGB image.
+struct Canvas {
+    width: usize,
+    height: usize,
+    pixels: Vec<u8>,
+}
+
+impl Canvas {
+    fn new(width: usize, height: usize) -> Self {
+        Self {
+            width,
+            height,
+            pixels: alloc::vec![0; width * height * 3],
+        }
+    }
+
+    /// Fill a rectangle, clipped to the image.
+    fn fill(&mut self, x: usize, y: usize, width: usize, height: usize, color: Rgb) {
+        for row in y..(y + height).min(self.height) {
+            for col in x..(x + width).min(self.width) {
+                let i = (row * self.width + col) * 3;
+                self.pixels[i..i + 3].copy_from_slice(&[color.0, color.1, color.2]);
+            }
+        }
+    }
+
+    #[cfg(test)]
+    fn pixel(&self, x: usize, y: usize) -> Rgb {
+        let i = (y * self.width + x) * 3;
+        (self.pixels[i], self.pixels[i + 1], self.pixels[i + 2])
+    }
+}
+
+/// Mix two colors, with `weight` out of 4 parts of `a`.
+fn mix(a: Rgb, b: Rgb, weight: u16) -> Rgb {
+    let channel = |a: u8, b: u8| {
+        let mixed = (u16::from(a) * weight + u16::from(b) * (4 - weight)) / 4;
+        u8::try_from(mixed).unwrap_or(u8::MAX)
+    };
+    (channel(a.0, b.0), channel(a.1, b.1), channel(a.2, b.2))
+}
+
+/// Get the colors a cell is drawn with.
+fn colors(style: Style) -> (Rgb, Rgb) {
+    let modifiers = style.modifiers();
+    let fg = style.fg.and_then(Color::to_rgb).unwrap_or(DEFAULT_FG);
+    let bg = style.bg.and_then(Color::to_rgb).unwrap_or(DEFAULT_BG);
+    let (fg, bg) = if modifiers.contains(Modifier::REVERSED) {
+        (bg, fg)
+    } else {
+        (fg, bg)
+    };
+    if modifiers.contains(Modifier::DIM) {
+        (mix(fg, bg, 2), bg)
+    } else {
+        (fg, bg)
+    }
+}
+
+/// Draw a symbol in the cell whose top left corner is at `x`, `y`.
+///
+/// ASCII comes from the embedded font, and box-drawing, block and braille
+/// characters are drawn from their shapes. Anything else is a hollow box.
+fn draw_symbol(canvas: &mut Canvas, x: usize, y: usize, symbol: &str, fg: Rgb, bold: bool) {
+    let mut chars = symbol.chars();
+    let Some(c) = chars.next() else {
+        return;
+    };
+    let mut fill = |dx: usize, dy: usize, width: usize, height: usize| {
+        canvas.fill(x + dx, y + dy, width, height, fg);
+    };
+    match c {
+        ' ' => {}
+        '!'..='~' => {
+            let glyph = FONT[c as usize - '!' as usize];
+            for (row, bits) in glyph.iter().enumerate() {
+                let bits = if bold { bits | bits << 1 } else { *bits };
+                for col in (0..CELL_WIDTH).filter(|col| bits >> col & 1 == 1) {
+                    fill(col, row * 2, 1, 2);
+                }
+            }
+        }
+        '·' => fill(3, 7, 2, 2),
+        '•' => fill(2, 6, 4, 4),
+        '●' => fill(1, 5, 6, 6),
+        '…' => {
+            for dx in [0, 3, 6] {
+                fill(dx, 12, 2, 2);
+            }
+        }
+        '▀' => fill(0, 0, CELL_WIDTH, CELL_HEIGHT / 2),
+        '▁'..='█' => {
+            let eighths = c as usize - '▀' as usize;
+            let height = CELL_HEIGHT * eighths / 8;
+            fill(0, CELL_HEIGHT - height, CELL_WIDTH, height);
+        }
+        '▉'..='▏' => fill(
+            0,
+            0,
+            CELL_WIDTH * ('▐' as usize - c as usize) / 8,
+            CELL_HEIGHT,
+        ),
+        '▐' => fill(CELL_WIDTH / 2, 0, CELL_WIDTH / 2, CELL_HEIGHT),
+        '░'..='▓' => {
+            let level = c as usize - '▐' as usize;
+            for dy in 0..CELL_HEIGHT {
+                for dx in 0..CELL_WIDTH {
+                    let on = match level {
+                        1 => dx % 2 == 0 && dy % 2 == 0,
+                        2 => (dx + dy) % 2 == 0,
+                        _ => dx % 2 == 0 || dy % 2 == 0,
+                    };
+                    if on {
+                        fill(dx, dy, 1, 1);
+                    }
+                }
+            }
+        }
+        '▔' => fill(0, 0, CELL_WIDTH, CELL_HEIGHT / 8),
+        '▕' => fill(CELL_WIDTH * 7 / 8, 0, CELL_WIDTH / 8, CELL_HEIGHT),
+        '▖'..='▟' => {
+            let bits = QUADRANTS[c as usize - '▖' as usize];
+            let (w, h) = (CELL_WIDTH / 2, CELL_HEIGHT / 2);
+            for (bit, dx, dy) in [(8, 0, 0), (4, w, 0), (2, 0, h), (1, w, h)] {
+                if bits & bit != 0 {
+                    fill(dx, dy, w, h);
+                }
+            }
+        }
+        '\u{2800}'..='\u{28ff}' => {
+            let dots = c as usize - 0x2800;
+            let positions = [
+                (0, 0),
+                (0, 1),
+                (0, 2),
+                (1, 0),
+                (1, 1),
+                (1, 2),
+                (0, 3),
+                (1, 3),
+            ];
+            for (bit, (col, row)) in positions.into_iter().enumerate() {
+                if dots >> bit & 1 == 1 {
+                    fill(1 + col * 4, 1 + row * 4, 2, 2);
+                }
+            }
+        }
+        c => match BOX.iter().find(|(b, _)| *b == c) {
+            Some((_, arms)) => draw_box(&mut fill, *arms),
+            None => {
+                fill(1, 3, 6, 1);
+                fill(1, 12, 6, 1);
+                fill(1, 3, 1, 10);
+                fill(6, 3, 1, 10);
+            }
+        },
+    }
+}
+
+/// Draw the arms of a box-drawing character from the middle of the cell.
+fn draw_box(fill: &mut impl FnMut(usize, usize, usize, usize), arms: [u8; 4]) {
+    // The offsets and thickness of the strokes of each weight.
+    let strokes = |weight, middle: usize| match weight {
+        1 => [(middle, 1), (0, 0)],
+        2 => [(middle, 2), (0, 0)],
+        _ => [(middle - 2, 1), (middle + 2, 1)],
+    };
+    let (cx, cy) = (CELL_WIDTH / 2 - 1, CELL_HEIGHT / 2 - 1);
+    let [up, right, down, left] = arms;
+    for (weight, vertical, start, end) in [
+        (up, true, 0, cy + 3),
+        (down, true, cy - 2, CELL_HEIGHT),
+        (left, false, 0, cx + 3),
+        (right, false, cx - 2, CELL_WIDTH),
+    ] {
+        if weight == 0 {
+            continue;
+        }
+        let middle = if vertical { cx } else { cy };
+        for (offset, thickness) in strokes(weight, middle) {
+            if vertical {
+                fill(offset, start, thickness, end - start);
+            } else {
+                fill(start, offset, end - start, thickness);
+            }
+        }
+    }
+}
+
+impl Buffer {
+    /// Draw the buffer as an RGB image of 8 by 16 pixels per cell.
+    fn rasterize(&self) -> Canvas {
+        let width = usize::from(self.area.width);
+        let mut canvas = Canvas::new(
+            width * CELL_WIDTH,
+            usize::from(self.area.height) * CELL_HEIGHT,
+        );
+        for (i, cell) in self.content.iter().enumerate() {
+            let (x, y) = (i % width * CELL_WIDTH, i / width * CELL_HEIGHT);
+            let (fg, bg) = colors(cell.style);
+            canvas.fill(x, y, CELL_WIDTH, CELL_HEIGHT, bg);
+            let modifiers = cell.style.modifiers();
+            if cell.skip || modifiers.contains(Modifier::HIDDEN) {
+                continue;
+            }
+            let bold = modifiers.contains(Modifier::BOLD);
+            draw_symbol(&mut canvas, x, y, cell.symbol(), fg, bold);
+            if modifiers.contains(Modifier::UNDERLINED) {
+                canvas.fill(x, y + CELL_HEIGHT - 2, CELL_WIDTH, 1, fg);
+            }
+            if modifiers.contains(Modifier::CROSSED_OUT) {
+                canvas.fill(x, y + CELL_HEIGHT / 2, CELL_WIDTH, 1, fg);
+            }
+        }
+        canvas
+    }
+
+    /// Render the buffer as a PNG image, for READMEs and golden tests.
+    ///
+    /// Each cell is 8 by 16 pixels. ASCII uses an embedded bitmap font, and
+    /// box-drawing, block and braille characters are drawn from their
+    /// shapes, so borders, gauges and canvases look as in a terminal; other
+    /// characters show as a hollow box. Colors use the xterm default palette
+    /// on a black background.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use tuxtui_core::buffer::Buffer;
+    ///
+    /// let buffer = Buffer::from_lines(&["┌─┐", "└─┘"]);
+    /// let png = buffer.to_png();
+    /// assert_eq!(&png[..8], b"\x89PNG\r\n\x1a\n");
+    /// ```
+    #[must_use]
+    pub fn to_png(&self) -> Vec<u8> {
+        let canvas = self.rasterize();
+        encode_png(&canvas)
+    }
+}
+
+/// The CRC-32 lookup table used by PNG chunks.
+const CRC_TABLE: [u32; 256] = {
+    let mut table = [0; 256];
+    let mut n = 0;
+    while n < 256 {
+        let mut c = n as u32;
+        let mut k = 0;
+        while k < 8 {
+            c = if c & 1 == 1 {
+                0xedb8_8320 ^ (c >> 1)
+            } else {
+                c >> 1
+            };
+            k += 1;
+        }
+        table[n] = c;
+        n += 1;
+    }
+    table
+};
+
+fn crc32(bytes: &[u8]) -> u32 {
+    !bytes.iter().fold(!0, |crc, byte| {
+        CRC_TABLE[(crc ^ u32::from(*byte)) as usize & 0xff] ^ (crc >> 8)
+    })
+}
+
+fn adler32(bytes: &[u8]) -> u32 {
+    let (a, b) = bytes.iter().fold((1, 0), |(a, b), byte| {
+        let a = (a + u32::from(*byte)) % 65521;
+        (a, (b + a) % 65521)
+    });
+    b << 16 | a
+}
+
+/// Append a PNG chunk.
+fn write_chunk(out: &mut Vec<u8>, kind: &[u8; 4], data: &[u8]) {
+    let len = u32::try_from(data.len()).expect("PNG chunks are smaller than 4 GiB");
+    out.extend_from_slice(&len.to_be_bytes());
+    let start = out.len();
+    out.extend_from_slice(kind);
+    out.extend_from_slice(data);
+    let crc = crc32(&out[start..]);
+    out.extend_from_slice(&crc.to_be_bytes());
+}
+
+/// Encode an image as an uncompressed 8-bit RGB PNG.
+///
+/// The image data is stored in a zlib stream of uncompressed blocks, which
+/// keeps the encoder small; the images are mostly flat color and compress
+/// well if size matters.
+fn encode_png(canvas: &Canvas) -> Vec<u8> {
+    let mut raw = Vec::with_capacity((canvas.width * 3 + 1) * canvas.height);
+    for row in canvas.pixels.chunks(canvas.width * 3) {
+        // Filter type 0: none
+        raw.push(0);
+        raw.extend_from_slice(row);
+    }
+
+    let mut zlib = alloc::vec![0x78, 0x01];
+    let blocks = raw.chunks(usize::from(u16::MAX)).collect::<Vec<_>>();
+    for (i, block) in blocks.iter().enumerate() {
+        let last = u8::from(i + 1 == blocks.len());
+        let len = u16::try_from(block.len()).expect("blocks are split at u16::MAX");
+        zlib.push(last);
+        zlib.extend_from_slice(&len.to_le_bytes());
+        zlib.extend_from_slice(&(!len).to_le_bytes());
+        zlib.extend_from_slice(block);
+    }
+    zlib.extend_from_slice(&adler32(&raw).to_be_bytes());
+
+    let dimension = |n: usize| u32::try_from(n).unwrap_or(u32::MAX).to_be_bytes();
+    let mut header = Vec::with_capacity(13);
+    header.extend_from_slice(&dimension(canvas.width));
+    header.extend_from_slice(&dimension(canvas.height));
+    // 8 bits per channel, RGB, default compression, filtering and no interlace
+    header.extend_from_slice(&[8, 2, 0, 0, 0]);
+
+    let mut out = b"\x89PNG\r\n\x1a\n".to_vec();
+    write_chunk(&mut out, b"IHDR", &header);
+    write_chunk(&mut out, b"IDAT", &zlib);
+    write_chunk(&mut out, b"IEND", &[]);
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::geometry::Rect;
+
+    #[test]
+    fn test_rasterize() {
+        let mut buffer = Buffer::from_lines(&["A─█"]);
+        buffer.set_style(Rect::new(0, 0, 1, 1), Style::new().bg(Color::Blue));
+        let canvas = buffer.rasterize();
+        assert_eq!((canvas.width, canvas.height), (24, 16));
+
+        // The corner of the `A` is background, its crossbar foreground.
+        assert_eq!(canvas.pixel(0, 0), Color::Blue.to_rgb().unwrap());
+        assert_eq!(canvas.pixel(1, 8), DEFAULT_FG);
+        // The horizontal line runs through the middle of its cell.
+        assert_eq!(canvas.pixel(8, 7), DEFAULT_FG);
+        assert_eq!(canvas.pixel(8, 0), DEFAULT_BG);
+        // The full block covers its cell.
+        assert_eq!(canvas.pixel(16, 0), DEFAULT_FG);
+        assert_eq!(canvas.pixel(23, 15), DEFAULT_FG);
+    }
+
+    #[test]
+    fn test_png_encoding() {
+        let png = Buffer::from_lines(&["ab"]).to_png();
+        assert_eq!(&png[..8], b"\x89PNG\r\n\x1a\n");
+        // IHDR: 16 by 16 pixels
+        assert_eq!(&png[12..16], b"IHDR");
+        assert_eq!(&png[16..24], &[0, 0, 0, 16, 0, 0, 0, 16]);
+        // IEND with its well-known checksum
+        assert_eq!(&png[png.len() - 8..], b"IEND\xae\x42\x60\x82");
+        assert_eq!(adler32(b"Wikipedia"), 0x11e6_0398);
+    }
+}
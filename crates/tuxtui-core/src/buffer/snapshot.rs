@@ -0,0 +1,273 @@
+//! Snapshot representation and styled assertions for tests.
+
+use super::Buffer;
+use crate::geometry::Rect;
+use crate::style::{Modifier, Style};
+use alloc::string::String;
+use alloc::vec::Vec;
+use core::fmt::{self, Write};
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Assert that two buffers hold the same symbols and styles.
+///
+/// On failure, the panic message shows both buffers in color followed by
+/// every differing cell.
+///
+/// # Example
+///
+/// ```
+/// use tuxtui_core::assert_buffer_eq;
+/// use tuxtui_core::buffer::Buffer;
+/// use tuxtui_core::geometry::Rect;
+/// use tuxtui_core::style::{Color, Style};
+///
+/// let mut actual = Buffer::empty(Rect::new(0, 0, 3, 1));
+/// actual.set_string(0, 0, "ok", Style::default().fg(Color::Green));
+///
+/// let mut expected = Buffer::from_lines(&["ok "]);
+/// expected.set_style(Rect::new(0, 0, 2, 1), Style::default().fg(Color::Green));
+/// assert_buffer_eq!(actual, expected);
+/// ```
+#[macro_export]
+macro_rules! assert_buffer_eq {
+    ($actual:expr, $expected:expr $(,)?) => {
+        if let Some(report) = $crate::buffer::Buffer::diff_report(&$actual, &$expected) {
+            panic!("buffers are not equal\n{report}");
+        }
+    };
+}
+
+/// A text representation of a buffer for snapshot tests.
+///
+/// The lines hold the symbols of each row, and the styles list every
+/// position where the style changes, in reading order, starting from the
+/// default style. Both [`fmt::Display`] and serde produce stable output
+/// suitable for tools such as insta.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct BufferSnapshot {
+    /// The area of the buffer
+    pub area: Rect,
+    /// The symbols of each row
+    pub lines: Vec<String>,
+    /// The style changes, in reading order
+    pub styles: Vec<StyleChange>,
+}
+
+/// A style change in a [`BufferSnapshot`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct StyleChange {
+    /// X coordinate of the first cell with the style
+    pub x: u16,
+    /// Y coordinate of the first cell with the style
+    pub y: u16,
+    /// The new style
+    pub style: Style,
+}
+
+impl fmt::Display for BufferSnapshot {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let Rect {
+            x,
+            y,
+            width,
+            height,
+        } = self.area;
+        writeln!(f, "area: {width}x{height} at ({x}, {y})")?;
+        for line in &self.lines {
+            writeln!(f, "{line:?}")?;
+        }
+        if !self.styles.is_empty() {
+            writeln!(f, "styles:")?;
+            for change in &self.styles {
+                writeln!(
+                    f,
+                    "  ({}, {}): {}",
+                    change.x,
+                    change.y,
+                    StyleDisplay(change.style)
+                )?;
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Compact display of a style, such as `fg=Red bg=Rgb(0, 0, 0) +BOLD`.
+struct StyleDisplay(Style);
+
+impl fmt::Display for StyleDisplay {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let style = self.0;
+        if style == Style::default() {
+            return f.write_str("default");
+        }
+        let mut parts = Vec::new();
+        if let Some(fg) = style.fg {
+            parts.push(alloc::format!("fg={fg:?}"));
+        }
+        if let Some(bg) = style.bg {
+            parts.push(alloc::format!("bg={bg:?}"));
+        }
+        #[cfg(feature = "underline-color")]
+        if let Some(color) = style.underline_color {
+            parts.push(alloc::format!("underline={color:?}"));
+        }
+        let modifiers = |sign: char, modifier: Modifier| {
+            modifier
+                .iter_names()
+                .map(move |(name, _)| alloc::format!("{sign}{name}"))
+        };
+        parts.extend(modifiers('+', style.add_modifier));
+        parts.extend(modifiers('-', style.sub_modifier));
+        f.write_str(&parts.join(" "))
+    }
+}
+
+impl Buffer {
+    /// Get a text representation of the buffer for snapshot tests.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use tuxtui_core::buffer::Buffer;
+    /// use tuxtui_core::geometry::Rect;
+    /// use tuxtui_core::style::{Color, Style};
+    ///
+    /// let mut buffer = Buffer::from_lines(&["hi!"]);
+    /// buffer.set_style(Rect::new(0, 0, 2, 1), Style::default().fg(Color::Red));
+    /// assert_eq!(
+    ///     buffer.snapshot().to_string(),
+    ///     "area: 3x1 at (0, 0)\n\"hi!\"\nstyles:\n  (0, 0): fg=Red\n  (2, 0): default\n"
+    /// );
+    /// ```
+    #[must_use]
+    pub fn snapshot(&self) -> BufferSnapshot {
+        let width = usize::from(self.area.width).max(1);
+        let mut lines = Vec::with_capacity(usize::from(self.area.height));
+        let mut styles = Vec::new();
+        let mut current = Style::default();
+        for (y, row) in (self.area.y..).zip(self.content.chunks(width)) {
+            let mut line = String::new();
+            for (x, cell) in (self.area.x..).zip(row) {
+                if cell.skip {
+                    continue;
+                }
+                if cell.style != current {
+                    current = cell.style;
+                    styles.push(StyleChange {
+                        x,
+                        y,
+                        style: current,
+                    });
+                }
+                line.push_str(cell.symbol());
+            }
+            lines.push(line);
+        }
+        BufferSnapshot {
+            area: self.area,
+            lines,
+            styles,
+        }
+    }
+
+    /// Describe how this buffer differs from `expected`.
+    ///
+    /// Only symbols and styles are compared, and the cells covered by wide
+    /// characters are ignored. Returns `None` if the buffers
+    /// are equal; otherwise the report shows both buffers with their styles
+    /// as ANSI escape sequences, followed by the differing cells. This backs
+    /// [`assert_buffer_eq!`](crate::assert_buffer_eq).
+    #[must_use]
+    pub fn diff_report(&self, expected: &Self) -> Option<String> {
+        let mut report = String::new();
+        if self.area != expected.area {
+            let _ = writeln!(
+                report,
+                "area: expected {:?}, actual {:?}",
+                expected.area, self.area
+            );
+        } else {
+            for (i, (actual, wanted)) in self.content.iter().zip(&expected.content).enumerate() {
+                let same = actual.symbol() == wanted.symbol() && actual.style == wanted.style;
+                // Continuations of wide characters are never drawn.
+                if same || (actual.skip && wanted.skip) {
+                    continue;
+                }
+                let width = usize::from(self.area.width);
+                let x = self.area.x as usize + i % width;
+                let y = self.area.y as usize + i / width;
+                let _ = writeln!(
+                    report,
+                    "  ({x}, {y}): expected {:?} {}, actual {:?} {}",
+                    wanted.symbol(),
+                    StyleDisplay(wanted.style),
+                    actual.symbol(),
+                    StyleDisplay(actual.style),
+                );
+            }
+            if report.is_empty() {
+                return None;
+            }
+            report.insert_str(0, "differences:\n");
+        }
+        Some(alloc::format!(
+            "expected:\n{}\nactual:\n{}\n{report}",
+            expected.to_ansi_string(),
+            self.to_ansi_string()
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::style::Color;
+
+    #[test]
+    fn test_snapshot_skips_wide_continuations() {
+        let mut buffer = Buffer::from_lines(&["你好", "ab"]);
+        buffer.set_style(
+            Rect::new(1, 1, 1, 1),
+            Style::default()
+                .bg(Color::Blue)
+                .add_modifier(Modifier::BOLD),
+        );
+        insta::assert_snapshot!(buffer.snapshot(), @r#"
+        area: 4x2 at (0, 0)
+        "你好"
+        "ab  "
+        styles:
+          (1, 1): bg=Blue +BOLD
+          (2, 1): default
+        "#);
+    }
+
+    #[test]
+    fn test_diff_report_lists_cells() {
+        let mut actual = Buffer::from_lines(&["abc"]);
+        let expected = actual.clone();
+        assert_eq!(actual.diff_report(&expected), None);
+        assert_buffer_eq!(actual, expected);
+
+        actual.set_style(Rect::new(1, 0, 1, 1), Style::default().fg(Color::Red));
+        let report = actual.diff_report(&expected).unwrap();
+        assert!(
+            report
+                .ends_with("differences:\n  (1, 0): expected \"b\" default, actual \"b\" fg=Red\n")
+        );
+
+        let report = Buffer::from_lines(&["ab"]).diff_report(&expected).unwrap();
+        assert!(report.contains("area: expected"));
+    }
+
+    #[test]
+    #[should_panic(expected = "buffers are not equal")]
+    fn test_assert_buffer_eq_panics() {
+        assert_buffer_eq!(Buffer::from_lines(&["a"]), Buffer::from_lines(&["b"]));
+    }
+}
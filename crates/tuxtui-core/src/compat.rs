@@ -0,0 +1,449 @@
+//! Interop with ratatui, for migrating an application one widget at a time.
+//!
+//! Rectangles, colors, styles, text and buffers convert both ways with
+//! [`From`], and [`RatatuiWidget`] renders any ratatui widget into a tuxtui
+//! [`Buffer`]. ratatui numbers its grays differently: its `Gray`, `DarkGray`
+//! and `White` are [`Color::White`], [`Color::Gray`] and [`Color::LightGray`].
+//!
+//! Some things have no counterpart and are dropped on the way: the
+//! [direction](crate::text::TextDirection) of a line, a cell's removed
+//! modifiers and ratatui's cell diff options. A cell color of
+//! [`Color::Reset`] becomes no color, which draws the same.
+//!
+//! ```
+//! use ratatui_core::text::Line as RatatuiLine;
+//! use tuxtui_core::buffer::Buffer;
+//! use tuxtui_core::compat::RatatuiWidget;
+//! use tuxtui_core::geometry::Rect;
+//! use tuxtui_core::terminal::Widget;
+//!
+//! let mut buffer = Buffer::empty(Rect::new(0, 0, 5, 1));
+//! RatatuiWidget::new(RatatuiLine::from("hello")).render(buffer.area, &mut buffer);
+//! assert_eq!(buffer, Buffer::from_lines(&["hello"]));
+//! ```
+
+use crate::buffer::Buffer;
+use crate::geometry::{Alignment, Rect};
+use crate::style::{Color, Modifier, Style};
+use crate::terminal::Widget;
+use crate::text::{Line, Span, Text};
+use alloc::vec::Vec;
+use ratatui_core::layout::HorizontalAlignment;
+
+impl From<Rect> for ratatui_core::layout::Rect {
+    fn from(rect: Rect) -> Self {
+        Self {
+            x: rect.x,
+            y: rect.y,
+            width: rect.width,
+            height: rect.height,
+        }
+    }
+}
+
+impl From<ratatui_core::layout::Rect> for Rect {
+    fn from(rect: ratatui_core::layout::Rect) -> Self {
+        Self {
+            x: rect.x,
+            y: rect.y,
+            width: rect.width,
+            height: rect.height,
+        }
+    }
+}
+
+impl From<Color> for ratatui_core::style::Color {
+    fn from(color: Color) -> Self {
+        match color {
+            Color::Reset => Self::Reset,
+            Color::Black => Self::Black,
+            Color::Red => Self::Red,
+            Color::Green => Self::Green,
+            Color::Yellow => Self::Yellow,
+            Color::Blue => Self::Blue,
+            Color::Magenta => Self::Magenta,
+            Color::Cyan => Self::Cyan,
+            Color::White => Self::Gray,
+            Color::Gray => Self::DarkGray,
+            Color::LightRed => Self::LightRed,
+            Color::LightGreen => Self::LightGreen,
+            Color::LightYellow => Self::LightYellow,
+            Color::LightBlue => Self::LightBlue,
+            Color::LightMagenta => Self::LightMagenta,
+            Color::LightCyan => Self::LightCyan,
+            Color::LightGray => Self::White,
+            Color::Indexed(index) => Self::Indexed(index),
+            Color::Rgb(r, g, b) => Self::Rgb(r, g, b),
+        }
+    }
+}
+
+impl From<ratatui_core::style::Color> for Color {
+    fn from(color: ratatui_core::style::Color) -> Self {
+        use ratatui_core::style::Color as C;
+        match color {
+            C::Reset => Self::Reset,
+            C::Black => Self::Black,
+            C::Red => Self::Red,
+            C::Green => Self::Green,
+            C::Yellow => Self::Yellow,
+            C::Blue => Self::Blue,
+            C::Magenta => Self::Magenta,
+            C::Cyan => Self::Cyan,
+            C::Gray => Self::White,
+            C::DarkGray => Self::Gray,
+            C::LightRed => Self::LightRed,
+            C::LightGreen => Self::LightGreen,
+            C::LightYellow => Self::LightYellow,
+            C::LightBlue => Self::LightBlue,
+            C::LightMagenta => Self::LightMagenta,
+            C::LightCyan => Self::LightCyan,
+            C::White => Self::LightGray,
+            C::Indexed(index) => Self::Indexed(index),
+            C::Rgb(r, g, b) => Self::Rgb(r, g, b),
+        }
+    }
+}
+
+impl From<Modifier> for ratatui_core::style::Modifier {
+    fn from(modifier: Modifier) -> Self {
+        Self::from_bits_truncate(modifier.bits())
+    }
+}
+
+impl From<ratatui_core::style::Modifier> for Modifier {
+    fn from(modifier: ratatui_core::style::Modifier) -> Self {
+        Self::from_bits_truncate(modifier.bits())
+    }
+}
+
+impl From<Style> for ratatui_core::style::Style {
+    fn from(style: Style) -> Self {
+        let mut converted = Self::new()
+            .add_modifier(style.add_modifier.into())
+            .remove_modifier(style.sub_modifier.into());
+        converted.fg = style.fg.map(Into::into);
+        converted.bg = style.bg.map(Into::into);
+        #[cfg(feature = "underline-color")]
+        {
+            converted.underline_color = style.underline_color.map(Into::into);
+        }
+        converted
+    }
+}
+
+impl From<ratatui_core::style::Style> for Style {
+    fn from(style: ratatui_core::style::Style) -> Self {
+        Self {
+            fg: style.fg.map(Into::into),
+            bg: style.bg.map(Into::into),
+            #[cfg(feature = "underline-color")]
+            underline_color: style.underline_color.map(Into::into),
+            add_modifier: style.add_modifier.into(),
+            sub_modifier: style.sub_modifier.into(),
+        }
+    }
+}
+
+impl From<Alignment> for HorizontalAlignment {
+    fn from(alignment: Alignment) -> Self {
+        match alignment {
+            Alignment::Start => Self::Left,
+            Alignment::Center => Self::Center,
+            Alignment::End => Self::Right,
+        }
+    }
+}
+
+impl From<HorizontalAlignment> for Alignment {
+    fn from(alignment: HorizontalAlignment) -> Self {
+        match alignment {
+            HorizontalAlignment::Left => Self::Start,
+            HorizontalAlignment::Center => Self::Center,
+            HorizontalAlignment::Right => Self::End,
+        }
+    }
+}
+
+impl<'a> From<Span<'a>> for ratatui_core::text::Span<'a> {
+    fn from(span: Span<'a>) -> Self {
+        Self::styled(span.content, span.style)
+    }
+}
+
+impl<'a> From<ratatui_core::text::Span<'a>> for Span<'a> {
+    fn from(span: ratatui_core::text::Span<'a>) -> Self {
+        Self::styled(span.content, span.style.into())
+    }
+}
+
+impl<'a> From<Line<'a>> for ratatui_core::text::Line<'a> {
+    fn from(line: Line<'a>) -> Self {
+        let spans: Vec<ratatui_core::text::Span<'a>> =
+            line.spans.into_iter().map(Into::into).collect();
+        let converted = Self::from(spans).style(line.style);
+        // Start is the default on both sides, so it stays unset and a
+        // ratatui text's own alignment still applies to the line.
+        match line.alignment {
+            Alignment::Start => converted,
+            alignment => converted.alignment(HorizontalAlignment::from(alignment)),
+        }
+    }
+}
+
+impl<'a> From<ratatui_core::text::Line<'a>> for Line<'a> {
+    fn from(line: ratatui_core::text::Line<'a>) -> Self {
+        let alignment = line.alignment.map_or(Alignment::Start, Into::into);
+        let mut converted =
+            Line::from_spans(line.spans.into_iter().map(Into::into).collect()).alignment(alignment);
+        converted.style = line.style.into();
+        converted
+    }
+}
+
+impl<'a> From<Text<'a>> for ratatui_core::text::Text<'a> {
+    fn from(text: Text<'a>) -> Self {
+        let lines: Vec<ratatui_core::text::Line<'a>> =
+            text.lines.into_iter().map(Into::into).collect();
+        Self::from(lines).style(text.style)
+    }
+}
+
+impl<'a> From<ratatui_core::text::Text<'a>> for Text<'a> {
+    /// Lines without an alignment of their own take the text's.
+    fn from(text: ratatui_core::text::Text<'a>) -> Self {
+        let alignment = text.alignment;
+        Self {
+            lines: text
+                .lines
+                .into_iter()
+                .map(|mut line| {
+                    line.alignment = line.alignment.or(alignment);
+                    line.into()
+                })
+                .collect(),
+            style: text.style.into(),
+        }
+    }
+}
+
+impl From<&Buffer> for ratatui_core::buffer::Buffer {
+    fn from(buffer: &Buffer) -> Self {
+        let mut converted = Self::empty(buffer.area.into());
+        copy_to_ratatui(buffer, &mut converted, buffer.area);
+        converted
+    }
+}
+
+impl From<&ratatui_core::buffer::Buffer> for Buffer {
+    fn from(buffer: &ratatui_core::buffer::Buffer) -> Self {
+        let mut converted = Self::empty(buffer.area.into());
+        copy_from_ratatui(buffer, &mut converted, buffer.area.into());
+        converted
+    }
+}
+
+/// Copy the cells in `area` to a ratatui buffer.
+///
+/// The cells hidden by a wide character stay blank, as ratatui expects.
+fn copy_to_ratatui(source: &Buffer, target: &mut ratatui_core::buffer::Buffer, area: Rect) {
+    for y in area.top()..area.bottom() {
+        for x in area.left()..area.right() {
+            let (Some(cell), Some(converted)) = (source.get(x, y), target.cell_mut((x, y))) else {
+                continue;
+            };
+            converted.reset();
+            if cell.skip {
+                continue;
+            }
+            converted.set_symbol(cell.symbol());
+            converted.set_style(cell.style);
+        }
+    }
+}
+
+/// Copy the cells in `area` from a ratatui buffer, marking the cells hidden
+/// by a wide character as [`skip`](crate::buffer::Cell::skip) cells.
+fn copy_from_ratatui(source: &ratatui_core::buffer::Buffer, target: &mut Buffer, area: Rect) {
+    use ratatui_core::style::Color as C;
+    for y in area.top()..area.bottom() {
+        let mut x = area.left();
+        while x < area.right() {
+            let Some(cell) = source.cell((x, y)) else {
+                x += 1;
+                continue;
+            };
+            let style = Style {
+                fg: (cell.fg != C::Reset).then(|| cell.fg.into()),
+                bg: (cell.bg != C::Reset).then(|| cell.bg.into()),
+                #[cfg(feature = "underline-color")]
+                underline_color: (cell.underline_color != C::Reset)
+                    .then(|| cell.underline_color.into()),
+                add_modifier: cell.modifier.into(),
+                sub_modifier: Modifier::empty(),
+            };
+            if !target.set(x, y, cell.symbol(), style) {
+                target.set(x, y, " ", style);
+            }
+            x += 1;
+            while x < area.right() && target.get(x, y).is_some_and(|cell| cell.skip) {
+                x += 1;
+            }
+        }
+    }
+}
+
+/// Renders a ratatui widget into a tuxtui [`Buffer`].
+///
+/// The widget draws on a copy of the area, so cells it leaves alone keep
+/// their content.
+///
+/// # Example
+///
+/// ```
+/// use ratatui_core::text::Line as RatatuiLine;
+/// use tuxtui_core::buffer::Buffer;
+/// use tuxtui_core::compat::RatatuiWidget;
+/// use tuxtui_core::geometry::Rect;
+/// use tuxtui_core::terminal::Widget;
+///
+/// let mut buffer = Buffer::empty(Rect::new(0, 0, 8, 1));
+/// RatatuiWidget::new(RatatuiLine::from("ratatui")).render(buffer.area, &mut buffer);
+/// assert_eq!(buffer, Buffer::from_lines(&["ratatui "]));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct RatatuiWidget<W> {
+    widget: W,
+}
+
+impl<W> RatatuiWidget<W> {
+    /// Wrap a ratatui widget.
+    #[must_use]
+    pub const fn new(widget: W) -> Self {
+        Self { widget }
+    }
+
+    /// Unwrap the ratatui widget.
+    #[must_use]
+    pub fn into_inner(self) -> W {
+        self.widget
+    }
+}
+
+impl<W: ratatui_core::widgets::Widget> Widget for RatatuiWidget<W> {
+    fn render(self, area: Rect, buf: &mut Buffer) {
+        let area = area.intersection(buf.area);
+        if area.is_empty() {
+            return;
+        }
+        let mut scratch = ratatui_core::buffer::Buffer::empty(area.into());
+        copy_to_ratatui(buf, &mut scratch, area);
+        self.widget.render(area.into(), &mut scratch);
+        copy_from_ratatui(&scratch, buf, area);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloc::vec;
+
+    #[test]
+    fn test_rect_round_trip() {
+        let rect = Rect::new(3, 4, 50, 60);
+        let converted = ratatui_core::layout::Rect::from(rect);
+        assert_eq!(converted, ratatui_core::layout::Rect::new(3, 4, 50, 60));
+        assert_eq!(Rect::from(converted), rect);
+    }
+
+    #[test]
+    fn test_color_round_trip() {
+        for color in [
+            Color::Reset,
+            Color::Red,
+            Color::White,
+            Color::Gray,
+            Color::LightGray,
+            Color::LightCyan,
+            Color::Indexed(42),
+            Color::Rgb(1, 2, 3),
+        ] {
+            assert_eq!(Color::from(ratatui_core::style::Color::from(color)), color);
+        }
+        assert_eq!(
+            ratatui_core::style::Color::from(Color::Gray),
+            ratatui_core::style::Color::DarkGray
+        );
+    }
+
+    #[test]
+    fn test_style_round_trip() {
+        let style = Style::default()
+            .fg(Color::Yellow)
+            .bg(Color::Rgb(10, 20, 30))
+            .add_modifier(Modifier::BOLD | Modifier::RAPID_BLINK)
+            .remove_modifier(Modifier::ITALIC);
+        let converted = ratatui_core::style::Style::from(style);
+        assert_eq!(
+            converted.add_modifier,
+            ratatui_core::style::Modifier::BOLD | ratatui_core::style::Modifier::RAPID_BLINK
+        );
+        assert_eq!(Style::from(converted), style);
+    }
+
+    #[test]
+    fn test_text_round_trip() {
+        let bold = Style::default().add_modifier(Modifier::BOLD);
+        let text = Text {
+            lines: vec![
+                Line::from_spans(vec![Span::raw("plain "), Span::styled("bold", bold)]),
+                Line::from("right").alignment(Alignment::End),
+            ],
+            style: Style::default().fg(Color::Blue),
+        };
+        let converted = ratatui_core::text::Text::from(text.clone());
+        assert_eq!(converted.lines[0].spans[1].content, "bold");
+        assert_eq!(converted.lines[0].alignment, None);
+        assert_eq!(
+            converted.lines[1].alignment,
+            Some(HorizontalAlignment::Right)
+        );
+        assert_eq!(Text::from(converted), text);
+    }
+
+    #[test]
+    fn test_text_alignment_applies_to_lines() {
+        let text = ratatui_core::text::Text::from(vec![
+            ratatui_core::text::Line::from("a"),
+            ratatui_core::text::Line::from("b").left_aligned(),
+        ])
+        .centered();
+        let converted = Text::from(text);
+        assert_eq!(converted.lines[0].alignment, Alignment::Center);
+        assert_eq!(converted.lines[1].alignment, Alignment::Start);
+    }
+
+    #[test]
+    fn test_buffer_round_trip() {
+        let mut buffer = Buffer::empty(Rect::new(2, 1, 6, 2));
+        buffer.set_string(2, 1, "a界b", Style::default().fg(Color::Red));
+        buffer.set_string(3, 2, "xyz", Style::default().add_modifier(Modifier::ITALIC));
+        let converted = ratatui_core::buffer::Buffer::from(&buffer);
+        assert_eq!(converted.area, ratatui_core::layout::Rect::new(2, 1, 6, 2));
+        assert_eq!(converted[(3, 1)].symbol(), "界");
+        assert_eq!(converted[(4, 1)].symbol(), " ");
+        assert_eq!(Buffer::from(&converted), buffer);
+    }
+
+    #[test]
+    fn test_render_ratatui_widget() {
+        let mut buffer = Buffer::from_lines(&["......", "......"]);
+        let line = ratatui_core::text::Line::styled("hi界", ratatui_core::style::Color::Green);
+        RatatuiWidget::new(line).render(Rect::new(1, 1, 4, 5), &mut buffer);
+
+        let mut expected = Buffer::from_lines(&["......", "......"]);
+        expected.set_string(1, 1, "hi界", Style::default().fg(Color::Green));
+        assert_eq!(buffer, expected);
+        assert!(buffer.get(4, 1).is_some_and(|cell| cell.skip));
+    }
+}
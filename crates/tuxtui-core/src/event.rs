@@ -1,8 +1,148 @@
 //! Event handling types and utilities.
 
+use alloc::string::String;
+
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
 
+/// A terminal input event, independent of the backend.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum Event {
+    /// A key was pressed
+    Key(KeyEvent),
+    /// A mouse event occurred
+    Mouse(MouseEvent),
+    /// The terminal was resized to the given width and height
+    Resize(u16, u16),
+    /// The terminal gained focus
+    FocusGained,
+    /// The terminal lost focus
+    FocusLost,
+    /// Text was pasted
+    Paste(String),
+}
+
+impl From<KeyEvent> for Event {
+    fn from(event: KeyEvent) -> Self {
+        Self::Key(event)
+    }
+}
+
+impl From<KeyCode> for Event {
+    fn from(code: KeyCode) -> Self {
+        Self::Key(code.into())
+    }
+}
+
+impl From<MouseEvent> for Event {
+    fn from(event: MouseEvent) -> Self {
+        Self::Mouse(event)
+    }
+}
+
+/// Key codes.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum KeyCode {
+    /// A character key
+    Char(char),
+    /// Enter key
+    Enter,
+    /// Backspace key
+    Backspace,
+    /// Tab key
+    Tab,
+    /// Shift+Tab
+    BackTab,
+    /// Escape key
+    Esc,
+    /// Left arrow
+    Left,
+    /// Right arrow
+    Right,
+    /// Up arrow
+    Up,
+    /// Down arrow
+    Down,
+    /// Home key
+    Home,
+    /// End key
+    End,
+    /// Page up key
+    PageUp,
+    /// Page down key
+    PageDown,
+    /// Delete key
+    Delete,
+    /// Insert key
+    Insert,
+    /// Function key (F1 is `F(1)`)
+    F(u8),
+}
+
+/// Whether a key event is a press, a repeat while held down, or a release.
+///
+/// Only some terminals report repeats and releases, such as Windows
+/// consoles and terminals with the kitty keyboard protocol; elsewhere every
+/// key event is a press.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum KeyEventKind {
+    /// The key was pressed
+    #[default]
+    Press,
+    /// The key is held down and repeating
+    Repeat,
+    /// The key was released
+    Release,
+}
+
+/// A key press, repeat or release with its modifiers.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct KeyEvent {
+    /// The key
+    pub code: KeyCode,
+    /// The modifiers held down
+    pub modifiers: KeyModifiers,
+    /// Whether the key was pressed, repeated or released
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub kind: KeyEventKind,
+}
+
+impl KeyEvent {
+    /// Create a new key press.
+    #[must_use]
+    pub const fn new(code: KeyCode, modifiers: KeyModifiers) -> Self {
+        Self {
+            code,
+            modifiers,
+            kind: KeyEventKind::Press,
+        }
+    }
+
+    /// Set whether the key was pressed, repeated or released.
+    #[must_use]
+    pub const fn kind(mut self, kind: KeyEventKind) -> Self {
+        self.kind = kind;
+        self
+    }
+
+    /// Check if the key was released, which widgets and key bindings
+    /// ignore.
+    #[must_use]
+    pub const fn is_release(&self) -> bool {
+        matches!(self.kind, KeyEventKind::Release)
+    }
+}
+
+impl From<KeyCode> for KeyEvent {
+    fn from(code: KeyCode) -> Self {
+        Self::new(code, KeyModifiers::NONE)
+    }
+}
+
 /// Mouse button types.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
@@ -27,7 +167,7 @@ pub enum MouseEventKind {
     Drag(MouseButton),
     /// Mouse moved without button
     Moved,
-    /// Mouse wheel scrolled
+    /// Mouse wheel scrolled down
     ScrollDown,
     /// Mouse wheel scrolled up
     ScrollUp,
@@ -37,6 +177,17 @@ pub enum MouseEventKind {
     ScrollRight,
 }
 
+impl MouseEventKind {
+    /// Check if this is a mouse wheel event.
+    #[must_use]
+    pub const fn is_scroll(&self) -> bool {
+        matches!(
+            self,
+            Self::ScrollDown | Self::ScrollUp | Self::ScrollLeft | Self::ScrollRight
+        )
+    }
+}
+
 /// A mouse event with position.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
@@ -47,13 +198,27 @@ pub struct MouseEvent {
     pub column: u16,
     /// Row position (0-indexed)
     pub row: u16,
+    /// Keyboard modifiers held during the event
+    pub modifiers: KeyModifiers,
 }
 
 impl MouseEvent {
     /// Create a new mouse event.
     #[must_use]
     pub const fn new(kind: MouseEventKind, column: u16, row: u16) -> Self {
-        Self { kind, column, row }
+        Self {
+            kind,
+            column,
+            row,
+            modifiers: KeyModifiers::NONE,
+        }
+    }
+
+    /// Set the keyboard modifiers held during the event.
+    #[must_use]
+    pub const fn modifiers(mut self, modifiers: KeyModifiers) -> Self {
+        self.modifiers = modifiers;
+        self
     }
 
     /// Check if this is a click event at the given position.
@@ -142,4 +307,24 @@ mod tests {
         let outside_area = Rect::new(20, 20, 10, 10);
         assert!(!event.is_click_in(outside_area));
     }
+
+    #[test]
+    fn test_key_event_kind() {
+        let press = KeyEvent::from(KeyCode::Up);
+        assert_eq!(press.kind, KeyEventKind::Press);
+        assert!(!press.is_release());
+        assert!(!press.kind(KeyEventKind::Repeat).is_release());
+        let release = press.kind(KeyEventKind::Release);
+        assert!(release.is_release());
+        assert_ne!(release, press);
+    }
+
+    #[test]
+    fn test_mouse_wheel_modifiers() {
+        let event = MouseEvent::new(MouseEventKind::ScrollUp, 1, 2).modifiers(KeyModifiers::CTRL);
+        assert!(event.kind.is_scroll());
+        assert!(event.modifiers.ctrl);
+        assert!(!event.is_click_at(1, 2));
+        assert!(!MouseEventKind::Moved.is_scroll());
+    }
 }
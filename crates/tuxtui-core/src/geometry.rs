@@ -29,7 +29,11 @@ impl Position {
     pub fn distance_to(self, other: Self) -> f64 {
         let dx = (other.x as f64) - (self.x as f64);
         let dy = (other.y as f64) - (self.y as f64);
-        (dx * dx + dy * dy).sqrt()
+        let squared = dx * dx + dy * dy;
+        #[cfg(feature = "std")]
+        return squared.sqrt();
+        #[cfg(not(feature = "std"))]
+        return libm::sqrt(squared);
     }
 }
 
@@ -332,10 +336,11 @@ impl Margin {
 }
 
 /// Alignment along an axis.
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub enum Alignment {
     /// Align to the start (left/top)
+    #[default]
     Start,
     /// Center alignment
     Center,
@@ -343,12 +348,6 @@ pub enum Alignment {
     End,
 }
 
-impl Default for Alignment {
-    fn default() -> Self {
-        Self::Start
-    }
-}
-
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -0,0 +1,245 @@
+//! Key bindings parsed from strings such as `"ctrl+s"`.
+
+use crate::event::{KeyCode, KeyEvent, KeyModifiers};
+use alloc::string::String;
+use alloc::vec::Vec;
+use core::fmt;
+use core::str::FromStr;
+
+/// Error type for key binding parsing.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseKeyError {
+    input: String,
+}
+
+impl fmt::Display for ParseKeyError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "invalid key binding: '{}'", self.input)
+    }
+}
+
+impl core::error::Error for ParseKeyError {}
+
+/// A key with the modifiers that must be held with it.
+///
+/// Bindings are written as modifiers and a key joined by `+`, such as
+/// `"ctrl+s"`, `"alt+shift+left"` or `"f5"`. Modifiers are `ctrl`, `alt`,
+/// `shift` and `meta`; keys are a single character or a name such as
+/// `enter`, `esc`, `tab`, `space`, `pageup` or `f1`. Names are not case
+/// sensitive.
+///
+/// A character already says whether shift was held, so `"shift+a"` is the
+/// same as `"A"` and shift is ignored when matching characters.
+///
+/// # Example
+///
+/// ```
+/// use tuxtui_core::event::{KeyCode, KeyEvent, KeyModifiers};
+/// use tuxtui_core::keymap::KeyBinding;
+///
+/// let save: KeyBinding = "ctrl+s".parse().unwrap();
+/// assert!(save.matches(&KeyEvent::new(KeyCode::Char('s'), KeyModifiers::CTRL)));
+/// assert!(!save.matches(&KeyEvent::from(KeyCode::Char('s'))));
+/// assert_eq!(save.to_string(), "ctrl+s");
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct KeyBinding {
+    /// The key
+    pub code: KeyCode,
+    /// The modifiers held down
+    pub modifiers: KeyModifiers,
+}
+
+impl KeyBinding {
+    /// Create a binding.
+    #[must_use]
+    pub const fn new(code: KeyCode, modifiers: KeyModifiers) -> Self {
+        Self { code, modifiers }
+    }
+
+    /// Parse a binding such as `"ctrl+s"`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if a modifier or the key is not recognized.
+    pub fn parse(s: &str) -> Result<Self, ParseKeyError> {
+        let error = || ParseKeyError { input: s.into() };
+        let mut modifiers = KeyModifiers::NONE;
+        // Split from the right, so that "ctrl++" binds the plus key.
+        let (prefix, key) = match s.strip_suffix('+') {
+            Some(rest) if rest.is_empty() || rest.ends_with('+') => (rest, "+"),
+            _ => s.rsplit_once('+').unwrap_or(("", s)),
+        };
+        for modifier in prefix.split('+').filter(|m| !m.is_empty()) {
+            let flag = match modifier.to_ascii_lowercase().as_str() {
+                "ctrl" | "control" => &mut modifiers.ctrl,
+                "alt" | "option" => &mut modifiers.alt,
+                "shift" => &mut modifiers.shift,
+                "meta" | "super" | "cmd" => &mut modifiers.meta,
+                _ => return Err(error()),
+            };
+            *flag = true;
+        }
+
+        let mut chars = key.chars();
+        let code = match (chars.next(), chars.next()) {
+            (Some(c), None) if modifiers.shift => {
+                modifiers.shift = false;
+                KeyCode::Char(c.to_ascii_uppercase())
+            }
+            (Some(c), None) => KeyCode::Char(c),
+            _ => match key.to_ascii_lowercase().as_str() {
+                "enter" | "return" => KeyCode::Enter,
+                "backspace" => KeyCode::Backspace,
+                "tab" => KeyCode::Tab,
+                "backtab" => KeyCode::BackTab,
+                "esc" | "escape" => KeyCode::Esc,
+                "space" => KeyCode::Char(' '),
+                "left" => KeyCode::Left,
+                "right" => KeyCode::Right,
+                "up" => KeyCode::Up,
+                "down" => KeyCode::Down,
+                "home" => KeyCode::Home,
+                "end" => KeyCode::End,
+                "pageup" => KeyCode::PageUp,
+                "pagedown" => KeyCode::PageDown,
+                "delete" | "del" => KeyCode::Delete,
+                "insert" | "ins" => KeyCode::Insert,
+                name => name
+                    .strip_prefix('f')
+                    .and_then(|n| n.parse().ok())
+                    .filter(|n| (1..=24).contains(n))
+                    .map(KeyCode::F)
+                    .ok_or_else(error)?,
+            },
+        };
+        Ok(Self { code, modifiers })
+    }
+
+    /// Check if a key event triggers this binding.
+    ///
+    /// Presses and repeats trigger bindings, releases never do.
+    #[must_use]
+    pub fn matches(&self, event: &KeyEvent) -> bool {
+        if event.is_release() {
+            return false;
+        }
+        let (a, b) = (self.modifiers, event.modifiers);
+        let shift = matches!(self.code, KeyCode::Char(_)) || a.shift == b.shift;
+        self.code == event.code && a.ctrl == b.ctrl && a.alt == b.alt && a.meta == b.meta && shift
+    }
+}
+
+impl FromStr for KeyBinding {
+    type Err = ParseKeyError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::parse(s)
+    }
+}
+
+impl From<KeyCode> for KeyBinding {
+    fn from(code: KeyCode) -> Self {
+        Self::new(code, KeyModifiers::NONE)
+    }
+}
+
+impl fmt::Display for KeyBinding {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let modifiers = [
+            (self.modifiers.ctrl, "ctrl"),
+            (self.modifiers.alt, "alt"),
+            (self.modifiers.shift, "shift"),
+            (self.modifiers.meta, "meta"),
+        ];
+        for (_, name) in modifiers.iter().filter(|(held, _)| *held) {
+            write!(f, "{name}+")?;
+        }
+        match self.code {
+            KeyCode::Char(' ') => f.write_str("space"),
+            KeyCode::Char(c) => write!(f, "{c}"),
+            KeyCode::Enter => f.write_str("enter"),
+            KeyCode::Backspace => f.write_str("backspace"),
+            KeyCode::Tab => f.write_str("tab"),
+            KeyCode::BackTab => f.write_str("backtab"),
+            KeyCode::Esc => f.write_str("esc"),
+            KeyCode::Left => f.write_str("left"),
+            KeyCode::Right => f.write_str("right"),
+            KeyCode::Up => f.write_str("up"),
+            KeyCode::Down => f.write_str("down"),
+            KeyCode::Home => f.write_str("home"),
+            KeyCode::End => f.write_str("end"),
+            KeyCode::PageUp => f.write_str("pageup"),
+            KeyCode::PageDown => f.write_str("pagedown"),
+            KeyCode::Delete => f.write_str("delete"),
+            KeyCode::Insert => f.write_str("insert"),
+            KeyCode::F(n) => write!(f, "f{n}"),
+        }
+    }
+}
+
+/// Actions that can be looked up by the key bound to them.
+///
+/// Usually derived with `#[derive(KeyBindings)]` from `tuxtui-macros`,
+/// which binds each variant of an enum to the keys in its `#[key("...")]`
+/// attributes.
+pub trait KeyBindings: Sized {
+    /// Get every binding with the action it triggers.
+    fn key_bindings() -> Vec<(KeyBinding, Self)>;
+
+    /// Get the action bound to a key event.
+    fn from_key_event(event: &KeyEvent) -> Option<Self> {
+        Self::key_bindings()
+            .into_iter()
+            .find(|(binding, _)| binding.matches(event))
+            .map(|(_, action)| action)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::event::KeyEventKind;
+    use alloc::string::ToString;
+
+    #[test]
+    fn test_parse_round_trip() {
+        for s in [
+            "ctrl+s",
+            "alt+shift+left",
+            "f5",
+            "space",
+            "ctrl++",
+            "+",
+            "A",
+        ] {
+            assert_eq!(KeyBinding::parse(s).unwrap().to_string(), s);
+        }
+        assert_eq!(
+            KeyBinding::parse("Shift+a").unwrap(),
+            KeyBinding::from(KeyCode::Char('A'))
+        );
+        assert_eq!(
+            KeyBinding::parse("Ctrl+PageDown").unwrap(),
+            KeyBinding::new(KeyCode::PageDown, KeyModifiers::CTRL)
+        );
+        for s in ["", "ctrl+", "hyper+s", "f0", "f25", "enterr"] {
+            assert!(KeyBinding::parse(s).is_err(), "{s}");
+        }
+    }
+
+    #[test]
+    fn test_matches() {
+        let binding = KeyBinding::parse("A").unwrap();
+        assert!(binding.matches(&KeyEvent::new(KeyCode::Char('A'), KeyModifiers::SHIFT)));
+        assert!(binding.matches(&KeyEvent::from(KeyCode::Char('A'))));
+
+        let binding = KeyBinding::parse("shift+up").unwrap();
+        assert!(binding.matches(&KeyEvent::new(KeyCode::Up, KeyModifiers::SHIFT)));
+        assert!(!binding.matches(&KeyEvent::from(KeyCode::Up)));
+
+        let held = KeyEvent::new(KeyCode::Up, KeyModifiers::SHIFT);
+        assert!(binding.matches(&held.kind(KeyEventKind::Repeat)));
+        assert!(!binding.matches(&held.kind(KeyEventKind::Release)));
+    }
+}
@@ -52,11 +52,119 @@ impl Constraint {
     }
 }
 
+/// Build an array of [`Constraint`]s.
+///
+/// Constraints are separated by `,` or `;` and written as:
+///
+/// - `==n` or a bare number for [`Constraint::Length`]
+/// - `>=n` for [`Constraint::Min`] and `<=n` for [`Constraint::Max`]
+/// - `*n` for [`Constraint::Fill`], or `fill` for a weight of 1
+/// - `n%` for [`Constraint::Percentage`]
+/// - `a/b` for [`Constraint::Ratio`]
+/// - any other expression evaluating to a [`Constraint`]
+///
+/// # Example
+///
+/// ```
+/// use tuxtui_core::constraints;
+/// use tuxtui_core::layout::Constraint;
+///
+/// let height = 3;
+/// assert_eq!(
+///     constraints![==10, >=height, *2, 25%, 1/3, fill, Constraint::Max(4)],
+///     [
+///         Constraint::Length(10),
+///         Constraint::Min(3),
+///         Constraint::Fill(2),
+///         Constraint::Percentage(25),
+///         Constraint::Ratio(1, 3),
+///         Constraint::Fill(1),
+///         Constraint::Max(4),
+///     ]
+/// );
+/// ```
+#[macro_export]
+macro_rules! constraints {
+    (@split [$($done:expr,)*] []) => {
+        [$($done),*]
+    };
+    (@split [$($done:expr,)*] [$($item:tt)+]) => {
+        [$($done,)* $crate::__constraint!($($item)+)]
+    };
+    (@split [$($done:expr,)*] [$($item:tt)*] , $($rest:tt)*) => {
+        $crate::constraints!(@split [$($done,)* $crate::__constraint!($($item)*),] [] $($rest)*)
+    };
+    (@split [$($done:expr,)*] [$($item:tt)*] ; $($rest:tt)*) => {
+        $crate::constraints!(@split [$($done,)* $crate::__constraint!($($item)*),] [] $($rest)*)
+    };
+    (@split [$($done:expr,)*] [$($item:tt)*] $next:tt $($rest:tt)*) => {
+        $crate::constraints!(@split [$($done,)*] [$($item)* $next] $($rest)*)
+    };
+    ($($tokens:tt)*) => {
+        $crate::constraints!(@split [] [] $($tokens)*)
+    };
+}
+
+/// Build a single constraint for [`constraints!`].
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __constraint {
+    (== $length:expr) => {
+        $crate::layout::Constraint::Length($length)
+    };
+    (>= $min:expr) => {
+        $crate::layout::Constraint::Min($min)
+    };
+    (<= $max:expr) => {
+        $crate::layout::Constraint::Max($max)
+    };
+    (* $weight:expr) => {
+        $crate::layout::Constraint::Fill($weight)
+    };
+    (fill) => {
+        $crate::layout::Constraint::Fill(1)
+    };
+    ($percent:literal %) => {
+        $crate::layout::Constraint::Percentage($percent)
+    };
+    ($num:literal / $den:literal) => {
+        $crate::layout::Constraint::Ratio($num, $den)
+    };
+    ($length:literal) => {
+        $crate::layout::Constraint::Length($length)
+    };
+    ($constraint:expr) => {
+        $constraint
+    };
+}
+
+/// Build a [`Layout`] from a direction and [`constraints!`].
+///
+/// # Example
+///
+/// ```
+/// use tuxtui_core::geometry::Rect;
+/// use tuxtui_core::layout;
+///
+/// let rects = layout!(vertical: [3; fill; 5]).split(Rect::new(0, 0, 10, 20));
+/// assert_eq!(rects[1], Rect::new(0, 3, 10, 12));
+/// ```
+#[macro_export]
+macro_rules! layout {
+    (vertical: [$($constraints:tt)*] $(,)?) => {
+        $crate::layout::Layout::vertical($crate::constraints![$($constraints)*])
+    };
+    (horizontal: [$($constraints:tt)*] $(,)?) => {
+        $crate::layout::Layout::horizontal($crate::constraints![$($constraints)*])
+    };
+}
+
 /// Flex layout modes for distributing space.
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub enum Flex {
     /// Place items at the start
+    #[default]
     Start,
     /// Center items
     Center,
@@ -68,12 +176,6 @@ pub enum Flex {
     SpaceAround,
 }
 
-impl Default for Flex {
-    fn default() -> Self {
-        Self::Start
-    }
-}
-
 /// Spacing between layout elements.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
@@ -318,7 +420,7 @@ impl Layout {
             .saturating_sub(spacing_total);
 
         // Second pass: distribute remaining space to Fill constraints
-        if fill_weights > 0 {
+        if let Some(fill_weights) = core::num::NonZeroU32::new(fill_weights) {
             for (i, constraint) in self.constraints.iter().enumerate() {
                 if let Constraint::Fill(weight) = constraint {
                     let fill_size =
@@ -420,4 +522,17 @@ mod tests {
         assert_eq!(rects[0].width, 50);
         assert_eq!(rects[1].width, 50);
     }
+
+    #[test]
+    fn test_layout_macros() {
+        let empty: [Constraint; 0] = constraints![];
+        assert!(empty.is_empty());
+        assert_eq!(
+            constraints![<=2 + 3; 50%,],
+            [Constraint::Max(5), Constraint::Percentage(50)]
+        );
+
+        let rects = layout!(horizontal: [==4, *1, *3]).split(Rect::new(0, 0, 12, 1));
+        assert_eq!(rects.iter().map(|r| r.width).collect::<Vec<_>>(), [4, 2, 6]);
+    }
 }
@@ -9,17 +9,25 @@
 //! - **Layout**: Flexible constraint-based layout engine with caching
 //! - **Backend**: Platform-agnostic terminal abstraction trait
 //! - **Theme**: Themable UI components with serialization support
+//! - **Keymap**: Key bindings parsed from strings such as `ctrl+s`
+//! - **Router**: Stack of screens with push, pop and replace transitions
+//! - **App**: Elm-style model, update and view loop with commands and timers
 //!
 //! ## Features
 //!
-//! - `std` (default): Enable standard library support
+//! - `std` (default): Enable standard library support. Without it the crate
+//!   is `no_std` and only needs `alloc`; terminal capability detection and
+//!   the `app` module are unavailable
 //! - `layout-cache`: Enable LRU caching for layout calculations
 //! - `serde`: Enable serialization/deserialization
 //! - `palette`: Enable advanced color manipulation with HSL/HSLuv
 //! - `portable-atomic`: Use portable atomics for no-std compatibility
 //! - `anstyle`: Enable anstyle conversions
+//! - `ratatui-compat`: Convert to and from ratatui types and render ratatui
+//!   widgets, in [`compat`]
 //! - `underline-color`: Enable colored underlines
 //! - `scrolling-regions`: Enable terminal scrolling region support
+//! - `png`: Rasterize buffers to PNG images with `Buffer::to_png`
 //!
 //! ## Example
 //!
@@ -38,12 +46,20 @@
 
 extern crate alloc;
 
+#[cfg(feature = "std")]
+pub mod app;
 pub mod backend;
 pub mod buffer;
+#[cfg(feature = "ratatui-compat")]
+pub mod compat;
 pub mod event;
 pub mod geometry;
+pub mod keymap;
 pub mod layout;
+pub mod narration;
 pub mod prelude;
+pub mod router;
+pub mod settings;
 pub mod style;
 pub mod symbols;
 pub mod terminal;
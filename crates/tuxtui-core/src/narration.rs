@@ -0,0 +1,311 @@
+//! Narration of focus and selection changes, for screen readers.
+//!
+//! While drawing, widgets describe what is focused or selected with
+//! [`Frame::describe`](crate::terminal::Frame::describe). After each frame
+//! the [`Narrator`] of the terminal announces the descriptions that changed
+//! to a [`NarrationSink`], such as speech-dispatcher or a log, so that
+//! moving through a list reads out the new item.
+
+use alloc::boxed::Box;
+use alloc::string::String;
+use alloc::vec::Vec;
+use core::fmt;
+
+/// How urgently an announcement is spoken.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Politeness {
+    /// Spoken after whatever is being spoken
+    #[default]
+    Polite,
+    /// Spoken right away, interrupting whatever is being spoken
+    Assertive,
+}
+
+/// A message for the user.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Announcement {
+    /// The text to speak
+    pub message: String,
+    /// How urgently to speak it
+    pub politeness: Politeness,
+}
+
+impl Announcement {
+    /// Create a polite announcement.
+    #[must_use]
+    pub fn new(message: impl Into<String>) -> Self {
+        Self {
+            message: message.into(),
+            politeness: Politeness::Polite,
+        }
+    }
+
+    /// Create an assertive announcement.
+    #[must_use]
+    pub fn assertive(message: impl Into<String>) -> Self {
+        Self {
+            message: message.into(),
+            politeness: Politeness::Assertive,
+        }
+    }
+}
+
+/// Where announcements go, such as a speech synthesizer or a log.
+///
+/// Closures taking an [`Announcement`] are sinks.
+pub trait NarrationSink {
+    /// Speak or record an announcement.
+    fn announce(&mut self, announcement: &Announcement);
+}
+
+impl<F: FnMut(&Announcement)> NarrationSink for F {
+    fn announce(&mut self, announcement: &Announcement) {
+        self(announcement);
+    }
+}
+
+/// A widget or state that can describe its focus or selection, such as
+/// "Name, text field, Ada".
+pub trait Describe {
+    /// Describe the focused or selected part, if any.
+    fn describe(&self) -> Option<String>;
+}
+
+/// Collects the descriptions and announcements of each frame, and passes
+/// on those that are new to its sink.
+///
+/// A description is announced when its ID was not described in the previous
+/// frame or its text changed, so only changes are spoken however often the
+/// screen is drawn.
+///
+/// # Example
+///
+/// ```
+/// use std::sync::{Arc, Mutex};
+/// use tuxtui_core::narration::{Announcement, Narrator};
+///
+/// let spoken = Arc::new(Mutex::new(Vec::new()));
+/// let log = Arc::clone(&spoken);
+/// let mut narrator = Narrator::new(move |announcement: &Announcement| {
+///     log.lock().unwrap().push(announcement.message.clone());
+/// });
+///
+/// narrator.describe("files", "readme.md, 1 of 3");
+/// narrator.finish_frame();
+/// narrator.describe("files", "readme.md, 1 of 3");
+/// narrator.finish_frame();
+/// narrator.describe("files", "main.rs, 2 of 3");
+/// narrator.finish_frame();
+/// assert_eq!(*spoken.lock().unwrap(), ["readme.md, 1 of 3", "main.rs, 2 of 3"]);
+/// ```
+pub struct Narrator {
+    sink: Box<dyn NarrationSink>,
+    previous: Vec<(String, String)>,
+    current: Vec<(String, String)>,
+    pending: Vec<Announcement>,
+}
+
+impl fmt::Debug for Narrator {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Narrator")
+            .field("previous", &self.previous)
+            .field("current", &self.current)
+            .field("pending", &self.pending)
+            .finish_non_exhaustive()
+    }
+}
+
+impl Narrator {
+    /// Create a narrator announcing to `sink`.
+    #[must_use]
+    pub fn new(sink: impl NarrationSink + 'static) -> Self {
+        Self {
+            sink: Box::new(sink),
+            previous: Vec::new(),
+            current: Vec::new(),
+            pending: Vec::new(),
+        }
+    }
+
+    /// Describe the focus or selection of the part identified by `id` in
+    /// the current frame, replacing any earlier description of it.
+    pub fn describe(&mut self, id: &str, description: impl Into<String>) {
+        let description = description.into();
+        match self.current.iter_mut().find(|(current, _)| current == id) {
+            Some((_, text)) => *text = description,
+            None => self.current.push((String::from(id), description)),
+        }
+    }
+
+    /// Queue an announcement, such as "File saved", to be made at the end
+    /// of the current frame.
+    pub fn announce(&mut self, announcement: Announcement) {
+        self.pending.push(announcement);
+    }
+
+    /// Announce the descriptions that changed since the previous frame,
+    /// then the queued announcements, and start the next frame.
+    pub fn finish_frame(&mut self) {
+        for (id, description) in &self.current {
+            let unchanged = self
+                .previous
+                .iter()
+                .any(|(previous, text)| previous == id && text == description);
+            if !unchanged {
+                self.sink.announce(&Announcement::new(description.clone()));
+            }
+        }
+        for announcement in self.pending.drain(..) {
+            self.sink.announce(&announcement);
+        }
+        self.previous = core::mem::take(&mut self.current);
+    }
+}
+
+/// A sink writing each announcement as a line, such as to a log file.
+#[cfg(feature = "std")]
+#[derive(Debug)]
+pub struct WriteSink<W: std::io::Write> {
+    writer: W,
+}
+
+#[cfg(feature = "std")]
+impl<W: std::io::Write> WriteSink<W> {
+    /// Create a sink writing to `writer`.
+    #[must_use]
+    pub const fn new(writer: W) -> Self {
+        Self { writer }
+    }
+
+    /// Get the writer back.
+    pub fn into_inner(self) -> W {
+        self.writer
+    }
+}
+
+#[cfg(feature = "std")]
+impl<W: std::io::Write> NarrationSink for WriteSink<W> {
+    fn announce(&mut self, announcement: &Announcement) {
+        // Narration must never break the application, so errors are dropped.
+        let _ = writeln!(self.writer, "{}", announcement.message);
+        let _ = self.writer.flush();
+    }
+}
+
+/// A sink running a program with each announcement as its last argument,
+/// such as speech-dispatcher's `spd-say`.
+///
+/// The program runs in the background. Assertive announcements add the
+/// [interrupt arguments](Self::interrupt_args) first, such as `--cancel`
+/// to stop what is being spoken.
+///
+/// # Example
+///
+/// ```no_run
+/// use tuxtui_core::narration::{CommandSink, Narrator};
+///
+/// let narrator = Narrator::new(CommandSink::new("spd-say").interrupt_args(["--cancel"]));
+/// ```
+#[cfg(feature = "std")]
+#[derive(Debug)]
+pub struct CommandSink {
+    program: String,
+    args: Vec<String>,
+    interrupt_args: Vec<String>,
+    children: Vec<std::process::Child>,
+}
+
+#[cfg(feature = "std")]
+impl CommandSink {
+    /// Create a sink running `program`.
+    #[must_use]
+    pub fn new(program: impl Into<String>) -> Self {
+        Self {
+            program: program.into(),
+            args: Vec::new(),
+            interrupt_args: Vec::new(),
+            children: Vec::new(),
+        }
+    }
+
+    /// Set the arguments passed before the announcement.
+    #[must_use]
+    pub fn args<S: Into<String>>(mut self, args: impl IntoIterator<Item = S>) -> Self {
+        self.args = args.into_iter().map(Into::into).collect();
+        self
+    }
+
+    /// Set the arguments added for assertive announcements.
+    #[must_use]
+    pub fn interrupt_args<S: Into<String>>(mut self, args: impl IntoIterator<Item = S>) -> Self {
+        self.interrupt_args = args.into_iter().map(Into::into).collect();
+        self
+    }
+}
+
+#[cfg(feature = "std")]
+impl NarrationSink for CommandSink {
+    fn announce(&mut self, announcement: &Announcement) {
+        // Reap the programs that finished.
+        self.children
+            .retain_mut(|child| matches!(child.try_wait(), Ok(None)));
+
+        let mut command = std::process::Command::new(&self.program);
+        command.args(&self.args);
+        if announcement.politeness == Politeness::Assertive {
+            command.args(&self.interrupt_args);
+        }
+        command
+            .arg(&announcement.message)
+            .stdin(std::process::Stdio::null())
+            .stdout(std::process::Stdio::null())
+            .stderr(std::process::Stdio::null());
+        if let Ok(child) = command.spawn() {
+            self.children.push(child);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloc::rc::Rc;
+    use core::cell::RefCell;
+
+    #[test]
+    fn test_announces_changes_only() {
+        let spoken = Rc::new(RefCell::new(Vec::new()));
+        let log = Rc::clone(&spoken);
+        let mut narrator = Narrator::new(move |announcement: &Announcement| {
+            log.borrow_mut().push(announcement.clone());
+        });
+
+        narrator.describe("list", "one");
+        narrator.describe("tabs", "Home");
+        narrator.finish_frame();
+        narrator.describe("list", "two");
+        narrator.describe("list", "three");
+        narrator.describe("tabs", "Home");
+        narrator.announce(Announcement::assertive("saved"));
+        narrator.finish_frame();
+        // Hidden, then shown again.
+        narrator.finish_frame();
+        narrator.describe("tabs", "Home");
+        narrator.finish_frame();
+
+        let spoken = spoken.borrow();
+        let messages: Vec<_> = spoken.iter().map(|a| a.message.as_str()).collect();
+        assert_eq!(messages, ["one", "Home", "three", "saved", "Home"]);
+        assert_eq!(spoken[3].politeness, Politeness::Assertive);
+        assert_eq!(spoken[2].politeness, Politeness::Polite);
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn test_write_sink() {
+        let mut sink = WriteSink::new(Vec::new());
+        sink.announce(&Announcement::new("Name, text field"));
+        sink.announce(&Announcement::new("OK button"));
+        assert_eq!(sink.into_inner(), b"Name, text field\nOK button\n");
+    }
+}
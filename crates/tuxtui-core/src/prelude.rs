@@ -12,13 +12,16 @@
 //! let style = Style::default().fg(Color::Blue);
 //! ```
 
-pub use crate::backend::{Backend, TestBackend};
-pub use crate::buffer::{Buffer, Cell};
-pub use crate::event::{KeyModifiers, MouseButton, MouseEvent, MouseEventKind};
+pub use crate::backend::{Backend, Capabilities, TestBackend, TestHarness};
+pub use crate::buffer::{Buffer, BufferView, Cell};
+pub use crate::event::{
+    Event, KeyCode, KeyEvent, KeyEventKind, KeyModifiers, MouseButton, MouseEvent, MouseEventKind,
+};
 pub use crate::geometry::{Alignment, Margin, Position, Rect};
 pub use crate::layout::{Constraint, Direction, Flex, Layout, Spacing};
+pub use crate::settings::UiSettings;
 pub use crate::style::{Color, Modifier, Style, Stylize};
 pub use crate::symbols;
-pub use crate::terminal::{Frame, Terminal, Widget};
-pub use crate::text::{Line, Span, Text};
+pub use crate::terminal::{Frame, RenderStats, Terminal, Widget, WidgetRef};
+pub use crate::text::{Line, Span, Text, TextDirection, Truncate};
 pub use crate::theme::{PaletteTheme, Theme, WidgetTheme};
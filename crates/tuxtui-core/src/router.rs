@@ -0,0 +1,474 @@
+//! Navigation between full-screen views.
+
+use crate::buffer::Buffer;
+use crate::event::Event;
+use crate::geometry::Rect;
+use alloc::boxed::Box;
+use alloc::vec::Vec;
+use core::fmt;
+
+/// A full-screen view managed by a [`Router`].
+///
+/// # Example
+///
+/// ```
+/// use tuxtui_core::prelude::*;
+/// use tuxtui_core::router::{Navigation, Screen};
+///
+/// struct Home;
+///
+/// impl Screen for Home {
+///     fn render(&mut self, area: Rect, buf: &mut Buffer) {
+///         buf.set_string(area.x, area.y, "home", Style::default());
+///     }
+///
+///     fn handle_event(&mut self, event: &Event) -> Navigation {
+///         match event {
+///             Event::Key(key) if key.code == KeyCode::Esc => Navigation::Pop,
+///             _ => Navigation::Stay,
+///         }
+///     }
+/// }
+/// ```
+pub trait Screen {
+    /// Draw the screen.
+    fn render(&mut self, area: Rect, buf: &mut Buffer);
+
+    /// Handle an event, returning where to navigate next.
+    fn handle_event(&mut self, event: &Event) -> Navigation {
+        let _ = event;
+        Navigation::Stay
+    }
+
+    /// Called when the screen becomes the current one.
+    fn on_enter(&mut self) {}
+
+    /// Called when the screen stops being the current one, either because
+    /// another was pushed over it or because it was removed.
+    fn on_exit(&mut self) {}
+}
+
+/// Where to navigate after a [`Screen`] handled an event.
+#[derive(Default)]
+pub enum Navigation {
+    /// Stay on the current screen
+    #[default]
+    Stay,
+    /// Show a new screen over the current one
+    Push(Box<dyn Screen>),
+    /// Go back to the previous screen
+    Pop,
+    /// Swap the current screen for a new one
+    Replace(Box<dyn Screen>),
+}
+
+impl fmt::Debug for Navigation {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Stay => f.write_str("Stay"),
+            Self::Push(_) => f.write_str("Push(..)"),
+            Self::Pop => f.write_str("Pop"),
+            Self::Replace(_) => f.write_str("Replace(..)"),
+        }
+    }
+}
+
+/// How a [`Router`] animates between screens.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Transition {
+    /// Switch screens at once
+    #[default]
+    None,
+    /// Slide the new screen in over the given number of frames, from the
+    /// right when going forward and from the left when going back
+    Slide(u16),
+}
+
+/// Which way a transition goes.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Direction {
+    Forward,
+    Back,
+}
+
+/// A transition in progress.
+struct Animation {
+    /// A picture of the screen being left, unless it is still on the stack
+    leaving: Option<Buffer>,
+    direction: Direction,
+    frame: u16,
+    frames: u16,
+}
+
+/// A stack of screens, showing the topmost one.
+///
+/// Events go to the current screen, whose [`Navigation`] pushes, pops or
+/// replaces screens. The root screen is never popped. With a
+/// [`Transition::Slide`], each call to [`render`](Self::render) advances the
+/// animation by one frame.
+///
+/// # Example
+///
+/// ```
+/// use tuxtui_core::prelude::*;
+/// use tuxtui_core::router::{Router, Screen};
+///
+/// struct Label(&'static str);
+///
+/// impl Screen for Label {
+///     fn render(&mut self, area: Rect, buf: &mut Buffer) {
+///         buf.set_string(area.x, area.y, self.0, Style::default());
+///     }
+/// }
+///
+/// let mut router = Router::new(Label("home"));
+/// router.push(Label("settings"));
+/// assert_eq!(router.depth(), 2);
+///
+/// let mut buf = Buffer::empty(Rect::new(0, 0, 10, 1));
+/// router.render(buf.area, &mut buf);
+/// assert_eq!(buf.get(0, 0).unwrap().symbol(), "s");
+///
+/// router.pop();
+/// assert_eq!(router.depth(), 1);
+/// ```
+pub struct Router {
+    screens: Vec<Box<dyn Screen>>,
+    transition: Transition,
+    animation: Option<Animation>,
+    /// The area of the last render, for pictures of leaving screens
+    area: Rect,
+}
+
+impl fmt::Debug for Router {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Router")
+            .field("depth", &self.screens.len())
+            .field("transition", &self.transition)
+            .field("animating", &self.is_animating())
+            .finish()
+    }
+}
+
+impl Router {
+    /// Create a router showing `root`, which is entered at once.
+    #[must_use]
+    pub fn new(root: impl Screen + 'static) -> Self {
+        let mut root: Box<dyn Screen> = Box::new(root);
+        root.on_enter();
+        Self {
+            screens: alloc::vec![root],
+            transition: Transition::None,
+            animation: None,
+            area: Rect::default(),
+        }
+    }
+
+    /// Set how to animate between screens.
+    #[must_use]
+    pub const fn transition(mut self, transition: Transition) -> Self {
+        self.transition = transition;
+        self
+    }
+
+    /// Get the number of screens on the stack, including the root.
+    #[must_use]
+    pub fn depth(&self) -> usize {
+        self.screens.len()
+    }
+
+    /// Get the current screen.
+    #[must_use]
+    pub fn current(&self) -> &dyn Screen {
+        self.screens.last().map(AsRef::as_ref).expect("root screen")
+    }
+
+    /// Get the current screen mutably.
+    pub fn current_mut(&mut self) -> &mut dyn Screen {
+        self.screens
+            .last_mut()
+            .map(AsMut::as_mut)
+            .expect("root screen")
+    }
+
+    /// Check if a transition is in progress.
+    #[must_use]
+    pub const fn is_animating(&self) -> bool {
+        self.animation.is_some()
+    }
+
+    /// Show a new screen over the current one.
+    pub fn push(&mut self, screen: impl Screen + 'static) {
+        self.push_boxed(Box::new(screen));
+    }
+
+    /// Go back to the previous screen, returning the one that was left.
+    ///
+    /// Returns `None` when only the root screen is left.
+    pub fn pop(&mut self) -> Option<Box<dyn Screen>> {
+        if self.screens.len() <= 1 {
+            return None;
+        }
+        let mut screen = self.screens.pop()?;
+        let leaving = picture(self.transition, self.area, screen.as_mut());
+        screen.on_exit();
+        self.current_mut().on_enter();
+        self.animate(Direction::Back, leaving);
+        Some(screen)
+    }
+
+    /// Swap the current screen for a new one, returning the old one.
+    pub fn replace(&mut self, screen: impl Screen + 'static) -> Box<dyn Screen> {
+        self.replace_boxed(Box::new(screen))
+    }
+
+    /// Apply a [`Navigation`] returned by a screen.
+    pub fn navigate(&mut self, navigation: Navigation) {
+        match navigation {
+            Navigation::Stay => {}
+            Navigation::Push(screen) => self.push_boxed(screen),
+            Navigation::Pop => {
+                self.pop();
+            }
+            Navigation::Replace(screen) => {
+                self.replace_boxed(screen);
+            }
+        }
+    }
+
+    /// Send an event to the current screen and follow its navigation.
+    pub fn handle_event(&mut self, event: &Event) {
+        let navigation = self.current_mut().handle_event(event);
+        self.navigate(navigation);
+    }
+
+    /// Draw the current screen, or the next frame of the transition to it.
+    pub fn render(&mut self, area: Rect, buf: &mut Buffer) {
+        self.area = area;
+        let Some(animation) = &mut self.animation else {
+            self.current_mut().render(area, buf);
+            return;
+        };
+        animation.frame += 1;
+        let (frame, frames, direction) = (animation.frame, animation.frames, animation.direction);
+        if frame >= frames {
+            self.animation = None;
+            self.current_mut().render(area, buf);
+            return;
+        }
+
+        // How far the incoming screen still is from its place.
+        let distance = u32::from(area.width) * u32::from(frames - frame) / u32::from(frames);
+        let distance = i32::try_from(distance).unwrap_or(i32::MAX);
+        let width = i32::from(area.width);
+        let (incoming, outgoing) = match direction {
+            Direction::Forward => (distance, distance - width),
+            Direction::Back => (-distance, width - distance),
+        };
+
+        let mut scratch = Buffer::empty(area);
+        let len = self.screens.len();
+        let (below, top) = self.screens.split_at_mut(len - 1);
+        match self.animation.as_ref().and_then(|a| a.leaving.as_ref()) {
+            Some(picture) => blit(picture, outgoing, area, buf),
+            // A pushed screen covers the one below, which is still on the stack.
+            None => {
+                if let Some(screen) = below.last_mut() {
+                    screen.render(area, &mut scratch);
+                    blit(&scratch, outgoing, area, buf);
+                    scratch.clear();
+                }
+            }
+        }
+        top[0].render(area, &mut scratch);
+        blit(&scratch, incoming, area, buf);
+    }
+
+    fn push_boxed(&mut self, mut screen: Box<dyn Screen>) {
+        self.current_mut().on_exit();
+        screen.on_enter();
+        self.screens.push(screen);
+        self.animate(Direction::Forward, None);
+    }
+
+    fn replace_boxed(&mut self, mut screen: Box<dyn Screen>) -> Box<dyn Screen> {
+        let last = self.screens.len() - 1;
+        let leaving = picture(self.transition, self.area, self.screens[last].as_mut());
+        self.current_mut().on_exit();
+        screen.on_enter();
+        let old = core::mem::replace(&mut self.screens[last], screen);
+        self.animate(Direction::Forward, leaving);
+        old
+    }
+
+    /// Start a transition, sliding out `leaving`, a picture of the screen
+    /// removed from the stack, if any.
+    fn animate(&mut self, direction: Direction, leaving: Option<Buffer>) {
+        self.animation = match self.transition {
+            Transition::Slide(frames) if frames > 1 => Some(Animation {
+                leaving,
+                direction,
+                frame: 0,
+                frames,
+            }),
+            _ => None,
+        };
+    }
+}
+
+/// Draw `screen` as it looks before it leaves, if `transition` slides it
+/// out.
+fn picture(transition: Transition, area: Rect, screen: &mut dyn Screen) -> Option<Buffer> {
+    if !matches!(transition, Transition::Slide(frames) if frames > 1) {
+        return None;
+    }
+    let mut picture = Buffer::empty(area);
+    screen.render(area, &mut picture);
+    Some(picture)
+}
+
+/// Copy the cells of `source` into `area` of `buf`, shifted right by `dx`.
+fn blit(source: &Buffer, dx: i32, area: Rect, buf: &mut Buffer) {
+    for y in area.top()..area.bottom() {
+        for x in area.left()..area.right() {
+            let Ok(to) = u16::try_from(i32::from(x) + dx) else {
+                continue;
+            };
+            if to < area.left() || to >= area.right() {
+                continue;
+            }
+            if let (Some(cell), Some(target)) = (source.get(x, y), buf.get_mut(to, y)) {
+                *target = cell.clone();
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::event::{KeyCode, KeyEvent, KeyModifiers};
+    use crate::style::Style;
+    use alloc::rc::Rc;
+    use alloc::string::String;
+    use core::cell::RefCell;
+
+    type Log = Rc<RefCell<Vec<String>>>;
+
+    struct Page {
+        name: &'static str,
+        log: Log,
+    }
+
+    impl Screen for Page {
+        fn render(&mut self, area: Rect, buf: &mut Buffer) {
+            let fill = self.name.repeat(usize::from(area.width));
+            buf.set_string(area.x, area.y, &fill, Style::default());
+        }
+
+        fn handle_event(&mut self, event: &Event) -> Navigation {
+            let Event::Key(key) = event else {
+                return Navigation::Stay;
+            };
+            match key.code {
+                KeyCode::Enter => Navigation::Push(Box::new(Page {
+                    name: "b",
+                    log: self.log.clone(),
+                })),
+                KeyCode::Esc => Navigation::Pop,
+                _ => Navigation::Stay,
+            }
+        }
+
+        fn on_enter(&mut self) {
+            self.log
+                .borrow_mut()
+                .push(alloc::format!("enter {}", self.name));
+        }
+
+        fn on_exit(&mut self) {
+            self.log
+                .borrow_mut()
+                .push(alloc::format!("exit {}", self.name));
+        }
+    }
+
+    fn key(code: KeyCode) -> Event {
+        Event::Key(KeyEvent::new(code, KeyModifiers::NONE))
+    }
+
+    fn row(buf: &Buffer) -> String {
+        (0..buf.area.width)
+            .map(|x| buf.get(x, 0).unwrap().symbol())
+            .collect()
+    }
+
+    #[test]
+    fn test_navigation_and_lifecycle() {
+        let log = Log::default();
+        let mut router = Router::new(Page {
+            name: "a",
+            log: log.clone(),
+        });
+        router.handle_event(&key(KeyCode::Enter));
+        assert_eq!(router.depth(), 2);
+        router.handle_event(&key(KeyCode::Esc));
+        router.handle_event(&key(KeyCode::Esc));
+        assert_eq!(router.depth(), 1);
+        assert_eq!(
+            *log.borrow(),
+            ["enter a", "exit a", "enter b", "exit b", "enter a"]
+        );
+    }
+
+    #[test]
+    fn test_slide_transition() {
+        let log = Log::default();
+        let mut router = Router::new(Page {
+            name: "a",
+            log: log.clone(),
+        })
+        .transition(Transition::Slide(2));
+        let mut buf = Buffer::empty(Rect::new(0, 0, 4, 1));
+
+        router.handle_event(&key(KeyCode::Enter));
+        router.render(buf.area, &mut buf);
+        assert!(router.is_animating());
+        assert_eq!(row(&buf), "aabb");
+        router.render(buf.area, &mut buf);
+        assert!(!router.is_animating());
+        assert_eq!(row(&buf), "bbbb");
+
+        router.handle_event(&key(KeyCode::Esc));
+        router.render(buf.area, &mut buf);
+        assert_eq!(row(&buf), "aabb");
+        router.render(buf.area, &mut buf);
+        assert_eq!(row(&buf), "aaaa");
+    }
+
+    #[test]
+    fn test_slide_out_on_pop_and_replace() {
+        let log = Log::default();
+        let page = |name| Page {
+            name,
+            log: log.clone(),
+        };
+        let mut router = Router::new(page("a")).transition(Transition::Slide(2));
+        let mut buf = Buffer::empty(Rect::new(0, 0, 4, 1));
+        router.push(page("b"));
+        router.render(buf.area, &mut buf);
+        router.render(buf.area, &mut buf);
+
+        assert!(router.pop().is_some());
+        router.render(buf.area, &mut buf);
+        assert_eq!(row(&buf), "aabb");
+        router.render(buf.area, &mut buf);
+        assert_eq!(row(&buf), "aaaa");
+
+        router.replace(page("c"));
+        router.render(buf.area, &mut buf);
+        assert_eq!(row(&buf), "aacc");
+        router.render(buf.area, &mut buf);
+        assert_eq!(row(&buf), "cccc");
+    }
+}
@@ -0,0 +1,166 @@
+//! Accessibility preferences honored by all widgets.
+
+use crate::style::{Color, Modifier, Style};
+use crate::symbols::{LineStyle, ScrollbarSymbols};
+use crate::theme::Theme;
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// User preferences for how the interface is drawn, such as without
+/// animations or with ASCII only.
+///
+/// Set them on the terminal with [`TerminalOptions`] or
+/// [`Terminal::set_settings`], and they reach every widget through
+/// [`Buffer::settings`] and [`Frame::settings`]. The official widgets
+/// consult them, and so can applications, such as to skip their own
+/// animations.
+///
+/// [`TerminalOptions`]: crate::terminal::TerminalOptions
+/// [`Terminal::set_settings`]: crate::terminal::Terminal::set_settings
+/// [`Buffer::settings`]: crate::buffer::Buffer::settings
+/// [`Frame::settings`]: crate::terminal::Frame::settings
+///
+/// # Example
+///
+/// ```
+/// use tuxtui_core::settings::UiSettings;
+/// use tuxtui_core::symbols;
+/// use tuxtui_core::theme::Theme;
+///
+/// let settings = UiSettings::new().ascii_only(true).high_contrast(true);
+/// assert_eq!(settings.line_style(symbols::ROUNDED), symbols::SIMPLE);
+/// assert_eq!(settings.theme(Theme::light()).name, "high-contrast");
+/// ```
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(default))]
+pub struct UiSettings {
+    /// Leave out animations: spinners and the like stand still
+    pub reduced_motion: bool,
+    /// Use the high-contrast theme and avoid faint text
+    pub high_contrast: bool,
+    /// Draw with ASCII symbols only, for terminals and fonts without
+    /// box drawing and other Unicode symbols
+    pub ascii_only: bool,
+}
+
+impl UiSettings {
+    /// Create the default settings, with all preferences off.
+    #[must_use]
+    pub const fn new() -> Self {
+        Self {
+            reduced_motion: false,
+            high_contrast: false,
+            ascii_only: false,
+        }
+    }
+
+    /// Set whether to leave out animations.
+    #[must_use]
+    pub const fn reduced_motion(mut self, reduced_motion: bool) -> Self {
+        self.reduced_motion = reduced_motion;
+        self
+    }
+
+    /// Set whether to use high contrast.
+    #[must_use]
+    pub const fn high_contrast(mut self, high_contrast: bool) -> Self {
+        self.high_contrast = high_contrast;
+        self
+    }
+
+    /// Set whether to draw with ASCII symbols only.
+    #[must_use]
+    pub const fn ascii_only(mut self, ascii_only: bool) -> Self {
+        self.ascii_only = ascii_only;
+        self
+    }
+
+    /// Get the theme to use instead of `theme`: the high-contrast theme if
+    /// high contrast is on.
+    #[must_use]
+    pub fn theme(&self, theme: Theme) -> Theme {
+        if self.high_contrast {
+            Theme::high_contrast()
+        } else {
+            theme
+        }
+    }
+
+    /// Adjust a style for high contrast, if on: faint text is drawn
+    /// normally and gray text white.
+    #[must_use]
+    pub fn contrast(&self, style: Style) -> Style {
+        if !self.high_contrast {
+            return style;
+        }
+        let mut style = style;
+        style.add_modifier.remove(Modifier::DIM);
+        if style.fg == Some(Color::Gray) {
+            style.fg = Some(Color::White);
+        }
+        style
+    }
+
+    /// Get the border symbols to draw instead of `line_style`:
+    /// [`SIMPLE`](crate::symbols::SIMPLE) if ASCII only is on and the style
+    /// is not ASCII.
+    #[must_use]
+    pub fn line_style(&self, line_style: LineStyle) -> LineStyle {
+        if self.ascii_only && !line_style.is_ascii() {
+            LineStyle::ascii()
+        } else {
+            line_style
+        }
+    }
+
+    /// Get the scrollbar symbols to draw instead of `symbols`:
+    /// [`SCROLLBAR_ASCII`](crate::symbols::SCROLLBAR_ASCII) if ASCII only is
+    /// on and the symbols are not ASCII.
+    #[must_use]
+    pub fn scrollbar_symbols(&self, symbols: ScrollbarSymbols) -> ScrollbarSymbols {
+        if self.ascii_only && !symbols.is_ascii() {
+            ScrollbarSymbols::ascii()
+        } else {
+            symbols
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::symbols;
+
+    #[test]
+    fn test_adjustments() {
+        let style = Style::new()
+            .fg(Color::Gray)
+            .add_modifier(Modifier::DIM | Modifier::BOLD);
+        assert_eq!(UiSettings::new().contrast(style), style);
+        let high = UiSettings::new().high_contrast(true);
+        assert_eq!(
+            high.contrast(style),
+            Style::new().fg(Color::White).add_modifier(Modifier::BOLD)
+        );
+        assert_eq!(high.theme(Theme::dark()), Theme::high_contrast());
+        assert_eq!(UiSettings::new().theme(Theme::light()), Theme::light());
+
+        let ascii = UiSettings::new().ascii_only(true);
+        assert_eq!(ascii.line_style(symbols::DOUBLE), symbols::SIMPLE);
+        let custom = LineStyle {
+            horizontal: "=",
+            ..symbols::SIMPLE
+        };
+        assert_eq!(ascii.line_style(custom), custom);
+        assert_eq!(
+            UiSettings::new().line_style(symbols::DOUBLE),
+            symbols::DOUBLE
+        );
+        assert_eq!(
+            ascii.scrollbar_symbols(symbols::SCROLLBAR_BLOCK),
+            symbols::SCROLLBAR_ASCII
+        );
+    }
+}
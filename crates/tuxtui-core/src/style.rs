@@ -3,6 +3,9 @@
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
 
+#[cfg(feature = "anstyle")]
+mod anstyle;
+
 /// Error type for color parsing.
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct ParseColorError {
@@ -15,14 +18,17 @@ impl core::fmt::Display for ParseColorError {
     }
 }
 
-#[cfg(feature = "std")]
-impl std::error::Error for ParseColorError {}
+impl core::error::Error for ParseColorError {}
 
 /// Terminal colors supporting indexed, RGB, and named colors.
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
-#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+///
+/// Colors are displayed, and serialized, as strings that [`Color::parse`]
+/// reads back: a name such as `"light_blue"`, a hex code such as
+/// `"#ff8800"` for RGB, or a number for indexed colors.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
 pub enum Color {
     /// Reset to default terminal color
+    #[default]
     Reset,
     /// Black (0)
     Black,
@@ -62,6 +68,26 @@ pub enum Color {
     Rgb(u8, u8, u8),
 }
 
+/// The xterm default palette for the 16 named colors.
+const PALETTE: [(u8, u8, u8); 16] = [
+    (0, 0, 0),
+    (205, 0, 0),
+    (0, 205, 0),
+    (205, 205, 0),
+    (0, 0, 238),
+    (205, 0, 205),
+    (0, 205, 205),
+    (229, 229, 229),
+    (127, 127, 127),
+    (255, 0, 0),
+    (0, 255, 0),
+    (255, 255, 0),
+    (92, 92, 255),
+    (255, 0, 255),
+    (0, 255, 255),
+    (255, 255, 255),
+];
+
 impl Color {
     /// Create an RGB color.
     ///
@@ -85,6 +111,120 @@ impl Color {
         Self::Indexed(index)
     }
 
+    /// Resolve the color to RGB, or `None` for [`Color::Reset`].
+    ///
+    /// Named and low indexed colors use the xterm default palette.
+    #[must_use]
+    pub const fn to_rgb(self) -> Option<(u8, u8, u8)> {
+        const CUBE: [u8; 6] = [0, 95, 135, 175, 215, 255];
+        match self {
+            Self::Reset => None,
+            Self::Rgb(r, g, b) => Some((r, g, b)),
+            Self::Indexed(index @ 0..=15) => Some(PALETTE[index as usize]),
+            Self::Indexed(index @ 16..=231) => {
+                let index = index - 16;
+                Some((
+                    CUBE[(index / 36) as usize],
+                    CUBE[(index / 6 % 6) as usize],
+                    CUBE[(index % 6) as usize],
+                ))
+            }
+            Self::Indexed(index) => {
+                let level = 8 + (index - 232) * 10;
+                Some((level, level, level))
+            }
+            named => match named.named_index() {
+                Some(index) => Some(PALETTE[index as usize]),
+                None => None,
+            },
+        }
+    }
+
+    /// Get the WCAG relative luminance, from 0 for black to 1 for white.
+    ///
+    /// Returns `None` for [`Color::Reset`], whose actual color is unknown.
+    #[must_use]
+    pub fn luminance(self) -> Option<f64> {
+        let linear = |c: u8| {
+            let c = f64::from(c) / 255.0;
+            if c <= 0.040_45 {
+                c / 12.92
+            } else {
+                libm::pow((c + 0.055) / 1.055, 2.4)
+            }
+        };
+        let (r, g, b) = self.to_rgb()?;
+        Some(0.2126 * linear(r) + 0.7152 * linear(g) + 0.0722 * linear(b))
+    }
+
+    /// Get the WCAG contrast ratio with another color, from 1 to 21.
+    ///
+    /// A ratio of at least 4.5 is readable as normal text, and 3 as large
+    /// or bold text. Returns `None` if either color is [`Color::Reset`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use tuxtui_core::style::Color;
+    ///
+    /// let ratio = Color::Rgb(0, 0, 0).contrast_ratio(Color::Rgb(255, 255, 255));
+    /// assert_eq!(ratio, Some(21.0));
+    /// ```
+    #[must_use]
+    pub fn contrast_ratio(self, other: Self) -> Option<f64> {
+        let (a, b) = (self.luminance()?, other.luminance()?);
+        Some((a.max(b) + 0.05) / (a.min(b) + 0.05))
+    }
+
+    /// Get black or white, whichever is more readable on `background`.
+    ///
+    /// Returns [`Color::Reset`] for a [`Color::Reset`] background, since the
+    /// terminal's default colors are chosen to go together.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use tuxtui_core::style::Color;
+    ///
+    /// assert_eq!(Color::best_text_on(Color::Yellow), Color::Black);
+    /// assert_eq!(Color::best_text_on(Color::Rgb(0, 0, 128)), Color::White);
+    /// ```
+    #[must_use]
+    pub fn best_text_on(background: Self) -> Self {
+        let Some(on_black) = Self::Black.contrast_ratio(background) else {
+            return Self::Reset;
+        };
+        let on_white = Self::White.contrast_ratio(background).unwrap_or_default();
+        if on_black >= on_white {
+            Self::Black
+        } else {
+            Self::White
+        }
+    }
+
+    /// Get the palette index of a named color.
+    pub(crate) const fn named_index(self) -> Option<u8> {
+        Some(match self {
+            Self::Black => 0,
+            Self::Red => 1,
+            Self::Green => 2,
+            Self::Yellow => 3,
+            Self::Blue => 4,
+            Self::Magenta => 5,
+            Self::Cyan => 6,
+            Self::White => 7,
+            Self::Gray => 8,
+            Self::LightRed => 9,
+            Self::LightGreen => 10,
+            Self::LightYellow => 11,
+            Self::LightBlue => 12,
+            Self::LightMagenta => 13,
+            Self::LightCyan => 14,
+            Self::LightGray => 15,
+            Self::Reset | Self::Indexed(_) | Self::Rgb(..) => return None,
+        })
+    }
+
     /// Parse a color from a string.
     ///
     /// Supports:
@@ -103,7 +243,7 @@ impl Color {
     /// let rgb = Color::parse("rgb(255, 0, 0)").unwrap();
     /// ```
     pub fn parse(s: &str) -> Result<Self, ParseColorError> {
-        let s = s.trim().to_lowercase();
+        let s = s.trim().to_ascii_lowercase();
 
         // Named colors
         match s.as_str() {
@@ -178,12 +318,6 @@ impl Color {
     }
 }
 
-impl Default for Color {
-    fn default() -> Self {
-        Self::Reset
-    }
-}
-
 impl core::str::FromStr for Color {
     type Err = ParseColorError;
 
@@ -192,6 +326,73 @@ impl core::str::FromStr for Color {
     }
 }
 
+impl core::fmt::Display for Color {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        let name = match self {
+            Self::Reset => "reset",
+            Self::Black => "black",
+            Self::Red => "red",
+            Self::Green => "green",
+            Self::Yellow => "yellow",
+            Self::Blue => "blue",
+            Self::Magenta => "magenta",
+            Self::Cyan => "cyan",
+            Self::White => "white",
+            Self::Gray => "gray",
+            Self::LightRed => "light_red",
+            Self::LightGreen => "light_green",
+            Self::LightYellow => "light_yellow",
+            Self::LightBlue => "light_blue",
+            Self::LightMagenta => "light_magenta",
+            Self::LightCyan => "light_cyan",
+            Self::LightGray => "light_gray",
+            Self::Indexed(index) => return write!(f, "{index}"),
+            Self::Rgb(r, g, b) => return write!(f, "#{r:02x}{g:02x}{b:02x}"),
+        };
+        f.write_str(name)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl Serialize for Color {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_str(self)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> Deserialize<'de> for Color {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        struct ColorVisitor;
+
+        impl serde::de::Visitor<'_> for ColorVisitor {
+            type Value = Color;
+
+            fn expecting(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+                f.write_str("a color name, \"#rrggbb\", \"rgb(r, g, b)\" or an index")
+            }
+
+            fn visit_str<E: serde::de::Error>(self, value: &str) -> Result<Color, E> {
+                Color::parse(value).map_err(E::custom)
+            }
+
+            fn visit_u64<E: serde::de::Error>(self, value: u64) -> Result<Color, E> {
+                u8::try_from(value)
+                    .map(Color::Indexed)
+                    .map_err(|_| E::invalid_value(serde::de::Unexpected::Unsigned(value), &self))
+            }
+
+            fn visit_i64<E: serde::de::Error>(self, value: i64) -> Result<Color, E> {
+                u8::try_from(value)
+                    .map(Color::Indexed)
+                    .map_err(|_| E::invalid_value(serde::de::Unexpected::Signed(value), &self))
+            }
+        }
+
+        deserializer.deserialize_any(ColorVisitor)
+    }
+}
+
 bitflags::bitflags! {
     /// Text style modifiers (bold, italic, underline, etc.).
     ///
@@ -249,19 +450,30 @@ impl Default for Modifier {
 ///     .bg(Color::Black)
 ///     .add_modifier(Modifier::BOLD);
 /// ```
+///
+/// Styles serialize as a map of only the fields that are set, such as
+/// `{ fg = "yellow", add_modifier = "BOLD | ITALIC" }` in TOML.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
-#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize), serde(default))]
 pub struct Style {
     /// Foreground color
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
     pub fg: Option<Color>,
     /// Background color
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
     pub bg: Option<Color>,
     /// Underline color (if `underline-color` feature enabled)
+    ///
+    /// Not every terminal supports colored underlines, see
+    /// [`Capabilities::underline_color`](crate::backend::Capabilities::underline_color).
     #[cfg(feature = "underline-color")]
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
     pub underline_color: Option<Color>,
     /// Text modifiers
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Modifier::is_empty"))]
     pub add_modifier: Modifier,
     /// Modifiers to remove
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Modifier::is_empty"))]
     pub sub_modifier: Modifier,
 }
 
@@ -321,6 +533,24 @@ impl Style {
         self
     }
 
+    /// Get the modifiers the style applies: those added and not removed.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use tuxtui_core::style::{Modifier, Style};
+    ///
+    /// let style = Style::new()
+    ///     .add_modifier(Modifier::BOLD | Modifier::ITALIC)
+    ///     .remove_modifier(Modifier::BOLD);
+    /// assert_eq!(style.modifiers(), Modifier::ITALIC);
+    /// ```
+    #[inline]
+    #[must_use]
+    pub const fn modifiers(self) -> Modifier {
+        self.add_modifier.difference(self.sub_modifier)
+    }
+
     /// Reset the style to default.
     #[inline]
     #[must_use]
@@ -367,9 +597,10 @@ impl Style {
 /// # Example
 ///
 /// ```
-/// use tuxtui_core::style::{Color, Stylize};
+/// use tuxtui_core::style::Stylize;
+/// use tuxtui_core::text::Span;
 ///
-/// let text = "Hello".blue().bold();
+/// let text = Span::raw("Hello").blue().bold();
 /// ```
 pub trait Stylize: Sized {
     /// Apply a style to this item.
@@ -500,6 +731,111 @@ pub trait Stylize: Sized {
 mod tests {
     use super::*;
 
+    #[test]
+    fn test_contrast() {
+        let black = Color::Rgb(0, 0, 0);
+        let white = Color::Rgb(255, 255, 255);
+        assert_eq!(black.luminance(), Some(0.0));
+        assert_eq!(white.luminance(), Some(1.0));
+        assert_eq!(white.contrast_ratio(black), Some(21.0));
+        assert_eq!(black.contrast_ratio(black), Some(1.0));
+        assert_eq!(Color::Reset.contrast_ratio(black), None);
+
+        let ratio = Color::Rgb(0x76, 0x76, 0x76).contrast_ratio(white).unwrap();
+        assert!((ratio - 4.54).abs() < 0.01, "{ratio}");
+
+        assert_eq!(Color::best_text_on(Color::LightGreen), Color::Black);
+        assert_eq!(Color::best_text_on(Color::Blue), Color::White);
+        assert_eq!(Color::best_text_on(Color::Indexed(232)), Color::White);
+        assert_eq!(Color::best_text_on(Color::Reset), Color::Reset);
+    }
+
+    #[test]
+    fn test_color_display_round_trip() {
+        use alloc::string::ToString;
+
+        let named = (0..16).map(|i| Color::parse(&i.to_string()).unwrap());
+        for color in named
+            .chain([Color::Reset, Color::Indexed(200), Color::Rgb(255, 136, 0)])
+            .chain(
+                [
+                    "black",
+                    "red",
+                    "green",
+                    "yellow",
+                    "blue",
+                    "magenta",
+                    "cyan",
+                    "white",
+                    "gray",
+                    "light_red",
+                    "light_green",
+                    "light_yellow",
+                    "light_blue",
+                    "light_magenta",
+                    "light_cyan",
+                    "light_gray",
+                ]
+                .map(|name| Color::parse(name).unwrap()),
+            )
+        {
+            assert_eq!(color.to_string().parse::<Color>(), Ok(color));
+        }
+        assert_eq!(Color::Rgb(255, 136, 0).to_string(), "#ff8800");
+        assert_eq!(Color::LightBlue.to_string(), "light_blue");
+        assert_eq!(Color::Indexed(42).to_string(), "42");
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_serde_formats() {
+        #[derive(Debug, PartialEq, Serialize, Deserialize)]
+        struct Config {
+            accent: Color,
+            title: Style,
+        }
+
+        let config = Config {
+            accent: Color::Rgb(255, 136, 0),
+            title: Style::default()
+                .fg(Color::LightBlue)
+                .add_modifier(Modifier::BOLD | Modifier::ITALIC),
+        };
+
+        let json = serde_json::to_string(&config).unwrap();
+        assert_eq!(
+            json,
+            r##"{"accent":"#ff8800","title":{"fg":"light_blue","add_modifier":"BOLD | ITALIC"}}"##
+        );
+        assert_eq!(serde_json::from_str::<Config>(&json).unwrap(), config);
+
+        let toml = toml::to_string(&config).unwrap();
+        assert_eq!(toml::from_str::<Config>(&toml).unwrap(), config);
+        let written = r##"
+            accent = "rgb(255, 136, 0)"
+            title = { fg = "Light_Blue", add_modifier = "BOLD | ITALIC" }
+        "##;
+        assert_eq!(toml::from_str::<Config>(written).unwrap(), config);
+
+        insta::assert_yaml_snapshot!(config, @r##"
+        accent: "#ff8800"
+        title:
+          fg: light_blue
+          add_modifier: BOLD | ITALIC
+        "##);
+
+        assert_eq!(
+            serde_json::from_str::<Color>("42").unwrap(),
+            Color::Indexed(42)
+        );
+        assert!(serde_json::from_str::<Color>("256").is_err());
+        assert!(serde_json::from_str::<Color>(r#""chartreuse""#).is_err());
+        assert_eq!(
+            serde_json::from_str::<Style>("{}").unwrap(),
+            Style::default()
+        );
+    }
+
     #[test]
     fn test_color_rgb() {
         let color = Color::rgb(255, 128, 64);
@@ -0,0 +1,201 @@
+//! Conversions between tuxtui styles and `anstyle` types.
+//!
+//! `anstyle` has no equivalent of [`Color::Reset`], so it converts to `None`,
+//! the terminal default. Underline styles all become
+//! [`Modifier::UNDERLINED`], and both blink speeds become a single blink.
+
+use super::{Color, Modifier, Style};
+use ::anstyle::{Ansi256Color, AnsiColor, Effects, RgbColor};
+
+/// Modifiers with the effects they convert to and from.
+const EFFECTS: [(Modifier, Effects); 8] = [
+    (Modifier::BOLD, Effects::BOLD),
+    (Modifier::DIM, Effects::DIMMED),
+    (Modifier::ITALIC, Effects::ITALIC),
+    (Modifier::UNDERLINED, Effects::UNDERLINE),
+    (Modifier::SLOW_BLINK, Effects::BLINK),
+    (Modifier::REVERSED, Effects::INVERT),
+    (Modifier::HIDDEN, Effects::HIDDEN),
+    (Modifier::CROSSED_OUT, Effects::STRIKETHROUGH),
+];
+
+/// Underline styles that become a plain underline.
+const UNDERLINES: [Effects; 4] = [
+    Effects::DOUBLE_UNDERLINE,
+    Effects::CURLY_UNDERLINE,
+    Effects::DOTTED_UNDERLINE,
+    Effects::DASHED_UNDERLINE,
+];
+
+impl From<AnsiColor> for Color {
+    fn from(color: AnsiColor) -> Self {
+        match color {
+            AnsiColor::Black => Self::Black,
+            AnsiColor::Red => Self::Red,
+            AnsiColor::Green => Self::Green,
+            AnsiColor::Yellow => Self::Yellow,
+            AnsiColor::Blue => Self::Blue,
+            AnsiColor::Magenta => Self::Magenta,
+            AnsiColor::Cyan => Self::Cyan,
+            AnsiColor::White => Self::White,
+            AnsiColor::BrightBlack => Self::Gray,
+            AnsiColor::BrightRed => Self::LightRed,
+            AnsiColor::BrightGreen => Self::LightGreen,
+            AnsiColor::BrightYellow => Self::LightYellow,
+            AnsiColor::BrightBlue => Self::LightBlue,
+            AnsiColor::BrightMagenta => Self::LightMagenta,
+            AnsiColor::BrightCyan => Self::LightCyan,
+            AnsiColor::BrightWhite => Self::LightGray,
+        }
+    }
+}
+
+impl From<::anstyle::Color> for Color {
+    fn from(color: ::anstyle::Color) -> Self {
+        match color {
+            ::anstyle::Color::Ansi(color) => color.into(),
+            ::anstyle::Color::Ansi256(Ansi256Color(index)) => Self::Indexed(index),
+            ::anstyle::Color::Rgb(RgbColor(r, g, b)) => Self::Rgb(r, g, b),
+        }
+    }
+}
+
+impl From<Color> for Option<::anstyle::Color> {
+    fn from(color: Color) -> Self {
+        let ansi = match color {
+            Color::Reset => return None,
+            Color::Indexed(index) => return Some(Ansi256Color(index).into()),
+            Color::Rgb(r, g, b) => return Some(RgbColor(r, g, b).into()),
+            Color::Black => AnsiColor::Black,
+            Color::Red => AnsiColor::Red,
+            Color::Green => AnsiColor::Green,
+            Color::Yellow => AnsiColor::Yellow,
+            Color::Blue => AnsiColor::Blue,
+            Color::Magenta => AnsiColor::Magenta,
+            Color::Cyan => AnsiColor::Cyan,
+            Color::White => AnsiColor::White,
+            Color::Gray => AnsiColor::BrightBlack,
+            Color::LightRed => AnsiColor::BrightRed,
+            Color::LightGreen => AnsiColor::BrightGreen,
+            Color::LightYellow => AnsiColor::BrightYellow,
+            Color::LightBlue => AnsiColor::BrightBlue,
+            Color::LightMagenta => AnsiColor::BrightMagenta,
+            Color::LightCyan => AnsiColor::BrightCyan,
+            Color::LightGray => AnsiColor::BrightWhite,
+        };
+        Some(ansi.into())
+    }
+}
+
+impl From<Effects> for Modifier {
+    fn from(effects: Effects) -> Self {
+        let mut modifier = Self::empty();
+        for (flag, effect) in EFFECTS {
+            if effects.contains(effect) {
+                modifier |= flag;
+            }
+        }
+        if UNDERLINES
+            .iter()
+            .any(|&underline| effects.contains(underline))
+        {
+            modifier |= Self::UNDERLINED;
+        }
+        modifier
+    }
+}
+
+impl From<Modifier> for Effects {
+    fn from(modifier: Modifier) -> Self {
+        let mut effects = Self::new();
+        for (flag, effect) in EFFECTS {
+            if modifier.contains(flag) {
+                effects |= effect;
+            }
+        }
+        if modifier.contains(Modifier::RAPID_BLINK) {
+            effects |= Self::BLINK;
+        }
+        effects
+    }
+}
+
+impl From<::anstyle::Style> for Style {
+    fn from(style: ::anstyle::Style) -> Self {
+        Self {
+            fg: style.get_fg_color().map(Color::from),
+            bg: style.get_bg_color().map(Color::from),
+            #[cfg(feature = "underline-color")]
+            underline_color: style.get_underline_color().map(Color::from),
+            add_modifier: style.get_effects().into(),
+            sub_modifier: Modifier::empty(),
+        }
+    }
+}
+
+impl From<Style> for ::anstyle::Style {
+    /// Convert the colors and the modifiers the style adds.
+    ///
+    /// An `anstyle` style always starts from the terminal default, so
+    /// removed modifiers are dropped.
+    fn from(style: Style) -> Self {
+        let converted = Self::new()
+            .fg_color(style.fg.and_then(Option::from))
+            .bg_color(style.bg.and_then(Option::from))
+            .effects(style.modifiers().into());
+        #[cfg(feature = "underline-color")]
+        let converted = converted.underline_color(style.underline_color.and_then(Option::from));
+        converted
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_color_round_trip() {
+        for color in [
+            Color::Black,
+            Color::Gray,
+            Color::White,
+            Color::LightGray,
+            Color::LightMagenta,
+            Color::Indexed(42),
+            Color::Rgb(1, 2, 3),
+        ] {
+            let converted: Option<::anstyle::Color> = color.into();
+            assert_eq!(converted.map(Color::from), Some(color));
+        }
+        assert_eq!(Option::<::anstyle::Color>::from(Color::Reset), None);
+    }
+
+    #[test]
+    fn test_style_conversion() {
+        let style = Style::default()
+            .fg(Color::Red)
+            .bg(Color::Reset)
+            .add_modifier(Modifier::BOLD | Modifier::RAPID_BLINK | Modifier::ITALIC)
+            .remove_modifier(Modifier::ITALIC);
+        let converted = ::anstyle::Style::from(style);
+        assert_eq!(
+            converted,
+            ::anstyle::Style::new()
+                .fg_color(Some(AnsiColor::Red.into()))
+                .bold()
+                .blink()
+        );
+        assert_eq!(
+            Style::from(converted),
+            Style::default()
+                .fg(Color::Red)
+                .add_modifier(Modifier::BOLD | Modifier::SLOW_BLINK)
+        );
+
+        let curly = ::anstyle::Style::new().effects(Effects::CURLY_UNDERLINE | Effects::HIDDEN);
+        assert_eq!(
+            Style::from(curly).add_modifier,
+            Modifier::UNDERLINED | Modifier::HIDDEN
+        );
+    }
+}
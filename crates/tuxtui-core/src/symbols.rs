@@ -27,6 +27,34 @@ pub struct LineStyle {
     pub cross: &'static str,
 }
 
+impl LineStyle {
+    /// Get the ASCII preset, [`SIMPLE`].
+    #[must_use]
+    pub const fn ascii() -> Self {
+        SIMPLE
+    }
+
+    /// Check whether all the symbols are ASCII.
+    #[must_use]
+    pub fn is_ascii(&self) -> bool {
+        [
+            self.horizontal,
+            self.vertical,
+            self.top_left,
+            self.top_right,
+            self.bottom_left,
+            self.bottom_right,
+            self.vertical_right,
+            self.vertical_left,
+            self.horizontal_down,
+            self.horizontal_up,
+            self.cross,
+        ]
+        .iter()
+        .all(|symbol| symbol.is_ascii())
+    }
+}
+
 /// Simple single-line borders (ASCII-compatible).
 pub const SIMPLE: LineStyle = LineStyle {
     horizontal: "-",
@@ -115,6 +143,22 @@ pub struct ScrollbarSymbols {
     pub end: &'static str,
 }
 
+impl ScrollbarSymbols {
+    /// Get the ASCII preset, [`SCROLLBAR_ASCII`].
+    #[must_use]
+    pub const fn ascii() -> Self {
+        SCROLLBAR_ASCII
+    }
+
+    /// Check whether all the symbols are ASCII.
+    #[must_use]
+    pub fn is_ascii(&self) -> bool {
+        [self.track, self.thumb, self.begin, self.end]
+            .iter()
+            .all(|symbol| symbol.is_ascii())
+    }
+}
+
 /// Default scrollbar symbols.
 pub const SCROLLBAR_DEFAULT: ScrollbarSymbols = ScrollbarSymbols {
     track: "│",
@@ -131,6 +175,14 @@ pub const SCROLLBAR_BLOCK: ScrollbarSymbols = ScrollbarSymbols {
     end: "▼",
 };
 
+/// Scrollbar with ASCII symbols.
+pub const SCROLLBAR_ASCII: ScrollbarSymbols = ScrollbarSymbols {
+    track: "|",
+    thumb: "#",
+    begin: "^",
+    end: "v",
+};
+
 /// Bar chart symbols.
 pub const BAR_FULL: &str = "█";
 /// Seven-eighths filled bar symbol.
@@ -161,6 +213,11 @@ pub const BLOCKS: [&str; 9] = [
     BAR_FULL,
 ];
 
+/// Upper half block symbol.
+pub const HALF_UPPER: &str = "▀";
+/// Lower half block symbol.
+pub const HALF_LOWER: &str = "▄";
+
 /// Dot symbols for charts.
 pub const DOT: &str = "•";
 /// Filled bullet point symbol.
@@ -168,6 +225,13 @@ pub const BULLET: &str = "●";
 /// Empty circle symbol.
 pub const CIRCLE: &str = "○";
 
+/// Light shade symbol.
+pub const SHADE_LIGHT: &str = "░";
+/// Medium shade symbol.
+pub const SHADE_MEDIUM: &str = "▒";
+/// Dark shade symbol.
+pub const SHADE_DARK: &str = "▓";
+
 /// Marker symbols for lists.
 pub const MARKER_DOT: &str = "•";
 /// Arrow marker symbol.
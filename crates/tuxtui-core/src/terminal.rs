@@ -1,27 +1,200 @@
 //! Terminal management and frame orchestration.
 
-use crate::backend::Backend;
-use crate::buffer::Buffer;
+use crate::backend::{Backend, Capabilities};
+use crate::buffer::{Buffer, ControlChars};
+use crate::event::Event;
 use crate::geometry::Rect;
+use crate::narration::{Announcement, Describe, NarrationSink, Narrator};
+use crate::settings::UiSettings;
+use crate::util::unicode::WidthPolicy;
+#[cfg(feature = "std")]
+use alloc::collections::VecDeque;
+use alloc::string::String;
+use core::fmt;
+#[cfg(not(feature = "portable-atomic"))]
+use core::sync::atomic::{AtomicBool, Ordering};
+#[cfg(feature = "std")]
+use core::time::Duration;
+#[cfg(feature = "portable-atomic")]
+use portable_atomic::{AtomicBool, Ordering};
+#[cfg(feature = "std")]
+use std::time::Instant;
+
+/// Number of recent frames the rolling frame rate is computed over.
+#[cfg(feature = "std")]
+const FPS_WINDOW: usize = 30;
+
+/// Whether a fullscreen [`Terminal`] is drawing on the process's terminal.
+static SCREEN_IN_USE: AtomicBool = AtomicBool::new(false);
+
+/// The part of the screen a [`Terminal`] draws on.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum Viewport {
+    /// The whole screen
+    #[default]
+    Fullscreen,
+    /// The given number of rows at the bottom of the screen, below the
+    /// output of the program
+    ///
+    /// The alternate screen is not used, so several inline terminals, or one
+    /// next to log output, can share the process's terminal. Print above the
+    /// viewport with [`Terminal::insert_before`].
+    Inline(u16),
+}
+
+impl Viewport {
+    /// Get the area of the viewport on a screen of `size`.
+    fn area(self, size: Rect) -> Rect {
+        match self {
+            Self::Fullscreen => size,
+            Self::Inline(height) => {
+                let height = height.min(size.height);
+                Rect::new(size.x, size.bottom() - height, size.width, height)
+            }
+        }
+    }
+}
+
+/// An error creating a terminal with [`Terminal::try_new`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum TerminalError<E> {
+    /// Another fullscreen terminal is drawing on the process's terminal
+    InUse,
+    /// The backend failed
+    Backend(E),
+}
+
+impl<E> From<E> for TerminalError<E> {
+    fn from(error: E) -> Self {
+        Self::Backend(error)
+    }
+}
+
+impl<E: fmt::Display> fmt::Display for TerminalError<E> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::InUse => write!(f, "another fullscreen terminal is active"),
+            Self::Backend(error) => write!(f, "{error}"),
+        }
+    }
+}
+
+impl<E: fmt::Debug + fmt::Display> core::error::Error for TerminalError<E> {}
 
 /// Options for configuring a terminal.
 #[derive(Debug, Clone)]
 pub struct TerminalOptions {
-    /// Enable alternate screen
+    /// The part of the screen to draw on
+    pub viewport: Viewport,
+    /// Enable alternate screen, for a fullscreen viewport
     pub alternate_screen: bool,
     /// Hide cursor during rendering
     pub hide_cursor: bool,
+    /// How control characters written by widgets are handled
+    pub control_chars: ControlChars,
+    /// The accessibility preferences widgets honor
+    pub settings: UiSettings,
+    /// How the width of emoji and other clusters is measured, to match the
+    /// terminal
+    pub width_policy: WidthPolicy,
+    /// Hold back resize events until the size has been stable this long
+    ///
+    /// See [`Terminal::poll_event`].
+    #[cfg(feature = "std")]
+    pub resize_debounce: Option<Duration>,
 }
 
 impl Default for TerminalOptions {
     fn default() -> Self {
         Self {
+            viewport: Viewport::Fullscreen,
             alternate_screen: true,
             hide_cursor: true,
+            control_chars: ControlChars::default(),
+            settings: UiSettings::new(),
+            width_policy: WidthPolicy::Unicode,
+            #[cfg(feature = "std")]
+            resize_debounce: None,
         }
     }
 }
 
+/// Statistics about the frames drawn by a [`Terminal`].
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct RenderStats {
+    /// Number of frames drawn
+    pub frames: usize,
+    /// Number of runs of changed cells in the last frame
+    pub diff_size: usize,
+    /// Number of cells sent to the backend for the last frame
+    pub cells_drawn: usize,
+    /// Time the last draw took, including flushing the backend
+    #[cfg(feature = "std")]
+    pub draw_duration: Duration,
+    /// Frames per second over the recent frames
+    #[cfg(feature = "std")]
+    pub fps: f64,
+}
+
+/// Limits how often an application's run loop draws frames.
+///
+/// # Example
+///
+/// ```
+/// use tuxtui_core::backend::TestBackend;
+/// use tuxtui_core::terminal::{FrameLimiter, Terminal};
+///
+/// let mut terminal = Terminal::new(TestBackend::new(10, 1)).unwrap();
+/// let mut limiter = FrameLimiter::new(60);
+/// for _ in 0..3 {
+///     // Handle input, waiting at most `limiter.remaining()` for it.
+///     limiter.wait();
+///     terminal.draw(|frame| frame.render_widget("hi", frame.area())).unwrap();
+/// }
+/// assert_eq!(terminal.stats().frames, 3);
+/// ```
+#[cfg(feature = "std")]
+#[derive(Debug, Clone)]
+pub struct FrameLimiter {
+    interval: Duration,
+    next: Option<Instant>,
+}
+
+#[cfg(feature = "std")]
+impl FrameLimiter {
+    /// Create a limiter allowing at most `fps` frames per second.
+    #[must_use]
+    pub fn new(fps: u32) -> Self {
+        Self {
+            interval: Duration::from_secs(1) / fps.max(1),
+            next: None,
+        }
+    }
+
+    /// Get the time until the next frame may be drawn.
+    #[must_use]
+    pub fn remaining(&self) -> Duration {
+        self.next.map_or(Duration::ZERO, |next| {
+            next.saturating_duration_since(Instant::now())
+        })
+    }
+
+    /// Check whether a frame may be drawn now, and if so start a new interval.
+    pub fn ready(&mut self) -> bool {
+        if !self.remaining().is_zero() {
+            return false;
+        }
+        self.next = Some(Instant::now() + self.interval);
+        true
+    }
+
+    /// Sleep until a frame may be drawn, then start a new interval.
+    pub fn wait(&mut self) {
+        std::thread::sleep(self.remaining());
+        self.next = Some(Instant::now() + self.interval);
+    }
+}
+
 /// A terminal interface managing rendering and buffering.
 ///
 /// The terminal orchestrates frame rendering, maintains double buffers,
@@ -41,9 +214,24 @@ impl Default for TerminalOptions {
 /// ```
 pub struct Terminal<B: Backend> {
     backend: B,
+    viewport: Viewport,
+    /// Whether this terminal holds the process's terminal, see
+    /// [`Terminal::try_new`].
+    owns_screen: bool,
+    alternate_screen: bool,
     buffers: [Buffer; 2],
     current: usize,
     hidden_cursor: bool,
+    pending_resize: Option<(u16, u16)>,
+    deferred_event: Option<Event>,
+    #[cfg(feature = "std")]
+    resize_debounce: Option<Duration>,
+    #[cfg(feature = "std")]
+    last_resize: Option<Instant>,
+    stats: RenderStats,
+    #[cfg(feature = "std")]
+    frame_times: VecDeque<Instant>,
+    narrator: Option<Narrator>,
 }
 
 impl<B: Backend> Terminal<B> {
@@ -53,10 +241,99 @@ impl<B: Backend> Terminal<B> {
     }
 
     /// Create a new terminal with options.
-    pub fn with_options(mut backend: B, options: TerminalOptions) -> Result<Self, B::Error> {
+    ///
+    /// Unlike [`Terminal::try_with_options`], this does not check whether
+    /// another fullscreen terminal is active.
+    pub fn with_options(backend: B, options: TerminalOptions) -> Result<Self, B::Error> {
+        let owns_screen = Self::needs_screen(&backend, &options) && claim_screen();
+        Self::setup(backend, options, owns_screen)
+    }
+
+    /// Create a new terminal, failing if another one is drawing fullscreen
+    /// on the process's terminal.
+    ///
+    /// Two terminals writing to the same screen corrupt each other's
+    /// output, so a fullscreen terminal whose backend
+    /// [draws on this process's terminal](Backend::is_process_terminal)
+    /// holds it until dropped. Terminals with an inline viewport never hold
+    /// it and can run next to other output.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`TerminalError::InUse`] if another fullscreen terminal is
+    /// active, or the error of the backend if setting up fails.
+    pub fn try_new(backend: B) -> Result<Self, TerminalError<B::Error>> {
+        Self::try_with_options(backend, TerminalOptions::default())
+    }
+
+    /// Create a new terminal with options, failing if another one is drawing
+    /// fullscreen on the process's terminal. See [`Terminal::try_new`].
+    ///
+    /// # Errors
+    ///
+    /// Returns [`TerminalError::InUse`] if another fullscreen terminal is
+    /// active, or the error of the backend if setting up fails.
+    pub fn try_with_options(
+        backend: B,
+        options: TerminalOptions,
+    ) -> Result<Self, TerminalError<B::Error>> {
+        let owns_screen = Self::needs_screen(&backend, &options);
+        if owns_screen && !claim_screen() {
+            return Err(TerminalError::InUse);
+        }
+        Ok(Self::setup(backend, options, owns_screen)?)
+    }
+
+    /// Check whether a terminal with `options` needs the process's terminal
+    /// to itself.
+    fn needs_screen(backend: &B, options: &TerminalOptions) -> bool {
+        options.viewport == Viewport::Fullscreen && backend.is_process_terminal()
+    }
+
+    /// Prepare the screen, releasing it again if that fails.
+    fn setup(
+        mut backend: B,
+        options: TerminalOptions,
+        owns_screen: bool,
+    ) -> Result<Self, B::Error> {
+        let result = Self::prepare(&mut backend, &options);
+        if result.is_err() && owns_screen {
+            release_screen();
+        }
+        let area = result?;
+
+        let buffer = Buffer::empty(area)
+            .with_control_chars(options.control_chars)
+            .with_settings(options.settings)
+            .with_width_policy(options.width_policy);
+        Ok(Self {
+            backend,
+            viewport: options.viewport,
+            owns_screen,
+            alternate_screen: options.alternate_screen,
+            buffers: [buffer.clone(), buffer],
+            current: 0,
+            hidden_cursor: options.hide_cursor,
+            pending_resize: None,
+            deferred_event: None,
+            #[cfg(feature = "std")]
+            resize_debounce: options.resize_debounce,
+            #[cfg(feature = "std")]
+            last_resize: None,
+            stats: RenderStats::default(),
+            #[cfg(feature = "std")]
+            frame_times: VecDeque::with_capacity(FPS_WINDOW),
+            narrator: None,
+        })
+    }
+
+    /// Set up the backend for drawing, returning the area of the viewport.
+    fn prepare(backend: &mut B, options: &TerminalOptions) -> Result<Rect, B::Error> {
         let size = backend.size()?;
+        let area = options.viewport.area(size);
+        let fullscreen = options.viewport == Viewport::Fullscreen;
 
-        if options.alternate_screen {
+        if fullscreen && options.alternate_screen {
             backend.enter_alternate_screen()?;
         }
 
@@ -65,15 +342,15 @@ impl<B: Backend> Terminal<B> {
         }
 
         backend.enable_raw_mode()?;
-        backend.clear()?;
+        if fullscreen {
+            backend.clear()?;
+        } else {
+            // Scroll the existing output up to make room.
+            backend.set_cursor(0, size.bottom().saturating_sub(1))?;
+            backend.append_lines(area.height)?;
+        }
         backend.flush()?;
-
-        Ok(Self {
-            backend,
-            buffers: [Buffer::empty(size), Buffer::empty(size)],
-            current: 0,
-            hidden_cursor: options.hide_cursor,
-        })
+        Ok(area)
     }
 
     /// Get the size of the terminal.
@@ -87,13 +364,21 @@ impl<B: Backend> Terminal<B> {
         self.buffers[self.current].area
     }
 
-    /// Clear the terminal.
+    /// Clear the terminal, or only the viewport if it is inline.
     pub fn clear(&mut self) -> Result<(), B::Error> {
-        self.backend.clear()?;
+        self.clear_viewport()?;
         self.buffers[self.current].clear();
         Ok(())
     }
 
+    /// Clear the viewport on the screen.
+    fn clear_viewport(&mut self) -> Result<(), B::Error> {
+        match self.viewport {
+            Viewport::Fullscreen => self.backend.clear(),
+            Viewport::Inline(_) => self.backend.clear_region(self.viewport()),
+        }
+    }
+
     /// Draw a frame using the provided closure.
     ///
     /// # Example
@@ -108,8 +393,11 @@ impl<B: Backend> Terminal<B> {
     where
         F: FnOnce(&mut Frame<'_>),
     {
+        #[cfg(feature = "std")]
+        let start = Instant::now();
+
         // Check for resize
-        let size = self.backend.size()?;
+        let size = self.viewport.area(self.backend.size()?);
         if size != self.buffers[self.current].area {
             self.resize(size)?;
         }
@@ -121,29 +409,241 @@ impl<B: Backend> Terminal<B> {
         let mut frame = Frame {
             buffer: &mut self.buffers[next],
             area: size,
+            count: self.stats.frames,
+            capabilities: self.backend.capabilities(),
+            narrator: self.narrator.as_mut(),
         };
         render(&mut frame);
 
         // Compute diff and render
         let diff = self.buffers[self.current].diff(&self.buffers[next]);
+        self.stats.diff_size = diff.len();
+        self.stats.cells_drawn = 0;
         for change in diff {
-            for cell in change.cells {
-                self.backend.draw_cell(change.x, change.y, cell)?;
+            self.stats.cells_drawn += change.cells.len();
+            for (i, cell) in change.cells.into_iter().enumerate() {
+                self.backend
+                    .draw_cell(change.x + i as u16, change.y, cell)?;
             }
         }
 
         self.backend.flush()?;
         self.current = next;
+        self.stats.frames = self.stats.frames.wrapping_add(1);
+        if let Some(narrator) = &mut self.narrator {
+            narrator.finish_frame();
+        }
+
+        #[cfg(feature = "std")]
+        self.record_frame_time(start);
 
         Ok(())
     }
 
+    /// Update the draw duration and rolling frame rate.
+    #[cfg(feature = "std")]
+    fn record_frame_time(&mut self, start: Instant) {
+        let now = Instant::now();
+        self.stats.draw_duration = now - start;
+        if self.frame_times.len() == FPS_WINDOW {
+            self.frame_times.pop_front();
+        }
+        self.frame_times.push_back(now);
+        let elapsed = self
+            .frame_times
+            .front()
+            .map_or(Duration::ZERO, |first| now - *first);
+        self.stats.fps = if elapsed.is_zero() {
+            0.0
+        } else {
+            (self.frame_times.len() - 1) as f64 / elapsed.as_secs_f64()
+        };
+    }
+
+    /// Announce focus and selection changes described while drawing to
+    /// `sink`, for screen readers.
+    ///
+    /// Narration is off until a sink is set. See [`Frame::describe`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use std::sync::{Arc, Mutex};
+    /// use tuxtui_core::backend::TestBackend;
+    /// use tuxtui_core::narration::Announcement;
+    /// use tuxtui_core::terminal::Terminal;
+    ///
+    /// let spoken = Arc::new(Mutex::new(Vec::new()));
+    /// let log = Arc::clone(&spoken);
+    /// let mut terminal = Terminal::new(TestBackend::new(20, 2)).unwrap();
+    /// terminal.set_narration_sink(move |announcement: &Announcement| {
+    ///     log.lock().unwrap().push(announcement.message.clone());
+    /// });
+    ///
+    /// for _ in 0..2 {
+    ///     terminal.draw(|frame| frame.describe("menu", "Open, 1 of 4")).unwrap();
+    /// }
+    /// assert_eq!(*spoken.lock().unwrap(), ["Open, 1 of 4"]);
+    /// ```
+    pub fn set_narration_sink(&mut self, sink: impl NarrationSink + 'static) {
+        self.narrator = Some(Narrator::new(sink));
+    }
+
+    /// Turn narration off.
+    pub fn clear_narration_sink(&mut self) {
+        self.narrator = None;
+    }
+
+    /// Get the accessibility preferences widgets honor.
+    #[must_use]
+    pub const fn settings(&self) -> UiSettings {
+        self.buffers[self.current].settings
+    }
+
+    /// Change the accessibility preferences, from the next frame on.
+    pub fn set_settings(&mut self, settings: UiSettings) {
+        for buffer in &mut self.buffers {
+            buffer.settings = settings;
+        }
+    }
+
+    /// Get statistics about the drawn frames.
+    #[must_use]
+    pub const fn stats(&self) -> &RenderStats {
+        &self.stats
+    }
+
     /// Resize the terminal buffers.
     fn resize(&mut self, size: Rect) -> Result<(), B::Error> {
         self.buffers[0].resize(size);
         self.buffers[1].resize(size);
-        self.backend.clear()?;
-        Ok(())
+        // The screen is cleared, so the next frame is drawn in full.
+        self.buffers[self.current].clear();
+        self.clear_viewport()
+    }
+
+    /// Print `height` lines above an inline viewport.
+    ///
+    /// `draw` renders the lines into a buffer as wide as the viewport. They
+    /// scroll the output above the viewport up, into the terminal's
+    /// scrollback, and the viewport is drawn again below them. Use this
+    /// instead of printing to stdout, which would overwrite the viewport.
+    /// Does nothing for a fullscreen viewport, or an inline one filling the
+    /// screen.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use tuxtui_core::backend::TestBackend;
+    /// use tuxtui_core::buffer::Buffer;
+    /// use tuxtui_core::style::Style;
+    /// use tuxtui_core::terminal::{Terminal, TerminalOptions, Viewport};
+    ///
+    /// let options = TerminalOptions {
+    ///     viewport: Viewport::Inline(1),
+    ///     ..TerminalOptions::default()
+    /// };
+    /// let mut terminal = Terminal::with_options(TestBackend::new(8, 3), options).unwrap();
+    /// terminal.draw(|frame| frame.render_widget("[====  ]", frame.area())).unwrap();
+    /// terminal
+    ///     .insert_before(1, |buffer| {
+    ///         buffer.set_string(0, 0, "step 1", Style::default());
+    ///     })
+    ///     .unwrap();
+    /// assert_eq!(
+    ///     *terminal.backend().buffer(),
+    ///     Buffer::from_lines(&["        ", "step 1  ", "[====  ]"])
+    /// );
+    /// ```
+    pub fn insert_before<F>(&mut self, height: u16, draw: F) -> Result<(), B::Error>
+    where
+        F: FnOnce(&mut Buffer),
+    {
+        let area = self.viewport();
+        if self.viewport == Viewport::Fullscreen || area.y == 0 {
+            return Ok(());
+        }
+        let current = &self.buffers[self.current];
+        let mut lines = Buffer::empty(Rect::new(0, 0, area.width, height))
+            .with_control_chars(current.control_chars)
+            .with_settings(current.settings)
+            .with_width_policy(current.width_policy);
+        draw(&mut lines);
+
+        // Scroll by at most the rows above the viewport at a time, then draw
+        // the lines over the part of the viewport that scrolled up.
+        let mut row = 0;
+        while row < height {
+            let count = (height - row).min(area.y);
+            self.backend.set_cursor(0, area.bottom() - 1)?;
+            self.backend.append_lines(count)?;
+            for y in 0..count {
+                for x in 0..area.width {
+                    if let Some(cell) = lines.get(x, row + y) {
+                        self.backend
+                            .draw_cell(area.x + x, area.y - count + y, cell)?;
+                    }
+                }
+            }
+            row += count;
+        }
+
+        let buffer = &self.buffers[self.current];
+        for y in area.top()..area.bottom() {
+            for x in area.left()..area.right() {
+                if let Some(cell) = buffer.get(x, y) {
+                    self.backend.draw_cell(x, y, cell)?;
+                }
+            }
+        }
+        self.backend.flush()
+    }
+
+    /// Get the next pending input event without blocking.
+    ///
+    /// Bursts of [`Event::Resize`] events, as sent while a window is being
+    /// dragged, are coalesced into one carrying the final size. With
+    /// [`TerminalOptions::resize_debounce`] set, that event is held back
+    /// until no resize arrived for the given duration, so keep polling to
+    /// receive it. Any other event delivers a held resize right away, so
+    /// events are never reordered.
+    pub fn poll_event(&mut self) -> Result<Option<Event>, B::Error> {
+        if let Some(event) = self.deferred_event.take() {
+            return Ok(Some(event));
+        }
+        while let Some(event) = self.backend.poll_event()? {
+            if let Event::Resize(width, height) = event {
+                self.pending_resize = Some((width, height));
+                #[cfg(feature = "std")]
+                {
+                    self.last_resize = Some(Instant::now());
+                }
+                continue;
+            }
+            return Ok(Some(match self.pending_resize.take() {
+                Some((width, height)) => {
+                    self.deferred_event = Some(event);
+                    Event::Resize(width, height)
+                }
+                None => event,
+            }));
+        }
+        if !self.resize_settled() {
+            return Ok(None);
+        }
+        Ok(self
+            .pending_resize
+            .take()
+            .map(|(width, height)| Event::Resize(width, height)))
+    }
+
+    /// Check whether a held resize event may be delivered.
+    fn resize_settled(&self) -> bool {
+        #[cfg(feature = "std")]
+        if let (Some(delay), Some(last)) = (self.resize_debounce, self.last_resize) {
+            return last.elapsed() >= delay;
+        }
+        true
     }
 
     /// Show the cursor.
@@ -165,6 +665,23 @@ impl<B: Backend> Terminal<B> {
         self.backend.set_cursor(x, y)
     }
 
+    /// Set the window title.
+    pub fn set_title(&mut self, title: &str) -> Result<(), B::Error> {
+        self.backend.set_title(title)
+    }
+
+    /// Get the backend.
+    #[must_use]
+    pub const fn backend(&self) -> &B {
+        &self.backend
+    }
+
+    /// Get the features supported by the terminal.
+    #[must_use]
+    pub fn capabilities(&self) -> Capabilities {
+        self.backend.capabilities()
+    }
+
     /// Get mutable access to the backend.
     pub fn backend_mut(&mut self) -> &mut B {
         &mut self.backend
@@ -174,25 +691,79 @@ impl<B: Backend> Terminal<B> {
     pub fn flush(&mut self) -> Result<(), B::Error> {
         self.backend.flush()
     }
+
+    /// Give the screen back, as when the terminal is dropped, for example to
+    /// run an editor or before the process is stopped.
+    ///
+    /// Raw mode is turned off, the alternate screen left and the cursor
+    /// shown. Call [`Terminal::resume`] to draw again.
+    pub fn suspend(&mut self) -> Result<(), B::Error> {
+        if self.viewport == Viewport::Fullscreen {
+            self.backend.disable_raw_mode()?;
+            self.backend.leave_alternate_screen()?;
+        } else {
+            // Keep the last frame, with the output continuing below it.
+            self.backend
+                .set_cursor(0, self.viewport().bottom().saturating_sub(1))?;
+            self.backend.append_lines(1)?;
+            self.backend.disable_raw_mode()?;
+        }
+        if self.hidden_cursor {
+            self.backend.show_cursor()?;
+        }
+        self.backend.flush()
+    }
+
+    /// Set the screen up again after [`Terminal::suspend`].
+    ///
+    /// Whatever was drawn meanwhile is cleared, and the next frame is drawn
+    /// in full.
+    pub fn resume(&mut self) -> Result<(), B::Error> {
+        let options = TerminalOptions {
+            viewport: self.viewport,
+            alternate_screen: self.alternate_screen,
+            hide_cursor: self.hidden_cursor,
+            ..TerminalOptions::default()
+        };
+        let area = Self::prepare(&mut self.backend, &options)?;
+        self.buffers[0].resize(area);
+        self.buffers[1].resize(area);
+        self.buffers[self.current].clear();
+        Ok(())
+    }
 }
 
 impl<B: Backend> Drop for Terminal<B> {
     fn drop(&mut self) {
-        let _ = self.backend.disable_raw_mode();
-        let _ = self.backend.leave_alternate_screen();
-        if self.hidden_cursor {
-            let _ = self.backend.show_cursor();
+        let _ = self.suspend();
+        if self.owns_screen {
+            release_screen();
         }
-        let _ = self.backend.flush();
     }
 }
 
+/// Take the process's terminal for a fullscreen terminal, returning whether
+/// it was free.
+fn claim_screen() -> bool {
+    SCREEN_IN_USE
+        .compare_exchange(false, true, Ordering::Acquire, Ordering::Relaxed)
+        .is_ok()
+}
+
+/// Give the process's terminal back.
+fn release_screen() {
+    SCREEN_IN_USE.store(false, Ordering::Release);
+}
+
 /// A frame for rendering widgets.
 ///
 /// Frames provide access to a buffer and the rendering area during a draw call.
 pub struct Frame<'a> {
     buffer: &'a mut Buffer,
     area: Rect,
+    count: usize,
+    capabilities: Capabilities,
+    narrator: Option<&'a mut Narrator>,
 }
 
 impl<'a> Frame<'a> {
@@ -202,17 +773,88 @@ impl<'a> Frame<'a> {
         self.area
     }
 
+    /// Get the number of frames drawn before this one.
+    ///
+    /// The count starts at zero and wraps around on overflow, which makes it
+    /// suitable for animations.
+    #[must_use]
+    pub const fn count(&self) -> usize {
+        self.count
+    }
+
+    /// Get the capabilities of the terminal, such as whether it supports
+    /// graphics or synchronized output.
+    #[must_use]
+    pub const fn capabilities(&self) -> Capabilities {
+        self.capabilities
+    }
+
+    /// Get the part of `area` inside the frame, such as for an area
+    /// computed by a layout for a larger screen.
+    #[must_use]
+    pub const fn area_of(&self, area: Rect) -> Rect {
+        self.area.intersection(area)
+    }
+
+    /// Get the accessibility preferences, such as whether to leave out
+    /// animations.
+    #[must_use]
+    pub const fn settings(&self) -> UiSettings {
+        self.buffer.settings
+    }
+
     /// Get mutable access to the buffer.
     pub fn buffer_mut(&mut self) -> &mut Buffer {
         self.buffer
     }
 
+    /// Describe the focus or selection of the part identified by `id`,
+    /// such as "Open, 1 of 4" for a menu.
+    ///
+    /// When narration is on, descriptions that changed since the previous
+    /// frame are announced after this one is drawn. See
+    /// [`Terminal::set_narration_sink`].
+    pub fn describe(&mut self, id: &str, description: impl Into<String>) {
+        if let Some(narrator) = &mut self.narrator {
+            narrator.describe(id, description);
+        }
+    }
+
+    /// Describe the focus or selection of a widget or state, see
+    /// [`describe`](Self::describe).
+    pub fn describe_widget<D: Describe + ?Sized>(&mut self, id: &str, widget: &D) {
+        if let Some(narrator) = &mut self.narrator {
+            if let Some(description) = widget.describe() {
+                narrator.describe(id, description);
+            }
+        }
+    }
+
+    /// Announce a message, such as "File saved", after this frame is drawn
+    /// if narration is on.
+    pub fn announce(&mut self, announcement: Announcement) {
+        if let Some(narrator) = &mut self.narrator {
+            narrator.announce(announcement);
+        }
+    }
+
     /// Render a widget at the given area.
+    ///
+    /// The area is clipped to the frame.
     pub fn render_widget<W>(&mut self, widget: W, area: Rect)
     where
         W: Widget,
     {
-        widget.render(area, self.buffer);
+        self.buffer.view_mut(area).render_widget(widget);
+    }
+
+    /// Render a widget by reference at the given area, keeping it for
+    /// later frames. See [`render_widget`](Self::render_widget).
+    pub fn render_widget_ref<W>(&mut self, widget: &W, area: Rect)
+    where
+        W: WidgetRef + ?Sized,
+    {
+        self.render_widget(widget, area);
     }
 }
 
@@ -222,12 +864,26 @@ pub trait Widget {
     fn render(self, area: Rect, buf: &mut Buffer);
 }
 
+/// A widget that can be rendered by reference, so that it can be kept and
+/// drawn again, or drawn as a trait object.
+///
+/// References to these widgets are [`Widget`]s.
+pub trait WidgetRef {
+    /// Render this widget into the given area of the buffer.
+    fn render_ref(&self, area: Rect, buf: &mut Buffer);
+}
+
+impl<W: WidgetRef + ?Sized> Widget for &W {
+    fn render(self, area: Rect, buf: &mut Buffer) {
+        self.render_ref(area, buf);
+    }
+}
+
 /// Implement Widget for string slices for convenience.
 impl Widget for &str {
     fn render(self, area: Rect, buf: &mut Buffer) {
-        if area.height > 0 {
-            buf.set_string(area.x, area.y, self, crate::style::Style::default());
-        }
+        buf.view_mut(area)
+            .set_string(0, 0, self, crate::style::Style::default());
     }
 }
 
@@ -256,4 +912,273 @@ mod tests {
 
         assert!(result.is_ok());
     }
+
+    #[test]
+    fn test_frame_capabilities_and_widget_refs() {
+        let mut backend = TestBackend::new(10, 2);
+        backend.set_capabilities(Capabilities::default());
+        let mut terminal = Terminal::new(backend).unwrap();
+        let line = crate::text::Line::from("kept");
+        let span = crate::text::Span::raw("dyn");
+        let dynamic: &dyn WidgetRef = &span;
+        terminal
+            .draw(|frame| {
+                assert!(!frame.capabilities().has_graphics());
+                assert_eq!(
+                    frame.area_of(Rect::new(6, 1, 10, 10)),
+                    Rect::new(6, 1, 4, 1)
+                );
+                frame.render_widget_ref(&line, Rect::new(0, 0, 2, 1));
+                frame.render_widget_ref(dynamic, Rect::new(0, 1, 10, 1));
+            })
+            .unwrap();
+        assert_eq!(
+            terminal.backend().buffer().to_string(),
+            "ke        \ndyn       "
+        );
+        assert_eq!(line, crate::text::Line::from("kept"));
+    }
+
+    #[test]
+    fn test_render_stats() {
+        let mut terminal = Terminal::new(TestBackend::new(10, 2)).unwrap();
+        terminal
+            .draw(|frame| {
+                assert_eq!(frame.count(), 0);
+                frame.render_widget("abc", frame.area());
+            })
+            .unwrap();
+        assert_eq!(terminal.stats().frames, 1);
+        assert_eq!(terminal.stats().diff_size, 1);
+        assert_eq!(terminal.stats().cells_drawn, 3);
+
+        terminal
+            .draw(|frame| {
+                assert_eq!(frame.count(), 1);
+                frame.render_widget("abd", frame.area());
+            })
+            .unwrap();
+        assert_eq!(terminal.stats().frames, 2);
+        assert_eq!(terminal.stats().cells_drawn, 1);
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn test_frame_limiter() {
+        let mut limiter = FrameLimiter::new(10);
+        assert!(limiter.ready());
+        assert!(!limiter.ready());
+        assert!(limiter.remaining() > Duration::ZERO);
+        limiter.wait();
+        assert!(limiter.remaining() > Duration::from_millis(50));
+    }
+
+    #[test]
+    fn test_poll_event_coalesces_resizes() {
+        use crate::event::KeyCode;
+
+        let mut terminal = Terminal::new(TestBackend::new(10, 5)).unwrap();
+        let backend = terminal.backend_mut();
+        backend.push_event(Event::Resize(8, 4));
+        backend.push_event(Event::Resize(6, 3));
+        backend.push_event(KeyCode::Enter);
+        backend.push_event(Event::Resize(4, 2));
+
+        assert_eq!(terminal.poll_event().unwrap(), Some(Event::Resize(6, 3)));
+        assert_eq!(terminal.poll_event().unwrap(), Some(KeyCode::Enter.into()));
+        assert_eq!(terminal.poll_event().unwrap(), Some(Event::Resize(4, 2)));
+        assert_eq!(terminal.poll_event().unwrap(), None);
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn test_poll_event_debounces_resizes() {
+        let options = TerminalOptions {
+            resize_debounce: Some(Duration::from_millis(20)),
+            ..TerminalOptions::default()
+        };
+        let mut terminal = Terminal::with_options(TestBackend::new(10, 5), options).unwrap();
+        terminal.backend_mut().push_event(Event::Resize(8, 4));
+
+        assert_eq!(terminal.poll_event().unwrap(), None);
+        terminal.backend_mut().push_event(Event::Resize(6, 3));
+        assert_eq!(terminal.poll_event().unwrap(), None);
+
+        std::thread::sleep(Duration::from_millis(25));
+        assert_eq!(terminal.poll_event().unwrap(), Some(Event::Resize(6, 3)));
+        assert_eq!(terminal.poll_event().unwrap(), None);
+    }
+
+    /// A [`TestBackend`] that claims to draw on the process's terminal.
+    struct ProcessBackend(TestBackend);
+
+    impl Backend for ProcessBackend {
+        type Error = <TestBackend as Backend>::Error;
+
+        fn size(&self) -> Result<Rect, Self::Error> {
+            self.0.size()
+        }
+
+        fn clear(&mut self) -> Result<(), Self::Error> {
+            self.0.clear()
+        }
+
+        fn hide_cursor(&mut self) -> Result<(), Self::Error> {
+            self.0.hide_cursor()
+        }
+
+        fn show_cursor(&mut self) -> Result<(), Self::Error> {
+            self.0.show_cursor()
+        }
+
+        fn get_cursor(&mut self) -> Result<crate::geometry::Position, Self::Error> {
+            self.0.get_cursor()
+        }
+
+        fn set_cursor(&mut self, x: u16, y: u16) -> Result<(), Self::Error> {
+            self.0.set_cursor(x, y)
+        }
+
+        fn draw_cell(
+            &mut self,
+            x: u16,
+            y: u16,
+            cell: &crate::buffer::Cell,
+        ) -> Result<(), Self::Error> {
+            self.0.draw_cell(x, y, cell)
+        }
+
+        fn set_style(&mut self, style: crate::style::Style) -> Result<(), Self::Error> {
+            self.0.set_style(style)
+        }
+
+        fn reset_style(&mut self) -> Result<(), Self::Error> {
+            self.0.reset_style()
+        }
+
+        fn flush(&mut self) -> Result<(), Self::Error> {
+            self.0.flush()
+        }
+
+        fn enable_raw_mode(&mut self) -> Result<(), Self::Error> {
+            self.0.enable_raw_mode()
+        }
+
+        fn disable_raw_mode(&mut self) -> Result<(), Self::Error> {
+            self.0.disable_raw_mode()
+        }
+
+        fn enter_alternate_screen(&mut self) -> Result<(), Self::Error> {
+            self.0.enter_alternate_screen()
+        }
+
+        fn leave_alternate_screen(&mut self) -> Result<(), Self::Error> {
+            self.0.leave_alternate_screen()
+        }
+
+        fn is_process_terminal(&self) -> bool {
+            true
+        }
+    }
+
+    #[test]
+    fn test_one_fullscreen_terminal_at_a_time() {
+        let inline = TerminalOptions {
+            viewport: Viewport::Inline(2),
+            ..TerminalOptions::default()
+        };
+        let first = Terminal::try_new(ProcessBackend(TestBackend::new(4, 4))).unwrap();
+        let second = Terminal::try_new(ProcessBackend(TestBackend::new(4, 4)));
+        assert!(matches!(second, Err(TerminalError::InUse)));
+        // Terminals drawing elsewhere or inline are not affected.
+        Terminal::try_new(TestBackend::new(4, 4)).unwrap();
+        Terminal::try_with_options(ProcessBackend(TestBackend::new(4, 4)), inline).unwrap();
+
+        drop(first);
+        Terminal::try_new(ProcessBackend(TestBackend::new(4, 4))).unwrap();
+    }
+
+    #[test]
+    fn test_resume_redraws_everything() {
+        let mut terminal = Terminal::new(TestBackend::new(4, 1)).unwrap();
+        terminal.draw(|f| f.render_widget("ab", f.area())).unwrap();
+        terminal.suspend().unwrap();
+        assert!(terminal.backend().is_cursor_visible());
+        // Something else draws on the screen meanwhile.
+        terminal.backend_mut().buffer_mut().set_string(
+            0,
+            0,
+            "$ fg",
+            crate::style::Style::default(),
+        );
+
+        terminal.resume().unwrap();
+        assert!(!terminal.backend().is_cursor_visible());
+        terminal.draw(|f| f.render_widget("ab", f.area())).unwrap();
+        terminal.backend().assert_buffer_equals("ab");
+    }
+
+    #[test]
+    fn test_inline_viewport() {
+        let options = TerminalOptions {
+            viewport: Viewport::Inline(2),
+            ..TerminalOptions::default()
+        };
+        let mut terminal = Terminal::with_options(TestBackend::new(4, 4), options).unwrap();
+        assert_eq!(terminal.viewport(), Rect::new(0, 2, 4, 2));
+        terminal
+            .draw(|frame| {
+                assert_eq!(frame.area(), Rect::new(0, 2, 4, 2));
+                frame.render_widget("vp", frame.area());
+            })
+            .unwrap();
+
+        terminal
+            .insert_before(3, |buffer| {
+                for (y, line) in (0..).zip(["a", "b", "c"]) {
+                    buffer.set_string(0, y, line, crate::style::Style::default());
+                }
+            })
+            .unwrap();
+        let expected = Buffer::from_lines(&["b   ", "c   ", "vp  ", "    "]);
+        assert_eq!(*terminal.backend().buffer(), expected);
+
+        terminal.backend_mut().resize(4, 3);
+        terminal
+            .draw(|frame| frame.render_widget("new", frame.area()))
+            .unwrap();
+        assert_eq!(terminal.viewport(), Rect::new(0, 1, 4, 2));
+        assert_eq!(terminal.backend().buffer().get(0, 1).unwrap().symbol(), "n");
+    }
+
+    #[test]
+    fn test_settings_reach_widgets() {
+        struct Probe;
+        impl Widget for Probe {
+            fn render(self, area: Rect, buf: &mut Buffer) {
+                let symbol = if buf.settings.ascii_only { "a" } else { "u" };
+                buf.set_string(area.x, area.y, symbol, crate::style::Style::default());
+            }
+        }
+
+        let options = TerminalOptions {
+            settings: UiSettings::new().ascii_only(true),
+            ..TerminalOptions::default()
+        };
+        let mut terminal = Terminal::with_options(TestBackend::new(2, 1), options).unwrap();
+        terminal
+            .draw(|frame| {
+                assert!(frame.settings().ascii_only);
+                frame.render_widget(Probe, frame.area());
+            })
+            .unwrap();
+        terminal.backend().assert_buffer_equals("a ");
+
+        terminal.set_settings(UiSettings::new().reduced_motion(true));
+        terminal
+            .draw(|frame| frame.render_widget(Probe, frame.area()))
+            .unwrap();
+        terminal.backend().assert_buffer_equals("u ");
+        assert!(terminal.settings().reduced_motion);
+    }
 }
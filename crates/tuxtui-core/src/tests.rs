@@ -35,7 +35,7 @@ mod integration {
         buffer.set_string(0, 0, "Test", Style::default().fg(Color::Blue));
 
         let cell = buffer.get(0, 0).unwrap();
-        assert_eq!(cell.symbol, "T");
+        assert_eq!(cell.symbol(), "T");
     }
 
     #[test]
@@ -1,16 +1,48 @@
 //! Rich text primitives for styled terminal content.
 
-use crate::geometry::Alignment;
+use crate::buffer::Buffer;
+use crate::geometry::{Alignment, Rect};
 use crate::style::{Style, Stylize};
+use crate::terminal::{Widget, WidgetRef};
+use crate::util::unicode;
 use alloc::borrow::Cow;
 use alloc::string::{String, ToString};
 use alloc::vec::Vec;
 use core::fmt;
-use unicode_width::UnicodeWidthStr;
 
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
 
+/// Which part of a line is removed when it is too wide.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum Truncate {
+    /// Remove the start of the line
+    Head,
+    /// Remove the middle of the line, keeping both ends
+    Middle,
+    /// Remove the end of the line
+    #[default]
+    Tail,
+}
+
+/// Base direction of a line of text.
+///
+/// Reordering mixed left-to-right and right-to-left text (such as Arabic or
+/// Hebrew) into display order requires the `bidi` feature; without it lines
+/// are always drawn in logical order.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum TextDirection {
+    /// Detect the direction from the first strong character
+    #[default]
+    Auto,
+    /// Left to right
+    LeftToRight,
+    /// Right to left
+    RightToLeft,
+}
+
 /// A styled span of text.
 ///
 /// The most basic text primitive representing a single string with a style.
@@ -54,7 +86,7 @@ impl<'a> Span<'a> {
     /// Get the display width of this span.
     #[must_use]
     pub fn width(&self) -> usize {
-        self.content.width()
+        unicode::width(&self.content)
     }
 
     /// Convert this span to an owned version.
@@ -123,6 +155,9 @@ pub struct Line<'a> {
     pub alignment: Alignment,
     /// Line style applied to all spans
     pub style: Style,
+    /// Base direction used to order bidirectional text
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub direction: TextDirection,
 }
 
 impl<'a> Line<'a> {
@@ -133,6 +168,7 @@ impl<'a> Line<'a> {
             spans: Vec::new(),
             alignment: Alignment::Start,
             style: Style::default(),
+            direction: TextDirection::Auto,
         }
     }
 
@@ -143,6 +179,7 @@ impl<'a> Line<'a> {
             spans,
             alignment: Alignment::Start,
             style: Style::default(),
+            direction: TextDirection::Auto,
         }
     }
 
@@ -153,6 +190,7 @@ impl<'a> Line<'a> {
             spans: alloc::vec![Span::styled(content, style)],
             alignment: Alignment::Start,
             style: Style::default(),
+            direction: TextDirection::Auto,
         }
     }
 
@@ -163,6 +201,92 @@ impl<'a> Line<'a> {
         self
     }
 
+    /// Set the base direction of this line.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use tuxtui_core::text::{Line, TextDirection};
+    ///
+    /// let line = Line::from("שלום").direction(TextDirection::RightToLeft);
+    /// assert_eq!(line.direction, TextDirection::RightToLeft);
+    /// ```
+    #[must_use]
+    pub const fn direction(mut self, direction: TextDirection) -> Self {
+        self.direction = direction;
+        self
+    }
+
+    /// Get this line with its graphemes in display order.
+    ///
+    /// Right-to-left runs are reversed according to the Unicode
+    /// Bidirectional Algorithm when the `bidi` feature is enabled. Lines that
+    /// need no reordering are borrowed.
+    #[must_use]
+    pub fn to_visual(&self) -> Cow<'_, Line<'a>> {
+        if !cfg!(feature = "bidi")
+            || (self.direction != TextDirection::RightToLeft
+                && self.spans.iter().all(|span| span.content.is_ascii()))
+        {
+            return Cow::Borrowed(self);
+        }
+
+        let mut text = String::new();
+        let mut starts = Vec::with_capacity(self.spans.len());
+        for span in &self.spans {
+            starts.push(text.len());
+            text.push_str(&span.content);
+        }
+        let order = crate::util::unicode::visual_graphemes(&text, self.direction);
+        if order.windows(2).all(|pair| pair[0].0 < pair[1].0) {
+            return Cow::Borrowed(self);
+        }
+
+        let mut spans: Vec<Span<'a>> = Vec::new();
+        for (offset, grapheme) in order {
+            let index = starts.partition_point(|&start| start <= offset) - 1;
+            let style = self.spans[index].style;
+            match spans.last_mut() {
+                Some(span) if span.style == style => span.content.to_mut().push_str(grapheme),
+                _ => spans.push(Span::styled(String::from(grapheme), style)),
+            }
+        }
+        Cow::Owned(Line {
+            spans,
+            alignment: self.alignment,
+            style: self.style,
+            direction: self.direction,
+        })
+    }
+
+    /// Align this line to the start (left) of its area.
+    #[must_use]
+    pub fn left_aligned(self) -> Self {
+        self.alignment(Alignment::Start)
+    }
+
+    /// Center this line in its area.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use tuxtui_core::geometry::Alignment;
+    /// use tuxtui_core::text::Line;
+    ///
+    /// let line = Line::from("Title").centered();
+    /// assert_eq!(line.alignment, Alignment::Center);
+    /// ```
+    #[must_use]
+    pub fn centered(self) -> Self {
+        self.alignment(Alignment::Center)
+    }
+
+    /// Align this line to the end (right) of its area.
+    #[must_use]
+    pub fn right_aligned(self) -> Self {
+        self.alignment(Alignment::End)
+    }
+
     /// Get the display width of this line.
     #[must_use]
     pub fn width(&self) -> usize {
@@ -182,59 +306,153 @@ impl<'a> Line<'a> {
     /// ```
     #[must_use]
     pub fn truncate(self, max_width: usize, ellipsis: Option<&str>) -> Line<'a> {
-        use unicode_width::{UnicodeWidthChar, UnicodeWidthStr};
+        self.truncated_with(max_width, Truncate::Tail, ellipsis.unwrap_or("..."))
+    }
+
+    /// Truncate the end of this line to fit within `max_width`, marking the
+    /// cut with "…".
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use tuxtui_core::text::Line;
+    ///
+    /// let line = Line::from("Hello, world").truncated(8);
+    /// assert_eq!(line.to_string(), "Hello, …");
+    /// ```
+    #[must_use]
+    pub fn truncated(self, max_width: usize) -> Line<'a> {
+        self.truncated_with(max_width, Truncate::Tail, "…")
+    }
+
+    /// Truncate this line to fit within `max_width`, replacing the removed
+    /// part with `ellipsis`.
+    ///
+    /// Cuts happen at grapheme boundaries, so wide characters and combining
+    /// sequences are never split. Span styles are kept; the ellipsis takes
+    /// the line's style. If the ellipsis is wider than `max_width`, the line
+    /// is cut without it.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use tuxtui_core::text::{Line, Truncate};
+    ///
+    /// let path = Line::from("/home/user/projects/tuxtui");
+    /// let line = path.clone().truncated_with(14, Truncate::Middle, "…");
+    /// assert_eq!(line.to_string(), "/home/u…tuxtui");
+    ///
+    /// let line = path.truncated_with(10, Truncate::Head, "...");
+    /// assert_eq!(line.to_string(), ".../tuxtui");
+    /// ```
+    #[must_use]
+    pub fn truncated_with(self, max_width: usize, truncate: Truncate, ellipsis: &str) -> Line<'a> {
+        use unicode_segmentation::UnicodeSegmentation;
 
-        let current_width = self.width();
-        if current_width <= max_width {
+        if self.width() <= max_width {
             return self;
         }
 
-        let ellipsis_str = ellipsis.unwrap_or("...");
-        let ellipsis_width = ellipsis_str.width();
+        let graphemes: Vec<(&str, Style, usize)> = self
+            .spans
+            .iter()
+            .flat_map(|span| {
+                span.content
+                    .graphemes(true)
+                    .map(move |g| (g, span.style, unicode::grapheme_width(g)))
+            })
+            .collect();
 
-        if ellipsis_width >= max_width {
-            return Line::from(ellipsis_str.to_string());
-        }
+        let ellipsis_width = unicode::width(ellipsis);
+        let (ellipsis, budget) = if ellipsis_width <= max_width {
+            (ellipsis, max_width - ellipsis_width)
+        } else {
+            ("", max_width)
+        };
 
-        let target_width = max_width.saturating_sub(ellipsis_width);
-        let mut new_spans = alloc::vec::Vec::new();
-        let mut accumulated_width = 0;
-
-        for span in self.spans {
-            let span_width = span.width();
-
-            if accumulated_width + span_width <= target_width {
-                accumulated_width += span_width;
-                new_spans.push(span);
-            } else {
-                let remaining = target_width.saturating_sub(accumulated_width);
-                if remaining > 0 {
-                    let mut truncated = alloc::string::String::new();
-                    let mut w = 0;
-                    for c in span.content.chars() {
-                        let cw = c.width().unwrap_or(0);
-                        if w + cw <= remaining {
-                            truncated.push(c);
-                            w += cw;
-                        } else {
-                            break;
-                        }
-                    }
-                    if !truncated.is_empty() {
-                        new_spans.push(Span::styled(truncated, span.style));
-                    }
-                }
-                break;
+        // Take graphemes from the front while they fit in `budget`.
+        let take_front = |budget: usize| {
+            let mut used = 0;
+            let count = graphemes
+                .iter()
+                .take_while(|(_, _, w)| {
+                    used += w;
+                    used <= budget
+                })
+                .count();
+            let width: usize = graphemes[..count].iter().map(|(_, _, w)| w).sum();
+            (count, width)
+        };
+        // Take graphemes from the back while they fit in `budget`.
+        let take_back = |budget: usize| {
+            let mut used = 0;
+            graphemes
+                .iter()
+                .rev()
+                .take_while(|(_, _, w)| {
+                    used += w;
+                    used <= budget
+                })
+                .count()
+        };
+
+        let (front, back) = match truncate {
+            Truncate::Tail => (take_front(budget).0, 0),
+            Truncate::Head => (0, take_back(budget)),
+            Truncate::Middle => {
+                let (front, front_width) = take_front(budget.div_ceil(2));
+                (front, take_back(budget - front_width))
             }
-        }
+        };
 
-        new_spans.push(Span::raw(ellipsis_str.to_string()));
+        let mut spans: Vec<Span<'a>> = Vec::new();
+        let mut push = |symbol: &str, style: Style| match spans.last_mut() {
+            Some(span) if span.style == style => span.content.to_mut().push_str(symbol),
+            _ => spans.push(Span::styled(String::from(symbol), style)),
+        };
+        for &(g, style, _) in &graphemes[..front] {
+            push(g, style);
+        }
+        if !ellipsis.is_empty() {
+            push(ellipsis, Style::default());
+        }
+        for &(g, style, _) in &graphemes[graphemes.len() - back..] {
+            push(g, style);
+        }
 
         Line {
-            spans: new_spans,
+            spans,
             alignment: self.alignment,
             style: self.style,
+            direction: self.direction,
+        }
+    }
+
+    /// Replace tabs with spaces up to the next tab stop.
+    ///
+    /// Tab stops are every `tab_width` columns from the start of the line,
+    /// across span boundaries. A tab width of zero removes tabs.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use tuxtui_core::text::{Line, Span};
+    ///
+    /// let line = Line::from(vec![Span::raw("a\tb"), Span::raw("\tc")]).expand_tabs(4);
+    /// assert_eq!(line.to_string(), "a   b   c");
+    /// ```
+    #[must_use]
+    pub fn expand_tabs(mut self, tab_width: usize) -> Self {
+        let mut column = 0;
+        for span in &mut self.spans {
+            if let Cow::Owned(expanded) =
+                crate::util::unicode::expand_tabs(&span.content, tab_width, column)
+            {
+                span.content = Cow::Owned(expanded);
+            }
+            column += span.width();
         }
+        self
     }
 
     /// Convert this line to an owned version.
@@ -244,6 +462,7 @@ impl<'a> Line<'a> {
             spans: self.spans.into_iter().map(Span::into_owned).collect(),
             alignment: self.alignment,
             style: self.style,
+            direction: self.direction,
         }
     }
 
@@ -258,6 +477,16 @@ impl<'a> Line<'a> {
     pub fn push_span(&mut self, span: Span<'a>) {
         self.spans.push(span);
     }
+
+    /// Iterate over the spans of this line.
+    pub fn iter(&self) -> core::slice::Iter<'_, Span<'a>> {
+        self.spans.iter()
+    }
+
+    /// Iterate mutably over the spans of this line.
+    pub fn iter_mut(&mut self) -> core::slice::IterMut<'_, Span<'a>> {
+        self.spans.iter_mut()
+    }
 }
 
 impl<'a> Default for Line<'a> {
@@ -399,6 +628,24 @@ impl<'a> Text<'a> {
     pub fn extend_lines(&mut self, lines: impl IntoIterator<Item = Line<'a>>) {
         self.lines.extend(lines);
     }
+
+    /// Push a span onto the last line, starting a line if there is none.
+    pub fn push_span(&mut self, span: Span<'a>) {
+        match self.lines.last_mut() {
+            Some(line) => line.push_span(span),
+            None => self.lines.push(Line::from(span)),
+        }
+    }
+
+    /// Iterate over the lines of this text.
+    pub fn iter(&self) -> core::slice::Iter<'_, Line<'a>> {
+        self.lines.iter()
+    }
+
+    /// Iterate mutably over the lines of this text.
+    pub fn iter_mut(&mut self) -> core::slice::IterMut<'_, Line<'a>> {
+        self.lines.iter_mut()
+    }
 }
 
 impl<'a> Default for Text<'a> {
@@ -458,6 +705,273 @@ impl fmt::Display for Text<'_> {
     }
 }
 
+impl<'a> IntoIterator for Line<'a> {
+    type Item = Span<'a>;
+    type IntoIter = alloc::vec::IntoIter<Span<'a>>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.spans.into_iter()
+    }
+}
+
+impl<'l, 'a> IntoIterator for &'l Line<'a> {
+    type Item = &'l Span<'a>;
+    type IntoIter = core::slice::Iter<'l, Span<'a>>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+impl<'l, 'a> IntoIterator for &'l mut Line<'a> {
+    type Item = &'l mut Span<'a>;
+    type IntoIter = core::slice::IterMut<'l, Span<'a>>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter_mut()
+    }
+}
+
+impl<'a> IntoIterator for Text<'a> {
+    type Item = Line<'a>;
+    type IntoIter = alloc::vec::IntoIter<Line<'a>>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.lines.into_iter()
+    }
+}
+
+impl<'t, 'a> IntoIterator for &'t Text<'a> {
+    type Item = &'t Line<'a>;
+    type IntoIter = core::slice::Iter<'t, Line<'a>>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+impl<'t, 'a> IntoIterator for &'t mut Text<'a> {
+    type Item = &'t mut Line<'a>;
+    type IntoIter = core::slice::IterMut<'t, Line<'a>>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter_mut()
+    }
+}
+
+impl<'a, T: Into<Span<'a>>> Extend<T> for Line<'a> {
+    fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
+        self.spans.extend(iter.into_iter().map(Into::into));
+    }
+}
+
+impl<'a, T: Into<Line<'a>>> Extend<T> for Text<'a> {
+    fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
+        self.lines.extend(iter.into_iter().map(Into::into));
+    }
+}
+
+/// Joins two spans into a line.
+impl<'a> core::ops::Add<Span<'a>> for Span<'a> {
+    type Output = Line<'a>;
+
+    fn add(self, rhs: Span<'a>) -> Line<'a> {
+        Line::from(alloc::vec![self, rhs])
+    }
+}
+
+/// Appends a span to the line.
+impl<'a> core::ops::Add<Span<'a>> for Line<'a> {
+    type Output = Line<'a>;
+
+    fn add(mut self, rhs: Span<'a>) -> Line<'a> {
+        self.push_span(rhs);
+        self
+    }
+}
+
+impl<'a> core::ops::AddAssign<Span<'a>> for Line<'a> {
+    fn add_assign(&mut self, rhs: Span<'a>) {
+        self.push_span(rhs);
+    }
+}
+
+/// Stacks two lines into a text.
+impl<'a> core::ops::Add<Line<'a>> for Line<'a> {
+    type Output = Text<'a>;
+
+    fn add(self, rhs: Line<'a>) -> Text<'a> {
+        Text::from(alloc::vec![self, rhs])
+    }
+}
+
+/// Appends a line to the text.
+impl<'a> core::ops::Add<Line<'a>> for Text<'a> {
+    type Output = Text<'a>;
+
+    fn add(mut self, rhs: Line<'a>) -> Text<'a> {
+        self.push_line(rhs);
+        self
+    }
+}
+
+impl<'a> core::ops::AddAssign<Line<'a>> for Text<'a> {
+    fn add_assign(&mut self, rhs: Line<'a>) {
+        self.push_line(rhs);
+    }
+}
+
+/// Appends the lines of another text; the right-hand text's style is not
+/// carried over.
+impl<'a> core::ops::Add<Text<'a>> for Text<'a> {
+    type Output = Text<'a>;
+
+    fn add(mut self, rhs: Text<'a>) -> Text<'a> {
+        self.lines.extend(rhs.lines);
+        self
+    }
+}
+
+impl<'a> core::ops::AddAssign<Text<'a>> for Text<'a> {
+    fn add_assign(&mut self, rhs: Text<'a>) {
+        self.lines.extend(rhs.lines);
+    }
+}
+
+/// A secret string that displays as a run of mask characters.
+///
+/// The real content is only reachable through [`Masked::value`]; `Debug`,
+/// `Display` and conversions into [`Span`], [`Line`] and [`Text`] all use
+/// the masked form, with one mask character per grapheme.
+///
+/// # Example
+///
+/// ```
+/// use tuxtui_core::text::{Masked, Span};
+///
+/// let password = Masked::new("hunter2", '*');
+/// assert_eq!(password.to_string(), "*******");
+/// assert_eq!(format!("{password:?}"), "Masked(\"*******\")");
+/// assert_eq!(password.value(), "hunter2");
+///
+/// let span = Span::from(password);
+/// assert_eq!(span.content, "*******");
+/// ```
+#[derive(Clone, PartialEq, Eq, Hash)]
+pub struct Masked<'a> {
+    inner: Cow<'a, str>,
+    mask_char: char,
+}
+
+impl<'a> Masked<'a> {
+    /// Create a masked string.
+    #[must_use]
+    pub fn new<T: Into<Cow<'a, str>>>(content: T, mask_char: char) -> Self {
+        Self {
+            inner: content.into(),
+            mask_char,
+        }
+    }
+
+    /// Get the real, unmasked content.
+    #[must_use]
+    pub fn value(&self) -> &str {
+        &self.inner
+    }
+
+    /// Get the mask character.
+    #[must_use]
+    pub const fn mask_char(&self) -> char {
+        self.mask_char
+    }
+
+    /// Get the masked representation.
+    #[must_use]
+    pub fn masked(&self) -> String {
+        use unicode_segmentation::UnicodeSegmentation;
+
+        let count = self.inner.graphemes(true).count();
+        core::iter::repeat_n(self.mask_char, count).collect()
+    }
+}
+
+impl fmt::Debug for Masked<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("Masked").field(&self.masked()).finish()
+    }
+}
+
+impl fmt::Display for Masked<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.masked())
+    }
+}
+
+impl From<Masked<'_>> for Span<'static> {
+    fn from(masked: Masked<'_>) -> Self {
+        Span::raw(masked.masked())
+    }
+}
+
+impl From<&Masked<'_>> for Span<'static> {
+    fn from(masked: &Masked<'_>) -> Self {
+        Span::raw(masked.masked())
+    }
+}
+
+impl From<Masked<'_>> for Line<'static> {
+    fn from(masked: Masked<'_>) -> Self {
+        Line::from(Span::from(masked))
+    }
+}
+
+impl From<Masked<'_>> for Text<'static> {
+    fn from(masked: Masked<'_>) -> Self {
+        Text::from(Line::from(masked))
+    }
+}
+
+/// Renders the span on the first row of the area, clipped to its width.
+impl WidgetRef for Span<'_> {
+    fn render_ref(&self, area: Rect, buf: &mut Buffer) {
+        Line::from(self.clone()).render(area, buf);
+    }
+}
+
+impl Widget for Span<'_> {
+    fn render(self, area: Rect, buf: &mut Buffer) {
+        self.render_ref(area, buf);
+    }
+}
+
+/// Renders the line on the first row of the area using its alignment,
+/// clipped to the area's width.
+impl WidgetRef for Line<'_> {
+    fn render_ref(&self, area: Rect, buf: &mut Buffer) {
+        buf.set_aligned_line(area, self, Style::default());
+    }
+}
+
+impl Widget for Line<'_> {
+    fn render(self, area: Rect, buf: &mut Buffer) {
+        self.render_ref(area, buf);
+    }
+}
+
+/// Renders one line per row, each with its own alignment; lines beyond the
+/// area's height are not drawn.
+impl WidgetRef for Text<'_> {
+    fn render_ref(&self, area: Rect, buf: &mut Buffer) {
+        buf.set_text(area, self);
+    }
+}
+
+impl Widget for Text<'_> {
+    fn render(self, area: Rect, buf: &mut Buffer) {
+        self.render_ref(area, buf);
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -488,4 +1002,134 @@ mod tests {
         let span = Span::raw("test").red().bold();
         assert_eq!(span.style.fg, Some(Color::Red));
     }
+
+    #[test]
+    fn test_truncate_wide_characters() {
+        let line = Line::from("你好世界");
+        assert_eq!(line.clone().truncated(5).to_string(), "你好…");
+        assert_eq!(line.clone().truncated(4).to_string(), "你…");
+        assert_eq!(
+            line.truncated_with(5, Truncate::Middle, "…").to_string(),
+            "你…界"
+        );
+    }
+
+    #[test]
+    fn test_truncate_keeps_styles() {
+        let red = Style::default().fg(Color::Red);
+        let line = Line::from(vec![Span::raw("ab"), Span::styled("cdef", red)]);
+        let truncated = line.truncated_with(4, Truncate::Tail, "~");
+
+        assert_eq!(truncated.to_string(), "abc~");
+        assert_eq!(truncated.spans[1].content, "c");
+        assert_eq!(truncated.spans[1].style, red);
+    }
+
+    #[test]
+    fn test_truncate_without_room_for_ellipsis() {
+        let line = Line::from("abcdef");
+        assert_eq!(
+            line.truncated_with(2, Truncate::Head, "...").to_string(),
+            "ef"
+        );
+    }
+
+    #[test]
+    fn test_truncate_narrow_widths() {
+        let line = Line::from("abcdef");
+        assert_eq!(line.clone().truncate(0, Some("...")).to_string(), "");
+        assert_eq!(line.clone().truncate(2, Some("...")).to_string(), "ab");
+        assert_eq!(line.truncate(3, Some("...")).to_string(), "...");
+    }
+
+    fn row(buf: &Buffer, y: u16) -> String {
+        (0..buf.area.width)
+            .map(|x| buf.get(x, y).unwrap().symbol())
+            .collect()
+    }
+
+    #[test]
+    fn test_composition() {
+        let line = Span::raw("a") + Span::raw("b") + Span::raw("c");
+        assert_eq!(line.spans.len(), 3);
+
+        let mut text = line + Line::from("d");
+        text += Line::from("e");
+        text.push_span(Span::raw("f"));
+        text.extend(["g"]);
+        text += Text::from("h\ni");
+        assert_eq!(text.to_string(), "abc\nd\nef\ng\nh\ni");
+
+        for line in &mut text {
+            for span in line.iter_mut() {
+                span.style = Style::default().fg(Color::Red);
+            }
+        }
+        let spans: usize = text.iter().map(|line| line.iter().count()).sum();
+        assert_eq!(spans, 9);
+        assert!(
+            text.into_iter()
+                .flatten()
+                .all(|span| span.style.fg == Some(Color::Red))
+        );
+    }
+
+    #[cfg(feature = "bidi")]
+    #[test]
+    fn test_visual_order() {
+        let red = Style::default().fg(Color::Red);
+        let line = Line::from(alloc::vec![Span::raw("abc "), Span::styled("שלום", red)]);
+        let visual = line.to_visual();
+        assert_eq!(visual.to_string(), "abc םולש");
+        assert_eq!(visual.spans[1].style, red);
+        let rtl = line.clone().direction(TextDirection::RightToLeft);
+        assert_eq!(rtl.to_visual().to_string(), "םולש abc");
+        assert!(matches!(Line::from("plain").to_visual(), Cow::Borrowed(_)));
+    }
+
+    #[cfg(not(feature = "bidi"))]
+    #[test]
+    fn test_logical_order_without_bidi() {
+        let line = Line::from("abc שלום").direction(TextDirection::RightToLeft);
+        assert!(matches!(line.to_visual(), Cow::Borrowed(_)));
+    }
+
+    #[test]
+    fn test_masked_hides_content() {
+        let masked = Masked::new("päss👍", '•');
+        assert_eq!(masked.masked(), "•••••");
+        assert!(!format!("{masked:?}").contains("päss"));
+
+        let text = Text::from(masked.clone());
+        assert_eq!(text.to_string(), "•••••");
+        assert_eq!(masked.value(), "päss👍");
+    }
+
+    #[test]
+    fn test_line_widget_alignment() {
+        let mut buf = Buffer::empty(Rect::new(0, 0, 7, 3));
+        Line::from("ab").render(Rect::new(0, 0, 7, 1), &mut buf);
+        Line::from("ab")
+            .centered()
+            .render(Rect::new(0, 1, 7, 1), &mut buf);
+        Line::from("abcdefghij")
+            .right_aligned()
+            .render(Rect::new(0, 2, 7, 1), &mut buf);
+
+        assert_eq!(row(&buf, 0), "ab     ");
+        assert_eq!(row(&buf, 1), "  ab   ");
+        assert_eq!(row(&buf, 2), "abcdefg");
+    }
+
+    #[test]
+    fn test_text_widget() {
+        let red = Style::default().fg(Color::Red);
+        let text = Text::styled("one\ntwo\nthree", red);
+        let mut buf = Buffer::empty(Rect::new(0, 0, 3, 2));
+        (&text).render(buf.area, &mut buf);
+
+        assert_eq!(row(&buf, 0), "one");
+        assert_eq!(row(&buf, 1), "two");
+        assert_eq!(buf.get(0, 1).unwrap().style.fg, Some(Color::Red));
+    }
 }
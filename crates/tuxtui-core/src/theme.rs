@@ -1,5 +1,6 @@
 //! Theme system for consistent styling across widgets.
 
+use crate::backend::Backend;
 use crate::style::{Color, Style};
 use alloc::string::String;
 
@@ -80,6 +81,41 @@ impl Theme {
         }
     }
 
+    /// Pick [`Theme::light`] or [`Theme::dark`] to match the terminal's
+    /// background.
+    ///
+    /// Falls back to the dark theme if the backend cannot report the
+    /// background color.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use tuxtui_core::backend::TestBackend;
+    /// use tuxtui_core::style::Color;
+    /// use tuxtui_core::theme::Theme;
+    ///
+    /// let mut backend = TestBackend::new(80, 24);
+    /// backend.set_default_colors(None, Some(Color::Rgb(250, 250, 240)));
+    /// assert_eq!(Theme::auto(&mut backend).name, "light");
+    /// ```
+    #[must_use]
+    pub fn auto<B: Backend>(backend: &mut B) -> Self {
+        match backend.background_color() {
+            Ok(Some(background)) => Self::for_background(background),
+            _ => Self::dark(),
+        }
+    }
+
+    /// Pick [`Theme::light`] or [`Theme::dark`] for a background color.
+    #[must_use]
+    pub fn for_background(background: Color) -> Self {
+        if Color::best_text_on(background) == Color::Black {
+            Self::light()
+        } else {
+            Self::dark()
+        }
+    }
+
     /// Create a high-contrast theme.
     #[must_use]
     pub fn high_contrast() -> Self {
@@ -147,6 +183,94 @@ impl Default for PaletteTheme {
     }
 }
 
+#[cfg(feature = "palette")]
+impl PaletteTheme {
+    /// Derive a whole palette from a single accent color.
+    ///
+    /// Light accents get a dark background and dark accents a light one,
+    /// both tinted with the accent's hue. Every other color is adjusted until
+    /// it meets the WCAG contrast ratio for text against the background
+    /// (4.5:1, or 3:1 for the muted color).
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use tuxtui_core::style::Color;
+    /// use tuxtui_core::theme::PaletteTheme;
+    ///
+    /// let palette = PaletteTheme::from_accent(Color::Rgb(255, 136, 0));
+    /// assert_eq!(palette.primary, Color::Rgb(255, 136, 0));
+    /// ```
+    #[must_use]
+    pub fn from_accent(accent: Color) -> Self {
+        use palette::{FromColor, Hsl, Srgb};
+
+        let Some((r, g, b)) = accent.to_rgb() else {
+            return Self::default();
+        };
+        let base = Hsl::from_color(Srgb::new(r, g, b).into_format::<f32>());
+        let hue = base.hue.into_degrees();
+        let dark = base.lightness >= 0.5;
+        let shade = |dark_lightness: f32, light_lightness: f32| {
+            if dark {
+                dark_lightness
+            } else {
+                light_lightness
+            }
+        };
+        // Status colors keep some of the accent's vividness.
+        let vivid = base.saturation.clamp(0.5, 0.8);
+
+        let background = Hsl::new(hue, base.saturation * 0.3, shade(0.1, 0.96));
+        let text = |hue: f32, saturation: f32, lightness: f32, ratio: f32| {
+            contrasting(
+                Hsl::new(hue, saturation, lightness),
+                background,
+                dark,
+                ratio,
+            )
+        };
+        Self {
+            background: to_color(background),
+            foreground: text(hue, base.saturation * 0.15, shade(0.9, 0.12), 4.5),
+            primary: accent,
+            secondary: text(hue + 30.0, base.saturation, base.lightness, 4.5),
+            accent,
+            error: text(0.0, vivid, 0.5, 4.5),
+            warning: text(40.0, vivid, 0.5, 4.5),
+            success: text(130.0, vivid, 0.45, 4.5),
+            muted: text(hue, base.saturation * 0.1, 0.5, 3.0),
+        }
+    }
+}
+
+/// Move `color` away from `background` until their contrast is at least
+/// `ratio`.
+#[cfg(feature = "palette")]
+fn contrasting(mut color: palette::Hsl, background: palette::Hsl, dark: bool, ratio: f32) -> Color {
+    use palette::color_difference::Wcag21RelativeContrast;
+    use palette::{FromColor, Srgb};
+
+    let background = Srgb::from_color(background);
+    let step = if dark { 0.02 } else { -0.02 };
+    while Srgb::from_color(color).relative_contrast(background) < ratio
+        && (0.0..=1.0).contains(&(color.lightness + step))
+    {
+        color.lightness += step;
+    }
+    to_color(color)
+}
+
+#[cfg(feature = "palette")]
+fn to_color(color: palette::Hsl) -> Color {
+    use palette::{FromColor, Srgb};
+
+    let (r, g, b) = Srgb::from_color(color)
+        .into_format::<u8>()
+        .into_components();
+    Color::Rgb(r, g, b)
+}
+
 /// Widget-specific theme styles.
 #[derive(Debug, Clone, PartialEq, Eq)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
@@ -192,6 +316,63 @@ mod tests {
         assert_eq!(theme.palette.background, Color::White);
     }
 
+    #[test]
+    fn test_theme_for_background() {
+        assert_eq!(Theme::for_background(Color::White).name, "light");
+        assert_eq!(Theme::for_background(Color::Rgb(40, 44, 52)).name, "dark");
+        assert_eq!(Theme::for_background(Color::Indexed(231)).name, "light");
+        assert_eq!(Theme::for_background(Color::Reset).name, "dark");
+
+        let mut backend = crate::backend::TestBackend::new(1, 1);
+        assert_eq!(Theme::auto(&mut backend).name, "dark");
+        backend.set_default_colors(Some(Color::Black), Some(Color::LightGray));
+        assert_eq!(Theme::auto(&mut backend).name, "light");
+    }
+
+    #[cfg(feature = "palette")]
+    #[test]
+    fn test_palette_from_accent() {
+        use palette::Srgb;
+        use palette::color_difference::Wcag21RelativeContrast;
+
+        let srgb = |color: Color| {
+            let (r, g, b) = color.to_rgb().unwrap();
+            Srgb::new(r, g, b).into_format::<f32>()
+        };
+        for accent in [
+            Color::Rgb(255, 136, 0),
+            Color::Rgb(20, 40, 120),
+            Color::Blue,
+        ] {
+            let palette = PaletteTheme::from_accent(accent);
+            assert_eq!(palette.primary, accent);
+            let background = srgb(palette.background);
+            for color in [
+                palette.foreground,
+                palette.secondary,
+                palette.error,
+                palette.warning,
+                palette.success,
+            ] {
+                assert!(
+                    srgb(color).relative_contrast(background) >= 4.5,
+                    "{color:?}"
+                );
+            }
+            assert!(srgb(palette.muted).relative_contrast(background) >= 3.0);
+        }
+        // Light accents get a dark background, and dark accents a light one.
+        let (r, ..) = PaletteTheme::from_accent(Color::Rgb(255, 136, 0))
+            .background
+            .to_rgb()
+            .unwrap();
+        assert!(r < 64);
+        assert_eq!(
+            PaletteTheme::from_accent(Color::Reset),
+            PaletteTheme::default()
+        );
+    }
+
     #[cfg(feature = "serde")]
     #[test]
     fn test_theme_serialization() {
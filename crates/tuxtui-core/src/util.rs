@@ -1,5 +1,8 @@
 //! Utility functions and helpers.
 
+pub mod unicode;
+
+#[cfg(test)]
 use unicode_width::UnicodeWidthStr;
 
 /// Calculate the display width of a string, respecting grapheme clusters.
@@ -14,7 +17,7 @@ use unicode_width::UnicodeWidthStr;
 /// ```
 #[must_use]
 pub fn string_width(s: &str) -> usize {
-    s.width()
+    unicode::width(s)
 }
 
 /// Truncate a string to fit within a given width, adding an ellipsis if needed.
@@ -29,9 +32,9 @@ pub fn string_width(s: &str) -> usize {
 /// ```
 #[must_use]
 pub fn truncate_string(s: &str, max_width: usize) -> alloc::string::String {
-    let width = s.width();
+    let width = unicode::width(s);
     if width <= max_width {
-        return s.to_string();
+        return alloc::string::String::from(s);
     }
 
     if max_width < 3 {
@@ -42,8 +45,7 @@ pub fn truncate_string(s: &str, max_width: usize) -> alloc::string::String {
     let mut current_width = 0;
     let target_width = max_width - 3; // Reserve space for "..."
 
-    for grapheme in unicode_segmentation::UnicodeSegmentation::graphemes(s, true) {
-        let grapheme_width = grapheme.width();
+    for (grapheme, grapheme_width) in unicode::graphemes_with_width(s) {
         if current_width + grapheme_width > target_width {
             break;
         }
@@ -65,7 +67,7 @@ pub fn wrap_text(text: &str, width: usize) -> alloc::vec::Vec<alloc::string::Str
     let mut current_width = 0;
 
     for word in text.split_whitespace() {
-        let word_width = word.width();
+        let word_width = unicode::width(word);
 
         if current_width + word_width + 1 > width && !current_line.is_empty() {
             lines.push(current_line);
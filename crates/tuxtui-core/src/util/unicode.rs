@@ -0,0 +1,378 @@
+//! Display-width aware string helpers.
+//!
+//! Terminal cells hold one grapheme cluster each, and a cluster takes at
+//! most two cells. These helpers measure and slice strings by grapheme
+//! cluster, so emoji sequences joined with a zero-width joiner (ZWJ), flags,
+//! skin-tone modifiers and combining marks are never split.
+//!
+//! # Example
+//!
+//! ```
+//! use tuxtui_core::util::unicode::{pad_to_width, slice_by_width, truncate_to_width, width};
+//! use tuxtui_core::geometry::Alignment;
+//!
+//! assert_eq!(width("👨‍👩‍👧 family"), 9);
+//! assert_eq!(truncate_to_width("你好世界", 5), "你好");
+//! assert_eq!(slice_by_width("hello world", 6..11), "world");
+//! assert_eq!(pad_to_width("hi", 6, Alignment::Center), "  hi  ");
+//! ```
+
+use crate::geometry::Alignment;
+use crate::text::TextDirection;
+use alloc::borrow::Cow;
+use alloc::string::String;
+use alloc::vec::Vec;
+use core::ops::Range;
+use unicode_segmentation::UnicodeSegmentation;
+use unicode_width::{UnicodeWidthChar, UnicodeWidthStr};
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// The zero-width joiner used to build emoji sequences.
+pub const ZWJ: char = '\u{200D}';
+
+/// Get the display width of a single grapheme cluster.
+///
+/// Clusters are capped at two cells, which is what terminals draw for
+/// emoji sequences such as `👨‍👩‍👧` or `🇺🇸`.
+#[must_use]
+pub fn grapheme_width(grapheme: &str) -> usize {
+    grapheme.width().min(2)
+}
+
+/// How the width of grapheme clusters is measured when writing to a
+/// [`Buffer`](crate::buffer::Buffer).
+///
+/// Terminals disagree on emoji: modern ones draw a cluster such as `❤️`
+/// (with variation selector 16), `🇺🇸` or `👨‍👩‍👧` in two cells, while
+/// terminals measuring each code point with `wcwidth` draw `❤️` in one cell
+/// and `👨‍👩‍👧` in six. Pick the policy matching the terminal so that the
+/// text after such clusters lines up.
+///
+/// # Example
+///
+/// ```
+/// use tuxtui_core::util::unicode::WidthPolicy;
+///
+/// assert_eq!(WidthPolicy::Unicode.grapheme_width("❤\u{fe0f}"), 2);
+/// assert_eq!(WidthPolicy::CodePoint.grapheme_width("❤\u{fe0f}"), 1);
+/// assert_eq!(WidthPolicy::CodePoint.width("👨‍👩‍👧"), 6);
+/// ```
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum WidthPolicy {
+    /// Measure clusters as Unicode specifies, capped at two cells, like
+    /// [`grapheme_width`]
+    #[default]
+    Unicode,
+    /// Add up the widths of the code points of a cluster, ignoring emoji
+    /// presentation, like terminals using `wcwidth`
+    CodePoint,
+}
+
+impl WidthPolicy {
+    /// Get the display width of a single grapheme cluster.
+    #[must_use]
+    pub fn grapheme_width(self, grapheme: &str) -> usize {
+        match self {
+            Self::Unicode => grapheme_width(grapheme),
+            Self::CodePoint => grapheme.chars().filter_map(UnicodeWidthChar::width).sum(),
+        }
+    }
+
+    /// Get the display width of a string, measured per grapheme cluster.
+    #[must_use]
+    pub fn width(self, s: &str) -> usize {
+        s.graphemes(true).map(|g| self.grapheme_width(g)).sum()
+    }
+}
+
+/// Check if terminals may disagree on the width of a grapheme cluster, as
+/// the [width policies](WidthPolicy) measure it differently.
+///
+/// Backends move the cursor explicitly after drawing such a cluster, so
+/// that a terminal drawing it wider or narrower than expected cannot shift
+/// what follows.
+#[must_use]
+pub fn is_ambiguous_width(grapheme: &str) -> bool {
+    !grapheme.is_ascii()
+        && WidthPolicy::Unicode.grapheme_width(grapheme)
+            != WidthPolicy::CodePoint.grapheme_width(grapheme)
+}
+
+/// Get the display width of a string, measured per grapheme cluster.
+#[must_use]
+pub fn width(s: &str) -> usize {
+    s.graphemes(true).map(grapheme_width).sum()
+}
+
+/// Iterate over the grapheme clusters of a string with their widths.
+pub fn graphemes_with_width(s: &str) -> impl Iterator<Item = (&str, usize)> {
+    s.graphemes(true).map(|g| (g, grapheme_width(g)))
+}
+
+/// Check if a grapheme cluster is an emoji sequence joined with a ZWJ.
+#[must_use]
+pub fn is_zwj_sequence(grapheme: &str) -> bool {
+    grapheme.chars().count() > 1 && grapheme.contains(ZWJ)
+}
+
+/// Get the longest prefix of `s` that fits in `max_width` columns.
+///
+/// Wide graphemes that would straddle the limit are left out.
+#[must_use]
+pub fn truncate_to_width(s: &str, max_width: usize) -> &str {
+    let mut used = 0;
+    for (offset, grapheme) in s.grapheme_indices(true) {
+        used += grapheme_width(grapheme);
+        if used > max_width {
+            return &s[..offset];
+        }
+    }
+    s
+}
+
+/// Pad `s` with spaces to `width` columns, aligned as requested.
+///
+/// Strings that are already wide enough are returned unchanged.
+#[must_use]
+pub fn pad_to_width(s: &str, width: usize, alignment: Alignment) -> Cow<'_, str> {
+    let current = self::width(s);
+    if current >= width {
+        return Cow::Borrowed(s);
+    }
+    let padding = width - current;
+    let (left, right) = match alignment {
+        Alignment::Start => (0, padding),
+        Alignment::Center => (padding / 2, padding - padding / 2),
+        Alignment::End => (padding, 0),
+    };
+    let mut padded = String::with_capacity(s.len() + padding);
+    padded.extend(core::iter::repeat_n(' ', left));
+    padded.push_str(s);
+    padded.extend(core::iter::repeat_n(' ', right));
+    Cow::Owned(padded)
+}
+
+/// Replace tab characters with spaces up to the next tab stop.
+///
+/// Tab stops are every `tab_width` columns, counted from the column before
+/// `s`, `start_column`. A tab width of zero removes tabs. Strings without
+/// tabs are borrowed.
+///
+/// # Example
+///
+/// ```
+/// use tuxtui_core::util::unicode::expand_tabs;
+///
+/// assert_eq!(expand_tabs("a\tbc\td", 4, 0), "a   bc  d");
+/// ```
+#[must_use]
+pub fn expand_tabs(s: &str, tab_width: usize, start_column: usize) -> Cow<'_, str> {
+    if !s.contains('\t') {
+        return Cow::Borrowed(s);
+    }
+    let mut expanded = String::with_capacity(s.len());
+    let mut column = start_column;
+    for (grapheme, width) in graphemes_with_width(s) {
+        if grapheme == "\t" {
+            if tab_width > 0 {
+                let spaces = tab_width - column % tab_width;
+                expanded.extend(core::iter::repeat_n(' ', spaces));
+                column += spaces;
+            }
+        } else {
+            expanded.push_str(grapheme);
+            column += width;
+        }
+    }
+    Cow::Owned(expanded)
+}
+
+/// Get the part of `s` that lies in the column range `columns`.
+///
+/// Only graphemes that fit entirely inside the range are included, so a
+/// wide grapheme cut by either edge is dropped.
+#[must_use]
+pub fn slice_by_width(s: &str, columns: Range<usize>) -> &str {
+    let mut start = None;
+    let mut end = s.len();
+    let mut column = 0;
+    for (offset, grapheme) in s.grapheme_indices(true) {
+        let next = column + grapheme_width(grapheme);
+        if start.is_none() && column >= columns.start {
+            start = Some(offset);
+        }
+        if next > columns.end {
+            end = offset;
+            break;
+        }
+        column = next;
+    }
+    match start {
+        Some(start) if start <= end => &s[start..end],
+        _ => "",
+    }
+}
+
+/// Iterate over the words of a string, skipping whitespace and punctuation.
+pub fn words(s: &str) -> impl Iterator<Item = &str> {
+    s.unicode_words()
+}
+
+/// Split a string at word boundaries.
+///
+/// Unlike [`words`], the whitespace and punctuation between words are
+/// yielded too, so the pieces concatenate back to `s`.
+pub fn word_bounds(s: &str) -> impl Iterator<Item = &str> {
+    s.split_word_bounds()
+}
+
+#[cfg(feature = "bidi")]
+fn bidi_level(direction: TextDirection) -> Option<unicode_bidi::Level> {
+    match direction {
+        TextDirection::Auto => None,
+        TextDirection::LeftToRight => Some(unicode_bidi::Level::ltr()),
+        TextDirection::RightToLeft => Some(unicode_bidi::Level::rtl()),
+    }
+}
+
+/// Check if `s` resolves to a right-to-left base direction.
+///
+/// With [`TextDirection::Auto`], the first strong character decides; this
+/// needs the `bidi` feature and is otherwise always left to right.
+#[must_use]
+pub fn is_rtl(s: &str, direction: TextDirection) -> bool {
+    match direction {
+        TextDirection::LeftToRight => false,
+        TextDirection::RightToLeft => true,
+        #[cfg(feature = "bidi")]
+        TextDirection::Auto => unicode_bidi::ParagraphBidiInfo::new(s, None)
+            .paragraph_level
+            .is_rtl(),
+        #[cfg(not(feature = "bidi"))]
+        TextDirection::Auto => {
+            let _ = s;
+            false
+        }
+    }
+}
+
+/// Get the graphemes of `s` in display order, with their byte offsets.
+///
+/// Right-to-left runs are reversed following the Unicode Bidirectional
+/// Algorithm when the `bidi` feature is enabled; otherwise graphemes are
+/// returned in logical order.
+#[must_use]
+pub fn visual_graphemes(s: &str, direction: TextDirection) -> Vec<(usize, &str)> {
+    #[cfg(feature = "bidi")]
+    {
+        let info = unicode_bidi::ParagraphBidiInfo::new(s, bidi_level(direction));
+        if info.levels.iter().any(|level| level.is_rtl()) {
+            let (levels, runs) = info.visual_runs(0..s.len());
+            let mut graphemes = Vec::new();
+            for run in runs {
+                let start = run.start;
+                let run_graphemes = s[run].grapheme_indices(true);
+                if levels[start].is_rtl() {
+                    graphemes.extend(run_graphemes.rev().map(|(i, g)| (start + i, g)));
+                } else {
+                    graphemes.extend(run_graphemes.map(|(i, g)| (start + i, g)));
+                }
+            }
+            return graphemes;
+        }
+    }
+    #[cfg(not(feature = "bidi"))]
+    let _ = direction;
+    s.grapheme_indices(true).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_emoji_widths() {
+        assert_eq!(width("👨‍👩‍👧"), 2);
+        assert_eq!(width("🇺🇸"), 2);
+        assert_eq!(width("👍🏽"), 2);
+        assert_eq!(width("e\u{301}"), 1);
+        assert!(is_zwj_sequence("👨‍👩‍👧"));
+        assert!(!is_zwj_sequence("👍🏽"));
+    }
+
+    #[test]
+    fn test_width_policies() {
+        // (grapheme, Unicode width, code point width)
+        let corpus = [
+            ("a", 1, 1),
+            ("你", 2, 2),
+            ("e\u{301}", 1, 1),
+            ("❤", 1, 1),
+            ("❤\u{fe0f}", 2, 1),
+            ("#\u{fe0f}\u{20e3}", 2, 1),
+            ("🇺🇸", 2, 2),
+            ("🇺", 1, 1),
+            ("👍🏽", 2, 4),
+            ("👩\u{200d}💻", 2, 4),
+            ("👨\u{200d}👩\u{200d}👧", 2, 6),
+            ("🏳\u{fe0f}\u{200d}🌈", 2, 3),
+            ("\u{fe0f}", 0, 0),
+        ];
+        for (grapheme, unicode, code_point) in corpus {
+            assert_eq!(grapheme.graphemes(true).count(), 1, "{grapheme:?}");
+            assert_eq!(WidthPolicy::Unicode.grapheme_width(grapheme), unicode);
+            assert_eq!(WidthPolicy::CodePoint.grapheme_width(grapheme), code_point);
+            assert_eq!(is_ambiguous_width(grapheme), unicode != code_point);
+        }
+        assert_eq!(WidthPolicy::CodePoint.width("🇺🇸🇬🇧 ok"), 7);
+    }
+
+    #[test]
+    fn test_truncate_to_width() {
+        assert_eq!(truncate_to_width("hello", 10), "hello");
+        assert_eq!(truncate_to_width("hello", 3), "hel");
+        assert_eq!(truncate_to_width("你好", 3), "你");
+        assert_eq!(truncate_to_width("👨‍👩‍👧!", 2), "👨‍👩‍👧");
+    }
+
+    #[test]
+    fn test_pad_to_width() {
+        assert_eq!(pad_to_width("ab", 4, Alignment::Start), "ab  ");
+        assert_eq!(pad_to_width("ab", 5, Alignment::Center), " ab  ");
+        assert_eq!(pad_to_width("你", 3, Alignment::End), " 你");
+        assert!(matches!(
+            pad_to_width("long", 2, Alignment::End),
+            Cow::Borrowed("long")
+        ));
+    }
+
+    #[test]
+    fn test_expand_tabs() {
+        assert!(matches!(expand_tabs("no tabs", 4, 0), Cow::Borrowed(_)));
+        assert_eq!(expand_tabs("\tx", 4, 0), "    x");
+        assert_eq!(expand_tabs("\tx", 4, 3), " x");
+        assert_eq!(expand_tabs("你\tx", 8, 0), "你      x");
+        assert_eq!(expand_tabs("a\tb", 0, 0), "ab");
+    }
+
+    #[test]
+    fn test_slice_by_width() {
+        assert_eq!(slice_by_width("abcdef", 2..4), "cd");
+        assert_eq!(slice_by_width("abcdef", 4..10), "ef");
+        assert_eq!(slice_by_width("你好世界", 1..6), "好世");
+        assert_eq!(slice_by_width("abc", 5..8), "");
+    }
+
+    #[test]
+    fn test_word_segmentation() {
+        let text = "Hello, wide 世界!";
+        assert_eq!(
+            words(text).collect::<Vec<_>>(),
+            ["Hello", "wide", "世", "界"]
+        );
+        assert_eq!(word_bounds(text).collect::<String>(), text);
+    }
+}
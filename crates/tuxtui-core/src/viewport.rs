@@ -1,5 +1,7 @@
 //! Viewport state management for scrollable content.
 
+use crate::event::{Event, MouseEventKind};
+
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
 
@@ -65,6 +67,28 @@ impl ViewportState {
         self
     }
 
+    /// Get the total content length.
+    #[must_use]
+    pub const fn content_len(&self) -> usize {
+        self.content_length
+    }
+
+    /// Get the viewport height.
+    #[must_use]
+    pub const fn viewport_len(&self) -> usize {
+        self.viewport_height
+    }
+
+    /// Set the total content length.
+    pub const fn set_content_length(&mut self, length: usize) {
+        self.content_length = length;
+    }
+
+    /// Set the viewport height.
+    pub const fn set_viewport_height(&mut self, height: usize) {
+        self.viewport_height = height;
+    }
+
     /// Get the current offset.
     #[must_use]
     pub const fn offset(&self) -> usize {
@@ -72,8 +96,15 @@ impl ViewportState {
     }
 
     /// Set the offset directly.
+    ///
+    /// The offset is clamped to the content once its length is known, so
+    /// widgets can be scrolled before they are first rendered.
     pub fn set_offset(&mut self, offset: usize) {
-        self.offset = offset.min(self.max_offset());
+        self.offset = if self.content_length == 0 {
+            offset
+        } else {
+            offset.min(self.max_offset())
+        };
     }
 
     /// Get the maximum possible offset.
@@ -106,6 +137,43 @@ impl ViewportState {
         self.offset = self.offset.saturating_sub(self.viewport_height);
     }
 
+    /// Scroll in response to a mouse wheel event.
+    ///
+    /// Each wheel tick moves the viewport by `lines_per_tick` lines, or by
+    /// a page while shift is held. Returns `true` if the event was a
+    /// vertical wheel event, whether or not the offset changed, so callers
+    /// can stop propagating it.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use tuxtui_core::event::{Event, MouseEvent, MouseEventKind};
+    /// use tuxtui_core::viewport::ViewportState;
+    ///
+    /// let mut viewport = ViewportState::new().content_length(100).viewport_height(10);
+    /// let wheel = Event::Mouse(MouseEvent::new(MouseEventKind::ScrollDown, 0, 0));
+    /// assert!(viewport.handle_scroll(&wheel, 3));
+    /// assert_eq!(viewport.offset(), 3);
+    /// ```
+    pub fn handle_scroll(&mut self, event: &Event, lines_per_tick: usize) -> bool {
+        let Event::Mouse(mouse) = event else {
+            return false;
+        };
+        let lines = if mouse.modifiers.shift {
+            self.viewport_height.max(1)
+        } else {
+            lines_per_tick
+        };
+        match mouse.kind {
+            MouseEventKind::ScrollDown => {
+                self.offset = self.offset.saturating_add(lines).min(self.max_offset());
+            }
+            MouseEventKind::ScrollUp => self.offset = self.offset.saturating_sub(lines),
+            _ => return false,
+        }
+        true
+    }
+
     /// Scroll to the top.
     pub fn scroll_to_top(&mut self) {
         self.offset = 0;
@@ -152,12 +220,40 @@ impl ViewportState {
         self.select(Some(prev));
     }
 
+    /// Select the item a page below the selection, stopping at the last.
+    pub fn select_next_page(&mut self) {
+        if self.content_length == 0 {
+            return;
+        }
+        let page = self.viewport_height.max(1);
+        let next = self
+            .selected
+            .map_or(0, |i| i.saturating_add(page))
+            .min(self.content_length - 1);
+        self.select(Some(next));
+    }
+
+    /// Select the item a page above the selection, stopping at the first.
+    pub fn select_previous_page(&mut self) {
+        if self.content_length == 0 {
+            return;
+        }
+        let page = self.viewport_height.max(1);
+        let previous = self.selected.map_or(0, |i| i.saturating_sub(page));
+        self.select(Some(previous));
+    }
+
     /// Ensure the given index is visible in the viewport.
+    ///
+    /// Does nothing while the viewport height is unknown.
     pub fn ensure_visible(&mut self, index: usize) {
+        if self.viewport_height == 0 {
+            return;
+        }
         if index < self.offset {
             self.offset = index;
         } else if index >= self.offset + self.viewport_height {
-            self.offset = index.saturating_sub(self.viewport_height - 1);
+            self.offset = index + 1 - self.viewport_height;
         }
     }
 
@@ -239,4 +335,48 @@ mod tests {
         viewport.page_up();
         assert_eq!(viewport.offset(), 10);
     }
+
+    #[test]
+    fn test_viewport_select_page() {
+        let mut viewport = ViewportState::new();
+        viewport.set_offset(30);
+        viewport.select(Some(40));
+        assert_eq!(viewport.offset(), 30);
+
+        viewport.set_content_length(45);
+        viewport.set_viewport_height(10);
+        assert_eq!((viewport.content_len(), viewport.viewport_len()), (45, 10));
+        viewport.select_next_page();
+        assert_eq!(viewport.selected(), Some(44));
+        assert_eq!(viewport.offset(), 35);
+        viewport.select_previous_page();
+        viewport.select_previous_page();
+        assert_eq!(viewport.selected(), Some(24));
+        assert_eq!(viewport.offset(), 24);
+
+        viewport.set_offset(100);
+        assert_eq!(viewport.offset(), 35);
+    }
+
+    #[test]
+    fn test_viewport_handle_scroll() {
+        use crate::event::{KeyCode, KeyModifiers, MouseEvent};
+
+        let mut viewport = ViewportState::new().content_length(20).viewport_height(10);
+        let wheel = |kind| Event::Mouse(MouseEvent::new(kind, 0, 0));
+
+        assert!(viewport.handle_scroll(&wheel(MouseEventKind::ScrollDown), 3));
+        assert_eq!(viewport.offset(), 3);
+        assert!(viewport.handle_scroll(&wheel(MouseEventKind::ScrollDown), 30));
+        assert_eq!(viewport.offset(), 10);
+        assert!(viewport.handle_scroll(&wheel(MouseEventKind::ScrollUp), 3));
+        assert_eq!(viewport.offset(), 7);
+
+        let paged = MouseEvent::new(MouseEventKind::ScrollUp, 0, 0).modifiers(KeyModifiers::SHIFT);
+        assert!(viewport.handle_scroll(&Event::Mouse(paged), 1));
+        assert_eq!(viewport.offset(), 0);
+
+        assert!(!viewport.handle_scroll(&wheel(MouseEventKind::ScrollLeft), 3));
+        assert!(!viewport.handle_scroll(&KeyCode::Down.into(), 3));
+    }
 }
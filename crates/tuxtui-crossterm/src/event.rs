@@ -0,0 +1,170 @@
+//! Conversion of crossterm input events to the tuxtui event model.
+
+use crossterm::event as ct;
+use tuxtui_core::event::{
+    Event, KeyCode, KeyEvent, KeyEventKind, KeyModifiers, MouseButton, MouseEvent, MouseEventKind,
+};
+
+/// Convert a crossterm event.
+///
+/// Returns `None` for keys without a tuxtui equivalent, such as media keys.
+///
+/// # Example
+///
+/// ```
+/// use crossterm::event::Event as CrosstermEvent;
+/// use tuxtui_core::event::Event;
+/// use tuxtui_crossterm::event::event;
+///
+/// assert_eq!(event(CrosstermEvent::Resize(80, 24)), Some(Event::Resize(80, 24)));
+/// ```
+#[must_use]
+pub fn event(event: ct::Event) -> Option<Event> {
+    Some(match event {
+        ct::Event::Key(key) => Event::Key(key_event(key)?),
+        ct::Event::Mouse(mouse) => Event::Mouse(mouse_event(mouse)),
+        ct::Event::Resize(width, height) => Event::Resize(width, height),
+        ct::Event::FocusGained => Event::FocusGained,
+        ct::Event::FocusLost => Event::FocusLost,
+        ct::Event::Paste(text) => Event::Paste(text),
+    })
+}
+
+/// Convert a crossterm key press, repeat or release.
+///
+/// Crossterm reports repeats and releases on Windows, and elsewhere once
+/// the kitty keyboard protocol is enabled with
+/// `PushKeyboardEnhancementFlags(KeyboardEnhancementFlags::REPORT_EVENT_TYPES)`.
+/// Returns `None` for keys without a tuxtui equivalent.
+#[must_use]
+pub const fn key_event(event: ct::KeyEvent) -> Option<KeyEvent> {
+    let kind = match event.kind {
+        ct::KeyEventKind::Press => KeyEventKind::Press,
+        ct::KeyEventKind::Repeat => KeyEventKind::Repeat,
+        ct::KeyEventKind::Release => KeyEventKind::Release,
+    };
+    let code = match event.code {
+        ct::KeyCode::Char(c) => KeyCode::Char(c),
+        ct::KeyCode::Enter => KeyCode::Enter,
+        ct::KeyCode::Backspace => KeyCode::Backspace,
+        ct::KeyCode::Tab => KeyCode::Tab,
+        ct::KeyCode::BackTab => KeyCode::BackTab,
+        ct::KeyCode::Esc => KeyCode::Esc,
+        ct::KeyCode::Left => KeyCode::Left,
+        ct::KeyCode::Right => KeyCode::Right,
+        ct::KeyCode::Up => KeyCode::Up,
+        ct::KeyCode::Down => KeyCode::Down,
+        ct::KeyCode::Home => KeyCode::Home,
+        ct::KeyCode::End => KeyCode::End,
+        ct::KeyCode::PageUp => KeyCode::PageUp,
+        ct::KeyCode::PageDown => KeyCode::PageDown,
+        ct::KeyCode::Delete => KeyCode::Delete,
+        ct::KeyCode::Insert => KeyCode::Insert,
+        ct::KeyCode::F(n) => KeyCode::F(n),
+        _ => return None,
+    };
+    Some(KeyEvent::new(code, key_modifiers(event.modifiers)).kind(kind))
+}
+
+/// Convert crossterm key modifiers.
+#[must_use]
+pub const fn key_modifiers(modifiers: ct::KeyModifiers) -> KeyModifiers {
+    KeyModifiers {
+        shift: modifiers.contains(ct::KeyModifiers::SHIFT),
+        ctrl: modifiers.contains(ct::KeyModifiers::CONTROL),
+        alt: modifiers.contains(ct::KeyModifiers::ALT),
+        meta: modifiers.intersects(ct::KeyModifiers::SUPER.union(ct::KeyModifiers::META)),
+    }
+}
+
+const fn mouse_button(button: ct::MouseButton) -> MouseButton {
+    match button {
+        ct::MouseButton::Left => MouseButton::Left,
+        ct::MouseButton::Right => MouseButton::Right,
+        ct::MouseButton::Middle => MouseButton::Middle,
+    }
+}
+
+/// Convert a crossterm mouse event, including wheel events and modifiers.
+///
+/// # Example
+///
+/// ```
+/// use crossterm::event::{KeyModifiers, MouseEvent, MouseEventKind};
+/// use tuxtui_crossterm::event::mouse_event;
+///
+/// let event = mouse_event(MouseEvent {
+///     kind: MouseEventKind::ScrollUp,
+///     column: 3,
+///     row: 4,
+///     modifiers: KeyModifiers::SHIFT,
+/// });
+/// assert!(event.kind.is_scroll());
+/// assert!(event.modifiers.shift);
+/// ```
+#[must_use]
+pub const fn mouse_event(event: ct::MouseEvent) -> MouseEvent {
+    let kind = match event.kind {
+        ct::MouseEventKind::Down(button) => MouseEventKind::Down(mouse_button(button)),
+        ct::MouseEventKind::Up(button) => MouseEventKind::Up(mouse_button(button)),
+        ct::MouseEventKind::Drag(button) => MouseEventKind::Drag(mouse_button(button)),
+        ct::MouseEventKind::Moved => MouseEventKind::Moved,
+        ct::MouseEventKind::ScrollDown => MouseEventKind::ScrollDown,
+        ct::MouseEventKind::ScrollUp => MouseEventKind::ScrollUp,
+        ct::MouseEventKind::ScrollLeft => MouseEventKind::ScrollLeft,
+        ct::MouseEventKind::ScrollRight => MouseEventKind::ScrollRight,
+    };
+    MouseEvent::new(kind, event.column, event.row).modifiers(key_modifiers(event.modifiers))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_event_conversion() {
+        let key = ct::KeyEvent::new(ct::KeyCode::Char('x'), ct::KeyModifiers::CONTROL);
+        assert_eq!(
+            event(ct::Event::Key(key)),
+            Some(Event::Key(KeyEvent::new(
+                KeyCode::Char('x'),
+                KeyModifiers::CTRL
+            )))
+        );
+
+        let release = ct::KeyEvent::new_with_kind(
+            ct::KeyCode::Enter,
+            ct::KeyModifiers::NONE,
+            ct::KeyEventKind::Release,
+        );
+        assert_eq!(
+            event(ct::Event::Key(release)),
+            Some(Event::Key(
+                KeyEvent::from(KeyCode::Enter).kind(KeyEventKind::Release)
+            ))
+        );
+        let media = ct::KeyEvent::new(
+            ct::KeyCode::Media(ct::MediaKeyCode::Play),
+            ct::KeyModifiers::NONE,
+        );
+        assert_eq!(event(ct::Event::Key(media)), None);
+        assert_eq!(event(ct::Event::Resize(4, 2)), Some(Event::Resize(4, 2)));
+        assert_eq!(
+            event(ct::Event::Paste("hi".into())),
+            Some(Event::Paste("hi".into()))
+        );
+    }
+
+    #[test]
+    fn test_mouse_wheel_conversion() {
+        let event = mouse_event(ct::MouseEvent {
+            kind: ct::MouseEventKind::ScrollRight,
+            column: 1,
+            row: 2,
+            modifiers: ct::KeyModifiers::CONTROL | ct::KeyModifiers::ALT,
+        });
+        assert_eq!(event.kind, MouseEventKind::ScrollRight);
+        assert_eq!((event.column, event.row), (1, 2));
+        assert!(event.modifiers.ctrl && event.modifiers.alt && !event.modifiers.shift);
+    }
+}
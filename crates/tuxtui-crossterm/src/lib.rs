@@ -10,10 +10,14 @@
 //! - `crossterm_0_28`: Use crossterm 0.28
 //! - `crossterm_0_29` (default): Use crossterm 0.29
 //! - `serde`: Enable serialization support
-//! - `underline-color`: Enable colored underlines
+//! - `underline-color`: Send `Style::underline_color` as colored underlines (SGR 58/59);
+//!   check `Capabilities::underline_color` to see if the terminal supports them
 //! - `scrolling-regions`: Enable scrolling region support
 //! - `unstable`: Enable unstable features
-//! - `unstable-backend-writer`: Enable unstable backend writer API
+//! - `unstable-backend-writer`: No longer needed; the writer API
+//!   ([`CrosstermBackend::writer`], [`CrosstermBackend::replace_writer`]) is stable
+//! - `remote`: [`remote::RemoteBackend`], for terminals reached over an async
+//!   connection such as SSH
 //!
 //! ## Example
 //!
@@ -29,24 +33,61 @@
 #![forbid(unsafe_code)]
 #![warn(missing_docs)]
 
+pub mod event;
+#[cfg(feature = "remote")]
+pub mod remote;
+pub mod style;
+
 use crossterm::{
     cursor, execute, queue,
-    style::{
-        self, Attribute, Color as CColor, SetAttribute, SetBackgroundColor, SetForegroundColor,
-    },
+    style::{Attribute, Print, SetAttribute, SetBackgroundColor, SetForegroundColor},
     terminal::{self, Clear, ClearType},
 };
+use std::fs::OpenOptions;
 use std::io::{self, Write};
-use tuxtui_core::backend::Backend;
+use std::time::Duration;
+use tuxtui_core::backend::{self as core_backend, Backend, Capabilities};
 use tuxtui_core::buffer::Cell;
+use tuxtui_core::event::Event;
 use tuxtui_core::geometry::{Position, Rect};
 use tuxtui_core::style::{Color, Modifier, Style};
+use tuxtui_core::util::unicode;
+
+/// How long to wait for the terminal to answer a query.
+const QUERY_TIMEOUT: Duration = Duration::from_millis(500);
+
+/// Attributes turning modifiers off, with the modifiers each turns off.
+const MODIFIERS_OFF: [(Modifier, Attribute); 7] = [
+    (
+        Modifier::BOLD.union(Modifier::DIM),
+        Attribute::NormalIntensity,
+    ),
+    (Modifier::ITALIC, Attribute::NoItalic),
+    (Modifier::UNDERLINED, Attribute::NoUnderline),
+    (
+        Modifier::SLOW_BLINK.union(Modifier::RAPID_BLINK),
+        Attribute::NoBlink,
+    ),
+    (Modifier::REVERSED, Attribute::NoReverse),
+    (Modifier::HIDDEN, Attribute::NoHidden),
+    (Modifier::CROSSED_OUT, Attribute::NotCrossedOut),
+];
 
 /// Crossterm backend.
 ///
 /// Wraps a writer (typically stdout) and uses crossterm for terminal operations.
+///
+/// The backend remembers the colors, modifiers and cursor position it left
+/// the terminal in, and only sends what changes between cells. The style is
+/// reset when the output is flushed. Writing to the terminal other than
+/// through the backend leaves this out of date.
+#[derive(Debug)]
 pub struct CrosstermBackend<W: Write> {
     writer: W,
+    /// The colors and modifiers currently applied by the terminal.
+    applied: Style,
+    /// Where the terminal cursor is, if known.
+    cursor: Option<Position>,
 }
 
 impl<W: Write> CrosstermBackend<W> {
@@ -61,7 +102,11 @@ impl<W: Write> CrosstermBackend<W> {
     /// let backend = CrosstermBackend::new(stdout());
     /// ```
     pub fn new(writer: W) -> Self {
-        Self { writer }
+        Self {
+            writer,
+            applied: Style::new(),
+            cursor: None,
+        }
     }
 
     /// Get a reference to the writer.
@@ -74,28 +119,108 @@ impl<W: Write> CrosstermBackend<W> {
         &mut self.writer
     }
 
-    fn convert_color(color: Color) -> CColor {
-        match color {
-            Color::Reset => CColor::Reset,
-            Color::Black => CColor::Black,
-            Color::Red => CColor::DarkRed,
-            Color::Green => CColor::DarkGreen,
-            Color::Yellow => CColor::DarkYellow,
-            Color::Blue => CColor::DarkBlue,
-            Color::Magenta => CColor::DarkMagenta,
-            Color::Cyan => CColor::DarkCyan,
-            Color::White => CColor::Grey,
-            Color::Gray => CColor::DarkGrey,
-            Color::LightRed => CColor::Red,
-            Color::LightGreen => CColor::Green,
-            Color::LightYellow => CColor::Yellow,
-            Color::LightBlue => CColor::Blue,
-            Color::LightMagenta => CColor::Magenta,
-            Color::LightCyan => CColor::Cyan,
-            Color::LightGray => CColor::White,
-            Color::Indexed(i) => CColor::AnsiValue(i),
-            Color::Rgb(r, g, b) => CColor::Rgb { r, g, b },
+    /// Send further output to `writer`, returning the previous one.
+    ///
+    /// Nothing is known about the new sink, so the next cell sends its full
+    /// style and position. The previous writer is returned as is; flush it
+    /// if it buffers. Use a `CrosstermBackend<Box<dyn Write>>` to switch
+    /// between sinks of different types, such as stdout and a recording.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use tuxtui_crossterm::CrosstermBackend;
+    /// use tuxtui_core::backend::Backend;
+    ///
+    /// let mut backend = CrosstermBackend::new(Vec::new());
+    /// backend.bell().unwrap();
+    /// let recorded = backend.replace_writer(Vec::new());
+    /// assert_eq!(recorded, b"\x07");
+    /// ```
+    pub fn replace_writer(&mut self, writer: W) -> W {
+        self.applied = Style::new();
+        self.cursor = None;
+        std::mem::replace(&mut self.writer, writer)
+    }
+
+    /// Get the writer back, dropping the backend.
+    pub fn into_writer(self) -> W {
+        self.writer
+    }
+
+    /// Ask the terminal for one of its default colors.
+    ///
+    /// The query goes straight to the controlling terminal, in raw mode so
+    /// the reply is not echoed. Without one, the color is unknown.
+    #[cfg(unix)]
+    fn query_color(code: u8) -> io::Result<Option<Color>> {
+        let Ok(mut tty) = OpenOptions::new().read(true).write(true).open("/dev/tty") else {
+            return Ok(None);
+        };
+        let raw = terminal::is_raw_mode_enabled()?;
+        if !raw {
+            terminal::enable_raw_mode()?;
+        }
+        let color = core_backend::query_color(&mut tty.try_clone()?, &mut tty, code, QUERY_TIMEOUT);
+        if !raw {
+            terminal::disable_raw_mode()?;
         }
+        color
+    }
+
+    /// Ask the terminal for one of its default colors, which is unknown
+    /// without a controlling terminal to query.
+    #[cfg(not(unix))]
+    #[allow(clippy::unnecessary_wraps)]
+    fn query_color(_code: u8) -> io::Result<Option<Color>> {
+        Ok(None)
+    }
+
+    /// Switch the terminal to `style`, sending only what changed.
+    fn apply_style(&mut self, style: Style) -> io::Result<()> {
+        let visible = |color: Option<Color>| color.filter(|color| *color != Color::Reset);
+        let mut target = Style::new().add_modifier(style.modifiers());
+        target.fg = visible(style.fg);
+        target.bg = visible(style.bg);
+        #[cfg(feature = "underline-color")]
+        {
+            target.underline_color = visible(style.underline_color);
+        }
+
+        let removed = self.applied.add_modifier.difference(target.add_modifier);
+        let mut added = target.add_modifier.difference(self.applied.add_modifier);
+        for (modifiers, attribute) in MODIFIERS_OFF {
+            if removed.intersects(modifiers) {
+                queue!(self.writer, SetAttribute(attribute))?;
+                // Turn back on what the same code turned off.
+                added |= target.add_modifier.intersection(modifiers);
+            }
+        }
+        self.apply_modifiers(added)?;
+        if self.applied.fg != target.fg {
+            let color = style::to_crossterm_color(target.fg.unwrap_or(Color::Reset));
+            queue!(self.writer, SetForegroundColor(color))?;
+        }
+        if self.applied.bg != target.bg {
+            let color = style::to_crossterm_color(target.bg.unwrap_or(Color::Reset));
+            queue!(self.writer, SetBackgroundColor(color))?;
+        }
+        #[cfg(feature = "underline-color")]
+        if self.applied.underline_color != target.underline_color {
+            let color = style::to_crossterm_color(target.underline_color.unwrap_or(Color::Reset));
+            queue!(self.writer, crossterm::style::SetUnderlineColor(color))?;
+        }
+        self.applied = target;
+        Ok(())
+    }
+
+    /// Reset the style, unless it already is the default.
+    fn reset(&mut self) -> io::Result<()> {
+        if self.applied != Style::new() {
+            queue!(self.writer, SetAttribute(Attribute::Reset))?;
+            self.applied = Style::new();
+        }
+        Ok(())
     }
 
     fn apply_modifiers(&mut self, modifiers: Modifier) -> io::Result<()> {
@@ -139,14 +264,18 @@ impl<W: Write> Backend for CrosstermBackend<W> {
     }
 
     fn clear(&mut self) -> Result<(), Self::Error> {
+        // Terminals clear to the current background color.
+        self.reset()?;
         execute!(self.writer, Clear(ClearType::All))
     }
 
     fn clear_region(&mut self, region: Rect) -> Result<(), Self::Error> {
+        self.reset()?;
+        self.cursor = None;
         for y in region.top()..region.bottom() {
             queue!(self.writer, cursor::MoveTo(region.left(), y))?;
             for _ in region.left()..region.right() {
-                queue!(self.writer, style::Print(" "))?;
+                queue!(self.writer, Print(" "))?;
             }
         }
         Ok(())
@@ -168,6 +297,7 @@ impl<W: Write> Backend for CrosstermBackend<W> {
 
     fn set_cursor(&mut self, x: u16, y: u16) -> Result<(), Self::Error> {
         queue!(self.writer, cursor::MoveTo(x, y))?;
+        self.cursor = Some(Position::new(x, y));
         Ok(())
     }
 
@@ -176,45 +306,36 @@ impl<W: Write> Backend for CrosstermBackend<W> {
             return Ok(());
         }
 
-        queue!(self.writer, cursor::MoveTo(x, y))?;
-
-        if let Some(fg) = cell.style.fg {
-            queue!(self.writer, SetForegroundColor(Self::convert_color(fg)))?;
+        let position = Position::new(x, y);
+        if self.cursor != Some(position) {
+            queue!(self.writer, cursor::MoveTo(x, y))?;
         }
-        if let Some(bg) = cell.style.bg {
-            queue!(self.writer, SetBackgroundColor(Self::convert_color(bg)))?;
-        }
-
-        self.apply_modifiers(cell.style.add_modifier)?;
-
-        queue!(self.writer, style::Print(&cell.symbol))?;
-
-        // Reset if we applied any modifiers
-        if !cell.style.add_modifier.is_empty() || cell.style.fg.is_some() || cell.style.bg.is_some()
-        {
-            queue!(self.writer, SetAttribute(Attribute::Reset))?;
-        }
-
+        self.apply_style(cell.style)?;
+        queue!(self.writer, Print(cell.symbol()))?;
+        // Terminals disagree on the width of some emoji, so the cursor is
+        // only known to be past them after moving it explicitly.
+        self.cursor = if unicode::is_ambiguous_width(cell.symbol()) {
+            None
+        } else {
+            let width = u16::try_from(cell.width()).unwrap_or(u16::MAX);
+            Some(Position::new(x.saturating_add(width), y))
+        };
         Ok(())
     }
 
     fn set_style(&mut self, style: Style) -> Result<(), Self::Error> {
-        if let Some(fg) = style.fg {
-            queue!(self.writer, SetForegroundColor(Self::convert_color(fg)))?;
-        }
-        if let Some(bg) = style.bg {
-            queue!(self.writer, SetBackgroundColor(Self::convert_color(bg)))?;
-        }
-        self.apply_modifiers(style.add_modifier)?;
-        Ok(())
+        self.apply_style(self.applied.patch(style))
     }
 
     fn reset_style(&mut self) -> Result<(), Self::Error> {
         queue!(self.writer, SetAttribute(Attribute::Reset))?;
+        self.applied = Style::new();
         Ok(())
     }
 
+    /// Reset the style, then flush the output.
     fn flush(&mut self) -> Result<(), Self::Error> {
+        self.reset()?;
         self.writer.flush()
     }
 
@@ -227,10 +348,56 @@ impl<W: Write> Backend for CrosstermBackend<W> {
     }
 
     fn enter_alternate_screen(&mut self) -> Result<(), Self::Error> {
+        self.cursor = None;
         execute!(self.writer, terminal::EnterAlternateScreen)
     }
 
+    fn set_title(&mut self, title: &str) -> Result<(), Self::Error> {
+        execute!(self.writer, terminal::SetTitle(title))
+    }
+
+    fn bell(&mut self) -> Result<(), Self::Error> {
+        self.writer.write_all(b"\x07")?;
+        self.writer.flush()
+    }
+
+    fn append_lines(&mut self, lines: u16) -> Result<(), Self::Error> {
+        for _ in 0..lines {
+            queue!(self.writer, Print("\n"))?;
+        }
+        self.cursor = None;
+        Ok(())
+    }
+
+    /// The writer may be anything, but raw mode and input always go through
+    /// the terminal of this process.
+    fn is_process_terminal(&self) -> bool {
+        true
+    }
+
+    fn poll_event(&mut self) -> Result<Option<Event>, Self::Error> {
+        while crossterm::event::poll(Duration::ZERO)? {
+            if let Some(event) = event::event(crossterm::event::read()?) {
+                return Ok(Some(event));
+            }
+        }
+        Ok(None)
+    }
+
+    fn capabilities(&self) -> Capabilities {
+        Capabilities::from_env()
+    }
+
+    fn foreground_color(&mut self) -> Result<Option<Color>, Self::Error> {
+        Self::query_color(10)
+    }
+
+    fn background_color(&mut self) -> Result<Option<Color>, Self::Error> {
+        Self::query_color(11)
+    }
+
     fn leave_alternate_screen(&mut self) -> Result<(), Self::Error> {
+        self.cursor = None;
         execute!(self.writer, terminal::LeaveAlternateScreen)
     }
 }
@@ -248,18 +415,108 @@ mod tests {
     }
 
     #[test]
-    fn test_color_conversion() {
-        assert!(matches!(
-            CrosstermBackend::<Vec<u8>>::convert_color(Color::Red),
-            CColor::DarkRed
-        ));
-        assert!(matches!(
-            CrosstermBackend::<Vec<u8>>::convert_color(Color::Rgb(255, 128, 0)),
-            CColor::Rgb {
-                r: 255,
-                g: 128,
-                b: 0
-            }
-        ));
+    fn test_draw_cells_sends_only_changes() {
+        let mut backend = CrosstermBackend::new(Vec::new());
+        let red = Style::new().fg(Color::Red);
+        let bold = red.add_modifier(Modifier::BOLD | Modifier::DIM);
+        let dim = bold.remove_modifier(Modifier::BOLD);
+        for (x, style) in [
+            (0, red),
+            (1, red),
+            (2, bold),
+            (3, dim),
+            (4, red),
+            (6, Style::new()),
+        ] {
+            let mut cell = Cell::default();
+            cell.set_symbol("x");
+            cell.set_style(style);
+            backend.draw_cell(x, 1, &cell).unwrap();
+        }
+        backend.flush().unwrap();
+        let output = String::from_utf8(backend.writer().clone()).unwrap();
+        assert_eq!(
+            output,
+            concat!(
+                "\x1b[2;1H\x1b[38;5;1mxx",
+                "\x1b[1m\x1b[2mx",
+                "\x1b[22m\x1b[2mx",
+                "\x1b[22mx",
+                "\x1b[2;7H\x1b[39mx",
+            )
+        );
+    }
+
+    #[test]
+    fn test_cursor_moves_after_ambiguous_emoji() {
+        let mut backend = CrosstermBackend::new(Vec::new());
+        for (x, symbol) in [(0, "你"), (2, "a"), (3, "❤\u{fe0f}"), (5, "b")] {
+            backend
+                .draw_cell(x, 0, &Cell::new(symbol, Style::new()))
+                .unwrap();
+        }
+        let output = String::from_utf8(backend.writer().clone()).unwrap();
+        assert_eq!(output, "\x1b[1;1H你a❤\u{fe0f}\x1b[1;6Hb");
+    }
+
+    #[test]
+    fn test_replace_writer_forgets_terminal_state() {
+        let mut backend = CrosstermBackend::new(Vec::new());
+        let mut cell = Cell::default();
+        cell.set_symbol("x");
+        cell.set_style(Style::new().fg(Color::Red));
+        backend.draw_cell(0, 0, &cell).unwrap();
+
+        let first = backend.replace_writer(Vec::new());
+        backend.draw_cell(1, 0, &cell).unwrap();
+        assert_eq!(first, b"\x1b[1;1H\x1b[38;5;1mx");
+        assert_eq!(backend.into_writer(), b"\x1b[1;2H\x1b[38;5;1mx");
+    }
+
+    #[test]
+    fn test_set_style_removes_modifiers() {
+        let mut backend = CrosstermBackend::new(Vec::new());
+        backend
+            .set_style(Style::new().add_modifier(Modifier::ITALIC | Modifier::REVERSED))
+            .unwrap();
+        backend
+            .set_style(Style::new().remove_modifier(Modifier::ITALIC))
+            .unwrap();
+        assert_eq!(backend.writer().as_slice(), b"\x1b[3m\x1b[7m\x1b[23m");
+    }
+
+    #[cfg(feature = "underline-color")]
+    #[test]
+    fn test_underline_color() {
+        let mut backend = CrosstermBackend::new(Vec::new());
+        let mut style = Style::new().add_modifier(Modifier::UNDERLINED);
+        style.underline_color = Some(Color::Rgb(255, 0, 0));
+        backend.set_style(style).unwrap();
+        backend.set_style(style).unwrap();
+        style.underline_color = Some(Color::Reset);
+        backend.set_style(style).unwrap();
+        assert_eq!(
+            backend.writer().as_slice(),
+            b"\x1b[4m\x1b[58;2;255;0;0m\x1b[59m"
+        );
+    }
+
+    #[test]
+    fn test_title_and_bell() {
+        let mut backend = CrosstermBackend::new(Vec::new());
+        backend.set_title("tuxtui").unwrap();
+        backend.bell().unwrap();
+        assert_eq!(backend.writer().as_slice(), b"\x1b]0;tuxtui\x07\x07");
+    }
+
+    #[test]
+    fn test_append_lines_forgets_cursor() {
+        let mut backend = CrosstermBackend::new(Vec::new());
+        let mut cell = Cell::default();
+        cell.set_symbol("x");
+        backend.draw_cell(0, 0, &cell).unwrap();
+        backend.append_lines(2).unwrap();
+        backend.draw_cell(1, 0, &cell).unwrap();
+        assert_eq!(backend.writer().as_slice(), b"\x1b[1;1Hx\n\n\x1b[1;2Hx");
     }
 }
@@ -0,0 +1,360 @@
+//! Backend for terminals on the other end of an async connection, such as
+//! an SSH channel.
+
+use crate::CrosstermBackend;
+use futures_channel::mpsc::{self, UnboundedSender};
+use futures_util::io::{AsyncWrite, AsyncWriteExt};
+use futures_util::stream::StreamExt;
+use std::future::Future;
+use std::io::{self, Write};
+use std::sync::Arc;
+use std::sync::atomic::{AtomicU32, Ordering};
+use std::sync::mpsc as std_mpsc;
+use tuxtui_core::backend::{Backend, Capabilities};
+use tuxtui_core::buffer::Cell;
+use tuxtui_core::event::Event;
+use tuxtui_core::geometry::{Position, Rect};
+use tuxtui_core::style::{Color, Style};
+use tuxtui_core::util::ColorSupport;
+
+/// Where a [`RemoteBackend`] gets the size of the remote terminal.
+///
+/// Implemented for [`SharedSize`] and for closures returning
+/// `(width, height)`.
+pub trait SizeProvider {
+    /// Get the current `(width, height)` of the terminal.
+    fn size(&self) -> (u16, u16);
+}
+
+impl<F: Fn() -> (u16, u16)> SizeProvider for F {
+    fn size(&self) -> (u16, u16) {
+        self()
+    }
+}
+
+/// A terminal size that can be updated from another task, such as the
+/// handler of SSH window change requests.
+///
+/// # Example
+///
+/// ```
+/// use tuxtui_crossterm::remote::{SharedSize, SizeProvider};
+///
+/// let size = SharedSize::new(80, 24);
+/// size.clone().set(100, 30);
+/// assert_eq!(size.size(), (100, 30));
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct SharedSize(Arc<AtomicU32>);
+
+impl SharedSize {
+    /// Create a size of `width` by `height`.
+    #[must_use]
+    pub fn new(width: u16, height: u16) -> Self {
+        Self(Arc::new(AtomicU32::new(pack(width, height))))
+    }
+
+    /// Change the size, for everyone sharing it.
+    pub fn set(&self, width: u16, height: u16) {
+        self.0.store(pack(width, height), Ordering::Relaxed);
+    }
+}
+
+impl SizeProvider for SharedSize {
+    fn size(&self) -> (u16, u16) {
+        let packed = self.0.load(Ordering::Relaxed);
+        ((packed >> 16) as u16, packed as u16)
+    }
+}
+
+/// Pack a size into one atomic word.
+fn pack(width: u16, height: u16) -> u32 {
+    u32::from(width) << 16 | u32::from(height)
+}
+
+/// The sending end of input for a [`RemoteBackend`].
+///
+/// Events pushed here are returned by [`Backend::poll_event`]. It can be
+/// cloned and sent to the task that reads from the connection.
+#[derive(Debug, Clone)]
+pub struct RemoteInput(std_mpsc::Sender<Event>);
+
+impl RemoteInput {
+    /// Queue an event for the application.
+    pub fn push_event(&self, event: impl Into<Event>) {
+        // The backend is gone, so nobody is waiting for the event.
+        let _ = self.0.send(event.into());
+    }
+}
+
+/// Collects output and hands it over, a frame at a time, on flush.
+#[derive(Debug)]
+struct FrameWriter {
+    pending: Vec<u8>,
+    sender: UnboundedSender<Vec<u8>>,
+}
+
+impl Write for FrameWriter {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        self.pending.extend_from_slice(buf);
+        Ok(buf.len())
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        if self.pending.is_empty() {
+            return Ok(());
+        }
+        self.sender
+            .unbounded_send(std::mem::take(&mut self.pending))
+            .map_err(|_| io::Error::from(io::ErrorKind::BrokenPipe))
+    }
+}
+
+/// A backend that draws on a remote terminal through an [`AsyncWrite`].
+///
+/// Multi-user servers, such as an SSH server giving every session its own
+/// TUI, can't hand a [`Stdout`](std::io::Stdout) to a backend. This one
+/// encodes output like [`CrosstermBackend`], but sends every flushed frame to
+/// an async writer such as an SSH channel. Drawing never blocks: frames are
+/// queued and written by a future, returned by [`RemoteBackend::new`], that
+/// runs on the server's executor.
+///
+/// The size comes from a [`SizeProvider`], and resizes are reported by
+/// [`Backend::poll_event`] along with the events pushed to
+/// [`RemoteBackend::input`]. Raw mode is left to the client, and nothing is
+/// queried from the server's own terminal. Once the writer fails, for example
+/// because the client disconnected, flushing returns
+/// [`io::ErrorKind::BrokenPipe`].
+///
+/// # Example
+///
+/// ```
+/// use tuxtui_core::terminal::Terminal;
+/// use tuxtui_crossterm::remote::{RemoteBackend, SharedSize};
+///
+/// # futures_executor::block_on(async {
+/// let size = SharedSize::new(20, 2);
+/// let (backend, output) = RemoteBackend::new(Vec::new(), size.clone());
+/// let mut terminal = Terminal::new(backend).unwrap();
+/// terminal.draw(|frame| frame.render_widget("hi", frame.area())).unwrap();
+/// drop(terminal);
+///
+/// // Normally spawned on the executor, next to the connection.
+/// let written = output.await.unwrap();
+/// assert!(String::from_utf8(written).unwrap().contains("hi"));
+/// # });
+/// ```
+#[derive(Debug)]
+pub struct RemoteBackend<S> {
+    inner: CrosstermBackend<FrameWriter>,
+    size: S,
+    /// The size last reported, to detect resizes.
+    reported: (u16, u16),
+    events: std_mpsc::Receiver<Event>,
+    input: RemoteInput,
+    capabilities: Capabilities,
+}
+
+impl<S: SizeProvider> RemoteBackend<S> {
+    /// Create a backend drawing to `writer`, with the size from `size`.
+    ///
+    /// Returns the backend and the future writing its output. Run the future
+    /// on the executor; it finishes with the writer once the backend is
+    /// dropped, or with the first write error.
+    pub fn new<W>(writer: W, size: S) -> (Self, impl Future<Output = io::Result<W>> + Send)
+    where
+        W: AsyncWrite + Unpin + Send,
+    {
+        let (sender, mut frames) = mpsc::unbounded::<Vec<u8>>();
+        let (events_sender, events) = std_mpsc::channel();
+        let reported = size.size();
+        let backend = Self {
+            inner: CrosstermBackend::new(FrameWriter {
+                pending: Vec::new(),
+                sender,
+            }),
+            size,
+            reported,
+            events,
+            input: RemoteInput(events_sender),
+            capabilities: Capabilities {
+                color: ColorSupport::TrueColor,
+                mouse: true,
+                scroll_regions: true,
+                set_title: true,
+                ..Capabilities::default()
+            },
+        };
+        let output = async move {
+            let mut writer = writer;
+            while let Some(frame) = frames.next().await {
+                writer.write_all(&frame).await?;
+                writer.flush().await?;
+            }
+            Ok(writer)
+        };
+        (backend, output)
+    }
+}
+
+impl<S> RemoteBackend<S> {
+    /// Get a handle to push input events, such as keys read from the
+    /// connection.
+    #[must_use]
+    pub fn input(&self) -> RemoteInput {
+        self.input.clone()
+    }
+
+    /// Set what the remote terminal supports, for instance detected from the
+    /// `TERM` sent with the client's pty request. By default true color,
+    /// mouse reporting, scroll regions and titles are assumed.
+    #[must_use]
+    pub const fn with_capabilities(mut self, capabilities: Capabilities) -> Self {
+        self.capabilities = capabilities;
+        self
+    }
+}
+
+impl<S: SizeProvider> Backend for RemoteBackend<S> {
+    type Error = io::Error;
+
+    fn size(&self) -> Result<Rect, Self::Error> {
+        let (width, height) = self.size.size();
+        Ok(Rect::new(0, 0, width, height))
+    }
+
+    fn clear(&mut self) -> Result<(), Self::Error> {
+        self.inner.clear()
+    }
+
+    fn clear_region(&mut self, region: Rect) -> Result<(), Self::Error> {
+        self.inner.clear_region(region)
+    }
+
+    fn hide_cursor(&mut self) -> Result<(), Self::Error> {
+        self.inner.hide_cursor()
+    }
+
+    fn show_cursor(&mut self) -> Result<(), Self::Error> {
+        self.inner.show_cursor()
+    }
+
+    fn get_cursor(&mut self) -> Result<Position, Self::Error> {
+        self.inner.get_cursor()
+    }
+
+    fn set_cursor(&mut self, x: u16, y: u16) -> Result<(), Self::Error> {
+        self.inner.set_cursor(x, y)
+    }
+
+    fn draw_cell(&mut self, x: u16, y: u16, cell: &Cell) -> Result<(), Self::Error> {
+        self.inner.draw_cell(x, y, cell)
+    }
+
+    fn set_style(&mut self, style: Style) -> Result<(), Self::Error> {
+        self.inner.set_style(style)
+    }
+
+    fn reset_style(&mut self) -> Result<(), Self::Error> {
+        self.inner.reset_style()
+    }
+
+    fn flush(&mut self) -> Result<(), Self::Error> {
+        self.inner.flush()
+    }
+
+    /// Does nothing: the client puts its terminal in raw mode.
+    fn enable_raw_mode(&mut self) -> Result<(), Self::Error> {
+        Ok(())
+    }
+
+    /// Does nothing: the client restores its terminal.
+    fn disable_raw_mode(&mut self) -> Result<(), Self::Error> {
+        Ok(())
+    }
+
+    fn enter_alternate_screen(&mut self) -> Result<(), Self::Error> {
+        self.inner.enter_alternate_screen()
+    }
+
+    fn leave_alternate_screen(&mut self) -> Result<(), Self::Error> {
+        self.inner.leave_alternate_screen()
+    }
+
+    fn set_title(&mut self, title: &str) -> Result<(), Self::Error> {
+        self.inner.set_title(title)
+    }
+
+    fn bell(&mut self) -> Result<(), Self::Error> {
+        self.inner.bell()
+    }
+
+    fn append_lines(&mut self, lines: u16) -> Result<(), Self::Error> {
+        self.inner.append_lines(lines)
+    }
+
+    /// Report a resize if the size changed, otherwise the next pushed event.
+    fn poll_event(&mut self) -> Result<Option<Event>, Self::Error> {
+        let size = self.size.size();
+        if size != self.reported {
+            self.reported = size;
+            return Ok(Some(Event::Resize(size.0, size.1)));
+        }
+        Ok(self.events.try_recv().ok())
+    }
+
+    fn capabilities(&self) -> Capabilities {
+        self.capabilities
+    }
+
+    fn foreground_color(&mut self) -> Result<Option<Color>, Self::Error> {
+        Ok(None)
+    }
+
+    fn background_color(&mut self) -> Result<Option<Color>, Self::Error> {
+        Ok(None)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use futures_executor::block_on;
+    use tuxtui_core::event::KeyCode;
+    use tuxtui_core::terminal::Terminal;
+
+    #[test]
+    fn test_frames_are_written_on_flush() {
+        let (mut backend, output) = RemoteBackend::new(Vec::new(), || (4, 1));
+        backend.bell().unwrap();
+        backend.set_cursor(1, 0).unwrap();
+        backend.flush().unwrap();
+        backend.flush().unwrap();
+        drop(backend);
+        assert_eq!(block_on(output).unwrap(), b"\x07\x1b[1;2H");
+    }
+
+    #[test]
+    fn test_input_and_resize() {
+        let size = SharedSize::new(4, 2);
+        let (backend, _output) = RemoteBackend::new(Vec::new(), size.clone());
+        let mut terminal = Terminal::new(backend).unwrap();
+        terminal.backend().input().push_event(KeyCode::Char('q'));
+        size.set(6, 3);
+
+        assert_eq!(terminal.size().unwrap(), Rect::new(0, 0, 6, 3));
+        assert_eq!(terminal.poll_event().unwrap(), Some(Event::Resize(6, 3)));
+        assert_eq!(
+            terminal.poll_event().unwrap(),
+            Some(KeyCode::Char('q').into())
+        );
+        assert_eq!(terminal.poll_event().unwrap(), None);
+    }
+
+    #[test]
+    fn test_flush_fails_once_output_is_gone() {
+        let (mut backend, output) = RemoteBackend::new(Vec::new(), || (1, 1));
+        drop(output);
+        backend.bell().unwrap_err();
+    }
+}
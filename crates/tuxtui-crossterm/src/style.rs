@@ -0,0 +1,157 @@
+//! Conversion between crossterm and tuxtui colors and styles.
+//!
+//! Both types are foreign to this crate, so conversions are functions
+//! rather than `From` implementations.
+
+use crossterm::style as ct;
+use tuxtui_core::style::{Color, Modifier, Style};
+
+/// Crossterm attributes with the modifiers they convert to.
+const ATTRIBUTES: [(ct::Attribute, Modifier); 13] = [
+    (ct::Attribute::Bold, Modifier::BOLD),
+    (ct::Attribute::Dim, Modifier::DIM),
+    (ct::Attribute::Italic, Modifier::ITALIC),
+    (ct::Attribute::Underlined, Modifier::UNDERLINED),
+    (ct::Attribute::DoubleUnderlined, Modifier::UNDERLINED),
+    (ct::Attribute::Undercurled, Modifier::UNDERLINED),
+    (ct::Attribute::Underdotted, Modifier::UNDERLINED),
+    (ct::Attribute::Underdashed, Modifier::UNDERLINED),
+    (ct::Attribute::SlowBlink, Modifier::SLOW_BLINK),
+    (ct::Attribute::RapidBlink, Modifier::RAPID_BLINK),
+    (ct::Attribute::Reverse, Modifier::REVERSED),
+    (ct::Attribute::Hidden, Modifier::HIDDEN),
+    (ct::Attribute::CrossedOut, Modifier::CROSSED_OUT),
+];
+
+/// Convert a crossterm color.
+///
+/// # Example
+///
+/// ```
+/// use crossterm::style::Color as CrosstermColor;
+/// use tuxtui_core::style::Color;
+/// use tuxtui_crossterm::style::color;
+///
+/// assert_eq!(color(CrosstermColor::DarkRed), Color::Red);
+/// ```
+#[must_use]
+pub const fn color(color: ct::Color) -> Color {
+    match color {
+        ct::Color::Reset => Color::Reset,
+        ct::Color::Black => Color::Black,
+        ct::Color::DarkRed => Color::Red,
+        ct::Color::DarkGreen => Color::Green,
+        ct::Color::DarkYellow => Color::Yellow,
+        ct::Color::DarkBlue => Color::Blue,
+        ct::Color::DarkMagenta => Color::Magenta,
+        ct::Color::DarkCyan => Color::Cyan,
+        ct::Color::Grey => Color::White,
+        ct::Color::DarkGrey => Color::Gray,
+        ct::Color::Red => Color::LightRed,
+        ct::Color::Green => Color::LightGreen,
+        ct::Color::Yellow => Color::LightYellow,
+        ct::Color::Blue => Color::LightBlue,
+        ct::Color::Magenta => Color::LightMagenta,
+        ct::Color::Cyan => Color::LightCyan,
+        ct::Color::White => Color::LightGray,
+        ct::Color::AnsiValue(index) => Color::Indexed(index),
+        ct::Color::Rgb { r, g, b } => Color::Rgb(r, g, b),
+    }
+}
+
+/// Convert a tuxtui color to crossterm.
+#[must_use]
+pub const fn to_crossterm_color(color: Color) -> ct::Color {
+    match color {
+        Color::Reset => ct::Color::Reset,
+        Color::Black => ct::Color::Black,
+        Color::Red => ct::Color::DarkRed,
+        Color::Green => ct::Color::DarkGreen,
+        Color::Yellow => ct::Color::DarkYellow,
+        Color::Blue => ct::Color::DarkBlue,
+        Color::Magenta => ct::Color::DarkMagenta,
+        Color::Cyan => ct::Color::DarkCyan,
+        Color::White => ct::Color::Grey,
+        Color::Gray => ct::Color::DarkGrey,
+        Color::LightRed => ct::Color::Red,
+        Color::LightGreen => ct::Color::Green,
+        Color::LightYellow => ct::Color::Yellow,
+        Color::LightBlue => ct::Color::Blue,
+        Color::LightMagenta => ct::Color::Magenta,
+        Color::LightCyan => ct::Color::Cyan,
+        Color::LightGray => ct::Color::White,
+        Color::Indexed(index) => ct::Color::AnsiValue(index),
+        Color::Rgb(r, g, b) => ct::Color::Rgb { r, g, b },
+    }
+}
+
+/// Convert crossterm attributes.
+///
+/// Every underline style becomes [`Modifier::UNDERLINED`], and attributes
+/// without a tuxtui equivalent are dropped.
+#[must_use]
+pub fn modifier(attributes: ct::Attributes) -> Modifier {
+    ATTRIBUTES
+        .iter()
+        .filter(|(attribute, _)| attributes.has(*attribute))
+        .fold(Modifier::empty(), |modifier, (_, flag)| modifier | *flag)
+}
+
+/// Convert a crossterm content style.
+#[must_use]
+pub fn style(style: ct::ContentStyle) -> Style {
+    let mut converted = Style::new().add_modifier(modifier(style.attributes));
+    converted.fg = style.foreground_color.map(color);
+    converted.bg = style.background_color.map(color);
+    #[cfg(feature = "underline-color")]
+    {
+        converted.underline_color = style.underline_color.map(color);
+    }
+    converted
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_color_conversion() {
+        assert!(matches!(to_crossterm_color(Color::Red), ct::Color::DarkRed));
+        assert!(matches!(
+            to_crossterm_color(Color::Rgb(255, 128, 0)),
+            ct::Color::Rgb {
+                r: 255,
+                g: 128,
+                b: 0
+            }
+        ));
+        for tux in [
+            Color::Reset,
+            Color::White,
+            Color::Gray,
+            Color::LightGray,
+            Color::Indexed(200),
+            Color::Rgb(1, 2, 3),
+        ] {
+            assert_eq!(color(to_crossterm_color(tux)), tux);
+        }
+    }
+
+    #[test]
+    fn test_style_conversion() {
+        let content = ct::ContentStyle {
+            foreground_color: Some(ct::Color::Cyan),
+            background_color: None,
+            underline_color: None,
+            attributes: ct::Attributes::from(ct::Attribute::Bold)
+                | ct::Attribute::Undercurled
+                | ct::Attribute::Framed,
+        };
+        assert_eq!(
+            style(content),
+            Style::default()
+                .fg(Color::LightCyan)
+                .add_modifier(Modifier::BOLD | Modifier::UNDERLINED)
+        );
+    }
+}
@@ -7,45 +7,310 @@
 //! ## Macros
 //!
 //! - `border!`: Create border configurations easily
+//! - `#[derive(KeyBindings)]`: Bind the variants of an action enum to keys
+//! - `#[derive(Styled)]`: Give a struct with a `Style` field the fluent styling API
+//! - `span!`, `line!` and `text!`: Build styled text without builder chains
 //!
 //! ## Example
 //!
 //! ```ignore
 //! use tuxtui_macros::border;
 //!
-//! let borders = border!(All);
+//! let borders = border!(Top | Bottom | Left | Right);
 //! ```
 
 #![forbid(unsafe_code)]
 #![warn(missing_docs)]
 
+mod text;
+
 use proc_macro::TokenStream;
 use quote::quote;
-use syn::{Ident, parse_macro_input};
+use syn::punctuated::Punctuated;
+use syn::{Data, DeriveInput, Fields, Ident, LitStr, Token, parse_macro_input};
+use tuxtui_core::keymap::KeyBinding;
 
 /// Create a border configuration.
 ///
+/// Sides are `Top`, `Bottom`, `Left` and `Right`, joined with `|`; `All`
+/// and `None` may also be used. `BorderType` cannot hold an arbitrary set
+/// of sides, so the union must be a single side, every side or none.
+///
 /// # Example
 ///
 /// ```ignore
 /// use tuxtui_macros::border;
 ///
 /// let borders = border!(All);
-/// let borders = border!(Top | Bottom);
+/// let borders = border!(Top | Bottom | Left | Right);
 /// ```
 #[proc_macro]
 pub fn border(input: TokenStream) -> TokenStream {
-    let ident = parse_macro_input!(input as Ident);
+    const SIDES: [(&str, u8); 6] = [
+        ("None", 0),
+        ("Top", 1),
+        ("Bottom", 2),
+        ("Left", 4),
+        ("Right", 8),
+        ("All", 15),
+    ];
 
-    let expanded = quote! {
-        tuxtui::widgets::block::BorderType::#ident
+    let parser = Punctuated::<Ident, Token![|]>::parse_separated_nonempty;
+    let sides = parse_macro_input!(input with parser);
+    let mut union = 0;
+    for side in &sides {
+        match SIDES.iter().find(|(name, _)| side == name) {
+            Some((_, bits)) => union |= bits,
+            None => {
+                let message = "expected `Top`, `Bottom`, `Left`, `Right`, `All` or `None`";
+                return syn::Error::new(side.span(), message)
+                    .to_compile_error()
+                    .into();
+            }
+        }
+    }
+    let Some((name, _)) = SIDES.iter().find(|(_, bits)| *bits == union) else {
+        let message = "`BorderType` can only hold a single side, every side or none";
+        return syn::Error::new_spanned(sides, message)
+            .to_compile_error()
+            .into();
     };
 
-    TokenStream::from(expanded)
+    let ident = Ident::new(name, proc_macro2::Span::call_site());
+    quote!(::tuxtui::widgets::block::BorderType::#ident).into()
+}
+
+/// Create a `tuxtui::text::Span`, with an optional style after a `;`.
+///
+/// A string literal first is a format string with the arguments that follow
+/// it; anything else is used as the content as is. The style is a
+/// comma-separated list of `fg = color`, `bg = color`, `style = expr` and
+/// modifiers such as `bold` or `italic`. Colors may be named, given as a
+/// string accepted by `Color::parse`, or be any `Color` expression.
+///
+/// # Example
+///
+/// ```ignore
+/// use tuxtui::span;
+///
+/// let error = "not found";
+/// let span = span!("err: {}", error; fg = red, bold);
+/// let plain = span!(error);
+/// ```
+#[proc_macro]
+pub fn span(input: TokenStream) -> TokenStream {
+    parse_macro_input!(input as text::Input).span().into()
+}
+
+/// Create a `tuxtui::text::Line` from anything convertible to a span, with
+/// an optional style after a `;` as for `span!`.
+///
+/// # Example
+///
+/// ```ignore
+/// use tuxtui::{line, span};
+///
+/// let line = line![span!("1"; bold), " of ", span!("3"; fg = gray)];
+/// let title = line!["Title"; fg = "#ff8800", underlined];
+/// ```
+#[proc_macro]
+pub fn line(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as text::Input);
+    input
+        .join(&quote!(::tuxtui::text::Line), &quote!(::tuxtui::text::Span))
+        .into()
+}
+
+/// Create a `tuxtui::text::Text` from anything convertible to a line, with
+/// an optional style after a `;` as for `span!`.
+///
+/// # Example
+///
+/// ```ignore
+/// use tuxtui::{line, text};
+///
+/// let text = text![line!["first"], "second"; italic];
+/// ```
+#[proc_macro]
+pub fn text(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as text::Input);
+    input
+        .join(&quote!(::tuxtui::text::Text), &quote!(::tuxtui::text::Line))
+        .into()
 }
 
-/// Derive macro for creating styled components (placeholder for future expansion).
-#[proc_macro_derive(Styled)]
-pub fn derive_styled(_input: TokenStream) -> TokenStream {
-    TokenStream::new()
+/// Derive `tuxtui::style::Stylize` for a struct with a `Style` field.
+///
+/// Styles are patched into the field named `style`, or into the field given
+/// with `#[styled(field = "...")]`, so the struct gets the whole fluent
+/// styling API such as `.red().bold()`.
+///
+/// # Example
+///
+/// ```ignore
+/// use tuxtui::Styled;
+/// use tuxtui::style::{Style, Stylize};
+///
+/// #[derive(Default, Styled)]
+/// #[styled(field = "label_style")]
+/// struct Badge {
+///     label: String,
+///     label_style: Style,
+/// }
+///
+/// let badge = Badge::default().yellow().bold();
+/// ```
+#[proc_macro_derive(Styled, attributes(styled))]
+pub fn derive_styled(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    styled(&input)
+        .unwrap_or_else(syn::Error::into_compile_error)
+        .into()
+}
+
+fn styled(input: &DeriveInput) -> syn::Result<proc_macro2::TokenStream> {
+    let Data::Struct(data) = &input.data else {
+        return Err(syn::Error::new_spanned(
+            &input.ident,
+            "Styled can only be derived for structs",
+        ));
+    };
+
+    let mut field = Ident::new("style", proc_macro2::Span::call_site());
+    for attr in input
+        .attrs
+        .iter()
+        .filter(|attr| attr.path().is_ident("styled"))
+    {
+        attr.parse_nested_meta(|meta| {
+            if meta.path.is_ident("field") {
+                let lit: LitStr = meta.value()?.parse()?;
+                field = lit.parse()?;
+                Ok(())
+            } else {
+                Err(meta.error("expected `field = \"...\"`"))
+            }
+        })?;
+    }
+    if !data
+        .fields
+        .iter()
+        .any(|f| f.ident.as_ref().is_some_and(|ident| *ident == field))
+    {
+        return Err(syn::Error::new_spanned(
+            &input.ident,
+            format!("Styled needs a `{field}: Style` field"),
+        ));
+    }
+
+    let name = &input.ident;
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+    Ok(quote! {
+        impl #impl_generics ::tuxtui::style::Stylize for #name #ty_generics #where_clause {
+            fn style(mut self, style: ::tuxtui::style::Style) -> Self {
+                self.#field = self.#field.patch(style);
+                self
+            }
+        }
+    })
+}
+
+/// Derive `tuxtui::keymap::KeyBindings` for an enum of actions.
+///
+/// Each unit variant is bound to the keys in its `#[key("...")]`
+/// attributes, written as for `tuxtui::keymap::KeyBinding`. Bindings are
+/// checked at compile time, and a key may only be bound once.
+///
+/// # Example
+///
+/// ```ignore
+/// use tuxtui::KeyBindings;
+/// use tuxtui::event::{KeyCode, KeyEvent, KeyModifiers};
+/// use tuxtui::keymap::KeyBindings as _;
+///
+/// #[derive(Clone, Copy, Debug, PartialEq, KeyBindings)]
+/// enum Action {
+///     #[key("ctrl+s")]
+///     Save,
+///     #[key("q", "esc")]
+///     Quit,
+/// }
+///
+/// let event = KeyEvent::new(KeyCode::Char('s'), KeyModifiers::CTRL);
+/// assert_eq!(Action::from_key_event(&event), Some(Action::Save));
+/// ```
+#[proc_macro_derive(KeyBindings, attributes(key))]
+pub fn derive_key_bindings(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    key_bindings(&input)
+        .unwrap_or_else(syn::Error::into_compile_error)
+        .into()
+}
+
+fn key_bindings(input: &DeriveInput) -> syn::Result<proc_macro2::TokenStream> {
+    let Data::Enum(data) = &input.data else {
+        return Err(syn::Error::new_spanned(
+            &input.ident,
+            "KeyBindings can only be derived for enums",
+        ));
+    };
+
+    let mut seen: Vec<KeyBinding> = Vec::new();
+    let mut keys = Vec::new();
+    let mut variants = Vec::new();
+    for variant in &data.variants {
+        if !matches!(variant.fields, Fields::Unit) {
+            return Err(syn::Error::new_spanned(
+                variant,
+                "KeyBindings variants cannot have fields",
+            ));
+        }
+        for attr in variant
+            .attrs
+            .iter()
+            .filter(|attr| attr.path().is_ident("key"))
+        {
+            let lits = attr.parse_args_with(Punctuated::<LitStr, Token![,]>::parse_terminated)?;
+            for lit in lits {
+                let binding = KeyBinding::parse(&lit.value())
+                    .map_err(|err| syn::Error::new(lit.span(), err))?;
+                if seen.contains(&binding) {
+                    return Err(syn::Error::new(
+                        lit.span(),
+                        format!("`{binding}` is bound more than once"),
+                    ));
+                }
+                seen.push(binding);
+                keys.push(lit);
+                variants.push(&variant.ident);
+            }
+        }
+    }
+
+    let name = &input.ident;
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+    Ok(quote! {
+        impl #impl_generics ::tuxtui::keymap::KeyBindings for #name #ty_generics #where_clause {
+            fn key_bindings() -> ::std::vec::Vec<(::tuxtui::keymap::KeyBinding, Self)> {
+                ::std::vec![
+                    #((
+                        ::tuxtui::keymap::KeyBinding::parse(#keys)
+                            .expect("checked by #[derive(KeyBindings)]"),
+                        Self::#variants,
+                    )),*
+                ]
+            }
+
+            fn from_key_event(event: &::tuxtui::event::KeyEvent) -> ::core::option::Option<Self> {
+                #(
+                    if ::tuxtui::keymap::KeyBinding::parse(#keys)
+                        .is_ok_and(|binding| binding.matches(event))
+                    {
+                        return ::core::option::Option::Some(Self::#variants);
+                    }
+                )*
+                ::core::option::Option::None
+            }
+        }
+    })
 }
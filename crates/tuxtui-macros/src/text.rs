@@ -0,0 +1,168 @@
+//! Parsing and expansion for the `span!`, `line!` and `text!` macros.
+
+use proc_macro2::TokenStream;
+use quote::{ToTokens, quote};
+use syn::parse::{Parse, ParseStream};
+use syn::punctuated::Punctuated;
+use syn::{Expr, ExprLit, Ident, Lit, Token};
+use tuxtui_core::style::Color;
+
+/// The modifiers that can be named in a style, with their `Modifier` flag.
+const MODIFIERS: [(&str, &str); 9] = [
+    ("bold", "BOLD"),
+    ("dim", "DIM"),
+    ("italic", "ITALIC"),
+    ("underlined", "UNDERLINED"),
+    ("slow_blink", "SLOW_BLINK"),
+    ("rapid_blink", "RAPID_BLINK"),
+    ("reversed", "REVERSED"),
+    ("hidden", "HIDDEN"),
+    ("crossed_out", "CROSSED_OUT"),
+];
+
+/// Macro input: comma-separated expressions, then optionally `;` and a
+/// comma-separated style.
+pub struct Input {
+    items: Vec<Expr>,
+    style: Option<Vec<StyleItem>>,
+}
+
+/// One part of an inline style, such as `fg = red` or `bold`.
+enum StyleItem {
+    Fg(TokenStream),
+    Bg(TokenStream),
+    Style(Expr),
+    Modifier(Ident),
+}
+
+impl Parse for Input {
+    fn parse(input: ParseStream<'_>) -> syn::Result<Self> {
+        let mut items = Vec::new();
+        while !input.is_empty() && !input.peek(Token![;]) {
+            items.push(input.parse()?);
+            if input.is_empty() || input.peek(Token![;]) {
+                break;
+            }
+            input.parse::<Token![,]>()?;
+        }
+        let style = if input.parse::<Option<Token![;]>>()?.is_some() {
+            let style = Punctuated::<StyleItem, Token![,]>::parse_terminated(input)?;
+            Some(style.into_iter().collect())
+        } else {
+            None
+        };
+        Ok(Self { items, style })
+    }
+}
+
+impl Parse for StyleItem {
+    fn parse(input: ParseStream<'_>) -> syn::Result<Self> {
+        let name: Ident = input.parse()?;
+        if name == "fg" || name == "bg" || name == "style" {
+            input.parse::<Token![=]>()?;
+            let value: Expr = input.parse()?;
+            return Ok(match name.to_string().as_str() {
+                "fg" => Self::Fg(color(value)?),
+                "bg" => Self::Bg(color(value)?),
+                _ => Self::Style(value),
+            });
+        }
+        if MODIFIERS.iter().any(|(modifier, _)| name == modifier) {
+            return Ok(Self::Modifier(name));
+        }
+        Err(syn::Error::new(
+            name.span(),
+            "expected `fg = ...`, `bg = ...`, `style = ...` or a modifier such as `bold`",
+        ))
+    }
+}
+
+/// Expand a color given by name, as a string, or as an expression.
+///
+/// Names and strings are parsed at compile time; an identifier that is not a
+/// color name is taken to be a variable.
+fn color(value: Expr) -> syn::Result<TokenStream> {
+    let (text, span) = match &value {
+        Expr::Path(path) if path.path.get_ident().is_some() => {
+            let ident = path.path.get_ident().map(ToString::to_string);
+            match ident.as_deref().map(Color::parse) {
+                Some(Ok(color)) => return Ok(color_tokens(color)),
+                _ => return Ok(value.to_token_stream()),
+            }
+        }
+        Expr::Lit(ExprLit {
+            lit: Lit::Str(lit), ..
+        }) => (lit.value(), lit.span()),
+        _ => return Ok(value.to_token_stream()),
+    };
+    Color::parse(&text)
+        .map(color_tokens)
+        .map_err(|err| syn::Error::new(span, err))
+}
+
+fn color_tokens(color: Color) -> TokenStream {
+    let color: TokenStream = format!("{color:?}")
+        .parse()
+        .expect("the debug output of a color is valid Rust");
+    quote!(::tuxtui::style::Color::#color)
+}
+
+impl Input {
+    /// Get the style expression, if a style was given.
+    fn style(&self) -> Option<TokenStream> {
+        let items = self.style.as_ref()?.iter().map(|item| match item {
+            StyleItem::Fg(color) => quote!(.fg(#color)),
+            StyleItem::Bg(color) => quote!(.bg(#color)),
+            StyleItem::Style(style) => quote!(.patch(#style)),
+            StyleItem::Modifier(name) => {
+                let flag = MODIFIERS
+                    .iter()
+                    .find(|(modifier, _)| name == modifier)
+                    .map(|(_, flag)| Ident::new(flag, name.span()))
+                    .expect("checked when parsing");
+                quote!(.add_modifier(::tuxtui::style::Modifier::#flag))
+            }
+        });
+        Some(quote!(::tuxtui::style::Style::default() #(#items)*))
+    }
+
+    /// Expand `span!`: a formatted or plain string with a style.
+    pub fn span(&self) -> TokenStream {
+        let items = &self.items;
+        let content = match items.first() {
+            Some(Expr::Lit(ExprLit {
+                lit: Lit::Str(_), ..
+            })) => quote!(::std::format!(#(#items),*)),
+            Some(item) if items.len() == 1 => quote!(#item),
+            _ => {
+                return syn::Error::new(
+                    proc_macro2::Span::call_site(),
+                    "expected a format string or a single expression",
+                )
+                .into_compile_error();
+            }
+        };
+        let style = self
+            .style()
+            .unwrap_or_else(|| quote!(::tuxtui::style::Style::default()));
+        quote!(::tuxtui::text::Span::styled(#content, #style))
+    }
+
+    /// Expand `line!` or `text!`: a `container` of `part`s with a style.
+    pub fn join(&self, container: &TokenStream, part: &TokenStream) -> TokenStream {
+        let items = &self.items;
+        let value = quote! {
+            #container::from(::std::vec![
+                #(::core::convert::Into::<#part>::into(#items)),*
+            ])
+        };
+        match self.style() {
+            Some(style) => quote!({
+                let mut value = #value;
+                value.style = #style;
+                value
+            }),
+            None => value,
+        }
+    }
+}
@@ -0,0 +1,18 @@
+use tuxtui::border;
+use tuxtui::widgets::block::BorderType;
+
+#[test]
+fn test_border() {
+    assert_eq!(border!(All), BorderType::All);
+    assert_eq!(border!(None), BorderType::None);
+    assert_eq!(border!(Left), BorderType::Left);
+    assert_eq!(border!(Top | Top), BorderType::Top);
+    assert_eq!(border!(Top | Bottom | Left | Right), BorderType::All);
+    assert_eq!(border!(None | Right), BorderType::Right);
+}
+
+#[test]
+fn test_border_errors() {
+    let tests = trybuild::TestCases::new();
+    tests.compile_fail("tests/ui/border_*.rs");
+}
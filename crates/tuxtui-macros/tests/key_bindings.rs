@@ -0,0 +1,47 @@
+use tuxtui::KeyBindings;
+use tuxtui::event::{KeyCode, KeyEvent, KeyModifiers};
+use tuxtui::keymap::{KeyBinding, KeyBindings as _};
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, KeyBindings)]
+enum Action {
+    #[key("ctrl+s")]
+    Save,
+    #[key("q", "esc")]
+    Quit,
+    #[key("alt+shift+up")]
+    MoveUp,
+    /// Not bound to any key
+    Noop,
+}
+
+#[test]
+fn test_from_key_event() {
+    let event = KeyEvent::new(KeyCode::Char('s'), KeyModifiers::CTRL);
+    assert_eq!(Action::from_key_event(&event), Some(Action::Save));
+    assert_eq!(
+        Action::from_key_event(&KeyCode::Esc.into()),
+        Some(Action::Quit)
+    );
+    assert_eq!(Action::from_key_event(&KeyCode::Char('s').into()), None);
+
+    let modifiers = KeyModifiers {
+        shift: true,
+        ..KeyModifiers::ALT
+    };
+    let event = KeyEvent::new(KeyCode::Up, modifiers);
+    assert_eq!(Action::from_key_event(&event), Some(Action::MoveUp));
+}
+
+#[test]
+fn test_key_bindings_table() {
+    let bindings = Action::key_bindings();
+    let expected = [
+        ("ctrl+s", Action::Save),
+        ("q", Action::Quit),
+        ("esc", Action::Quit),
+        ("alt+shift+up", Action::MoveUp),
+    ]
+    .map(|(key, action)| (KeyBinding::parse(key).unwrap(), action));
+    assert_eq!(bindings, expected);
+    assert!(!bindings.iter().any(|(_, action)| *action == Action::Noop));
+}
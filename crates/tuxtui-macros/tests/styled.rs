@@ -0,0 +1,54 @@
+use tuxtui::Styled;
+use tuxtui::style::{Color, Modifier, Style, Stylize};
+
+#[derive(Debug, Default, Styled)]
+struct Label<'a> {
+    text: &'a str,
+    style: Style,
+}
+
+#[derive(Debug, Default, Styled)]
+#[styled(field = "badge_style")]
+struct Badge<T> {
+    value: T,
+    badge_style: Style,
+}
+
+#[test]
+fn test_default_field() {
+    let label = Label {
+        text: "hi",
+        ..Label::default()
+    }
+    .red()
+    .bold();
+    assert_eq!(label.text, "hi");
+    assert_eq!(
+        label.style,
+        Style::default().fg(Color::Red).add_modifier(Modifier::BOLD)
+    );
+}
+
+#[test]
+fn test_configured_field() {
+    let badge = Badge {
+        value: 3,
+        badge_style: Style::default().bg(Color::Blue),
+    }
+    .fg(Color::White)
+    .italic();
+    assert_eq!(badge.value, 3);
+    assert_eq!(
+        badge.badge_style,
+        Style::default()
+            .fg(Color::White)
+            .bg(Color::Blue)
+            .add_modifier(Modifier::ITALIC)
+    );
+}
+
+#[test]
+fn test_styled_errors() {
+    let tests = trybuild::TestCases::new();
+    tests.compile_fail("tests/ui/styled_*.rs");
+}
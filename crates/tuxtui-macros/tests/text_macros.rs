@@ -0,0 +1,57 @@
+use tuxtui::style::{Color, Modifier, Style};
+use tuxtui::text::{Line, Span, Text};
+use tuxtui::{line, span, text};
+
+#[test]
+fn test_span() {
+    let error = "not found";
+    assert_eq!(
+        span!("err: {}", error; fg = red, bold),
+        Span::styled(
+            "err: not found",
+            Style::default().fg(Color::Red).add_modifier(Modifier::BOLD)
+        )
+    );
+    assert_eq!(span!("{error}"), Span::raw("not found"));
+    assert_eq!(span!(error), Span::raw("not found"));
+
+    let accent = Color::Indexed(208);
+    assert_eq!(
+        span!("x"; fg = accent, bg = "#102030", italic, crossed_out),
+        Span::styled(
+            "x",
+            Style::default()
+                .fg(accent)
+                .bg(Color::Rgb(0x10, 0x20, 0x30))
+                .add_modifier(Modifier::ITALIC | Modifier::CROSSED_OUT)
+        )
+    );
+    let base = Style::default().bg(Color::Blue);
+    assert_eq!(
+        span!("x"; style = base, fg = light_green),
+        Span::styled("x", base.fg(Color::LightGreen))
+    );
+}
+
+#[test]
+fn test_line_and_text() {
+    let count = span!("3"; bold);
+    let line = line![span!("1"; bold), " of ", count.clone()];
+    assert_eq!(
+        line,
+        Line::from(vec![span!("1"; bold), Span::raw(" of "), count])
+    );
+    assert_eq!(line![], Line::from(Vec::<Span>::new()));
+
+    let title = line!["Title"; fg = yellow, underlined];
+    let style = Style::default()
+        .fg(Color::Yellow)
+        .add_modifier(Modifier::UNDERLINED);
+    assert_eq!(title.style, style);
+    assert_eq!(title.spans, [Span::raw("Title")]);
+
+    let text = text![title.clone(), "second"; dim];
+    assert_eq!(text.lines, [title, Line::from("second")]);
+    assert_eq!(text.style, Style::default().add_modifier(Modifier::DIM));
+    assert_eq!(text![String::from("a")], Text::from("a"));
+}
@@ -0,0 +1,3 @@
+fn main() {
+    let _ = tuxtui::border!(Top + Bottom);
+}
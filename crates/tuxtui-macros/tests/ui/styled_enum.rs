@@ -0,0 +1,6 @@
+#[derive(tuxtui::Styled)]
+enum Label {
+    Plain,
+}
+
+fn main() {}
@@ -0,0 +1,6 @@
+#[derive(tuxtui::Styled)]
+struct Label {
+    text: String,
+}
+
+fn main() {}
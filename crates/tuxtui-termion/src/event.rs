@@ -0,0 +1,153 @@
+//! Conversion of termion input events to the tuxtui event model.
+
+use termion::event as tm;
+use tuxtui_core::event::{
+    Event, KeyCode, KeyEvent, KeyModifiers, MouseButton, MouseEvent, MouseEventKind,
+};
+
+/// Convert a termion event.
+///
+/// Returns `None` for sequences termion does not recognize.
+///
+/// # Example
+///
+/// ```
+/// use termion::event::{Event as TermionEvent, Key};
+/// use tuxtui_core::event::{Event, KeyCode, KeyEvent, KeyModifiers};
+/// use tuxtui_termion::event::event;
+///
+/// let key = KeyEvent::new(KeyCode::Char('s'), KeyModifiers::CTRL);
+/// assert_eq!(event(TermionEvent::Key(Key::Ctrl('s'))), Some(Event::Key(key)));
+/// ```
+#[must_use]
+pub fn event(event: tm::Event) -> Option<Event> {
+    Some(match event {
+        tm::Event::Key(key) => Event::Key(key_event(key)?),
+        tm::Event::Mouse(mouse) => Event::Mouse(mouse_event(mouse)),
+        tm::Event::Unsupported(_) => return None,
+    })
+}
+
+/// Convert a termion key press.
+///
+/// Termion folds modifiers into the key, such as `Key::CtrlUp`, and reports
+/// enter and tab as characters; both are unfolded here. Ctrl with space
+/// arrives as `Key::Null`.
+#[must_use]
+pub const fn key_event(key: tm::Key) -> Option<KeyEvent> {
+    const NONE: KeyModifiers = KeyModifiers::NONE;
+    const SHIFT: KeyModifiers = KeyModifiers::SHIFT;
+    const CTRL: KeyModifiers = KeyModifiers::CTRL;
+    const ALT: KeyModifiers = KeyModifiers::ALT;
+    let (code, modifiers) = match key {
+        tm::Key::Char('\n') => (KeyCode::Enter, NONE),
+        tm::Key::Char('\t') => (KeyCode::Tab, NONE),
+        tm::Key::Char(c) => (KeyCode::Char(c), NONE),
+        tm::Key::Alt(c) => (KeyCode::Char(c), ALT),
+        tm::Key::Ctrl(c) => (KeyCode::Char(c), CTRL),
+        tm::Key::Null => (KeyCode::Char(' '), CTRL),
+        tm::Key::Backspace => (KeyCode::Backspace, NONE),
+        tm::Key::BackTab => (KeyCode::BackTab, NONE),
+        tm::Key::Esc => (KeyCode::Esc, NONE),
+        tm::Key::Left => (KeyCode::Left, NONE),
+        tm::Key::ShiftLeft => (KeyCode::Left, SHIFT),
+        tm::Key::AltLeft => (KeyCode::Left, ALT),
+        tm::Key::CtrlLeft => (KeyCode::Left, CTRL),
+        tm::Key::Right => (KeyCode::Right, NONE),
+        tm::Key::ShiftRight => (KeyCode::Right, SHIFT),
+        tm::Key::AltRight => (KeyCode::Right, ALT),
+        tm::Key::CtrlRight => (KeyCode::Right, CTRL),
+        tm::Key::Up => (KeyCode::Up, NONE),
+        tm::Key::ShiftUp => (KeyCode::Up, SHIFT),
+        tm::Key::AltUp => (KeyCode::Up, ALT),
+        tm::Key::CtrlUp => (KeyCode::Up, CTRL),
+        tm::Key::Down => (KeyCode::Down, NONE),
+        tm::Key::ShiftDown => (KeyCode::Down, SHIFT),
+        tm::Key::AltDown => (KeyCode::Down, ALT),
+        tm::Key::CtrlDown => (KeyCode::Down, CTRL),
+        tm::Key::Home => (KeyCode::Home, NONE),
+        tm::Key::CtrlHome => (KeyCode::Home, CTRL),
+        tm::Key::End => (KeyCode::End, NONE),
+        tm::Key::CtrlEnd => (KeyCode::End, CTRL),
+        tm::Key::PageUp => (KeyCode::PageUp, NONE),
+        tm::Key::PageDown => (KeyCode::PageDown, NONE),
+        tm::Key::Delete => (KeyCode::Delete, NONE),
+        tm::Key::Insert => (KeyCode::Insert, NONE),
+        tm::Key::F(n) => (KeyCode::F(n), NONE),
+        _ => return None,
+    };
+    Some(KeyEvent::new(code, modifiers))
+}
+
+/// Convert a termion mouse event, including wheel events.
+///
+/// Termion reports one-based coordinates, which are converted to zero-based
+/// ones. It reports neither modifiers nor the button of release and drag
+/// events, so those are attributed to the left button.
+///
+/// # Example
+///
+/// ```
+/// use termion::event::{MouseButton, MouseEvent};
+/// use tuxtui_core::event::MouseEventKind;
+/// use tuxtui_termion::event::mouse_event;
+///
+/// let event = mouse_event(MouseEvent::Press(MouseButton::WheelDown, 1, 1));
+/// assert_eq!(event.kind, MouseEventKind::ScrollDown);
+/// assert_eq!((event.column, event.row), (0, 0));
+/// ```
+#[must_use]
+pub const fn mouse_event(event: tm::MouseEvent) -> MouseEvent {
+    let (kind, x, y) = match event {
+        tm::MouseEvent::Press(button, x, y) => {
+            let kind = match button {
+                tm::MouseButton::Left => MouseEventKind::Down(MouseButton::Left),
+                tm::MouseButton::Right => MouseEventKind::Down(MouseButton::Right),
+                tm::MouseButton::Middle => MouseEventKind::Down(MouseButton::Middle),
+                tm::MouseButton::WheelUp => MouseEventKind::ScrollUp,
+                tm::MouseButton::WheelDown => MouseEventKind::ScrollDown,
+                tm::MouseButton::WheelLeft => MouseEventKind::ScrollLeft,
+                tm::MouseButton::WheelRight => MouseEventKind::ScrollRight,
+            };
+            (kind, x, y)
+        }
+        tm::MouseEvent::Release(x, y) => (MouseEventKind::Up(MouseButton::Left), x, y),
+        tm::MouseEvent::Hold(x, y) => (MouseEventKind::Drag(MouseButton::Left), x, y),
+    };
+    MouseEvent::new(kind, x.saturating_sub(1), y.saturating_sub(1))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_key_conversion() {
+        let key = |key| key_event(key).unwrap();
+        assert_eq!(key(tm::Key::Char('\n')), KeyEvent::from(KeyCode::Enter));
+        assert_eq!(key(tm::Key::Char('\t')), KeyEvent::from(KeyCode::Tab));
+        assert_eq!(
+            key(tm::Key::Alt('x')),
+            KeyEvent::new(KeyCode::Char('x'), KeyModifiers::ALT)
+        );
+        assert_eq!(
+            key(tm::Key::ShiftUp),
+            KeyEvent::new(KeyCode::Up, KeyModifiers::SHIFT)
+        );
+        assert_eq!(
+            key(tm::Key::Null),
+            KeyEvent::new(KeyCode::Char(' '), KeyModifiers::CTRL)
+        );
+        assert_eq!(key_event(tm::Key::__IsNotComplete), None);
+        assert_eq!(event(tm::Event::Unsupported(vec![0x1b])), None);
+    }
+
+    #[test]
+    fn test_mouse_conversion() {
+        let event = mouse_event(tm::MouseEvent::Press(tm::MouseButton::WheelLeft, 5, 3));
+        assert_eq!(event, MouseEvent::new(MouseEventKind::ScrollLeft, 4, 2));
+
+        let event = mouse_event(tm::MouseEvent::Hold(1, 1));
+        assert_eq!(event.kind, MouseEventKind::Drag(MouseButton::Left));
+    }
+}
@@ -15,26 +15,133 @@
 //! let backend = TermionBackend::new(stdout());
 //! let mut terminal = Terminal::new(backend).unwrap();
 //! ```
+//!
+//! [`TermionBackend::fullscreen`] instead wraps the writer in termion's
+//! raw mode, mouse and alternate screen guards, which restore the terminal
+//! when the backend is dropped:
+//!
+//! ```no_run
+//! use tuxtui_termion::TermionBackend;
+//! use tuxtui_core::terminal::Terminal;
+//! use std::io::stdout;
+//!
+//! let backend = TermionBackend::fullscreen(stdout()).unwrap();
+//! let mut terminal = Terminal::new(backend).unwrap();
+//! ```
 
 #![forbid(unsafe_code)]
 #![warn(missing_docs)]
 
+pub mod event;
+
+use std::fs::{File, OpenOptions};
 use std::io::{self, Write};
+use std::os::fd::AsFd;
+use std::time::Duration;
+use termion::AsyncReader;
+use termion::input::{Events, MouseTerminal, TermRead};
+use termion::raw::{IntoRawMode, RawTerminal};
+use termion::screen::{AlternateScreen, IntoAlternateScreen};
 use termion::{clear, cursor, style};
-use tuxtui_core::backend::Backend;
+use tuxtui_core::backend::{self as core_backend, Backend, Capabilities};
 use tuxtui_core::buffer::Cell;
+use tuxtui_core::event::Event;
 use tuxtui_core::geometry::{Position, Rect};
 use tuxtui_core::style::{Color as TuxColor, Modifier, Style};
 
+/// How long to wait for the terminal to answer a query.
+const QUERY_TIMEOUT: Duration = Duration::from_millis(500);
+
 /// Termion backend.
+///
+/// Raw mode is switched on the controlling terminal, whatever the writer.
+/// Input is read from the terminal on a background thread started by the
+/// first [`Backend::poll_event`].
 pub struct TermionBackend<W: Write> {
     writer: W,
+    last_size: Option<(u16, u16)>,
+    raw: Option<RawTerminal<File>>,
+    input: Option<Events<AsyncReader>>,
 }
 
+/// A backend writing through termion's raw mode, mouse and alternate screen
+/// guards, as made by [`TermionBackend::fullscreen`].
+pub type FullscreenBackend<W> = TermionBackend<AlternateScreen<MouseTerminal<RawTerminal<W>>>>;
+
 impl<W: Write> TermionBackend<W> {
     /// Create a new termion backend.
-    pub fn new(writer: W) -> Self {
-        Self { writer }
+    pub const fn new(writer: W) -> Self {
+        Self {
+            writer,
+            last_size: None,
+            raw: None,
+            input: None,
+        }
+    }
+
+    /// Get the writer.
+    pub const fn writer(&self) -> &W {
+        &self.writer
+    }
+
+    /// Get the writer mutably.
+    pub const fn writer_mut(&mut self) -> &mut W {
+        &mut self.writer
+    }
+
+    /// Send further output to `writer`, returning the previous one.
+    ///
+    /// The previous writer is returned as is; flush it if it buffers.
+    pub const fn replace_writer(&mut self, writer: W) -> W {
+        std::mem::replace(&mut self.writer, writer)
+    }
+
+    /// Get the writer back, dropping the backend.
+    pub fn into_writer(self) -> W {
+        self.writer
+    }
+
+    /// Create a backend that owns the terminal in raw mode.
+    ///
+    /// The previous mode is restored when the backend is dropped.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `writer` is not a terminal.
+    pub fn with_raw_mode(writer: W) -> io::Result<TermionBackend<RawTerminal<W>>>
+    where
+        W: AsFd,
+    {
+        Ok(TermionBackend::new(writer.into_raw_mode()?))
+    }
+
+    /// Create a backend that owns the terminal in raw mode, with mouse
+    /// reporting and on the alternate screen.
+    ///
+    /// All three are undone when the backend is dropped.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `writer` is not a terminal.
+    pub fn fullscreen(writer: W) -> io::Result<FullscreenBackend<W>>
+    where
+        W: AsFd,
+    {
+        let writer = MouseTerminal::from(writer.into_raw_mode()?).into_alternate_screen()?;
+        Ok(TermionBackend::new(writer))
+    }
+
+    /// Ask the terminal for one of its default colors.
+    ///
+    /// The query goes straight to the controlling terminal, in raw mode so
+    /// the reply is not echoed. Without one, the color is unknown.
+    fn query_color(code: u8) -> io::Result<Option<TuxColor>> {
+        let Ok(mut tty) = OpenOptions::new().read(true).write(true).open("/dev/tty") else {
+            return Ok(None);
+        };
+        // The previous mode is restored when `raw` is dropped.
+        let mut raw = tty.try_clone()?.into_raw_mode()?;
+        core_backend::query_color(&mut raw, &mut tty, code, QUERY_TIMEOUT)
     }
 
     fn convert_fg_color(&mut self, color: TuxColor) -> io::Result<()> {
@@ -85,6 +192,35 @@ impl<W: Write> TermionBackend<W> {
         }
     }
 
+    /// Turn modifiers off.
+    ///
+    /// Bold and dim are turned off together, as are both blink speeds.
+    fn remove_modifiers(&mut self, modifiers: Modifier) -> io::Result<()> {
+        if modifiers.intersects(Modifier::BOLD | Modifier::DIM) {
+            // Normal intensity; `NoBold` is double underline on many terminals.
+            write!(self.writer, "{}", style::NoFaint)?;
+        }
+        if modifiers.contains(Modifier::ITALIC) {
+            write!(self.writer, "{}", style::NoItalic)?;
+        }
+        if modifiers.contains(Modifier::UNDERLINED) {
+            write!(self.writer, "{}", style::NoUnderline)?;
+        }
+        if modifiers.intersects(Modifier::SLOW_BLINK | Modifier::RAPID_BLINK) {
+            write!(self.writer, "{}", style::NoBlink)?;
+        }
+        if modifiers.contains(Modifier::REVERSED) {
+            write!(self.writer, "{}", style::NoInvert)?;
+        }
+        if modifiers.contains(Modifier::HIDDEN) {
+            self.writer.write_all(b"\x1b[28m")?;
+        }
+        if modifiers.contains(Modifier::CROSSED_OUT) {
+            write!(self.writer, "{}", style::NoCrossedOut)?;
+        }
+        Ok(())
+    }
+
     fn apply_modifiers(&mut self, modifiers: Modifier) -> io::Result<()> {
         if modifiers.contains(Modifier::BOLD) {
             write!(self.writer, "{}", style::Bold)?;
@@ -153,9 +289,9 @@ impl<W: Write> Backend for TermionBackend<W> {
             self.convert_bg_color(bg)?;
         }
 
-        self.apply_modifiers(cell.style.add_modifier)?;
+        self.apply_modifiers(cell.style.modifiers())?;
 
-        write!(self.writer, "{}", cell.symbol)?;
+        write!(self.writer, "{}", cell.symbol())?;
         write!(self.writer, "{}", style::Reset)?;
 
         Ok(())
@@ -168,7 +304,8 @@ impl<W: Write> Backend for TermionBackend<W> {
         if let Some(bg) = style.bg {
             self.convert_bg_color(bg)?;
         }
-        self.apply_modifiers(style.add_modifier)?;
+        self.remove_modifiers(style.sub_modifier)?;
+        self.apply_modifiers(style.modifiers())?;
         Ok(())
     }
 
@@ -181,12 +318,16 @@ impl<W: Write> Backend for TermionBackend<W> {
     }
 
     fn enable_raw_mode(&mut self) -> Result<(), Self::Error> {
-        // Termion handles raw mode through RawTerminal wrapper
+        if self.raw.is_none() {
+            let tty = OpenOptions::new().write(true).open("/dev/tty")?;
+            self.raw = Some(tty.into_raw_mode()?);
+        }
         Ok(())
     }
 
     fn disable_raw_mode(&mut self) -> Result<(), Self::Error> {
-        // Termion handles raw mode through RawTerminal wrapper
+        // Dropping the guard restores the mode it replaced.
+        self.raw = None;
         Ok(())
     }
 
@@ -194,6 +335,60 @@ impl<W: Write> Backend for TermionBackend<W> {
         write!(self.writer, "{}", termion::screen::ToAlternateScreen)
     }
 
+    fn set_title(&mut self, title: &str) -> Result<(), Self::Error> {
+        write!(self.writer, "\x1b]0;{title}\x07")?;
+        self.writer.flush()
+    }
+
+    fn bell(&mut self) -> Result<(), Self::Error> {
+        self.writer.write_all(b"\x07")?;
+        self.writer.flush()
+    }
+
+    fn append_lines(&mut self, lines: u16) -> Result<(), Self::Error> {
+        for _ in 0..lines {
+            self.writer.write_all(b"\n")?;
+        }
+        Ok(())
+    }
+
+    /// Raw mode and input go through the terminal of this process.
+    fn is_process_terminal(&self) -> bool {
+        true
+    }
+
+    /// Report a resize when the terminal size changed since the last poll,
+    /// then pending key and mouse input.
+    ///
+    /// Mouse input is only reported while mouse reporting is on, as with
+    /// [`TermionBackend::fullscreen`].
+    fn poll_event(&mut self) -> Result<Option<Event>, Self::Error> {
+        if let Ok(size) = termion::terminal_size() {
+            let changed = self.last_size.is_some_and(|last| last != size);
+            self.last_size = Some(size);
+            if changed {
+                return Ok(Some(Event::Resize(size.0, size.1)));
+            }
+        }
+        let input = self
+            .input
+            .get_or_insert_with(|| termion::async_stdin().events());
+        // Sequences termion cannot parse are skipped.
+        Ok(input.by_ref().flatten().find_map(event::event))
+    }
+
+    fn capabilities(&self) -> Capabilities {
+        Capabilities::from_env()
+    }
+
+    fn foreground_color(&mut self) -> Result<Option<TuxColor>, Self::Error> {
+        Self::query_color(10)
+    }
+
+    fn background_color(&mut self) -> Result<Option<TuxColor>, Self::Error> {
+        Self::query_color(11)
+    }
+
     fn leave_alternate_screen(&mut self) -> Result<(), Self::Error> {
         write!(self.writer, "{}", termion::screen::ToMainScreen)
     }
@@ -208,4 +403,44 @@ mod tests {
         let buffer = Vec::new();
         let _backend = TermionBackend::new(buffer);
     }
+
+    #[test]
+    fn test_removed_modifiers() {
+        let mut backend = TermionBackend::new(Vec::new());
+        let mut cell = Cell::default();
+        cell.set_symbol("x");
+        cell.set_style(
+            Style::new()
+                .add_modifier(Modifier::BOLD | Modifier::ITALIC)
+                .remove_modifier(Modifier::BOLD),
+        );
+        backend.draw_cell(0, 0, &cell).unwrap();
+        assert_eq!(backend.writer, b"\x1b[1;1H\x1b[3mx\x1b[m");
+
+        backend.writer.clear();
+        backend
+            .set_style(Style::new().remove_modifier(Modifier::DIM | Modifier::HIDDEN))
+            .unwrap();
+        assert_eq!(backend.writer, b"\x1b[22m\x1b[28m");
+    }
+
+    #[test]
+    fn test_raw_mode_guard() {
+        let mut backend = TermionBackend::new(Vec::new());
+        // Without a terminal there is nothing to switch.
+        if backend.enable_raw_mode().is_ok() {
+            assert!(backend.raw.is_some());
+            backend.enable_raw_mode().unwrap();
+        }
+        backend.disable_raw_mode().unwrap();
+        assert!(backend.raw.is_none());
+    }
+
+    #[test]
+    fn test_title_and_bell() {
+        let mut backend = TermionBackend::new(Vec::new());
+        backend.set_title("tuxtui").unwrap();
+        backend.bell().unwrap();
+        assert_eq!(backend.writer, b"\x1b]0;tuxtui\x07\x07");
+    }
 }
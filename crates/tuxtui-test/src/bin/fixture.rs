@@ -0,0 +1,32 @@
+//! A small crossterm app driven by the pseudo-terminal tests.
+//!
+//! It draws a styled header and wide characters, echoes typed characters on
+//! the third row, and exits on `q`.
+
+use crossterm::event::{self, Event, KeyCode};
+use std::io::{self, stdout};
+use tuxtui_core::style::{Color, Modifier, Style};
+use tuxtui_core::terminal::Terminal;
+use tuxtui_crossterm::CrosstermBackend;
+
+fn main() -> io::Result<()> {
+    let mut terminal = Terminal::new(CrosstermBackend::new(stdout()))?;
+    let mut typed = String::new();
+    loop {
+        terminal.draw(|frame| {
+            let buf = frame.buffer_mut();
+            let header = Style::default().fg(Color::Red).add_modifier(Modifier::BOLD);
+            buf.set_string(0, 0, "tuxtui", header);
+            buf.set_string(0, 1, "你好", Style::default().bg(Color::Rgb(0, 128, 255)));
+            buf.set_string(0, 2, &typed, Style::default().fg(Color::Indexed(42)));
+        })?;
+        if let Event::Key(key) = event::read()? {
+            match key.code {
+                KeyCode::Char('q') => break,
+                KeyCode::Char(c) => typed.push(c),
+                _ => {}
+            }
+        }
+    }
+    Ok(())
+}
@@ -0,0 +1,370 @@
+//! # tuxtui-test
+//!
+//! End-to-end testing of tuxtui backends in a pseudo-terminal.
+//!
+//! [`PtyTerminal`] runs a program inside a pseudo-terminal and parses the
+//! escape sequences it emits with a VT100 emulator. The emulated screen is
+//! converted back into a [`Buffer`], so tests can assert on exactly what a
+//! real terminal would show, including styles. This catches regressions in
+//! the escape sequences backends emit, which [`TestBackend`] cannot see.
+//!
+//! The crate also ships the `tuxtui-pty-fixture` binary, a small app on the
+//! crossterm backend used by this crate's own tests.
+//!
+//! ## Example
+//!
+//! ```no_run
+//! use std::time::Duration;
+//! use tuxtui_test::{CommandBuilder, PtyTerminal};
+//!
+//! let mut pty = PtyTerminal::spawn(CommandBuilder::new("my-app"), 80, 24).unwrap();
+//! let screen = pty.wait_for_text("Ready", Duration::from_secs(5)).unwrap();
+//! pty.send("q").unwrap();
+//! assert!(pty.wait(Duration::from_secs(5)).unwrap().success());
+//! ```
+//!
+//! [`TestBackend`]: tuxtui_core::backend::TestBackend
+
+#![forbid(unsafe_code)]
+#![warn(missing_docs)]
+
+use portable_pty::{Child, MasterPty, PtySize, native_pty_system};
+use std::fmt;
+use std::io::{self, Read, Write};
+use std::sync::mpsc::{self, Receiver, TryRecvError};
+use std::thread;
+use std::time::{Duration, Instant};
+use tuxtui_core::buffer::Buffer;
+use tuxtui_core::geometry::{Position, Rect};
+use tuxtui_core::style::{Color, Modifier, Style};
+
+pub use portable_pty::{CommandBuilder, ExitStatus};
+
+/// How long to sleep between checks while waiting on the program.
+const POLL_INTERVAL: Duration = Duration::from_millis(10);
+
+/// A program running in a pseudo-terminal with an emulated screen.
+///
+/// The program's output is read on a background thread and applied to the
+/// screen whenever the terminal is queried. The program is killed when the
+/// terminal is dropped.
+pub struct PtyTerminal {
+    master: Box<dyn MasterPty + Send>,
+    child: Box<dyn Child + Send + Sync>,
+    writer: Box<dyn Write + Send>,
+    output: Receiver<Vec<u8>>,
+    closed: bool,
+    parser: vt100::Parser,
+}
+
+impl PtyTerminal {
+    /// Spawn `command` in a pseudo-terminal of the given size.
+    ///
+    /// `TERM` is set to `xterm-256color` unless the command sets it.
+    pub fn spawn(mut command: CommandBuilder, width: u16, height: u16) -> Result<Self, PtyError> {
+        let pair = native_pty_system()
+            .openpty(pty_size(width, height))
+            .map_err(PtyError::pty)?;
+        if command.get_env("TERM").is_none() {
+            command.env("TERM", "xterm-256color");
+        }
+        let child = pair.slave.spawn_command(command).map_err(PtyError::pty)?;
+        // Only the child keeps the slave open, so reads end when it exits.
+        drop(pair.slave);
+
+        let mut reader = pair.master.try_clone_reader().map_err(PtyError::pty)?;
+        let writer = pair.master.take_writer().map_err(PtyError::pty)?;
+        let (sender, output) = mpsc::channel();
+        thread::spawn(move || {
+            let mut chunk = [0; 4096];
+            while let Ok(read @ 1..) = reader.read(&mut chunk) {
+                if sender.send(chunk[..read].to_vec()).is_err() {
+                    break;
+                }
+            }
+        });
+
+        Ok(Self {
+            master: pair.master,
+            child,
+            writer,
+            output,
+            closed: false,
+            parser: vt100::Parser::new(height, width, 0),
+        })
+    }
+
+    /// Send input to the program, as if typed on the terminal.
+    ///
+    /// Keys other than text are sent as their escape sequences, such as
+    /// `"\x1b[A"` for the up arrow.
+    pub fn send(&mut self, input: impl AsRef<[u8]>) -> Result<(), PtyError> {
+        self.writer.write_all(input.as_ref())?;
+        self.writer.flush()?;
+        Ok(())
+    }
+
+    /// Resize the terminal, which notifies the program.
+    pub fn resize(&mut self, width: u16, height: u16) -> Result<(), PtyError> {
+        self.master
+            .resize(pty_size(width, height))
+            .map_err(PtyError::pty)?;
+        self.parser.screen_mut().set_size(height, width);
+        Ok(())
+    }
+
+    /// Apply the output received so far to the screen.
+    ///
+    /// Returns `true` if the screen may have changed.
+    pub fn update(&mut self) -> bool {
+        let mut changed = false;
+        loop {
+            match self.output.try_recv() {
+                Ok(bytes) => {
+                    self.parser.process(&bytes);
+                    changed = true;
+                }
+                Err(TryRecvError::Empty) => break,
+                Err(TryRecvError::Disconnected) => {
+                    self.closed = true;
+                    break;
+                }
+            }
+        }
+        changed
+    }
+
+    /// Get the current screen contents.
+    pub fn buffer(&mut self) -> Buffer {
+        self.update();
+        screen_to_buffer(self.parser.screen())
+    }
+
+    /// Get the cursor position on the screen.
+    pub fn cursor_position(&mut self) -> Position {
+        self.update();
+        let (row, column) = self.parser.screen().cursor_position();
+        Position::new(column, row)
+    }
+
+    /// Check if the program switched to the alternate screen.
+    pub fn is_alternate_screen(&mut self) -> bool {
+        self.update();
+        self.parser.screen().alternate_screen()
+    }
+
+    /// Wait until the screen satisfies `predicate`, then return it.
+    ///
+    /// Fails if the program closes the terminal or `timeout` runs out
+    /// first; the error holds the last screen contents.
+    pub fn wait_until(
+        &mut self,
+        timeout: Duration,
+        mut predicate: impl FnMut(&Buffer) -> bool,
+    ) -> Result<Buffer, PtyError> {
+        let deadline = Instant::now() + timeout;
+        loop {
+            self.update();
+            let buffer = screen_to_buffer(self.parser.screen());
+            if predicate(&buffer) {
+                return Ok(buffer);
+            }
+            if self.closed {
+                return Err(PtyError::Closed(buffer.to_string()));
+            }
+            if Instant::now() >= deadline {
+                return Err(PtyError::Timeout(buffer.to_string()));
+            }
+            thread::sleep(POLL_INTERVAL);
+        }
+    }
+
+    /// Wait until `text` appears on the screen, then return it.
+    pub fn wait_for_text(&mut self, text: &str, timeout: Duration) -> Result<Buffer, PtyError> {
+        self.wait_until(timeout, |buffer| buffer.to_string().contains(text))
+    }
+
+    /// Wait for the program to exit, killing it if `timeout` runs out.
+    ///
+    /// All output of the program is applied to the screen before this
+    /// returns.
+    pub fn wait(&mut self, timeout: Duration) -> Result<ExitStatus, PtyError> {
+        let deadline = Instant::now() + timeout;
+        loop {
+            self.update();
+            if let Some(status) = self.child.try_wait()? {
+                // The output may still be in flight on the reader thread.
+                while !self.closed && Instant::now() < deadline {
+                    thread::sleep(POLL_INTERVAL);
+                    self.update();
+                }
+                return Ok(status);
+            }
+            if Instant::now() >= deadline {
+                self.child.kill()?;
+                return Err(PtyError::Timeout(self.buffer().to_string()));
+            }
+            thread::sleep(POLL_INTERVAL);
+        }
+    }
+}
+
+impl Drop for PtyTerminal {
+    fn drop(&mut self) {
+        if let Ok(None) = self.child.try_wait() {
+            let _ = self.child.kill();
+        }
+    }
+}
+
+impl fmt::Debug for PtyTerminal {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("PtyTerminal")
+            .field("child", &self.child)
+            .field("closed", &self.closed)
+            .finish_non_exhaustive()
+    }
+}
+
+const fn pty_size(width: u16, height: u16) -> PtySize {
+    PtySize {
+        rows: height,
+        cols: width,
+        pixel_width: 0,
+        pixel_height: 0,
+    }
+}
+
+/// Convert an emulated screen into a [`Buffer`].
+///
+/// The first 16 indexed colors map to the named colors, so a named color
+/// written by a backend reads back as itself. The emulator does not track
+/// blinking, hidden or crossed out text, so those modifiers are lost.
+#[must_use]
+pub fn screen_to_buffer(screen: &vt100::Screen) -> Buffer {
+    let (rows, columns) = screen.size();
+    let mut buffer = Buffer::empty(Rect::new(0, 0, columns, rows));
+    for y in 0..rows {
+        for x in 0..columns {
+            let Some(cell) = screen.cell(y, x) else {
+                continue;
+            };
+            if cell.is_wide_continuation() {
+                continue;
+            }
+            let symbol = if cell.has_contents() {
+                cell.contents()
+            } else {
+                " "
+            };
+            buffer.set(x, y, symbol, cell_style(cell));
+        }
+    }
+    buffer
+}
+
+fn cell_style(cell: &vt100::Cell) -> Style {
+    let mut style = Style {
+        fg: convert_color(cell.fgcolor()),
+        bg: convert_color(cell.bgcolor()),
+        ..Style::default()
+    };
+    let modifiers = [
+        (cell.bold(), Modifier::BOLD),
+        (cell.dim(), Modifier::DIM),
+        (cell.italic(), Modifier::ITALIC),
+        (cell.underline(), Modifier::UNDERLINED),
+        (cell.inverse(), Modifier::REVERSED),
+    ];
+    for (enabled, modifier) in modifiers {
+        if enabled {
+            style = style.add_modifier(modifier);
+        }
+    }
+    style
+}
+
+const fn convert_color(color: vt100::Color) -> Option<Color> {
+    Some(match color {
+        vt100::Color::Default => return None,
+        vt100::Color::Idx(0) => Color::Black,
+        vt100::Color::Idx(1) => Color::Red,
+        vt100::Color::Idx(2) => Color::Green,
+        vt100::Color::Idx(3) => Color::Yellow,
+        vt100::Color::Idx(4) => Color::Blue,
+        vt100::Color::Idx(5) => Color::Magenta,
+        vt100::Color::Idx(6) => Color::Cyan,
+        vt100::Color::Idx(7) => Color::White,
+        vt100::Color::Idx(8) => Color::Gray,
+        vt100::Color::Idx(9) => Color::LightRed,
+        vt100::Color::Idx(10) => Color::LightGreen,
+        vt100::Color::Idx(11) => Color::LightYellow,
+        vt100::Color::Idx(12) => Color::LightBlue,
+        vt100::Color::Idx(13) => Color::LightMagenta,
+        vt100::Color::Idx(14) => Color::LightCyan,
+        vt100::Color::Idx(15) => Color::LightGray,
+        vt100::Color::Idx(index) => Color::Indexed(index),
+        vt100::Color::Rgb(r, g, b) => Color::Rgb(r, g, b),
+    })
+}
+
+/// Errors from running a program in a pseudo-terminal.
+#[derive(Debug)]
+pub enum PtyError {
+    /// The pseudo-terminal could not be set up
+    Pty(String),
+    /// Reading from or writing to the terminal failed
+    Io(io::Error),
+    /// The program closed the terminal; holds the last screen contents
+    Closed(String),
+    /// Waiting timed out; holds the last screen contents
+    Timeout(String),
+}
+
+impl PtyError {
+    #[allow(clippy::needless_pass_by_value)]
+    fn pty(error: impl fmt::Display) -> Self {
+        Self::Pty(error.to_string())
+    }
+}
+
+impl fmt::Display for PtyError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Pty(msg) => write!(f, "pseudo-terminal error: {msg}"),
+            Self::Io(err) => write!(f, "I/O error: {err}"),
+            Self::Closed(screen) => write!(f, "program closed the terminal; screen:\n{screen}"),
+            Self::Timeout(screen) => write!(f, "timed out; screen:\n{screen}"),
+        }
+    }
+}
+
+impl std::error::Error for PtyError {}
+
+impl From<io::Error> for PtyError {
+    fn from(err: io::Error) -> Self {
+        Self::Io(err)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_screen_to_buffer_keeps_styles() {
+        let mut parser = vt100::Parser::new(2, 6, 0);
+        parser.process("\x1b[1;38;5;1mab\x1b[0m\r\n\x1b[48;2;1;2;3m你\x1b[0m!".as_bytes());
+        let buffer = screen_to_buffer(parser.screen());
+
+        assert_eq!(buffer.to_string(), "ab    \n你!   ");
+        let bold_red = Style::default().fg(Color::Red).add_modifier(Modifier::BOLD);
+        assert_eq!(buffer.get(1, 0).unwrap().style, bold_red);
+        assert_eq!(
+            buffer.get(0, 1).unwrap().style.bg,
+            Some(Color::Rgb(1, 2, 3))
+        );
+        assert!(buffer.get(1, 1).unwrap().skip);
+        assert_eq!(buffer.get(2, 1).unwrap().style, Style::default());
+    }
+}
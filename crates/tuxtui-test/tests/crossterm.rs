@@ -0,0 +1,51 @@
+//! End-to-end tests of the crossterm backend in a pseudo-terminal.
+
+use std::time::Duration;
+use tuxtui_core::assert_buffer_eq;
+use tuxtui_core::buffer::Buffer;
+use tuxtui_core::geometry::Rect;
+use tuxtui_core::style::{Color, Modifier, Style};
+use tuxtui_test::{CommandBuilder, PtyTerminal};
+
+const TIMEOUT: Duration = Duration::from_secs(10);
+
+fn spawn_fixture(width: u16, height: u16) -> PtyTerminal {
+    let command = CommandBuilder::new(env!("CARGO_BIN_EXE_tuxtui-pty-fixture"));
+    PtyTerminal::spawn(command, width, height).unwrap()
+}
+
+#[test]
+fn test_crossterm_output_round_trips() {
+    let mut pty = spawn_fixture(8, 3);
+    let screen = pty.wait_for_text("tuxtui", TIMEOUT).unwrap();
+
+    let mut expected = Buffer::from_lines(&["tuxtui  ", "你好    ", "        "]);
+    let header = Style::default().fg(Color::Red).add_modifier(Modifier::BOLD);
+    expected.set_style(Rect::new(0, 0, 6, 1), header);
+    expected.set_style(
+        Rect::new(0, 1, 4, 1),
+        Style::default().bg(Color::Rgb(0, 128, 255)),
+    );
+    assert_buffer_eq!(screen, expected);
+    assert!(pty.is_alternate_screen());
+
+    pty.send("ab").unwrap();
+    let screen = pty.wait_for_text("ab", TIMEOUT).unwrap();
+    assert_eq!(screen.get(1, 2).unwrap().style.fg, Some(Color::Indexed(42)));
+
+    pty.send("q").unwrap();
+    assert!(pty.wait(TIMEOUT).unwrap().success());
+    assert!(!pty.is_alternate_screen());
+}
+
+#[test]
+fn test_crossterm_redraws_after_resize() {
+    let mut pty = spawn_fixture(4, 2);
+    pty.wait_for_text("tuxt", TIMEOUT).unwrap();
+
+    // The resize event wakes the app up, and it redraws at the new size.
+    pty.resize(10, 3).unwrap();
+    let screen = pty.wait_for_text("tuxtui", TIMEOUT).unwrap();
+    assert_eq!(screen.area, Rect::new(0, 0, 10, 3));
+    assert!(screen.get(1, 1).unwrap().skip);
+}
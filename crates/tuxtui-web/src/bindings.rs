@@ -0,0 +1,59 @@
+//! JavaScript bindings to xterm.js, for `wasm32` targets.
+
+use crate::{WebBackend, WebInput};
+use wasm_bindgen::prelude::*;
+
+#[wasm_bindgen]
+extern "C" {
+    /// An xterm.js `Terminal`.
+    #[wasm_bindgen(js_name = Terminal)]
+    pub type Xterm;
+
+    #[wasm_bindgen(method)]
+    fn write(this: &Xterm, data: &str);
+
+    #[wasm_bindgen(method, getter)]
+    fn cols(this: &Xterm) -> u16;
+
+    #[wasm_bindgen(method, getter)]
+    fn rows(this: &Xterm) -> u16;
+}
+
+impl WebBackend {
+    /// Create a backend drawing to an xterm.js terminal, at its current
+    /// size.
+    pub fn from_xterm(terminal: Xterm) -> Self {
+        let (width, height) = (terminal.cols(), terminal.rows());
+        Self::new(move |data: &str| terminal.write(data), width, height)
+    }
+
+    /// Get the input to connect to the terminal's callbacks from
+    /// JavaScript.
+    #[must_use]
+    pub fn xterm_input(&self) -> XtermInput {
+        XtermInput {
+            input: self.input(),
+        }
+    }
+}
+
+/// The input of a [`WebBackend`], for the `onData` and `onResize`
+/// callbacks of an xterm.js terminal.
+#[wasm_bindgen]
+#[derive(Debug, Clone)]
+pub struct XtermInput {
+    input: WebInput,
+}
+
+#[wasm_bindgen]
+impl XtermInput {
+    /// Handle data typed or pasted into the terminal.
+    pub fn on_data(&self, data: &str) {
+        self.input.push_data(data);
+    }
+
+    /// Handle a change of the terminal size.
+    pub fn on_resize(&self, cols: u16, rows: u16) {
+        self.input.resize(cols, rows);
+    }
+}
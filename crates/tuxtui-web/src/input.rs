@@ -0,0 +1,270 @@
+//! Parsing of terminal input, as delivered by xterm.js `onData`, into the
+//! tuxtui event model.
+
+use tuxtui_core::event::{
+    Event, KeyCode, KeyEvent, KeyEventKind, KeyModifiers, MouseButton, MouseEvent, MouseEventKind,
+};
+
+/// Parse input sent by the terminal into events.
+///
+/// Understands characters and control keys, CSI and SS3 key sequences with
+/// modifiers, SGR mouse reports and bracketed pastes. Escape sequences that
+/// are not recognized are dropped.
+///
+/// # Example
+///
+/// ```
+/// use tuxtui_core::event::{Event, KeyCode, KeyEvent, KeyModifiers};
+/// use tuxtui_web::parse_input;
+///
+/// let events = parse_input("a\x1b[1;5A\r");
+/// assert_eq!(
+///     events,
+///     [
+///         Event::from(KeyCode::Char('a')),
+///         Event::Key(KeyEvent::new(KeyCode::Up, KeyModifiers::CTRL)),
+///         Event::from(KeyCode::Enter),
+///     ]
+/// );
+/// ```
+#[must_use]
+pub fn parse_input(data: &str) -> Vec<Event> {
+    let mut events = Vec::new();
+    let mut rest = data;
+    while let Some(c) = rest.chars().next() {
+        let (event, len) = if c == '\x1b' {
+            escape(rest)
+        } else {
+            (Some(Event::Key(char_key(c))), c.len_utf8())
+        };
+        events.extend(event);
+        rest = &rest[len..];
+    }
+    events
+}
+
+/// Get the key for a character, including control characters.
+fn char_key(c: char) -> KeyEvent {
+    let code = match c {
+        '\r' | '\n' => KeyCode::Enter,
+        '\t' => KeyCode::Tab,
+        '\x7f' | '\x08' => KeyCode::Backspace,
+        '\0' => return KeyEvent::new(KeyCode::Char(' '), KeyModifiers::CTRL),
+        '\x01'..='\x1a' => {
+            let letter = char::from(b'a' + (c as u8 - 1));
+            return KeyEvent::new(KeyCode::Char(letter), KeyModifiers::CTRL);
+        }
+        '\x1c'..='\x1f' => {
+            let symbol = char::from(b'\\' + (c as u8 - 0x1c));
+            return KeyEvent::new(KeyCode::Char(symbol), KeyModifiers::CTRL);
+        }
+        c => KeyCode::Char(c),
+    };
+    KeyEvent::from(code)
+}
+
+/// Parse the escape sequence at the start of `s`, returning its event and
+/// length.
+fn escape(s: &str) -> (Option<Event>, usize) {
+    const PASTE_START: &str = "\x1b[200~";
+    const PASTE_END: &str = "\x1b[201~";
+
+    if let Some(text) = s.strip_prefix(PASTE_START) {
+        return match text.find(PASTE_END) {
+            Some(end) => (
+                Some(Event::Paste(text[..end].into())),
+                PASTE_START.len() + end + PASTE_END.len(),
+            ),
+            None => (Some(Event::Paste(text.into())), s.len()),
+        };
+    }
+    let Some(next) = s[1..].chars().next() else {
+        return (Some(KeyCode::Esc.into()), 1);
+    };
+    match next {
+        '[' => csi(s),
+        'O' => match s[2..].chars().next() {
+            Some(last) => (
+                key_for_final(last, &[]).map(Event::Key),
+                2 + last.len_utf8(),
+            ),
+            None => (None, 2),
+        },
+        '\x1b' => (Some(KeyCode::Esc.into()), 1),
+        c => {
+            let mut key = char_key(c);
+            key.modifiers.alt = true;
+            (Some(Event::Key(key)), 1 + c.len_utf8())
+        }
+    }
+}
+
+/// Parse a CSI sequence, `ESC [ parameters final`.
+fn csi(s: &str) -> (Option<Event>, usize) {
+    let body = &s[2..];
+    let Some(end) = body.find(|c: char| ('\x40'..='\x7e').contains(&c)) else {
+        return (None, s.len());
+    };
+    let len = 2 + end + 1;
+    let last = body[end..].chars().next().unwrap_or_default();
+    if let Some(params) = body[..end].strip_prefix('<') {
+        return (mouse(params, last).map(Event::Mouse), len);
+    }
+    // The kitty keyboard protocol follows the modifiers with the event
+    // type, as in `1;5:3A` for releasing Ctrl+Up.
+    let kind = match body[..end]
+        .split(';')
+        .nth(1)
+        .and_then(|p| p.split_once(':'))
+    {
+        Some((_, "2")) => KeyEventKind::Repeat,
+        Some((_, "3")) => KeyEventKind::Release,
+        _ => KeyEventKind::Press,
+    };
+    let params: Vec<u16> = body[..end]
+        .split(';')
+        .map(|param| {
+            let value = param.split(':').next().unwrap_or_default();
+            value.parse().unwrap_or(1)
+        })
+        .collect();
+    let key = key_for_final(last, &params).map(|key| key.kind(kind));
+    (key.map(Event::Key), len)
+}
+
+/// Get the key for the final character of a CSI or SS3 sequence.
+fn key_for_final(last: char, params: &[u16]) -> Option<KeyEvent> {
+    let code = match (last, params.first()) {
+        ('A', _) => KeyCode::Up,
+        ('B', _) => KeyCode::Down,
+        ('C', _) => KeyCode::Right,
+        ('D', _) => KeyCode::Left,
+        ('H', _) | ('~', Some(1 | 7)) => KeyCode::Home,
+        ('F', _) | ('~', Some(4 | 8)) => KeyCode::End,
+        ('Z', _) => KeyCode::BackTab,
+        ('P', _) => KeyCode::F(1),
+        ('Q', _) => KeyCode::F(2),
+        ('R', _) => KeyCode::F(3),
+        ('S', _) => KeyCode::F(4),
+        ('~', Some(2)) => KeyCode::Insert,
+        ('~', Some(3)) => KeyCode::Delete,
+        ('~', Some(5)) => KeyCode::PageUp,
+        ('~', Some(6)) => KeyCode::PageDown,
+        ('~', Some(n @ 11..=15)) => KeyCode::F((n - 10) as u8),
+        ('~', Some(n @ 17..=21)) => KeyCode::F((n - 11) as u8),
+        ('~', Some(n @ 23..=24)) => KeyCode::F((n - 12) as u8),
+        _ => return None,
+    };
+    let modifiers = params.get(1).map_or(KeyModifiers::NONE, |&m| modifiers(m));
+    Some(KeyEvent::new(code, modifiers))
+}
+
+/// Decode the modifier parameter of a key sequence, one more than a bit set
+/// of shift, alt, ctrl and meta.
+const fn modifiers(param: u16) -> KeyModifiers {
+    let bits = param.saturating_sub(1);
+    KeyModifiers {
+        shift: bits & 1 != 0,
+        alt: bits & 2 != 0,
+        ctrl: bits & 4 != 0,
+        meta: bits & 8 != 0,
+    }
+}
+
+/// Decode an SGR mouse report, `ESC [ < button ; column ; row M` with `m`
+/// for releases.
+fn mouse(params: &str, last: char) -> Option<MouseEvent> {
+    let mut params = params.split(';').map(str::parse::<u16>);
+    let (button, column, row) = (
+        params.next()?.ok()?,
+        params.next()?.ok()?,
+        params.next()?.ok()?,
+    );
+    let pressed = match button & 0b11 {
+        0 => MouseButton::Left,
+        1 => MouseButton::Middle,
+        _ => MouseButton::Right,
+    };
+    let kind = match (button & !0b1_1100, last) {
+        (64, _) => MouseEventKind::ScrollUp,
+        (65, _) => MouseEventKind::ScrollDown,
+        (66, _) => MouseEventKind::ScrollLeft,
+        (67, _) => MouseEventKind::ScrollRight,
+        (35, _) => MouseEventKind::Moved,
+        (32..=34, _) => MouseEventKind::Drag(pressed),
+        (_, 'm') => MouseEventKind::Up(pressed),
+        (_, 'M') => MouseEventKind::Down(pressed),
+        _ => return None,
+    };
+    let modifiers = KeyModifiers {
+        shift: button & 4 != 0,
+        alt: button & 8 != 0,
+        ctrl: button & 16 != 0,
+        meta: false,
+    };
+    let event = MouseEvent::new(kind, column.saturating_sub(1), row.saturating_sub(1));
+    Some(event.modifiers(modifiers))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn key(code: KeyCode, modifiers: KeyModifiers) -> Event {
+        Event::Key(KeyEvent::new(code, modifiers))
+    }
+
+    #[test]
+    fn test_keys() {
+        let events = parse_input("\x7f\x03\x1bx\x1b[Z\x1bOP\x1b[15;2~\x1b[3~");
+        assert_eq!(
+            events,
+            [
+                KeyCode::Backspace.into(),
+                key(KeyCode::Char('c'), KeyModifiers::CTRL),
+                key(KeyCode::Char('x'), KeyModifiers::ALT),
+                KeyCode::BackTab.into(),
+                KeyCode::F(1).into(),
+                key(KeyCode::F(5), KeyModifiers::SHIFT),
+                KeyCode::Delete.into(),
+            ]
+        );
+        assert_eq!(parse_input("\x1b"), [KeyCode::Esc.into()]);
+        assert_eq!(parse_input("\x1b[99x"), []);
+    }
+
+    #[test]
+    fn test_kitty_key_event_types() {
+        let up = KeyEvent::new(KeyCode::Up, KeyModifiers::CTRL);
+        assert_eq!(
+            parse_input("\x1b[1;5A\x1b[1;5:2A\x1b[1;5:3A\x1b[3;1:3~"),
+            [
+                Event::Key(up),
+                Event::Key(up.kind(KeyEventKind::Repeat)),
+                Event::Key(up.kind(KeyEventKind::Release)),
+                Event::Key(KeyEvent::from(KeyCode::Delete).kind(KeyEventKind::Release)),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_mouse_and_paste() {
+        let events = parse_input("\x1b[<0;3;4M\x1b[<16;3;4m\x1b[<65;1;1M\x1b[200~a\x1bb\x1b[201~");
+        assert_eq!(
+            events,
+            [
+                Event::Mouse(MouseEvent::new(
+                    MouseEventKind::Down(MouseButton::Left),
+                    2,
+                    3
+                )),
+                Event::Mouse(
+                    MouseEvent::new(MouseEventKind::Up(MouseButton::Left), 2, 3)
+                        .modifiers(KeyModifiers::CTRL)
+                ),
+                Event::Mouse(MouseEvent::new(MouseEventKind::ScrollDown, 0, 0)),
+                Event::Paste("a\x1bb".into()),
+            ]
+        );
+    }
+}
@@ -0,0 +1,410 @@
+//! # tuxtui-web
+//!
+//! Browser backend for tuxtui, drawing to [xterm.js](https://xtermjs.org).
+//!
+//! [`WebBackend`] turns drawing into ANSI escape sequences and hands them to
+//! a write function once per frame. Input comes the other way: the terminal's
+//! data and resize callbacks are fed to a [`WebInput`], whose events the
+//! backend returns from `poll_event`.
+//!
+//! On `wasm32` targets, [`WebBackend::from_xterm`] and [`XtermInput`] bridge
+//! to JavaScript through `wasm-bindgen`:
+//!
+//! ```js
+//! const term = new Terminal();
+//! term.open(document.getElementById("terminal"));
+//! const app = App.new(term);              // exported by the application
+//! const input = app.input();              // from WebBackend::xterm_input
+//! term.onData((data) => input.on_data(data));
+//! term.onResize(({ cols, rows }) => input.on_resize(cols, rows));
+//! const frame = () => { app.tick(); requestAnimationFrame(frame); };
+//! requestAnimationFrame(frame);
+//! ```
+//!
+//! The browser cannot block, so instead of a loop the application polls
+//! events and draws once per animation frame.
+//!
+//! Build `tuxtui-core` and `tuxtui-widgets` without their `std` feature:
+//! frame timing uses `std::time::Instant`, which panics on
+//! `wasm32-unknown-unknown`.
+//!
+//! ## Example
+//!
+//! ```
+//! use std::cell::RefCell;
+//! use std::rc::Rc;
+//! use tuxtui_core::terminal::Terminal;
+//! use tuxtui_web::WebBackend;
+//!
+//! let output = Rc::new(RefCell::new(String::new()));
+//! let sink = Rc::clone(&output);
+//! let backend = WebBackend::new(move |data: &str| sink.borrow_mut().push_str(data), 20, 2);
+//! let input = backend.input();
+//!
+//! let mut terminal = Terminal::new(backend).unwrap();
+//! terminal.draw(|frame| frame.render_widget("hello", frame.area())).unwrap();
+//! assert!(output.borrow().contains("hello"));
+//!
+//! input.push_data("q");
+//! assert!(terminal.poll_event().unwrap().is_some());
+//! ```
+
+#![forbid(unsafe_code)]
+#![warn(missing_docs)]
+
+#[cfg(target_arch = "wasm32")]
+mod bindings;
+mod input;
+
+#[cfg(target_arch = "wasm32")]
+pub use bindings::{Xterm, XtermInput};
+pub use input::parse_input;
+
+use core::convert::Infallible;
+use core::fmt::{self, Write as _};
+use std::cell::RefCell;
+use std::collections::VecDeque;
+use std::rc::Rc;
+use tuxtui_core::backend::{Backend, Capabilities};
+use tuxtui_core::buffer::Cell;
+use tuxtui_core::event::Event;
+use tuxtui_core::geometry::{Position, Rect};
+use tuxtui_core::style::{Color, Modifier, Style};
+use tuxtui_core::util::{ColorSupport, unicode};
+
+/// SGR codes of the modifiers, in the order they are sent.
+const MODIFIER_CODES: [(Modifier, u8); 9] = [
+    (Modifier::BOLD, 1),
+    (Modifier::DIM, 2),
+    (Modifier::ITALIC, 3),
+    (Modifier::UNDERLINED, 4),
+    (Modifier::SLOW_BLINK, 5),
+    (Modifier::RAPID_BLINK, 6),
+    (Modifier::REVERSED, 7),
+    (Modifier::HIDDEN, 8),
+    (Modifier::CROSSED_OUT, 9),
+];
+
+/// State shared between a backend and its inputs.
+#[derive(Debug)]
+struct Shared {
+    size: (u16, u16),
+    events: VecDeque<Event>,
+}
+
+/// The receiving end of terminal input for a [`WebBackend`].
+///
+/// Feed it the terminal's data and resize callbacks; clones share the same
+/// queue.
+#[derive(Debug, Clone)]
+pub struct WebInput {
+    shared: Rc<RefCell<Shared>>,
+}
+
+impl WebInput {
+    /// Queue the events in data sent by the terminal, such as the argument
+    /// of xterm.js `onData`.
+    pub fn push_data(&self, data: &str) {
+        self.shared.borrow_mut().events.extend(parse_input(data));
+    }
+
+    /// Queue an event.
+    pub fn push_event(&self, event: impl Into<Event>) {
+        self.shared.borrow_mut().events.push_back(event.into());
+    }
+
+    /// Record that the terminal now has `width` columns and `height` rows.
+    pub fn resize(&self, width: u16, height: u16) {
+        let mut shared = self.shared.borrow_mut();
+        shared.size = (width, height);
+        shared.events.push_back(Event::Resize(width, height));
+    }
+}
+
+/// Write function receiving the output of a frame.
+type WriteFn = Box<dyn FnMut(&str)>;
+
+/// Backend writing ANSI escape sequences to a browser terminal.
+///
+/// Output is collected and passed to the write function on flush, so each
+/// frame reaches the terminal in one piece. Colors and the cursor position
+/// are tracked so only changes are sent.
+pub struct WebBackend {
+    write: WriteFn,
+    output: String,
+    shared: Rc<RefCell<Shared>>,
+    /// The style currently applied by the terminal.
+    applied: Style,
+    /// Where the terminal cursor is, if known.
+    cursor: Option<Position>,
+}
+
+impl fmt::Debug for WebBackend {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("WebBackend")
+            .field("output", &self.output)
+            .field("shared", &self.shared)
+            .finish_non_exhaustive()
+    }
+}
+
+impl WebBackend {
+    /// Create a backend for a terminal of `width` columns and `height` rows,
+    /// sending output to `write`.
+    pub fn new(write: impl FnMut(&str) + 'static, width: u16, height: u16) -> Self {
+        Self {
+            write: Box::new(write),
+            output: String::new(),
+            shared: Rc::new(RefCell::new(Shared {
+                size: (width, height),
+                events: VecDeque::new(),
+            })),
+            applied: Style::new(),
+            cursor: None,
+        }
+    }
+
+    /// Get the input feeding this backend's events and size.
+    #[must_use]
+    pub fn input(&self) -> WebInput {
+        WebInput {
+            shared: Rc::clone(&self.shared),
+        }
+    }
+
+    /// Move the cursor, unless it is already there.
+    fn move_to(&mut self, x: u16, y: u16) {
+        if self.cursor != Some(Position::new(x, y)) {
+            let _ = write!(self.output, "\x1b[{};{}H", y + 1, x + 1);
+            self.cursor = Some(Position::new(x, y));
+        }
+    }
+
+    /// Apply a style, unless it is already applied.
+    ///
+    /// The style is sent in full after a reset, which keeps removing
+    /// modifiers simple; cells mostly share the style of their neighbors.
+    fn apply_style(&mut self, style: Style) {
+        if style == self.applied {
+            return;
+        }
+        self.output.push_str("\x1b[0");
+        let modifiers = style.modifiers();
+        for (modifier, code) in MODIFIER_CODES {
+            if modifiers.contains(modifier) {
+                let _ = write!(self.output, ";{code}");
+            }
+        }
+        if let Some(fg) = style.fg {
+            write_color(&mut self.output, fg, 30);
+        }
+        if let Some(bg) = style.bg {
+            write_color(&mut self.output, bg, 40);
+        }
+        self.output.push('m');
+        self.applied = style;
+    }
+}
+
+/// Append the SGR parameters selecting `color`, using `base` 30 or 40.
+fn write_color(out: &mut String, color: Color, base: u8) {
+    let index = match color {
+        Color::Reset => {
+            let _ = write!(out, ";{}", base + 9);
+            return;
+        }
+        Color::Rgb(r, g, b) => {
+            let _ = write!(out, ";{};2;{r};{g};{b}", base + 8);
+            return;
+        }
+        Color::Indexed(index) => index,
+        Color::Black => 0,
+        Color::Red => 1,
+        Color::Green => 2,
+        Color::Yellow => 3,
+        Color::Blue => 4,
+        Color::Magenta => 5,
+        Color::Cyan => 6,
+        Color::White => 7,
+        Color::Gray => 8,
+        Color::LightRed => 9,
+        Color::LightGreen => 10,
+        Color::LightYellow => 11,
+        Color::LightBlue => 12,
+        Color::LightMagenta => 13,
+        Color::LightCyan => 14,
+        Color::LightGray => 15,
+    };
+    let _ = write!(out, ";{};5;{index}", base + 8);
+}
+
+impl Backend for WebBackend {
+    type Error = Infallible;
+
+    fn size(&self) -> Result<Rect, Self::Error> {
+        let (width, height) = self.shared.borrow().size;
+        Ok(Rect::new(0, 0, width, height))
+    }
+
+    fn clear(&mut self) -> Result<(), Self::Error> {
+        self.apply_style(Style::new());
+        self.output.push_str("\x1b[2J");
+        Ok(())
+    }
+
+    fn hide_cursor(&mut self) -> Result<(), Self::Error> {
+        self.output.push_str("\x1b[?25l");
+        Ok(())
+    }
+
+    fn show_cursor(&mut self) -> Result<(), Self::Error> {
+        self.output.push_str("\x1b[?25h");
+        Ok(())
+    }
+
+    fn get_cursor(&mut self) -> Result<Position, Self::Error> {
+        Ok(self.cursor.unwrap_or_default())
+    }
+
+    fn set_cursor(&mut self, x: u16, y: u16) -> Result<(), Self::Error> {
+        self.move_to(x, y);
+        Ok(())
+    }
+
+    fn draw_cell(&mut self, x: u16, y: u16, cell: &Cell) -> Result<(), Self::Error> {
+        if cell.skip {
+            return Ok(());
+        }
+        self.move_to(x, y);
+        self.apply_style(cell.style);
+        self.output.push_str(cell.symbol());
+        // Terminals disagree on the width of some emoji, so the cursor is
+        // only known to be past them after moving it explicitly.
+        self.cursor = if unicode::is_ambiguous_width(cell.symbol()) {
+            None
+        } else {
+            let width = u16::try_from(cell.width()).unwrap_or(1);
+            Some(Position::new(x.saturating_add(width), y))
+        };
+        Ok(())
+    }
+
+    fn set_style(&mut self, style: Style) -> Result<(), Self::Error> {
+        self.apply_style(self.applied.patch(style));
+        Ok(())
+    }
+
+    fn reset_style(&mut self) -> Result<(), Self::Error> {
+        self.apply_style(Style::new());
+        Ok(())
+    }
+
+    /// Send the output collected since the last flush to the terminal.
+    fn flush(&mut self) -> Result<(), Self::Error> {
+        if !self.output.is_empty() {
+            (self.write)(&self.output);
+            self.output.clear();
+        }
+        Ok(())
+    }
+
+    /// The browser terminal has no line discipline to switch.
+    fn enable_raw_mode(&mut self) -> Result<(), Self::Error> {
+        Ok(())
+    }
+
+    fn disable_raw_mode(&mut self) -> Result<(), Self::Error> {
+        Ok(())
+    }
+
+    fn enter_alternate_screen(&mut self) -> Result<(), Self::Error> {
+        self.output.push_str("\x1b[?1049h");
+        self.cursor = None;
+        Ok(())
+    }
+
+    fn leave_alternate_screen(&mut self) -> Result<(), Self::Error> {
+        self.output.push_str("\x1b[?1049l");
+        self.cursor = None;
+        Ok(())
+    }
+
+    fn set_title(&mut self, title: &str) -> Result<(), Self::Error> {
+        let _ = write!(self.output, "\x1b]0;{title}\x07");
+        Ok(())
+    }
+
+    fn bell(&mut self) -> Result<(), Self::Error> {
+        self.output.push('\x07');
+        Ok(())
+    }
+
+    fn append_lines(&mut self, lines: u16) -> Result<(), Self::Error> {
+        for _ in 0..lines {
+            self.output.push('\n');
+        }
+        self.cursor = None;
+        Ok(())
+    }
+
+    fn poll_event(&mut self) -> Result<Option<Event>, Self::Error> {
+        Ok(self.shared.borrow_mut().events.pop_front())
+    }
+
+    /// Report what xterm.js supports out of the box.
+    fn capabilities(&self) -> Capabilities {
+        Capabilities {
+            color: ColorSupport::TrueColor,
+            mouse: true,
+            scroll_regions: true,
+            set_title: true,
+            ..Capabilities::default()
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tuxtui_core::event::KeyCode;
+
+    fn backend() -> (WebBackend, Rc<RefCell<Vec<String>>>) {
+        let frames = Rc::new(RefCell::new(Vec::new()));
+        let sink = Rc::clone(&frames);
+        let backend = WebBackend::new(move |data: &str| sink.borrow_mut().push(data.into()), 4, 2);
+        (backend, frames)
+    }
+
+    #[test]
+    fn test_output_is_sent_on_flush() {
+        let (mut backend, frames) = backend();
+        let mut cell = Cell::default();
+        cell.set_symbol("a");
+        cell.set_style(Style::new().fg(Color::Red).add_modifier(Modifier::BOLD));
+        backend.draw_cell(0, 0, &cell).unwrap();
+        backend.draw_cell(1, 0, &cell).unwrap();
+        cell.set_style(Style::new().bg(Color::Rgb(1, 2, 3)));
+        backend.draw_cell(3, 1, &cell).unwrap();
+        assert!(frames.borrow().is_empty());
+
+        backend.flush().unwrap();
+        backend.flush().unwrap();
+        assert_eq!(
+            *frames.borrow(),
+            ["\x1b[1;1H\x1b[0;1;38;5;1maa\x1b[2;4H\x1b[0;48;2;1;2;3ma"]
+        );
+    }
+
+    #[test]
+    fn test_input_and_resize() {
+        let (mut backend, _) = backend();
+        let input = backend.input();
+        input.push_data("x");
+        input.resize(10, 5);
+        let key = Event::from(KeyCode::Char('x'));
+        assert_eq!(backend.poll_event().unwrap(), Some(key));
+        assert_eq!(backend.poll_event().unwrap(), Some(Event::Resize(10, 5)));
+        assert_eq!(backend.poll_event().unwrap(), None);
+        assert_eq!(backend.size().unwrap(), Rect::new(0, 0, 10, 5));
+    }
+}
@@ -0,0 +1,73 @@
+//! Benchmarks comparing eager and lazy (virtualized) list/table rendering
+
+use criterion::{BenchmarkId, Criterion, black_box, criterion_group, criterion_main};
+use tuxtui_core::buffer::Buffer;
+use tuxtui_core::geometry::Rect;
+use tuxtui_core::layout::Constraint;
+use tuxtui_widgets::list::{List, ListItem, ListState};
+use tuxtui_widgets::table::{Row, Table, TableState};
+
+const AREA: Rect = Rect::new(0, 0, 80, 24);
+
+fn bench_list(c: &mut Criterion) {
+    let mut group = c.benchmark_group("list");
+
+    for len in [1_000, 100_000, 1_000_000].iter() {
+        group.bench_with_input(BenchmarkId::new("eager", len), len, |b, &len| {
+            let mut buf = Buffer::empty(AREA);
+            b.iter(|| {
+                let list = List::new((0..len).map(|i| ListItem::new(format!("Item {i}"))));
+                let mut state = ListState::default();
+                state.select(Some(black_box(len / 2)));
+                list.render_stateful(AREA, &mut buf, &mut state);
+            });
+        });
+
+        group.bench_with_input(BenchmarkId::new("lazy", len), len, |b, &len| {
+            let mut buf = Buffer::empty(AREA);
+            b.iter(|| {
+                let list = List::from_fn(len, |i| ListItem::new(format!("Item {i}")));
+                let mut state = ListState::default();
+                state.select(Some(black_box(len / 2)));
+                list.render_stateful(AREA, &mut buf, &mut state);
+            });
+        });
+    }
+
+    group.finish();
+}
+
+fn bench_table(c: &mut Criterion) {
+    let mut group = c.benchmark_group("table");
+    let widths = [Constraint::Length(10), Constraint::Fill(1)];
+
+    for len in [1_000, 100_000, 1_000_000].iter() {
+        group.bench_with_input(BenchmarkId::new("eager", len), len, |b, &len| {
+            let mut buf = Buffer::empty(AREA);
+            b.iter(|| {
+                let rows = (0..len).map(|i| Row::new(vec![format!("{i}"), format!("Row {i}")]));
+                let table = Table::new(rows, widths);
+                let mut state = TableState::default();
+                state.viewport_mut().set_offset(black_box(len / 2));
+                table.render_stateful(AREA, &mut buf, &mut state);
+            });
+        });
+
+        group.bench_with_input(BenchmarkId::new("lazy", len), len, |b, &len| {
+            let mut buf = Buffer::empty(AREA);
+            b.iter(|| {
+                let table = Table::from_fn(len, widths, |i| {
+                    Row::new(vec![format!("{i}"), format!("Row {i}")])
+                });
+                let mut state = TableState::default();
+                state.viewport_mut().set_offset(black_box(len / 2));
+                table.render_stateful(AREA, &mut buf, &mut state);
+            });
+        });
+    }
+
+    group.finish();
+}
+
+criterion_group!(benches, bench_list, bench_table);
+criterion_main!(benches);
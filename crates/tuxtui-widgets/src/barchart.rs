@@ -5,6 +5,7 @@ use tuxtui_core::geometry::Rect;
 use tuxtui_core::style::Style;
 use tuxtui_core::symbols;
 use tuxtui_core::terminal::Widget;
+use tuxtui_core::util::unicode;
 
 /// A bar in a bar chart.
 #[derive(Debug, Clone, PartialEq, Eq)]
@@ -165,8 +166,12 @@ impl Widget for BarChart<'_> {
             // Draw label
             if let Some(label) = bar.label {
                 let label_y = area.top() + chart_height;
-                let label_x = x + (self.bar_width.saturating_sub(label.len() as u16)) / 2;
-                buf.set_string(label_x, label_y, label, self.style);
+                let label_width = u16::try_from(unicode::width(label)).unwrap_or(u16::MAX);
+                let label_x = self.bar_width.saturating_sub(label_width) / 2;
+                // Labels wider than their bar are cut, not drawn over the
+                // next bar.
+                buf.view_mut(Rect::new(x, label_y, self.bar_width, 1))
+                    .set_string(label_x, 0, label, self.style);
             }
 
             x += self.bar_width + self.bar_gap;
@@ -177,6 +182,7 @@ impl Widget for BarChart<'_> {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use alloc::string::ToString;
 
     #[test]
     fn test_barchart_creation() {
@@ -185,6 +191,29 @@ mod tests {
         assert_eq!(chart.bars.len(), 3);
     }
 
+    #[test]
+    fn test_wide_label_is_centered() {
+        let bars = [Bar::new(1).label("你好")];
+        let mut buf = Buffer::empty(Rect::new(0, 0, 6, 3));
+        BarChart::default()
+            .data(&bars)
+            .bar_width(6)
+            .render(buf.area, &mut buf);
+        assert_eq!(buf.to_string().lines().nth(1), Some(" 你好 "));
+    }
+
+    #[test]
+    fn test_wide_labels_are_cut_to_the_bar() {
+        let bars = [Bar::new(1).label("first"), Bar::new(1).label("second")];
+        let mut buf = Buffer::from_lines(&["..........", "..........", ".........."]);
+        BarChart::default()
+            .data(&bars)
+            .bar_width(3)
+            .bar_gap(1)
+            .render(Rect::new(0, 0, 7, 3), &mut buf);
+        assert_eq!(buf.to_string().lines().nth(1), Some("fir.sec..."));
+    }
+
     #[test]
     fn test_bar_with_label() {
         let bar = Bar::new(42).label("Test");
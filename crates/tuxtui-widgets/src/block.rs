@@ -40,6 +40,8 @@ pub enum Borders {
     Double,
     /// Thick borders
     Thick,
+    /// ASCII-only borders, drawn with `+`, `-` and `|`
+    Ascii,
     /// Custom line style
     Custom(LineStyle),
 }
@@ -53,6 +55,7 @@ impl Borders {
             Self::Rounded => ROUNDED,
             Self::Double => DOUBLE,
             Self::Thick => THICK,
+            Self::Ascii => LineStyle::ascii(),
             Self::Custom(style) => style,
         }
     }
@@ -235,8 +238,9 @@ impl Widget for Block<'_> {
         if area.area() == 0 {
             return;
         }
+        buf.set_style(area, self.style);
 
-        let symbols = self.border_style.line_style();
+        let symbols = buf.settings.line_style(self.border_style.line_style());
 
         // Render borders
         match self.borders {
@@ -292,34 +296,25 @@ impl Widget for Block<'_> {
             }
         }
 
-        // Render titles
+        // Render titles between the corners, aligned by display width so
+        // that wide characters are placed correctly.
+        let left = area.left() + 1;
+        let right = area.right().saturating_sub(1).max(left);
         for title in &self.titles {
-            let title_width = title.content.width() as u16;
+            let title_width = u16::try_from(title.content.width()).unwrap_or(u16::MAX);
+            let free = (right - left).saturating_sub(title_width);
             let (x, y) = match title.position {
-                TitlePosition::TopLeft => (area.left() + 1, area.top()),
-                TitlePosition::TopCenter => {
-                    let center = area.left() + area.width / 2;
-                    (center.saturating_sub(title_width / 2), area.top())
-                }
-                TitlePosition::TopRight => {
-                    (area.right().saturating_sub(title_width + 1), area.top())
-                }
-                TitlePosition::BottomLeft => (area.left() + 1, area.bottom() - 1),
-                TitlePosition::BottomCenter => {
-                    let center = area.left() + area.width / 2;
-                    (center.saturating_sub(title_width / 2), area.bottom() - 1)
-                }
-                TitlePosition::BottomRight => (
-                    area.right().saturating_sub(title_width + 1),
-                    area.bottom() - 1,
-                ),
+                TitlePosition::TopLeft => (left, area.top()),
+                TitlePosition::TopCenter => (left + free / 2, area.top()),
+                TitlePosition::TopRight => (left + free, area.top()),
+                TitlePosition::BottomLeft => (left, area.bottom() - 1),
+                TitlePosition::BottomCenter => (left + free / 2, area.bottom() - 1),
+                TitlePosition::BottomRight => (left + free, area.bottom() - 1),
             };
 
-            let mut current_x = x;
-            for span in &title.content.spans {
-                let span_style = self.style.patch(span.style);
-                current_x = buf.set_string(current_x, y, &span.content, span_style);
-            }
+            let mut content = title.content.clone();
+            content.style = self.style.patch(content.style);
+            buf.set_line(x, y, &content, right - x);
         }
     }
 }
@@ -328,7 +323,7 @@ impl Widget for Block<'_> {
 mod tests {
     use super::*;
     use tuxtui_core::backend::TestBackend;
-    use tuxtui_core::style::Color;
+    use tuxtui_core::settings::UiSettings;
     use tuxtui_core::terminal::Terminal;
 
     #[test]
@@ -361,4 +356,52 @@ mod tests {
             })
             .unwrap();
     }
+
+    #[test]
+    fn test_wide_title_alignment() {
+        let render = |title: Title<'static>, width| {
+            let mut buf = Buffer::empty(Rect::new(0, 0, width, 2));
+            Block::default()
+                .borders(BorderType::All)
+                .title(title)
+                .render(buf.area, &mut buf);
+            buf
+        };
+        let cases = [
+            (TitlePosition::TopLeft, "┌你好────┐"),
+            (TitlePosition::TopCenter, "┌──你好──┐"),
+            (TitlePosition::TopRight, "┌────你好┐"),
+        ];
+        for (position, expected) in cases {
+            let buf = render(Title::new("你好").position(position), 10);
+            assert_eq!(buf, Buffer::from_lines(&[expected, "└────────┘"]));
+        }
+        let buf = render(Title::new("你好").position(TitlePosition::BottomCenter), 9);
+        assert_eq!(buf, Buffer::from_lines(&["┌───────┐", "└─你好──┘"]));
+
+        // Too wide titles stop before the corner, without half a character.
+        let buf = render(Title::new("你好世界").position(TitlePosition::TopCenter), 9);
+        assert_eq!(buf, Buffer::from_lines(&["┌你好世─┐", "└───────┘"]));
+    }
+
+    #[test]
+    fn test_ascii_only_borders() {
+        let mut buf =
+            Buffer::empty(Rect::new(0, 0, 4, 3)).with_settings(UiSettings::new().ascii_only(true));
+        Block::default()
+            .borders(BorderType::All)
+            .border_style(Borders::Rounded)
+            .render(buf.area, &mut buf);
+        assert_eq!(
+            buf,
+            Buffer::from_lines(&["+--+", "|  |", "+--+"]).with_settings(buf.settings)
+        );
+
+        let mut plain = Buffer::empty(Rect::new(0, 0, 4, 3));
+        Block::default()
+            .borders(BorderType::All)
+            .border_style(Borders::Ascii)
+            .render(plain.area, &mut plain);
+        assert_eq!(plain, Buffer::from_lines(&["+--+", "|  |", "+--+"]));
+    }
 }
@@ -0,0 +1,217 @@
+//! Breadcrumbs widget for showing a navigation path.
+
+use alloc::vec::Vec;
+use tuxtui_core::buffer::Buffer;
+use tuxtui_core::geometry::Rect;
+use tuxtui_core::style::{Style, Stylize};
+use tuxtui_core::terminal::Widget;
+use tuxtui_core::text::Line;
+use unicode_width::UnicodeWidthStr;
+
+/// A breadcrumbs widget showing a path of segments.
+///
+/// When the path does not fit, middle segments are collapsed into a single
+/// ellipsis, keeping the first and last segments visible for as long as
+/// possible. The selected segment (the last one by default) is highlighted.
+///
+/// # Example
+///
+/// ```
+/// use tuxtui_core::prelude::*;
+/// use tuxtui_widgets::breadcrumbs::Breadcrumbs;
+///
+/// let breadcrumbs = Breadcrumbs::new(vec!["home", "user", "projects", "tuxtui"])
+///     .separator(" / ")
+///     .highlight_style(Style::default().fg(Color::Yellow));
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Breadcrumbs<'a> {
+    segments: Vec<Line<'a>>,
+    selected: Option<usize>,
+    style: Style,
+    highlight_style: Style,
+    separator: &'static str,
+    ellipsis: &'static str,
+}
+
+/// An entry of the rendered path.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Crumb {
+    Segment(usize),
+    Ellipsis,
+}
+
+impl<'a> Breadcrumbs<'a> {
+    /// Create new breadcrumbs from path segments.
+    #[must_use]
+    pub fn new<T>(segments: T) -> Self
+    where
+        T: IntoIterator,
+        T::Item: Into<Line<'a>>,
+    {
+        Self {
+            segments: segments.into_iter().map(Into::into).collect(),
+            selected: None,
+            style: Style::default(),
+            highlight_style: Style::default(),
+            separator: " › ",
+            ellipsis: "…",
+        }
+    }
+
+    /// Select the highlighted segment. Defaults to the last segment.
+    #[must_use]
+    pub const fn select(mut self, index: usize) -> Self {
+        self.selected = Some(index);
+        self
+    }
+
+    /// Set the overall style.
+    #[must_use]
+    pub const fn style(mut self, style: Style) -> Self {
+        self.style = style;
+        self
+    }
+
+    /// Set the highlight style for the selected segment.
+    #[must_use]
+    pub const fn highlight_style(mut self, style: Style) -> Self {
+        self.highlight_style = style;
+        self
+    }
+
+    /// Set the separator between segments.
+    #[must_use]
+    pub const fn separator(mut self, separator: &'static str) -> Self {
+        self.separator = separator;
+        self
+    }
+
+    /// Set the symbol that replaces collapsed segments.
+    #[must_use]
+    pub const fn ellipsis(mut self, ellipsis: &'static str) -> Self {
+        self.ellipsis = ellipsis;
+        self
+    }
+
+    fn crumb_width(&self, crumb: Crumb) -> usize {
+        match crumb {
+            Crumb::Segment(i) => self.segments[i].width(),
+            Crumb::Ellipsis => self.ellipsis.width(),
+        }
+    }
+
+    fn path_width(&self, crumbs: &[Crumb]) -> usize {
+        let separators = self.separator.width() * crumbs.len().saturating_sub(1);
+        crumbs.iter().map(|&c| self.crumb_width(c)).sum::<usize>() + separators
+    }
+
+    /// Choose the entries to render so the path fits in `width` if possible.
+    fn layout(&self, width: usize) -> Vec<Crumb> {
+        let count = self.segments.len();
+        let full: Vec<Crumb> = (0..count).map(Crumb::Segment).collect();
+        if count <= 2 || self.path_width(&full) <= width {
+            return full;
+        }
+
+        // Collapse more and more middle segments, oldest first.
+        for hidden in 1..count - 1 {
+            let mut crumbs = alloc::vec![Crumb::Segment(0), Crumb::Ellipsis];
+            crumbs.extend((1 + hidden..count).map(Crumb::Segment));
+            if self.path_width(&crumbs) <= width {
+                return crumbs;
+            }
+        }
+
+        // Keep only the last segment; it is clipped if still too wide.
+        alloc::vec![Crumb::Ellipsis, Crumb::Segment(count - 1)]
+    }
+}
+
+impl<'a> Stylize for Breadcrumbs<'a> {
+    fn style(mut self, style: Style) -> Self {
+        self.style = style;
+        self
+    }
+}
+
+impl Widget for Breadcrumbs<'_> {
+    fn render(self, area: Rect, buf: &mut Buffer) {
+        if area.area() == 0 || self.segments.is_empty() {
+            return;
+        }
+
+        let selected = self.selected.unwrap_or(self.segments.len() - 1);
+        let crumbs = self.layout(area.width as usize);
+        let y = area.top();
+        let mut x = area.left();
+
+        for (i, &crumb) in crumbs.iter().enumerate() {
+            if i > 0 {
+                x = render_clipped(buf, x, y, area, self.separator, self.style);
+            }
+            match crumb {
+                Crumb::Segment(index) => {
+                    let segment_style = if index == selected {
+                        self.style.patch(self.highlight_style)
+                    } else {
+                        self.style
+                    };
+                    for span in &self.segments[index].spans {
+                        let span_style = segment_style.patch(span.style);
+                        x = render_clipped(buf, x, y, area, &span.content, span_style);
+                    }
+                }
+                Crumb::Ellipsis => {
+                    x = render_clipped(buf, x, y, area, self.ellipsis, self.style);
+                }
+            }
+            if x >= area.right() {
+                break;
+            }
+        }
+    }
+}
+
+/// Render a string clipped to the right edge of `area`.
+fn render_clipped(buf: &mut Buffer, x: u16, y: u16, area: Rect, s: &str, style: Style) -> u16 {
+    let available = area.right().saturating_sub(x) as usize;
+    if s.width() <= available {
+        buf.set_string(x, y, s, style)
+    } else {
+        let clipped = tuxtui_core::util::truncate_string(s, available);
+        buf.set_string(x, y, &clipped, style)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloc::string::String;
+    use alloc::vec;
+
+    fn render(breadcrumbs: Breadcrumbs<'_>, width: u16) -> String {
+        let mut buf = Buffer::empty(Rect::new(0, 0, width, 1));
+        breadcrumbs.render(buf.area, &mut buf);
+        (0..width)
+            .map(|x| buf.get(x, 0).unwrap().symbol())
+            .collect::<String>()
+            .trim_end()
+            .into()
+    }
+
+    #[test]
+    fn test_full_path() {
+        let breadcrumbs = Breadcrumbs::new(vec!["a", "bb", "c"]).separator("/");
+        assert_eq!(render(breadcrumbs, 10), "a/bb/c");
+    }
+
+    #[test]
+    fn test_middle_segments_collapse() {
+        let breadcrumbs = Breadcrumbs::new(vec!["home", "user", "src", "lib"]).separator("/");
+        assert_eq!(render(breadcrumbs.clone(), 17), "home/user/src/lib");
+        assert_eq!(render(breadcrumbs.clone(), 16), "home/…/src/lib");
+        assert_eq!(render(breadcrumbs.clone(), 11), "home/…/lib");
+        assert_eq!(render(breadcrumbs, 5), "…/lib");
+    }
+}
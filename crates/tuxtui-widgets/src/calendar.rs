@@ -1,12 +1,24 @@
-//! Calendar widget (stub implementation).
+//! Calendar widgets: an event store with week and agenda views.
 //!
-//! This is a placeholder for future calendar widget implementation.
+//! The monthly [`Calendar`] is still a placeholder; [`Weekly`] and
+//! [`Agenda`] show the events of a [`CalendarEvents`] store.
 //! Requires the `widget-calendar` feature flag.
 
+use alloc::format;
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
+use time::{Date, PrimitiveDateTime, Time};
 use tuxtui_core::buffer::Buffer;
 use tuxtui_core::geometry::Rect;
-use tuxtui_core::style::Style;
+use tuxtui_core::style::{Color, Modifier, Style};
 use tuxtui_core::terminal::Widget;
+use tuxtui_core::text::{Line, Span};
+
+/// Minutes in a day.
+const DAY_MINUTES: u16 = 24 * 60;
+
+/// The width of the time gutter of [`Weekly`], such as `09:00 `.
+const GUTTER_WIDTH: u16 = 6;
 
 /// A calendar widget.
 ///
@@ -37,3 +49,651 @@ impl Widget for Calendar {
         // TODO: Implement full calendar widget in future version
     }
 }
+
+/// An event in a [`CalendarEvents`] store.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CalendarEvent {
+    /// The title
+    pub title: String,
+    /// When the event starts
+    pub start: PrimitiveDateTime,
+    /// When the event ends
+    pub end: PrimitiveDateTime,
+    /// The style, or reversed colors if unset
+    pub style: Style,
+}
+
+impl CalendarEvent {
+    /// Create an event from `start` to `end`.
+    ///
+    /// The times are swapped if `end` is before `start`.
+    #[must_use]
+    pub fn new(title: impl Into<String>, start: PrimitiveDateTime, end: PrimitiveDateTime) -> Self {
+        Self {
+            title: title.into(),
+            start: start.min(end),
+            end: end.max(start),
+            style: Style::new(),
+        }
+    }
+
+    /// Set the style.
+    #[must_use]
+    pub const fn style(mut self, style: Style) -> Self {
+        self.style = style;
+        self
+    }
+
+    /// Get the minutes since midnight of `date` the event covers on that
+    /// day, or `None` if it is not on that day.
+    fn minutes_on(&self, date: Date) -> Option<(u16, u16)> {
+        let day_start = date.midnight();
+        let day_end = date
+            .next_day()
+            .map_or(PrimitiveDateTime::MAX, Date::midnight);
+        // Instant events show on their day; others need to overlap it.
+        let overlaps = if self.start == self.end {
+            self.start >= day_start && self.start < day_end
+        } else {
+            self.start < day_end && self.end > day_start
+        };
+        if !overlaps {
+            return None;
+        }
+        let start = if self.start < day_start {
+            0
+        } else {
+            minutes(self.start.time())
+        };
+        let end = if self.end >= day_end {
+            DAY_MINUTES
+        } else {
+            minutes(self.end.time())
+        };
+        Some((start, end))
+    }
+
+    fn display_style(&self) -> Style {
+        if self.style == Style::default() {
+            Style::new().add_modifier(Modifier::REVERSED)
+        } else {
+            self.style
+        }
+    }
+}
+
+/// Get the minutes since midnight.
+fn minutes(time: Time) -> u16 {
+    u16::from(time.hour()) * 60 + u16::from(time.minute())
+}
+
+/// Format minutes since midnight as `HH:MM`.
+fn format_minutes(minutes: u16) -> String {
+    format!("{:02}:{:02}", minutes / 60, minutes % 60)
+}
+
+/// A store of events, shared by the calendar views.
+///
+/// # Example
+///
+/// ```
+/// use time::macros::datetime;
+/// use tuxtui_widgets::calendar::{CalendarEvent, CalendarEvents};
+///
+/// let mut events = CalendarEvents::new();
+/// events.add(CalendarEvent::new(
+///     "Standup",
+///     datetime!(2024-05-06 9:00),
+///     datetime!(2024-05-06 9:15),
+/// ));
+/// assert_eq!(events.on(time::macros::date!(2024-05-06)).len(), 1);
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct CalendarEvents {
+    events: Vec<CalendarEvent>,
+}
+
+impl CalendarEvents {
+    /// Create an empty store.
+    #[must_use]
+    pub const fn new() -> Self {
+        Self { events: Vec::new() }
+    }
+
+    /// Add an event, keeping the events ordered by start.
+    pub fn add(&mut self, event: CalendarEvent) {
+        let index = self
+            .events
+            .partition_point(|other| (other.start, other.end) <= (event.start, event.end));
+        self.events.insert(index, event);
+    }
+
+    /// Remove the events matching `predicate`.
+    pub fn remove_where(&mut self, predicate: impl Fn(&CalendarEvent) -> bool) {
+        self.events.retain(|event| !predicate(event));
+    }
+
+    /// Get all events, ordered by start.
+    #[must_use]
+    pub fn events(&self) -> &[CalendarEvent] {
+        &self.events
+    }
+
+    /// Get the events on `date`, including those continuing from or into
+    /// other days, ordered by start.
+    #[must_use]
+    pub fn on(&self, date: Date) -> Vec<&CalendarEvent> {
+        self.events
+            .iter()
+            .filter(|event| event.minutes_on(date).is_some())
+            .collect()
+    }
+}
+
+/// Assign each of `spans`, ordered by start, to the first lane free at its
+/// start. Returns the lane of each and the number of lanes.
+fn lanes(spans: &[(u16, u16)]) -> (Vec<usize>, usize) {
+    let mut ends: Vec<u16> = Vec::new();
+    let lanes = spans
+        .iter()
+        .map(|&(start, end)| {
+            // Instant events still take up their slot.
+            let end = end.max(start + 1);
+            match ends.iter().position(|&lane_end| lane_end <= start) {
+                Some(lane) => {
+                    ends[lane] = end;
+                    lane
+                }
+                None => {
+                    ends.push(end);
+                    ends.len() - 1
+                }
+            }
+        })
+        .collect();
+    (lanes, ends.len())
+}
+
+/// A week view with a column per day and a row per time slot.
+///
+/// Events fill the slots they cover, with overlapping events side by side
+/// in lanes, and a line marks the current time.
+///
+/// # Example
+///
+/// ```
+/// use time::macros::{date, datetime};
+/// use tuxtui_core::prelude::*;
+/// use tuxtui_widgets::calendar::{CalendarEvent, CalendarEvents, Weekly};
+///
+/// let mut events = CalendarEvents::new();
+/// events.add(CalendarEvent::new(
+///     "Review",
+///     datetime!(2024-05-07 10:00),
+///     datetime!(2024-05-07 11:30),
+/// ));
+///
+/// let mut buf = Buffer::empty(Rect::new(0, 0, 60, 20));
+/// Weekly::new(&events, date!(2024-05-06))
+///     .hours(9, 17)
+///     .now(Some(datetime!(2024-05-07 14:20)))
+///     .render(buf.area, &mut buf);
+/// ```
+#[derive(Debug, Clone)]
+pub struct Weekly<'a> {
+    events: &'a CalendarEvents,
+    start: Date,
+    days: u8,
+    first_hour: u8,
+    last_hour: u8,
+    slot_minutes: u16,
+    now: Option<PrimitiveDateTime>,
+    style: Style,
+    header_style: Style,
+    now_style: Style,
+}
+
+impl<'a> Weekly<'a> {
+    /// Create a week view of `events`, starting at `start`.
+    #[must_use]
+    pub const fn new(events: &'a CalendarEvents, start: Date) -> Self {
+        Self {
+            events,
+            start,
+            days: 7,
+            first_hour: 8,
+            last_hour: 18,
+            slot_minutes: 30,
+            now: None,
+            style: Style::new(),
+            header_style: Style::new().add_modifier(Modifier::BOLD),
+            now_style: Style::new().fg(Color::Red),
+        }
+    }
+
+    /// Set the number of days shown, from 1 to 7.
+    #[must_use]
+    pub fn days(mut self, days: u8) -> Self {
+        self.days = days.clamp(1, 7);
+        self
+    }
+
+    /// Set the hours shown, from `first` to the start of `last`.
+    #[must_use]
+    pub fn hours(mut self, first: u8, last: u8) -> Self {
+        self.first_hour = first.min(23);
+        self.last_hour = last.clamp(self.first_hour + 1, 24);
+        self
+    }
+
+    /// Set the minutes per row, at least 1.
+    #[must_use]
+    pub fn slot_minutes(mut self, minutes: u16) -> Self {
+        self.slot_minutes = minutes.max(1);
+        self
+    }
+
+    /// Set the current time, marked with a line.
+    #[must_use]
+    pub const fn now(mut self, now: Option<PrimitiveDateTime>) -> Self {
+        self.now = now;
+        self
+    }
+
+    /// Set the overall style.
+    #[must_use]
+    pub const fn style(mut self, style: Style) -> Self {
+        self.style = style;
+        self
+    }
+
+    /// Set the style of the day names.
+    #[must_use]
+    pub const fn header_style(mut self, style: Style) -> Self {
+        self.header_style = style;
+        self
+    }
+
+    /// Set the style of the current time line.
+    #[must_use]
+    pub const fn now_style(mut self, style: Style) -> Self {
+        self.now_style = style;
+        self
+    }
+}
+
+impl Widget for Weekly<'_> {
+    fn render(self, area: Rect, buf: &mut Buffer) {
+        if area.area() == 0 {
+            return;
+        }
+        buf.set_style(area, self.style);
+        let column_width = area.width.saturating_sub(GUTTER_WIDTH) / u16::from(self.days);
+        if column_width == 0 {
+            return;
+        }
+        // Leave a gap between days.
+        let content_width = column_width.saturating_sub(1).max(1);
+        let view_start = u16::from(self.first_hour) * 60;
+        let view_end = u16::from(self.last_hour) * 60;
+        let rows = (view_end - view_start)
+            .div_ceil(self.slot_minutes)
+            .min(area.height - 1);
+        let top = area.top() + 1;
+
+        for row in 0..rows {
+            let minute = view_start + row * self.slot_minutes;
+            if minute % 60 == 0 {
+                buf.set_string(area.left(), top + row, &format_minutes(minute), self.style);
+            }
+        }
+
+        let mut date = Some(self.start);
+        for day in 0..u16::from(self.days) {
+            let Some(current) = date else {
+                break;
+            };
+            date = current.next_day();
+            let x = area.left() + GUTTER_WIDTH + day * column_width;
+            let header = format!("{} {}", &current.weekday().to_string()[..3], current.day());
+            buf.set_line(
+                x,
+                area.top(),
+                &Line::styled(header, self.style.patch(self.header_style)),
+                content_width,
+            );
+
+            if let Some(now) = self.now.filter(|now| now.date() == current) {
+                let minute = minutes(now.time());
+                if (view_start..view_end).contains(&minute) {
+                    let row = (minute - view_start) / self.slot_minutes;
+                    if row < rows {
+                        let line = "─".repeat(usize::from(content_width));
+                        buf.set_string(x, top + row, &line, self.style.patch(self.now_style));
+                    }
+                }
+            }
+
+            // Events within the hours shown, clipped to them.
+            let events: Vec<_> = self
+                .events
+                .on(current)
+                .into_iter()
+                .filter_map(|event| {
+                    let (start, end) = event.minutes_on(current)?;
+                    let visible = if start == end {
+                        (view_start..view_end).contains(&start)
+                    } else {
+                        start < view_end && end > view_start
+                    };
+                    visible.then(|| (event, (start.max(view_start), end.min(view_end))))
+                })
+                .collect();
+            let spans: Vec<_> = events.iter().map(|&(_, span)| span).collect();
+            let (event_lanes, lane_count) = lanes(&spans);
+            let lane_width = (content_width / lane_count.max(1) as u16).max(1);
+            for ((event, (start, end)), lane) in events.into_iter().zip(event_lanes) {
+                let lane_x = x + lane as u16 * lane_width;
+                if lane_x >= x + content_width {
+                    continue;
+                }
+                let width = lane_width.min(x + content_width - lane_x);
+                let first = (start - view_start) / self.slot_minutes;
+                let last = (end - view_start)
+                    .div_ceil(self.slot_minutes)
+                    .max(first + 1)
+                    .min(rows);
+                let style = self.style.patch(event.display_style());
+                for row in first..last {
+                    buf.set_string(lane_x, top + row, &" ".repeat(usize::from(width)), style);
+                }
+                if first < rows {
+                    buf.set_line(
+                        lane_x,
+                        top + first,
+                        &Line::styled(event.title.as_str(), style),
+                        width,
+                    );
+                }
+            }
+        }
+    }
+}
+
+/// A list of days, each with its events and their times.
+///
+/// Events continuing from or into other days show `…` for the time outside
+/// the day, and a line marks the current time among the day's events.
+///
+/// # Example
+///
+/// ```
+/// use time::macros::{date, datetime};
+/// use tuxtui_core::prelude::*;
+/// use tuxtui_widgets::calendar::{Agenda, CalendarEvent, CalendarEvents};
+///
+/// let mut events = CalendarEvents::new();
+/// events.add(CalendarEvent::new(
+///     "Lunch",
+///     datetime!(2024-05-06 12:00),
+///     datetime!(2024-05-06 13:00),
+/// ));
+///
+/// let mut buf = Buffer::empty(Rect::new(0, 0, 40, 10));
+/// Agenda::new(&events, date!(2024-05-06)).days(3).render(buf.area, &mut buf);
+/// ```
+#[derive(Debug, Clone)]
+pub struct Agenda<'a> {
+    events: &'a CalendarEvents,
+    start: Date,
+    days: u16,
+    skip_empty: bool,
+    now: Option<PrimitiveDateTime>,
+    style: Style,
+    header_style: Style,
+    time_style: Style,
+    now_style: Style,
+}
+
+impl<'a> Agenda<'a> {
+    /// Create an agenda of `events`, starting at `start`.
+    #[must_use]
+    pub const fn new(events: &'a CalendarEvents, start: Date) -> Self {
+        Self {
+            events,
+            start,
+            days: 7,
+            skip_empty: false,
+            now: None,
+            style: Style::new(),
+            header_style: Style::new().add_modifier(Modifier::BOLD),
+            time_style: Style::new().fg(Color::Gray),
+            now_style: Style::new().fg(Color::Red),
+        }
+    }
+
+    /// Set the number of days shown.
+    #[must_use]
+    pub const fn days(mut self, days: u16) -> Self {
+        self.days = days;
+        self
+    }
+
+    /// Set whether to leave out days without events.
+    #[must_use]
+    pub const fn skip_empty(mut self, skip: bool) -> Self {
+        self.skip_empty = skip;
+        self
+    }
+
+    /// Set the current time, marked with a line.
+    #[must_use]
+    pub const fn now(mut self, now: Option<PrimitiveDateTime>) -> Self {
+        self.now = now;
+        self
+    }
+
+    /// Set the overall style.
+    #[must_use]
+    pub const fn style(mut self, style: Style) -> Self {
+        self.style = style;
+        self
+    }
+
+    /// Set the style of the dates.
+    #[must_use]
+    pub const fn header_style(mut self, style: Style) -> Self {
+        self.header_style = style;
+        self
+    }
+
+    /// Set the style of the event times.
+    #[must_use]
+    pub const fn time_style(mut self, style: Style) -> Self {
+        self.time_style = style;
+        self
+    }
+
+    /// Set the style of the current time line.
+    #[must_use]
+    pub const fn now_style(mut self, style: Style) -> Self {
+        self.now_style = style;
+        self
+    }
+
+    /// Lay out the lines of the agenda.
+    fn lines(&self) -> Vec<Line<'a>> {
+        let mut lines = Vec::new();
+        let mut date = Some(self.start);
+        for _ in 0..self.days {
+            let Some(current) = date else {
+                break;
+            };
+            date = current.next_day();
+            let events = self.events.on(current);
+            if events.is_empty() && self.skip_empty {
+                continue;
+            }
+            lines.push(Line::styled(
+                format!(
+                    "{}, {} {}",
+                    current.weekday(),
+                    current.day(),
+                    current.month()
+                ),
+                self.header_style,
+            ));
+            let mut now = self
+                .now
+                .filter(|now| now.date() == current)
+                .map(|now| minutes(now.time()));
+            for event in &events {
+                let Some((start, end)) = event.minutes_on(current) else {
+                    continue;
+                };
+                if let Some(minute) = now.filter(|&minute| minute < start) {
+                    lines.push(self.now_line(minute));
+                    now = None;
+                }
+                let from = if event.start.date() < current {
+                    String::from("  …  ")
+                } else {
+                    format_minutes(start)
+                };
+                let to = if end == DAY_MINUTES {
+                    String::from("…")
+                } else {
+                    format_minutes(end)
+                };
+                lines.push(Line::from(alloc::vec![
+                    Span::styled(format!("  {from}–{to:<5}  "), self.time_style),
+                    Span::styled(event.title.clone(), event.style),
+                ]));
+            }
+            if let Some(minute) = now {
+                lines.push(self.now_line(minute));
+            }
+            if events.is_empty() {
+                lines.push(Line::styled("  No events", self.time_style));
+            }
+        }
+        lines
+    }
+
+    fn now_line(&self, minute: u16) -> Line<'a> {
+        Line::styled(
+            format!("  {} ──── now", format_minutes(minute)),
+            self.now_style,
+        )
+    }
+}
+
+impl Widget for Agenda<'_> {
+    fn render(self, area: Rect, buf: &mut Buffer) {
+        if area.area() == 0 {
+            return;
+        }
+        buf.set_style(area, self.style);
+        for (y, line) in (area.top()..area.bottom()).zip(self.lines()) {
+            buf.set_line(area.left(), y, &line, area.width);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use time::macros::{date, datetime};
+
+    fn store() -> CalendarEvents {
+        let mut events = CalendarEvents::new();
+        events.add(CalendarEvent::new(
+            "Sync",
+            datetime!(2024-05-06 9:30),
+            datetime!(2024-05-06 10:30),
+        ));
+        events.add(CalendarEvent::new(
+            "Plan",
+            datetime!(2024-05-06 9:00),
+            datetime!(2024-05-06 10:00),
+        ));
+        events.add(CalendarEvent::new(
+            "Trip",
+            datetime!(2024-05-06 22:00),
+            datetime!(2024-05-07 2:00),
+        ));
+        events
+    }
+
+    fn rows(buf: &Buffer) -> Vec<String> {
+        buf.to_string()
+            .lines()
+            .map(|line| String::from(line.trim_end()))
+            .collect()
+    }
+
+    #[test]
+    fn test_store_and_lanes() {
+        let events = store();
+        let titles: Vec<_> = events.events().iter().map(|e| e.title.as_str()).collect();
+        assert_eq!(titles, ["Plan", "Sync", "Trip"]);
+        assert_eq!(events.on(date!(2024 - 05 - 07)).len(), 1);
+        assert_eq!(
+            events.events()[2].minutes_on(date!(2024 - 05 - 07)),
+            Some((0, 120))
+        );
+        assert_eq!(events.on(date!(2024 - 05 - 08)).len(), 0);
+
+        assert_eq!(
+            lanes(&[(540, 600), (570, 630), (600, 660)]),
+            (alloc::vec![0, 1, 0], 2)
+        );
+    }
+
+    #[test]
+    fn test_weekly() {
+        let events = store();
+        let mut buf = Buffer::empty(Rect::new(0, 0, 26, 5));
+        Weekly::new(&events, date!(2024 - 05 - 06))
+            .days(2)
+            .hours(9, 11)
+            .now(Some(datetime!(2024-05-07 10:10)))
+            .render(buf.area, &mut buf);
+        assert_eq!(
+            rows(&buf),
+            [
+                "      Mon 6     Tue 7",
+                "09:00 Plan",
+                "          Sync",
+                "10:00           ─────────",
+                "",
+            ]
+        );
+        let plan = buf.get(6, 2).unwrap();
+        assert!(plan.style.add_modifier.contains(Modifier::REVERSED));
+    }
+
+    #[test]
+    fn test_agenda() {
+        let events = store();
+        let mut buf = Buffer::empty(Rect::new(0, 0, 30, 9));
+        Agenda::new(&events, date!(2024 - 05 - 06))
+            .days(3)
+            .now(Some(datetime!(2024-05-06 12:00)))
+            .render(buf.area, &mut buf);
+        assert_eq!(
+            rows(&buf),
+            [
+                "Monday, 6 May",
+                "  09:00–10:00  Plan",
+                "  09:30–10:30  Sync",
+                "  12:00 ──── now",
+                "  22:00–…      Trip",
+                "Tuesday, 7 May",
+                "    …  –02:00  Trip",
+                "Wednesday, 8 May",
+                "  No events",
+            ]
+        );
+    }
+}
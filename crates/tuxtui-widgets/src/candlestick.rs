@@ -0,0 +1,486 @@
+//! Candlestick chart widget for open, high, low and close prices.
+
+use alloc::format;
+use alloc::string::String;
+use alloc::vec::Vec;
+use tuxtui_core::buffer::Buffer;
+use tuxtui_core::event::{KeyCode, KeyEvent};
+use tuxtui_core::geometry::Rect;
+use tuxtui_core::style::{Color, Style};
+use tuxtui_core::symbols::{self, braille};
+use tuxtui_core::terminal::Widget;
+use unicode_width::UnicodeWidthStr;
+
+/// The prices of one period of a [`CandlestickChart`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Candle {
+    /// The first price
+    pub open: f64,
+    /// The highest price
+    pub high: f64,
+    /// The lowest price
+    pub low: f64,
+    /// The last price
+    pub close: f64,
+}
+
+impl Candle {
+    /// Create a candle.
+    #[must_use]
+    pub const fn new(open: f64, high: f64, low: f64, close: f64) -> Self {
+        Self {
+            open,
+            high,
+            low,
+            close,
+        }
+    }
+
+    /// Check whether the price closed at or above where it opened.
+    #[must_use]
+    pub fn is_up(&self) -> bool {
+        self.close >= self.open
+    }
+}
+
+/// How a [`CandlestickChart`] draws its candles.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum CandleMarker {
+    /// Block bodies and line wicks, 2 steps per cell
+    #[default]
+    HalfBlock,
+    /// Braille dots, 4 steps per cell, with thin wicks
+    Braille,
+}
+
+impl CandleMarker {
+    /// Get the steps per cell vertically.
+    const fn resolution(self) -> u16 {
+        match self {
+            Self::HalfBlock => 2,
+            Self::Braille => 4,
+        }
+    }
+
+    /// Get the symbol of a cell, given what each step of it shows.
+    fn symbol(self, steps: &[Part]) -> String {
+        match self {
+            Self::HalfBlock => String::from(match (steps[0], steps[1]) {
+                (Part::Body, Part::Body | Part::Wick) | (Part::Wick, Part::Body) => {
+                    symbols::BAR_FULL
+                }
+                (Part::Body, Part::Empty) => symbols::HALF_UPPER,
+                (Part::Empty, Part::Body) => symbols::HALF_LOWER,
+                (Part::Wick, Part::Wick) => symbols::NORMAL.vertical,
+                (Part::Wick, Part::Empty) => "╵",
+                (Part::Empty, Part::Wick) => "╷",
+                (Part::Empty, Part::Empty) => " ",
+            }),
+            Self::Braille => {
+                const LEFT: [u8; 4] = [0, 1, 2, 6];
+                const RIGHT: [u8; 4] = [3, 4, 5, 7];
+                let bits = steps
+                    .iter()
+                    .enumerate()
+                    .fold(0, |bits, (dy, part)| match part {
+                        Part::Body => bits | 1 << LEFT[dy] | 1 << RIGHT[dy],
+                        Part::Wick => bits | 1 << LEFT[dy],
+                        Part::Empty => bits,
+                    });
+                String::from(braille::char_from_bits(bits))
+            }
+        }
+    }
+}
+
+/// What a step of a candle's column shows.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Part {
+    Empty,
+    Wick,
+    Body,
+}
+
+/// State for a [`CandlestickChart`], holding the crosshair and scrolling.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct CandlestickState {
+    cursor: Option<usize>,
+    offset: usize,
+}
+
+impl CandlestickState {
+    /// Create a state without a crosshair.
+    #[must_use]
+    pub const fn new() -> Self {
+        Self {
+            cursor: None,
+            offset: 0,
+        }
+    }
+
+    /// Get the index of the candle under the crosshair.
+    #[must_use]
+    pub const fn cursor(&self) -> Option<usize> {
+        self.cursor
+    }
+
+    /// Put the crosshair on a candle, or remove it.
+    pub const fn select(&mut self, index: Option<usize>) {
+        self.cursor = index;
+    }
+
+    /// Get the index of the first candle shown.
+    #[must_use]
+    pub const fn offset(&self) -> usize {
+        self.offset
+    }
+
+    /// Handle a key press for `len` candles.
+    ///
+    /// Left and Right move the crosshair, showing it on the newest candle
+    /// first, Home and End jump to the oldest and newest, and Esc removes
+    /// it. Returns `true` if the key was consumed.
+    pub fn handle_key(&mut self, key: &KeyEvent, len: usize) -> bool {
+        if key.is_release() {
+            return false;
+        }
+        let last = len.checked_sub(1);
+        self.cursor = match (key.code, self.cursor) {
+            (KeyCode::Left, Some(cursor)) => Some(cursor.saturating_sub(1)),
+            (KeyCode::Right, Some(cursor)) => Some((cursor + 1).min(last.unwrap_or(0))),
+            (KeyCode::Left | KeyCode::Right | KeyCode::End, _) => last,
+            (KeyCode::Home, _) if len > 0 => Some(0),
+            (KeyCode::Esc, Some(_)) => None,
+            _ => return false,
+        };
+        true
+    }
+}
+
+/// A candlestick chart of prices over time.
+///
+/// Each candle's body spans its open and close prices, colored by whether
+/// the price went up or down, and its wick spans the high and low. Prices
+/// are labeled on the y-axis, which scales to the candles shown. The newest
+/// candles are shown, unless the crosshair of a [`CandlestickState`] is on
+/// an older one; its prices are then shown at the top.
+///
+/// # Example
+///
+/// ```
+/// use tuxtui_core::prelude::*;
+/// use tuxtui_widgets::candlestick::{Candle, CandlestickChart, CandlestickState};
+///
+/// let candles = [
+///     Candle::new(10.0, 12.0, 9.5, 11.5),
+///     Candle::new(11.5, 11.8, 10.2, 10.4),
+/// ];
+/// let mut state = CandlestickState::new();
+/// state.handle_key(&KeyCode::Left.into(), candles.len());
+/// assert_eq!(state.cursor(), Some(1));
+///
+/// let mut buf = Buffer::empty(Rect::new(0, 0, 30, 10));
+/// CandlestickChart::new(&candles).render_stateful(buf.area, &mut buf, &mut state);
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct CandlestickChart<'a> {
+    candles: &'a [Candle],
+    marker: CandleMarker,
+    spacing: u16,
+    precision: usize,
+    style: Style,
+    up_style: Style,
+    down_style: Style,
+    axis_style: Style,
+    crosshair_style: Style,
+}
+
+impl<'a> CandlestickChart<'a> {
+    /// Create a chart of `candles`, oldest first.
+    #[must_use]
+    pub const fn new(candles: &'a [Candle]) -> Self {
+        Self {
+            candles,
+            marker: CandleMarker::HalfBlock,
+            spacing: 1,
+            precision: 2,
+            style: Style::new(),
+            up_style: Style::new().fg(Color::Green),
+            down_style: Style::new().fg(Color::Red),
+            axis_style: Style::new(),
+            crosshair_style: Style::new().fg(Color::Gray),
+        }
+    }
+
+    /// Set how candles are drawn.
+    #[must_use]
+    pub const fn marker(mut self, marker: CandleMarker) -> Self {
+        self.marker = marker;
+        self
+    }
+
+    /// Set the number of columns between candles.
+    #[must_use]
+    pub const fn spacing(mut self, spacing: u16) -> Self {
+        self.spacing = spacing;
+        self
+    }
+
+    /// Set the decimal places of prices.
+    #[must_use]
+    pub const fn precision(mut self, precision: usize) -> Self {
+        self.precision = precision;
+        self
+    }
+
+    /// Set the overall style.
+    #[must_use]
+    pub const fn style(mut self, style: Style) -> Self {
+        self.style = style;
+        self
+    }
+
+    /// Set the style of candles closing at or above their open.
+    #[must_use]
+    pub const fn up_style(mut self, style: Style) -> Self {
+        self.up_style = style;
+        self
+    }
+
+    /// Set the style of candles closing below their open.
+    #[must_use]
+    pub const fn down_style(mut self, style: Style) -> Self {
+        self.down_style = style;
+        self
+    }
+
+    /// Set the style of the y-axis and its labels.
+    #[must_use]
+    pub const fn axis_style(mut self, style: Style) -> Self {
+        self.axis_style = style;
+        self
+    }
+
+    /// Set the style of the crosshair and the prices under it.
+    #[must_use]
+    pub const fn crosshair_style(mut self, style: Style) -> Self {
+        self.crosshair_style = style;
+        self
+    }
+
+    fn price(&self, price: f64) -> String {
+        format!("{price:.*}", self.precision)
+    }
+
+    /// Render the chart with state.
+    pub fn render_stateful(self, area: Rect, buf: &mut Buffer, state: &mut CandlestickState) {
+        if area.area() == 0 {
+            return;
+        }
+        buf.set_style(area, self.style);
+        if self.candles.is_empty() {
+            return;
+        }
+        state.cursor = state
+            .cursor
+            .map(|cursor| cursor.min(self.candles.len() - 1));
+
+        // Fit the labels of the widest prices in view; the bounds depend on
+        // the candles shown, which depend on the room left by the labels.
+        let widest = self
+            .candles
+            .iter()
+            .flat_map(|candle| [candle.high, candle.low])
+            .map(|price| self.price(price).width())
+            .max()
+            .unwrap_or(0) as u16;
+        let axis_x = area.left() + widest;
+        if axis_x + 1 >= area.right() {
+            return;
+        }
+        let plot = Rect::new(
+            axis_x + 1,
+            area.top(),
+            area.right() - axis_x - 1,
+            area.height,
+        );
+        let step = self.spacing + 1;
+        let visible = usize::from(plot.width.div_ceil(step));
+
+        let len = self.candles.len();
+        state.offset = match state.cursor {
+            Some(cursor) if cursor < state.offset => cursor,
+            Some(cursor) if cursor >= state.offset + visible => cursor + 1 - visible,
+            Some(_) => state.offset.min(len.saturating_sub(visible)),
+            None => len.saturating_sub(visible),
+        };
+        let shown = &self.candles[state.offset..len.min(state.offset + visible)];
+
+        let high = shown
+            .iter()
+            .map(|c| c.high)
+            .fold(f64::NEG_INFINITY, f64::max);
+        let low = shown.iter().map(|c| c.low).fold(f64::INFINITY, f64::min);
+        let range = if high > low { high - low } else { 1.0 };
+        let resolution = self.marker.resolution();
+        let steps = plot.height * resolution;
+        let to_step = |price: f64| {
+            let step = (high - price) / range * f64::from(steps - 1) + 0.5;
+            (step.max(0.0) as u16).min(steps - 1)
+        };
+
+        let axis_style = self.style.patch(self.axis_style);
+        for y in plot.top()..plot.bottom() {
+            buf.set(axis_x, y, symbols::NORMAL.vertical, axis_style);
+        }
+        let top = self.price(high);
+        let bottom = self.price(low);
+        buf.set_string(axis_x - top.width() as u16, plot.top(), &top, axis_style);
+        if plot.height > 1 {
+            let x = axis_x - bottom.width() as u16;
+            buf.set_string(x, plot.bottom() - 1, &bottom, axis_style);
+        }
+
+        let crosshair_style = self.style.patch(self.crosshair_style);
+        let cursor = state
+            .cursor
+            .and_then(|cursor| cursor.checked_sub(state.offset));
+        if let Some(column) = cursor {
+            // The price line of the close, under the candles.
+            let candle = shown[column];
+            let y = plot.top() + to_step(candle.close) / resolution;
+            for x in plot.left()..plot.right() {
+                buf.set(x, y, symbols::NORMAL.horizontal, crosshair_style);
+            }
+            let close = self.price(candle.close);
+            buf.set_string(axis_x - close.width() as u16, y, &close, crosshair_style);
+        }
+
+        let mut parts = Vec::with_capacity(usize::from(resolution));
+        for (column, candle) in shown.iter().enumerate() {
+            let x = plot.left() + column as u16 * step;
+            let style = self.style.patch(if candle.is_up() {
+                self.up_style
+            } else {
+                self.down_style
+            });
+            let (wick_top, wick_bottom) = (to_step(candle.high), to_step(candle.low));
+            let body_top = to_step(candle.open.max(candle.close));
+            let body_bottom = to_step(candle.open.min(candle.close));
+            for row in 0..plot.height {
+                parts.clear();
+                parts.extend((row * resolution..(row + 1) * resolution).map(|step| {
+                    if (body_top..=body_bottom).contains(&step) {
+                        Part::Body
+                    } else if (wick_top..=wick_bottom).contains(&step) {
+                        Part::Wick
+                    } else {
+                        Part::Empty
+                    }
+                }));
+                if parts.iter().any(|&part| part != Part::Empty) {
+                    buf.set(x, plot.top() + row, self.marker.symbol(&parts), style);
+                } else if cursor == Some(column) {
+                    buf.set(
+                        x,
+                        plot.top() + row,
+                        symbols::NORMAL.vertical,
+                        crosshair_style,
+                    );
+                }
+            }
+        }
+
+        if let Some(column) = cursor {
+            let candle = shown[column];
+            let info = format!(
+                "O {} H {} L {} C {}",
+                self.price(candle.open),
+                self.price(candle.high),
+                self.price(candle.low),
+                self.price(candle.close)
+            );
+            let width = (info.width() as u16).min(plot.width);
+            // Keep the readout clear of the candle it describes.
+            let x = if plot.left() + column as u16 * step < plot.left() + plot.width / 2 {
+                plot.right() - width
+            } else {
+                plot.left()
+            };
+            buf.set_line(x, plot.top(), &info.as_str().into(), width);
+            buf.set_style(Rect::new(x, plot.top(), width, 1), crosshair_style);
+        }
+    }
+}
+
+impl Widget for CandlestickChart<'_> {
+    fn render(self, area: Rect, buf: &mut Buffer) {
+        self.render_stateful(area, buf, &mut CandlestickState::new());
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn rows(buf: &Buffer) -> Vec<String> {
+        buf.to_string().lines().map(String::from).collect()
+    }
+
+    #[test]
+    fn test_render_candles() {
+        let candles = [
+            Candle::new(1.0, 4.0, 0.0, 3.0),
+            Candle::new(3.0, 3.0, 1.0, 2.0),
+        ];
+        let mut buf = Buffer::empty(Rect::new(0, 0, 8, 3));
+        CandlestickChart::new(&candles)
+            .precision(0)
+            .render(buf.area, &mut buf);
+        assert_eq!(rows(&buf), ["4│█ ▄   ", " │█ █   ", "0│█ ╵   "]);
+        assert_eq!(buf.get(2, 1).unwrap().style.fg, Some(Color::Green));
+        assert_eq!(buf.get(4, 1).unwrap().style.fg, Some(Color::Red));
+
+        let mut buf = Buffer::empty(Rect::new(0, 0, 8, 1));
+        CandlestickChart::new(&candles)
+            .precision(0)
+            .marker(CandleMarker::Braille)
+            .render(buf.area, &mut buf);
+        assert_eq!(rows(&buf), ["4│⡷ ⠶   "]);
+    }
+
+    #[test]
+    fn test_crosshair_scrolls() {
+        let candles: Vec<_> = (0..10)
+            .map(|i| {
+                Candle::new(
+                    f64::from(i),
+                    f64::from(i) + 1.0,
+                    f64::from(i),
+                    f64::from(i) + 1.0,
+                )
+            })
+            .collect();
+        let mut state = CandlestickState::new();
+        let mut buf = Buffer::empty(Rect::new(0, 0, 9, 4));
+        CandlestickChart::new(&candles)
+            .precision(0)
+            .render_stateful(buf.area, &mut buf, &mut state);
+        assert_eq!(state.offset(), 7);
+
+        assert!(state.handle_key(&KeyCode::Home.into(), candles.len()));
+        let mut buf = Buffer::empty(buf.area);
+        CandlestickChart::new(&candles)
+            .precision(0)
+            .render_stateful(buf.area, &mut buf, &mut state);
+        assert_eq!((state.cursor(), state.offset()), (Some(0), 0));
+        let text = rows(&buf);
+        assert!(text[0].ends_with("O 0 H "));
+        assert_eq!(text[2], " 1│▄─█───");
+
+        assert!(state.handle_key(&KeyCode::Esc.into(), candles.len()));
+        assert!(!state.handle_key(&KeyCode::Esc.into(), candles.len()));
+        assert!(state.handle_key(&KeyCode::Right.into(), candles.len()));
+        assert_eq!(state.cursor(), Some(9));
+    }
+}
@@ -94,36 +94,45 @@ impl CanvasContext {
         self.draw_line(x1, y2, x1, y1);
     }
 
-    /// Render the canvas to a buffer.
+    /// Get the braille dot bits of a cell.
+    fn cell_bits(&self, cell_x: u16, cell_y: u16) -> u8 {
+        let mut bits = 0u8;
+
+        // Each braille character represents a 2x4 grid
+        for dy in 0..4 {
+            for dx in 0..2 {
+                let gx = cell_x as usize * 2 + dx;
+                let gy = cell_y as usize * 4 + dy;
+
+                if gy < self.grid.len() && gx < self.grid[0].len() && self.grid[gy][gx] {
+                    let bit_index = match (dx, dy) {
+                        (0, 0) => 0,
+                        (0, 1) => 1,
+                        (0, 2) => 2,
+                        (1, 0) => 3,
+                        (1, 1) => 4,
+                        (1, 2) => 5,
+                        (0, 3) => 6,
+                        (1, 3) => 7,
+                        _ => 0,
+                    };
+                    bits |= 1 << bit_index;
+                }
+            }
+        }
+        bits
+    }
+
+    /// Render the canvas to a buffer, in braille unless ASCII only is set
+    /// in the buffer's settings.
     pub fn render(&self, buf: &mut Buffer) {
+        if buf.settings.ascii_only {
+            self.render_ascii(buf);
+            return;
+        }
         for cell_y in 0..self.area.height {
             for cell_x in 0..self.area.width {
-                let mut bits = 0u8;
-
-                // Each braille character represents a 2x4 grid
-                for dy in 0..4 {
-                    for dx in 0..2 {
-                        let gx = cell_x as usize * 2 + dx;
-                        let gy = cell_y as usize * 4 + dy;
-
-                        if gy < self.grid.len() && gx < self.grid[0].len() && self.grid[gy][gx] {
-                            let bit_index = match (dx, dy) {
-                                (0, 0) => 0,
-                                (0, 1) => 1,
-                                (0, 2) => 2,
-                                (1, 0) => 3,
-                                (1, 1) => 4,
-                                (1, 2) => 5,
-                                (0, 3) => 6,
-                                (1, 3) => 7,
-                                _ => 0,
-                            };
-                            bits |= 1 << bit_index;
-                        }
-                    }
-                }
-
-                let ch = braille::char_from_bits(bits);
+                let ch = braille::char_from_bits(self.cell_bits(cell_x, cell_y));
                 let ch_str = alloc::string::String::from(ch);
                 buf.set(
                     self.area.left() + cell_x,
@@ -134,6 +143,31 @@ impl CanvasContext {
             }
         }
     }
+
+    /// Render the canvas to a buffer with ASCII only, at half a cell's
+    /// height: `'` for dots in the top half of a cell, `.` for the bottom
+    /// half and `:` for both.
+    pub fn render_ascii(&self, buf: &mut Buffer) {
+        const TOP: u8 = 0b0001_1011;
+        const BOTTOM: u8 = 0b1110_0100;
+        for cell_y in 0..self.area.height {
+            for cell_x in 0..self.area.width {
+                let bits = self.cell_bits(cell_x, cell_y);
+                let symbol = match (bits & TOP != 0, bits & BOTTOM != 0) {
+                    (true, true) => ":",
+                    (true, false) => "'",
+                    (false, true) => ".",
+                    (false, false) => " ",
+                };
+                buf.set(
+                    self.area.left() + cell_x,
+                    self.area.top() + cell_y,
+                    symbol,
+                    self.style,
+                );
+            }
+        }
+    }
 }
 
 /// A canvas widget for custom drawing.
@@ -147,7 +181,7 @@ impl CanvasContext {
 /// let canvas = Canvas::default()
 ///     .x_bounds([0.0, 10.0])
 ///     .y_bounds([0.0, 10.0])
-///     .paint(|ctx| {
+///     .paint(&|ctx| {
 ///         ctx.draw_line(0.0, 0.0, 10.0, 10.0);
 ///     });
 /// ```
@@ -156,6 +190,7 @@ pub struct Canvas<'a> {
     y_bounds: [f64; 2],
     style: Style,
     painter: Option<&'a dyn Fn(&mut CanvasContext)>,
+    use_unicode: bool,
 }
 
 impl<'a> Default for Canvas<'a> {
@@ -165,6 +200,7 @@ impl<'a> Default for Canvas<'a> {
             y_bounds: [0.0, 1.0],
             style: Style::new(),
             painter: None,
+            use_unicode: true,
         }
     }
 }
@@ -178,6 +214,7 @@ impl<'a> Canvas<'a> {
             y_bounds: [0.0, 1.0],
             style: Style::new(),
             painter: None,
+            use_unicode: true,
         }
     }
 
@@ -208,6 +245,13 @@ impl<'a> Canvas<'a> {
         self.painter = Some(painter);
         self
     }
+
+    /// Set whether to draw in braille (the default), or with ASCII only.
+    #[must_use]
+    pub const fn use_unicode(mut self, use_unicode: bool) -> Self {
+        self.use_unicode = use_unicode;
+        self
+    }
 }
 
 impl Widget for Canvas<'_> {
@@ -223,7 +267,11 @@ impl Widget for Canvas<'_> {
             painter(&mut ctx);
         }
 
-        ctx.render(buf);
+        if self.use_unicode {
+            ctx.render(buf);
+        } else {
+            ctx.render_ascii(buf);
+        }
     }
 }
 
@@ -237,4 +285,21 @@ mod tests {
         let mut ctx = CanvasContext::new(area, [0.0, 10.0], [0.0, 10.0]);
         ctx.draw_point(5.0, 5.0);
     }
+
+    #[test]
+    fn test_ascii_canvas() {
+        let mut buf = Buffer::empty(Rect::new(0, 0, 2, 1));
+        Canvas::new()
+            .x_bounds([0.0, 4.0])
+            .y_bounds([0.0, 4.0])
+            .paint(&|ctx| {
+                ctx.draw_point(0.0, 3.5);
+                ctx.draw_point(3.0, 3.5);
+                ctx.draw_point(3.0, 0.5);
+            })
+            .use_unicode(false)
+            .render(buf.area, &mut buf);
+        assert_eq!(buf.get(0, 0).unwrap().symbol(), "'");
+        assert_eq!(buf.get(1, 0).unwrap().symbol(), ":");
+    }
 }
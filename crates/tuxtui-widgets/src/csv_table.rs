@@ -0,0 +1,325 @@
+//! Load comma or tab separated data into a table.
+
+use crate::table::{Row, Table};
+use alloc::borrow::Cow;
+use alloc::format;
+use alloc::string::String;
+use alloc::vec::Vec;
+use std::io::{self, Read};
+use tuxtui_core::layout::Constraint;
+use tuxtui_core::text::{Line, Span, Text};
+use unicode_width::UnicodeWidthStr;
+
+/// The widest a column is made to fit its contents.
+const MAX_COLUMN_WIDTH: usize = 40;
+
+/// Delimiters recognized when none is set, in order of preference.
+const DELIMITERS: [char; 4] = [',', '\t', ';', '|'];
+
+/// Options for parsing delimited data.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct CsvOptions {
+    delimiter: Option<char>,
+    headers: Option<bool>,
+}
+
+impl CsvOptions {
+    /// Create options detecting the delimiter and whether there is a header.
+    #[must_use]
+    pub const fn new() -> Self {
+        Self {
+            delimiter: None,
+            headers: None,
+        }
+    }
+
+    /// Set the delimiter, such as `'\t'` for TSV. By default the most
+    /// frequent of `,`, tab, `;` and `|` on the first line is used.
+    #[must_use]
+    pub const fn delimiter(mut self, delimiter: char) -> Self {
+        self.delimiter = Some(delimiter);
+        self
+    }
+
+    /// Set whether the first record is a header. By default it is unless
+    /// it looks like data, such as numbers in numeric columns.
+    #[must_use]
+    pub const fn headers(mut self, headers: bool) -> Self {
+        self.headers = Some(headers);
+        self
+    }
+}
+
+/// Delimited data parsed into records, backing a [`Table`].
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct CsvData {
+    /// The column names, either from the header or numbered from 1
+    pub headers: Vec<String>,
+    /// The records, each with a field per column
+    pub records: Vec<Vec<String>>,
+    /// Column widths fitting the contents, up to 40 cells
+    pub widths: Vec<Constraint>,
+}
+
+impl CsvData {
+    /// Parse delimited data, as described in RFC 4180: fields may be quoted
+    /// with `"` to hold delimiters, line breaks and doubled quotes.
+    ///
+    /// Records shorter than the widest are padded with empty fields.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if reading fails or the data is not UTF-8.
+    pub fn parse(mut reader: impl Read, options: CsvOptions) -> io::Result<Self> {
+        let mut input = String::new();
+        reader.read_to_string(&mut input)?;
+        let delimiter = options.delimiter.unwrap_or_else(|| sniff_delimiter(&input));
+        let mut records = parse_records(&input, delimiter);
+        let columns = records.iter().map(Vec::len).max().unwrap_or(0);
+        for record in &mut records {
+            record.resize(columns, String::new());
+        }
+
+        let has_headers = options
+            .headers
+            .unwrap_or_else(|| looks_like_header(&records));
+        let headers = if has_headers && !records.is_empty() {
+            records.remove(0)
+        } else {
+            (1..=columns).map(|i| format!("Column {i}")).collect()
+        };
+        let widths = (0..columns)
+            .map(|column| {
+                let width = records
+                    .iter()
+                    .map(|record| record[column].width())
+                    .fold(headers[column].width(), usize::max)
+                    .min(MAX_COLUMN_WIDTH);
+                Constraint::Length(width as u16)
+            })
+            .collect();
+        Ok(Self {
+            headers,
+            records,
+            widths,
+        })
+    }
+
+    /// Create a table borrowing the records.
+    ///
+    /// Numbers are right-aligned.
+    #[must_use]
+    pub fn table(&self) -> Table<'_> {
+        build_table(self, Cow::Borrowed)
+    }
+}
+
+impl Table<'static> {
+    /// Create a table from comma or tab separated data, along with the
+    /// parsed records, for example to sort or filter them. See
+    /// [`CsvData::parse`].
+    ///
+    /// The delimiter and whether the first line is a header are detected,
+    /// and column widths fit the contents.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if reading fails or the data is not UTF-8.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use tuxtui_widgets::table::Table;
+    ///
+    /// let csv = "level\tcount\nwarn\t3\nerror\t12\n";
+    /// let (table, data) = Table::from_csv(csv.as_bytes())?;
+    /// assert_eq!(data.headers, ["level", "count"]);
+    /// assert_eq!(table.len(), 2);
+    /// # Ok::<(), std::io::Error>(())
+    /// ```
+    pub fn from_csv(reader: impl Read) -> io::Result<(Self, CsvData)> {
+        Self::from_csv_with(reader, CsvOptions::new())
+    }
+
+    /// Create a table from delimited data, parsed with `options`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if reading fails or the data is not UTF-8.
+    pub fn from_csv_with(reader: impl Read, options: CsvOptions) -> io::Result<(Self, CsvData)> {
+        let data = CsvData::parse(reader, options)?;
+        let table = build_table(&data, |field| Cow::Owned(String::from(field)));
+        Ok((table, data))
+    }
+}
+
+/// Build a table of the records, with `text` turning fields into cells.
+fn build_table<'d, 'a>(data: &'d CsvData, text: impl Fn(&'d str) -> Cow<'a, str>) -> Table<'a> {
+    let header = Row::new(
+        data.headers
+            .iter()
+            .map(|name| Line::from(Span::raw(text(name)))),
+    );
+    let rows: Vec<Row<'a>> = data
+        .records
+        .iter()
+        .map(|record| {
+            Row::new(record.iter().map(|field| {
+                let line = Line::from(Span::raw(text(field)));
+                Text::from(if is_number(field) {
+                    line.right_aligned()
+                } else {
+                    line
+                })
+            }))
+        })
+        .collect();
+    Table::new(rows, data.widths.iter().copied()).header(header)
+}
+
+/// Check whether a field holds a number.
+fn is_number(field: &str) -> bool {
+    let field = field.trim();
+    !field.is_empty() && field.parse::<f64>().is_ok()
+}
+
+/// Pick the delimiter occurring most often outside quotes on the first line.
+fn sniff_delimiter(input: &str) -> char {
+    let mut counts = [0_usize; DELIMITERS.len()];
+    let mut quoted = false;
+    for c in input.chars() {
+        match c {
+            '"' => quoted = !quoted,
+            '\n' if !quoted => break,
+            _ if !quoted => {
+                if let Some(i) = DELIMITERS.iter().position(|&d| d == c) {
+                    counts[i] += 1;
+                }
+            }
+            _ => {}
+        }
+    }
+    // The first of the most frequent, so ties go to the comma.
+    let (index, _) =
+        counts.iter().enumerate().fold(
+            (0, 0),
+            |best, (i, &count)| if count > best.1 { (i, count) } else { best },
+        );
+    DELIMITERS[index]
+}
+
+/// Split the input into records of fields.
+fn parse_records(input: &str, delimiter: char) -> Vec<Vec<String>> {
+    let mut records = Vec::new();
+    let mut record = Vec::new();
+    let mut field = String::new();
+    let mut quoted = false;
+    let mut chars = input.chars().peekable();
+    while let Some(c) = chars.next() {
+        if quoted {
+            match c {
+                '"' if chars.peek() == Some(&'"') => {
+                    chars.next();
+                    field.push('"');
+                }
+                '"' => quoted = false,
+                _ => field.push(c),
+            }
+            continue;
+        }
+        match c {
+            '"' if field.is_empty() => quoted = true,
+            '\r' if chars.peek() == Some(&'\n') => {}
+            '\n' => {
+                record.push(core::mem::take(&mut field));
+                records.push(core::mem::take(&mut record));
+            }
+            _ if c == delimiter => record.push(core::mem::take(&mut field)),
+            _ => field.push(c),
+        }
+    }
+    if !field.is_empty() || !record.is_empty() {
+        record.push(field);
+        records.push(record);
+    }
+    // Blank lines carry no data.
+    records.retain(|record| !(record.len() == 1 && record[0].is_empty()));
+    records
+}
+
+/// Guess whether the first record is a header.
+///
+/// Every column votes: one holding numbers is a vote for a header if the
+/// first field is not a number, and against otherwise. Without any numbers
+/// to go by, the first record is taken as a header.
+fn looks_like_header(records: &[Vec<String>]) -> bool {
+    let Some((first, rest)) = records.split_first() else {
+        return false;
+    };
+    let mut votes = 0_i32;
+    for (column, name) in first.iter().enumerate() {
+        let mut values = rest.iter().map(|record| record[column].as_str());
+        let numeric = values.clone().any(|value| !value.is_empty())
+            && values.all(|value| value.is_empty() || is_number(value));
+        if numeric {
+            votes += if is_number(name) { -1 } else { 1 };
+        }
+    }
+    votes >= 0
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tuxtui_core::buffer::Buffer;
+    use tuxtui_core::geometry::Rect;
+    use tuxtui_core::terminal::Widget;
+
+    #[test]
+    fn test_parse_quotes_and_delimiters() {
+        let csv = "name,note\r\n\"Smith, J\",\"said \"\"hi\"\"\nand left\"\n\nDoe\n";
+        let data = CsvData::parse(csv.as_bytes(), CsvOptions::new()).unwrap();
+        assert_eq!(data.headers, ["name", "note"]);
+        assert_eq!(
+            data.records,
+            [["Smith, J", "said \"hi\"\nand left"], ["Doe", ""]]
+        );
+
+        let tsv = "a\tb,c\td\n1\t2\t3\n";
+        let data = CsvData::parse(tsv.as_bytes(), CsvOptions::new()).unwrap();
+        assert_eq!(data.headers, ["a", "b,c", "d"]);
+        let data = CsvData::parse(tsv.as_bytes(), CsvOptions::new().delimiter(',')).unwrap();
+        assert_eq!(data.headers, ["a\tb", "c\td"]);
+    }
+
+    #[test]
+    fn test_infer_headers() {
+        let data = CsvData::parse("1,2\n3,4\n".as_bytes(), CsvOptions::new()).unwrap();
+        assert_eq!(data.headers, ["Column 1", "Column 2"]);
+        assert_eq!(data.records.len(), 2);
+
+        let options = CsvOptions::new().headers(true);
+        let data = CsvData::parse("1,2\n3,4\n".as_bytes(), options).unwrap();
+        assert_eq!(data.headers, ["1", "2"]);
+
+        let options = CsvOptions::new().headers(false);
+        let data = CsvData::parse("id,name\n".as_bytes(), options).unwrap();
+        assert_eq!(data.records, [["id", "name"]]);
+    }
+
+    #[test]
+    fn test_from_csv() {
+        let csv = "host,requests\nalpha,7\nbeta,1200\n";
+        let (table, data) = Table::from_csv(csv.as_bytes()).unwrap();
+        assert_eq!(data.widths, [Constraint::Length(5), Constraint::Length(8)]);
+        assert_eq!(data.table().len(), table.len());
+
+        let area = Rect::new(0, 0, 14, 3);
+        let mut buf = Buffer::empty(area);
+        table.render(area, &mut buf);
+        assert_eq!(
+            buf.to_string(),
+            "host  requests\nalpha        7\nbeta      1200"
+        );
+    }
+}
@@ -0,0 +1,932 @@
+//! Forms built from a list of fields, with focus order and validation.
+
+use crate::input::{InputFilter, InputState, TextInput};
+use crate::slider::{Slider, SliderState};
+use alloc::collections::BTreeMap;
+use alloc::format;
+use alloc::string::String;
+use alloc::vec::Vec;
+use tuxtui_core::buffer::Buffer;
+use tuxtui_core::event::{KeyCode, KeyEvent};
+use tuxtui_core::geometry::Rect;
+use tuxtui_core::narration::Describe;
+use tuxtui_core::style::{Color, Style};
+use tuxtui_core::text::Line;
+use unicode_width::UnicodeWidthStr;
+
+/// The value of a form field.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum FieldValue {
+    /// The text of a text or password field
+    Text(String),
+    /// Whether a checkbox is checked
+    Bool(bool),
+    /// The value of a slider
+    Number(i64),
+    /// The chosen option of a select field
+    Choice(String),
+}
+
+impl FieldValue {
+    /// Get the text of a text field or the chosen option of a select field.
+    #[must_use]
+    pub fn as_str(&self) -> Option<&str> {
+        match self {
+            Self::Text(text) | Self::Choice(text) => Some(text),
+            _ => None,
+        }
+    }
+
+    /// Get whether a checkbox is checked.
+    #[must_use]
+    pub const fn as_bool(&self) -> Option<bool> {
+        match self {
+            Self::Bool(checked) => Some(*checked),
+            _ => None,
+        }
+    }
+
+    /// Get the value of a slider.
+    #[must_use]
+    pub const fn as_number(&self) -> Option<i64> {
+        match self {
+            Self::Number(number) => Some(*number),
+            _ => None,
+        }
+    }
+}
+
+impl From<&str> for FieldValue {
+    fn from(text: &str) -> Self {
+        Self::Text(String::from(text))
+    }
+}
+
+impl From<String> for FieldValue {
+    fn from(text: String) -> Self {
+        Self::Text(text)
+    }
+}
+
+impl From<bool> for FieldValue {
+    fn from(checked: bool) -> Self {
+        Self::Bool(checked)
+    }
+}
+
+impl From<i64> for FieldValue {
+    fn from(number: i64) -> Self {
+        Self::Number(number)
+    }
+}
+
+/// The values of a form's fields, by field name.
+pub type FormValues = BTreeMap<String, FieldValue>;
+
+/// Checks the value of a field, returning an error message if it is invalid.
+pub type FieldValidator = fn(&FieldValue) -> Result<(), String>;
+
+/// Checks the values of a form as a whole, returning an error for one of
+/// its fields, such as a password confirmation that does not match.
+pub type FormValidator = fn(&FormValues) -> Result<(), FieldError>;
+
+/// An error reported by a [`FormValidator`] for a field.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct FieldError {
+    /// The name of the field
+    pub field: String,
+    /// The message shown below the field
+    pub message: String,
+}
+
+impl FieldError {
+    /// Create an error for the field named `field`.
+    #[must_use]
+    pub fn new(field: impl Into<String>, message: impl Into<String>) -> Self {
+        Self {
+            field: field.into(),
+            message: message.into(),
+        }
+    }
+}
+
+/// The input of a field.
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum Input {
+    Text {
+        state: InputState,
+        placeholder: String,
+        mask: Option<char>,
+    },
+    Checkbox(bool),
+    Select {
+        options: Vec<String>,
+        selected: usize,
+    },
+    Slider(SliderState),
+}
+
+/// A field of a [`FormState`]: a labeled text input, password input,
+/// checkbox, select or slider, with its validation.
+#[derive(Debug, Clone)]
+pub struct Field {
+    name: String,
+    label: String,
+    input: Input,
+    required: bool,
+    validator: Option<FieldValidator>,
+    initial: FieldValue,
+    error: Option<String>,
+}
+
+impl Field {
+    fn new(name: impl Into<String>, label: impl Into<String>, input: Input) -> Self {
+        let mut field = Self {
+            name: name.into(),
+            label: label.into(),
+            input,
+            required: false,
+            validator: None,
+            initial: FieldValue::Bool(false),
+            error: None,
+        };
+        field.initial = field.value();
+        field
+    }
+
+    /// Create a text field named `name`, shown as `label`.
+    #[must_use]
+    pub fn text(name: impl Into<String>, label: impl Into<String>) -> Self {
+        let input = Input::Text {
+            state: InputState::new(),
+            placeholder: String::new(),
+            mask: None,
+        };
+        Self::new(name, label, input)
+    }
+
+    /// Create a text field whose characters are hidden.
+    #[must_use]
+    pub fn password(name: impl Into<String>, label: impl Into<String>) -> Self {
+        let input = Input::Text {
+            state: InputState::new(),
+            placeholder: String::new(),
+            mask: Some('•'),
+        };
+        Self::new(name, label, input)
+    }
+
+    /// Create a checkbox, unchecked.
+    #[must_use]
+    pub fn checkbox(name: impl Into<String>, label: impl Into<String>) -> Self {
+        Self::new(name, label, Input::Checkbox(false))
+    }
+
+    /// Create a select field choosing one of `options`, the first by
+    /// default.
+    #[must_use]
+    pub fn select<S: Into<String>>(
+        name: impl Into<String>,
+        label: impl Into<String>,
+        options: impl IntoIterator<Item = S>,
+    ) -> Self {
+        let input = Input::Select {
+            options: options.into_iter().map(Into::into).collect(),
+            selected: 0,
+        };
+        Self::new(name, label, input)
+    }
+
+    /// Create a slider over the range and value of `state`.
+    #[must_use]
+    pub fn slider(name: impl Into<String>, label: impl Into<String>, state: SliderState) -> Self {
+        Self::new(name, label, Input::Slider(state))
+    }
+
+    /// Set the initial value, which the field is reset to and compared
+    /// with to tell whether it is dirty.
+    ///
+    /// Values of the wrong type, and options a select does not have, are
+    /// ignored.
+    #[must_use]
+    pub fn with_value(mut self, value: impl Into<FieldValue>) -> Self {
+        self.set_value(value);
+        self.initial = self.value();
+        self
+    }
+
+    /// Set the placeholder of a text field.
+    #[must_use]
+    pub fn placeholder(mut self, placeholder: impl Into<String>) -> Self {
+        if let Input::Text {
+            placeholder: text, ..
+        } = &mut self.input
+        {
+            *text = placeholder.into();
+        }
+        self
+    }
+
+    /// Set the characters a text field accepts.
+    #[must_use]
+    pub fn filter(mut self, filter: InputFilter) -> Self {
+        if let Input::Text { state, .. } = &mut self.input {
+            state.filter = filter;
+        }
+        self
+    }
+
+    /// Set the maximum number of characters of a text field.
+    #[must_use]
+    pub fn max_length(mut self, max_length: usize) -> Self {
+        if let Input::Text { state, .. } = &mut self.input {
+            state.max_length = Some(max_length);
+        }
+        self
+    }
+
+    /// Require a text field to be filled in, or a checkbox to be checked.
+    #[must_use]
+    pub fn required(mut self) -> Self {
+        self.required = true;
+        self
+    }
+
+    /// Set a validator for the value.
+    #[must_use]
+    pub fn validator(mut self, validator: FieldValidator) -> Self {
+        self.validator = Some(validator);
+        self
+    }
+
+    /// Get the name.
+    #[must_use]
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// Get the label.
+    #[must_use]
+    pub fn label(&self) -> &str {
+        &self.label
+    }
+
+    /// Get the current value.
+    #[must_use]
+    pub fn value(&self) -> FieldValue {
+        match &self.input {
+            Input::Text { state, .. } => FieldValue::Text(state.value.clone()),
+            Input::Checkbox(checked) => FieldValue::Bool(*checked),
+            Input::Select { options, selected } => {
+                FieldValue::Choice(options.get(*selected).cloned().unwrap_or_default())
+            }
+            Input::Slider(state) => FieldValue::Number(state.value()),
+        }
+    }
+
+    /// Set the current value, returning `false` if it is of the wrong type
+    /// or an option a select does not have.
+    pub fn set_value(&mut self, value: impl Into<FieldValue>) -> bool {
+        match (&mut self.input, value.into()) {
+            (Input::Text { state, .. }, FieldValue::Text(text)) => {
+                state.value = text;
+                state.move_cursor_end();
+            }
+            (Input::Checkbox(checked), FieldValue::Bool(value)) => *checked = value,
+            (Input::Select { options, selected }, FieldValue::Choice(choice)) => {
+                match options.iter().position(|option| *option == choice) {
+                    Some(index) => *selected = index,
+                    None => return false,
+                }
+            }
+            (Input::Slider(state), FieldValue::Number(number)) => state.set_value(number),
+            _ => return false,
+        }
+        true
+    }
+
+    /// Get the validation error shown below the field, if any.
+    #[must_use]
+    pub fn error(&self) -> Option<&str> {
+        self.error.as_deref()
+    }
+
+    /// Check whether the value differs from the initial value.
+    #[must_use]
+    pub fn is_dirty(&self) -> bool {
+        self.value() != self.initial
+    }
+
+    /// Check the value, keeping the error to show.
+    fn validate(&mut self) -> bool {
+        let value = self.value();
+        let missing = match &value {
+            FieldValue::Text(text) => text.is_empty(),
+            FieldValue::Bool(checked) => !checked,
+            _ => false,
+        };
+        self.error = if self.required && missing {
+            Some(String::from("required"))
+        } else {
+            self.validator.and_then(|validate| validate(&value).err())
+        };
+        self.error.is_none()
+    }
+
+    /// Handle a key press on the field.
+    fn handle_key(&mut self, key: &KeyEvent) -> bool {
+        let consumed = match &mut self.input {
+            Input::Text { state, .. } => match key.code {
+                KeyCode::Char(c) => {
+                    state.insert_char(c);
+                    true
+                }
+                KeyCode::Backspace => {
+                    state.delete_char();
+                    true
+                }
+                KeyCode::Left => {
+                    state.move_cursor_left();
+                    true
+                }
+                KeyCode::Right => {
+                    state.move_cursor_right();
+                    true
+                }
+                KeyCode::Home => {
+                    state.move_cursor_start();
+                    true
+                }
+                KeyCode::End => {
+                    state.move_cursor_end();
+                    true
+                }
+                _ => false,
+            },
+            Input::Checkbox(checked) => {
+                let toggle = key.code == KeyCode::Char(' ');
+                *checked ^= toggle;
+                toggle
+            }
+            Input::Select { options, selected } => {
+                let len = options.len().max(1);
+                match key.code {
+                    KeyCode::Right | KeyCode::Char(' ') => *selected = (*selected + 1) % len,
+                    KeyCode::Left => *selected = (*selected + len - 1) % len,
+                    _ => return false,
+                }
+                true
+            }
+            Input::Slider(state) => state.handle_key(key),
+        };
+        // Clear errors as soon as they are fixed.
+        if consumed && self.error.is_some() {
+            self.validate();
+        }
+        consumed
+    }
+
+    /// Get the number of rows the field takes, with its error.
+    fn height(&self) -> usize {
+        1 + usize::from(self.error.is_some())
+    }
+}
+
+/// State for a [`Form`]: its fields, in focus order, and the focused field.
+///
+/// Fields are validated when focus leaves them and when the form is
+/// [submitted](Self::submit), which also runs the form validators for
+/// checks across fields.
+///
+/// # Example
+///
+/// ```
+/// use tuxtui_core::prelude::*;
+/// use tuxtui_widgets::form::{FieldError, FieldValue, Field, Form, FormState};
+///
+/// let mut state = FormState::new()
+///     .field(Field::text("name", "Name").required())
+///     .field(Field::password("password", "Password"))
+///     .field(Field::password("confirm", "Confirm"))
+///     .field(Field::select("plan", "Plan", ["Free", "Pro"]))
+///     .validator(|values| {
+///         if values["password"] == values["confirm"] {
+///             Ok(())
+///         } else {
+///             Err(FieldError::new("confirm", "passwords differ"))
+///         }
+///     });
+///
+/// assert!(state.submit().is_none());
+/// assert_eq!(state.get("name").and_then(|field| field.error()), Some("required"));
+///
+/// for c in "Ada".chars() {
+///     state.handle_key(&KeyCode::Char(c).into());
+/// }
+/// let values = state.submit().unwrap();
+/// assert_eq!(values["plan"], FieldValue::Choice("Free".into()));
+///
+/// let mut buf = Buffer::empty(Rect::new(0, 0, 30, 6));
+/// Form::new().render_stateful(buf.area, &mut buf, &mut state);
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct FormState {
+    fields: Vec<Field>,
+    validators: Vec<FormValidator>,
+    focused: usize,
+    offset: usize,
+}
+
+impl FormState {
+    /// Create a form without fields.
+    #[must_use]
+    pub const fn new() -> Self {
+        Self {
+            fields: Vec::new(),
+            validators: Vec::new(),
+            focused: 0,
+            offset: 0,
+        }
+    }
+
+    /// Add a field after the others.
+    #[must_use]
+    pub fn field(mut self, field: Field) -> Self {
+        self.fields.push(field);
+        self
+    }
+
+    /// Add a validator run across the fields on submit.
+    #[must_use]
+    pub fn validator(mut self, validator: FormValidator) -> Self {
+        self.validators.push(validator);
+        self
+    }
+
+    /// Get the fields, in focus order.
+    #[must_use]
+    pub fn fields(&self) -> &[Field] {
+        &self.fields
+    }
+
+    /// Get the field named `name`.
+    #[must_use]
+    pub fn get(&self, name: &str) -> Option<&Field> {
+        self.fields.iter().find(|field| field.name == name)
+    }
+
+    /// Get the field named `name` mutably.
+    pub fn get_mut(&mut self, name: &str) -> Option<&mut Field> {
+        self.fields.iter_mut().find(|field| field.name == name)
+    }
+
+    /// Get the index of the focused field.
+    #[must_use]
+    pub const fn focused(&self) -> usize {
+        self.focused
+    }
+
+    /// Focus the field at `index`, validating the field losing focus.
+    pub fn focus(&mut self, index: usize) {
+        if index >= self.fields.len() || index == self.focused {
+            return;
+        }
+        if let Some(field) = self.fields.get_mut(self.focused) {
+            field.validate();
+        }
+        self.focused = index;
+    }
+
+    /// Focus the next field, wrapping around.
+    pub fn focus_next(&mut self) {
+        if !self.fields.is_empty() {
+            self.focus((self.focused + 1) % self.fields.len());
+        }
+    }
+
+    /// Focus the previous field, wrapping around.
+    pub fn focus_previous(&mut self) {
+        let len = self.fields.len();
+        if len > 0 {
+            self.focus((self.focused + len - 1) % len);
+        }
+    }
+
+    /// Get the values of the fields, by name.
+    #[must_use]
+    pub fn values(&self) -> FormValues {
+        self.fields
+            .iter()
+            .map(|field| (field.name.clone(), field.value()))
+            .collect()
+    }
+
+    /// Validate every field, then the form validators, and focus the first
+    /// invalid field. Returns `true` if the form is valid.
+    pub fn validate(&mut self) -> bool {
+        let mut valid = true;
+        for field in &mut self.fields {
+            valid &= field.validate();
+        }
+        if valid {
+            let values = self.values();
+            for validate in &self.validators {
+                if let Err(error) = validate(&values) {
+                    if let Some(field) = self.fields.iter_mut().find(|f| f.name == error.field) {
+                        field.error = Some(error.message);
+                    }
+                    valid = false;
+                }
+            }
+        }
+        if let Some(index) = self.fields.iter().position(|f| f.error.is_some()) {
+            self.focused = index;
+        }
+        valid
+    }
+
+    /// Validate the form and get its values if it is valid.
+    pub fn submit(&mut self) -> Option<FormValues> {
+        self.validate().then(|| self.values())
+    }
+
+    /// Check whether any field differs from its initial value.
+    #[must_use]
+    pub fn is_dirty(&self) -> bool {
+        self.fields.iter().any(Field::is_dirty)
+    }
+
+    /// Make the current values the initial ones, such as after saving them.
+    pub fn mark_clean(&mut self) {
+        for field in &mut self.fields {
+            field.initial = field.value();
+        }
+    }
+
+    /// Restore the initial values and clear the errors.
+    pub fn reset(&mut self) {
+        for field in &mut self.fields {
+            field.set_value(field.initial.clone());
+            field.error = None;
+        }
+    }
+
+    /// Handle a key press.
+    ///
+    /// Tab and Enter move to the next field and Shift+Tab to the previous
+    /// one. Otherwise the focused field takes the key: text fields are
+    /// edited, Space toggles a checkbox, Left and Right change the option
+    /// of a select and sliders take their usual keys. Returns `true` if the
+    /// key was consumed.
+    pub fn handle_key(&mut self, key: &KeyEvent) -> bool {
+        if key.is_release() {
+            return false;
+        }
+        if let Some(field) = self.fields.get_mut(self.focused) {
+            if field.handle_key(key) {
+                return true;
+            }
+        }
+        match key.code {
+            KeyCode::Tab | KeyCode::Enter => self.focus_next(),
+            KeyCode::BackTab => self.focus_previous(),
+            _ => return false,
+        }
+        true
+    }
+}
+
+impl Describe for FormState {
+    /// Describe the focused field, such as "Email, text, ada@example.com,
+    /// not an email address".
+    fn describe(&self) -> Option<String> {
+        let field = self.fields.get(self.focused)?;
+        let value = match &field.input {
+            Input::Text { mask: Some(_), .. } => String::from("password"),
+            Input::Text { state, .. } => format!("text, {}", state.value),
+            Input::Checkbox(checked) => String::from(if *checked {
+                "checkbox, checked"
+            } else {
+                "checkbox, not checked"
+            }),
+            Input::Select { options, selected } => format!(
+                "select, {}, {} of {}",
+                options.get(*selected).map_or("", String::as_str),
+                selected + 1,
+                options.len()
+            ),
+            Input::Slider(state) => format!("slider, {}", state.value()),
+        };
+        let mut description = format!("{}, {value}", field.label);
+        if let Some(error) = &field.error {
+            description.push_str(", ");
+            description.push_str(error);
+        }
+        Some(description)
+    }
+}
+
+/// A form showing the fields of a [`FormState`] as rows of labels and
+/// inputs, with any validation error below its field.
+///
+/// The labels are aligned in a column as wide as the widest. Forms taller
+/// than the area scroll to keep the focused field in view.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Form {
+    style: Style,
+    label_style: Style,
+    focused_style: Style,
+    input_style: Style,
+    error_style: Style,
+}
+
+impl Default for Form {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Form {
+    /// Create a form.
+    #[must_use]
+    pub const fn new() -> Self {
+        Self {
+            style: Style::new(),
+            label_style: Style::new(),
+            focused_style: Style::new().fg(Color::Yellow),
+            input_style: Style::new(),
+            error_style: Style::new().fg(Color::Red),
+        }
+    }
+
+    /// Set the overall style.
+    #[must_use]
+    pub const fn style(mut self, style: Style) -> Self {
+        self.style = style;
+        self
+    }
+
+    /// Set the style of labels.
+    #[must_use]
+    pub const fn label_style(mut self, style: Style) -> Self {
+        self.label_style = style;
+        self
+    }
+
+    /// Set the style of the focused field's label.
+    #[must_use]
+    pub const fn focused_style(mut self, style: Style) -> Self {
+        self.focused_style = style;
+        self
+    }
+
+    /// Set the style of inputs.
+    #[must_use]
+    pub const fn input_style(mut self, style: Style) -> Self {
+        self.input_style = style;
+        self
+    }
+
+    /// Set the style of error messages.
+    #[must_use]
+    pub const fn error_style(mut self, style: Style) -> Self {
+        self.error_style = style;
+        self
+    }
+
+    /// Render the form with state.
+    pub fn render_stateful(self, area: Rect, buf: &mut Buffer, state: &mut FormState) {
+        if area.area() == 0 {
+            return;
+        }
+        buf.set_style(area, self.style);
+
+        // Scroll so the focused field and its error fit.
+        let height = usize::from(area.height);
+        state.offset = state.offset.min(state.focused);
+        while state.offset < state.focused
+            && state.fields[state.offset..=state.focused]
+                .iter()
+                .map(Field::height)
+                .sum::<usize>()
+                > height
+        {
+            state.offset += 1;
+        }
+
+        let label_width = state
+            .fields
+            .iter()
+            .map(|field| field.label.width())
+            .max()
+            .unwrap_or(0);
+        let label_width = u16::try_from(label_width + 2).unwrap_or(u16::MAX);
+        let input_x = area.x.saturating_add(label_width).min(area.right());
+        let input_width = area.right() - input_x;
+
+        let mut y = area.y;
+        for (index, field) in state.fields.iter_mut().enumerate().skip(state.offset) {
+            if y >= area.bottom() {
+                break;
+            }
+            let focused = index == state.focused;
+            let label_style = if focused {
+                self.style.patch(self.label_style).patch(self.focused_style)
+            } else {
+                self.style.patch(self.label_style)
+            };
+            let label = Rect::new(area.x, y, area.width.min(label_width), 1);
+            let text = Line::styled(field.label.as_str(), label_style);
+            buf.set_line(label.x, y, &text, label.width);
+            let input_area = Rect::new(input_x, y, input_width, 1);
+            self.render_input(input_area, buf, &mut field.input, focused);
+            y += 1;
+
+            if let Some(error) = &field.error {
+                if y < area.bottom() {
+                    let style = self.style.patch(self.error_style);
+                    buf.set_line(
+                        input_x,
+                        y,
+                        &Line::styled(error.as_str(), style),
+                        input_width,
+                    );
+                    y += 1;
+                }
+            }
+        }
+    }
+
+    fn render_input(&self, area: Rect, buf: &mut Buffer, input: &mut Input, focused: bool) {
+        if area.area() == 0 {
+            return;
+        }
+        let style = self.style.patch(self.input_style);
+        match input {
+            Input::Text {
+                state,
+                placeholder,
+                mask,
+            } => TextInput::new()
+                .placeholder(placeholder)
+                .mask_char(*mask)
+                .style(style)
+                .show_cursor(focused)
+                .render_stateful(area, buf, state),
+            Input::Checkbox(checked) => {
+                let symbol = if *checked { "[x]" } else { "[ ]" };
+                buf.set_line(area.x, area.y, &Line::styled(symbol, style), area.width);
+            }
+            Input::Select { options, selected } => {
+                let option = options.get(*selected).map_or("", String::as_str);
+                let text = ["‹ ", option, " ›"].concat();
+                buf.set_line(area.x, area.y, &Line::styled(text, style), area.width);
+            }
+            Input::Slider(state) => Slider::new().style(style).render_stateful(area, buf, state),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloc::string::ToString;
+    use tuxtui_core::event::KeyModifiers;
+
+    fn signup() -> FormState {
+        FormState::new()
+            .field(Field::text("name", "Name").required())
+            .field(Field::text("age", "Age").validator(|value| {
+                match value.as_str().unwrap_or("").parse::<u8>() {
+                    Ok(_) => Ok(()),
+                    Err(_) => Err("not an age".to_string()),
+                }
+            }))
+            .field(Field::checkbox("terms", "Accept terms").required())
+            .field(
+                Field::select("plan", "Plan", ["Free", "Pro"])
+                    .with_value(FieldValue::Choice("Pro".into())),
+            )
+            .field(Field::slider("seats", "Seats", SliderState::new(1, 10)))
+    }
+
+    fn typed(state: &mut FormState, text: &str) {
+        for c in text.chars() {
+            state.handle_key(&KeyCode::Char(c).into());
+        }
+    }
+
+    fn rows(buf: &Buffer) -> Vec<String> {
+        buf.to_string()
+            .lines()
+            .map(|line| String::from(line.trim_end()))
+            .collect()
+    }
+
+    #[test]
+    fn test_focus_and_validation() {
+        let mut state = signup();
+        assert!(!state.is_dirty());
+        typed(&mut state, "Ada");
+        assert!(state.handle_key(&KeyCode::Tab.into()));
+        assert_eq!(state.focused(), 1);
+        typed(&mut state, "x");
+
+        // Leaving a field validates it, and fixing it clears the error.
+        state.handle_key(&KeyCode::Enter.into());
+        assert_eq!(state.get("age").unwrap().error(), Some("not an age"));
+        state.handle_key(&KeyEvent::new(KeyCode::BackTab, KeyModifiers::SHIFT));
+        state.handle_key(&KeyCode::Backspace.into());
+        typed(&mut state, "36");
+        assert_eq!(state.get("age").unwrap().error(), None);
+
+        assert!(state.submit().is_none());
+        assert_eq!(state.focused(), 2);
+        assert_eq!(state.get("terms").unwrap().error(), Some("required"));
+        assert_eq!(
+            state.describe().as_deref(),
+            Some("Accept terms, checkbox, not checked, required")
+        );
+        state.handle_key(&KeyCode::Char(' ').into());
+        state.focus_next();
+        state.handle_key(&KeyCode::Left.into());
+        state.focus_next();
+        state.handle_key(&KeyCode::Right.into());
+
+        let values = state.submit().unwrap();
+        assert_eq!(values["name"].as_str(), Some("Ada"));
+        assert_eq!(values["age"], FieldValue::Text("36".into()));
+        assert_eq!(values["terms"].as_bool(), Some(true));
+        assert_eq!(values["plan"].as_str(), Some("Free"));
+        assert_eq!(values["seats"].as_number(), Some(2));
+        state.focus_previous();
+        assert_eq!(
+            state.describe().as_deref(),
+            Some("Plan, select, Free, 1 of 2")
+        );
+    }
+
+    #[test]
+    fn test_cross_field_validation_and_dirty() {
+        let mut state = FormState::new()
+            .field(Field::password("password", "Password").with_value("secret"))
+            .field(Field::password("confirm", "Confirm"))
+            .validator(|values| {
+                if values["password"] == values["confirm"] {
+                    Ok(())
+                } else {
+                    Err(FieldError::new("confirm", "passwords differ"))
+                }
+            });
+        assert!(!state.validate());
+        assert_eq!(state.focused(), 1);
+        assert_eq!(
+            state.get("confirm").unwrap().error(),
+            Some("passwords differ")
+        );
+
+        assert!(state.get_mut("confirm").unwrap().set_value("secret"));
+        assert!(!state.get_mut("confirm").unwrap().set_value(true));
+        assert!(state.is_dirty());
+        assert!(state.validate());
+        state.mark_clean();
+        assert!(!state.is_dirty());
+
+        typed(&mut state, "!");
+        assert!(state.is_dirty());
+        state.reset();
+        assert_eq!(state.values()["confirm"].as_str(), Some("secret"));
+    }
+
+    #[test]
+    fn test_render_layout_and_scroll() {
+        let mut state = signup();
+        state.validate();
+        let mut buf = Buffer::empty(Rect::new(0, 0, 24, 4));
+        Form::new().render_stateful(buf.area, &mut buf, &mut state);
+        assert_eq!(
+            rows(&buf),
+            [
+                "Name",
+                "              required",
+                "Age",
+                "              not an age",
+            ]
+        );
+        assert_eq!(buf.get(0, 0).unwrap().style.fg, Some(Color::Yellow));
+        assert_eq!(buf.get(14, 1).unwrap().style.fg, Some(Color::Red));
+
+        state.focus(2);
+        let mut buf = Buffer::empty(Rect::new(0, 0, 24, 4));
+        Form::new().render_stateful(buf.area, &mut buf, &mut state);
+        assert_eq!(
+            rows(&buf),
+            [
+                "Age",
+                "              not an age",
+                "Accept terms  [ ]",
+                "              required",
+            ]
+        );
+    }
+}
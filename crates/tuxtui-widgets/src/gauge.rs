@@ -5,9 +5,14 @@ use tuxtui_core::geometry::Rect;
 use tuxtui_core::style::Style;
 use tuxtui_core::symbols;
 use tuxtui_core::terminal::Widget;
+use tuxtui_core::text::Line;
 
 /// A gauge (progress bar) widget.
 ///
+/// The fill is drawn with eighth blocks at its leading edge, so progress
+/// moves smoothly rather than a whole cell at a time. Disable this with
+/// [`Gauge::use_unicode`] for terminals limited to ASCII.
+///
 /// # Example
 ///
 /// ```
@@ -15,16 +20,23 @@ use tuxtui_core::terminal::Widget;
 /// use tuxtui_widgets::gauge::Gauge;
 ///
 /// let gauge = Gauge::default()
-///     .percent(75)
-///     .label("75%")
-///     .style(Style::default().fg(Color::Yellow));
+///     .ratio(0.75)
+///     .label(Span::styled("75%", Style::default().add_modifier(Modifier::BOLD)))
+///     .gauge_style(Style::default().fg(Color::Yellow));
 /// ```
-#[derive(Debug, Clone, PartialEq, Eq, Default)]
+#[derive(Debug, Clone, PartialEq)]
 pub struct Gauge<'a> {
-    percent: u16,
-    label: Option<&'a str>,
+    ratio: f64,
+    label: Option<Line<'a>>,
     style: Style,
     gauge_style: Style,
+    use_unicode: bool,
+}
+
+impl Default for Gauge<'_> {
+    fn default() -> Self {
+        Self::new()
+    }
 }
 
 impl<'a> Gauge<'a> {
@@ -32,24 +44,39 @@ impl<'a> Gauge<'a> {
     #[must_use]
     pub const fn new() -> Self {
         Self {
-            percent: 0,
+            ratio: 0.0,
             label: None,
             style: Style::new(),
             gauge_style: Style::new(),
+            use_unicode: true,
         }
     }
 
     /// Set the percentage (0-100).
     #[must_use]
     pub const fn percent(mut self, percent: u16) -> Self {
-        self.percent = if percent > 100 { 100 } else { percent };
+        let percent = if percent > 100 { 100 } else { percent };
+        self.ratio = percent as f64 / 100.0;
         self
     }
 
-    /// Set the label.
+    /// Set the filled ratio (0.0-1.0).
     #[must_use]
-    pub const fn label(mut self, label: &'a str) -> Self {
-        self.label = Some(label);
+    pub const fn ratio(mut self, ratio: f64) -> Self {
+        self.ratio = if ratio > 1.0 {
+            1.0
+        } else if ratio >= 0.0 {
+            ratio
+        } else {
+            0.0
+        };
+        self
+    }
+
+    /// Set the label, drawn centered over the gauge.
+    #[must_use]
+    pub fn label(mut self, label: impl Into<Line<'a>>) -> Self {
+        self.label = Some(label.into());
         self
     }
 
@@ -66,6 +93,14 @@ impl<'a> Gauge<'a> {
         self.gauge_style = style;
         self
     }
+
+    /// Set whether to draw with block characters (the default), or with
+    /// `#` and whole cells only.
+    #[must_use]
+    pub const fn use_unicode(mut self, use_unicode: bool) -> Self {
+        self.use_unicode = use_unicode;
+        self
+    }
 }
 
 impl Widget for Gauge<'_> {
@@ -74,30 +109,39 @@ impl Widget for Gauge<'_> {
             return;
         }
 
-        // Calculate filled width
-        let filled_width = (area.width as u32 * self.percent as u32 / 100) as u16;
+        // Calculate filled width, in eighths of a cell
+        let eighths = (self.ratio * f64::from(area.width) * 8.0) as u32;
+        let filled_width = (eighths / 8) as u16;
+        let (fill, partial) = if self.use_unicode && !buf.settings.ascii_only {
+            (symbols::BAR_FULL, symbols::BLOCKS[(eighths % 8) as usize])
+        } else {
+            ("#", " ")
+        };
 
-        // Render filled portion
         for y in area.top()..area.bottom() {
+            // Render filled portion
             for x in area.left()..area.left() + filled_width {
-                buf.set(x, y, symbols::BAR_FULL, self.gauge_style);
+                buf.set(x, y, fill, self.gauge_style);
             }
-        }
 
-        // Render unfilled portion
-        for y in area.top()..area.bottom() {
+            // Render unfilled portion, starting with the leading edge
             for x in (area.left() + filled_width)..area.right() {
-                buf.set(x, y, " ", self.style);
+                if x == area.left() + filled_width {
+                    buf.set(x, y, partial, self.style.patch(self.gauge_style));
+                } else {
+                    buf.set(x, y, " ", self.style);
+                }
             }
         }
 
         // Render label (centered)
-        if let Some(label) = self.label {
-            let label_width = label.len() as u16;
+        if let Some(mut label) = self.label {
+            let label_width = label.width() as u16;
             if label_width <= area.width {
+                label.style = self.style.patch(label.style);
                 let x = area.left() + (area.width - label_width) / 2;
                 let y = area.top() + area.height / 2;
-                buf.set_string(x, y, label, self.style);
+                buf.set_line(x, y, &label, label_width);
             }
         }
     }
@@ -106,14 +150,50 @@ impl Widget for Gauge<'_> {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use tuxtui_core::style::Color;
+    use tuxtui_core::style::{Color, Modifier};
+    use tuxtui_core::text::Span;
+
+    fn render(gauge: Gauge<'_>, width: u16) -> Buffer {
+        let area = Rect::new(0, 0, width, 1);
+        let mut buf = Buffer::empty(area);
+        gauge.render(area, &mut buf);
+        buf
+    }
 
     #[test]
     fn test_gauge_percent() {
         let gauge = Gauge::default().percent(50);
-        assert_eq!(gauge.percent, 50);
+        assert!((gauge.ratio - 0.5).abs() < f64::EPSILON);
 
         let gauge = Gauge::default().percent(150);
-        assert_eq!(gauge.percent, 100);
+        assert!((gauge.ratio - 1.0).abs() < f64::EPSILON);
+
+        assert_eq!(Gauge::default().ratio(-0.5).ratio, 0.0);
+        assert_eq!(Gauge::default().ratio(2.0), Gauge::default().percent(100));
+    }
+
+    #[test]
+    fn test_gauge_partial_fill() {
+        let buf = render(Gauge::default().ratio(0.3), 5);
+        assert_eq!(buf.to_string().trim_end(), "█▌");
+
+        let buf = render(Gauge::default().ratio(0.3).use_unicode(false), 5);
+        assert_eq!(buf.to_string().trim_end(), "#");
+
+        let buf = render(Gauge::default().ratio(1.0), 5);
+        assert_eq!(buf.to_string().trim_end(), "█████");
+    }
+
+    #[test]
+    fn test_gauge_styled_label() {
+        let bold = Style::default().add_modifier(Modifier::BOLD);
+        let gauge = Gauge::default()
+            .style(Style::default().fg(Color::Blue))
+            .label(Span::styled("ab", bold));
+        let buf = render(gauge, 6);
+        assert_eq!(buf.to_string().trim_end(), "  ab");
+        let cell = buf.get(2, 0).unwrap();
+        assert_eq!(cell.style.fg, Some(Color::Blue));
+        assert!(cell.style.add_modifier.contains(Modifier::BOLD));
     }
 }
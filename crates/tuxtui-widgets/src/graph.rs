@@ -0,0 +1,602 @@
+//! Graph widget laying out nodes and edges in layers, for dependency and
+//! pipeline diagrams.
+
+use alloc::borrow::Cow;
+use alloc::string::String;
+use alloc::vec;
+use alloc::vec::Vec;
+use tuxtui_core::buffer::Buffer;
+use tuxtui_core::event::{KeyCode, KeyEvent};
+use tuxtui_core::geometry::Rect;
+use tuxtui_core::style::{Modifier, Style};
+use tuxtui_core::terminal::Widget;
+use tuxtui_core::text::Line;
+use unicode_width::UnicodeWidthStr;
+
+/// The rows of a node's box.
+const BOX_HEIGHT: u16 = 3;
+
+/// The rows between layers, where edges are routed.
+const LAYER_GAP: u16 = 2;
+
+const UP: u8 = 1;
+const DOWN: u8 = 2;
+const LEFT: u8 = 4;
+const RIGHT: u8 = 8;
+
+/// A node of a [`Graph`], drawn as a box around its label.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct GraphNode<'a> {
+    /// The label
+    pub label: Cow<'a, str>,
+    /// The style of the box, patched onto the graph's node style
+    pub style: Style,
+}
+
+impl<'a> GraphNode<'a> {
+    /// Create a node.
+    #[must_use]
+    pub fn new(label: impl Into<Cow<'a, str>>) -> Self {
+        Self {
+            label: label.into(),
+            style: Style::new(),
+        }
+    }
+
+    /// Set the style of the box.
+    #[must_use]
+    pub const fn style(mut self, style: Style) -> Self {
+        self.style = style;
+        self
+    }
+}
+
+impl<'a> From<&'a str> for GraphNode<'a> {
+    fn from(label: &'a str) -> Self {
+        Self::new(label)
+    }
+}
+
+impl From<String> for GraphNode<'_> {
+    fn from(label: String) -> Self {
+        Self::new(label)
+    }
+}
+
+/// State for a [`Graph`], holding the selected node and scrolling.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct GraphState {
+    selected: Option<usize>,
+    offset: (u16, u16),
+}
+
+impl GraphState {
+    /// Create a state without a selection.
+    #[must_use]
+    pub const fn new() -> Self {
+        Self {
+            selected: None,
+            offset: (0, 0),
+        }
+    }
+
+    /// Get the index of the selected node.
+    #[must_use]
+    pub const fn selected(&self) -> Option<usize> {
+        self.selected
+    }
+
+    /// Select a node, or clear the selection.
+    pub const fn select(&mut self, index: Option<usize>) {
+        self.selected = index;
+    }
+
+    /// Get the column and row of the graph shown at the top left.
+    #[must_use]
+    pub const fn offset(&self) -> (u16, u16) {
+        self.offset
+    }
+
+    /// Handle a key press for `graph`.
+    ///
+    /// Up and Down move to the nearest parent or child, Left and Right to
+    /// the neighbors in the same layer, and Esc clears the selection. The
+    /// first arrow key selects the first node. Returns `true` if the key
+    /// was consumed.
+    pub fn handle_key(&mut self, key: &KeyEvent, graph: &Graph<'_>) -> bool {
+        if graph.nodes.is_empty() || key.is_release() {
+            return false;
+        }
+        let layout = graph.layout();
+        let selected = match (key.code, self.selected) {
+            (KeyCode::Esc, Some(_)) => {
+                self.selected = None;
+                return true;
+            }
+            (KeyCode::Up | KeyCode::Down | KeyCode::Left | KeyCode::Right, None) => {
+                self.selected = Some(layout.layers[0][0]);
+                return true;
+            }
+            (KeyCode::Up | KeyCode::Down | KeyCode::Left | KeyCode::Right, Some(selected)) => {
+                selected.min(graph.nodes.len() - 1)
+            }
+            _ => return false,
+        };
+        let layer = &layout.layers[layout.layer[selected]];
+        let position = layer.iter().position(|&node| node == selected).unwrap_or(0);
+        let center = |node: usize| {
+            let rect = layout.boxes[node];
+            i32::from(rect.x) + i32::from(rect.width / 2)
+        };
+        let nearest = |nodes: &mut dyn Iterator<Item = usize>| {
+            nodes.min_by_key(|&node| (center(node) - center(selected)).abs())
+        };
+        let next = match key.code {
+            KeyCode::Up => nearest(
+                &mut graph
+                    .edges
+                    .iter()
+                    .filter(|&&(_, to)| to == selected)
+                    .map(|&(from, _)| from),
+            ),
+            KeyCode::Down => nearest(
+                &mut graph
+                    .edges
+                    .iter()
+                    .filter(|&&(from, _)| from == selected)
+                    .map(|&(_, to)| to),
+            ),
+            KeyCode::Left => position.checked_sub(1).map(|i| layer[i]),
+            _ => layer.get(position + 1).copied(),
+        };
+        self.selected = Some(next.unwrap_or(selected));
+        true
+    }
+}
+
+/// Where the nodes of a graph are placed.
+struct GraphLayout {
+    /// The layer of each node
+    layer: Vec<usize>,
+    /// The nodes of each layer, left to right
+    layers: Vec<Vec<usize>>,
+    /// The box of each node
+    boxes: Vec<Rect>,
+    width: u16,
+    height: u16,
+}
+
+/// Nodes joined by edges, laid out in layers like a dependency diagram or
+/// an org chart.
+///
+/// Every node is placed one layer below the lowest of its parents, so edges
+/// point down, and nodes are ordered within their layer to keep them under
+/// their parents. Edges closing a cycle are not drawn. A [`GraphState`]
+/// holds the selected node, scrolling to keep it in view.
+///
+/// # Example
+///
+/// ```
+/// use tuxtui_core::prelude::*;
+/// use tuxtui_widgets::graph::{Graph, GraphState};
+///
+/// let graph = Graph::new(["fetch", "build", "test", "deploy"])
+///     .edges([(0, 1), (1, 2), (1, 3), (2, 3)]);
+///
+/// let mut state = GraphState::new();
+/// state.handle_key(&KeyCode::Down.into(), &graph);
+/// assert_eq!(state.selected(), Some(0));
+///
+/// let mut buf = Buffer::empty(Rect::new(0, 0, 40, 20));
+/// graph.render_stateful(buf.area, &mut buf, &mut state);
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Graph<'a> {
+    nodes: Vec<GraphNode<'a>>,
+    edges: Vec<(usize, usize)>,
+    spacing: u16,
+    style: Style,
+    node_style: Style,
+    edge_style: Style,
+    highlight_style: Style,
+}
+
+impl<'a> Graph<'a> {
+    /// Create a graph of `nodes`, without edges.
+    #[must_use]
+    pub fn new<T>(nodes: T) -> Self
+    where
+        T: IntoIterator,
+        T::Item: Into<GraphNode<'a>>,
+    {
+        Self {
+            nodes: nodes.into_iter().map(Into::into).collect(),
+            edges: Vec::new(),
+            spacing: 2,
+            style: Style::new(),
+            node_style: Style::new(),
+            edge_style: Style::new(),
+            highlight_style: Style::new().add_modifier(Modifier::REVERSED),
+        }
+    }
+
+    /// Add an edge from the node at index `from` to the node at `to`.
+    ///
+    /// Edges to nodes that do not exist, or from a node to itself, are
+    /// ignored.
+    #[must_use]
+    pub fn edge(mut self, from: usize, to: usize) -> Self {
+        let len = self.nodes.len();
+        if from != to && from < len && to < len && !self.edges.contains(&(from, to)) {
+            self.edges.push((from, to));
+        }
+        self
+    }
+
+    /// Add edges, as pairs of node indices. See [`Graph::edge`].
+    #[must_use]
+    pub fn edges(self, edges: impl IntoIterator<Item = (usize, usize)>) -> Self {
+        edges
+            .into_iter()
+            .fold(self, |graph, (from, to)| graph.edge(from, to))
+    }
+
+    /// Set the columns between nodes in a layer.
+    #[must_use]
+    pub const fn spacing(mut self, spacing: u16) -> Self {
+        self.spacing = spacing;
+        self
+    }
+
+    /// Set the overall style.
+    #[must_use]
+    pub const fn style(mut self, style: Style) -> Self {
+        self.style = style;
+        self
+    }
+
+    /// Set the style of node boxes.
+    #[must_use]
+    pub const fn node_style(mut self, style: Style) -> Self {
+        self.node_style = style;
+        self
+    }
+
+    /// Set the style of edges.
+    #[must_use]
+    pub const fn edge_style(mut self, style: Style) -> Self {
+        self.edge_style = style;
+        self
+    }
+
+    /// Set the style of the selected node.
+    #[must_use]
+    pub const fn highlight_style(mut self, style: Style) -> Self {
+        self.highlight_style = style;
+        self
+    }
+
+    /// Assign each node the layer below its lowest parent.
+    ///
+    /// Nodes are taken once all their parents are; when a cycle leaves none
+    /// ready, the first remaining node is taken regardless.
+    fn assign_layers(&self) -> Vec<usize> {
+        let len = self.nodes.len();
+        let mut parents = vec![0_usize; len];
+        for &(_, to) in &self.edges {
+            parents[to] += 1;
+        }
+        let mut layer = vec![0; len];
+        let mut done = vec![false; len];
+        let mut ready: Vec<usize> = (0..len).filter(|&node| parents[node] == 0).collect();
+        loop {
+            while let Some(node) = ready.pop() {
+                done[node] = true;
+                for &(from, to) in &self.edges {
+                    if from == node && !done[to] {
+                        layer[to] = layer[to].max(layer[node] + 1);
+                        parents[to] -= 1;
+                        if parents[to] == 0 {
+                            ready.push(to);
+                        }
+                    }
+                }
+            }
+            match done.iter().position(|&done| !done) {
+                Some(node) => ready.push(node),
+                None => break,
+            }
+        }
+        layer
+    }
+
+    fn layout(&self) -> GraphLayout {
+        let layer = self.assign_layers();
+        let mut layers = vec![Vec::new(); layer.iter().max().map_or(0, |&max| max + 1)];
+        for (node, &index) in layer.iter().enumerate() {
+            layers[index].push(node);
+        }
+
+        // Order each layer by the average position of the parents, which
+        // keeps nodes near their parents and edges from crossing.
+        let mut position = vec![0.0; self.nodes.len()];
+        for nodes in &mut layers {
+            let key = |node: usize| {
+                let (sum, count) = self
+                    .edges
+                    .iter()
+                    .filter(|&&(from, to)| to == node && layer[from] < layer[node])
+                    .fold((0.0, 0), |(sum, count), &(from, _)| {
+                        (sum + position[from], count + 1)
+                    });
+                if count == 0 {
+                    f64::MAX
+                } else {
+                    sum / f64::from(count)
+                }
+            };
+            let mut keyed: Vec<(f64, usize)> =
+                nodes.iter().map(|&node| (key(node), node)).collect();
+            keyed.sort_by(|a, b| a.0.total_cmp(&b.0));
+            for (i, (_, node)) in keyed.iter().enumerate() {
+                nodes[i] = *node;
+                position[*node] = i as f64;
+            }
+        }
+
+        let box_width = |node: usize| self.nodes[node].label.width() as u16 + 4;
+        let row_width = |nodes: &[usize]| {
+            nodes.iter().map(|&node| box_width(node)).sum::<u16>()
+                + self.spacing * nodes.len().saturating_sub(1) as u16
+        };
+        let width = layers
+            .iter()
+            .map(|nodes| row_width(nodes))
+            .max()
+            .unwrap_or(0);
+        let mut boxes = vec![Rect::default(); self.nodes.len()];
+        for (index, nodes) in layers.iter().enumerate() {
+            let mut x = (width - row_width(nodes)) / 2;
+            let y = index as u16 * (BOX_HEIGHT + LAYER_GAP);
+            for &node in nodes {
+                boxes[node] = Rect::new(x, y, box_width(node), BOX_HEIGHT);
+                x += box_width(node) + self.spacing;
+            }
+        }
+        let height = (layers.len() as u16 * (BOX_HEIGHT + LAYER_GAP)).saturating_sub(LAYER_GAP);
+        GraphLayout {
+            layer,
+            layers,
+            boxes,
+            width,
+            height,
+        }
+    }
+
+    /// Render the graph with state.
+    pub fn render_stateful(self, area: Rect, buf: &mut Buffer, state: &mut GraphState) {
+        if area.area() == 0 {
+            return;
+        }
+        buf.set_style(area, self.style);
+        if self.nodes.is_empty() {
+            return;
+        }
+        let layout = self.layout();
+        state.selected = state.selected.map(|node| node.min(self.nodes.len() - 1));
+
+        // Scroll to keep the selected node in view.
+        let (mut x, mut y) = state.offset;
+        if let Some(node) = state.selected {
+            let rect = layout.boxes[node];
+            x = x.max(rect.right().saturating_sub(area.width)).min(rect.x);
+            y = y.max(rect.bottom().saturating_sub(area.height)).min(rect.y);
+        }
+        state.offset = (
+            x.min(layout.width.saturating_sub(area.width)),
+            y.min(layout.height.saturating_sub(area.height)),
+        );
+
+        // Lines are drawn as the directions they leave each cell in, so
+        // that crossings and joins with boxes get the right symbols.
+        let (width, height) = (usize::from(layout.width), usize::from(layout.height));
+        let mut lines = vec![0_u8; width * height];
+        let mut covered = vec![false; width * height];
+        for rect in &layout.boxes {
+            for y in rect.top()..rect.bottom() {
+                for x in rect.left()..rect.right() {
+                    covered[usize::from(y) * width + usize::from(x)] = true;
+                }
+            }
+        }
+        let mut line = |from: (u16, u16), to: (u16, u16)| {
+            let ((x0, y0), (x1, y1)) = (from.min(to), from.max(to));
+            for y in y0..=y1 {
+                for x in x0..=x1 {
+                    let index = usize::from(y) * width + usize::from(x);
+                    if covered[index] {
+                        continue;
+                    }
+                    if x0 == x1 {
+                        lines[index] |= if y > y0 { UP } else { 0 } | if y < y1 { DOWN } else { 0 };
+                    } else {
+                        lines[index] |=
+                            if x > x0 { LEFT } else { 0 } | if x < x1 { RIGHT } else { 0 };
+                    }
+                }
+            }
+        };
+        let mut joins = Vec::new();
+        for &(from, to) in &self.edges {
+            if layout.layer[from] >= layout.layer[to] {
+                continue;
+            }
+            let (parent, child) = (layout.boxes[from], layout.boxes[to]);
+            let start = (parent.x + parent.width / 2, parent.bottom() - 1);
+            let end = (child.x + child.width / 2, child.y);
+            let turn = child.y - LAYER_GAP;
+            line(start, (start.0, turn));
+            line((start.0, turn), (end.0, turn));
+            line((end.0, turn), end);
+            joins.push((start, DOWN));
+            joins.push((end, UP));
+        }
+
+        let visible = |x: u16, y: u16| {
+            let (x, y) = (
+                x.checked_sub(state.offset.0)?,
+                y.checked_sub(state.offset.1)?,
+            );
+            (x < area.width && y < area.height).then(|| (area.x + x, area.y + y))
+        };
+        let edge_style = self.style.patch(self.edge_style);
+        for (index, &bits) in lines.iter().enumerate() {
+            let (x, y) = ((index % width) as u16, (index / width) as u16);
+            if let (Some((x, y)), true) = (visible(x, y), bits != 0) {
+                buf.set(x, y, line_symbol(bits), edge_style);
+            }
+        }
+
+        let node_style = self.style.patch(self.node_style);
+        for (node, rect) in layout.boxes.iter().enumerate() {
+            let mut style = node_style.patch(self.nodes[node].style);
+            if state.selected == Some(node) {
+                style = style.patch(self.highlight_style);
+            }
+            for y in rect.top()..rect.bottom() {
+                for x in rect.left()..rect.right() {
+                    let Some((cell_x, cell_y)) = visible(x, y) else {
+                        continue;
+                    };
+                    let horizontal = if x > rect.left() { LEFT } else { 0 }
+                        | if x < rect.right() - 1 { RIGHT } else { 0 };
+                    let vertical = if y > rect.top() { UP } else { 0 }
+                        | if y < rect.bottom() - 1 { DOWN } else { 0 };
+                    let mut bits = match (
+                        y == rect.top() || y == rect.bottom() - 1,
+                        x == rect.left() || x == rect.right() - 1,
+                    ) {
+                        (true, true) => horizontal | vertical,
+                        (true, false) => horizontal,
+                        (false, true) => vertical,
+                        (false, false) => 0,
+                    };
+                    bits |= joins
+                        .iter()
+                        .filter(|&&(at, _)| at == (x, y))
+                        .fold(0, |bits, &(_, join)| bits | join);
+                    buf.set(cell_x, cell_y, line_symbol(bits), style);
+                }
+            }
+            let label = &self.nodes[node].label;
+            let (label_x, label_y) = (rect.x + 2, rect.y + 1);
+            if let Some((x, y)) = visible(label_x, label_y) {
+                let max_width = (area.right() - x).min(rect.width - 4);
+                buf.set_line(x, y, &Line::styled(label.as_ref(), style), max_width);
+            }
+        }
+    }
+}
+
+impl Widget for Graph<'_> {
+    fn render(self, area: Rect, buf: &mut Buffer) {
+        self.render_stateful(area, buf, &mut GraphState::new());
+    }
+}
+
+/// Get the box drawing symbol joining the given directions.
+const fn line_symbol(bits: u8) -> &'static str {
+    match bits {
+        0 => " ",
+        UP => "╵",
+        DOWN => "╷",
+        LEFT => "╴",
+        RIGHT => "╶",
+        0b0011 => "│",
+        0b1100 => "─",
+        0b1010 => "┌",
+        0b0110 => "┐",
+        0b1001 => "└",
+        0b0101 => "┘",
+        0b1011 => "├",
+        0b0111 => "┤",
+        0b1110 => "┬",
+        0b1101 => "┴",
+        _ => "┼",
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn rows(buf: &Buffer) -> Vec<String> {
+        buf.to_string().lines().map(String::from).collect()
+    }
+
+    #[test]
+    fn test_render_tree() {
+        let graph = Graph::new(["a", "b", "c"]).edges([(0, 1), (0, 2), (2, 2), (0, 9)]);
+        let mut buf = Buffer::empty(Rect::new(0, 0, 12, 8));
+        graph.render(buf.area, &mut buf);
+        assert_eq!(
+            rows(&buf),
+            [
+                "   ┌───┐    ",
+                "   │ a │    ",
+                "   └─┬─┘    ",
+                "  ┌──┴───┐  ",
+                "  │      │  ",
+                "┌─┴─┐  ┌─┴─┐",
+                "│ b │  │ c │",
+                "└───┘  └───┘",
+            ]
+        );
+    }
+
+    #[test]
+    fn test_layers_and_order() {
+        // A cycle between 2 and 3 is broken at the first of them.
+        let graph =
+            Graph::new(["x", "y", "p", "q", "r"]).edges([(0, 4), (1, 2), (2, 3), (3, 2), (0, 3)]);
+        assert_eq!(graph.assign_layers(), [0, 0, 1, 2, 1]);
+        let layout = graph.layout();
+        // r sits under x, its parent, ahead of p under y.
+        assert_eq!(layout.layers, [vec![0, 1], vec![4, 2], vec![3]]);
+        assert_eq!((layout.width, layout.height), (12, 13));
+    }
+
+    #[test]
+    fn test_selection_keys_and_scroll() {
+        let graph = Graph::new(["root", "left", "right", "leaf"]).edges([(0, 1), (0, 2), (2, 3)]);
+        let mut state = GraphState::new();
+        assert!(state.handle_key(&KeyCode::Down.into(), &graph));
+        assert_eq!(state.selected(), Some(0));
+        assert!(state.handle_key(&KeyCode::Down.into(), &graph));
+        assert_eq!(state.selected(), Some(1));
+        assert!(state.handle_key(&KeyCode::Right.into(), &graph));
+        assert!(state.handle_key(&KeyCode::Down.into(), &graph));
+        assert_eq!(state.selected(), Some(3));
+        assert!(state.handle_key(&KeyCode::Up.into(), &graph));
+        assert_eq!(state.selected(), Some(2));
+        assert!(!state.handle_key(&KeyCode::Enter.into(), &graph));
+
+        state.select(Some(3));
+        let mut buf = Buffer::empty(Rect::new(0, 0, 6, 4));
+        graph
+            .clone()
+            .render_stateful(buf.area, &mut buf, &mut state);
+        assert_eq!(state.offset(), (5, 9));
+        assert_eq!(buf.get(2, 2).unwrap().symbol(), "l");
+        assert!(
+            buf.get(2, 2)
+                .unwrap()
+                .style
+                .add_modifier
+                .contains(Modifier::REVERSED)
+        );
+
+        assert!(state.handle_key(&KeyCode::Esc.into(), &graph));
+        assert_eq!(state.selected(), None);
+    }
+}
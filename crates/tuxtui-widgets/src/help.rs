@@ -0,0 +1,432 @@
+//! Key binding hints shown as a footer line or a full-screen help overlay.
+
+use alloc::vec::Vec;
+use tuxtui_core::buffer::Buffer;
+use tuxtui_core::geometry::Rect;
+use tuxtui_core::style::{Modifier, Style};
+use tuxtui_core::terminal::Widget;
+use tuxtui_core::util::unicode::truncate_to_width;
+use unicode_width::UnicodeWidthStr;
+
+/// A key and what it does.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct KeyHint<'a> {
+    key: &'a str,
+    description: &'a str,
+    priority: u8,
+}
+
+impl<'a> KeyHint<'a> {
+    /// Create a hint with the default priority of 0.
+    #[must_use]
+    pub const fn new(key: &'a str, description: &'a str) -> Self {
+        Self {
+            key,
+            description,
+            priority: 0,
+        }
+    }
+
+    /// Set the priority. Hints with a lower priority are dropped first when
+    /// a [`HelpBar`] does not fit.
+    #[must_use]
+    pub const fn priority(mut self, priority: u8) -> Self {
+        self.priority = priority;
+        self
+    }
+
+    /// Get the key.
+    #[must_use]
+    pub const fn key(&self) -> &'a str {
+        self.key
+    }
+
+    /// Get the description.
+    #[must_use]
+    pub const fn description(&self) -> &'a str {
+        self.description
+    }
+
+    /// Get the width of the key cap, which pads the key by a space per side.
+    fn key_width(&self) -> usize {
+        self.key.width() + 2
+    }
+
+    /// Get the width of the key cap followed by the description.
+    fn width(&self) -> usize {
+        self.key_width() + 1 + self.description.width()
+    }
+}
+
+impl<'a> From<(&'a str, &'a str)> for KeyHint<'a> {
+    fn from((key, description): (&'a str, &'a str)) -> Self {
+        Self::new(key, description)
+    }
+}
+
+/// Draw `text` at `(x, y)`, clipped to `right`, and get the x after it.
+fn set_clipped(buf: &mut Buffer, x: u16, y: u16, right: u16, text: &str, style: Style) -> u16 {
+    let width = usize::from(right.saturating_sub(x));
+    if width == 0 {
+        return x;
+    }
+    buf.set_string(x, y, truncate_to_width(text, width), style)
+}
+
+/// Draw a hint at `(x, y)`, clipped to `right`, and get the x after it.
+fn render_hint(
+    buf: &mut Buffer,
+    hint: &KeyHint<'_>,
+    (x, y): (u16, u16),
+    right: u16,
+    (key_style, description_style): (Style, Style),
+) -> u16 {
+    let mut x = set_clipped(buf, x, y, right, " ", key_style);
+    x = set_clipped(buf, x, y, right, hint.key, key_style);
+    x = set_clipped(buf, x, y, right, " ", key_style);
+    x = set_clipped(buf, x, y, right, " ", description_style);
+    set_clipped(buf, x, y, right, hint.description, description_style)
+}
+
+/// A footer line of key binding hints.
+///
+/// Keys are drawn as caps in the key style, followed by their description.
+/// When the hints do not fit, the ones with the lowest priority are dropped,
+/// starting from the end, while the rest keep their order.
+///
+/// # Example
+///
+/// ```
+/// use tuxtui_core::prelude::*;
+/// use tuxtui_widgets::help::{HelpBar, KeyHint};
+///
+/// let help = HelpBar::new([("q", "quit"), ("?", "help")])
+///     .hint(KeyHint::new("ctrl+s", "save").priority(1))
+///     .key_style(Style::default().bg(Color::Blue));
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct HelpBar<'a> {
+    hints: Vec<KeyHint<'a>>,
+    style: Style,
+    key_style: Style,
+    description_style: Style,
+    separator: &'a str,
+}
+
+impl<'a> HelpBar<'a> {
+    /// Create a help bar from hints or `(key, description)` pairs.
+    #[must_use]
+    pub fn new<T>(hints: T) -> Self
+    where
+        T: IntoIterator,
+        T::Item: Into<KeyHint<'a>>,
+    {
+        Self {
+            hints: hints.into_iter().map(Into::into).collect(),
+            style: Style::default(),
+            key_style: Style::default().add_modifier(Modifier::REVERSED),
+            description_style: Style::default(),
+            separator: "  ",
+        }
+    }
+
+    /// Add a hint.
+    #[must_use]
+    pub fn hint(mut self, hint: impl Into<KeyHint<'a>>) -> Self {
+        self.hints.push(hint.into());
+        self
+    }
+
+    /// Set the overall style.
+    #[must_use]
+    pub const fn style(mut self, style: Style) -> Self {
+        self.style = style;
+        self
+    }
+
+    /// Set the style of the key caps.
+    #[must_use]
+    pub const fn key_style(mut self, style: Style) -> Self {
+        self.key_style = style;
+        self
+    }
+
+    /// Set the style of the descriptions.
+    #[must_use]
+    pub const fn description_style(mut self, style: Style) -> Self {
+        self.description_style = style;
+        self
+    }
+
+    /// Set the text between hints.
+    #[must_use]
+    pub const fn separator(mut self, separator: &'a str) -> Self {
+        self.separator = separator;
+        self
+    }
+
+    /// Get the hints that fit in `width`, in their original order.
+    fn visible(&self, width: usize) -> Vec<&KeyHint<'a>> {
+        let separator = self.separator.width();
+        let total = |hints: &[&KeyHint<'_>]| {
+            hints.iter().map(|hint| hint.width()).sum::<usize>()
+                + separator * hints.len().saturating_sub(1)
+        };
+        let mut visible: Vec<_> = self.hints.iter().collect();
+        while visible.len() > 1 && total(&visible) > width {
+            let lowest = visible
+                .iter()
+                .enumerate()
+                .rev()
+                .min_by_key(|(_, hint)| hint.priority)
+                .map(|(i, _)| i);
+            if let Some(i) = lowest {
+                visible.remove(i);
+            }
+        }
+        visible
+    }
+}
+
+impl Widget for HelpBar<'_> {
+    fn render(self, area: Rect, buf: &mut Buffer) {
+        if area.area() == 0 {
+            return;
+        }
+        buf.set_style(area, self.style);
+        let styles = (
+            self.style.patch(self.key_style),
+            self.style.patch(self.description_style),
+        );
+        let y = area.top();
+        let mut x = area.left();
+        for (i, hint) in self
+            .visible(usize::from(area.width))
+            .into_iter()
+            .enumerate()
+        {
+            if i > 0 {
+                x = set_clipped(buf, x, y, area.right(), self.separator, self.style);
+            }
+            x = render_hint(buf, hint, (x, y), area.right(), styles);
+        }
+    }
+}
+
+/// A full-screen help overlay listing key binding hints in columns.
+///
+/// The area is cleared first, and hints fill the columns top to bottom.
+/// As many columns as fit the widest hint are used.
+///
+/// # Example
+///
+/// ```
+/// use tuxtui_core::prelude::*;
+/// use tuxtui_widgets::help::HelpOverlay;
+///
+/// let overlay = HelpOverlay::new([("q", "quit"), ("j", "down"), ("k", "up")])
+///     .title("Keys");
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct HelpOverlay<'a> {
+    hints: Vec<KeyHint<'a>>,
+    title: Option<&'a str>,
+    style: Style,
+    title_style: Style,
+    key_style: Style,
+    description_style: Style,
+    column_spacing: u16,
+}
+
+impl<'a> HelpOverlay<'a> {
+    /// Create a help overlay from hints or `(key, description)` pairs.
+    #[must_use]
+    pub fn new<T>(hints: T) -> Self
+    where
+        T: IntoIterator,
+        T::Item: Into<KeyHint<'a>>,
+    {
+        Self {
+            hints: hints.into_iter().map(Into::into).collect(),
+            title: None,
+            style: Style::default(),
+            title_style: Style::default().add_modifier(Modifier::BOLD),
+            key_style: Style::default().add_modifier(Modifier::REVERSED),
+            description_style: Style::default(),
+            column_spacing: 3,
+        }
+    }
+
+    /// Set a title shown above the hints.
+    #[must_use]
+    pub const fn title(mut self, title: &'a str) -> Self {
+        self.title = Some(title);
+        self
+    }
+
+    /// Set the overall style.
+    #[must_use]
+    pub const fn style(mut self, style: Style) -> Self {
+        self.style = style;
+        self
+    }
+
+    /// Set the style of the title.
+    #[must_use]
+    pub const fn title_style(mut self, style: Style) -> Self {
+        self.title_style = style;
+        self
+    }
+
+    /// Set the style of the key caps.
+    #[must_use]
+    pub const fn key_style(mut self, style: Style) -> Self {
+        self.key_style = style;
+        self
+    }
+
+    /// Set the style of the descriptions.
+    #[must_use]
+    pub const fn description_style(mut self, style: Style) -> Self {
+        self.description_style = style;
+        self
+    }
+
+    /// Set the space between columns.
+    #[must_use]
+    pub const fn column_spacing(mut self, spacing: u16) -> Self {
+        self.column_spacing = spacing;
+        self
+    }
+}
+
+impl Widget for HelpOverlay<'_> {
+    fn render(self, area: Rect, buf: &mut Buffer) {
+        if area.area() == 0 {
+            return;
+        }
+        for y in area.top()..area.bottom() {
+            for x in area.left()..area.right() {
+                buf.set(x, y, " ", self.style);
+            }
+        }
+
+        let mut area = area;
+        if let Some(title) = self.title {
+            let width = u16::try_from(title.width()).unwrap_or(u16::MAX);
+            let x = area.left() + area.width.saturating_sub(width) / 2;
+            let style = self.style.patch(self.title_style);
+            set_clipped(buf, x, area.top(), area.right(), title, style);
+            area = Rect::new(
+                area.x,
+                area.y + 2,
+                area.width,
+                area.height.saturating_sub(2),
+            );
+        }
+        if area.height == 0 || self.hints.is_empty() {
+            return;
+        }
+
+        // Align the descriptions by padding every key cap to the widest key.
+        let key_width = self.hints.iter().map(KeyHint::key_width).max().unwrap_or(0);
+        let description_width = self
+            .hints
+            .iter()
+            .map(|hint| hint.description.width())
+            .max()
+            .unwrap_or(0);
+        let column_width = key_width + 1 + description_width;
+        let stride = column_width + usize::from(self.column_spacing);
+        let columns =
+            ((usize::from(area.width) + usize::from(self.column_spacing)) / stride).max(1);
+        let rows = self
+            .hints
+            .len()
+            .div_ceil(columns)
+            .min(usize::from(area.height));
+
+        let key_style = self.style.patch(self.key_style);
+        let description_style = self.style.patch(self.description_style);
+        for (i, hint) in self.hints.iter().enumerate().take(rows * columns) {
+            let (column, row) = (i / rows, i % rows);
+            let Ok(offset) = u16::try_from(column * stride) else {
+                break;
+            };
+            let x = area.left().saturating_add(offset);
+            if x >= area.right() {
+                break;
+            }
+            let y = area.top() + row as u16;
+            let cap_x = x + u16::try_from(key_width - hint.key_width()).unwrap_or(0);
+            render_hint(
+                buf,
+                hint,
+                (cap_x, y),
+                area.right(),
+                (key_style, description_style),
+            );
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tuxtui_core::assert_buffer_eq;
+
+    #[test]
+    fn test_help_bar_drops_low_priority() {
+        let help = HelpBar::new([("q", "quit"), ("?", "help"), ("s", "save")])
+            .key_style(Style::default())
+            .hint(KeyHint::new("x", "exit").priority(2));
+        let mut buf = Buffer::empty(Rect::new(0, 0, 20, 1));
+        help.clone().render(buf.area, &mut buf);
+        assert_buffer_eq!(buf, Buffer::from_lines(&[" q  quit   x  exit  "]));
+
+        let mut buf = Buffer::empty(Rect::new(0, 0, 32, 1));
+        help.render(buf.area, &mut buf);
+        assert_buffer_eq!(
+            buf,
+            Buffer::from_lines(&[" q  quit   ?  help   x  exit    "])
+        );
+    }
+
+    #[test]
+    fn test_help_bar_styles_key_caps() {
+        let mut buf = Buffer::empty(Rect::new(0, 0, 8, 1));
+        HelpBar::new([("q", "quit")]).render(buf.area, &mut buf);
+        let mut expected = Buffer::from_lines(&[" q  quit"]);
+        expected.set_style(
+            Rect::new(0, 0, 3, 1),
+            Style::default().add_modifier(Modifier::REVERSED),
+        );
+        assert_buffer_eq!(buf, expected);
+    }
+
+    #[test]
+    fn test_help_overlay_columns() {
+        let overlay = HelpOverlay::new([
+            ("q", "quit"),
+            ("tab", "next"),
+            ("j", "down"),
+            ("k", "up"),
+            ("?", "help"),
+        ])
+        .key_style(Style::default())
+        .title_style(Style::default())
+        .title("Keys");
+        let mut buf = Buffer::empty(Rect::new(0, 0, 23, 5));
+        overlay.render(buf.area, &mut buf);
+        assert_buffer_eq!(
+            buf,
+            Buffer::from_lines(&[
+                "         Keys          ",
+                "                       ",
+                "   q  quit      k  up  ",
+                " tab  next      ?  help",
+                "   j  down             ",
+            ])
+        );
+    }
+}
@@ -0,0 +1,478 @@
+//! Hex viewer widget for binary data.
+
+use alloc::format;
+use alloc::vec;
+use alloc::vec::Vec;
+use core::ops::Range;
+use tuxtui_core::buffer::Buffer;
+use tuxtui_core::event::{KeyCode, KeyEvent};
+use tuxtui_core::geometry::Rect;
+use tuxtui_core::style::{Color, Modifier, Style};
+use tuxtui_core::terminal::Widget;
+
+/// Hex digits of a byte.
+const HEX: &[u8; 16] = b"0123456789abcdef";
+
+/// A source of bytes read by position, such as a slice or a file.
+///
+/// A [`HexView`] only reads the rows it shows, so sources can be far larger
+/// than memory.
+pub trait ReadAt {
+    /// Get the number of bytes.
+    fn len(&self) -> u64;
+
+    /// Check whether there are no bytes.
+    fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Read bytes starting at `offset` into `buf`, returning how many were
+    /// read. Fewer bytes than asked for are shown as blanks, so failures to
+    /// read can return 0.
+    fn read_at(&self, offset: u64, buf: &mut [u8]) -> usize;
+}
+
+impl ReadAt for [u8] {
+    fn len(&self) -> u64 {
+        <[u8]>::len(self) as u64
+    }
+
+    fn read_at(&self, offset: u64, buf: &mut [u8]) -> usize {
+        let Some(rest) = usize::try_from(offset)
+            .ok()
+            .and_then(|start| self.get(start..))
+        else {
+            return 0;
+        };
+        let len = rest.len().min(buf.len());
+        buf[..len].copy_from_slice(&rest[..len]);
+        len
+    }
+}
+
+impl ReadAt for Vec<u8> {
+    fn len(&self) -> u64 {
+        self.as_slice().len() as u64
+    }
+
+    fn read_at(&self, offset: u64, buf: &mut [u8]) -> usize {
+        self.as_slice().read_at(offset, buf)
+    }
+}
+
+#[cfg(all(feature = "std", any(unix, windows)))]
+impl ReadAt for std::fs::File {
+    fn len(&self) -> u64 {
+        self.metadata().map_or(0, |metadata| metadata.len())
+    }
+
+    fn read_at(&self, offset: u64, buf: &mut [u8]) -> usize {
+        #[cfg(unix)]
+        let read = std::os::unix::fs::FileExt::read_at(self, buf, offset);
+        #[cfg(windows)]
+        let read = std::os::windows::fs::FileExt::seek_read(self, buf, offset);
+        read.unwrap_or(0)
+    }
+}
+
+/// State for a [`HexView`], holding the cursor, the selection and
+/// scrolling.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct HexViewState {
+    cursor: u64,
+    anchor: Option<u64>,
+    offset: u64,
+    page: u16,
+}
+
+impl HexViewState {
+    /// Create a state with the cursor on the first byte.
+    #[must_use]
+    pub const fn new() -> Self {
+        Self {
+            cursor: 0,
+            anchor: None,
+            offset: 0,
+            page: 0,
+        }
+    }
+
+    /// Get the position of the byte under the cursor.
+    #[must_use]
+    pub const fn cursor(&self) -> u64 {
+        self.cursor
+    }
+
+    /// Move the cursor to a byte, clearing the selection.
+    pub const fn set_cursor(&mut self, position: u64) {
+        self.cursor = position;
+        self.anchor = None;
+    }
+
+    /// Select the bytes from `start` to `end`, with the cursor at `end`.
+    pub const fn select(&mut self, start: u64, end: u64) {
+        self.anchor = Some(start);
+        self.cursor = end;
+    }
+
+    /// Get the selected bytes, including those at both ends.
+    #[must_use]
+    pub fn selection(&self) -> Option<Range<u64>> {
+        let anchor = self.anchor?;
+        Some(anchor.min(self.cursor)..anchor.max(self.cursor) + 1)
+    }
+
+    /// Get the row shown at the top.
+    #[must_use]
+    pub const fn offset(&self) -> u64 {
+        self.offset
+    }
+
+    /// Handle a key press for `view`.
+    ///
+    /// The arrow keys move the cursor by a byte or a row, Page Up and Page
+    /// Down by a page, and Home and End to the start and end of the row, or
+    /// of the data with Ctrl. Holding Shift selects the bytes passed over,
+    /// and Esc clears the selection. Returns `true` if the key was consumed.
+    pub fn handle_key<D: ReadAt + ?Sized>(
+        &mut self,
+        key: &KeyEvent,
+        view: &HexView<'_, D>,
+    ) -> bool {
+        let len = view.data.len();
+        if len == 0 || key.is_release() {
+            return false;
+        }
+        let row = view.bytes_per_row as u64;
+        let page = row * u64::from(self.page.max(1));
+        let cursor = self.cursor.min(len - 1);
+        let target = match key.code {
+            KeyCode::Left => cursor.saturating_sub(1),
+            KeyCode::Right => cursor + 1,
+            KeyCode::Up => cursor.checked_sub(row).unwrap_or(cursor),
+            KeyCode::Down if cursor + row < len => cursor + row,
+            KeyCode::Down => cursor,
+            KeyCode::PageUp => cursor.saturating_sub(page),
+            KeyCode::PageDown => cursor.saturating_add(page),
+            KeyCode::Home if key.modifiers.ctrl => 0,
+            KeyCode::End if key.modifiers.ctrl => len - 1,
+            KeyCode::Home => cursor - cursor % row,
+            KeyCode::End => cursor - cursor % row + row - 1,
+            KeyCode::Esc if self.anchor.is_some() => {
+                self.anchor = None;
+                return true;
+            }
+            _ => return false,
+        };
+        if key.modifiers.shift {
+            self.anchor.get_or_insert(cursor);
+        } else {
+            self.anchor = None;
+        }
+        self.cursor = target.min(len - 1);
+        true
+    }
+}
+
+/// Binary data shown as offsets, hex bytes and their ASCII characters.
+///
+/// Only the rows in view are read from the [`ReadAt`] source, so files of
+/// any size can be shown. The byte under the cursor of a [`HexViewState`]
+/// and the selected bytes are highlighted, as are bytes marked dirty, such
+/// as unsaved edits.
+///
+/// # Example
+///
+/// ```
+/// use tuxtui_core::prelude::*;
+/// use tuxtui_widgets::hex::{HexView, HexViewState};
+///
+/// let data = b"Hello, world!";
+/// let mut state = HexViewState::new();
+/// let mut buf = Buffer::empty(Rect::new(0, 0, 80, 4));
+/// HexView::new(&data[..])
+///     .bytes_per_row(8)
+///     .dirty([0..5, 7..12])
+///     .render_stateful(buf.area, &mut buf, &mut state);
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct HexView<'a, D: ?Sized = [u8]> {
+    data: &'a D,
+    bytes_per_row: usize,
+    show_ascii: bool,
+    dirty: Vec<Range<u64>>,
+    style: Style,
+    offset_style: Style,
+    cursor_style: Style,
+    selection_style: Style,
+    dirty_style: Style,
+}
+
+impl<'a, D: ReadAt + ?Sized> HexView<'a, D> {
+    /// Create a view of `data`.
+    #[must_use]
+    pub fn new(data: &'a D) -> Self {
+        Self {
+            data,
+            bytes_per_row: 16,
+            show_ascii: true,
+            dirty: Vec::new(),
+            style: Style::new(),
+            offset_style: Style::new().fg(Color::Gray),
+            cursor_style: Style::new().add_modifier(Modifier::REVERSED),
+            selection_style: Style::new().bg(Color::Blue),
+            dirty_style: Style::new().fg(Color::Red),
+        }
+    }
+
+    /// Set the bytes shown in each row, 16 by default.
+    #[must_use]
+    pub const fn bytes_per_row(mut self, bytes: usize) -> Self {
+        self.bytes_per_row = if bytes == 0 { 1 } else { bytes };
+        self
+    }
+
+    /// Set whether the ASCII column is shown.
+    #[must_use]
+    pub const fn show_ascii(mut self, show: bool) -> Self {
+        self.show_ascii = show;
+        self
+    }
+
+    /// Mark ranges of bytes as dirty, such as unsaved edits.
+    #[must_use]
+    pub fn dirty(mut self, ranges: impl IntoIterator<Item = Range<u64>>) -> Self {
+        self.dirty = ranges.into_iter().collect();
+        self
+    }
+
+    /// Set the overall style.
+    #[must_use]
+    pub const fn style(mut self, style: Style) -> Self {
+        self.style = style;
+        self
+    }
+
+    /// Set the style of the offset column.
+    #[must_use]
+    pub const fn offset_style(mut self, style: Style) -> Self {
+        self.offset_style = style;
+        self
+    }
+
+    /// Set the style of the byte under the cursor.
+    #[must_use]
+    pub const fn cursor_style(mut self, style: Style) -> Self {
+        self.cursor_style = style;
+        self
+    }
+
+    /// Set the style of selected bytes.
+    #[must_use]
+    pub const fn selection_style(mut self, style: Style) -> Self {
+        self.selection_style = style;
+        self
+    }
+
+    /// Set the style of dirty bytes.
+    #[must_use]
+    pub const fn dirty_style(mut self, style: Style) -> Self {
+        self.dirty_style = style;
+        self
+    }
+
+    /// Get the column of a byte in the hex column, from its start, with a
+    /// wider gap after every 8 bytes.
+    const fn hex_column(index: usize) -> usize {
+        index * 3 + index / 8
+    }
+
+    /// Render the view with state.
+    pub fn render_stateful(self, area: Rect, buf: &mut Buffer, state: &mut HexViewState) {
+        if area.area() == 0 {
+            return;
+        }
+        buf.set_style(area, self.style);
+        let len = self.data.len();
+        let row_len = self.bytes_per_row as u64;
+        let rows = len.div_ceil(row_len);
+        let height = u64::from(area.height);
+        state.page = area.height;
+        state.cursor = state.cursor.min(len.saturating_sub(1));
+
+        // Scroll to keep the cursor in view.
+        let cursor_row = state.cursor / row_len;
+        if cursor_row < state.offset {
+            state.offset = cursor_row;
+        } else if cursor_row >= state.offset + height {
+            state.offset = cursor_row + 1 - height;
+        }
+        state.offset = state.offset.min(rows.saturating_sub(height));
+
+        let digits = (64 - len.saturating_sub(1).leading_zeros())
+            .div_ceil(4)
+            .max(8) as usize;
+        let hex_x = digits + 2;
+        let ascii_x = hex_x + Self::hex_column(self.bytes_per_row - 1) + 2 + 2;
+        let offset_style = self.style.patch(self.offset_style);
+        let selection = state.selection();
+        let mut bytes = vec![0; self.bytes_per_row];
+        for (y, row) in (area.top()..area.bottom()).zip(state.offset..rows) {
+            let start = row * row_len;
+            let offset = format!("{start:0digits$x}");
+            buf.set_line(area.x, y, &offset.as_str().into(), area.width);
+            buf.set_style(
+                Rect::new(area.x, y, (digits as u16).min(area.width), 1),
+                offset_style,
+            );
+
+            let read = self.data.read_at(start, &mut bytes);
+            for (index, &byte) in bytes[..read].iter().enumerate() {
+                let position = start + index as u64;
+                let mut style = self.style;
+                if self.dirty.iter().any(|range| range.contains(&position)) {
+                    style = style.patch(self.dirty_style);
+                }
+                if selection
+                    .as_ref()
+                    .is_some_and(|range| range.contains(&position))
+                {
+                    style = style.patch(self.selection_style);
+                }
+                if position == state.cursor {
+                    style = style.patch(self.cursor_style);
+                }
+
+                let hex = [HEX[usize::from(byte >> 4)], HEX[usize::from(byte & 0xf)]];
+                let x = hex_x + Self::hex_column(index);
+                for (i, &digit) in hex.iter().enumerate() {
+                    if let Ok(x) = u16::try_from(x + i) {
+                        if x < area.width {
+                            buf.set(area.x + x, y, digit_str(digit), style);
+                        }
+                    }
+                }
+                if self.show_ascii {
+                    let x = ascii_x + index;
+                    if let Ok(x) = u16::try_from(x) {
+                        if x < area.width {
+                            let c = if byte.is_ascii_graphic() || byte == b' ' {
+                                byte
+                            } else {
+                                b'.'
+                            };
+                            buf.set(area.x + x, y, digit_str(c), style);
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
+
+impl<D: ReadAt + ?Sized> Widget for HexView<'_, D> {
+    fn render(self, area: Rect, buf: &mut Buffer) {
+        self.render_stateful(area, buf, &mut HexViewState::new());
+    }
+}
+
+/// Get a printable ASCII byte as a string.
+fn digit_str(byte: u8) -> &'static str {
+    const ASCII: &str = " !\"#$%&'()*+,-./0123456789:;<=>?@ABCDEFGHIJKLMNOPQRSTUVWXYZ[\\]^_`abcdefghijklmnopqrstuvwxyz{|}~";
+    let index = usize::from(byte - b' ');
+    &ASCII[index..=index]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloc::string::{String, ToString};
+    use core::cell::Cell;
+    use tuxtui_core::event::KeyModifiers;
+
+    fn rows(buf: &Buffer) -> Vec<String> {
+        buf.to_string().lines().map(String::from).collect()
+    }
+
+    #[test]
+    fn test_render_rows() {
+        let data = b"Hello, world!\x00\x7f";
+        let mut state = HexViewState::new();
+        state.select(1, 2);
+        let mut buf = Buffer::empty(Rect::new(0, 0, 43, 2));
+        HexView::new(&data[..])
+            .bytes_per_row(8)
+            .dirty([0..1, 1..2])
+            .render_stateful(buf.area, &mut buf, &mut state);
+        assert_eq!(
+            rows(&buf),
+            [
+                "00000000  48 65 6c 6c 6f 2c 20 77  Hello, w",
+                "00000008  6f 72 6c 64 21 00 7f     orld!.. ",
+            ]
+        );
+        let style = |x| buf.get(x, 0).unwrap().style;
+        assert_eq!(style(10).fg, Some(Color::Red));
+        assert_eq!(style(13).bg, Some(Color::Blue));
+        assert!(style(16).add_modifier.contains(Modifier::REVERSED));
+        assert!(style(37).add_modifier.contains(Modifier::REVERSED));
+        assert_eq!(style(19), Style::new());
+    }
+
+    #[test]
+    fn test_keys_move_and_select() {
+        let data = vec![0_u8; 40];
+        let view = HexView::new(&data);
+        let mut state = HexViewState::new();
+        let mut buf = Buffer::empty(Rect::new(0, 0, 80, 2));
+        view.clone().render_stateful(buf.area, &mut buf, &mut state);
+
+        let shift = |code| KeyEvent::new(code, KeyModifiers::SHIFT);
+        let ctrl = |code| KeyEvent::new(code, KeyModifiers::CTRL);
+        assert!(state.handle_key(&KeyCode::Down.into(), &view));
+        assert!(state.handle_key(&shift(KeyCode::Right), &view));
+        assert_eq!(state.selection(), Some(16..18));
+        assert!(state.handle_key(&KeyCode::End.into(), &view));
+        assert_eq!((state.cursor(), state.selection()), (31, None));
+        assert!(state.handle_key(&ctrl(KeyCode::End), &view));
+        assert_eq!(state.cursor(), 39);
+        view.clone().render_stateful(buf.area, &mut buf, &mut state);
+        assert_eq!(state.offset(), 1);
+
+        assert!(state.handle_key(&KeyCode::PageUp.into(), &view));
+        assert_eq!(state.cursor(), 7);
+        assert!(state.handle_key(&KeyCode::Home.into(), &view));
+        assert_eq!(state.cursor(), 0);
+        assert!(!state.handle_key(&KeyCode::Esc.into(), &view));
+    }
+
+    #[test]
+    fn test_reads_only_rows_in_view() {
+        struct Huge {
+            reads: Cell<usize>,
+        }
+
+        impl ReadAt for Huge {
+            fn len(&self) -> u64 {
+                1 << 40
+            }
+
+            fn read_at(&self, offset: u64, buf: &mut [u8]) -> usize {
+                self.reads.set(self.reads.get() + 1);
+                buf.fill(offset as u8);
+                buf.len()
+            }
+        }
+
+        let data = Huge {
+            reads: Cell::new(0),
+        };
+        let mut state = HexViewState::new();
+        state.set_cursor(1 << 39);
+        let mut buf = Buffer::empty(Rect::new(0, 0, 80, 3));
+        HexView::new(&data).render_stateful(buf.area, &mut buf, &mut state);
+        assert_eq!(data.reads.get(), 3);
+        assert_eq!(state.offset(), (1 << 35) - 2);
+        assert!(rows(&buf)[2].starts_with("8000000000  00 00"));
+    }
+}
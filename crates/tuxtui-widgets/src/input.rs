@@ -1,14 +1,36 @@
 //! Text input widgets for user input.
 
+use crate::popup::{Anchor, Popup};
 use alloc::string::String;
+use alloc::vec::Vec;
 use tuxtui_core::buffer::Buffer;
+use tuxtui_core::event::{KeyCode, KeyEvent};
 use tuxtui_core::geometry::Rect;
-use tuxtui_core::style::{Modifier, Style};
+use tuxtui_core::style::{Color, Modifier, Style};
 use tuxtui_core::terminal::Widget;
+use tuxtui_core::text::{Line, Span, TextDirection};
+use tuxtui_core::util::unicode::{grapheme_width, is_rtl, visual_graphemes};
+use unicode_segmentation::UnicodeSegmentation;
+use unicode_width::UnicodeWidthStr;
 
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
 
+/// Which characters an [`InputState`] accepts.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum InputFilter {
+    /// Accept any character
+    #[default]
+    Any,
+    /// Accept ASCII digits only
+    Digits,
+    /// Accept a decimal number: digits, one decimal point and a leading minus
+    Numeric,
+    /// Accept letters and digits only
+    Alphanumeric,
+}
+
 /// State for a text input widget.
 #[derive(Debug, Clone, PartialEq, Eq)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
@@ -19,6 +41,12 @@ pub struct InputState {
     pub cursor: usize,
     /// Scroll offset for long text
     pub offset: usize,
+    /// Characters accepted by [`insert_char`](Self::insert_char)
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub filter: InputFilter,
+    /// Maximum number of graphemes accepted by [`insert_char`](Self::insert_char)
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub max_length: Option<usize>,
 }
 
 impl Default for InputState {
@@ -35,20 +63,36 @@ impl InputState {
             value: String::new(),
             cursor: 0,
             offset: 0,
+            filter: InputFilter::Any,
+            max_length: None,
         }
     }
 
     /// Create input state with initial value.
     #[must_use]
     pub fn with_value(value: String) -> Self {
-        let cursor = value.chars().count();
+        let cursor = value.graphemes(true).count();
         Self {
             value,
             cursor,
-            offset: 0,
+            ..Self::new()
         }
     }
 
+    /// Set the characters accepted when typing.
+    #[must_use]
+    pub const fn with_filter(mut self, filter: InputFilter) -> Self {
+        self.filter = filter;
+        self
+    }
+
+    /// Set the maximum number of graphemes accepted when typing.
+    #[must_use]
+    pub const fn with_max_length(mut self, max_length: usize) -> Self {
+        self.max_length = Some(max_length);
+        self
+    }
+
     /// Get the current value.
     #[must_use]
     pub fn value(&self) -> &str {
@@ -56,18 +100,49 @@ impl InputState {
     }
 
     /// Insert a character at the cursor position.
+    ///
+    /// Combining characters join the grapheme before the cursor, so the
+    /// cursor only moves when a new grapheme starts. Characters rejected by
+    /// the [`filter`](Self::filter) or beyond the
+    /// [`max_length`](Self::max_length) are ignored.
     pub fn insert_char(&mut self, c: char) {
-        let char_idx = self.grapheme_index_to_char_index(self.cursor);
-        self.value.insert(char_idx, c);
-        self.cursor += 1;
+        if !self.accepts(c) {
+            return;
+        }
+        let index = self.byte_index(self.cursor);
+        self.value.insert(index, c);
+        if self
+            .max_length
+            .is_some_and(|max| self.value.graphemes(true).count() > max)
+        {
+            self.value.remove(index);
+            return;
+        }
+        self.cursor = self.value[..index + c.len_utf8()].graphemes(true).count();
     }
 
-    /// Delete the character before the cursor.
+    /// Check whether the filter accepts `c` at the cursor.
+    fn accepts(&self, c: char) -> bool {
+        match self.filter {
+            InputFilter::Any => true,
+            InputFilter::Digits => c.is_ascii_digit(),
+            InputFilter::Alphanumeric => c.is_alphanumeric(),
+            InputFilter::Numeric => match c {
+                '0'..='9' => !self.value.starts_with('-') || self.cursor > 0,
+                '.' => !self.value.contains('.'),
+                '-' => self.cursor == 0 && !self.value.starts_with('-'),
+                _ => false,
+            },
+        }
+    }
+
+    /// Delete the grapheme before the cursor.
     pub fn delete_char(&mut self) {
         if self.cursor > 0 {
             self.cursor -= 1;
-            let char_idx = self.grapheme_index_to_char_index(self.cursor);
-            self.value.remove(char_idx);
+            let start = self.byte_index(self.cursor);
+            let end = self.byte_index(self.cursor + 1);
+            self.value.replace_range(start..end, "");
         }
     }
 
@@ -80,7 +155,7 @@ impl InputState {
 
     /// Move cursor right.
     pub fn move_cursor_right(&mut self) {
-        let len = self.value.chars().count();
+        let len = self.value.graphemes(true).count();
         if self.cursor < len {
             self.cursor += 1;
         }
@@ -94,7 +169,7 @@ impl InputState {
 
     /// Move cursor to end.
     pub fn move_cursor_end(&mut self) {
-        self.cursor = self.value.chars().count();
+        self.cursor = self.value.graphemes(true).count();
     }
 
     /// Clear all content.
@@ -104,12 +179,22 @@ impl InputState {
         self.offset = 0;
     }
 
-    /// Helper to convert grapheme index to char index.
-    fn grapheme_index_to_char_index(&self, grapheme_idx: usize) -> usize {
-        self.value.chars().take(grapheme_idx).count()
+    /// Convert a grapheme index to a byte index into the value.
+    fn byte_index(&self, grapheme_idx: usize) -> usize {
+        self.value
+            .grapheme_indices(true)
+            .nth(grapheme_idx)
+            .map_or(self.value.len(), |(i, _)| i)
     }
 }
 
+/// Checks the value of a [`TextInput`], returning an error message if it
+/// is invalid.
+pub type Validator = fn(&str) -> Result<(), String>;
+
+/// Formats the value of a [`TextInput`] for display.
+pub type Formatter = fn(&str) -> String;
+
 /// A text input widget.
 ///
 /// # Example
@@ -123,7 +208,7 @@ impl InputState {
 ///     .placeholder("Enter text...")
 ///     .style(Style::default().fg(Color::White));
 /// ```
-#[derive(Debug, Clone, PartialEq, Eq)]
+#[derive(Debug, Clone)]
 pub struct TextInput<'a> {
     placeholder: Option<&'a str>,
     style: Style,
@@ -131,6 +216,10 @@ pub struct TextInput<'a> {
     cursor_style: Style,
     show_cursor: bool,
     mask_char: Option<char>,
+    direction: TextDirection,
+    validator: Option<Validator>,
+    error_style: Style,
+    formatter: Option<Formatter>,
 }
 
 impl<'a> Default for TextInput<'a> {
@@ -142,6 +231,10 @@ impl<'a> Default for TextInput<'a> {
             cursor_style: Style::new().add_modifier(Modifier::REVERSED),
             show_cursor: true,
             mask_char: None,
+            direction: TextDirection::Auto,
+            validator: None,
+            error_style: Style::new().fg(Color::Red),
+            formatter: None,
         }
     }
 }
@@ -208,8 +301,104 @@ impl<'a> TextInput<'a> {
         self
     }
 
+    /// Set the base text direction.
+    ///
+    /// Right-to-left input is drawn right-aligned, with bidirectional text
+    /// reordered for display when the `bidi` feature is enabled. Masked
+    /// input is always drawn left to right.
+    #[must_use]
+    pub const fn direction(mut self, direction: TextDirection) -> Self {
+        self.direction = direction;
+        self
+    }
+
+    /// Set a validator for the value.
+    ///
+    /// While the validator returns an error, the text is drawn with the
+    /// [error style](Self::error_style) and, if the area is more than one
+    /// row high, the message is shown on the row below the input.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use tuxtui_core::prelude::*;
+    /// use tuxtui_widgets::input::{InputState, TextInput};
+    ///
+    /// let input = TextInput::new().validator(|value| {
+    ///     if value.contains('@') {
+    ///         Ok(())
+    ///     } else {
+    ///         Err("not an email address".into())
+    ///     }
+    /// });
+    ///
+    /// let mut state = InputState::with_value("user".to_string());
+    /// assert!(input.validate(&state).is_err());
+    ///
+    /// let mut buf = Buffer::empty(Rect::new(0, 0, 20, 2));
+    /// input.render_stateful(buf.area, &mut buf, &mut state);
+    /// assert_eq!(buf.get(0, 1).unwrap().symbol(), "n");
+    /// ```
+    #[must_use]
+    pub const fn validator(mut self, validator: Validator) -> Self {
+        self.validator = Some(validator);
+        self
+    }
+
+    /// Set the style of invalid text and of the error message.
+    #[must_use]
+    pub const fn error_style(mut self, style: Style) -> Self {
+        self.error_style = style;
+        self
+    }
+
+    /// Set a formatter for displaying the value, such as adding thousands
+    /// separators.
+    ///
+    /// Only the display changes; the value in the state is kept as typed.
+    /// Formatters that only insert characters keep the cursor on the right
+    /// character.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use tuxtui_core::prelude::*;
+    /// use tuxtui_widgets::input::{InputState, TextInput};
+    ///
+    /// fn thousands(value: &str) -> String {
+    ///     let mut out = String::new();
+    ///     for (i, c) in value.chars().enumerate() {
+    ///         if i > 0 && (value.len() - i) % 3 == 0 {
+    ///             out.push(',');
+    ///         }
+    ///         out.push(c);
+    ///     }
+    ///     out
+    /// }
+    ///
+    /// let mut state = InputState::with_value("1234567".to_string());
+    /// let mut buf = Buffer::empty(Rect::new(0, 0, 10, 1));
+    /// TextInput::new()
+    ///     .formatter(thousands)
+    ///     .show_cursor(false)
+    ///     .render_stateful(buf.area, &mut buf, &mut state);
+    /// assert_eq!(buf, Buffer::from_lines(&["1,234,567 "]));
+    /// assert_eq!(state.value(), "1234567");
+    /// ```
+    #[must_use]
+    pub const fn formatter(mut self, formatter: Formatter) -> Self {
+        self.formatter = Some(formatter);
+        self
+    }
+
+    /// Run the validator on the value, if one is set.
+    pub fn validate(&self, state: &InputState) -> Result<(), String> {
+        self.validator
+            .map_or(Ok(()), |validate| validate(&state.value))
+    }
+
     /// Render the input with state.
-    pub fn render_stateful(self, area: Rect, buf: &mut Buffer, state: &mut InputState) {
+    pub fn render_stateful(mut self, area: Rect, buf: &mut Buffer, state: &mut InputState) {
         if area.area() == 0 {
             return;
         }
@@ -217,10 +406,21 @@ impl<'a> TextInput<'a> {
         let y = area.top();
         let width = area.width as usize;
 
+        if let Err(message) = self.validate(state) {
+            self.style = self.style.patch(self.error_style);
+            if area.height > 1 {
+                let message_area = Rect::new(area.x, y + 1, area.width, 1);
+                buf.set_line(area.x, y + 1, &Line::from(message), message_area.width);
+                buf.set_style(message_area, self.error_style);
+            }
+        }
+
         // Display placeholder if empty
         if state.value.is_empty() {
             if let Some(placeholder) = self.placeholder {
-                let placeholder_style = self.style.patch(self.placeholder_style);
+                let placeholder_style = buf
+                    .settings
+                    .contrast(self.style.patch(self.placeholder_style));
                 buf.set_string(area.left(), y, placeholder, placeholder_style);
             }
 
@@ -231,41 +431,84 @@ impl<'a> TextInput<'a> {
             return;
         }
 
-        // Calculate visible portion
-        let chars: alloc::vec::Vec<char> = state.value.chars().collect();
+        state.cursor = state.cursor.min(state.value.graphemes(true).count());
+        let formatted = self.formatter.map(|format| format(&state.value));
+        let (text, cursor) = match &formatted {
+            Some(formatted) => (
+                formatted.as_str(),
+                display_cursor(&state.value, state.cursor, formatted),
+            ),
+            None => (state.value.as_str(), state.cursor),
+        };
 
-        // Adjust offset to keep cursor visible
-        if state.cursor < state.offset {
-            state.offset = state.cursor;
-        } else if state.cursor > state.offset + width {
-            state.offset = state.cursor.saturating_sub(width);
+        let mask = self.mask_char.map(String::from);
+        let graphemes: Vec<(usize, &str)> = text.grapheme_indices(true).collect();
+        let widths: Vec<usize> = graphemes
+            .iter()
+            .map(|&(_, g)| grapheme_width(mask.as_deref().unwrap_or(g)))
+            .collect();
+        let len = graphemes.len();
+
+        // Adjust offset to keep the cursor cell visible
+        let cursor_width = widths.get(cursor).copied().unwrap_or(1);
+        if cursor < state.offset {
+            state.offset = cursor;
+        }
+        while state.offset < cursor
+            && widths[state.offset..cursor].iter().sum::<usize>() + cursor_width > width
+        {
+            state.offset += 1;
         }
 
-        let visible_start = state.offset;
-        let visible_end = (state.offset + width).min(chars.len());
-        let visible_text: String = chars[visible_start..visible_end].iter().collect();
+        // Calculate visible portion
+        let mut end = state.offset;
+        let mut used = 0;
+        while end < len && used + widths[end] <= width {
+            used += widths[end];
+            end += 1;
+        }
+        let start_byte = graphemes.get(state.offset).map_or(0, |&(i, _)| i);
+        let end_byte = graphemes.get(end).map_or(text.len(), |&(i, _)| i);
+        let visible = &text[start_byte..end_byte];
+
+        let rtl = mask.is_none() && is_rtl(text, self.direction);
+        let order = if mask.is_some() {
+            visible.grapheme_indices(true).collect()
+        } else {
+            visual_graphemes(visible, self.direction)
+        };
+
+        let end_cursor = self.show_cursor && cursor == len;
+        let mut x = if rtl {
+            let total = used + usize::from(end_cursor);
+            let x = area.right().saturating_sub(total as u16).max(area.left());
+            if end_cursor {
+                buf.set(x, y, " ", self.cursor_style);
+                x + 1
+            } else {
+                x
+            }
+        } else {
+            area.left()
+        };
 
         // Render text (with masking if enabled)
-        let mut x = area.left();
-        for (i, ch) in visible_text.chars().enumerate() {
-            let global_idx = visible_start + i;
-            let style = if self.show_cursor && global_idx == state.cursor {
+        for (offset, grapheme) in order {
+            let index = graphemes.partition_point(|&(i, _)| i <= start_byte + offset) - 1;
+            let style = if self.show_cursor && index == cursor {
                 self.style.patch(self.cursor_style)
             } else {
                 self.style
             };
-
-            // Use mask character if set, otherwise show actual character
-            let display_char = self.mask_char.unwrap_or(ch);
-            let ch_str = alloc::string::ToString::to_string(&display_char);
-            x = buf.set_string(x, y, &ch_str, style);
-            if x >= area.right() {
+            let symbol = mask.as_deref().unwrap_or(grapheme);
+            if x as usize + widths[index] > area.right() as usize {
                 break;
             }
+            x = buf.set_string(x, y, symbol, style);
         }
 
         // Show cursor at end if needed
-        if self.show_cursor && state.cursor == chars.len() && x < area.right() {
+        if end_cursor && !rtl && x < area.right() {
             buf.set(x, y, " ", self.cursor_style);
         }
     }
@@ -278,6 +521,330 @@ impl Widget for TextInput<'_> {
     }
 }
 
+/// Map a cursor in `value` to the matching grapheme of its formatted form.
+///
+/// Graphemes of the value are matched in order against the formatted text,
+/// treating unmatched graphemes as inserted decoration. If the formatter
+/// changed the value itself, the cursor index is kept as is.
+fn display_cursor(value: &str, cursor: usize, formatted: &str) -> usize {
+    let mut raw = value.graphemes(true).enumerate().peekable();
+    let mut target = None;
+    for (index, grapheme) in formatted.graphemes(true).enumerate() {
+        match raw.peek() {
+            Some(&(raw_index, raw_grapheme)) if raw_grapheme == grapheme => {
+                if raw_index == cursor {
+                    target = Some(index);
+                }
+                raw.next();
+            }
+            Some(_) => {}
+            None => break,
+        }
+    }
+    if raw.peek().is_some() {
+        return cursor;
+    }
+    target.unwrap_or_else(|| formatted.graphemes(true).count())
+}
+
+/// State for an [`Autocomplete`] dropdown.
+///
+/// Call [`update`](Self::update) after every edit of the input to refresh
+/// the suggestions, and give keys to [`handle_key`](Self::handle_key) before
+/// the input sees them.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct AutocompleteState {
+    word: String,
+    matches: Vec<String>,
+    selected: usize,
+    offset: usize,
+    open: bool,
+}
+
+impl AutocompleteState {
+    /// Create a closed autocomplete state.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Refresh the suggestions for the word before the cursor.
+    ///
+    /// Candidates starting with the word, ignoring ASCII case, are kept in
+    /// order. The dropdown opens when there is a word with at least one
+    /// suggestion other than the word itself.
+    pub fn update<I, S>(&mut self, input: &InputState, candidates: I)
+    where
+        I: IntoIterator<Item = S>,
+        S: AsRef<str>,
+    {
+        let word = current_word(input);
+        let matches: Vec<String> = candidates
+            .into_iter()
+            .filter(|candidate| starts_with_ignore_case(candidate.as_ref(), word))
+            .map(|candidate| String::from(candidate.as_ref()))
+            .collect();
+        if matches != self.matches {
+            self.selected = 0;
+            self.offset = 0;
+        }
+        self.open = !word.is_empty() && matches.iter().any(|m| m != word);
+        self.word = String::from(word);
+        self.matches = matches;
+    }
+
+    /// Check whether the dropdown is open.
+    #[must_use]
+    pub const fn is_open(&self) -> bool {
+        self.open
+    }
+
+    /// Close the dropdown until the next [`update`](Self::update).
+    pub fn close(&mut self) {
+        self.open = false;
+    }
+
+    /// Get the word being completed.
+    #[must_use]
+    pub fn word(&self) -> &str {
+        &self.word
+    }
+
+    /// Get the suggestions.
+    #[must_use]
+    pub fn matches(&self) -> &[String] {
+        &self.matches
+    }
+
+    /// Get the selected suggestion.
+    #[must_use]
+    pub fn selected(&self) -> Option<&str> {
+        self.matches.get(self.selected).map(String::as_str)
+    }
+
+    /// Select the next suggestion, wrapping around.
+    pub fn select_next(&mut self) {
+        if !self.matches.is_empty() {
+            self.selected = (self.selected + 1) % self.matches.len();
+        }
+    }
+
+    /// Select the previous suggestion, wrapping around.
+    pub fn select_previous(&mut self) {
+        if !self.matches.is_empty() {
+            self.selected = self
+                .selected
+                .checked_sub(1)
+                .unwrap_or(self.matches.len() - 1);
+        }
+    }
+
+    /// Replace the word before the cursor with the selected suggestion and
+    /// close the dropdown.
+    ///
+    /// Returns `false` if nothing was selected.
+    pub fn accept(&mut self, input: &mut InputState) -> bool {
+        let Some(selected) = self.matches.get(self.selected) else {
+            return false;
+        };
+        let end = input.byte_index(input.cursor);
+        let start = end - current_word(input).len();
+        input.value.replace_range(start..end, selected);
+        input.cursor = input.value[..start + selected.len()]
+            .graphemes(true)
+            .count();
+        self.open = false;
+        true
+    }
+
+    /// Handle a key while the dropdown is open.
+    ///
+    /// Tab and Enter accept the selection, Up, Down and Back Tab move it,
+    /// and Esc closes the dropdown. Returns `true` if the key was consumed.
+    pub fn handle_key(&mut self, key: &KeyEvent, input: &mut InputState) -> bool {
+        if !self.open || key.is_release() {
+            return false;
+        }
+        match key.code {
+            KeyCode::Tab | KeyCode::Enter => self.accept(input),
+            KeyCode::Down => {
+                self.select_next();
+                true
+            }
+            KeyCode::Up | KeyCode::BackTab => {
+                self.select_previous();
+                true
+            }
+            KeyCode::Esc => {
+                self.close();
+                true
+            }
+            _ => false,
+        }
+    }
+}
+
+/// Get the run of non-whitespace text directly before the cursor.
+fn current_word(input: &InputState) -> &str {
+    let before = &input.value[..input.byte_index(input.cursor)];
+    let start = before
+        .grapheme_indices(true)
+        .rev()
+        .find(|(_, g)| g.chars().all(char::is_whitespace))
+        .map_or(0, |(i, g)| i + g.len());
+    &before[start..]
+}
+
+fn starts_with_ignore_case(candidate: &str, prefix: &str) -> bool {
+    candidate
+        .get(..prefix.len())
+        .is_some_and(|head| head.eq_ignore_ascii_case(prefix))
+}
+
+/// A dropdown of suggestions shown below a [`TextInput`].
+///
+/// # Example
+///
+/// ```
+/// use tuxtui_core::prelude::*;
+/// use tuxtui_widgets::input::{Autocomplete, AutocompleteState, InputState, TextInput};
+///
+/// let mut buf = Buffer::empty(Rect::new(0, 0, 20, 6));
+/// let input_area = Rect::new(0, 0, 20, 1);
+/// let mut input = InputState::with_value("git ch".to_string());
+/// let mut completion = AutocompleteState::new();
+/// completion.update(&input, ["checkout", "cherry-pick", "commit"]);
+///
+/// TextInput::new().render_stateful(input_area, &mut buf, &mut input);
+/// Autocomplete::new().render_stateful(input_area, &mut buf, &mut completion);
+/// assert_eq!(completion.matches(), ["checkout", "cherry-pick"]);
+///
+/// completion.handle_key(&KeyCode::Tab.into(), &mut input);
+/// assert_eq!(input.value(), "git checkout");
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Autocomplete {
+    max_height: u16,
+    width: Option<u16>,
+    style: Style,
+    selected_style: Style,
+    match_style: Style,
+}
+
+impl Default for Autocomplete {
+    fn default() -> Self {
+        Self {
+            max_height: 8,
+            width: None,
+            style: Style::new().add_modifier(Modifier::REVERSED),
+            selected_style: Style::new().add_modifier(Modifier::BOLD),
+            match_style: Style::new().add_modifier(Modifier::UNDERLINED),
+        }
+    }
+}
+
+impl Autocomplete {
+    /// Create a new autocomplete dropdown.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Set the maximum number of visible suggestions.
+    #[must_use]
+    pub const fn max_height(mut self, height: u16) -> Self {
+        self.max_height = height;
+        self
+    }
+
+    /// Set a fixed width instead of fitting the longest suggestion.
+    #[must_use]
+    pub const fn width(mut self, width: u16) -> Self {
+        self.width = Some(width);
+        self
+    }
+
+    /// Set the dropdown style.
+    #[must_use]
+    pub const fn style(mut self, style: Style) -> Self {
+        self.style = style;
+        self
+    }
+
+    /// Set the style of the selected suggestion.
+    #[must_use]
+    pub const fn selected_style(mut self, style: Style) -> Self {
+        self.selected_style = style;
+        self
+    }
+
+    /// Set the style of the part of each suggestion matching the word.
+    #[must_use]
+    pub const fn match_style(mut self, style: Style) -> Self {
+        self.match_style = style;
+        self
+    }
+
+    /// Render the dropdown for the input drawn at `input_area`.
+    ///
+    /// The dropdown opens below the input, or above it when there is no
+    /// room below, and renders nothing while the state is closed.
+    pub fn render_stateful(
+        self,
+        input_area: Rect,
+        buf: &mut Buffer,
+        state: &mut AutocompleteState,
+    ) {
+        if !state.open || state.matches.is_empty() {
+            return;
+        }
+        let rows = state.matches.len().min(usize::from(self.max_height));
+        let width = self.width.unwrap_or_else(|| {
+            let longest = state.matches.iter().map(|m| m.width()).max().unwrap_or(0);
+            (longest + 2).min(usize::from(u16::MAX)) as u16
+        });
+        let area = Popup::new()
+            .width(width)
+            .height(rows as u16)
+            .anchor(Anchor::Relative(input_area))
+            .area(buf.area);
+        let rows = usize::from(area.height);
+        if rows == 0 {
+            return;
+        }
+
+        state.selected = state.selected.min(state.matches.len() - 1);
+        if state.selected < state.offset {
+            state.offset = state.selected;
+        } else if state.selected >= state.offset + rows {
+            state.offset = state.selected + 1 - rows;
+        }
+
+        let prefix_len = state.word.len();
+        let visible = state.matches.iter().enumerate().skip(state.offset);
+        for ((index, candidate), y) in visible.zip(area.top()..area.bottom()) {
+            let style = if index == state.selected {
+                self.style.patch(self.selected_style)
+            } else {
+                self.style
+            };
+            let row = Rect::new(area.x, y, area.width, 1);
+            buf.set_style(row, style);
+            for x in row.left()..row.right() {
+                buf.set(x, y, " ", style);
+            }
+            let (head, tail) = candidate.split_at(prefix_len.min(candidate.len()));
+            let line = Line::from_spans(alloc::vec![
+                Span::styled(head, self.match_style),
+                Span::raw(tail),
+            ])
+            .patch_style(style);
+            buf.set_line(area.x + 1, y, &line, area.width.saturating_sub(2));
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -315,6 +882,180 @@ mod tests {
         assert_eq!(state.cursor, 4);
     }
 
+    #[test]
+    fn test_input_state_graphemes() {
+        let mut state = InputState::with_value("e".to_string());
+        state.insert_char('\u{301}');
+        assert_eq!(state.cursor, 1);
+        state.insert_char('你');
+        assert_eq!(state.value(), "e\u{301}你");
+        state.move_cursor_left();
+        state.delete_char();
+        assert_eq!(state.value(), "你");
+        assert_eq!(state.cursor, 0);
+    }
+
+    fn row(buf: &Buffer) -> String {
+        (0..buf.area.width)
+            .map(|x| buf.get(x, 0).unwrap())
+            .filter(|cell| !cell.skip)
+            .map(|cell| cell.symbol())
+            .collect()
+    }
+
+    #[test]
+    fn test_wide_text_scrolls_by_width() {
+        let mut state = InputState::with_value("你好世界".to_string());
+        let mut buf = Buffer::empty(Rect::new(0, 0, 5, 1));
+        TextInput::new().render_stateful(buf.area, &mut buf, &mut state);
+        assert_eq!(state.offset, 2);
+        assert_eq!(row(&buf), "世界 ");
+    }
+
+    #[test]
+    fn test_right_to_left_input() {
+        let mut state = InputState::with_value("שלום".to_string());
+        let mut buf = Buffer::empty(Rect::new(0, 0, 8, 1));
+        TextInput::new()
+            .direction(TextDirection::RightToLeft)
+            .render_stateful(buf.area, &mut buf, &mut state);
+        // Reordering depends on the `bidi` feature of the core crate.
+        let reordered = visual_graphemes("שלום", TextDirection::RightToLeft)[0].0 > 0;
+        let expected = if reordered {
+            "    םולש"
+        } else {
+            "    שלום"
+        };
+        assert_eq!(row(&buf), expected);
+        assert_eq!(buf.get(3, 0).unwrap().style, TextInput::new().cursor_style);
+    }
+
+    #[test]
+    fn test_autocomplete_filters_and_accepts() {
+        let commands = ["help", "Hello", "history", "quit"];
+        let mut input = InputState::with_value("say he".to_string());
+        let mut completion = AutocompleteState::new();
+        completion.update(&input, commands);
+
+        assert!(completion.is_open());
+        assert_eq!(completion.word(), "he");
+        assert_eq!(completion.matches(), ["help", "Hello"]);
+
+        assert!(completion.handle_key(&KeyCode::Down.into(), &mut input));
+        assert_eq!(completion.selected(), Some("Hello"));
+        assert!(!completion.handle_key(&KeyCode::Char('x').into(), &mut input));
+        assert!(completion.handle_key(&KeyCode::Enter.into(), &mut input));
+        assert_eq!(input.value(), "say Hello");
+        assert_eq!(input.cursor, 9);
+        assert!(!completion.is_open());
+
+        // Only the completed word itself matches now.
+        completion.update(&input, commands);
+        assert!(!completion.is_open());
+        input.insert_char(' ');
+        input.insert_char('q');
+        completion.update(&input, commands);
+        assert_eq!(completion.matches(), ["quit"]);
+        assert!(completion.is_open());
+    }
+
+    #[test]
+    fn test_autocomplete_render() {
+        let mut buf = Buffer::empty(Rect::new(0, 0, 12, 4));
+        let input_area = Rect::new(2, 0, 10, 1);
+        let input = InputState::with_value("ab".to_string());
+        let mut completion = AutocompleteState::new();
+        completion.update(&input, ["abc", "abcdef", "abx"]);
+        completion.select_previous();
+
+        Autocomplete::new()
+            .max_height(2)
+            .style(Style::new())
+            .selected_style(Style::new())
+            .match_style(Style::new())
+            .render_stateful(input_area, &mut buf, &mut completion);
+        let expected = Buffer::from_lines(&[
+            "            ",
+            "   abcdef   ",
+            "   abx      ",
+            "            ",
+        ]);
+        assert_eq!(buf, expected);
+
+        let mut buf = Buffer::empty(Rect::new(0, 0, 12, 4));
+        Autocomplete::new()
+            .max_height(2)
+            .render_stateful(input_area, &mut buf, &mut completion);
+        let head = buf.get(3, 2).unwrap().style;
+        assert!(
+            head.add_modifier
+                .contains(Modifier::UNDERLINED | Modifier::BOLD)
+        );
+        assert!(
+            !buf.get(5, 2)
+                .unwrap()
+                .style
+                .add_modifier
+                .contains(Modifier::UNDERLINED)
+        );
+    }
+
+    #[test]
+    fn test_input_filters() {
+        let mut state = InputState::new().with_filter(InputFilter::Numeric);
+        "-12.5.x-3".chars().for_each(|c| state.insert_char(c));
+        assert_eq!(state.value(), "-12.53");
+
+        let mut state = InputState::new()
+            .with_filter(InputFilter::Digits)
+            .with_max_length(3);
+        "1a2345".chars().for_each(|c| state.insert_char(c));
+        assert_eq!(state.value(), "123");
+        assert_eq!(state.cursor, 3);
+    }
+
+    #[test]
+    fn test_validation_and_formatting() {
+        fn grouped(value: &str) -> String {
+            value
+                .as_bytes()
+                .chunks(2)
+                .map(|pair| core::str::from_utf8(pair).unwrap())
+                .collect::<Vec<_>>()
+                .join("-")
+        }
+        let input = TextInput::new()
+            .formatter(grouped)
+            .validator(|value| match value.len() {
+                6 => Ok(()),
+                _ => Err("6 digits".into()),
+            });
+
+        let mut state = InputState::with_value("12345".to_string());
+        state.move_cursor_left();
+        let mut buf = Buffer::empty(Rect::new(0, 0, 9, 2));
+        input
+            .clone()
+            .render_stateful(buf.area, &mut buf, &mut state);
+        assert_eq!(row(&buf), "12-34-5  ");
+        assert_eq!(buf.get(1, 1).unwrap().symbol(), " ");
+        assert_eq!(buf.get(0, 1).unwrap().symbol(), "6");
+        assert_eq!(buf.get(0, 0).unwrap().style.fg, Some(Color::Red));
+        assert!(
+            buf.get(6, 0)
+                .unwrap()
+                .style
+                .add_modifier
+                .contains(Modifier::REVERSED)
+        );
+
+        state.move_cursor_end();
+        state.insert_char('6');
+        assert_eq!(input.validate(&state), Ok(()));
+        assert_eq!(display_cursor("123456", 6, "12-34-56"), 8);
+        assert_eq!(display_cursor("ab", 1, "AB"), 1);
+    }
+
     #[test]
     fn test_input_state_clear() {
         let mut state = InputState::with_value("test".to_string());
@@ -0,0 +1,333 @@
+//! JSON viewer widget showing values as a collapsible tree.
+
+use crate::tree::{Tree, TreeNode, TreeState, TreeSymbols};
+use alloc::format;
+use alloc::string::String;
+use alloc::vec::Vec;
+use serde_json::Value;
+use tuxtui_core::buffer::Buffer;
+use tuxtui_core::geometry::Rect;
+use tuxtui_core::style::{Color, Modifier, Style};
+use tuxtui_core::terminal::Widget;
+use tuxtui_core::text::{Line, Span};
+
+/// The ID of the root node, and the start of every path.
+const ROOT: &str = "$";
+
+/// Get the path of a member of the object at `parent`, such as `$.name`,
+/// or `$["first name"]` for keys that are not identifiers.
+fn key_path(parent: &str, key: &str) -> String {
+    let identifier = key
+        .chars()
+        .next()
+        .is_some_and(|c| c.is_alphabetic() || c == '_')
+        && key.chars().all(|c| c.is_alphanumeric() || c == '_');
+    if identifier {
+        format!("{parent}.{key}")
+    } else {
+        format!("{parent}[{}]", Value::from(key))
+    }
+}
+
+/// A JSON value shown as a tree, with keys and values colored by type.
+///
+/// Objects and arrays can be expanded and collapsed, showing their number
+/// of members while collapsed, and array elements are labeled with their
+/// index. The widget is drawn by a [`Tree`], so its state is a
+/// [`TreeState`], in which each node's ID is its path, such as
+/// `$.users[0].name`: the path of the selection is
+/// [`TreeState::selected`].
+///
+/// # Example
+///
+/// ```
+/// use serde_json::json;
+/// use tuxtui_core::prelude::*;
+/// use tuxtui_widgets::json_view::JsonView;
+/// use tuxtui_widgets::tree::TreeState;
+///
+/// let value = json!({"name": "tuxtui", "tags": ["tui", "terminal"]});
+/// let view = JsonView::new(&value);
+///
+/// let mut state = TreeState::new();
+/// state.expand_all(&view.nodes());
+/// state.select(Some("$.tags[1]".into()));
+/// assert_eq!(view.selected_value(&state), Some(&json!("terminal")));
+///
+/// let mut buf = Buffer::empty(Rect::new(0, 0, 30, 6));
+/// view.render_stateful(buf.area, &mut buf, &mut state);
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct JsonView<'a> {
+    value: &'a Value,
+    style: Style,
+    highlight_style: Style,
+    key_style: Style,
+    index_style: Style,
+    string_style: Style,
+    number_style: Style,
+    bool_style: Style,
+    null_style: Style,
+    symbols: TreeSymbols,
+}
+
+impl<'a> JsonView<'a> {
+    /// Create a view of `value`.
+    #[must_use]
+    pub const fn new(value: &'a Value) -> Self {
+        Self {
+            value,
+            style: Style::new(),
+            highlight_style: Style::new().add_modifier(Modifier::REVERSED),
+            key_style: Style::new().fg(Color::Cyan),
+            index_style: Style::new().fg(Color::Gray),
+            string_style: Style::new().fg(Color::Green),
+            number_style: Style::new().fg(Color::Yellow),
+            bool_style: Style::new().fg(Color::Magenta),
+            null_style: Style::new().fg(Color::Gray),
+            symbols: TreeSymbols::unicode(),
+        }
+    }
+
+    /// Set the overall style.
+    #[must_use]
+    pub const fn style(mut self, style: Style) -> Self {
+        self.style = style;
+        self
+    }
+
+    /// Set the style of the selected row.
+    #[must_use]
+    pub const fn highlight_style(mut self, style: Style) -> Self {
+        self.highlight_style = style;
+        self
+    }
+
+    /// Set the style of object keys.
+    #[must_use]
+    pub const fn key_style(mut self, style: Style) -> Self {
+        self.key_style = style;
+        self
+    }
+
+    /// Set the style of array indices.
+    #[must_use]
+    pub const fn index_style(mut self, style: Style) -> Self {
+        self.index_style = style;
+        self
+    }
+
+    /// Set the style of strings.
+    #[must_use]
+    pub const fn string_style(mut self, style: Style) -> Self {
+        self.string_style = style;
+        self
+    }
+
+    /// Set the style of numbers.
+    #[must_use]
+    pub const fn number_style(mut self, style: Style) -> Self {
+        self.number_style = style;
+        self
+    }
+
+    /// Set the style of `true` and `false`.
+    #[must_use]
+    pub const fn bool_style(mut self, style: Style) -> Self {
+        self.bool_style = style;
+        self
+    }
+
+    /// Set the style of `null`.
+    #[must_use]
+    pub const fn null_style(mut self, style: Style) -> Self {
+        self.null_style = style;
+        self
+    }
+
+    /// Set the tree symbols.
+    #[must_use]
+    pub const fn symbols(mut self, symbols: TreeSymbols) -> Self {
+        self.symbols = symbols;
+        self
+    }
+
+    /// Build the tree nodes, for navigating with a [`TreeState`].
+    ///
+    /// The members of a top-level object or array are the roots; any other
+    /// value is a single root with the ID `$`.
+    #[must_use]
+    pub fn nodes(&self) -> Vec<TreeNode<'a>> {
+        match self.value {
+            Value::Object(_) | Value::Array(_) => self.children(self.value, ROOT),
+            value => {
+                let mut line = Line::default();
+                line.push_span(self.value_span(value));
+                Vec::from([TreeNode::new(line, ROOT)])
+            }
+        }
+    }
+
+    /// Build the nodes of the members of `value`, whose path is `path`.
+    fn children(&self, value: &'a Value, path: &str) -> Vec<TreeNode<'a>> {
+        match value {
+            Value::Object(members) => members
+                .iter()
+                .map(|(key, value)| {
+                    let label = Span::styled(key.as_str(), self.key_style);
+                    self.node(label, value, key_path(path, key))
+                })
+                .collect(),
+            Value::Array(elements) => elements
+                .iter()
+                .enumerate()
+                .map(|(index, value)| {
+                    let label = Span::styled(format!("[{index}]"), self.index_style);
+                    self.node(label, value, format!("{path}[{index}]"))
+                })
+                .collect(),
+            _ => Vec::new(),
+        }
+    }
+
+    fn node(&self, label: Span<'a>, value: &'a Value, path: String) -> TreeNode<'a> {
+        let line = Line::from(Vec::from([label, Span::raw(": "), self.value_span(value)]));
+        let children = self.children(value, &path);
+        let mut node = TreeNode::new(line, path);
+        node.children = children;
+        node
+    }
+
+    /// Show a scalar, or the size of an object or array.
+    fn value_span(&self, value: &'a Value) -> Span<'a> {
+        match value {
+            Value::Null => Span::styled("null", self.null_style),
+            Value::Bool(value) => {
+                Span::styled(if *value { "true" } else { "false" }, self.bool_style)
+            }
+            Value::Number(number) => Span::styled(format!("{number}"), self.number_style),
+            Value::String(_) => Span::styled(format!("{value}"), self.string_style),
+            Value::Array(elements) => Span::raw(match elements.len() {
+                1 => String::from("[1 item]"),
+                len => format!("[{len} items]"),
+            }),
+            Value::Object(members) => Span::raw(match members.len() {
+                1 => String::from("{1 key}"),
+                len => format!("{{{len} keys}}"),
+            }),
+        }
+    }
+
+    /// Get the value at the path selected in `state`.
+    #[must_use]
+    pub fn selected_value(&self, state: &TreeState) -> Option<&'a Value> {
+        self.value_at(state.selected()?)
+    }
+
+    /// Get the value at `path`, such as `$.users[0].name`.
+    #[must_use]
+    pub fn value_at(&self, path: &str) -> Option<&'a Value> {
+        fn find<'v>(value: &'v Value, path: &str, target: &str) -> Option<&'v Value> {
+            if path == target {
+                return Some(value);
+            }
+            match value {
+                Value::Object(members) => members.iter().find_map(|(key, value)| {
+                    let path = key_path(path, key);
+                    target
+                        .starts_with(&path)
+                        .then(|| find(value, &path, target))?
+                }),
+                Value::Array(elements) => elements.iter().enumerate().find_map(|(index, value)| {
+                    let path = format!("{path}[{index}]");
+                    target
+                        .starts_with(&path)
+                        .then(|| find(value, &path, target))?
+                }),
+                _ => None,
+            }
+        }
+        find(self.value, ROOT, path)
+    }
+
+    /// Render the view with state.
+    pub fn render_stateful(self, area: Rect, buf: &mut Buffer, state: &mut TreeState) {
+        Tree::new(self.nodes())
+            .style(self.style)
+            .highlight_style(self.highlight_style)
+            .symbols(self.symbols)
+            .render_stateful(area, buf, state);
+    }
+}
+
+impl Widget for JsonView<'_> {
+    fn render(self, area: Rect, buf: &mut Buffer) {
+        self.render_stateful(area, buf, &mut TreeState::new());
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloc::string::ToString;
+    use serde_json::json;
+
+    fn rows(buf: &Buffer) -> Vec<String> {
+        buf.to_string()
+            .lines()
+            .map(|line| String::from(line.trim_end()))
+            .collect()
+    }
+
+    #[test]
+    fn test_paths_and_values() {
+        let value = json!({"users": [{"first name": "Ada", "admin": true}], "count": 1});
+        let view = JsonView::new(&value);
+        let nodes = view.nodes();
+        let ids: Vec<_> = nodes.iter().map(|node| node.id.as_str()).collect();
+        assert_eq!(ids, ["count", "users"].map(|key| key_path(ROOT, key)));
+        assert_eq!(
+            nodes[1].children[0].children[1].id,
+            "$.users[0][\"first name\"]"
+        );
+        assert_eq!(
+            view.value_at("$.users[0][\"first name\"]"),
+            Some(&json!("Ada"))
+        );
+        assert_eq!(view.value_at("$.users[0].admin"), Some(&json!(true)));
+        assert_eq!(view.value_at("$"), Some(&value));
+        assert_eq!(view.value_at("$.users[1]"), None);
+
+        let scalar = json!(null);
+        assert_eq!(JsonView::new(&scalar).nodes()[0].id, "$");
+    }
+
+    #[test]
+    fn test_render_expand_and_collapse_all() {
+        let value = json!({"name": "tui", "tags": ["a", 2, null]});
+        let view = JsonView::new(&value);
+        let mut state = TreeState::new();
+        let mut buf = Buffer::empty(Rect::new(0, 0, 24, 5));
+        view.clone().render_stateful(buf.area, &mut buf, &mut state);
+        assert_eq!(
+            rows(&buf),
+            ["  name: \"tui\"", "▶ tags: [3 items]", "", "", "",]
+        );
+        assert_eq!(buf.get(2, 0).unwrap().style.fg, Some(Color::Cyan));
+        assert_eq!(buf.get(8, 0).unwrap().style.fg, Some(Color::Green));
+
+        state.expand_all(&view.nodes());
+        state.select(Some("$.tags[1]".to_string()));
+        view.clone().render_stateful(buf.area, &mut buf, &mut state);
+        assert_eq!(
+            rows(&buf)[2..],
+            ["├─   [0]: \"a\"", "├─   [1]: 2", "└─   [2]: null",]
+        );
+        assert_eq!(view.selected_value(&state), Some(&json!(2)));
+
+        state.collapse_all();
+        buf.clear();
+        view.render_stateful(buf.area, &mut buf, &mut state);
+        assert_eq!(rows(&buf)[2], "");
+    }
+}
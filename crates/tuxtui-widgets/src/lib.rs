@@ -11,32 +11,55 @@
 //! - **Table**: Tabular data with row/column/cell selection
 //! - **Tabs**: Tab navigation widgets
 //! - **Gauge**: Progress indicators (linear and radial)
+//! - **MultiProgress**: Stacked progress bars with rates and time left
 //! - **BarChart**: Bar chart visualization
 //! - **Sparkline**: Compact line charts
 //! - **Chart**: Full-featured charts with axes and datasets
+//! - **CandlestickChart**: Open, high, low and close prices with a crosshair
+//! - **Plot**: Streaming time series with automatic scaling and a legend
+//! - **Timeline**: Labeled bars across a time axis, like a Gantt chart
 //! - **Scrollbar**: Scrollbars for scrollable content
+//! - **Minimap**: Compressed overview of a long document with click-to-jump
 //! - **Canvas**: Low-level drawing canvas
+//! - **Graph**: Nodes and edges laid out in layers, with a selected node
+//! - **Pager**: Pagination helper for lists and tables
+//! - **Breadcrumbs**: Navigation path with collapsing segments
+//! - **HelpBar**: Key binding hints as a footer line or a help overlay
+//! - **HexView**: Offsets, hex bytes and ASCII of binary data of any size
+//! - **MaskedInput**: Structured input for dates, phone numbers and serials
+//! - **ScrollView**: Scrollable window onto content of any size
+//! - **Slider**: Number input with a bar showing the value within its range
+//! - **StatusLine**: Left, centered and right-aligned segments on one row
+//! - **Windows**: Overlapping floating windows with focus, moving and resizing
 //!
 //! ## Features
 //!
+//! - `std` (default): Enable standard library support in `tuxtui-core`,
+//!   and loading CSV and TSV data into tables; without it the crate is
+//!   `no_std` and only needs `alloc`
 //! - `all-widgets` (default): Enable all widgets
-//! - `widget-calendar`: Enable calendar widget (requires `time` crate)
-//! - `serde`: Enable serialization for widget state
-//! - `unstable-rendered-line-info`: Enable experimental line info API
+//! - `widget-calendar`: Enable calendar widgets with week and agenda views
+//!   (requires `time` crate)
+//! - `widget-json`: Enable the JSON tree viewer (requires `serde_json` crate)
+//! - `serde`: Enable serialization for widget state, and building table
+//!   rows from any `Serialize` values
+//! - `unstable-rendered-line-info`: No longer needed; `Paragraph::line_count`
+//!   and `Paragraph::measure` are always available
 //!
 //! ## Example
 //!
 //! ```rust
+//! # #[cfg(feature = "block")] {
 //! use tuxtui_core::prelude::*;
 //! use tuxtui_widgets::block::{Block, BorderType};
 //!
 //! let block = Block::default()
 //!     .title("My Block")
 //!     .borders(BorderType::All);
+//! # }
 //! ```
 
 #![cfg_attr(not(feature = "std"), no_std)]
-#![allow(unexpected_cfgs)]
 #![cfg_attr(docsrs, feature(doc_cfg))]
 #![forbid(unsafe_code)]
 #![warn(missing_docs)]
@@ -46,6 +69,9 @@ extern crate alloc;
 #[cfg(feature = "block")]
 pub mod block;
 
+#[cfg(feature = "block")]
+pub mod window;
+
 #[cfg(feature = "paragraph")]
 pub mod paragraph;
 
@@ -55,33 +81,80 @@ pub mod list;
 #[cfg(feature = "table")]
 pub mod table;
 
+#[cfg(all(feature = "table", feature = "std"))]
+pub mod csv_table;
+
+#[cfg(all(feature = "table", feature = "serde"))]
+#[cfg_attr(docsrs, doc(cfg(feature = "serde")))]
+pub mod serde_table;
+
 #[cfg(feature = "tabs")]
 pub mod tabs;
 
 #[cfg(feature = "gauge")]
 pub mod gauge;
 
+#[cfg(feature = "gauge")]
+pub mod progress;
+
 #[cfg(feature = "barchart")]
 pub mod barchart;
 
 #[cfg(feature = "sparkline")]
 pub mod sparkline;
 
+#[cfg(feature = "chart")]
+pub mod candlestick;
+
 #[cfg(feature = "chart")]
 pub mod chart;
 
+#[cfg(feature = "chart")]
+pub mod plot;
+
+#[cfg(feature = "chart")]
+pub mod timeline;
+
+#[cfg(feature = "scrollbar")]
+pub mod minimap;
+
 #[cfg(feature = "scrollbar")]
 pub mod scrollbar;
 
+#[cfg(feature = "scrollbar")]
+pub mod scroll_view;
+
 #[cfg(feature = "canvas")]
 pub mod canvas;
 
+#[cfg(feature = "canvas")]
+pub mod graph;
+
 #[cfg(feature = "widget-calendar")]
 #[cfg_attr(docsrs, doc(cfg(feature = "widget-calendar")))]
 pub mod calendar;
 
+#[cfg(feature = "widget-json")]
+#[cfg_attr(docsrs, doc(cfg(feature = "widget-json")))]
+pub mod json_view;
+
+pub mod breadcrumbs;
+pub mod form;
+pub mod help;
+pub mod hex;
 pub mod input;
+pub mod masked;
+pub mod pager;
 pub mod popup;
+pub mod search;
+pub mod slider;
+pub mod status;
 pub mod tree;
 
 pub mod prelude;
+
+mod scroll;
+#[cfg(any(feature = "list", feature = "table"))]
+mod source;
+#[cfg(any(feature = "paragraph", feature = "list", feature = "table"))]
+mod wrap;
@@ -1,11 +1,19 @@
 //! List widget for rendering selectable items.
 
+use crate::pager::Pager;
+use crate::scroll::scroll_offset;
+use crate::source::{ItemCache, ItemSource};
+use crate::wrap::wrap_words;
+use alloc::borrow::Cow;
+use alloc::collections::BTreeSet;
 use alloc::vec::Vec;
 use tuxtui_core::buffer::Buffer;
-use tuxtui_core::geometry::Rect;
+use tuxtui_core::geometry::{Position, Rect};
 use tuxtui_core::style::{Style, Stylize};
 use tuxtui_core::terminal::Widget;
-use tuxtui_core::text::Line;
+use tuxtui_core::text::{Line, Text, Truncate};
+use tuxtui_core::viewport::ViewportState;
+use unicode_width::UnicodeWidthStr;
 
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
@@ -23,6 +31,8 @@ pub enum ListMarker {
 
 /// A single list item.
 ///
+/// Items may span several lines; each line of the content takes one row.
+///
 /// # Example
 ///
 /// ```
@@ -31,17 +41,20 @@ pub enum ListMarker {
 ///
 /// let item = ListItem::new("Item 1")
 ///     .style(Style::default().fg(Color::Green));
+///
+/// let multi_line = ListItem::new("Title\nDescription");
+/// assert_eq!(multi_line.height(), 2);
 /// ```
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct ListItem<'a> {
-    content: Line<'a>,
+    content: Text<'a>,
     style: Style,
 }
 
 impl<'a> ListItem<'a> {
     /// Create a new list item.
     #[must_use]
-    pub fn new<T: Into<Line<'a>>>(content: T) -> Self {
+    pub fn new<T: Into<Text<'a>>>(content: T) -> Self {
         Self {
             content: content.into(),
             style: Style::default(),
@@ -57,12 +70,20 @@ impl<'a> ListItem<'a> {
 
     /// Get the content of this item.
     #[must_use]
-    pub const fn content(&self) -> &Line<'a> {
+    pub const fn content(&self) -> &Text<'a> {
         &self.content
     }
+
+    /// Get the number of rows this item takes when not wrapped.
+    ///
+    /// An item always takes at least one row.
+    #[must_use]
+    pub fn height(&self) -> usize {
+        self.content.height().max(1)
+    }
 }
 
-impl<'a, T: Into<Line<'a>>> From<T> for ListItem<'a> {
+impl<'a, T: Into<Text<'a>>> From<T> for ListItem<'a> {
     fn from(content: T) -> Self {
         Self::new(content)
     }
@@ -75,26 +96,39 @@ impl<'a> Stylize for ListItem<'a> {
     }
 }
 
+/// Direction in which list items are laid out.
+///
+/// With [`ListDirection::BottomToTop`] the item at the scroll offset is drawn
+/// at the bottom of the area and later items grow upwards, which suits chat
+/// and log views where the newest entry sits at the bottom.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum ListDirection {
+    /// First item at the top, later items below it
+    #[default]
+    TopToBottom,
+    /// First item at the bottom, later items above it
+    BottomToTop,
+}
+
 /// Corner to start rendering from.
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub enum Corner {
     /// Start from top-left
+    #[default]
     TopLeft,
     /// Start from bottom-left (reverse order)
     BottomLeft,
 }
 
-impl Default for Corner {
-    fn default() -> Self {
-        Self::TopLeft
-    }
-}
-
 /// State for a stateful list widget.
 ///
-/// Tracks the currently selected item and scroll offset.
-/// Supports both single and multi-select modes.
+/// Tracks the currently selected item and scroll offset in a
+/// [`ViewportState`], which is updated with the number of items and the
+/// number of visible items whenever the list is rendered.
+/// Supports both single and multi-select modes, as well as a set of marked
+/// items that is independent of the selection cursor (for check lists).
 ///
 /// # Example
 ///
@@ -108,9 +142,15 @@ impl Default for Corner {
 #[derive(Debug, Clone, Default, PartialEq, Eq)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct ListState {
-    selected: Option<usize>,
-    offset: usize,
+    viewport: ViewportState,
     multi_select: Vec<usize>,
+    marked: BTreeSet<usize>,
+    page: usize,
+    scroll_padding: usize,
+    /// Index, top row relative to the list area, and height of each item
+    /// drawn by the last render.
+    #[cfg_attr(feature = "serde", serde(skip))]
+    rendered: Vec<(usize, u16, u16)>,
 }
 
 impl ListState {
@@ -118,21 +158,35 @@ impl ListState {
     #[must_use]
     pub fn new() -> Self {
         Self {
-            selected: None,
-            offset: 0,
+            viewport: ViewportState::new(),
             multi_select: Vec::new(),
+            marked: BTreeSet::new(),
+            page: 0,
+            scroll_padding: 0,
+            rendered: Vec::new(),
         }
     }
 
+    /// Get the viewport tracking the selection and scroll offset.
+    #[must_use]
+    pub const fn viewport(&self) -> &ViewportState {
+        &self.viewport
+    }
+
+    /// Get the viewport mutably.
+    pub const fn viewport_mut(&mut self) -> &mut ViewportState {
+        &mut self.viewport
+    }
+
     /// Get the selected item index.
     #[must_use]
     pub const fn selected(&self) -> Option<usize> {
-        self.selected
+        self.viewport.selected()
     }
 
     /// Select an item.
     pub fn select(&mut self, index: Option<usize>) {
-        self.selected = index;
+        self.viewport.select(index);
     }
 
     /// Select the next item.
@@ -140,10 +194,10 @@ impl ListState {
         if items_len == 0 {
             return;
         }
-        self.selected = Some(match self.selected {
+        self.select(Some(match self.selected() {
             Some(i) => (i + 1) % items_len,
             None => 0,
-        });
+        }));
     }
 
     /// Select the previous item.
@@ -151,27 +205,74 @@ impl ListState {
         if items_len == 0 {
             return;
         }
-        self.selected = Some(match self.selected {
-            Some(i) => {
-                if i == 0 {
-                    items_len - 1
-                } else {
-                    i - 1
-                }
-            }
-            None => items_len - 1,
-        });
+        self.select(Some(match self.selected() {
+            Some(0) | None => items_len - 1,
+            Some(i) => i - 1,
+        }));
+    }
+
+    /// Select the item a page below the selection.
+    ///
+    /// A page is the number of items visible when the list was last rendered.
+    pub fn select_next_page(&mut self) {
+        self.viewport.select_next_page();
+    }
+
+    /// Select the item a page above the selection.
+    pub fn select_previous_page(&mut self) {
+        self.viewport.select_previous_page();
+    }
+
+    /// Scroll so that an item is visible.
+    pub fn ensure_visible(&mut self, index: usize) {
+        self.viewport.ensure_visible(index);
+    }
+
+    /// Get how far the list is scrolled, from 0.0 to 1.0.
+    #[must_use]
+    pub fn scroll_percentage(&self) -> f64 {
+        self.viewport.scroll_percentage()
     }
 
     /// Get the scroll offset.
     #[must_use]
     pub const fn offset(&self) -> usize {
-        self.offset
+        self.viewport.offset()
     }
 
     /// Set the scroll offset.
+    #[deprecated(note = "use `viewport_mut().set_offset()` instead")]
     pub fn set_offset(&mut self, offset: usize) {
-        self.offset = offset;
+        self.viewport.set_offset(offset);
+    }
+
+    /// Get the index of the item drawn at `position`, such as a mouse click.
+    ///
+    /// `area` is the area the list was last rendered into. Rows between
+    /// items, and positions outside the area, have no item.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use tuxtui_core::prelude::*;
+    /// use tuxtui_widgets::list::{List, ListState};
+    ///
+    /// let area = Rect::new(0, 0, 10, 3);
+    /// let mut buf = Buffer::empty(area);
+    /// let mut state = ListState::new();
+    /// List::new(["a", "b", "c", "d"]).render_stateful(area, &mut buf, &mut state);
+    /// assert_eq!(state.index_at(Position::new(4, 1), area), Some(1));
+    /// ```
+    #[must_use]
+    pub fn index_at(&self, position: Position, area: Rect) -> Option<usize> {
+        if !area.contains(position) {
+            return None;
+        }
+        let y = position.y - area.y;
+        self.rendered
+            .iter()
+            .find(|&&(_, top, height)| (top..top + height).contains(&y))
+            .map(|&(index, _, _)| index)
     }
 
     /// Toggle multi-selection for an item.
@@ -204,6 +305,101 @@ impl ListState {
     pub fn select_multiple(&mut self, indices: Vec<usize>) {
         self.multi_select = indices;
     }
+
+    /// Toggle whether an item is marked.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use tuxtui_widgets::list::ListState;
+    ///
+    /// let mut state = ListState::default();
+    /// state.toggle_marked(2);
+    /// state.toggle_marked(0);
+    /// assert!(state.is_marked(2));
+    /// assert_eq!(state.marked().iter().copied().collect::<Vec<_>>(), [0, 2]);
+    ///
+    /// state.toggle_marked(2);
+    /// assert!(!state.is_marked(2));
+    /// ```
+    pub fn toggle_marked(&mut self, index: usize) {
+        if !self.marked.remove(&index) {
+            self.marked.insert(index);
+        }
+    }
+
+    /// Mark or unmark an item.
+    pub fn set_marked(&mut self, index: usize, marked: bool) {
+        if marked {
+            self.marked.insert(index);
+        } else {
+            self.marked.remove(&index);
+        }
+    }
+
+    /// Check if an item is marked.
+    #[must_use]
+    pub fn is_marked(&self, index: usize) -> bool {
+        self.marked.contains(&index)
+    }
+
+    /// Get the marked item indices, in ascending order.
+    #[must_use]
+    pub const fn marked(&self) -> &BTreeSet<usize> {
+        &self.marked
+    }
+
+    /// Unmark all items.
+    pub fn clear_marked(&mut self) {
+        self.marked.clear();
+    }
+
+    /// Keep up to `padding` items visible above and below the selection
+    /// when scrolling, like Vim's `scrolloff`.
+    ///
+    /// The padding shrinks near the ends of the list and when the viewport
+    /// is too small to fit it.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use tuxtui_widgets::list::ListState;
+    ///
+    /// let state = ListState::default().scroll_padding(2);
+    /// ```
+    #[must_use]
+    pub const fn scroll_padding(mut self, padding: usize) -> Self {
+        self.scroll_padding = padding;
+        self
+    }
+
+    /// Set the scroll padding. See [`ListState::scroll_padding`].
+    pub fn set_scroll_padding(&mut self, padding: usize) {
+        self.scroll_padding = padding;
+    }
+
+    /// Get the current page of a paginated list.
+    #[must_use]
+    pub const fn page(&self) -> usize {
+        self.page
+    }
+
+    /// Set the current page of a paginated list.
+    ///
+    /// The page is clamped to the last page when the list is rendered.
+    pub fn set_page(&mut self, page: usize) {
+        self.page = page;
+    }
+
+    /// Move to the next page of a paginated list.
+    pub fn next_page(&mut self) {
+        self.page = self.page.saturating_add(1);
+    }
+
+    /// Move to the previous page of a paginated list.
+    pub fn prev_page(&mut self) {
+        self.page = self.page.saturating_sub(1);
+    }
 }
 
 /// A list widget.
@@ -227,12 +423,20 @@ impl ListState {
 /// ```
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct List<'a> {
-    items: Vec<ListItem<'a>>,
+    items: ItemSource<'a, ListItem<'a>>,
     style: Style,
     highlight_style: Style,
     highlight_symbol: Option<&'static str>,
     marker: Option<ListMarker>,
-    start_corner: Corner,
+    direction: ListDirection,
+    page_size: Option<usize>,
+    check_markers: Option<(&'static str, &'static str)>,
+    wrap: bool,
+    truncate: Option<Truncate>,
+    ellipsis: &'static str,
+    item_spacing: u16,
+    tab_width: usize,
+    use_unicode: bool,
 }
 
 impl<'a> List<'a> {
@@ -243,16 +447,63 @@ impl<'a> List<'a> {
         T: IntoIterator,
         T::Item: Into<ListItem<'a>>,
     {
+        Self::from_source(ItemSource::Owned(
+            items.into_iter().map(Into::into).collect(),
+        ))
+    }
+
+    /// Create a list of `len` items built on demand by `item_fn`.
+    ///
+    /// Only the items in the visible window are built when rendering, so
+    /// this is suitable for very large collections.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use tuxtui_widgets::list::{List, ListItem};
+    ///
+    /// let list = List::from_fn(1_000_000, |i| ListItem::new(format!("Item {i}")));
+    /// assert_eq!(list.len(), 1_000_000);
+    /// ```
+    #[must_use]
+    pub fn from_fn<F>(len: usize, item_fn: F) -> Self
+    where
+        F: Fn(usize) -> ListItem<'a> + Send + Sync + 'a,
+    {
+        Self::from_source(ItemSource::lazy(len, item_fn))
+    }
+
+    fn from_source(items: ItemSource<'a, ListItem<'a>>) -> Self {
         Self {
-            items: items.into_iter().map(Into::into).collect(),
+            items,
             style: Style::default(),
             highlight_style: Style::default(),
             highlight_symbol: Some(">> "),
             marker: None,
-            start_corner: Corner::TopLeft,
+            direction: ListDirection::TopToBottom,
+            page_size: None,
+            check_markers: None,
+            wrap: false,
+            truncate: None,
+            ellipsis: "…",
+            item_spacing: 0,
+            tab_width: 4,
+            use_unicode: true,
         }
     }
 
+    /// Get the number of items.
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.items.len()
+    }
+
+    /// Check if the list has no items.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.items.is_empty()
+    }
+
     /// Set the overall style.
     #[must_use]
     pub const fn style(mut self, style: Style) -> Self {
@@ -281,9 +532,78 @@ impl<'a> List<'a> {
         self
     }
 
+    /// Show a check marker before each item, reflecting [`ListState::is_marked`].
+    ///
+    /// The marker is drawn after the highlight symbol, so the selection
+    /// cursor and the marked state are visible at the same time.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use tuxtui_widgets::list::List;
+    ///
+    /// let list = List::new(vec!["Apples", "Pears"]).check_markers("[x] ", "[ ] ");
+    /// ```
+    #[must_use]
+    pub const fn check_markers(mut self, marked: &'static str, unmarked: &'static str) -> Self {
+        self.check_markers = Some((marked, unmarked));
+        self
+    }
+
+    /// Word-wrap item content to the width of the list.
+    ///
+    /// Wrapped items grow taller; words longer than the width are broken at
+    /// grapheme boundaries.
+    #[must_use]
+    pub const fn wrap(mut self, wrap: bool) -> Self {
+        self.wrap = wrap;
+        self
+    }
+
+    /// Truncate item lines that are too wide, marking the cut with an ellipsis.
+    ///
+    /// Has no effect when [`List::wrap`] is enabled; without it, long lines
+    /// are cut off at the edge.
+    #[must_use]
+    pub const fn truncate(mut self, truncate: Truncate) -> Self {
+        self.truncate = Some(truncate);
+        self
+    }
+
+    /// Set the ellipsis used by [`List::truncate`]. Defaults to "…".
+    #[must_use]
+    pub const fn ellipsis(mut self, ellipsis: &'static str) -> Self {
+        self.ellipsis = ellipsis;
+        self
+    }
+
+    /// Set whether to draw with Unicode symbols (the default), or with ASCII
+    /// only: `* ` for bullets and `...` for an ellipsis that is not ASCII.
+    #[must_use]
+    pub const fn use_unicode(mut self, use_unicode: bool) -> Self {
+        self.use_unicode = use_unicode;
+        self
+    }
+
+    /// Set the number of blank rows between items.
+    #[must_use]
+    pub const fn item_spacing(mut self, spacing: u16) -> Self {
+        self.item_spacing = spacing;
+        self
+    }
+
+    /// Set the distance between tab stops in item content. Defaults to 4.
+    #[must_use]
+    pub const fn tab_width(mut self, tab_width: usize) -> Self {
+        self.tab_width = tab_width;
+        self
+    }
+
     /// Set the starting corner for rendering.
     ///
     /// Use `Corner::BottomLeft` to render items in reverse order (bottom to top).
+    /// This is equivalent to [`List::direction`] with
+    /// [`ListDirection::BottomToTop`].
     ///
     /// # Example
     ///
@@ -295,70 +615,237 @@ impl<'a> List<'a> {
     /// ```
     #[must_use]
     pub const fn start_corner(mut self, corner: Corner) -> Self {
-        self.start_corner = corner;
+        self.direction = match corner {
+            Corner::TopLeft => ListDirection::TopToBottom,
+            Corner::BottomLeft => ListDirection::BottomToTop,
+        };
+        self
+    }
+
+    /// Set the direction in which items are laid out.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use tuxtui_widgets::list::{List, ListDirection};
+    ///
+    /// let log = List::new(vec!["newest", "older", "oldest"])
+    ///     .direction(ListDirection::BottomToTop);
+    /// ```
+    #[must_use]
+    pub const fn direction(mut self, direction: ListDirection) -> Self {
+        self.direction = direction;
+        self
+    }
+
+    /// Render one page of `page_size` items at a time.
+    ///
+    /// The last line of the area shows a "page X of Y" label, and the page
+    /// is controlled with [`ListState::next_page`] and
+    /// [`ListState::prev_page`] instead of following the selection.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use tuxtui_widgets::list::{List, ListState};
+    ///
+    /// let list = List::new((0..100).map(|i| i.to_string())).paginate(20);
+    /// let mut state = ListState::default();
+    /// state.next_page();
+    /// ```
+    #[must_use]
+    pub const fn paginate(mut self, page_size: usize) -> Self {
+        self.page_size = Some(page_size);
         self
     }
 
     /// Render the list with state.
-    pub fn render_stateful(self, area: Rect, buf: &mut Buffer, state: &mut ListState) {
-        if area.area() == 0 || self.items.is_empty() {
+    pub fn render_stateful(mut self, area: Rect, buf: &mut Buffer, state: &mut ListState) {
+        if area.area() == 0 {
             return;
         }
-
-        // Adjust offset to ensure selected item is visible
-        if let Some(selected) = state.selected() {
-            if selected < state.offset {
-                state.offset = selected;
-            } else if selected >= state.offset + area.height as usize {
-                state.offset = selected.saturating_sub(area.height as usize - 1);
-            }
+        if buf.settings.ascii_only {
+            self.use_unicode = false;
         }
 
-        let visible_items = &self.items[state.offset.min(self.items.len())..];
+        buf.set_style(area, self.style);
+        let items = ItemCache::new(&self.items);
+        let mut area = area;
+        let visible = if let Some(page_size) = self.page_size {
+            let pager = Pager::new(page_size, self.items.len()).with_page(state.page);
+            state.page = pager.page();
 
-        for (i, item) in visible_items.iter().enumerate().take(area.height as usize) {
-            let y = area.top() + i as u16;
-            let item_index = state.offset + i;
-            let is_selected = state.selected() == Some(item_index);
+            let label_area = Rect::new(area.x, area.bottom() - 1, area.width, 1);
+            pager.render_label(label_area, buf, self.style);
+            area.height -= 1;
+
+            pager.range()
+        } else {
+            let offset = self.scroll_offset(area, state, &items);
+            offset.min(self.items.len())..self.items.len()
+        };
+        let offset = visible.start;
+
+        state.rendered.clear();
+
+        // Rows used so far, counted from the edge the list starts at.
+        let mut used = 0u16;
+        let mut shown = 0;
+        for item_index in visible {
+            if used >= area.height {
+                break;
+            }
+            let Some(item) = items.get(item_index) else {
+                break;
+            };
 
+            let is_selected = state.selected() == Some(item_index);
             let item_style = if is_selected {
                 self.style.patch(self.highlight_style).patch(item.style)
             } else {
                 self.style.patch(item.style)
             };
 
-            let mut x = area.left();
+            let prefix = self.prefix(item_index, state);
+            let prefix_width: usize = prefix.iter().map(|s| s.width()).sum();
+            let x = area
+                .left()
+                .saturating_add(prefix_width as u16)
+                .min(area.right());
+            let content_width = area.right() - x;
+            let lines = self.item_lines(&item, content_width);
+
+            // Items cut off by the far edge lose their trailing lines; when
+            // rendering upwards, they lose their leading lines instead.
+            let height = lines.len().max(1) as u16;
+            let rows = height.min(area.height - used);
+            let (top, skip) = match self.direction {
+                ListDirection::TopToBottom => (area.top() + used, 0),
+                ListDirection::BottomToTop => (area.bottom() - used - rows, height - rows),
+            };
 
-            // Render highlight symbol
             if is_selected {
-                if let Some(symbol) = self.highlight_symbol {
-                    x = buf.set_string(x, y, symbol, item_style);
-                }
-            } else if let Some(marker) = self.marker {
-                match marker {
-                    ListMarker::Bullet => {
-                        x = buf.set_string(x, y, "• ", item_style);
-                    }
-                    ListMarker::Numbered => {
-                        let numbered = alloc::format!("{}. ", item_index + 1);
-                        x = buf.set_string(x, y, &numbered, item_style);
-                    }
-                    ListMarker::Custom(s) => {
-                        x = buf.set_string(x, y, s, item_style);
-                    }
+                buf.set_style(Rect::new(area.x, top, area.width, rows), item_style);
+            }
+            if skip == 0 {
+                let mut prefix_x = area.left();
+                for symbol in &prefix {
+                    prefix_x = buf.set_string(prefix_x, top, symbol, item_style);
                 }
             }
 
-            // Render item content
-            for span in &item.content.spans {
-                let span_style = item_style.patch(span.style);
-                x = buf.set_string(x, y, &span.content, span_style);
-                if x >= area.right() {
-                    break;
-                }
+            state.rendered.push((item_index, top - area.top(), rows));
+
+            let text_style = item_style.patch(item.content.style);
+            let visible_lines = lines.iter().skip(skip as usize);
+            for (y, line) in (top..top + rows).zip(visible_lines) {
+                render_line(line, x, y, content_width, text_style, buf);
+            }
+
+            used = used
+                .saturating_add(height)
+                .saturating_add(self.item_spacing);
+            shown += 1;
+        }
+
+        state.viewport.set_content_length(self.items.len());
+        state.viewport.set_viewport_height(shown);
+        state.viewport.set_offset(offset);
+    }
+
+    /// Get the highlight, marker and check symbols drawn before an item.
+    fn prefix(&self, index: usize, state: &ListState) -> Vec<Cow<'static, str>> {
+        let mut prefix = Vec::new();
+        if state.selected() == Some(index) {
+            if let Some(symbol) = self.highlight_symbol {
+                prefix.push(Cow::Borrowed(symbol));
             }
+        } else if let Some(marker) = self.marker {
+            prefix.push(match marker {
+                ListMarker::Bullet if self.use_unicode => Cow::Borrowed("• "),
+                ListMarker::Bullet => Cow::Borrowed("* "),
+                ListMarker::Numbered => Cow::Owned(alloc::format!("{}. ", index + 1)),
+                ListMarker::Custom(s) => Cow::Borrowed(s),
+            });
+        }
+        if let Some((marked, unmarked)) = self.check_markers {
+            prefix.push(Cow::Borrowed(if state.is_marked(index) {
+                marked
+            } else {
+                unmarked
+            }));
         }
+        prefix
     }
+
+    /// Get the lines of an item, wrapped to `width` if wrapping is enabled.
+    fn item_lines(&self, item: &ListItem<'a>, width: u16) -> Vec<Line<'a>> {
+        let lines = item
+            .content
+            .lines
+            .iter()
+            .map(|line| line.clone().expand_tabs(self.tab_width));
+        if self.wrap {
+            lines
+                .flat_map(|line| wrap_words(&line, width as usize, true))
+                .collect()
+        } else if let Some(truncate) = self.truncate {
+            let ellipsis = if self.use_unicode || self.ellipsis.is_ascii() {
+                self.ellipsis
+            } else {
+                "..."
+            };
+            lines
+                .map(|line| line.truncated_with(width as usize, truncate, ellipsis))
+                .collect()
+        } else {
+            lines.collect()
+        }
+    }
+
+    /// Get the number of rows an item takes, excluding spacing.
+    fn item_height(
+        &self,
+        items: &ItemCache<'_, 'a, ListItem<'a>>,
+        index: usize,
+        state: &ListState,
+        width: u16,
+    ) -> usize {
+        let Some(item) = items.get(index) else {
+            return 1;
+        };
+        let prefix_width: usize = self.prefix(index, state).iter().map(|s| s.width()).sum();
+        let content_width = (width as usize).saturating_sub(prefix_width) as u16;
+        self.item_lines(&item, content_width).len().max(1)
+    }
+
+    /// Get the offset that keeps the selected item fully visible.
+    fn scroll_offset(
+        &self,
+        area: Rect,
+        state: &ListState,
+        items: &ItemCache<'_, 'a, ListItem<'a>>,
+    ) -> usize {
+        let Some(selected) = state.selected().filter(|&i| i < self.items.len()) else {
+            return state.offset();
+        };
+        scroll_offset(
+            state.offset(),
+            selected,
+            self.items.len(),
+            state.scroll_padding,
+            area.height as usize,
+            self.item_spacing as usize,
+            |index| self.item_height(items, index, state, area.width),
+        )
+    }
+}
+
+/// Render a single line of item content, clipped to `width`.
+fn render_line(line: &Line<'_>, x: u16, y: u16, width: u16, style: Style, buf: &mut Buffer) {
+    let mut line = line.clone();
+    line.style = style.patch(line.style);
+    buf.set_text(Rect::new(x, y, width, 1), &Text::from(line));
 }
 
 impl Widget for List<'_> {
@@ -371,6 +858,7 @@ impl Widget for List<'_> {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use alloc::string::String;
     use alloc::vec;
 
     #[test]
@@ -380,6 +868,28 @@ mod tests {
         assert_eq!(list.items.len(), 3);
     }
 
+    #[test]
+    fn test_from_fn_builds_only_visible_items() {
+        use core::sync::atomic::{AtomicUsize, Ordering};
+
+        fn assert_send_sync<T: Send + Sync>(_: &T) {}
+
+        let built = AtomicUsize::new(0);
+        let list = List::from_fn(1_000_000, |i| {
+            built.fetch_add(1, Ordering::Relaxed);
+            ListItem::new(alloc::format!("{i}"))
+        });
+        assert_send_sync(&list);
+        let mut state = ListState::default();
+        state.select(Some(500_000));
+        let mut buf = Buffer::empty(Rect::new(0, 0, 10, 3));
+        list.render_stateful(buf.area, &mut buf, &mut state);
+
+        assert_eq!(built.load(Ordering::Relaxed), 3);
+        assert_eq!(state.offset(), 499_998);
+        assert_eq!(buf.get(3, 2).unwrap().symbol(), "5");
+    }
+
     #[test]
     fn test_list_state() {
         let mut state = ListState::default();
@@ -406,4 +916,166 @@ mod tests {
         state.select_previous(5);
         assert_eq!(state.selected(), Some(4));
     }
+
+    fn row(buf: &Buffer, y: u16) -> String {
+        (0..buf.area.width)
+            .map(|x| buf.get(x, y).unwrap().symbol())
+            .collect()
+    }
+
+    #[test]
+    fn test_multi_line_items() {
+        let list = List::new(vec!["a\nb", "c"]).item_spacing(1);
+        let mut buf = Buffer::empty(Rect::new(0, 0, 1, 4));
+        list.render(buf.area, &mut buf);
+
+        assert_eq!(row(&buf, 0), "a");
+        assert_eq!(row(&buf, 1), "b");
+        assert_eq!(row(&buf, 2), " ");
+        assert_eq!(row(&buf, 3), "c");
+    }
+
+    #[test]
+    fn test_long_items_are_clipped() {
+        let list = List::new(vec!["abcdefgh"]);
+        let mut buf = Buffer::empty(Rect::new(0, 0, 5, 1));
+        list.render(buf.area, &mut buf);
+        assert_eq!(row(&buf, 0), "abcde");
+
+        // Nothing spills into the cells around the list.
+        let mut buf = Buffer::from_lines(&["........", "........"]);
+        List::new(vec!["abcdefgh", "你好世界"])
+            .marker(ListMarker::Bullet)
+            .render(Rect::new(1, 0, 5, 2), &mut buf);
+        assert_eq!(buf, Buffer::from_lines(&[".• abc..", ".• 你..."]));
+    }
+
+    #[test]
+    fn test_truncated_items() {
+        let list = List::new(vec!["abcdefgh"])
+            .highlight_symbol("")
+            .truncate(Truncate::Middle)
+            .ellipsis("~");
+        let mut buf = Buffer::empty(Rect::new(0, 0, 5, 1));
+        list.render(buf.area, &mut buf);
+        assert_eq!(row(&buf, 0), "ab~gh");
+    }
+
+    #[test]
+    fn test_ascii_symbols() {
+        let list = List::new(vec!["abcdefgh", "b"])
+            .marker(ListMarker::Bullet)
+            .truncate(Truncate::Tail)
+            .use_unicode(false);
+        let mut buf = Buffer::empty(Rect::new(0, 0, 8, 2));
+        list.render(buf.area, &mut buf);
+        assert_eq!(row(&buf, 0), "* abc...");
+        assert_eq!(row(&buf, 1), "* b     ");
+
+        let mut buf = Buffer::empty(Rect::new(0, 0, 8, 1))
+            .with_settings(tuxtui_core::settings::UiSettings::new().ascii_only(true));
+        List::new(vec!["b"])
+            .marker(ListMarker::Bullet)
+            .render(buf.area, &mut buf);
+        assert_eq!(row(&buf, 0), "* b     ");
+    }
+
+    #[test]
+    fn test_wrapped_items() {
+        let list = List::new(vec!["one two three", "abcdefgh"]).wrap(true);
+        let mut buf = Buffer::empty(Rect::new(0, 0, 5, 5));
+        list.render(buf.area, &mut buf);
+
+        assert_eq!(row(&buf, 0), "one  ");
+        assert_eq!(row(&buf, 1), "two  ");
+        assert_eq!(row(&buf, 2), "three");
+        assert_eq!(row(&buf, 3), "abcde");
+        assert_eq!(row(&buf, 4), "fgh  ");
+    }
+
+    #[test]
+    fn test_scroll_with_variable_heights() {
+        let list = List::new(vec!["0", "1\n1", "2\n2\n2"]).highlight_symbol("");
+        let mut state = ListState::default();
+        state.select(Some(2));
+        let mut buf = Buffer::empty(Rect::new(0, 0, 1, 4));
+        list.render_stateful(buf.area, &mut buf, &mut state);
+
+        // Item 2 takes three rows, so item 1 (two rows) no longer fits above it.
+        assert_eq!(state.offset(), 2);
+        assert_eq!(row(&buf, 0), "2");
+
+        state.select(Some(1));
+        state.viewport_mut().set_offset(0);
+        let mut buf = Buffer::empty(Rect::new(0, 0, 1, 4));
+        List::new(vec!["0", "1\n1", "2\n2\n2"])
+            .highlight_symbol("")
+            .render_stateful(buf.area, &mut buf, &mut state);
+        assert_eq!(state.offset(), 0);
+        assert_eq!(row(&buf, 3), "2");
+    }
+
+    #[test]
+    fn test_bottom_to_top() {
+        let list = List::new(vec!["a", "b\nc", "d\ne"]).direction(ListDirection::BottomToTop);
+        let mut buf = Buffer::empty(Rect::new(0, 0, 1, 4));
+        list.render(buf.area, &mut buf);
+
+        // The last item is cut off at the top, so it keeps only its last line.
+        assert_eq!(row(&buf, 0), "e");
+        assert_eq!(row(&buf, 1), "b");
+        assert_eq!(row(&buf, 2), "c");
+        assert_eq!(row(&buf, 3), "a");
+    }
+
+    #[test]
+    fn test_index_at() {
+        let list = List::new(vec!["a", "b\nc", "d\ne"]).direction(ListDirection::BottomToTop);
+        let area = Rect::new(2, 1, 3, 4);
+        let mut buf = Buffer::empty(Rect::new(0, 0, 6, 6));
+        let mut state = ListState::default();
+        list.render_stateful(area, &mut buf, &mut state);
+
+        let at = |x, y| state.index_at(Position::new(x, y), area);
+        assert_eq!(at(2, 1), Some(2));
+        assert_eq!(at(3, 2), Some(1));
+        assert_eq!(at(4, 3), Some(1));
+        assert_eq!(at(2, 4), Some(0));
+        assert_eq!(at(1, 4), None);
+        assert_eq!(at(2, 5), None);
+    }
+
+    #[test]
+    fn test_check_markers() {
+        let list = List::new(vec!["a", "b"])
+            .highlight_symbol(">")
+            .check_markers("x", "o");
+        let mut state = ListState::default();
+        state.select(Some(0));
+        state.toggle_marked(1);
+        let mut buf = Buffer::empty(Rect::new(0, 0, 3, 2));
+        list.render_stateful(buf.area, &mut buf, &mut state);
+
+        assert_eq!(buf.get(0, 0).unwrap().symbol(), ">");
+        assert_eq!(buf.get(1, 0).unwrap().symbol(), "o");
+        assert_eq!(buf.get(2, 0).unwrap().symbol(), "a");
+        assert_eq!(buf.get(0, 1).unwrap().symbol(), "x");
+        assert_eq!(buf.get(1, 1).unwrap().symbol(), "b");
+    }
+
+    #[test]
+    fn test_paginated_list() {
+        let list = List::new((0..5).map(|i| alloc::format!("{i}"))).paginate(2);
+        let mut state = ListState::default();
+        state.set_page(10);
+        let mut buf = Buffer::empty(Rect::new(0, 0, 12, 3));
+        list.render_stateful(buf.area, &mut buf, &mut state);
+
+        assert_eq!(state.page(), 2);
+        assert_eq!(buf.get(0, 0).unwrap().symbol(), "4");
+        assert_eq!(buf.get(0, 1).unwrap().symbol(), " ");
+        let label: alloc::string::String =
+            (0..11).map(|x| buf.get(x, 2).unwrap().symbol()).collect();
+        assert_eq!(label, "page 3 of 3");
+    }
 }
@@ -0,0 +1,429 @@
+//! Masked input for structured values such as dates, phone numbers and serials.
+
+use alloc::string::String;
+use alloc::vec::Vec;
+use tuxtui_core::buffer::Buffer;
+use tuxtui_core::geometry::Rect;
+use tuxtui_core::style::{Modifier, Style};
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// The characters accepted by a slot of a mask.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum SlotKind {
+    /// ASCII digits
+    Digit,
+    /// Letters
+    Letter,
+    /// Letters and digits
+    Alphanumeric,
+    /// Any character that is not whitespace
+    Any,
+}
+
+impl SlotKind {
+    /// Check whether the slot accepts `c`.
+    #[must_use]
+    pub fn accepts(self, c: char) -> bool {
+        match self {
+            Self::Digit => c.is_ascii_digit(),
+            Self::Letter => c.is_alphabetic(),
+            Self::Alphanumeric => c.is_alphanumeric(),
+            Self::Any => !c.is_whitespace(),
+        }
+    }
+}
+
+/// A position in a mask.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+enum Slot {
+    Literal(char),
+    Input(SlotKind, Option<char>),
+}
+
+/// State for a [`MaskedInput`], holding the mask and the typed characters.
+///
+/// In the pattern, `_` and `#` are digit slots, `@` is a letter slot and `*`
+/// is an alphanumeric slot. Every other character is a literal that the
+/// cursor skips over.
+///
+/// # Example
+///
+/// ```
+/// use tuxtui_widgets::masked::MaskedInputState;
+///
+/// let mut date = MaskedInputState::new("____-__-__");
+/// for c in "2024x0131".chars() {
+///     date.insert_char(c);
+/// }
+/// assert_eq!(date.text(), "2024-01-31");
+/// assert_eq!(date.value(), "20240131");
+/// assert!(date.is_complete());
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct MaskedInputState {
+    slots: Vec<Slot>,
+    cursor: usize,
+}
+
+impl MaskedInputState {
+    /// Create an empty state for the given pattern.
+    #[must_use]
+    pub fn new(pattern: &str) -> Self {
+        let slots = pattern
+            .chars()
+            .map(|c| match c {
+                '_' | '#' => Slot::Input(SlotKind::Digit, None),
+                '@' => Slot::Input(SlotKind::Letter, None),
+                '*' => Slot::Input(SlotKind::Alphanumeric, None),
+                c => Slot::Literal(c),
+            })
+            .collect();
+        let mut state = Self { slots, cursor: 0 };
+        state.cursor = state.next_input(0);
+        state
+    }
+
+    /// Set the kind of every input slot.
+    #[must_use]
+    pub fn with_slot_kind(mut self, kind: SlotKind) -> Self {
+        for slot in &mut self.slots {
+            if let Slot::Input(slot_kind, _) = slot {
+                *slot_kind = kind;
+            }
+        }
+        self
+    }
+
+    /// Get the cursor position in the mask.
+    #[must_use]
+    pub const fn cursor(&self) -> usize {
+        self.cursor
+    }
+
+    /// Get the typed characters, without literals.
+    #[must_use]
+    pub fn value(&self) -> String {
+        self.slots
+            .iter()
+            .filter_map(|slot| match slot {
+                Slot::Input(_, c) => *c,
+                Slot::Literal(_) => None,
+            })
+            .collect()
+    }
+
+    /// Get the text as displayed, with `_` for empty slots.
+    #[must_use]
+    pub fn text(&self) -> String {
+        self.slots
+            .iter()
+            .map(|slot| match slot {
+                Slot::Literal(c) | Slot::Input(_, Some(c)) => *c,
+                Slot::Input(_, None) => '_',
+            })
+            .collect()
+    }
+
+    /// Get the number of filled slots.
+    #[must_use]
+    pub fn filled(&self) -> usize {
+        self.slots
+            .iter()
+            .filter(|slot| matches!(slot, Slot::Input(_, Some(_))))
+            .count()
+    }
+
+    /// Get the number of input slots.
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.slots
+            .iter()
+            .filter(|slot| matches!(slot, Slot::Input(..)))
+            .count()
+    }
+
+    /// Check whether the mask has no input slots.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Check whether every slot is filled.
+    #[must_use]
+    pub fn is_complete(&self) -> bool {
+        self.slots
+            .iter()
+            .all(|slot| !matches!(slot, Slot::Input(_, None)))
+    }
+
+    /// Type a character at the cursor.
+    ///
+    /// Literals are skipped automatically, so typing the literal just before
+    /// the cursor, such as `-` in a date, is accepted without effect.
+    /// Returns `false` if the slot does not accept the character.
+    pub fn insert_char(&mut self, c: char) -> bool {
+        let skipped = self.cursor.checked_sub(1).map(|i| self.slots[i]);
+        match self.slots.get_mut(self.cursor) {
+            Some(Slot::Input(kind, value)) if kind.accepts(c) => {
+                *value = Some(c);
+                self.cursor = self.next_input(self.cursor + 1);
+                true
+            }
+            _ => skipped == Some(Slot::Literal(c)),
+        }
+    }
+
+    /// Clear the slot before the cursor and move onto it.
+    pub fn delete_char(&mut self) {
+        if let Some(cursor) = self.previous_input(self.cursor) {
+            self.slots[cursor] = match self.slots[cursor] {
+                Slot::Input(kind, _) => Slot::Input(kind, None),
+                literal => literal,
+            };
+            self.cursor = cursor;
+        }
+    }
+
+    /// Move the cursor to the previous slot.
+    pub fn move_cursor_left(&mut self) {
+        if let Some(cursor) = self.previous_input(self.cursor) {
+            self.cursor = cursor;
+        }
+    }
+
+    /// Move the cursor to the next slot.
+    pub fn move_cursor_right(&mut self) {
+        if self.cursor < self.slots.len() {
+            self.cursor = self.next_input(self.cursor + 1);
+        }
+    }
+
+    /// Move the cursor to the first slot.
+    pub fn move_cursor_start(&mut self) {
+        self.cursor = self.next_input(0);
+    }
+
+    /// Move the cursor past the last slot.
+    pub fn move_cursor_end(&mut self) {
+        self.cursor = self.slots.len();
+    }
+
+    /// Clear every slot.
+    pub fn clear(&mut self) {
+        for slot in &mut self.slots {
+            if let Slot::Input(_, value) = slot {
+                *value = None;
+            }
+        }
+        self.move_cursor_start();
+    }
+
+    /// Find the first input slot at or after `from`, or the end.
+    fn next_input(&self, from: usize) -> usize {
+        (from..self.slots.len())
+            .find(|&i| matches!(self.slots[i], Slot::Input(..)))
+            .unwrap_or(self.slots.len())
+    }
+
+    /// Find the last input slot before `before`.
+    fn previous_input(&self, before: usize) -> Option<usize> {
+        (0..before.min(self.slots.len()))
+            .rev()
+            .find(|&i| matches!(self.slots[i], Slot::Input(..)))
+    }
+}
+
+/// A single-line input that fills the slots of a [`MaskedInputState`].
+///
+/// # Example
+///
+/// ```
+/// use tuxtui_core::prelude::*;
+/// use tuxtui_widgets::masked::{MaskedInput, MaskedInputState};
+///
+/// let mut phone = MaskedInputState::new("(___) ___-____");
+/// "555".chars().for_each(|c| { phone.insert_char(c); });
+///
+/// let mut buf = Buffer::empty(Rect::new(0, 0, 14, 1));
+/// MaskedInput::new().render_stateful(buf.area, &mut buf, &mut phone);
+/// assert_eq!(buf.get(6, 0).unwrap().symbol(), "_");
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MaskedInput<'a> {
+    style: Style,
+    placeholder: &'a str,
+    placeholder_style: Style,
+    cursor_style: Style,
+    show_cursor: bool,
+}
+
+impl Default for MaskedInput<'_> {
+    fn default() -> Self {
+        Self {
+            style: Style::new(),
+            placeholder: "_",
+            placeholder_style: Style::new().add_modifier(Modifier::DIM),
+            cursor_style: Style::new().add_modifier(Modifier::REVERSED),
+            show_cursor: true,
+        }
+    }
+}
+
+impl<'a> MaskedInput<'a> {
+    /// Create a new masked input.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Set the overall style.
+    #[must_use]
+    pub const fn style(mut self, style: Style) -> Self {
+        self.style = style;
+        self
+    }
+
+    /// Set the symbol drawn in empty slots.
+    #[must_use]
+    pub const fn placeholder(mut self, placeholder: &'a str) -> Self {
+        self.placeholder = placeholder;
+        self
+    }
+
+    /// Set the style of empty slots.
+    #[must_use]
+    pub const fn placeholder_style(mut self, style: Style) -> Self {
+        self.placeholder_style = style;
+        self
+    }
+
+    /// Set the cursor style.
+    #[must_use]
+    pub const fn cursor_style(mut self, style: Style) -> Self {
+        self.cursor_style = style;
+        self
+    }
+
+    /// Set whether to show the cursor.
+    #[must_use]
+    pub const fn show_cursor(mut self, show: bool) -> Self {
+        self.show_cursor = show;
+        self
+    }
+
+    /// Render the input with state.
+    pub fn render_stateful(self, area: Rect, buf: &mut Buffer, state: &mut MaskedInputState) {
+        if area.area() == 0 {
+            return;
+        }
+        let y = area.top();
+        let mut x = area.left();
+        let mut buffer = [0; 4];
+        for (index, slot) in state.slots.iter().enumerate() {
+            if x >= area.right() {
+                break;
+            }
+            let (symbol, style) = match slot {
+                Slot::Literal(c) | Slot::Input(_, Some(c)) => {
+                    (&*c.encode_utf8(&mut buffer), self.style)
+                }
+                Slot::Input(_, None) => {
+                    let style = self.style.patch(self.placeholder_style);
+                    (self.placeholder, buf.settings.contrast(style))
+                }
+            };
+            let style = if self.show_cursor && index == state.cursor {
+                style.patch(self.cursor_style)
+            } else {
+                style
+            };
+            x = buf.set_string(x, y, symbol, style).min(area.right());
+        }
+        if self.show_cursor && state.cursor == state.slots.len() && x < area.right() {
+            buf.set(x, y, " ", self.style.patch(self.cursor_style));
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_literals_are_skipped() {
+        let mut phone = MaskedInputState::new("(___) ___-____");
+        assert_eq!(phone.cursor(), 1);
+        assert!(phone.insert_char('('));
+        assert_eq!(phone.cursor(), 1);
+        for c in "555123".chars() {
+            assert!(phone.insert_char(c));
+        }
+        assert_eq!(phone.text(), "(555) 123-____");
+        assert_eq!(phone.cursor(), 10);
+        assert!(!phone.insert_char('x'));
+        assert_eq!((phone.filled(), phone.len()), (6, 10));
+        assert!(!phone.is_complete());
+
+        phone.delete_char();
+        assert_eq!(phone.text(), "(555) 12_-____");
+        assert_eq!(phone.cursor(), 8);
+        phone.move_cursor_left();
+        phone.move_cursor_left();
+        phone.move_cursor_left();
+        assert_eq!(phone.cursor(), 3);
+    }
+
+    #[test]
+    fn test_slot_kinds() {
+        let mut serial = MaskedInputState::new("@@-##");
+        assert!(!serial.insert_char('1'));
+        assert!(serial.insert_char('A'));
+        assert!(serial.insert_char('b'));
+        assert!(serial.insert_char('-'));
+        assert!(serial.insert_char('4'));
+        assert!(serial.insert_char('2'));
+        assert!(serial.is_complete());
+        assert_eq!(serial.value(), "Ab42");
+        assert!(!serial.insert_char('9'));
+
+        let mut any = MaskedInputState::new("__").with_slot_kind(SlotKind::Any);
+        assert!(any.insert_char('%'));
+        assert!(!any.insert_char(' '));
+        any.clear();
+        assert_eq!(any.text(), "__");
+    }
+
+    #[test]
+    fn test_render() {
+        let mut time = MaskedInputState::new("__:__");
+        "123".chars().for_each(|c| {
+            time.insert_char(c);
+        });
+        let mut buf = Buffer::empty(Rect::new(0, 0, 6, 1));
+        MaskedInput::new()
+            .placeholder("·")
+            .render_stateful(buf.area, &mut buf, &mut time);
+
+        let symbols: String = (0..6).map(|x| buf.get(x, 0).unwrap().symbol()).collect();
+        assert_eq!(symbols, "12:3· ");
+        assert!(
+            buf.get(4, 0)
+                .unwrap()
+                .style
+                .add_modifier
+                .contains(Modifier::REVERSED)
+        );
+        assert!(
+            buf.get(4, 0)
+                .unwrap()
+                .style
+                .add_modifier
+                .contains(Modifier::DIM)
+        );
+    }
+}
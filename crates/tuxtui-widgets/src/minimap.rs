@@ -0,0 +1,298 @@
+//! Minimap widget giving a compressed overview of a long document.
+
+use alloc::vec;
+use alloc::vec::Vec;
+use tuxtui_core::buffer::Buffer;
+use tuxtui_core::event::{MouseButton, MouseEvent, MouseEventKind};
+use tuxtui_core::geometry::Rect;
+use tuxtui_core::style::{Color, Style};
+use tuxtui_core::symbols::braille;
+use tuxtui_core::terminal::Widget;
+use tuxtui_core::text::Text;
+use tuxtui_core::viewport::ViewportState;
+use unicode_width::UnicodeWidthChar;
+
+/// The braille dot bits of each row of a cell, left and right.
+const DOTS: [[u8; 2]; 4] = [[0, 3], [1, 4], [2, 5], [6, 7]];
+
+/// A compressed overview of a long [`Text`], such as a source file or a
+/// log, shown beside it.
+///
+/// Each row of braille dots stands for a number of source lines, with a
+/// dot wherever they hold text, so the shape of the document shows. The
+/// lines in view are highlighted, as are search hits. Link it to the
+/// [`ViewportState`] scrolling the document, and pass clicks to
+/// [`Minimap::handle_mouse`] to jump there.
+///
+/// # Example
+///
+/// ```
+/// use tuxtui_core::prelude::*;
+/// use tuxtui_core::viewport::ViewportState;
+/// use tuxtui_widgets::minimap::Minimap;
+///
+/// let text = Text::from("fn main() {\n    println!(\"hi\");\n}");
+/// let viewport = ViewportState::new().content_length(3).viewport_height(2);
+/// let minimap = Minimap::new(&text).viewport(&viewport).hits([1]);
+///
+/// let mut buf = Buffer::empty(Rect::new(0, 0, 10, 5));
+/// minimap.render(buf.area, &mut buf);
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Minimap<'a> {
+    text: &'a Text<'a>,
+    lines_per_dot: Option<usize>,
+    columns_per_dot: usize,
+    offset: usize,
+    viewport_length: usize,
+    hits: Vec<usize>,
+    style: Style,
+    viewport_style: Style,
+    hit_style: Style,
+}
+
+impl<'a> Minimap<'a> {
+    /// Create a minimap of `text`.
+    #[must_use]
+    pub const fn new(text: &'a Text<'a>) -> Self {
+        Self {
+            text,
+            lines_per_dot: None,
+            columns_per_dot: 2,
+            offset: 0,
+            viewport_length: 0,
+            hits: Vec::new(),
+            style: Style::new(),
+            viewport_style: Style::new().bg(Color::Gray),
+            hit_style: Style::new().fg(Color::Yellow),
+        }
+    }
+
+    /// Set the source lines each row of dots stands for.
+    ///
+    /// By default as few as fit the whole document in the area. With more
+    /// rows than fit, the minimap scrolls along with the viewport.
+    #[must_use]
+    pub const fn lines_per_dot(mut self, lines: usize) -> Self {
+        self.lines_per_dot = Some(if lines == 0 { 1 } else { lines });
+        self
+    }
+
+    /// Set the source columns each dot stands for, 2 by default.
+    #[must_use]
+    pub const fn columns_per_dot(mut self, columns: usize) -> Self {
+        self.columns_per_dot = if columns == 0 { 1 } else { columns };
+        self
+    }
+
+    /// Set the lines in view from the viewport scrolling the document.
+    #[must_use]
+    pub const fn viewport(mut self, viewport: &ViewportState) -> Self {
+        self.offset = viewport.offset();
+        self.viewport_length = viewport.viewport_len();
+        self
+    }
+
+    /// Set the indices of the lines matching a search.
+    #[must_use]
+    pub fn hits(mut self, lines: impl IntoIterator<Item = usize>) -> Self {
+        self.hits = lines.into_iter().collect();
+        self
+    }
+
+    /// Set the overall style.
+    #[must_use]
+    pub const fn style(mut self, style: Style) -> Self {
+        self.style = style;
+        self
+    }
+
+    /// Set the style of the rows showing the lines in view.
+    #[must_use]
+    pub const fn viewport_style(mut self, style: Style) -> Self {
+        self.viewport_style = style;
+        self
+    }
+
+    /// Set the style of dots from search hits.
+    #[must_use]
+    pub const fn hit_style(mut self, style: Style) -> Self {
+        self.hit_style = style;
+        self
+    }
+
+    /// Get the lines per row of cells, and the first row shown, for an
+    /// area `height` rows tall.
+    fn scale(&self, height: u16) -> (usize, usize) {
+        let len = self.text.lines.len();
+        let dot_rows = usize::from(height) * 4;
+        let per_dot = self
+            .lines_per_dot
+            .unwrap_or_else(|| len.div_ceil(dot_rows).max(1));
+        let per_row = per_dot * 4;
+        let rows = len.div_ceil(per_row);
+        let max_offset = len.saturating_sub(self.viewport_length);
+        let first = if rows > usize::from(height) && max_offset > 0 {
+            self.offset.min(max_offset) * (rows - usize::from(height)) / max_offset
+        } else {
+            0
+        };
+        (per_dot, first)
+    }
+
+    /// Scroll `viewport` to the lines under a click or drag within `area`,
+    /// where the minimap was rendered.
+    ///
+    /// The clicked row is centered in the viewport, whose content length is
+    /// set to the lines of the text. Returns `true` if the event was
+    /// consumed.
+    pub fn handle_mouse(
+        &self,
+        area: Rect,
+        event: &MouseEvent,
+        viewport: &mut ViewportState,
+    ) -> bool {
+        if !matches!(
+            event.kind,
+            MouseEventKind::Down(MouseButton::Left) | MouseEventKind::Drag(MouseButton::Left)
+        ) {
+            return false;
+        }
+        let inside = (area.left()..area.right()).contains(&event.column)
+            && (area.top()..area.bottom()).contains(&event.row);
+        if !inside {
+            return false;
+        }
+        let (per_dot, first) = self.scale(area.height);
+        let row = first + usize::from(event.row - area.top());
+        let line = row * per_dot * 4 + per_dot * 2;
+        viewport.set_content_length(self.text.lines.len());
+        viewport.set_offset(line.saturating_sub(viewport.viewport_len() / 2));
+        true
+    }
+}
+
+impl Widget for Minimap<'_> {
+    fn render(self, area: Rect, buf: &mut Buffer) {
+        if area.area() == 0 {
+            return;
+        }
+        buf.set_style(area, self.style);
+        let (per_dot, first) = self.scale(area.height);
+        let per_row = per_dot * 4;
+        let width = usize::from(area.width);
+
+        let viewport = self.offset..self.offset + self.viewport_length;
+        let viewport_style = self.style.patch(self.viewport_style);
+        let hit_style = self.style.patch(self.hit_style);
+        let mut bits = vec![0_u8; width];
+        let mut hit = vec![false; width];
+        for y in 0..area.height {
+            let start = (first + usize::from(y)) * per_row;
+            if start >= self.text.lines.len() {
+                break;
+            }
+            bits.fill(0);
+            hit.fill(false);
+            let lines = self.text.lines.iter().enumerate().skip(start).take(per_row);
+            for (index, line) in lines {
+                let dot_row = (index - start) / per_dot;
+                let is_hit = self.hits.contains(&index);
+                let mut column = 0;
+                for c in line.spans.iter().flat_map(|span| span.content.chars()) {
+                    let dot = column / self.columns_per_dot;
+                    column += c.width().unwrap_or(0);
+                    if c.is_whitespace() || dot / 2 >= width {
+                        continue;
+                    }
+                    bits[dot / 2] |= 1 << DOTS[dot_row][dot % 2];
+                    hit[dot / 2] |= is_hit;
+                }
+            }
+
+            let in_view = viewport.start < start + per_row && start < viewport.end;
+            let row_style = if in_view { viewport_style } else { self.style };
+            for x in 0..area.width {
+                let cell = usize::from(x);
+                let style = if hit[cell] {
+                    row_style.patch(hit_style)
+                } else {
+                    row_style
+                };
+                let mut utf8 = [0; 4];
+                let symbol = match bits[cell] {
+                    0 => " ",
+                    bits => braille::char_from_bits(bits).encode_utf8(&mut utf8),
+                };
+                buf.set(area.x + x, area.y + y, symbol, style);
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloc::string::String;
+    use tuxtui_core::text::Line;
+
+    fn document(lines: usize) -> Text<'static> {
+        Text::from((0..lines).map(|_| Line::from("ab  cd")).collect::<Vec<_>>())
+    }
+
+    fn rows(buf: &Buffer) -> Vec<String> {
+        buf.to_string().lines().map(String::from).collect()
+    }
+
+    #[test]
+    fn test_render_fits_document() {
+        let text = document(16);
+        let mut viewport = ViewportState::new().content_length(16).viewport_height(4);
+        viewport.set_offset(8);
+        let mut buf = Buffer::empty(Rect::new(0, 0, 3, 2));
+        Minimap::new(&text)
+            .viewport(&viewport)
+            .hits([2])
+            .render(buf.area, &mut buf);
+        assert_eq!(rows(&buf), ["⡇⡇ ", "⡇⡇ "]);
+        assert_eq!(buf.get(0, 0).unwrap().style.bg, None);
+        assert_eq!(buf.get(2, 1).unwrap().style.bg, Some(Color::Gray));
+        assert_eq!(buf.get(1, 0).unwrap().style.fg, Some(Color::Yellow));
+        assert_eq!(buf.get(1, 1).unwrap().style.fg, None);
+    }
+
+    #[test]
+    fn test_scrolls_with_viewport() {
+        let text = Text::from(
+            (0..16)
+                .map(|i| Line::from(if i < 12 { "" } else { "x" }))
+                .collect::<Vec<_>>(),
+        );
+        let mut viewport = ViewportState::new().content_length(16).viewport_height(4);
+        viewport.set_offset(12);
+        let mut buf = Buffer::empty(Rect::new(0, 0, 1, 2));
+        Minimap::new(&text)
+            .lines_per_dot(1)
+            .viewport(&viewport)
+            .render(buf.area, &mut buf);
+        assert_eq!(rows(&buf), [" ", "⡇"]);
+        assert_eq!(buf.get(0, 1).unwrap().style.bg, Some(Color::Gray));
+    }
+
+    #[test]
+    fn test_click_to_jump() {
+        let text = document(16);
+        let mut viewport = ViewportState::new().viewport_height(4);
+        let area = Rect::new(10, 0, 3, 2);
+        let minimap = Minimap::new(&text).viewport(&viewport);
+        let click = |row| MouseEvent::new(MouseEventKind::Down(MouseButton::Left), 11, row);
+        assert!(minimap.handle_mouse(area, &click(1), &mut viewport));
+        assert_eq!(viewport.offset(), 10);
+        assert!(minimap.handle_mouse(area, &click(0), &mut viewport));
+        assert_eq!(viewport.offset(), 2);
+        let outside = MouseEvent::new(MouseEventKind::Down(MouseButton::Left), 0, 0);
+        assert!(!minimap.handle_mouse(area, &outside, &mut viewport));
+        let moved = MouseEvent::new(MouseEventKind::Moved, 11, 0);
+        assert!(!minimap.handle_mouse(area, &moved, &mut viewport));
+    }
+}
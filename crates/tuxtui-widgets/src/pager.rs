@@ -0,0 +1,177 @@
+//! Pagination helper for splitting large collections into pages.
+
+use alloc::format;
+use alloc::string::String;
+use core::ops::Range;
+use tuxtui_core::buffer::Buffer;
+use tuxtui_core::geometry::Rect;
+use tuxtui_core::style::Style;
+use tuxtui_core::util::truncate_string;
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Pagination over a collection of items.
+///
+/// Pages are zero-indexed; the label shown to users is one-indexed.
+/// An empty collection still has a single (empty) page.
+///
+/// # Example
+///
+/// ```
+/// use tuxtui_widgets::pager::Pager;
+///
+/// let mut pager = Pager::new(10, 25);
+/// assert_eq!(pager.page_count(), 3);
+/// assert_eq!(pager.range(), 0..10);
+///
+/// pager.next_page();
+/// pager.next_page();
+/// assert_eq!(pager.range(), 20..25);
+/// assert_eq!(pager.label(), "page 3 of 3");
+/// ```
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct Pager {
+    page_size: usize,
+    page: usize,
+    total: usize,
+}
+
+impl Pager {
+    /// Create a new pager on the first page.
+    ///
+    /// A page size of zero is treated as one.
+    #[must_use]
+    pub const fn new(page_size: usize, total: usize) -> Self {
+        Self {
+            page_size: if page_size == 0 { 1 } else { page_size },
+            page: 0,
+            total,
+        }
+    }
+
+    /// Set the current page, clamped to the last page.
+    #[must_use]
+    pub const fn with_page(mut self, page: usize) -> Self {
+        self.set_page(page);
+        self
+    }
+
+    /// Get the number of items per page.
+    #[must_use]
+    pub const fn page_size(&self) -> usize {
+        self.page_size
+    }
+
+    /// Get the current (zero-indexed) page.
+    #[must_use]
+    pub const fn page(&self) -> usize {
+        self.page
+    }
+
+    /// Get the total number of items.
+    #[must_use]
+    pub const fn total(&self) -> usize {
+        self.total
+    }
+
+    /// Get the number of pages.
+    #[must_use]
+    pub const fn page_count(&self) -> usize {
+        if self.total == 0 {
+            1
+        } else {
+            self.total.div_ceil(self.page_size)
+        }
+    }
+
+    /// Set the current page, clamped to the last page.
+    pub const fn set_page(&mut self, page: usize) {
+        let last = self.page_count() - 1;
+        self.page = if page > last { last } else { page };
+    }
+
+    /// Set the total number of items, keeping the current page in range.
+    pub const fn set_total(&mut self, total: usize) {
+        self.total = total;
+        self.set_page(self.page);
+    }
+
+    /// Move to the next page, stopping at the last one.
+    pub const fn next_page(&mut self) {
+        self.set_page(self.page.saturating_add(1));
+    }
+
+    /// Move to the previous page, stopping at the first one.
+    pub const fn prev_page(&mut self) {
+        self.page = self.page.saturating_sub(1);
+    }
+
+    /// Check if the current page is the first one.
+    #[must_use]
+    pub const fn is_first(&self) -> bool {
+        self.page == 0
+    }
+
+    /// Check if the current page is the last one.
+    #[must_use]
+    pub const fn is_last(&self) -> bool {
+        self.page + 1 >= self.page_count()
+    }
+
+    /// Get the range of item indices on the current page.
+    #[must_use]
+    pub fn range(&self) -> Range<usize> {
+        let start = (self.page * self.page_size).min(self.total);
+        let end = start.saturating_add(self.page_size).min(self.total);
+        start..end
+    }
+
+    /// Get the "page X of Y" label for the current page.
+    #[must_use]
+    pub fn label(&self) -> String {
+        format!("page {} of {}", self.page + 1, self.page_count())
+    }
+
+    /// Render the page label on the first line of `area`.
+    pub fn render_label(&self, area: Rect, buf: &mut Buffer, style: Style) {
+        if area.area() == 0 {
+            return;
+        }
+        let label = truncate_string(&self.label(), area.width as usize);
+        buf.set_string(area.left(), area.top(), &label, style);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_page_count() {
+        assert_eq!(Pager::new(10, 0).page_count(), 1);
+        assert_eq!(Pager::new(10, 10).page_count(), 1);
+        assert_eq!(Pager::new(10, 11).page_count(), 2);
+        assert_eq!(Pager::new(0, 3).page_count(), 3);
+    }
+
+    #[test]
+    fn test_navigation_is_clamped() {
+        let mut pager = Pager::new(5, 12);
+        pager.prev_page();
+        assert_eq!(pager.page(), 0);
+        assert!(pager.is_first());
+
+        pager.next_page();
+        pager.next_page();
+        pager.next_page();
+        assert_eq!(pager.page(), 2);
+        assert!(pager.is_last());
+        assert_eq!(pager.range(), 10..12);
+
+        pager.set_total(4);
+        assert_eq!(pager.page(), 0);
+        assert_eq!(pager.range(), 0..4);
+    }
+}
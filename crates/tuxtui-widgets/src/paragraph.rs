@@ -1,14 +1,14 @@
 //! Paragraph widget for rendering rich text with wrapping.
 
-use alloc::format;
-use alloc::string::{String, ToString};
+use crate::wrap::{Widths, wrap_chars, wrap_words};
 use alloc::vec::Vec;
 use tuxtui_core::buffer::Buffer;
 use tuxtui_core::geometry::{Alignment, Rect};
 use tuxtui_core::style::Style;
 use tuxtui_core::terminal::Widget;
-use tuxtui_core::text::{Line, Text};
-use unicode_width::UnicodeWidthStr;
+use tuxtui_core::text::{Line, Text, Truncate};
+use tuxtui_core::util::unicode;
+use unicode_segmentation::UnicodeSegmentation;
 
 /// Text wrapping strategy.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
@@ -61,6 +61,11 @@ pub struct Paragraph<'a> {
     text: Text<'a>,
     style: Style,
     wrap: Option<Wrap>,
+    truncate: Option<Truncate>,
+    ellipsis: &'static str,
+    trim: bool,
+    indent: (u16, u16),
+    tab_width: usize,
     scroll: Scroll,
     alignment: Alignment,
 }
@@ -73,6 +78,11 @@ impl<'a> Paragraph<'a> {
             text: text.into(),
             style: Style::default(),
             wrap: None,
+            truncate: None,
+            ellipsis: "…",
+            trim: false,
+            indent: (0, 0),
+            tab_width: 4,
             scroll: Scroll::default(),
             alignment: Alignment::Start,
         }
@@ -92,6 +102,65 @@ impl<'a> Paragraph<'a> {
         self
     }
 
+    /// Truncate lines that are too wide, marking the cut with an ellipsis.
+    ///
+    /// Only applies when the paragraph is not wrapped ([`Wrap::NoWrap`] or no
+    /// wrapping set).
+    #[must_use]
+    pub const fn truncate(mut self, truncate: Truncate) -> Self {
+        self.truncate = Some(truncate);
+        self
+    }
+
+    /// Set the ellipsis used by [`Paragraph::truncate`]. Defaults to "…".
+    #[must_use]
+    pub const fn ellipsis(mut self, ellipsis: &'static str) -> Self {
+        self.ellipsis = ellipsis;
+        self
+    }
+
+    /// Trim leading whitespace from wrapped lines.
+    ///
+    /// By default indentation is kept. Whitespace at a word-wrap point is
+    /// always dropped.
+    #[must_use]
+    pub const fn trim(mut self, trim: bool) -> Self {
+        self.trim = trim;
+        self
+    }
+
+    /// Indent the first line of each paragraph line by `first_line` columns
+    /// and the lines it wraps onto by `subsequent` columns.
+    ///
+    /// Use a larger `subsequent` indent for hanging bullet points, or equal
+    /// indents for block quotes. Alignment applies to the space after the
+    /// indent.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use tuxtui_widgets::paragraph::{Paragraph, Wrap};
+    ///
+    /// let bullet = Paragraph::new("- a bullet point that wraps")
+    ///     .wrap(Wrap::Word)
+    ///     .indent(0, 2);
+    /// ```
+    #[must_use]
+    pub const fn indent(mut self, first_line: u16, subsequent: u16) -> Self {
+        self.indent = (first_line, subsequent);
+        self
+    }
+
+    /// Set the distance between tab stops. Defaults to 4.
+    ///
+    /// Tabs are expanded to spaces before wrapping; a width of zero removes
+    /// them.
+    #[must_use]
+    pub const fn tab_width(mut self, tab_width: usize) -> Self {
+        self.tab_width = tab_width;
+        self
+    }
+
     /// Set the scroll offset.
     #[must_use]
     pub const fn scroll(mut self, scroll: Scroll) -> Self {
@@ -106,75 +175,75 @@ impl<'a> Paragraph<'a> {
         self
     }
 
-    fn wrap_lines(&self, lines: &[Line<'a>], width: u16) -> Vec<Line<'a>> {
-        let mut wrapped = Vec::new();
+    /// Get the number of lines the paragraph takes when rendered `width`
+    /// columns wide, including lines hidden by scrolling.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use tuxtui_widgets::paragraph::{Paragraph, Wrap};
+    ///
+    /// let paragraph = Paragraph::new("Hello world, this wraps").wrap(Wrap::Word);
+    /// assert_eq!(paragraph.line_count(11), 3);
+    /// ```
+    #[must_use]
+    pub fn line_count(&self, width: u16) -> usize {
+        self.measure(width).0
+    }
+
+    /// Measure the paragraph when rendered `width` columns wide.
+    ///
+    /// Returns the number of lines and the width of the widest line, which
+    /// can be used to size layout constraints to fit the text exactly.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use tuxtui_widgets::paragraph::{Paragraph, Wrap};
+    ///
+    /// let paragraph = Paragraph::new("Hello world, this wraps").wrap(Wrap::Word);
+    /// assert_eq!(paragraph.measure(11), (3, 11));
+    /// ```
+    #[must_use]
+    pub fn measure(&self, width: u16) -> (usize, usize) {
+        let lines = self.wrap_lines(&self.text.lines, width);
+        let max_width = lines
+            .iter()
+            .map(|(indent, line)| *indent as usize + line.width())
+            .max()
+            .unwrap_or(0);
+        (lines.len(), max_width)
+    }
 
+    /// Wrap the lines to `width`, pairing each with its indent.
+    fn wrap_lines(&self, lines: &[Line<'a>], width: u16) -> Vec<(u16, Line<'a>)> {
+        let (first_indent, indent) = self.indent;
+        let widths = Widths {
+            first: width.saturating_sub(first_indent) as usize,
+            rest: width.saturating_sub(indent) as usize,
+        };
+
+        let mut wrapped = Vec::new();
         for line in lines {
-            if let Some(wrap_mode) = self.wrap {
-                match wrap_mode {
-                    Wrap::NoWrap => {
-                        wrapped.push(line.clone());
-                    }
-                    Wrap::Word => {
-                        let line_text = format!("{line}");
-                        let words: Vec<String> = line_text
-                            .split_whitespace()
-                            .map(|s| s.to_string())
-                            .collect();
-                        let mut current_line = Line::new();
-                        let mut current_width = 0;
-
-                        for word in words {
-                            let word_width = word.width();
-                            if current_width + word_width + 1 > width as usize && current_width > 0
-                            {
-                                wrapped.push(current_line);
-                                current_line = Line::new();
-                                current_width = 0;
-                            }
-
-                            if current_width > 0 {
-                                current_line.push_span(" ".into());
-                                current_width += 1;
-                            }
-                            current_line.push_span(word.into());
-                            current_width += word_width;
-                        }
-
-                        if !current_line.spans.is_empty() {
-                            wrapped.push(current_line);
-                        }
-                    }
-                    Wrap::Char => {
-                        let line_text = format!("{line}");
-                        let mut current_line = Line::new();
-                        let mut current_width = 0;
-
-                        for grapheme in unicode_segmentation::UnicodeSegmentation::graphemes(
-                            line_text.as_str(),
-                            true,
-                        ) {
-                            let grapheme_width = grapheme.width();
-                            if current_width + grapheme_width > width as usize {
-                                wrapped.push(current_line);
-                                current_line = Line::new();
-                                current_width = 0;
-                            }
-
-                            current_line.push_span(grapheme.to_string().into());
-                            current_width += grapheme_width;
-                        }
-
-                        if !current_line.spans.is_empty() {
-                            wrapped.push(current_line);
-                        }
+            let line = &line.clone().expand_tabs(self.tab_width);
+            let pieces = match self.wrap {
+                Some(Wrap::Word) => wrap_words(line, widths, self.trim),
+                Some(Wrap::Char) => wrap_chars(line, widths, self.trim),
+                Some(Wrap::NoWrap) | None => match self.truncate {
+                    Some(truncate) => {
+                        alloc::vec![line.clone().truncated_with(
+                            widths.first,
+                            truncate,
+                            self.ellipsis
+                        )]
                     }
-                }
-            } else {
-                wrapped.push(line.clone());
+                    None => alloc::vec![line.clone()],
+                },
+            };
+            for (i, piece) in pieces.into_iter().enumerate() {
+                wrapped.push((if i == 0 { first_indent } else { indent }, piece));
             }
         }
-
         wrapped
     }
 }
@@ -186,7 +255,7 @@ impl Widget for Paragraph<'_> {
         }
 
         // Apply base style
-        buf.set_style(self.style);
+        buf.set_style(area, self.style);
 
         // Wrap lines if needed
         let lines = self.wrap_lines(&self.text.lines, area.width);
@@ -194,26 +263,39 @@ impl Widget for Paragraph<'_> {
         // Apply scroll offset
         let start_line = self.scroll.vertical as usize;
         let visible_lines = &lines[start_line.min(lines.len())..];
+        let text_style = self.style.patch(self.text.style);
 
         // Render lines
-        for (i, line) in visible_lines.iter().enumerate().take(area.height as usize) {
+        for (i, (indent, line)) in visible_lines.iter().enumerate().take(area.height as usize) {
             let y = area.top() + i as u16;
             let line_width = line.width();
+            let free = (area.width.saturating_sub(*indent) as usize).saturating_sub(line_width);
 
-            let x = match self.alignment {
-                Alignment::Start => area.left(),
-                Alignment::Center => {
-                    area.left() + (area.width.saturating_sub(line_width as u16)) / 2
-                }
-                Alignment::End => area.left() + area.width.saturating_sub(line_width as u16),
-            };
+            // Column of the next grapheme relative to the left of the area;
+            // negative while scrolled out of view.
+            let mut column = *indent as isize
+                + match self.alignment {
+                    Alignment::Start => 0,
+                    Alignment::Center => free / 2,
+                    Alignment::End => free,
+                } as isize
+                - self.scroll.horizontal as isize;
 
-            let mut current_x = x.saturating_sub(self.scroll.horizontal);
-            for span in &line.spans {
-                let span_style = self.style.patch(line.style).patch(span.style);
-                current_x = buf.set_string(current_x, y, &span.content, span_style);
-                if current_x >= area.right() {
-                    break;
+            let line_style = text_style.patch(line.style);
+            'spans: for span in &line.to_visual().spans {
+                let span_style = line_style.patch(span.style);
+                for grapheme in span.content.graphemes(true) {
+                    let grapheme_width = unicode::grapheme_width(grapheme) as isize;
+                    if grapheme_width == 0 {
+                        continue;
+                    }
+                    if column + grapheme_width > area.width as isize {
+                        break 'spans;
+                    }
+                    if column >= 0 {
+                        buf.set(area.left() + column as u16, y, grapheme, span_style);
+                    }
+                    column += grapheme_width;
                 }
             }
         }
@@ -224,6 +306,7 @@ impl Widget for Paragraph<'_> {
 mod tests {
     use super::*;
     use tuxtui_core::style::Color;
+    use tuxtui_core::text::Span;
 
     #[test]
     fn test_paragraph_creation() {
@@ -232,6 +315,111 @@ mod tests {
         assert_eq!(paragraph.alignment, Alignment::Start);
     }
 
+    #[test]
+    fn test_measure() {
+        let paragraph = Paragraph::new("one two\nthree four five");
+        assert_eq!(paragraph.measure(5), (2, 15));
+
+        let paragraph = paragraph.wrap(Wrap::Word);
+        assert_eq!(paragraph.measure(10), (3, 10));
+        assert_eq!(paragraph.line_count(5), 5);
+    }
+
+    fn row(buf: &Buffer, y: u16) -> alloc::string::String {
+        (0..buf.area.width)
+            .map(|x| buf.get(x, y).unwrap())
+            .filter(|cell| !cell.skip)
+            .map(|cell| cell.symbol())
+            .collect()
+    }
+
+    #[test]
+    fn test_word_wrap_keeps_styles_and_indent() {
+        let red = Style::default().fg(Color::Red);
+        let text = Text::from(Line::from(alloc::vec![
+            Span::raw("  one "),
+            Span::styled("two three", red),
+        ]));
+        let mut buf = Buffer::empty(Rect::new(0, 0, 9, 2));
+        Paragraph::new(text)
+            .wrap(Wrap::Word)
+            .render(buf.area, &mut buf);
+
+        assert_eq!(row(&buf, 0), "  one two");
+        assert_eq!(row(&buf, 1), "three    ");
+        assert_eq!(buf.get(2, 0).unwrap().style.fg, None);
+        assert_eq!(buf.get(6, 0).unwrap().style.fg, Some(Color::Red));
+        assert_eq!(buf.get(0, 1).unwrap().style.fg, Some(Color::Red));
+    }
+
+    #[test]
+    fn test_hanging_indent() {
+        let paragraph = Paragraph::new("- one two three")
+            .wrap(Wrap::Word)
+            .indent(1, 3);
+        assert_eq!(paragraph.measure(10), (2, 10));
+
+        let mut buf = Buffer::empty(Rect::new(0, 0, 10, 2));
+        paragraph.render(buf.area, &mut buf);
+        assert_eq!(row(&buf, 0), " - one two");
+        assert_eq!(row(&buf, 1), "   three  ");
+
+        let mut buf = Buffer::empty(Rect::new(0, 0, 10, 2));
+        Paragraph::new("ab")
+            .indent(4, 0)
+            .alignment(Alignment::Center)
+            .render(buf.area, &mut buf);
+        assert_eq!(row(&buf, 0), "      ab  ");
+    }
+
+    #[test]
+    fn test_truncate() {
+        let mut buf = Buffer::empty(Rect::new(0, 0, 6, 1));
+        Paragraph::new("你好世界")
+            .truncate(Truncate::Tail)
+            .render(buf.area, &mut buf);
+        assert_eq!(row(&buf, 0), "你好… ");
+    }
+
+    #[test]
+    fn test_wide_characters_are_aligned() {
+        let mut buf = Buffer::empty(Rect::new(0, 0, 7, 1));
+        for (alignment, expected) in [
+            (Alignment::Start, "你好   "),
+            (Alignment::Center, " 你好  "),
+            (Alignment::End, "   你好"),
+        ] {
+            buf.clear();
+            Paragraph::new("你好")
+                .alignment(alignment)
+                .render(buf.area, &mut buf);
+            assert_eq!(row(&buf, 0), expected);
+        }
+    }
+
+    #[test]
+    fn test_wide_characters_are_not_split() {
+        let mut buf = Buffer::empty(Rect::new(0, 0, 5, 3));
+        Paragraph::new("你好世界🦀")
+            .wrap(Wrap::Char)
+            .render(buf.area, &mut buf);
+
+        assert_eq!(row(&buf, 0), "你好 ");
+        assert_eq!(row(&buf, 1), "世界 ");
+        assert_eq!(row(&buf, 2), "🦀   ");
+    }
+
+    #[test]
+    fn test_tabs_expand_to_tab_stops() {
+        let mut buf = Buffer::empty(Rect::new(0, 0, 10, 2));
+        Paragraph::new("a\tb\nab\tc").render(buf.area, &mut buf);
+        assert_eq!(row(&buf, 0), "a   b     ");
+        assert_eq!(row(&buf, 1), "ab  c     ");
+
+        let paragraph = Paragraph::new("\tx").tab_width(8);
+        assert_eq!(paragraph.measure(20), (1, 9));
+    }
+
     #[test]
     fn test_paragraph_wrap() {
         let text = Text::from("Hello world this is a long line");
@@ -0,0 +1,580 @@
+//! Plot widget for streaming time series, lighter than a full chart.
+
+use alloc::collections::VecDeque;
+use alloc::format;
+use alloc::string::String;
+use alloc::vec;
+use alloc::vec::Vec;
+use core::time::Duration;
+use tuxtui_core::buffer::Buffer;
+use tuxtui_core::geometry::Rect;
+use tuxtui_core::style::Style;
+use tuxtui_core::symbols::{self, braille};
+use tuxtui_core::terminal::Widget;
+use tuxtui_core::text::{Line, Span};
+use unicode_width::UnicodeWidthStr;
+
+/// A series of timestamped values kept in a ring buffer.
+///
+/// Once full, pushing a value drops the oldest one.
+///
+/// # Example
+///
+/// ```
+/// use std::time::Duration;
+/// use tuxtui_widgets::plot::TimeSeries;
+///
+/// let mut cpu = TimeSeries::new("cpu", 2);
+/// cpu.push(Duration::from_secs(1), 10.0);
+/// cpu.push(Duration::from_secs(2), 20.0);
+/// cpu.push(Duration::from_secs(3), 30.0);
+/// assert_eq!(cpu.len(), 2);
+/// assert_eq!(cpu.last(), Some((Duration::from_secs(3), 30.0)));
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct TimeSeries {
+    name: String,
+    points: VecDeque<(Duration, f64)>,
+    capacity: usize,
+    style: Style,
+}
+
+impl TimeSeries {
+    /// Create an empty series keeping up to `capacity` values, at least 1.
+    #[must_use]
+    pub fn new(name: impl Into<String>, capacity: usize) -> Self {
+        let capacity = capacity.max(1);
+        Self {
+            name: name.into(),
+            points: VecDeque::with_capacity(capacity),
+            capacity,
+            style: Style::new(),
+        }
+    }
+
+    /// Set the style of the series and its legend entry.
+    #[must_use]
+    pub const fn style(mut self, style: Style) -> Self {
+        self.style = style;
+        self
+    }
+
+    /// Add a value at `time`, such as the time since the application
+    /// started. Values are expected in time order.
+    pub fn push(&mut self, time: Duration, value: f64) {
+        if self.points.len() == self.capacity {
+            self.points.pop_front();
+        }
+        self.points.push_back((time, value));
+    }
+
+    /// Remove all values.
+    pub fn clear(&mut self) {
+        self.points.clear();
+    }
+
+    /// Get the name.
+    #[must_use]
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// Get the values with their times, oldest first.
+    pub fn points(&self) -> impl Iterator<Item = (Duration, f64)> + '_ {
+        self.points.iter().copied()
+    }
+
+    /// Get the newest value.
+    #[must_use]
+    pub fn last(&self) -> Option<(Duration, f64)> {
+        self.points.back().copied()
+    }
+
+    /// Get the number of values.
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.points.len()
+    }
+
+    /// Check whether there are no values.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.points.is_empty()
+    }
+}
+
+/// How a [`Plot`] draws its series.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum PlotMarker {
+    /// Braille dots, 2 by 4 per cell
+    #[default]
+    Braille,
+    /// Half blocks, 1 by 2 per cell, for fonts without braille
+    Block,
+}
+
+impl PlotMarker {
+    /// Get the dots per cell, horizontally and vertically.
+    const fn resolution(self) -> (usize, usize) {
+        match self {
+            Self::Braille => (2, 4),
+            Self::Block => (1, 2),
+        }
+    }
+
+    /// Get the symbol of a cell with the dots of `bits`.
+    fn symbol(self, bits: u8) -> String {
+        match self {
+            Self::Braille => String::from(braille::char_from_bits(bits)),
+            Self::Block => String::from(match bits {
+                0b01 => symbols::HALF_UPPER,
+                0b10 => symbols::HALF_LOWER,
+                0b11 => symbols::BAR_FULL,
+                _ => " ",
+            }),
+        }
+    }
+
+    /// Get the bit of the dot at `(dx, dy)` within a cell.
+    const fn bit(self, dx: usize, dy: usize) -> u8 {
+        match self {
+            Self::Braille => match (dx, dy) {
+                (0, 3) => 6,
+                (1, 3) => 7,
+                (0, dy) => dy as u8,
+                (_, dy) => 3 + dy as u8,
+            },
+            Self::Block => dy as u8,
+        }
+    }
+}
+
+/// Dots drawn by the series, with the style of the last series in each cell.
+struct Grid {
+    marker: PlotMarker,
+    width: usize,
+    height: usize,
+    cells: Vec<(u8, Option<Style>)>,
+    columns: usize,
+}
+
+impl Grid {
+    fn new(marker: PlotMarker, columns: u16, rows: u16) -> Self {
+        let (dx, dy) = marker.resolution();
+        let columns = usize::from(columns);
+        Self {
+            marker,
+            width: columns * dx,
+            height: usize::from(rows) * dy,
+            cells: vec![(0, None); columns * usize::from(rows)],
+            columns,
+        }
+    }
+
+    fn set(&mut self, x: i64, y: i64, style: Style) {
+        let (Ok(x), Ok(y)) = (usize::try_from(x), usize::try_from(y)) else {
+            return;
+        };
+        if x >= self.width || y >= self.height {
+            return;
+        }
+        let (dx, dy) = self.marker.resolution();
+        let cell = &mut self.cells[y / dy * self.columns + x / dx];
+        cell.0 |= 1 << self.marker.bit(x % dx, y % dy);
+        cell.1 = Some(style);
+    }
+
+    /// Draw a line of dots from one point to another.
+    fn line(&mut self, (x0, y0): (i64, i64), (x1, y1): (i64, i64), style: Style) {
+        let steps = (x1 - x0).abs().max((y1 - y0).abs()).max(1);
+        for step in 0..=steps {
+            let x = x0 + (x1 - x0) * step / steps;
+            let y = y0 + (y1 - y0) * step / steps;
+            self.set(x, y, style);
+        }
+    }
+}
+
+/// Round to the nearest integer.
+fn round(value: f64) -> i64 {
+    if value < 0.0 {
+        (value - 0.5) as i64
+    } else {
+        (value + 0.5) as i64
+    }
+}
+
+/// Format an axis value compactly, such as `12.5` or `3.2k`.
+fn format_value(value: f64) -> String {
+    let magnitude = if value < 0.0 { -value } else { value };
+    if magnitude >= 1_000_000.0 {
+        format!("{:.1}M", value / 1_000_000.0)
+    } else if magnitude >= 10_000.0 {
+        format!("{:.1}k", value / 1000.0)
+    } else if magnitude >= 100.0 {
+        format!("{value:.0}")
+    } else {
+        format!("{value:.1}")
+    }
+}
+
+/// Format a time before the newest value, such as `-30s` or `-5m`.
+fn format_age(age: Duration) -> String {
+    let seconds = age.as_secs();
+    if seconds == 0 {
+        String::from("now")
+    } else if seconds < 120 {
+        format!("-{seconds}s")
+    } else if seconds < 7200 {
+        format!("-{}m", seconds / 60)
+    } else {
+        format!("-{}h", seconds / 3600)
+    }
+}
+
+/// A plot of streaming time series, for dashboards.
+///
+/// The x-axis ends at the newest value and is labeled with the time before
+/// it. The y-axis scales to the values shown, with some headroom, unless
+/// bounds are set. Several series share the plot, with a legend naming
+/// them in their colors.
+///
+/// # Example
+///
+/// ```
+/// use std::time::Duration;
+/// use tuxtui_core::prelude::*;
+/// use tuxtui_widgets::plot::{Plot, TimeSeries};
+///
+/// let mut rx = TimeSeries::new("rx", 300).style(Style::default().fg(Color::Green));
+/// let mut tx = TimeSeries::new("tx", 300).style(Style::default().fg(Color::Blue));
+/// for second in 0..60 {
+///     rx.push(Duration::from_secs(second), (second % 10) as f64);
+///     tx.push(Duration::from_secs(second), (second % 7) as f64);
+/// }
+///
+/// let series = [rx, tx];
+/// let mut buf = Buffer::empty(Rect::new(0, 0, 40, 10));
+/// Plot::new(&series)
+///     .window(Duration::from_secs(30))
+///     .render(buf.area, &mut buf);
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct Plot<'a> {
+    series: &'a [TimeSeries],
+    window: Option<Duration>,
+    y_bounds: Option<[f64; 2]>,
+    headroom: f64,
+    marker: PlotMarker,
+    show_legend: bool,
+    style: Style,
+    axis_style: Style,
+}
+
+impl<'a> Plot<'a> {
+    /// Create a plot of `series`.
+    #[must_use]
+    pub const fn new(series: &'a [TimeSeries]) -> Self {
+        Self {
+            series,
+            window: None,
+            y_bounds: None,
+            headroom: 0.1,
+            marker: PlotMarker::Braille,
+            show_legend: true,
+            style: Style::new(),
+            axis_style: Style::new(),
+        }
+    }
+
+    /// Set how far back from the newest value to show. By default all
+    /// values are shown.
+    #[must_use]
+    pub const fn window(mut self, window: Duration) -> Self {
+        self.window = Some(window);
+        self
+    }
+
+    /// Set fixed y-axis bounds instead of scaling to the values.
+    #[must_use]
+    pub const fn y_bounds(mut self, bounds: [f64; 2]) -> Self {
+        self.y_bounds = Some(bounds);
+        self
+    }
+
+    /// Set the room left above and below the values when scaling, as a
+    /// fraction of their range. Defaults to 0.1.
+    #[must_use]
+    pub const fn headroom(mut self, headroom: f64) -> Self {
+        self.headroom = if headroom > 0.0 { headroom } else { 0.0 };
+        self
+    }
+
+    /// Set how the series are drawn.
+    #[must_use]
+    pub const fn marker(mut self, marker: PlotMarker) -> Self {
+        self.marker = marker;
+        self
+    }
+
+    /// Set whether to show the legend when there are several series.
+    #[must_use]
+    pub const fn show_legend(mut self, show: bool) -> Self {
+        self.show_legend = show;
+        self
+    }
+
+    /// Set the overall style.
+    #[must_use]
+    pub const fn style(mut self, style: Style) -> Self {
+        self.style = style;
+        self
+    }
+
+    /// Set the style of the axes and their labels.
+    #[must_use]
+    pub const fn axis_style(mut self, style: Style) -> Self {
+        self.axis_style = style;
+        self
+    }
+
+    /// Get the time range shown.
+    fn time_bounds(&self) -> Option<(Duration, Duration)> {
+        let end = self
+            .series
+            .iter()
+            .filter_map(TimeSeries::last)
+            .map(|p| p.0)
+            .max()?;
+        let start = match self.window {
+            Some(window) => end.saturating_sub(window),
+            None => self
+                .series
+                .iter()
+                .filter_map(|series| series.points.front())
+                .map(|p| p.0)
+                .min()
+                .unwrap_or(end),
+        };
+        Some((start, end))
+    }
+
+    /// Get the y-axis bounds for the values from `start`.
+    fn value_bounds(&self, start: Duration) -> [f64; 2] {
+        if let Some(bounds) = self.y_bounds {
+            return bounds;
+        }
+        let values = self
+            .series
+            .iter()
+            .flat_map(TimeSeries::points)
+            .filter(|&(time, value)| time >= start && value.is_finite())
+            .map(|(_, value)| value);
+        let Some((min, max)) = values.fold(None, |bounds: Option<(f64, f64)>, value| {
+            Some(bounds.map_or((value, value), |(min, max)| {
+                (min.min(value), max.max(value))
+            }))
+        }) else {
+            return [0.0, 1.0];
+        };
+        let range = if max > min { max - min } else { 1.0 };
+        let lower = min - range * self.headroom;
+        // Don't dip below zero for values that never do.
+        let lower = if min >= 0.0 { lower.max(0.0) } else { lower };
+        [lower, max + range * self.headroom]
+    }
+}
+
+impl Widget for Plot<'_> {
+    fn render(self, area: Rect, buf: &mut Buffer) {
+        if area.area() == 0 {
+            return;
+        }
+        buf.set_style(area, self.style);
+        let Some((start, end)) = self.time_bounds() else {
+            return;
+        };
+        let [y_min, y_max] = self.value_bounds(start);
+
+        // The y labels, the axis, then the plot; the x-axis and its labels
+        // take the last two rows.
+        let labels = [format_value(y_max), format_value(y_min)];
+        let label_width = labels.iter().map(|l| l.width()).max().unwrap_or(0) as u16;
+        let axis_x = area.left() + label_width;
+        let axis_y = area.bottom().saturating_sub(2);
+        if axis_x + 1 >= area.right() || axis_y <= area.top() {
+            return;
+        }
+        let plot = Rect::new(
+            axis_x + 1,
+            area.top(),
+            area.right() - axis_x - 1,
+            axis_y - area.top(),
+        );
+
+        let axis_style = self.style.patch(self.axis_style);
+        for y in plot.top()..plot.bottom() {
+            buf.set(axis_x, y, symbols::NORMAL.vertical, axis_style);
+        }
+        buf.set(axis_x, axis_y, symbols::NORMAL.bottom_left, axis_style);
+        for x in plot.left()..plot.right() {
+            buf.set(x, axis_y, symbols::NORMAL.horizontal, axis_style);
+        }
+        let [top_label, bottom_label] = &labels;
+        buf.set_string(area.left(), plot.top(), top_label, axis_style);
+        buf.set_string(area.left(), plot.bottom() - 1, bottom_label, axis_style);
+
+        let label_y = axis_y + 1;
+        if label_y < area.bottom() {
+            let span = end - start;
+            let oldest = format_age(span);
+            buf.set_string(plot.left(), label_y, &oldest, axis_style);
+            // Labels are left out where they would run into each other.
+            let oldest_end = plot.left() + oldest.width() as u16;
+            let newest_x = plot.right().saturating_sub(3);
+            let middle = format_age(span / 2);
+            let middle_x = (plot.left() + plot.width / 2).saturating_sub(middle.width() as u16 / 2);
+            if span.as_secs() >= 2
+                && middle_x > oldest_end
+                && middle_x + (middle.width() as u16) < newest_x
+            {
+                buf.set_string(middle_x, label_y, &middle, axis_style);
+            }
+            if newest_x > oldest_end {
+                buf.set_string(newest_x, label_y, "now", axis_style);
+            }
+        }
+
+        // Map the values onto the dots.
+        let mut grid = Grid::new(self.marker, plot.width, plot.height);
+        let seconds = (end - start).as_secs_f64();
+        let x_scale = (grid.width - 1) as f64;
+        let y_scale = (grid.height - 1) as f64;
+        let y_range = if y_max > y_min { y_max - y_min } else { 1.0 };
+        for series in self.series {
+            let style = self.style.patch(series.style);
+            // Start from the value before the window, for a line entering it.
+            let first = series
+                .points
+                .partition_point(|p| p.0 < start)
+                .saturating_sub(1);
+            let mut previous = None;
+            for (time, value) in series.points.iter().skip(first) {
+                if !value.is_finite() {
+                    previous = None;
+                    continue;
+                }
+                let x = if seconds > 0.0 {
+                    (time.as_secs_f64() - start.as_secs_f64()) / seconds * x_scale
+                } else {
+                    x_scale
+                };
+                let y = (1.0 - (value - y_min) / y_range) * y_scale;
+                let point = (round(x), round(y));
+                match previous {
+                    Some(previous) => grid.line(previous, point, style),
+                    None => grid.set(point.0, point.1, style),
+                }
+                previous = Some(point);
+            }
+        }
+        for (i, &(bits, style)) in grid.cells.iter().enumerate() {
+            if let Some(style) = style {
+                let x = plot.left() + (i % grid.columns) as u16;
+                let y = plot.top() + (i / grid.columns) as u16;
+                buf.set(x, y, self.marker.symbol(bits), style);
+            }
+        }
+
+        if self.show_legend && self.series.len() > 1 {
+            let width = self
+                .series
+                .iter()
+                .map(|s| s.name.width())
+                .max()
+                .unwrap_or(0)
+                + 2;
+            let width = width as u16;
+            if width <= plot.width {
+                let x = plot.right() - width;
+                for (y, series) in (plot.top()..plot.bottom()).zip(self.series) {
+                    let line = Line::from(vec![
+                        Span::styled(symbols::BULLET, self.style.patch(series.style)),
+                        Span::styled(format!(" {}", series.name), self.style),
+                    ]);
+                    buf.set_string(x, y, &" ".repeat(usize::from(width)), self.style);
+                    buf.set_line(x, y, &line, width);
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tuxtui_core::style::Color;
+
+    fn series(name: &str, values: &[f64]) -> TimeSeries {
+        let mut series = TimeSeries::new(name, 100);
+        for (second, &value) in (0..).zip(values) {
+            series.push(Duration::from_secs(second), value);
+        }
+        series
+    }
+
+    fn rows(buf: &Buffer) -> Vec<String> {
+        buf.to_string().lines().map(String::from).collect()
+    }
+
+    #[test]
+    fn test_scaling() {
+        let data = [series("a", &[10.0, 20.0, 30.0])];
+        let plot = Plot::new(&data);
+        assert_eq!(
+            plot.time_bounds(),
+            Some((Duration::ZERO, Duration::from_secs(2)))
+        );
+        assert_eq!(plot.value_bounds(Duration::ZERO), [8.0, 32.0]);
+        // Only the values in the window count.
+        let plot = plot.window(Duration::from_secs(1));
+        assert_eq!(plot.value_bounds(Duration::from_secs(1)), [19.0, 31.0]);
+        assert_eq!(
+            plot.y_bounds([0.0, 5.0]).value_bounds(Duration::ZERO),
+            [0.0, 5.0]
+        );
+
+        let data = [series("b", &[-1.0, 1.0])];
+        assert_eq!(Plot::new(&data).value_bounds(Duration::ZERO), [-1.2, 1.2]);
+    }
+
+    #[test]
+    fn test_render_blocks() {
+        let data = [series("a", &[0.0, 1.0, 2.0, 3.0])];
+        let mut buf = Buffer::empty(Rect::new(0, 0, 8, 4));
+        Plot::new(&data)
+            .marker(PlotMarker::Block)
+            .y_bounds([0.0, 3.0])
+            .render(buf.area, &mut buf);
+        assert_eq!(
+            rows(&buf),
+            ["3.0│  ▄▀", "0.0│▄▀  ", "   └────", "    -3s ",]
+        );
+    }
+
+    #[test]
+    fn test_legend() {
+        let red = Style::default().fg(Color::Red);
+        let data = [
+            series("in", &[1.0, 2.0]).style(red),
+            series("out", &[2.0, 1.0]),
+        ];
+        let mut buf = Buffer::empty(Rect::new(0, 0, 20, 6));
+        Plot::new(&data).render(buf.area, &mut buf);
+        let text = rows(&buf);
+        assert!(text[0].ends_with("● in "));
+        assert!(text[1].ends_with("● out"));
+        assert_eq!(buf.get(15, 0).unwrap().style.fg, Some(Color::Red));
+        assert!(text[5].ends_with("now"));
+    }
+}
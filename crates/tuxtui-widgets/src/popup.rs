@@ -1,28 +1,86 @@
 //! Popup and modal widgets.
 
-use tuxtui_core::buffer::Buffer;
-use tuxtui_core::geometry::{Alignment, Rect};
-use tuxtui_core::style::Style;
+use tuxtui_core::buffer::{BlendMode, Buffer};
+use tuxtui_core::geometry::{Position, Rect};
+use tuxtui_core::style::{Color, Style};
 use tuxtui_core::terminal::Widget;
 use tuxtui_core::text::Text;
 
-/// A popup widget that centers content over the background.
+/// Where a [`Popup`] is placed.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Anchor {
+    /// Centered in the available area
+    #[default]
+    Center,
+    /// In the top-right corner of the available area
+    TopRight,
+    /// In the bottom-left corner of the available area
+    BottomLeft,
+    /// Below the given cursor position, starting at its column
+    Cursor(Position),
+    /// Below the given area, such as an input field, aligned to its left edge
+    Relative(Rect),
+}
+
+/// The size of a popup along one axis.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Size {
+    Percent(u16),
+    Fixed(u16),
+}
+
+impl Size {
+    fn resolve(self, available: u16) -> u16 {
+        match self {
+            Self::Percent(percent) => (u32::from(available) * u32::from(percent) / 100) as u16,
+            Self::Fixed(length) => length.min(available),
+        }
+    }
+}
+
+/// Place a run of `length` cells inside `lo..hi`, preferably starting at
+/// `start`, else ending at `end`, else as close to `start` as fits.
+fn flip(start: u16, end: u16, length: u16, lo: u16, hi: u16) -> u16 {
+    if start >= lo && start.saturating_add(length) <= hi {
+        start
+    } else if end <= hi && end.saturating_sub(length) >= lo && end >= length {
+        end - length
+    } else {
+        start.min(hi.saturating_sub(length)).max(lo)
+    }
+}
+
+/// A popup widget that places content over the background.
+///
+/// Popups are centered by default. An [`Anchor`] places them in a corner,
+/// next to the cursor or below another widget instead; anchored popups flip
+/// to the other side of their anchor when they would leave the screen.
 ///
 /// # Example
 ///
 /// ```
 /// use tuxtui_core::prelude::*;
-/// use tuxtui_widgets::popup::Popup;
-/// use tuxtui_widgets::block::{Block, BorderType};
+/// use tuxtui_widgets::popup::{Anchor, Popup};
+///
+/// let screen = Rect::new(0, 0, 80, 24);
 ///
 /// let popup = Popup::new()
 ///     .percent_x(50)
 ///     .percent_y(50);
+/// assert_eq!(popup.area(screen), Rect::new(20, 6, 40, 12));
+///
+/// // A dropdown near the bottom of the screen opens upward.
+/// let dropdown = Popup::new()
+///     .width(20)
+///     .height(5)
+///     .anchor(Anchor::Cursor(Position::new(10, 22)));
+/// assert_eq!(dropdown.area(screen), Rect::new(10, 17, 20, 5));
 /// ```
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct Popup {
-    percent_x: u16,
-    percent_y: u16,
+    width: Size,
+    height: Size,
+    anchor: Anchor,
     clear_background: bool,
     background_style: Option<Style>,
 }
@@ -30,8 +88,9 @@ pub struct Popup {
 impl Default for Popup {
     fn default() -> Self {
         Self {
-            percent_x: 60,
-            percent_y: 50,
+            width: Size::Percent(60),
+            height: Size::Percent(50),
+            anchor: Anchor::Center,
             clear_background: true,
             background_style: None,
         }
@@ -48,14 +107,35 @@ impl Popup {
     /// Set the horizontal size as percentage of the screen.
     #[must_use]
     pub fn percent_x(mut self, percent: u16) -> Self {
-        self.percent_x = percent.min(100);
+        self.width = Size::Percent(percent.min(100));
         self
     }
 
     /// Set the vertical size as percentage of the screen.
     #[must_use]
     pub fn percent_y(mut self, percent: u16) -> Self {
-        self.percent_y = percent.min(100);
+        self.height = Size::Percent(percent.min(100));
+        self
+    }
+
+    /// Set a fixed width, limited to the width of the screen.
+    #[must_use]
+    pub const fn width(mut self, width: u16) -> Self {
+        self.width = Size::Fixed(width);
+        self
+    }
+
+    /// Set a fixed height, limited to the height of the screen.
+    #[must_use]
+    pub const fn height(mut self, height: u16) -> Self {
+        self.height = Size::Fixed(height);
+        self
+    }
+
+    /// Set where the popup is placed.
+    #[must_use]
+    pub const fn anchor(mut self, anchor: Anchor) -> Self {
+        self.anchor = anchor;
         self
     }
 
@@ -73,14 +153,30 @@ impl Popup {
         self
     }
 
-    /// Calculate the centered area for the popup.
+    /// Calculate the area of the popup within the screen.
     #[must_use]
     pub fn area(&self, full_area: Rect) -> Rect {
-        let width = (full_area.width * self.percent_x) / 100;
-        let height = (full_area.height * self.percent_y) / 100;
-
-        let x = full_area.x + (full_area.width.saturating_sub(width)) / 2;
-        let y = full_area.y + (full_area.height.saturating_sub(height)) / 2;
+        let width = self.width.resolve(full_area.width);
+        let height = self.height.resolve(full_area.height);
+        let (left, right) = (full_area.left(), full_area.right());
+        let (top, bottom) = (full_area.top(), full_area.bottom());
+
+        let (x, y) = match self.anchor {
+            Anchor::Center => (
+                left + (full_area.width - width) / 2,
+                top + (full_area.height - height) / 2,
+            ),
+            Anchor::TopRight => (right - width, top),
+            Anchor::BottomLeft => (left, bottom - height),
+            Anchor::Cursor(cursor) => (
+                flip(cursor.x, cursor.x.saturating_add(1), width, left, right),
+                flip(cursor.y.saturating_add(1), cursor.y, height, top, bottom),
+            ),
+            Anchor::Relative(target) => (
+                flip(target.left(), target.right(), width, left, right),
+                flip(target.bottom(), target.top(), height, top, bottom),
+            ),
+        };
 
         Rect::new(x, y, width, height)
     }
@@ -102,6 +198,101 @@ impl Popup {
     }
 }
 
+/// A backdrop that dims everything in its area, drawn below a modal.
+///
+/// By default the content below stays readable and is dimmed. It can instead
+/// be tinted toward a color or covered with a shade pattern, and an extra
+/// style can be patched on top of either.
+///
+/// # Example
+///
+/// ```
+/// use tuxtui_core::prelude::*;
+/// use tuxtui_widgets::popup::{Backdrop, Modal, Popup};
+///
+/// let area = Rect::new(0, 0, 40, 12);
+/// let mut buf = Buffer::empty(area);
+/// buf.set_string(0, 0, "background", Style::default());
+///
+/// Backdrop::new().render(area, &mut buf);
+/// let popup = Popup::new().area(area);
+/// # #[cfg(all(feature = "block", feature = "paragraph"))]
+/// Modal::new("Confirm", "Are you sure?").render(popup, &mut buf);
+///
+/// assert!(buf.get(0, 0).unwrap().style.add_modifier.contains(Modifier::DIM));
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Backdrop<'a> {
+    mode: BlendMode,
+    shade: Option<&'a str>,
+    style: Style,
+}
+
+impl Default for Backdrop<'_> {
+    fn default() -> Self {
+        Self {
+            mode: BlendMode::Dim,
+            shade: None,
+            style: Style::new(),
+        }
+    }
+}
+
+impl<'a> Backdrop<'a> {
+    /// Create a dimming backdrop.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Set how the content below is restyled.
+    #[must_use]
+    pub const fn mode(mut self, mode: BlendMode) -> Self {
+        self.mode = mode;
+        self
+    }
+
+    /// Tint the content below toward `color`, with an `alpha` of 0 to 255.
+    #[must_use]
+    pub const fn tint(self, color: Color, alpha: u8) -> Self {
+        self.mode(BlendMode::Tint { color, alpha })
+    }
+
+    /// Cover the content with a shade symbol, such as
+    /// [`SHADE_LIGHT`](tuxtui_core::symbols::SHADE_LIGHT).
+    #[must_use]
+    pub const fn shade(mut self, symbol: &'a str) -> Self {
+        self.shade = Some(symbol);
+        self
+    }
+
+    /// Set a style patched onto every cell of the backdrop.
+    #[must_use]
+    pub const fn style(mut self, style: Style) -> Self {
+        self.style = style;
+        self
+    }
+}
+
+impl Widget for Backdrop<'_> {
+    fn render(self, area: Rect, buf: &mut Buffer) {
+        let area = area.intersection(buf.area);
+        if area.area() == 0 {
+            return;
+        }
+        if let Some(symbol) = self.shade {
+            for y in area.top()..area.bottom() {
+                for x in area.left()..area.right() {
+                    buf.set(x, y, symbol, Style::default());
+                }
+            }
+        } else {
+            buf.blend(&Buffer::transparent(area), self.mode);
+        }
+        buf.set_style(area, self.style);
+    }
+}
+
 /// A modal dialog widget.
 ///
 /// # Example
@@ -201,6 +392,8 @@ impl<'a> Modal<'a> {
     }
 }
 
+/// Drawing a modal needs the `block` and `paragraph` widgets.
+#[cfg(all(feature = "block", feature = "paragraph"))]
 impl Widget for Modal<'_> {
     fn render(self, area: Rect, buf: &mut Buffer) {
         if area.area() == 0 {
@@ -209,6 +402,8 @@ impl Widget for Modal<'_> {
 
         use crate::block::{Block, BorderType};
         use crate::paragraph::Paragraph;
+        use tuxtui_core::geometry::Alignment;
+        use tuxtui_core::util::unicode;
 
         // Render border
         let block = Block::default()
@@ -259,16 +454,18 @@ impl Widget for Modal<'_> {
                     self.button_style
                 };
 
-                // Render button background
-                for i in 0..button_width {
-                    if x + i < button_area.right() {
-                        buf.set(x + i, button_y, " ", button_style);
-                    }
+                // Draw through a view of the button, so that buttons and
+                // text that do not fit are clipped.
+                let button = Rect::new(x, button_y, button_width, 1).intersection(button_area);
+                let mut view = buf.view_mut(button);
+                for i in 0..view.width() {
+                    view.set(i, 0, " ", button_style);
                 }
 
                 // Center button text
-                let text_start = x + (button_width.saturating_sub(button_text.len() as u16)) / 2;
-                buf.set_string(text_start, button_y, button_text, button_style);
+                let text_width = u16::try_from(unicode::width(button_text)).unwrap_or(u16::MAX);
+                let text_start = button_width.saturating_sub(text_width) / 2;
+                view.set_string(text_start, 0, button_text, button_style);
             }
         }
     }
@@ -277,6 +474,8 @@ impl Widget for Modal<'_> {
 #[cfg(test)]
 mod tests {
     use super::*;
+    #[cfg(all(feature = "block", feature = "paragraph"))]
+    use alloc::string::ToString;
 
     #[test]
     fn test_popup_area() {
@@ -290,6 +489,105 @@ mod tests {
         assert_eq!(popup_area.y, 12);
     }
 
+    #[test]
+    fn test_popup_anchors() {
+        let screen = Rect::new(0, 0, 40, 20);
+        let popup = Popup::new().width(10).height(4);
+
+        assert_eq!(popup.area(screen), Rect::new(15, 8, 10, 4));
+        assert_eq!(
+            popup.clone().anchor(Anchor::TopRight).area(screen),
+            Rect::new(30, 0, 10, 4)
+        );
+        assert_eq!(
+            popup.clone().anchor(Anchor::BottomLeft).area(screen),
+            Rect::new(0, 16, 10, 4)
+        );
+        assert_eq!(
+            popup.clone().width(100).area(screen),
+            Rect::new(0, 8, 40, 4)
+        );
+    }
+
+    #[test]
+    fn test_popup_flips_to_stay_on_screen() {
+        let screen = Rect::new(0, 0, 40, 20);
+        let popup = Popup::new().width(10).height(4);
+        let at_cursor = |x, y| {
+            popup
+                .clone()
+                .anchor(Anchor::Cursor(Position::new(x, y)))
+                .area(screen)
+        };
+
+        assert_eq!(at_cursor(5, 5), Rect::new(5, 6, 10, 4));
+        // Flipped above the cursor and left of it.
+        assert_eq!(at_cursor(35, 18), Rect::new(26, 14, 10, 4));
+
+        let input = Rect::new(32, 2, 6, 1);
+        assert_eq!(
+            popup.clone().anchor(Anchor::Relative(input)).area(screen),
+            Rect::new(28, 3, 10, 4)
+        );
+
+        // Too tall for either side, so it is clamped to the screen.
+        let tall = popup
+            .height(15)
+            .anchor(Anchor::Cursor(Position::new(0, 10)));
+        assert_eq!(tall.area(screen), Rect::new(0, 5, 10, 15));
+    }
+
+    #[test]
+    fn test_backdrop_dims_and_shades() {
+        use tuxtui_core::style::Modifier;
+        use tuxtui_core::symbols::SHADE_LIGHT;
+
+        let area = Rect::new(0, 0, 4, 2);
+        let mut buf = Buffer::empty(area);
+        buf.set_string(0, 0, "text", Style::default().bg(Color::Rgb(200, 200, 200)));
+        Backdrop::new()
+            .tint(Color::Rgb(0, 0, 0), 128)
+            .render(Rect::new(0, 0, 2, 1), &mut buf);
+        assert_eq!(buf.get(0, 0).unwrap().symbol(), "t");
+        assert_eq!(
+            buf.get(0, 0).unwrap().style.bg,
+            Some(Color::Rgb(100, 100, 100))
+        );
+        assert_eq!(
+            buf.get(2, 0).unwrap().style.bg,
+            Some(Color::Rgb(200, 200, 200))
+        );
+
+        Backdrop::new()
+            .shade(SHADE_LIGHT)
+            .style(Style::default().fg(Color::Gray))
+            .render(area, &mut buf);
+        let cell = buf.get(3, 1).unwrap();
+        assert_eq!(cell.symbol(), SHADE_LIGHT);
+        assert_eq!(cell.style.fg, Some(Color::Gray));
+        assert!(!cell.style.add_modifier.contains(Modifier::DIM));
+    }
+
+    #[cfg(all(feature = "block", feature = "paragraph"))]
+    #[test]
+    fn test_modal_centers_wide_button_text() {
+        let mut buf = Buffer::empty(Rect::new(0, 0, 14, 7));
+        Modal::new("确认", "")
+            .buttons(&["确定"])
+            .render(buf.area, &mut buf);
+        assert_eq!(buf.to_string().lines().nth(4), Some("│   确定     │"));
+    }
+
+    #[cfg(all(feature = "block", feature = "paragraph"))]
+    #[test]
+    fn test_modal_buttons_are_clipped() {
+        let mut buf = Buffer::from_lines(&["...................."; 7]);
+        Modal::new("Quit", "")
+            .buttons(&["Save and quit", "Cancel"])
+            .render(Rect::new(0, 0, 12, 7), &mut buf);
+        assert_eq!(buf.to_string().lines().nth(4), Some("│Save and q│........"));
+    }
+
     #[test]
     fn test_modal_button_navigation() {
         let mut modal = Modal::new("Test", "Content").buttons(&["Yes", "No", "Cancel"]);
@@ -3,29 +3,35 @@
 //! # Example
 //!
 //! ```
+//! # #[cfg(all(feature = "block", feature = "paragraph"))] {
 //! use tuxtui_widgets::prelude::*;
 //!
 //! let block = Block::default().borders(BorderType::All);
 //! let paragraph = Paragraph::new("Hello!");
+//! # }
 //! ```
 
 #[cfg(feature = "block")]
 pub use crate::block::{Block, BorderType, Borders, Title, TitlePosition};
+#[cfg(feature = "block")]
+pub use crate::window::{Window, WindowFlags, WindowManager, Windows};
 
 #[cfg(feature = "paragraph")]
 pub use crate::paragraph::{Paragraph, Scroll, Wrap};
 
 #[cfg(feature = "list")]
-pub use crate::list::{List, ListItem, ListMarker, ListState};
+pub use crate::list::{List, ListDirection, ListItem, ListMarker, ListState};
 
 #[cfg(feature = "table")]
 pub use crate::table::{Row, Table, TableState};
 
 #[cfg(feature = "tabs")]
-pub use crate::tabs::Tabs;
+pub use crate::tabs::{TabView, TabViewState, Tabs};
 
 #[cfg(feature = "gauge")]
 pub use crate::gauge::Gauge;
+#[cfg(feature = "gauge")]
+pub use crate::progress::{MultiProgress, MultiProgressState, ProgressTask, TaskStatus};
 
 #[cfg(feature = "barchart")]
 pub use crate::barchart::{Bar, BarChart};
@@ -33,15 +39,36 @@ pub use crate::barchart::{Bar, BarChart};
 #[cfg(feature = "sparkline")]
 pub use crate::sparkline::Sparkline;
 
+#[cfg(feature = "chart")]
+pub use crate::candlestick::{Candle, CandleMarker, CandlestickChart, CandlestickState};
 #[cfg(feature = "chart")]
 pub use crate::chart::{Chart, DataPoint, Dataset};
+#[cfg(feature = "chart")]
+pub use crate::plot::{Plot, PlotMarker, TimeSeries};
+#[cfg(feature = "chart")]
+pub use crate::timeline::{Timeline, TimelineItem, TimelineState};
 
+#[cfg(feature = "scrollbar")]
+pub use crate::minimap::Minimap;
+#[cfg(feature = "scrollbar")]
+pub use crate::scroll_view::{ScrollView, ScrollViewState, ScrollbarVisibility};
 #[cfg(feature = "scrollbar")]
 pub use crate::scrollbar::{Scrollbar, ScrollbarOrientation};
 
 #[cfg(feature = "canvas")]
 pub use crate::canvas::{Canvas, CanvasContext, Shape};
+#[cfg(feature = "canvas")]
+pub use crate::graph::{Graph, GraphNode, GraphState};
 
-pub use crate::input::{InputState, TextInput};
-pub use crate::popup::{Modal, Popup};
+pub use crate::breadcrumbs::Breadcrumbs;
+pub use crate::form::{Field, FieldError, FieldValue, Form, FormState, FormValues};
+pub use crate::help::{HelpBar, HelpOverlay, KeyHint};
+pub use crate::hex::{HexView, HexViewState, ReadAt};
+pub use crate::input::{Autocomplete, AutocompleteState, InputFilter, InputState, TextInput};
+pub use crate::masked::{MaskedInput, MaskedInputState, SlotKind};
+pub use crate::pager::Pager;
+pub use crate::popup::{Anchor, Backdrop, Modal, Popup};
+pub use crate::search::{SearchMatch, SearchState};
+pub use crate::slider::{Slider, SliderState};
+pub use crate::status::{StatusLine, StatusSegment};
 pub use crate::tree::{Tree, TreeNode, TreeState, TreeSymbols};
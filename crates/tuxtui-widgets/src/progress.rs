@@ -0,0 +1,625 @@
+//! Multi-progress widget stacking named progress bars, such as downloads.
+
+use crate::gauge::Gauge;
+use alloc::collections::VecDeque;
+use alloc::format;
+use alloc::string::String;
+use alloc::vec::Vec;
+use core::time::Duration;
+use tuxtui_core::buffer::Buffer;
+use tuxtui_core::geometry::Rect;
+use tuxtui_core::style::{Color, Style};
+use tuxtui_core::terminal::Widget;
+use tuxtui_core::text::{Line, Span};
+use unicode_width::UnicodeWidthStr;
+
+/// How far back rates are measured.
+const RATE_WINDOW: Duration = Duration::from_secs(5);
+
+/// The width of the percentage, rate and time left columns.
+const DETAILS_WIDTH: u16 = 21;
+
+/// How long each spinner frame is shown.
+const SPINNER_INTERVAL: Duration = Duration::from_millis(100);
+
+/// The default spinner frames.
+pub const SPINNER_DOTS: &[&str] = &["⠋", "⠙", "⠹", "⠸", "⠼", "⠴", "⠦", "⠧", "⠇", "⠏"];
+
+/// Spinner frames for terminals limited to ASCII.
+pub const SPINNER_ASCII: &[&str] = &["|", "/", "-", "\\"];
+
+/// Where a task of a [`MultiProgressState`] is at.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum TaskStatus {
+    /// Still going
+    #[default]
+    Running,
+    /// Completed
+    Finished,
+    /// Stopped with an error
+    Failed,
+}
+
+/// A task tracked by a [`MultiProgressState`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ProgressTask {
+    name: String,
+    position: u64,
+    length: Option<u64>,
+    status: TaskStatus,
+    message: Option<String>,
+    /// Positions over the last [`RATE_WINDOW`], with when they were reached.
+    samples: VecDeque<(Duration, u64)>,
+}
+
+impl ProgressTask {
+    /// Get the name.
+    #[must_use]
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// Get the position, such as the number of bytes downloaded.
+    #[must_use]
+    pub const fn position(&self) -> u64 {
+        self.position
+    }
+
+    /// Get the length, or `None` if unknown.
+    #[must_use]
+    pub const fn length(&self) -> Option<u64> {
+        self.length
+    }
+
+    /// Get the status.
+    #[must_use]
+    pub const fn status(&self) -> TaskStatus {
+        self.status
+    }
+
+    /// Get the message shown in place of the rate, if any.
+    #[must_use]
+    pub fn message(&self) -> Option<&str> {
+        self.message.as_deref()
+    }
+
+    /// Get how much is done, from 0.0 to 1.0, or `None` if the length is
+    /// unknown.
+    #[must_use]
+    pub fn ratio(&self) -> Option<f64> {
+        match self.length {
+            _ if self.status == TaskStatus::Finished => Some(1.0),
+            Some(0) => Some(1.0),
+            Some(length) => Some((self.position as f64 / length as f64).min(1.0)),
+            None => None,
+        }
+    }
+}
+
+/// State for a [`MultiProgress`], holding its tasks and the time.
+///
+/// The state has no clock of its own, so it also works without `std`: call
+/// [`MultiProgressState::tick`] with the time passed, typically once per
+/// frame. Rates are measured over the last five seconds of updates.
+///
+/// # Example
+///
+/// ```
+/// use std::time::Duration;
+/// use tuxtui_widgets::progress::MultiProgressState;
+///
+/// let mut state = MultiProgressState::new();
+/// let task = state.add("linux.tar.xz", Some(1000));
+/// state.tick(Duration::from_secs(2));
+/// state.set_position(task, 400);
+/// assert_eq!(state.rate(task), Some(200.0));
+/// assert_eq!(state.eta(task), Some(Duration::from_secs(3)));
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct MultiProgressState {
+    tasks: Vec<ProgressTask>,
+    elapsed: Duration,
+}
+
+impl MultiProgressState {
+    /// Create an empty state.
+    #[must_use]
+    pub const fn new() -> Self {
+        Self {
+            tasks: Vec::new(),
+            elapsed: Duration::ZERO,
+        }
+    }
+
+    /// Add a task of `length` units, or of unknown length, and get its
+    /// index.
+    pub fn add(&mut self, name: impl Into<String>, length: Option<u64>) -> usize {
+        self.tasks.push(ProgressTask {
+            name: name.into(),
+            position: 0,
+            length,
+            status: TaskStatus::Running,
+            message: None,
+            samples: VecDeque::from([(self.elapsed, 0)]),
+        });
+        self.tasks.len() - 1
+    }
+
+    /// Get the tasks, in the order they were added.
+    #[must_use]
+    pub fn tasks(&self) -> &[ProgressTask] {
+        &self.tasks
+    }
+
+    /// Get a task by index.
+    #[must_use]
+    pub fn task(&self, index: usize) -> Option<&ProgressTask> {
+        self.tasks.get(index)
+    }
+
+    /// Remove all tasks.
+    pub fn clear(&mut self) {
+        self.tasks.clear();
+    }
+
+    /// Get the time passed, as told by [`MultiProgressState::tick`].
+    #[must_use]
+    pub const fn elapsed(&self) -> Duration {
+        self.elapsed
+    }
+
+    /// Advance the time by `delta`, animating spinners and letting rates
+    /// of stalled tasks drop.
+    pub fn tick(&mut self, delta: Duration) {
+        self.elapsed = self.elapsed.saturating_add(delta);
+        let now = self.elapsed;
+        for task in &mut self.tasks {
+            prune(&mut task.samples, now);
+        }
+    }
+
+    /// Set the position of a task.
+    pub fn set_position(&mut self, index: usize, position: u64) {
+        let now = self.elapsed;
+        if let Some(task) = self.tasks.get_mut(index) {
+            task.position = position;
+            task.samples.push_back((now, position));
+            prune(&mut task.samples, now);
+        }
+    }
+
+    /// Advance the position of a task by `delta`.
+    pub fn inc(&mut self, index: usize, delta: u64) {
+        if let Some(task) = self.tasks.get(index) {
+            self.set_position(index, task.position.saturating_add(delta));
+        }
+    }
+
+    /// Set the length of a task, or `None` if unknown.
+    pub fn set_length(&mut self, index: usize, length: Option<u64>) {
+        if let Some(task) = self.tasks.get_mut(index) {
+            task.length = length;
+        }
+    }
+
+    /// Set a message shown in place of the rate, such as "verifying".
+    pub fn set_message(&mut self, index: usize, message: Option<String>) {
+        if let Some(task) = self.tasks.get_mut(index) {
+            task.message = message;
+        }
+    }
+
+    /// Mark a task as completed.
+    pub fn finish(&mut self, index: usize) {
+        self.set_status(index, TaskStatus::Finished);
+    }
+
+    /// Mark a task as stopped with an error, shown as its message.
+    pub fn fail(&mut self, index: usize, error: impl Into<String>) {
+        self.set_message(index, Some(error.into()));
+        self.set_status(index, TaskStatus::Failed);
+    }
+
+    /// Set the status of a task.
+    pub fn set_status(&mut self, index: usize, status: TaskStatus) {
+        if let Some(task) = self.tasks.get_mut(index) {
+            task.status = status;
+        }
+    }
+
+    /// Check whether no task is running anymore.
+    #[must_use]
+    pub fn is_done(&self) -> bool {
+        self.tasks
+            .iter()
+            .all(|task| task.status != TaskStatus::Running)
+    }
+
+    /// Get the rate of a running task in units per second, measured over the
+    /// last five seconds.
+    #[must_use]
+    pub fn rate(&self, index: usize) -> Option<f64> {
+        let task = self.tasks.get(index)?;
+        if task.status != TaskStatus::Running {
+            return None;
+        }
+        let &(since, from) = task.samples.front()?;
+        let seconds = self.elapsed.saturating_sub(since).as_secs_f64();
+        if seconds <= 0.0 {
+            return None;
+        }
+        Some(task.position.saturating_sub(from) as f64 / seconds)
+    }
+
+    /// Get the estimated time left for a running task, if its length is
+    /// known and it is making progress.
+    #[must_use]
+    pub fn eta(&self, index: usize) -> Option<Duration> {
+        let rate = self.rate(index)?;
+        let task = &self.tasks[index];
+        let remaining = task.length?.saturating_sub(task.position);
+        if rate <= 0.0 {
+            return None;
+        }
+        Duration::try_from_secs_f64(remaining as f64 / rate).ok()
+    }
+}
+
+/// Drop samples older than the rate window, keeping the last one before it
+/// as the starting point.
+fn prune(samples: &mut VecDeque<(Duration, u64)>, now: Duration) {
+    let start = now.saturating_sub(RATE_WINDOW);
+    while samples.len() > 1 && samples[1].0 <= start {
+        samples.pop_front();
+    }
+}
+
+/// Format an amount with an SI suffix, such as `1.5M`.
+fn format_amount(amount: f64) -> String {
+    const SUFFIXES: [&str; 5] = ["", "k", "M", "G", "T"];
+    let mut amount = amount;
+    let mut suffix = 0;
+    while amount >= 1000.0 && suffix < SUFFIXES.len() - 1 {
+        amount /= 1000.0;
+        suffix += 1;
+    }
+    if suffix == 0 {
+        format!("{amount:.0}")
+    } else {
+        format!("{amount:.1}{}", SUFFIXES[suffix])
+    }
+}
+
+/// Format a duration as `m:ss`, or `h:mm:ss` from an hour.
+fn format_duration(duration: Duration) -> String {
+    let seconds = duration.as_secs();
+    let (hours, minutes, seconds) = (seconds / 3600, seconds / 60 % 60, seconds % 60);
+    if hours > 0 {
+        format!("{hours}:{minutes:02}:{seconds:02}")
+    } else {
+        format!("{minutes}:{seconds:02}")
+    }
+}
+
+/// Several named progress bars stacked one per row, like the download
+/// screen of a package manager.
+///
+/// Each row shows a spinner or status symbol, the name, a bar, and the
+/// percentage, rate and estimated time left. Tasks of unknown length show
+/// their position in place of the bar. When there are more tasks than rows,
+/// running tasks are shown first.
+///
+/// # Example
+///
+/// ```
+/// use std::time::Duration;
+/// use tuxtui_core::prelude::*;
+/// use tuxtui_widgets::progress::{MultiProgress, MultiProgressState};
+///
+/// let mut state = MultiProgressState::new();
+/// let kernel = state.add("kernel", Some(4096));
+/// let docs = state.add("docs", None);
+/// state.tick(Duration::from_millis(500));
+/// state.inc(kernel, 1024);
+/// state.inc(docs, 300);
+///
+/// let mut buf = Buffer::empty(Rect::new(0, 0, 50, 2));
+/// MultiProgress::new()
+///     .bar_style(Style::default().fg(Color::Cyan))
+///     .render_stateful(buf.area, &mut buf, &mut state);
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MultiProgress<'a> {
+    style: Style,
+    bar_style: Style,
+    finished_style: Style,
+    failed_style: Style,
+    spinner: &'a [&'a str],
+    use_unicode: bool,
+    hide_finished: bool,
+}
+
+impl Default for MultiProgress<'_> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<'a> MultiProgress<'a> {
+    /// Create a new multi-progress widget.
+    #[must_use]
+    pub const fn new() -> Self {
+        Self {
+            style: Style::new(),
+            bar_style: Style::new(),
+            finished_style: Style::new().fg(Color::Green),
+            failed_style: Style::new().fg(Color::Red),
+            spinner: SPINNER_DOTS,
+            use_unicode: true,
+            hide_finished: false,
+        }
+    }
+
+    /// Set the overall style.
+    #[must_use]
+    pub const fn style(mut self, style: Style) -> Self {
+        self.style = style;
+        self
+    }
+
+    /// Set the style of the filled part of the bars.
+    #[must_use]
+    pub const fn bar_style(mut self, style: Style) -> Self {
+        self.bar_style = style;
+        self
+    }
+
+    /// Set the style of the status symbol of finished tasks.
+    #[must_use]
+    pub const fn finished_style(mut self, style: Style) -> Self {
+        self.finished_style = style;
+        self
+    }
+
+    /// Set the style of the status symbol and message of failed tasks.
+    #[must_use]
+    pub const fn failed_style(mut self, style: Style) -> Self {
+        self.failed_style = style;
+        self
+    }
+
+    /// Set the spinner frames of running tasks.
+    #[must_use]
+    pub const fn spinner(mut self, frames: &'a [&'a str]) -> Self {
+        self.spinner = frames;
+        self
+    }
+
+    /// Set whether to draw with Unicode symbols (the default), or with
+    /// ASCII only.
+    #[must_use]
+    pub const fn use_unicode(mut self, use_unicode: bool) -> Self {
+        self.use_unicode = use_unicode;
+        if !use_unicode {
+            self.spinner = SPINNER_ASCII;
+        }
+        self
+    }
+
+    /// Set whether to leave out finished tasks.
+    #[must_use]
+    pub const fn hide_finished(mut self, hide: bool) -> Self {
+        self.hide_finished = hide;
+        self
+    }
+
+    /// Get the symbol before a task.
+    fn symbol(&self, status: TaskStatus, elapsed: Duration) -> (&'a str, Style) {
+        match status {
+            TaskStatus::Running if self.spinner.is_empty() => (" ", self.style),
+            TaskStatus::Running => {
+                let frame = elapsed.as_millis() / SPINNER_INTERVAL.as_millis();
+                let frame = (frame % self.spinner.len() as u128) as usize;
+                (self.spinner[frame], self.style)
+            }
+            TaskStatus::Finished if self.use_unicode => ("✓", self.finished_style),
+            TaskStatus::Finished => ("+", self.finished_style),
+            TaskStatus::Failed if self.use_unicode => ("✗", self.failed_style),
+            TaskStatus::Failed => ("x", self.failed_style),
+        }
+    }
+
+    /// Render the tasks with state.
+    pub fn render_stateful(mut self, area: Rect, buf: &mut Buffer, state: &mut MultiProgressState) {
+        if area.area() == 0 {
+            return;
+        }
+        if buf.settings.ascii_only {
+            self = self.use_unicode(false);
+        }
+        buf.set_style(area, self.style);
+
+        let mut rows: Vec<usize> = (0..state.tasks.len())
+            .filter(|&i| !(self.hide_finished && state.tasks[i].status == TaskStatus::Finished))
+            .collect();
+        // Keep running tasks in view, in their own order.
+        rows.sort_by_key(|&i| state.tasks[i].status != TaskStatus::Running);
+        rows.truncate(usize::from(area.height));
+        rows.sort_unstable();
+
+        let name_width = rows
+            .iter()
+            .map(|&i| state.tasks[i].name.width())
+            .max()
+            .unwrap_or(0);
+        let name_width = u16::try_from(name_width)
+            .unwrap_or(u16::MAX)
+            .min(area.width / 3);
+
+        for (y, index) in (area.top()..area.bottom()).zip(rows) {
+            self.render_task(area, y, name_width, state, index, buf);
+        }
+    }
+
+    /// Render the row of one task.
+    fn render_task(
+        &self,
+        area: Rect,
+        y: u16,
+        name_width: u16,
+        state: &MultiProgressState,
+        index: usize,
+        buf: &mut Buffer,
+    ) {
+        let task = &state.tasks[index];
+        // Spinners stand still for reduced motion.
+        let elapsed = if buf.settings.reduced_motion {
+            Duration::ZERO
+        } else {
+            state.elapsed
+        };
+        let (symbol, symbol_style) = self.symbol(task.status, elapsed);
+        buf.set_string(area.left(), y, symbol, symbol_style);
+        let mut x = area.left().saturating_add(2);
+        if x >= area.right() {
+            return;
+        }
+        buf.set_line(
+            x,
+            y,
+            &Line::from(task.name.as_str()),
+            name_width.min(area.right() - x),
+        );
+        x = x.saturating_add(name_width).saturating_add(1);
+        if x >= area.right() {
+            return;
+        }
+
+        // Percentage, then the rate or message, then the time left, in
+        // columns lined up across rows.
+        let details_x = area.right().saturating_sub(DETAILS_WIDTH).max(x);
+        let mut details = Vec::new();
+        details.push(Span::raw(task.ratio().map_or_else(
+            || String::from("     "),
+            |ratio| format!(" {:>3}%", (ratio * 100.0) as u8),
+        )));
+        match (&task.message, task.status) {
+            (Some(message), TaskStatus::Failed) => {
+                details.push(Span::styled(format!(" {message}"), self.failed_style));
+            }
+            (Some(message), _) => details.push(Span::raw(format!(" {message}"))),
+            (None, _) => {
+                if let Some(rate) = state.rate(index) {
+                    details.push(Span::raw(format!(" {:>6}/s", format_amount(rate))));
+                }
+                if let Some(eta) = state.eta(index) {
+                    details.push(Span::raw(format!(" {:>6}", format_duration(eta))));
+                }
+            }
+        }
+        buf.set_line(details_x, y, &Line::from(details), area.right() - details_x);
+
+        let bar = Rect::new(x, y, details_x.saturating_sub(x), 1);
+        match task.ratio() {
+            Some(ratio) => Gauge::new()
+                .ratio(ratio)
+                .style(self.style)
+                .gauge_style(self.bar_style)
+                .use_unicode(self.use_unicode)
+                .render(bar, buf),
+            None => {
+                let position = format_amount(task.position as f64);
+                buf.set_line(bar.x, y, &Line::from(position), bar.width);
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tuxtui_core::settings::UiSettings;
+
+    fn row(buf: &Buffer, y: u16) -> String {
+        let text = buf.to_string();
+        String::from(text.lines().nth(usize::from(y)).unwrap().trim_end())
+    }
+
+    #[test]
+    fn test_rate_and_eta() {
+        let mut state = MultiProgressState::new();
+        let task = state.add("a", Some(100));
+        assert_eq!(state.rate(task), None);
+
+        state.tick(Duration::from_secs(1));
+        state.set_position(task, 10);
+        state.tick(Duration::from_secs(1));
+        state.inc(task, 10);
+        assert_eq!(state.rate(task), Some(10.0));
+        assert_eq!(state.eta(task), Some(Duration::from_secs(8)));
+
+        // Stalled: only the last update before the window is kept.
+        state.tick(Duration::from_secs(10));
+        assert_eq!(state.task(task).unwrap().samples.len(), 1);
+        assert_eq!(state.rate(task), Some(0.0));
+        assert_eq!(state.eta(task), None);
+
+        state.finish(task);
+        assert_eq!(state.rate(task), None);
+        assert_eq!(state.task(task).unwrap().ratio(), Some(1.0));
+        assert!(state.is_done());
+    }
+
+    #[test]
+    fn test_render_rows() {
+        let mut state = MultiProgressState::new();
+        let done = state.add("done", Some(10));
+        let file = state.add("file", Some(1000));
+        let docs = state.add("docs", None);
+        let bad = state.add("bad", Some(10));
+        state.finish(done);
+        state.tick(Duration::from_secs(2));
+        state.set_position(file, 500);
+        state.set_position(docs, 1500);
+        state.fail(bad, "404");
+
+        let mut buf = Buffer::empty(Rect::new(0, 0, 40, 4));
+        MultiProgress::new().render_stateful(buf.area, &mut buf, &mut state);
+        assert_eq!(row(&buf, 0), "✓ done ████████████ 100%");
+        assert_eq!(row(&buf, 1), "⠋ file ██████        50%    250/s   0:02");
+        assert_eq!(row(&buf, 2), "⠋ docs 1.5k                 750/s");
+        assert_eq!(row(&buf, 3), "✗ bad                 0% 404");
+        assert_eq!(buf.get(0, 3).unwrap().style.fg, Some(Color::Red));
+    }
+
+    #[test]
+    fn test_running_tasks_stay_in_view() {
+        let mut state = MultiProgressState::new();
+        let first = state.add("first", Some(1));
+        state.add("second", Some(1));
+        state.finish(first);
+
+        let mut buf = Buffer::empty(Rect::new(0, 0, 20, 1));
+        MultiProgress::new()
+            .use_unicode(false)
+            .render_stateful(buf.area, &mut buf, &mut state);
+        assert!(row(&buf, 0).starts_with("| second"));
+
+        // The settings pick ASCII symbols and stop the spinner.
+        state.tick(Duration::from_millis(150));
+        let settings = UiSettings::new().ascii_only(true).reduced_motion(true);
+        let mut buf = Buffer::empty(Rect::new(0, 0, 20, 1)).with_settings(settings);
+        MultiProgress::new().render_stateful(buf.area, &mut buf, &mut state);
+        assert!(row(&buf, 0).starts_with("| second"));
+    }
+
+    #[test]
+    fn test_narrow_area() {
+        let mut state = MultiProgressState::new();
+        state.add("name", Some(10));
+
+        let mut buf = Buffer::empty(Rect::new(0, 0, 3, 1));
+        MultiProgress::new().render_stateful(buf.area, &mut buf, &mut state);
+        assert_eq!(row(&buf, 0), "⠋ n");
+    }
+}
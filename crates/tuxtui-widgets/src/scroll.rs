@@ -0,0 +1,116 @@
+//! Scroll offset calculation shared by the selectable widgets.
+
+/// Compute the scroll offset that keeps `selected` visible.
+///
+/// Up to `padding` items before and after the selection are kept visible
+/// too; the padding shrinks when the padded window would not fit in
+/// `height` rows. `item_height` gives the rows taken by each item, and
+/// `spacing` blank rows separate consecutive items.
+///
+/// The offset only moves when needed, so the selection can travel freely
+/// inside the viewport.
+pub(crate) fn scroll_offset(
+    offset: usize,
+    selected: usize,
+    len: usize,
+    padding: usize,
+    height: usize,
+    spacing: usize,
+    item_height: impl Fn(usize) -> usize,
+) -> usize {
+    if len == 0 || height == 0 {
+        return offset;
+    }
+    let selected = selected.min(len - 1);
+
+    // Check whether items `first..=last` fit in the viewport. Every item
+    // takes at least one row, so long ranges are rejected without measuring.
+    let fits = |first: usize, last: usize| {
+        if last - first >= height {
+            return false;
+        }
+        let mut used = 0;
+        for index in first..=last {
+            used += item_height(index);
+            if index < last {
+                used += spacing;
+            }
+            if used > height {
+                return false;
+            }
+        }
+        true
+    };
+
+    let mut padding = padding;
+    let (first, last) = loop {
+        let first = selected.saturating_sub(padding);
+        let last = selected.saturating_add(padding).min(len - 1);
+        if padding == 0 || fits(first, last) {
+            break (first, last);
+        }
+        padding -= 1;
+    };
+
+    if first < offset {
+        return first;
+    }
+    if fits(offset, last) {
+        return offset;
+    }
+
+    // Walk back from the end of the window to the first item that still fits.
+    let mut used = 0;
+    let mut new_offset = last;
+    for index in (offset..=last).rev() {
+        let mut rows = item_height(index);
+        if index < last {
+            rows += spacing;
+        }
+        if index < last && used + rows > height {
+            break;
+        }
+        used += rows;
+        new_offset = index;
+        if used >= height {
+            break;
+        }
+    }
+    new_offset
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_selection_hugs_edges_without_padding() {
+        assert_eq!(scroll_offset(0, 4, 100, 0, 5, 0, |_| 1), 0);
+        assert_eq!(scroll_offset(0, 5, 100, 0, 5, 0, |_| 1), 1);
+        assert_eq!(scroll_offset(10, 3, 100, 0, 5, 0, |_| 1), 3);
+    }
+
+    #[test]
+    fn test_padding_keeps_context_visible() {
+        assert_eq!(scroll_offset(0, 3, 100, 2, 5, 0, |_| 1), 1);
+        assert_eq!(scroll_offset(10, 11, 100, 2, 5, 0, |_| 1), 9);
+        // No padding is possible past the ends of the list.
+        assert_eq!(scroll_offset(0, 99, 100, 2, 5, 0, |_| 1), 95);
+        assert_eq!(scroll_offset(3, 0, 100, 2, 5, 0, |_| 1), 0);
+    }
+
+    #[test]
+    fn test_padding_shrinks_to_fit() {
+        // A padding of 3 needs 7 rows; only 1 item of padding fits in 4.
+        assert_eq!(scroll_offset(0, 5, 100, 3, 4, 0, |_| 1), 3);
+    }
+
+    #[test]
+    fn test_variable_heights() {
+        let heights = [1, 2, 3, 1];
+        let offset = scroll_offset(0, 2, 4, 0, 4, 0, |i| heights[i]);
+        assert_eq!(offset, 2);
+        let offset = scroll_offset(0, 1, 4, 0, 4, 1, |i| heights[i]);
+        assert_eq!(offset, 0);
+    }
+}
@@ -0,0 +1,405 @@
+//! Scroll view for making any widget composition scrollable.
+
+use crate::scrollbar::{Scrollbar, ScrollbarOrientation};
+use tuxtui_core::buffer::Buffer;
+use tuxtui_core::event::{Event, KeyCode, KeyEvent, MouseEventKind};
+use tuxtui_core::geometry::{Position, Rect};
+use tuxtui_core::style::Style;
+use tuxtui_core::terminal::Widget;
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// When a [`ScrollView`] shows its scrollbars.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ScrollbarVisibility {
+    /// Show a scrollbar only when the content overflows in its direction
+    #[default]
+    Automatic,
+    /// Always show both scrollbars
+    Always,
+    /// Never show scrollbars
+    Never,
+}
+
+/// State for a [`ScrollView`], holding the scroll offset.
+///
+/// The content and viewport sizes are recorded when the view is rendered,
+/// so the offset is clamped to the content from then on.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct ScrollViewState {
+    offset: Position,
+    content: (u16, u16),
+    viewport: (u16, u16),
+}
+
+impl ScrollViewState {
+    /// Create a new state scrolled to the top left.
+    #[must_use]
+    pub const fn new() -> Self {
+        Self {
+            offset: Position::new(0, 0),
+            content: (0, 0),
+            viewport: (0, 0),
+        }
+    }
+
+    /// Get the position of the content shown at the top left.
+    #[must_use]
+    pub const fn offset(&self) -> Position {
+        self.offset
+    }
+
+    /// Set the offset, clamped to the content once it is known.
+    pub fn set_offset(&mut self, offset: Position) {
+        self.offset = offset;
+        self.clamp();
+    }
+
+    /// Get the largest offset that still fills the viewport.
+    #[must_use]
+    pub const fn max_offset(&self) -> Position {
+        Position::new(
+            self.content.0.saturating_sub(self.viewport.0),
+            self.content.1.saturating_sub(self.viewport.1),
+        )
+    }
+
+    /// Scroll up by `lines`.
+    pub const fn scroll_up(&mut self, lines: u16) {
+        self.offset.y = self.offset.y.saturating_sub(lines);
+    }
+
+    /// Scroll down by `lines`.
+    pub fn scroll_down(&mut self, lines: u16) {
+        self.offset.y = self.offset.y.saturating_add(lines);
+        self.clamp();
+    }
+
+    /// Scroll left by `columns`.
+    pub const fn scroll_left(&mut self, columns: u16) {
+        self.offset.x = self.offset.x.saturating_sub(columns);
+    }
+
+    /// Scroll right by `columns`.
+    pub fn scroll_right(&mut self, columns: u16) {
+        self.offset.x = self.offset.x.saturating_add(columns);
+        self.clamp();
+    }
+
+    /// Scroll up by a page.
+    pub fn page_up(&mut self) {
+        self.scroll_up(self.viewport.1.max(1));
+    }
+
+    /// Scroll down by a page.
+    pub fn page_down(&mut self) {
+        self.scroll_down(self.viewport.1.max(1));
+    }
+
+    /// Scroll to the top.
+    pub const fn scroll_to_top(&mut self) {
+        self.offset.y = 0;
+    }
+
+    /// Scroll to the bottom.
+    pub const fn scroll_to_bottom(&mut self) {
+        self.offset.y = self.max_offset().y;
+    }
+
+    /// Handle a key press.
+    ///
+    /// The arrow keys scroll by one line or column, Page Up and Page Down by
+    /// a page, and Home and End jump to the top and bottom. Returns `true`
+    /// if the key was consumed.
+    pub fn handle_key(&mut self, key: &KeyEvent) -> bool {
+        if key.is_release() {
+            return false;
+        }
+        match key.code {
+            KeyCode::Up => self.scroll_up(1),
+            KeyCode::Down => self.scroll_down(1),
+            KeyCode::Left => self.scroll_left(1),
+            KeyCode::Right => self.scroll_right(1),
+            KeyCode::PageUp => self.page_up(),
+            KeyCode::PageDown => self.page_down(),
+            KeyCode::Home => self.scroll_to_top(),
+            KeyCode::End => self.scroll_to_bottom(),
+            _ => return false,
+        }
+        true
+    }
+
+    /// Scroll in response to a mouse wheel event.
+    ///
+    /// Each wheel tick moves the view by `lines_per_tick`, or by a page
+    /// while shift is held. Returns `true` for any wheel event.
+    pub fn handle_scroll(&mut self, event: &Event, lines_per_tick: u16) -> bool {
+        let Event::Mouse(mouse) = event else {
+            return false;
+        };
+        let (columns, lines) = if mouse.modifiers.shift {
+            (self.viewport.0.max(1), self.viewport.1.max(1))
+        } else {
+            (lines_per_tick, lines_per_tick)
+        };
+        match mouse.kind {
+            MouseEventKind::ScrollUp => self.scroll_up(lines),
+            MouseEventKind::ScrollDown => self.scroll_down(lines),
+            MouseEventKind::ScrollLeft => self.scroll_left(columns),
+            MouseEventKind::ScrollRight => self.scroll_right(columns),
+            _ => return false,
+        }
+        true
+    }
+
+    /// Clamp the offset to the recorded content and viewport sizes, if the
+    /// content is known.
+    fn clamp(&mut self) {
+        if self.content != (0, 0) {
+            self.fit();
+        }
+    }
+
+    /// Clamp the offset to the recorded content and viewport sizes, even
+    /// if the content is empty.
+    fn fit(&mut self) {
+        let max = self.max_offset();
+        self.offset = Position::new(self.offset.x.min(max.x), self.offset.y.min(max.y));
+    }
+}
+
+/// A canvas of any size whose visible window is drawn into a smaller area.
+///
+/// Widgets are rendered into the view's own buffer at content
+/// coordinates; rendering the view then copies the part selected by a
+/// [`ScrollViewState`] into the target area, with scrollbars on the right
+/// and bottom edges when the content overflows.
+///
+/// # Example
+///
+/// ```
+/// use tuxtui_core::prelude::*;
+/// use tuxtui_widgets::paragraph::Paragraph;
+/// use tuxtui_widgets::scroll_view::{ScrollView, ScrollViewState};
+///
+/// let mut view = ScrollView::new(40, 100);
+/// for row in 0..100 {
+///     let line = format!("row {row}");
+///     view.render_widget(Paragraph::new(line.as_str()), Rect::new(0, row, 40, 1));
+/// }
+///
+/// let mut state = ScrollViewState::new();
+/// let mut buf = Buffer::empty(Rect::new(0, 0, 20, 10));
+/// view.render_stateful(buf.area, &mut buf, &mut state);
+/// state.scroll_down(5);
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ScrollView {
+    buffer: Buffer,
+    scrollbars: ScrollbarVisibility,
+    scrollbar_style: Style,
+}
+
+impl ScrollView {
+    /// Create a view whose content is `width` by `height` cells.
+    #[must_use]
+    pub fn new(width: u16, height: u16) -> Self {
+        Self {
+            buffer: Buffer::empty(Rect::new(0, 0, width, height)),
+            scrollbars: ScrollbarVisibility::Automatic,
+            scrollbar_style: Style::new(),
+        }
+    }
+
+    /// Set when to show the scrollbars.
+    #[must_use]
+    pub const fn scrollbars(mut self, visibility: ScrollbarVisibility) -> Self {
+        self.scrollbars = visibility;
+        self
+    }
+
+    /// Set the scrollbar style.
+    #[must_use]
+    pub const fn scrollbar_style(mut self, style: Style) -> Self {
+        self.scrollbar_style = style;
+        self
+    }
+
+    /// Get the area of the content.
+    #[must_use]
+    pub const fn area(&self) -> Rect {
+        self.buffer.area
+    }
+
+    /// Get the content buffer for drawing directly.
+    pub const fn buffer_mut(&mut self) -> &mut Buffer {
+        &mut self.buffer
+    }
+
+    /// Render a widget into `area` of the content, clipped to the content.
+    pub fn render_widget<W: Widget>(&mut self, widget: W, area: Rect) {
+        self.buffer.view_mut(area).render_widget(widget);
+    }
+
+    /// Render the visible window of the content with state.
+    pub fn render_stateful(self, area: Rect, buf: &mut Buffer, state: &mut ScrollViewState) {
+        let content = self.buffer.area;
+        let (vertical, horizontal) = match self.scrollbars {
+            ScrollbarVisibility::Never => (false, false),
+            ScrollbarVisibility::Always => (true, true),
+            ScrollbarVisibility::Automatic => {
+                // One scrollbar can take the room that makes the other needed.
+                let mut vertical = content.height > area.height;
+                let horizontal = content.width > area.width.saturating_sub(u16::from(vertical));
+                vertical = content.height > area.height.saturating_sub(u16::from(horizontal));
+                (vertical, horizontal)
+            }
+        };
+        let viewport = Rect::new(
+            area.x,
+            area.y,
+            area.width.saturating_sub(u16::from(vertical)),
+            area.height.saturating_sub(u16::from(horizontal)),
+        );
+        state.content = (content.width, content.height);
+        state.viewport = (viewport.width, viewport.height);
+        state.fit();
+        if viewport.area() == 0 {
+            return;
+        }
+
+        let offset = state.offset;
+        for y in 0..viewport.height {
+            for x in 0..viewport.width {
+                let Some(cell) = self.buffer.get(offset.x + x, offset.y + y) else {
+                    continue;
+                };
+                let Some(target) = buf.get_mut(viewport.x + x, viewport.y + y) else {
+                    continue;
+                };
+                *target = cell.clone();
+                // Wide characters cut by either edge are blanked.
+                let cut_left = x == 0 && cell.skip;
+                let width = self.buffer.width_policy.grapheme_width(cell.symbol());
+                let cut_right = !cell.skip && usize::from(x) + width > usize::from(viewport.width);
+                if cut_left || cut_right {
+                    target.set_symbol(" ");
+                    target.skip = false;
+                }
+            }
+        }
+
+        if vertical {
+            Scrollbar::new()
+                .orientation(ScrollbarOrientation::Vertical)
+                .position(usize::from(offset.y))
+                .content_length(usize::from(content.height))
+                .viewport_length(usize::from(viewport.height))
+                .style(self.scrollbar_style)
+                .render(
+                    Rect::new(viewport.right(), viewport.y, 1, viewport.height),
+                    buf,
+                );
+        }
+        if horizontal {
+            Scrollbar::new()
+                .orientation(ScrollbarOrientation::Horizontal)
+                .position(usize::from(offset.x))
+                .content_length(usize::from(content.width))
+                .viewport_length(usize::from(viewport.width))
+                .style(self.scrollbar_style)
+                .render(
+                    Rect::new(viewport.x, viewport.bottom(), viewport.width, 1),
+                    buf,
+                );
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tuxtui_core::assert_buffer_eq;
+    use tuxtui_core::event::{KeyEventKind, MouseEvent};
+
+    fn numbered(width: u16, height: u16) -> ScrollView {
+        let mut view = ScrollView::new(width, height);
+        for y in 0..height {
+            let line = alloc::format!("{y}abcdefghij");
+            view.render_widget(line.as_str(), Rect::new(0, y, width, 1));
+        }
+        view
+    }
+
+    #[test]
+    fn test_visible_window() {
+        let mut state = ScrollViewState::new();
+        state.set_offset(Position::new(2, 50));
+        let mut buf = Buffer::empty(Rect::new(0, 0, 4, 2));
+        numbered(6, 4)
+            .scrollbars(ScrollbarVisibility::Never)
+            .render_stateful(buf.area, &mut buf, &mut state);
+        assert_eq!(state.offset(), Position::new(2, 2));
+        assert_buffer_eq!(buf, Buffer::from_lines(&["bcde", "bcde"]));
+    }
+
+    #[test]
+    fn test_empty_content_resets_the_offset() {
+        let mut state = ScrollViewState::new();
+        state.set_offset(Position::new(u16::MAX, u16::MAX));
+        let mut buf = Buffer::empty(Rect::new(0, 0, 4, 2));
+        ScrollView::new(0, 0).render_stateful(buf.area, &mut buf, &mut state);
+        assert_eq!(state.offset(), Position::new(0, 0));
+        assert_buffer_eq!(buf, Buffer::empty(Rect::new(0, 0, 4, 2)));
+    }
+
+    #[test]
+    fn test_automatic_scrollbars() {
+        let mut state = ScrollViewState::new();
+        let mut buf = Buffer::empty(Rect::new(0, 0, 5, 3));
+        numbered(4, 6).render_stateful(buf.area, &mut buf, &mut state);
+        let symbols: alloc::string::String =
+            (0..5).map(|x| buf.get(x, 0).unwrap().symbol()).collect();
+        assert!(symbols.starts_with("0abc"));
+        assert_ne!(buf.get(4, 0).unwrap().symbol(), " ");
+        assert_eq!(state.max_offset(), Position::new(0, 3));
+
+        let mut buf = Buffer::empty(Rect::new(0, 0, 5, 3));
+        numbered(4, 3).render_stateful(buf.area, &mut buf, &mut state);
+        assert_buffer_eq!(buf, Buffer::from_lines(&["0abc ", "1abc ", "2abc "]));
+    }
+
+    #[test]
+    fn test_wide_characters_cut_at_edges() {
+        let mut view = ScrollView::new(4, 1);
+        view.render_widget("你好", view.area());
+        let mut state = ScrollViewState::new();
+        state.set_offset(Position::new(1, 0));
+        let mut buf = Buffer::empty(Rect::new(0, 0, 2, 1));
+        view.render_stateful(buf.area, &mut buf, &mut state);
+        assert_buffer_eq!(buf, Buffer::from_lines(&["  "]));
+    }
+
+    #[test]
+    fn test_input() {
+        let mut state = ScrollViewState::new();
+        let mut buf = Buffer::empty(Rect::new(0, 0, 4, 4));
+        numbered(10, 20).render_stateful(buf.area, &mut buf, &mut state);
+        assert!(state.handle_key(&KeyCode::PageDown.into()));
+        assert_eq!(state.offset().y, 3);
+        assert!(state.handle_key(&KeyCode::End.into()));
+        assert_eq!(state.offset().y, 17);
+        let wheel = Event::Mouse(MouseEvent::new(MouseEventKind::ScrollRight, 0, 0));
+        assert!(state.handle_scroll(&wheel, 10));
+        assert_eq!(state.offset(), Position::new(7, 17));
+        assert!(!state.handle_key(&KeyCode::Enter.into()));
+
+        // Holding a key scrolls, releasing it does not.
+        let up = KeyEvent::from(KeyCode::Up);
+        assert!(state.handle_key(&up.kind(KeyEventKind::Repeat)));
+        assert!(!state.handle_key(&up.kind(KeyEventKind::Release)));
+        assert_eq!(state.offset().y, 16);
+    }
+}
@@ -5,6 +5,7 @@ use tuxtui_core::geometry::Rect;
 use tuxtui_core::style::Style;
 use tuxtui_core::symbols::{SCROLLBAR_DEFAULT, ScrollbarSymbols};
 use tuxtui_core::terminal::Widget;
+use tuxtui_core::viewport::ViewportState;
 
 /// Scrollbar orientation.
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
@@ -93,6 +94,25 @@ impl Scrollbar {
         self
     }
 
+    /// Set the position and lengths from a viewport.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use tuxtui_core::viewport::ViewportState;
+    /// use tuxtui_widgets::scrollbar::Scrollbar;
+    ///
+    /// let viewport = ViewportState::new().content_length(100).viewport_height(20);
+    /// let scrollbar = Scrollbar::new().viewport(&viewport);
+    /// ```
+    #[must_use]
+    pub const fn viewport(mut self, viewport: &ViewportState) -> Self {
+        self.position = viewport.offset();
+        self.content_length = viewport.content_len();
+        self.viewport_length = viewport.viewport_len();
+        self
+    }
+
     /// Set the style.
     #[must_use]
     pub const fn style(mut self, style: Style) -> Self {
@@ -113,6 +133,7 @@ impl Widget for Scrollbar {
         if area.area() == 0 || self.content_length == 0 {
             return;
         }
+        let symbols = buf.settings.scrollbar_symbols(self.symbols);
 
         match self.orientation {
             ScrollbarOrientation::Vertical => {
@@ -132,9 +153,9 @@ impl Widget for Scrollbar {
 
                 for y in 0..track_height {
                     let symbol = if y >= thumb_position && y < thumb_position + thumb_size {
-                        self.symbols.thumb
+                        symbols.thumb
                     } else {
-                        self.symbols.track
+                        symbols.track
                     };
                     buf.set(area.left(), area.top() + y as u16, symbol, self.style);
                 }
@@ -156,9 +177,9 @@ impl Widget for Scrollbar {
 
                 for x in 0..track_width {
                     let symbol = if x >= thumb_position && x < thumb_position + thumb_size {
-                        self.symbols.thumb
+                        symbols.thumb
                     } else {
-                        self.symbols.track
+                        symbols.track
                     };
                     buf.set(area.left() + x as u16, area.top(), symbol, self.style);
                 }
@@ -181,4 +202,23 @@ mod tests {
         assert_eq!(scrollbar.position, 10);
         assert_eq!(scrollbar.content_length, 100);
     }
+
+    #[test]
+    fn test_ascii_symbols() {
+        let scrollbar = Scrollbar::new().content_length(4).viewport_length(2);
+        let mut buf = Buffer::empty(Rect::new(0, 0, 1, 2));
+        scrollbar
+            .clone()
+            .symbols(ScrollbarSymbols::ascii())
+            .render(buf.area, &mut buf);
+        assert_eq!(buf, Buffer::from_lines(&["#", "|"]));
+
+        let mut buf = Buffer::empty(Rect::new(0, 0, 1, 2))
+            .with_settings(tuxtui_core::settings::UiSettings::new().ascii_only(true));
+        scrollbar.render(buf.area, &mut buf);
+        assert_eq!(
+            buf,
+            Buffer::from_lines(&["#", "|"]).with_settings(buf.settings)
+        );
+    }
 }
@@ -0,0 +1,411 @@
+//! Searching text content, highlighting and stepping through the matches.
+
+use alloc::borrow::Cow;
+use alloc::format;
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
+use core::ops::Range;
+use tuxtui_core::event::{KeyCode, KeyEvent};
+use tuxtui_core::narration::Describe;
+use tuxtui_core::style::{Color, Style};
+use tuxtui_core::text::{Line, Span, Text};
+
+/// A match of a search: a byte range within a line.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct SearchMatch {
+    /// The index of the line, list item or table row
+    pub line: usize,
+    /// The bytes of the line's text that match
+    pub range: Range<usize>,
+}
+
+/// The query and matches of a search through lines of text, such as a
+/// paragraph, the items of a list or the rows of a table.
+///
+/// Set the query, then [`update`](Self::update) the matches from the
+/// content whenever either changes. The current match is stepped through
+/// with [`next_match`](Self::next_match) and
+/// [`previous_match`](Self::previous_match), and its line can be scrolled
+/// into view. [`highlight_line`](Self::highlight_line) and
+/// [`highlight_text`](Self::highlight_text) style the matches for display.
+///
+/// # Example
+///
+/// ```
+/// use tuxtui_core::prelude::*;
+/// use tuxtui_widgets::search::SearchState;
+///
+/// let text = Text::from("error: disk full\nok\nerror: retrying");
+/// let mut search = SearchState::new();
+/// search.set_query("error");
+/// search.update_text(&text);
+/// assert_eq!(search.len(), 2);
+///
+/// search.next_match();
+/// assert_eq!(search.current().map(|m| m.line), Some(2));
+/// # #[cfg(feature = "paragraph")]
+/// let paragraph = tuxtui_widgets::paragraph::Paragraph::new(search.highlight_text(&text));
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SearchState {
+    query: String,
+    case_sensitive: bool,
+    matches: Vec<SearchMatch>,
+    current: Option<usize>,
+    match_style: Style,
+    current_style: Style,
+}
+
+impl Default for SearchState {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl SearchState {
+    /// Create a search with an empty query, ignoring case.
+    #[must_use]
+    pub const fn new() -> Self {
+        Self {
+            query: String::new(),
+            case_sensitive: false,
+            matches: Vec::new(),
+            current: None,
+            match_style: Style::new().fg(Color::Black).bg(Color::Yellow),
+            current_style: Style::new().fg(Color::Black).bg(Color::Cyan),
+        }
+    }
+
+    /// Set whether matches must have the same case as the query.
+    #[must_use]
+    pub const fn case_sensitive(mut self, case_sensitive: bool) -> Self {
+        self.case_sensitive = case_sensitive;
+        self
+    }
+
+    /// Set the style of matches.
+    #[must_use]
+    pub const fn match_style(mut self, style: Style) -> Self {
+        self.match_style = style;
+        self
+    }
+
+    /// Set the style of the current match.
+    #[must_use]
+    pub const fn current_style(mut self, style: Style) -> Self {
+        self.current_style = style;
+        self
+    }
+
+    /// Get the query.
+    #[must_use]
+    pub fn query(&self) -> &str {
+        &self.query
+    }
+
+    /// Set the query. The matches are kept until the next update.
+    pub fn set_query(&mut self, query: impl Into<String>) {
+        self.query = query.into();
+    }
+
+    /// Clear the query and the matches.
+    pub fn clear(&mut self) {
+        self.query.clear();
+        self.matches.clear();
+        self.current = None;
+    }
+
+    /// Find the matches of the query in `lines`.
+    ///
+    /// The current match moves to the first match at or after its line,
+    /// so refining the query keeps the search near where it was.
+    pub fn update<S: AsRef<str>>(&mut self, lines: impl IntoIterator<Item = S>) {
+        let from = self.current().map_or(0, |current| current.line);
+        self.matches.clear();
+        if !self.query.is_empty() {
+            for (line, text) in lines.into_iter().enumerate() {
+                let text = text.as_ref();
+                let mut start = 0;
+                while let Some(range) = self.find(text, start) {
+                    start = range.end;
+                    self.matches.push(SearchMatch { line, range });
+                }
+            }
+        }
+        self.current = match self.matches.iter().position(|m| m.line >= from) {
+            Some(index) => Some(index),
+            None if self.matches.is_empty() => None,
+            None => Some(0),
+        };
+    }
+
+    /// Find the matches of the query in the lines of `text`.
+    pub fn update_text(&mut self, text: &Text<'_>) {
+        self.update(text.lines.iter().map(ToString::to_string));
+    }
+
+    /// Find the next match in `text` from the byte `start`.
+    fn find(&self, text: &str, start: usize) -> Option<Range<usize>> {
+        let same = |a: char, b: char| {
+            a == b || (!self.case_sensitive && a.to_lowercase().eq(b.to_lowercase()))
+        };
+        text[start..].char_indices().find_map(|(offset, _)| {
+            let begin = start + offset;
+            let mut chars = text[begin..].char_indices();
+            for query in self.query.chars() {
+                match chars.next() {
+                    Some((_, c)) if same(c, query) => {}
+                    _ => return None,
+                }
+            }
+            let end = chars
+                .next()
+                .map_or(text.len(), |(offset, _)| begin + offset);
+            Some(begin..end)
+        })
+    }
+
+    /// Get the matches, in order.
+    #[must_use]
+    pub fn matches(&self) -> &[SearchMatch] {
+        &self.matches
+    }
+
+    /// Get the number of matches.
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.matches.len()
+    }
+
+    /// Check whether nothing matches.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.matches.is_empty()
+    }
+
+    /// Get the current match.
+    #[must_use]
+    pub fn current(&self) -> Option<&SearchMatch> {
+        self.matches.get(self.current?)
+    }
+
+    /// Get the position of the current match among all matches, from 0.
+    #[must_use]
+    pub const fn current_index(&self) -> Option<usize> {
+        self.current
+    }
+
+    /// Move to the next match, wrapping around, and get it.
+    pub fn next_match(&mut self) -> Option<&SearchMatch> {
+        let len = self.matches.len();
+        self.current = self.current.map(|current| (current + 1) % len);
+        self.current()
+    }
+
+    /// Move to the previous match, wrapping around, and get it.
+    pub fn previous_match(&mut self) -> Option<&SearchMatch> {
+        let len = self.matches.len();
+        self.current = self.current.map(|current| (current + len - 1) % len);
+        self.current()
+    }
+
+    /// Handle a key press.
+    ///
+    /// `n` moves to the next match and `N` to the previous one, as in
+    /// pagers. Returns `true` if the key was consumed.
+    pub fn handle_key(&mut self, key: &KeyEvent) -> bool {
+        if self.matches.is_empty() || key.is_release() {
+            return false;
+        }
+        match key.code {
+            KeyCode::Char('n') => self.next_match(),
+            KeyCode::Char('N') => self.previous_match(),
+            _ => return false,
+        };
+        true
+    }
+
+    /// Style the matches in the line at `index`, keeping the styles of its
+    /// spans elsewhere.
+    #[must_use]
+    pub fn highlight_line<'a>(&self, index: usize, line: &Line<'a>) -> Line<'a> {
+        let first = self.matches.partition_point(|m| m.line < index);
+        let matches = self.matches[first..]
+            .iter()
+            .take_while(|m| m.line == index)
+            .enumerate()
+            .map(|(i, m)| {
+                let style = if self.current == Some(first + i) {
+                    self.current_style
+                } else {
+                    self.match_style
+                };
+                (m.range.clone(), style)
+            });
+        let mut matches = matches.peekable();
+        if matches.peek().is_none() {
+            return line.clone();
+        }
+
+        let mut spans = Vec::with_capacity(line.spans.len() + 2);
+        let mut offset = 0;
+        for span in &line.spans {
+            let end = offset + span.content.len();
+            let mut at = offset;
+            while at < end {
+                // Skip matches ending before this point.
+                while matches.peek().is_some_and(|(range, _)| range.end <= at) {
+                    matches.next();
+                }
+                let (next, style) = match matches.peek() {
+                    Some((range, style)) if range.start <= at => {
+                        (range.end.min(end), span.style.patch(*style))
+                    }
+                    Some((range, _)) => (range.start.min(end), span.style),
+                    None => (end, span.style),
+                };
+                spans.push(Span::styled(
+                    slice(&span.content, at - offset..next - offset),
+                    style,
+                ));
+                at = next;
+            }
+            offset = end;
+        }
+        Line {
+            spans,
+            alignment: line.alignment,
+            style: line.style,
+            direction: line.direction,
+        }
+    }
+
+    /// Style the matches in `text`, whose lines were searched.
+    #[must_use]
+    pub fn highlight_text<'a>(&self, text: &Text<'a>) -> Text<'a> {
+        Text {
+            lines: text
+                .lines
+                .iter()
+                .enumerate()
+                .map(|(index, line)| self.highlight_line(index, line))
+                .collect(),
+            style: text.style,
+        }
+    }
+}
+
+impl Describe for SearchState {
+    /// Describe the current match, such as "match 2 of 5, line 14", or
+    /// "no matches" for a query without any.
+    fn describe(&self) -> Option<String> {
+        if self.query.is_empty() {
+            return None;
+        }
+        Some(match self.current() {
+            Some(current) => format!(
+                "match {} of {}, line {}",
+                self.current? + 1,
+                self.matches.len(),
+                current.line + 1
+            ),
+            None => String::from("no matches"),
+        })
+    }
+}
+
+/// Get part of a span's content, borrowing it where possible.
+fn slice<'a>(content: &Cow<'a, str>, range: Range<usize>) -> Cow<'a, str> {
+    match content {
+        Cow::Borrowed(content) => Cow::Borrowed(&content[range]),
+        Cow::Owned(content) => Cow::Owned(String::from(&content[range])),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloc::vec;
+    use tuxtui_core::style::Modifier;
+
+    #[test]
+    fn test_update_and_navigate() {
+        let lines = ["Foo foo", "bar", "FOOfoo", "ÄÖ äö"];
+        let mut search = SearchState::new();
+        search.set_query("foo");
+        search.update(lines);
+        let found: Vec<_> = search
+            .matches()
+            .iter()
+            .map(|m| (m.line, m.range.clone()))
+            .collect();
+        assert_eq!(found, [(0, 0..3), (0, 4..7), (2, 0..3), (2, 3..6)]);
+        assert_eq!(search.current_index(), Some(0));
+
+        assert_eq!(search.previous_match().map(|m| m.line), Some(2));
+        assert!(search.handle_key(&KeyCode::Char('n').into()));
+        assert_eq!(search.current_index(), Some(0));
+        assert!(!search.handle_key(&KeyCode::Char('x').into()));
+        assert_eq!(search.describe().as_deref(), Some("match 1 of 4, line 1"));
+
+        // Refining the query stays near the current match.
+        search.next_match();
+        search.next_match();
+        search.set_query("foofoo");
+        search.update(lines);
+        assert_eq!(
+            search.current().map(|m| (m.line, m.range.clone())),
+            Some((2, 0..6))
+        );
+
+        let mut search = SearchState::new().case_sensitive(true);
+        search.set_query("Foo");
+        search.update(lines);
+        assert_eq!(search.len(), 1);
+        search.set_query("äö");
+        search.update(lines);
+        assert_eq!(search.matches()[0].range, 5..9);
+        let mut search = SearchState::new();
+        search.set_query("äö");
+        search.update(lines);
+        assert_eq!(search.len(), 2);
+
+        search.clear();
+        assert!(search.is_empty());
+        assert_eq!(search.describe(), None);
+        assert!(!search.handle_key(&KeyCode::Char('n').into()));
+    }
+
+    #[test]
+    fn test_highlight_across_spans() {
+        let bold = Style::new().add_modifier(Modifier::BOLD);
+        let text = Text::from(vec![
+            Line::from(vec![Span::styled("an ap", bold), Span::raw("ple, apple")]),
+            Line::from("no fruit"),
+        ]);
+        let mut search = SearchState::new()
+            .match_style(Style::new().bg(Color::Yellow))
+            .current_style(Style::new().bg(Color::Red));
+        search.set_query("apple");
+        search.update_text(&text);
+        assert_eq!(search.len(), 2);
+
+        let highlighted = search.highlight_text(&text);
+        let spans: Vec<_> = highlighted.lines[0]
+            .spans
+            .iter()
+            .map(|span| (span.content.as_ref(), span.style))
+            .collect();
+        assert_eq!(
+            spans,
+            [
+                ("an ", bold),
+                ("ap", bold.bg(Color::Red)),
+                ("ple", Style::new().bg(Color::Red)),
+                (", ", Style::new()),
+                ("apple", Style::new().bg(Color::Yellow)),
+            ]
+        );
+        assert_eq!(highlighted.lines[1], text.lines[1]);
+    }
+}
@@ -0,0 +1,658 @@
+//! Build table rows from any [`Serialize`] values, such as a slice of
+//! structs.
+
+use crate::table::{Row, Table};
+use alloc::borrow::ToOwned;
+use alloc::format;
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
+use core::fmt::{self, Display};
+use serde::Serialize;
+use serde::ser::{self, Impossible};
+use tuxtui_core::layout::Constraint;
+use tuxtui_core::text::{Line, Text};
+use unicode_width::UnicodeWidthStr;
+
+/// An error building rows from serialized values.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum SerializeRowsError {
+    /// A value is not a struct or map, so it has no fields to use as columns
+    NotARecord,
+    /// The value failed to serialize
+    Custom(String),
+}
+
+impl Display for SerializeRowsError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::NotARecord => f.write_str("rows must serialize as structs or maps"),
+            Self::Custom(message) => f.write_str(message),
+        }
+    }
+}
+
+impl core::error::Error for SerializeRowsError {}
+
+impl ser::Error for SerializeRowsError {
+    fn custom<T: Display>(msg: T) -> Self {
+        Self::Custom(msg.to_string())
+    }
+}
+
+/// Rows built from serialized values, with a header of their field names.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SerializedRows {
+    /// The field names, in the order they first appear
+    pub header: Row<'static>,
+    /// One row per value
+    pub rows: Vec<Row<'static>>,
+    /// Column widths fitting the header and every cell
+    pub widths: Vec<Constraint>,
+}
+
+/// Build rows from values serializing as structs or maps.
+///
+/// Field names become the header. Numbers are right-aligned, `None` and
+/// missing fields are left empty, enum variants show their name, and nested
+/// sequences, maps and structs are written inline.
+///
+/// # Errors
+///
+/// Returns an error if a value is not a struct or map, or fails to
+/// serialize.
+///
+/// # Example
+///
+/// ```
+/// use serde::Serialize;
+/// use tuxtui_widgets::serde_table::serialize_rows;
+///
+/// #[derive(Serialize)]
+/// struct Package {
+///     name: &'static str,
+///     size: u64,
+/// }
+///
+/// let rows = serialize_rows(&[Package { name: "tuxtui", size: 120 }]).unwrap();
+/// assert_eq!(rows.rows.len(), 1);
+/// ```
+pub fn serialize_rows<T: Serialize>(items: &[T]) -> Result<SerializedRows, SerializeRowsError> {
+    let mut names: Vec<String> = Vec::new();
+    let mut records = Vec::with_capacity(items.len());
+    for item in items {
+        let mut fields = Vec::new();
+        for (name, cell) in item.serialize(RecordSerializer)? {
+            let column = names.iter().position(|n| *n == name).unwrap_or_else(|| {
+                names.push(name);
+                names.len() - 1
+            });
+            fields.push((column, cell));
+        }
+        records.push(fields);
+    }
+
+    let mut widths: Vec<usize> = names.iter().map(|name| name.width()).collect();
+    let rows = records
+        .into_iter()
+        .map(|fields| {
+            let mut cells = Vec::new();
+            cells.resize_with(names.len(), Text::default);
+            for (column, cell) in fields {
+                widths[column] = widths[column].max(cell.text.width());
+                let line = Line::from(cell.text);
+                cells[column] = Text::from(if cell.numeric {
+                    line.right_aligned()
+                } else {
+                    line
+                });
+            }
+            Row::new(cells)
+        })
+        .collect();
+    Ok(SerializedRows {
+        header: Row::new(names),
+        rows,
+        widths: widths
+            .into_iter()
+            .map(|width| Constraint::Length(u16::try_from(width).unwrap_or(u16::MAX)))
+            .collect(),
+    })
+}
+
+impl Table<'static> {
+    /// Create a table of values serializing as structs or maps, with their
+    /// field names as the header. See [`serialize_rows`].
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if a value is not a struct or map, or fails to
+    /// serialize.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use serde::Serialize;
+    /// use tuxtui_widgets::table::Table;
+    ///
+    /// #[derive(Serialize)]
+    /// struct Job {
+    ///     id: u32,
+    ///     state: &'static str,
+    /// }
+    ///
+    /// let table = Table::from_serialize(&[
+    ///     Job { id: 1, state: "done" },
+    ///     Job { id: 2, state: "queued" },
+    /// ])
+    /// .unwrap();
+    /// assert_eq!(table.len(), 2);
+    /// ```
+    pub fn from_serialize<T: Serialize>(items: &[T]) -> Result<Self, SerializeRowsError> {
+        let rows = serialize_rows(items)?;
+        Ok(Self::new(rows.rows, rows.widths).header(rows.header))
+    }
+}
+
+/// The text of a cell, and whether it holds a number.
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct Cell {
+    text: String,
+    numeric: bool,
+}
+
+impl Cell {
+    fn text(text: impl Into<String>) -> Self {
+        Self {
+            text: text.into(),
+            numeric: false,
+        }
+    }
+
+    fn number(number: impl Display) -> Self {
+        Self {
+            text: number.to_string(),
+            numeric: true,
+        }
+    }
+}
+
+type Fields = Vec<(String, Cell)>;
+
+/// Serializes a struct or map into its named fields.
+struct RecordSerializer;
+
+/// Collects the fields of a struct or map.
+struct RecordFields {
+    fields: Fields,
+    key: Option<String>,
+}
+
+impl ser::SerializeStruct for RecordFields {
+    type Ok = Fields;
+    type Error = SerializeRowsError;
+
+    fn serialize_field<T: ?Sized + Serialize>(
+        &mut self,
+        key: &'static str,
+        value: &T,
+    ) -> Result<(), Self::Error> {
+        self.fields
+            .push((key.to_owned(), value.serialize(CellSerializer)?));
+        Ok(())
+    }
+
+    fn end(self) -> Result<Self::Ok, Self::Error> {
+        Ok(self.fields)
+    }
+}
+
+impl ser::SerializeMap for RecordFields {
+    type Ok = Fields;
+    type Error = SerializeRowsError;
+
+    fn serialize_key<T: ?Sized + Serialize>(&mut self, key: &T) -> Result<(), Self::Error> {
+        self.key = Some(key.serialize(CellSerializer)?.text);
+        Ok(())
+    }
+
+    fn serialize_value<T: ?Sized + Serialize>(&mut self, value: &T) -> Result<(), Self::Error> {
+        let key = self.key.take().unwrap_or_default();
+        self.fields.push((key, value.serialize(CellSerializer)?));
+        Ok(())
+    }
+
+    fn end(self) -> Result<Self::Ok, Self::Error> {
+        Ok(self.fields)
+    }
+}
+
+/// Reject values other than structs and maps.
+macro_rules! not_a_record {
+    ($($method:ident($($arg:ty),*)),* $(,)?) => {
+        $(fn $method(self $(, _: $arg)*) -> Result<Self::Ok, Self::Error> {
+            Err(SerializeRowsError::NotARecord)
+        })*
+    };
+}
+
+impl ser::Serializer for RecordSerializer {
+    type Ok = Fields;
+    type Error = SerializeRowsError;
+    type SerializeSeq = Impossible<Fields, SerializeRowsError>;
+    type SerializeTuple = Impossible<Fields, SerializeRowsError>;
+    type SerializeTupleStruct = Impossible<Fields, SerializeRowsError>;
+    type SerializeTupleVariant = Impossible<Fields, SerializeRowsError>;
+    type SerializeMap = RecordFields;
+    type SerializeStruct = RecordFields;
+    type SerializeStructVariant = Impossible<Fields, SerializeRowsError>;
+
+    not_a_record!(
+        serialize_bool(bool),
+        serialize_i8(i8),
+        serialize_i16(i16),
+        serialize_i32(i32),
+        serialize_i64(i64),
+        serialize_i128(i128),
+        serialize_u8(u8),
+        serialize_u16(u16),
+        serialize_u32(u32),
+        serialize_u64(u64),
+        serialize_u128(u128),
+        serialize_f32(f32),
+        serialize_f64(f64),
+        serialize_char(char),
+        serialize_str(&str),
+        serialize_bytes(&[u8]),
+        serialize_none(),
+        serialize_unit(),
+        serialize_unit_struct(&'static str),
+        serialize_unit_variant(&'static str, u32, &'static str),
+    );
+
+    fn serialize_some<T: ?Sized + Serialize>(self, value: &T) -> Result<Self::Ok, Self::Error> {
+        value.serialize(self)
+    }
+
+    fn serialize_newtype_struct<T: ?Sized + Serialize>(
+        self,
+        _name: &'static str,
+        value: &T,
+    ) -> Result<Self::Ok, Self::Error> {
+        value.serialize(self)
+    }
+
+    fn serialize_newtype_variant<T: ?Sized + Serialize>(
+        self,
+        _name: &'static str,
+        _index: u32,
+        _variant: &'static str,
+        _value: &T,
+    ) -> Result<Self::Ok, Self::Error> {
+        Err(SerializeRowsError::NotARecord)
+    }
+
+    fn serialize_seq(self, _len: Option<usize>) -> Result<Self::SerializeSeq, Self::Error> {
+        Err(SerializeRowsError::NotARecord)
+    }
+
+    fn serialize_tuple(self, _len: usize) -> Result<Self::SerializeTuple, Self::Error> {
+        Err(SerializeRowsError::NotARecord)
+    }
+
+    fn serialize_tuple_struct(
+        self,
+        _name: &'static str,
+        _len: usize,
+    ) -> Result<Self::SerializeTupleStruct, Self::Error> {
+        Err(SerializeRowsError::NotARecord)
+    }
+
+    fn serialize_tuple_variant(
+        self,
+        _name: &'static str,
+        _index: u32,
+        _variant: &'static str,
+        _len: usize,
+    ) -> Result<Self::SerializeTupleVariant, Self::Error> {
+        Err(SerializeRowsError::NotARecord)
+    }
+
+    fn serialize_map(self, len: Option<usize>) -> Result<Self::SerializeMap, Self::Error> {
+        Ok(RecordFields {
+            fields: Vec::with_capacity(len.unwrap_or(0)),
+            key: None,
+        })
+    }
+
+    fn serialize_struct(
+        self,
+        _name: &'static str,
+        len: usize,
+    ) -> Result<Self::SerializeStruct, Self::Error> {
+        Ok(RecordFields {
+            fields: Vec::with_capacity(len),
+            key: None,
+        })
+    }
+
+    fn serialize_struct_variant(
+        self,
+        _name: &'static str,
+        _index: u32,
+        _variant: &'static str,
+        _len: usize,
+    ) -> Result<Self::SerializeStructVariant, Self::Error> {
+        Err(SerializeRowsError::NotARecord)
+    }
+}
+
+/// Serializes a field value into the text of its cell.
+struct CellSerializer;
+
+/// Writes a nested sequence, map or struct inline, such as `[1, 2]` or
+/// `{a: 1}`.
+struct Inline {
+    prefix: &'static str,
+    open: char,
+    close: char,
+    items: Vec<String>,
+    key: Option<String>,
+}
+
+impl Inline {
+    fn new(prefix: &'static str, open: char, close: char) -> Self {
+        Self {
+            prefix,
+            open,
+            close,
+            items: Vec::new(),
+            key: None,
+        }
+    }
+
+    fn push<T: ?Sized + Serialize>(&mut self, value: &T) -> Result<(), SerializeRowsError> {
+        let text = value.serialize(CellSerializer)?.text;
+        self.items.push(match self.key.take() {
+            Some(key) => format!("{key}: {text}"),
+            None => text,
+        });
+        Ok(())
+    }
+
+    fn end(self) -> Cell {
+        let mut text = String::from(self.prefix);
+        text.push(self.open);
+        text.push_str(&self.items.join(", "));
+        text.push(self.close);
+        Cell::text(text)
+    }
+}
+
+/// Forward the compound serializer traits to [`Inline`].
+macro_rules! inline_compound {
+    ($($trait:ident::$method:ident($($key:ident),*)),* $(,)?) => {
+        $(impl ser::$trait for Inline {
+            type Ok = Cell;
+            type Error = SerializeRowsError;
+
+            fn $method<T: ?Sized + Serialize>(
+                &mut self,
+                $($key: &'static str,)*
+                value: &T,
+            ) -> Result<(), Self::Error> {
+                $(self.key = Some($key.to_owned());)*
+                self.push(value)
+            }
+
+            fn end(self) -> Result<Self::Ok, Self::Error> {
+                Ok(Inline::end(self))
+            }
+        })*
+    };
+}
+
+inline_compound!(
+    SerializeSeq::serialize_element(),
+    SerializeTuple::serialize_element(),
+    SerializeTupleStruct::serialize_field(),
+    SerializeTupleVariant::serialize_field(),
+    SerializeStruct::serialize_field(key),
+    SerializeStructVariant::serialize_field(key),
+);
+
+impl ser::SerializeMap for Inline {
+    type Ok = Cell;
+    type Error = SerializeRowsError;
+
+    fn serialize_key<T: ?Sized + Serialize>(&mut self, key: &T) -> Result<(), Self::Error> {
+        self.key = Some(key.serialize(CellSerializer)?.text);
+        Ok(())
+    }
+
+    fn serialize_value<T: ?Sized + Serialize>(&mut self, value: &T) -> Result<(), Self::Error> {
+        self.push(value)
+    }
+
+    fn end(self) -> Result<Self::Ok, Self::Error> {
+        Ok(Inline::end(self))
+    }
+}
+
+/// Write numbers and other displayable values.
+macro_rules! display_cell {
+    ($($method:ident($ty:ty) => $cell:path),* $(,)?) => {
+        $(fn $method(self, value: $ty) -> Result<Self::Ok, Self::Error> {
+            Ok($cell(value))
+        })*
+    };
+}
+
+impl ser::Serializer for CellSerializer {
+    type Ok = Cell;
+    type Error = SerializeRowsError;
+    type SerializeSeq = Inline;
+    type SerializeTuple = Inline;
+    type SerializeTupleStruct = Inline;
+    type SerializeTupleVariant = Inline;
+    type SerializeMap = Inline;
+    type SerializeStruct = Inline;
+    type SerializeStructVariant = Inline;
+
+    display_cell!(
+        serialize_i8(i8) => Cell::number,
+        serialize_i16(i16) => Cell::number,
+        serialize_i32(i32) => Cell::number,
+        serialize_i64(i64) => Cell::number,
+        serialize_i128(i128) => Cell::number,
+        serialize_u8(u8) => Cell::number,
+        serialize_u16(u16) => Cell::number,
+        serialize_u32(u32) => Cell::number,
+        serialize_u64(u64) => Cell::number,
+        serialize_u128(u128) => Cell::number,
+        serialize_f32(f32) => Cell::number,
+        serialize_f64(f64) => Cell::number,
+        serialize_str(&str) => Cell::text,
+    );
+
+    fn serialize_bool(self, value: bool) -> Result<Self::Ok, Self::Error> {
+        Ok(Cell::text(if value { "true" } else { "false" }))
+    }
+
+    fn serialize_char(self, value: char) -> Result<Self::Ok, Self::Error> {
+        Ok(Cell::text(value.to_string()))
+    }
+
+    fn serialize_bytes(self, value: &[u8]) -> Result<Self::Ok, Self::Error> {
+        Ok(Cell::text(format!("{value:?}")))
+    }
+
+    fn serialize_none(self) -> Result<Self::Ok, Self::Error> {
+        Ok(Cell::text(""))
+    }
+
+    fn serialize_some<T: ?Sized + Serialize>(self, value: &T) -> Result<Self::Ok, Self::Error> {
+        value.serialize(self)
+    }
+
+    fn serialize_unit(self) -> Result<Self::Ok, Self::Error> {
+        Ok(Cell::text(""))
+    }
+
+    fn serialize_unit_struct(self, name: &'static str) -> Result<Self::Ok, Self::Error> {
+        Ok(Cell::text(name))
+    }
+
+    fn serialize_unit_variant(
+        self,
+        _name: &'static str,
+        _index: u32,
+        variant: &'static str,
+    ) -> Result<Self::Ok, Self::Error> {
+        Ok(Cell::text(variant))
+    }
+
+    fn serialize_newtype_struct<T: ?Sized + Serialize>(
+        self,
+        _name: &'static str,
+        value: &T,
+    ) -> Result<Self::Ok, Self::Error> {
+        value.serialize(self)
+    }
+
+    fn serialize_newtype_variant<T: ?Sized + Serialize>(
+        self,
+        _name: &'static str,
+        _index: u32,
+        variant: &'static str,
+        value: &T,
+    ) -> Result<Self::Ok, Self::Error> {
+        let inner = value.serialize(self)?.text;
+        Ok(Cell::text(format!("{variant}({inner})")))
+    }
+
+    fn serialize_seq(self, _len: Option<usize>) -> Result<Self::SerializeSeq, Self::Error> {
+        Ok(Inline::new("", '[', ']'))
+    }
+
+    fn serialize_tuple(self, _len: usize) -> Result<Self::SerializeTuple, Self::Error> {
+        Ok(Inline::new("", '(', ')'))
+    }
+
+    fn serialize_tuple_struct(
+        self,
+        name: &'static str,
+        _len: usize,
+    ) -> Result<Self::SerializeTupleStruct, Self::Error> {
+        Ok(Inline::new(name, '(', ')'))
+    }
+
+    fn serialize_tuple_variant(
+        self,
+        _name: &'static str,
+        _index: u32,
+        variant: &'static str,
+        _len: usize,
+    ) -> Result<Self::SerializeTupleVariant, Self::Error> {
+        Ok(Inline::new(variant, '(', ')'))
+    }
+
+    fn serialize_map(self, _len: Option<usize>) -> Result<Self::SerializeMap, Self::Error> {
+        Ok(Inline::new("", '{', '}'))
+    }
+
+    fn serialize_struct(
+        self,
+        _name: &'static str,
+        _len: usize,
+    ) -> Result<Self::SerializeStruct, Self::Error> {
+        Ok(Inline::new("", '{', '}'))
+    }
+
+    fn serialize_struct_variant(
+        self,
+        _name: &'static str,
+        _index: u32,
+        variant: &'static str,
+        _len: usize,
+    ) -> Result<Self::SerializeStructVariant, Self::Error> {
+        Ok(Inline::new(variant, '{', '}'))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloc::collections::BTreeMap;
+    use alloc::vec;
+    use tuxtui_core::buffer::Buffer;
+    use tuxtui_core::geometry::{Alignment, Rect};
+    use tuxtui_core::terminal::Widget;
+
+    #[derive(Serialize)]
+    enum Kind {
+        File,
+        Link(String),
+    }
+
+    #[derive(Serialize)]
+    struct Entry {
+        name: &'static str,
+        size: u64,
+        kind: Kind,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        owner: Option<&'static str>,
+        tags: Vec<&'static str>,
+    }
+
+    #[test]
+    fn test_serialize_rows() {
+        let entries = [
+            Entry {
+                name: "a.txt",
+                size: 12,
+                kind: Kind::File,
+                owner: None,
+                tags: vec![],
+            },
+            Entry {
+                name: "b",
+                size: 3400,
+                kind: Kind::Link("a.txt".into()),
+                owner: Some("root"),
+                tags: vec!["x", "y"],
+            },
+        ];
+        let rows = serialize_rows(&entries).unwrap();
+        assert_eq!(
+            rows.header,
+            Row::new(["name", "size", "kind", "tags", "owner"])
+        );
+        let first = rows.rows[0].cells();
+        assert_eq!(first[1].lines[0].alignment, Alignment::End);
+        assert_eq!(first[2].lines[0].alignment, Alignment::Start);
+        assert_eq!(first[4], Text::default());
+        assert_eq!(rows.rows[1].cells()[2].to_string(), "Link(a.txt)");
+        assert_eq!(rows.rows[1].cells()[3].to_string(), "[x, y]");
+        assert_eq!(rows.widths[2], Constraint::Length(11));
+
+        let area = Rect::new(0, 0, 40, 3);
+        let mut buf = Buffer::empty(area);
+        Table::from_serialize(&entries)
+            .unwrap()
+            .render(area, &mut buf);
+        let text = buf.to_string();
+        let lines: Vec<_> = text.lines().map(str::trim_end).collect();
+        assert_eq!(lines[0], "name  size kind        tags   owner");
+        assert_eq!(lines[2], "b     3400 Link(a.txt) [x, y] root");
+    }
+
+    #[test]
+    fn test_maps_and_errors() {
+        let map = BTreeMap::from([("b", 1.5), ("a", 2.0)]);
+        let rows = serialize_rows(&[map]).unwrap();
+        assert_eq!(rows.header, Row::new(["a", "b"]));
+        assert_eq!(rows.rows[0].cells()[1].to_string(), "1.5");
+
+        assert_eq!(serialize_rows(&[1, 2]), Err(SerializeRowsError::NotARecord));
+    }
+}
@@ -0,0 +1,450 @@
+//! Slider and number input widgets for choosing a value within a range.
+
+use crate::input::{InputFilter, InputState};
+use alloc::format;
+use alloc::string::{String, ToString};
+use tuxtui_core::buffer::Buffer;
+use tuxtui_core::event::{KeyCode, KeyEvent};
+use tuxtui_core::geometry::Rect;
+use tuxtui_core::narration::Describe;
+use tuxtui_core::style::{Modifier, Style};
+use tuxtui_core::symbols;
+use tuxtui_core::util::unicode;
+use unicode_segmentation::UnicodeSegmentation;
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// The number of steps moved by Page Up and Page Down.
+const PAGE_STEPS: i64 = 10;
+
+/// State for a [`Slider`], holding the value, its range and any text being
+/// typed.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct SliderState {
+    value: i64,
+    min: i64,
+    max: i64,
+    step: i64,
+    editable: bool,
+    editing: Option<InputState>,
+}
+
+impl Default for SliderState {
+    fn default() -> Self {
+        Self::new(0, 100)
+    }
+}
+
+impl SliderState {
+    /// Create a state for the range `min..=max`, starting at `min`.
+    ///
+    /// The bounds are swapped if `min` is greater than `max`.
+    #[must_use]
+    pub fn new(min: i64, max: i64) -> Self {
+        Self {
+            value: min.min(max),
+            min: min.min(max),
+            max: max.max(min),
+            step: 1,
+            editable: false,
+            editing: None,
+        }
+    }
+
+    /// Set the initial value, clamped to the range.
+    #[must_use]
+    pub fn with_value(mut self, value: i64) -> Self {
+        self.set_value(value);
+        self
+    }
+
+    /// Set the amount the value changes per key press, at least 1.
+    #[must_use]
+    pub fn with_step(mut self, step: i64) -> Self {
+        self.step = step.max(1);
+        self
+    }
+
+    /// Set whether the value can be typed in as text.
+    #[must_use]
+    pub const fn with_editable(mut self, editable: bool) -> Self {
+        self.editable = editable;
+        self
+    }
+
+    /// Get the current value.
+    #[must_use]
+    pub const fn value(&self) -> i64 {
+        self.value
+    }
+
+    /// Get the lower bound.
+    #[must_use]
+    pub const fn min(&self) -> i64 {
+        self.min
+    }
+
+    /// Get the upper bound.
+    #[must_use]
+    pub const fn max(&self) -> i64 {
+        self.max
+    }
+
+    /// Get the step.
+    #[must_use]
+    pub const fn step(&self) -> i64 {
+        self.step
+    }
+
+    /// Set the value, clamped to the range.
+    pub fn set_value(&mut self, value: i64) {
+        self.value = value.clamp(self.min, self.max);
+    }
+
+    /// Get the position of the value within the range, from 0.0 to 1.0.
+    #[must_use]
+    pub fn ratio(&self) -> f64 {
+        if self.max == self.min {
+            return 1.0;
+        }
+        // Wide ranges such as the whole of i64 do not fit in an i64.
+        let offset = i128::from(self.value) - i128::from(self.min);
+        let range = i128::from(self.max) - i128::from(self.min);
+        offset as f64 / range as f64
+    }
+
+    /// Increase the value by one step.
+    pub fn increment(&mut self) {
+        self.set_value(self.value.saturating_add(self.step));
+    }
+
+    /// Decrease the value by one step.
+    pub fn decrement(&mut self) {
+        self.set_value(self.value.saturating_sub(self.step));
+    }
+
+    /// Check whether the value is being typed in.
+    #[must_use]
+    pub const fn is_editing(&self) -> bool {
+        self.editing.is_some()
+    }
+
+    /// Get the text being typed in, if editing.
+    #[must_use]
+    pub fn edit_text(&self) -> Option<&InputState> {
+        self.editing.as_ref()
+    }
+
+    /// Start typing in a value, beginning with the current one.
+    ///
+    /// Does nothing unless the state is editable.
+    pub fn start_editing(&mut self) {
+        if self.editable && self.editing.is_none() {
+            self.editing = Some(
+                InputState::with_value(self.value.to_string()).with_filter(InputFilter::Numeric),
+            );
+        }
+    }
+
+    /// Apply the typed value, clamped to the range, and stop editing.
+    ///
+    /// Returns `false` and keeps editing if the text is not an integer.
+    pub fn commit(&mut self) -> bool {
+        let Some(input) = &self.editing else {
+            return false;
+        };
+        let Ok(value) = input.value().parse() else {
+            return false;
+        };
+        self.set_value(value);
+        self.editing = None;
+        true
+    }
+
+    /// Discard the typed value and stop editing.
+    pub fn cancel(&mut self) {
+        self.editing = None;
+    }
+
+    /// Handle a key press.
+    ///
+    /// Left and Down decrease the value by a step, Right and Up increase it,
+    /// Page Up and Page Down move ten steps, and Home and End jump to the
+    /// bounds. When editable, Enter or a digit starts typing; while typing,
+    /// Enter applies the text and Esc discards it. Returns `true` if the key
+    /// was consumed.
+    pub fn handle_key(&mut self, key: &KeyEvent) -> bool {
+        if key.is_release() {
+            return false;
+        }
+        if let Some(input) = &mut self.editing {
+            match key.code {
+                KeyCode::Enter => {
+                    self.commit();
+                }
+                KeyCode::Esc => self.cancel(),
+                KeyCode::Char(c) => input.insert_char(c),
+                KeyCode::Backspace => input.delete_char(),
+                KeyCode::Left => input.move_cursor_left(),
+                KeyCode::Right => input.move_cursor_right(),
+                KeyCode::Home => input.move_cursor_start(),
+                KeyCode::End => input.move_cursor_end(),
+                _ => return false,
+            }
+            return true;
+        }
+        let page = self.step.saturating_mul(PAGE_STEPS);
+        match key.code {
+            KeyCode::Left | KeyCode::Down | KeyCode::Char('-') => self.decrement(),
+            KeyCode::Right | KeyCode::Up | KeyCode::Char('+') => self.increment(),
+            KeyCode::PageDown => self.set_value(self.value.saturating_sub(page)),
+            KeyCode::PageUp => self.set_value(self.value.saturating_add(page)),
+            KeyCode::Home => self.value = self.min,
+            KeyCode::End => self.value = self.max,
+            KeyCode::Enter if self.editable => self.start_editing(),
+            KeyCode::Char(c) if self.editable && c.is_ascii_digit() => {
+                self.start_editing();
+                if let Some(input) = &mut self.editing {
+                    input.clear();
+                    input.insert_char(c);
+                }
+            }
+            _ => return false,
+        }
+        true
+    }
+}
+
+impl Describe for SliderState {
+    /// Describe the value and range, such as "50, 0 to 100".
+    fn describe(&self) -> Option<String> {
+        Some(format!("{}, {} to {}", self.value, self.min, self.max))
+    }
+}
+
+/// A horizontal slider showing a value within its range.
+///
+/// The bar fills in proportion to the value, followed by the value as text.
+/// Without the bar, the slider works as a plain number input.
+///
+/// # Example
+///
+/// ```
+/// use tuxtui_core::prelude::*;
+/// use tuxtui_widgets::slider::{Slider, SliderState};
+///
+/// let mut volume = SliderState::new(0, 100).with_value(50).with_step(5);
+/// volume.handle_key(&KeyCode::Right.into());
+/// assert_eq!(volume.value(), 55);
+///
+/// let mut buf = Buffer::empty(Rect::new(0, 0, 20, 1));
+/// Slider::new().render_stateful(buf.area, &mut buf, &mut volume);
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Slider<'a> {
+    style: Style,
+    filled_style: Style,
+    filled_symbol: &'a str,
+    empty_symbol: &'a str,
+    cursor_style: Style,
+    show_bar: bool,
+    show_value: bool,
+}
+
+impl Default for Slider<'_> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<'a> Slider<'a> {
+    /// Create a new slider.
+    #[must_use]
+    pub const fn new() -> Self {
+        Self {
+            style: Style::new(),
+            filled_style: Style::new(),
+            filled_symbol: symbols::BAR_FULL,
+            empty_symbol: symbols::SHADE_LIGHT,
+            cursor_style: Style::new().add_modifier(Modifier::REVERSED),
+            show_bar: true,
+            show_value: true,
+        }
+    }
+
+    /// Set the overall style.
+    #[must_use]
+    pub const fn style(mut self, style: Style) -> Self {
+        self.style = style;
+        self
+    }
+
+    /// Set the style of the filled part of the bar.
+    #[must_use]
+    pub const fn filled_style(mut self, style: Style) -> Self {
+        self.filled_style = style;
+        self
+    }
+
+    /// Set the symbols of the filled and empty parts of the bar.
+    #[must_use]
+    pub const fn symbols(mut self, filled: &'a str, empty: &'a str) -> Self {
+        self.filled_symbol = filled;
+        self.empty_symbol = empty;
+        self
+    }
+
+    /// Set the style of the cursor while typing.
+    #[must_use]
+    pub const fn cursor_style(mut self, style: Style) -> Self {
+        self.cursor_style = style;
+        self
+    }
+
+    /// Set whether to draw the bar.
+    #[must_use]
+    pub const fn show_bar(mut self, show: bool) -> Self {
+        self.show_bar = show;
+        self
+    }
+
+    /// Set whether to draw the value as text.
+    #[must_use]
+    pub const fn show_value(mut self, show: bool) -> Self {
+        self.show_value = show;
+        self
+    }
+
+    /// Render the slider with state.
+    pub fn render_stateful(self, area: Rect, buf: &mut Buffer, state: &mut SliderState) {
+        if area.area() == 0 {
+            return;
+        }
+        buf.set_style(area, self.style);
+        let y = area.top();
+
+        let text = state.editing.as_ref().map_or_else(
+            || state.value.to_string(),
+            |input| String::from(input.value()),
+        );
+        let text_width = if self.show_value || !self.show_bar {
+            // Reserve room for either bound so the bar keeps its length.
+            let widest = state.min.to_string().len().max(state.max.to_string().len());
+            let width = widest.max(unicode::width(&text)) + 1;
+            u16::try_from(width).unwrap_or(u16::MAX)
+        } else {
+            0
+        };
+
+        let bar_width = if self.show_bar {
+            area.width.saturating_sub(text_width)
+        } else {
+            0
+        };
+        if bar_width > 0 {
+            let filled = (f64::from(bar_width) * state.ratio()) as u16;
+            for x in 0..bar_width {
+                let (symbol, style) = if x < filled {
+                    (self.filled_symbol, self.style.patch(self.filled_style))
+                } else {
+                    (self.empty_symbol, self.style)
+                };
+                buf.set_string(area.left() + x, y, symbol, style);
+            }
+        }
+
+        if text_width > 0 {
+            // Right-align the value next to the bar.
+            let padding = if bar_width > 0 {
+                text_width.saturating_sub(u16::try_from(unicode::width(&text)).unwrap_or(u16::MAX))
+            } else {
+                0
+            };
+            let x = area.left() + bar_width + padding;
+            let end = buf.set_string(x, y, &text, self.style).min(area.right());
+            if let Some(input) = &state.editing {
+                let cursor = text.graphemes(true).take(input.cursor).count();
+                let cursor_x = x.saturating_add(u16::try_from(cursor).unwrap_or(u16::MAX));
+                if cursor_x < area.right() && cursor_x <= end {
+                    buf.set_style(Rect::new(cursor_x, y, 1, 1), self.cursor_style);
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_step_and_bounds() {
+        let mut state = SliderState::new(10, -10).with_step(4).with_value(8);
+        assert_eq!((state.min(), state.max()), (-10, 10));
+        state.increment();
+        assert_eq!(state.value(), 10);
+        assert!(state.handle_key(&KeyCode::PageDown.into()));
+        assert_eq!(state.value(), -10);
+        assert!(state.handle_key(&KeyCode::Up.into()));
+        assert_eq!(state.value(), -6);
+        assert!(state.handle_key(&KeyCode::End.into()));
+        assert!((state.ratio() - 1.0).abs() < f64::EPSILON);
+        assert!(!state.handle_key(&KeyCode::Enter.into()));
+        assert!(!state.handle_key(&KeyCode::Char('5').into()));
+    }
+
+    #[test]
+    fn test_ratio_of_the_widest_range() {
+        let state = SliderState::new(i64::MIN, i64::MAX).with_value(i64::MAX);
+        assert!((state.ratio() - 1.0).abs() < f64::EPSILON);
+        let state = state.with_value(0);
+        assert!((state.ratio() - 0.5).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_editing() {
+        let mut state = SliderState::new(0, 100).with_value(30).with_editable(true);
+        assert!(state.handle_key(&KeyCode::Char('7').into()));
+        assert_eq!(state.edit_text().unwrap().value(), "7");
+        state.handle_key(&KeyCode::Char('5').into());
+        state.handle_key(&KeyCode::Enter.into());
+        assert!(!state.is_editing());
+        assert_eq!(state.value(), 75);
+
+        state.start_editing();
+        state.handle_key(&KeyCode::Char('0').into());
+        state.handle_key(&KeyCode::Esc.into());
+        assert_eq!(state.value(), 75);
+
+        state.start_editing();
+        state.handle_key(&KeyCode::Char('.').into());
+        assert!(!state.commit());
+        state.handle_key(&KeyCode::Backspace.into());
+        state.handle_key(&KeyCode::Char('9').into());
+        assert!(state.commit());
+        assert_eq!(state.value(), 100);
+    }
+
+    #[test]
+    fn test_render() {
+        let mut state = SliderState::new(0, 100).with_value(50);
+        let mut buf = Buffer::empty(Rect::new(0, 0, 12, 1));
+        Slider::new()
+            .symbols("#", "-")
+            .render_stateful(buf.area, &mut buf, &mut state);
+        tuxtui_core::assert_buffer_eq!(buf, Buffer::from_lines(&["####----  50"]));
+
+        state = state.with_editable(true);
+        state.start_editing();
+        let mut buf = Buffer::empty(Rect::new(0, 0, 4, 1));
+        Slider::new()
+            .show_bar(false)
+            .render_stateful(buf.area, &mut buf, &mut state);
+        let symbols: String = (0..4).map(|x| buf.get(x, 0).unwrap().symbol()).collect();
+        assert_eq!(symbols, "50  ");
+        let cursor = buf.get(2, 0).unwrap().style;
+        assert!(cursor.add_modifier.contains(Modifier::REVERSED));
+    }
+}
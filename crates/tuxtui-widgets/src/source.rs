@@ -0,0 +1,159 @@
+//! Item storage shared by the collection widgets.
+
+#[cfg(feature = "table")]
+use alloc::borrow::Cow;
+#[cfg(feature = "list")]
+use alloc::collections::BTreeMap;
+#[cfg(feature = "list")]
+use alloc::rc::Rc;
+use alloc::sync::Arc;
+use alloc::vec::Vec;
+#[cfg(feature = "list")]
+use core::cell::RefCell;
+use core::fmt;
+#[cfg(feature = "list")]
+use core::ops::Deref;
+
+/// Items of a collection widget, either stored up front or produced on demand.
+///
+/// Lazy sources only build the items that are actually rendered, which keeps
+/// huge collections cheap to draw.
+pub(crate) enum ItemSource<'a, T> {
+    /// Items collected up front.
+    Owned(Vec<T>),
+    /// Items built by index when needed.
+    Lazy {
+        len: usize,
+        item_fn: Arc<dyn Fn(usize) -> T + Send + Sync + 'a>,
+    },
+}
+
+impl<'a, T: Clone> ItemSource<'a, T> {
+    /// Create a lazy source of `len` items.
+    pub(crate) fn lazy<F>(len: usize, item_fn: F) -> Self
+    where
+        F: Fn(usize) -> T + Send + Sync + 'a,
+    {
+        Self::Lazy {
+            len,
+            item_fn: Arc::new(item_fn),
+        }
+    }
+
+    /// Get the number of items.
+    pub(crate) fn len(&self) -> usize {
+        match self {
+            Self::Owned(items) => items.len(),
+            Self::Lazy { len, .. } => *len,
+        }
+    }
+
+    /// Check if there are no items.
+    pub(crate) fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Get the item at `index`, building it if the source is lazy.
+    #[cfg(feature = "table")]
+    pub(crate) fn get(&self, index: usize) -> Option<Cow<'_, T>> {
+        match self {
+            Self::Owned(items) => items.get(index).map(Cow::Borrowed),
+            Self::Lazy { len, item_fn } => (index < *len).then(|| Cow::Owned(item_fn(index))),
+        }
+    }
+}
+
+impl<T: Clone> Clone for ItemSource<'_, T> {
+    fn clone(&self) -> Self {
+        match self {
+            Self::Owned(items) => Self::Owned(items.clone()),
+            Self::Lazy { len, item_fn } => Self::Lazy {
+                len: *len,
+                item_fn: Arc::clone(item_fn),
+            },
+        }
+    }
+}
+
+impl<T: fmt::Debug> fmt::Debug for ItemSource<'_, T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Owned(items) => f.debug_tuple("Owned").field(items).finish(),
+            Self::Lazy { len, .. } => f
+                .debug_struct("Lazy")
+                .field("len", len)
+                .finish_non_exhaustive(),
+        }
+    }
+}
+
+/// Lazy sources are equal only if they share the same item function.
+impl<T: PartialEq> PartialEq for ItemSource<'_, T> {
+    fn eq(&self, other: &Self) -> bool {
+        match (self, other) {
+            (Self::Owned(a), Self::Owned(b)) => a == b,
+            (Self::Lazy { len: a, item_fn: f }, Self::Lazy { len: b, item_fn: g }) => {
+                a == b && Arc::ptr_eq(f, g)
+            }
+            _ => false,
+        }
+    }
+}
+
+impl<T: Eq> Eq for ItemSource<'_, T> {}
+
+/// Items of a source, each built at most once while rendering.
+///
+/// Widgets that measure items before drawing them share one cache between
+/// both passes, so a lazy source never builds the same item twice.
+#[cfg(feature = "list")]
+pub(crate) struct ItemCache<'s, 'a, T> {
+    source: &'s ItemSource<'a, T>,
+    built: RefCell<BTreeMap<usize, Rc<T>>>,
+}
+
+#[cfg(feature = "list")]
+impl<'s, 'a, T> ItemCache<'s, 'a, T> {
+    /// Create an empty cache over `source`.
+    pub(crate) const fn new(source: &'s ItemSource<'a, T>) -> Self {
+        Self {
+            source,
+            built: RefCell::new(BTreeMap::new()),
+        }
+    }
+
+    /// Get the item at `index`, building it on first access if the source is lazy.
+    pub(crate) fn get(&self, index: usize) -> Option<CachedItem<'s, T>> {
+        match self.source {
+            ItemSource::Owned(items) => items.get(index).map(CachedItem::Borrowed),
+            ItemSource::Lazy { len, item_fn } => (index < *len).then(|| {
+                let mut built = self.built.borrow_mut();
+                let item = built
+                    .entry(index)
+                    .or_insert_with(|| Rc::new(item_fn(index)));
+                CachedItem::Built(Rc::clone(item))
+            }),
+        }
+    }
+}
+
+/// An item handed out by an [`ItemCache`].
+#[cfg(feature = "list")]
+pub(crate) enum CachedItem<'s, T> {
+    /// An item stored up front.
+    Borrowed(&'s T),
+    /// An item built by a lazy source.
+    Built(Rc<T>),
+}
+
+#[cfg(feature = "list")]
+impl<T> Deref for CachedItem<'_, T> {
+    type Target = T;
+
+    fn deref(&self) -> &T {
+        match self {
+            Self::Borrowed(item) => item,
+            Self::Built(item) => item,
+        }
+    }
+}
@@ -6,8 +6,15 @@ use tuxtui_core::style::Style;
 use tuxtui_core::symbols;
 use tuxtui_core::terminal::Widget;
 
+/// Symbols for a partly filled cell in eighths, without Unicode.
+const ASCII_PARTS: [&str; 8] = [" ", ".", ".", ".", ":", ":", ":", ":"];
+
 /// A sparkline widget for rendering compact charts.
 ///
+/// Bars are drawn with block characters, or with `#`, `:` and `.` with
+/// [`Sparkline::use_unicode`] or when ASCII only is set in the
+/// [`UiSettings`](tuxtui_core::settings::UiSettings).
+///
 /// # Example
 ///
 /// ```
@@ -19,11 +26,18 @@ use tuxtui_core::terminal::Widget;
 ///     .data(&data)
 ///     .style(Style::default().fg(Color::Cyan));
 /// ```
-#[derive(Debug, Clone, PartialEq, Eq, Default)]
+#[derive(Debug, Clone, PartialEq, Eq)]
 pub struct Sparkline<'a> {
     data: &'a [u64],
     style: Style,
     max: Option<u64>,
+    use_unicode: bool,
+}
+
+impl Default for Sparkline<'_> {
+    fn default() -> Self {
+        Self::new()
+    }
 }
 
 impl<'a> Sparkline<'a> {
@@ -34,6 +48,7 @@ impl<'a> Sparkline<'a> {
             data: &[],
             style: Style::new(),
             max: None,
+            use_unicode: true,
         }
     }
 
@@ -57,6 +72,14 @@ impl<'a> Sparkline<'a> {
         self.max = Some(max);
         self
     }
+
+    /// Set whether to draw with block characters (the default), or with
+    /// ASCII only.
+    #[must_use]
+    pub const fn use_unicode(mut self, use_unicode: bool) -> Self {
+        self.use_unicode = use_unicode;
+        self
+    }
 }
 
 impl Widget for Sparkline<'_> {
@@ -73,6 +96,11 @@ impl Widget for Sparkline<'_> {
             return;
         }
 
+        let (full, parts) = if self.use_unicode && !buf.settings.ascii_only {
+            (symbols::BAR_FULL, &symbols::BLOCKS[..8])
+        } else {
+            ("#", &ASCII_PARTS[..])
+        };
         let height = area.height as u64;
         let width = area.width.min(self.data.len() as u16);
 
@@ -87,7 +115,7 @@ impl Widget for Sparkline<'_> {
             for j in 0..full_blocks {
                 let y = area.bottom() - 1 - j;
                 if y >= area.top() {
-                    buf.set(x, y, symbols::BAR_FULL, self.style);
+                    buf.set(x, y, full, self.style);
                 }
             }
 
@@ -95,8 +123,7 @@ impl Widget for Sparkline<'_> {
             if remainder > 0 && full_blocks < area.height {
                 let y = area.bottom() - 1 - full_blocks;
                 if y >= area.top() {
-                    let symbol = symbols::BLOCKS[remainder];
-                    buf.set(x, y, symbol, self.style);
+                    buf.set(x, y, parts[remainder], self.style);
                 }
             }
         }
@@ -121,4 +148,19 @@ mod tests {
         let sparkline = Sparkline::default().data(&data).max(10);
         assert_eq!(sparkline.max, Some(10));
     }
+
+    #[test]
+    fn test_ascii_bars() {
+        let data = vec![8, 4, 1, 0];
+        let mut buf = Buffer::empty(Rect::new(0, 0, 4, 2));
+        Sparkline::new()
+            .data(&data)
+            .use_unicode(false)
+            .render(buf.area, &mut buf);
+        assert_eq!(buf.get(0, 0).unwrap().symbol(), "#");
+        assert_eq!(buf.get(1, 0).unwrap().symbol(), " ");
+        assert_eq!(buf.get(1, 1).unwrap().symbol(), "#");
+        assert_eq!(buf.get(2, 1).unwrap().symbol(), ".");
+        assert_eq!(buf.get(3, 1).unwrap().symbol(), " ");
+    }
 }
@@ -0,0 +1,271 @@
+//! Status line widget with left, centered and right-aligned segments.
+
+use alloc::vec::Vec;
+use tuxtui_core::buffer::Buffer;
+use tuxtui_core::geometry::Rect;
+use tuxtui_core::style::Style;
+use tuxtui_core::terminal::Widget;
+use tuxtui_core::text::Line;
+use unicode_width::UnicodeWidthStr;
+
+/// A segment of a [`StatusLine`], such as a mode indicator or a clock.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct StatusSegment<'a> {
+    content: Line<'a>,
+    priority: u8,
+}
+
+impl<'a> StatusSegment<'a> {
+    /// Create a segment with the default priority of 0.
+    #[must_use]
+    pub fn new(content: impl Into<Line<'a>>) -> Self {
+        Self {
+            content: content.into(),
+            priority: 0,
+        }
+    }
+
+    /// Set the priority. Segments with a lower priority are dropped first
+    /// when the status line does not fit.
+    #[must_use]
+    pub const fn priority(mut self, priority: u8) -> Self {
+        self.priority = priority;
+        self
+    }
+}
+
+impl<'a, T: Into<Line<'a>>> From<T> for StatusSegment<'a> {
+    fn from(content: T) -> Self {
+        Self::new(content)
+    }
+}
+
+/// Which group of a [`StatusLine`] a segment belongs to.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Group {
+    Left,
+    Center,
+    Right,
+}
+
+/// A single row with left-aligned, centered and right-aligned segments.
+///
+/// Segments within a group are joined by the separator. When everything
+/// does not fit, the segments with the lowest priority are dropped, the
+/// last one first, and the centered group moves aside to stay clear of the
+/// others.
+///
+/// # Example
+///
+/// ```
+/// use tuxtui_core::prelude::*;
+/// use tuxtui_widgets::status::{StatusLine, StatusSegment};
+///
+/// let status = StatusLine::new()
+///     .left(Span::styled(" NORMAL ", Style::default().bg(Color::Blue)))
+///     .center("main.rs")
+///     .right(StatusSegment::new("12:34").priority(1))
+///     .right("ln 4, col 2");
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct StatusLine<'a> {
+    segments: Vec<(Group, StatusSegment<'a>)>,
+    style: Style,
+    separator: &'a str,
+}
+
+impl Default for StatusLine<'_> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<'a> StatusLine<'a> {
+    /// Create an empty status line.
+    #[must_use]
+    pub fn new() -> Self {
+        Self {
+            segments: Vec::new(),
+            style: Style::default(),
+            separator: " ",
+        }
+    }
+
+    /// Add a left-aligned segment after the previous ones.
+    #[must_use]
+    pub fn left(mut self, segment: impl Into<StatusSegment<'a>>) -> Self {
+        self.segments.push((Group::Left, segment.into()));
+        self
+    }
+
+    /// Add a centered segment after the previous ones.
+    #[must_use]
+    pub fn center(mut self, segment: impl Into<StatusSegment<'a>>) -> Self {
+        self.segments.push((Group::Center, segment.into()));
+        self
+    }
+
+    /// Add a right-aligned segment after the previous ones.
+    #[must_use]
+    pub fn right(mut self, segment: impl Into<StatusSegment<'a>>) -> Self {
+        self.segments.push((Group::Right, segment.into()));
+        self
+    }
+
+    /// Set the overall style.
+    #[must_use]
+    pub const fn style(mut self, style: Style) -> Self {
+        self.style = style;
+        self
+    }
+
+    /// Set the text between segments of a group.
+    #[must_use]
+    pub const fn separator(mut self, separator: &'a str) -> Self {
+        self.separator = separator;
+        self
+    }
+
+    /// Get the width of the visible segments in `group`.
+    fn group_width(&self, visible: &[bool], group: Group) -> usize {
+        let (count, width) = self
+            .segments
+            .iter()
+            .zip(visible)
+            .filter(|((g, _), visible)| **visible && *g == group)
+            .fold((0_usize, 0), |(count, width), ((_, segment), _)| {
+                (count + 1, width + segment.content.width())
+            });
+        width + self.separator.width() * count.saturating_sub(1)
+    }
+
+    /// Get the total width, with a space between non-empty groups.
+    fn total_width(&self, visible: &[bool]) -> usize {
+        let widths = [Group::Left, Group::Center, Group::Right]
+            .map(|group| self.group_width(visible, group));
+        let groups = widths.iter().filter(|&&width| width > 0).count();
+        widths.iter().sum::<usize>() + groups.saturating_sub(1)
+    }
+
+    /// Draw the visible segments of `group` from `x` and get the x after.
+    fn render_group(
+        &self,
+        visible: &[bool],
+        group: Group,
+        x: u16,
+        area: Rect,
+        buf: &mut Buffer,
+    ) -> u16 {
+        let mut x = x;
+        let segments = self
+            .segments
+            .iter()
+            .zip(visible)
+            .filter(|((g, _), visible)| **visible && *g == group);
+        for (i, ((_, segment), _)) in segments.enumerate() {
+            if i > 0 {
+                let width = area.right().saturating_sub(x);
+                x = buf
+                    .set_line(x, area.y, &Line::from(self.separator), width)
+                    .0;
+            }
+            let width = area.right().saturating_sub(x);
+            x = buf.set_line(x, area.y, &segment.content, width).0;
+        }
+        x
+    }
+}
+
+impl Widget for StatusLine<'_> {
+    fn render(self, area: Rect, buf: &mut Buffer) {
+        if area.area() == 0 {
+            return;
+        }
+        let area = Rect::new(area.x, area.y, area.width, 1);
+        buf.set_style(area, self.style);
+
+        let mut visible = alloc::vec![true; self.segments.len()];
+        while self.total_width(&visible) > usize::from(area.width) {
+            let lowest = self
+                .segments
+                .iter()
+                .enumerate()
+                .rev()
+                .filter(|(i, _)| visible[*i])
+                .min_by_key(|(_, (_, segment))| segment.priority)
+                .map(|(i, _)| i);
+            match lowest {
+                Some(i) => visible[i] = false,
+                None => break,
+            }
+        }
+
+        let width = |group| u16::try_from(self.group_width(&visible, group)).unwrap_or(u16::MAX);
+        let (left, center, right) = (
+            width(Group::Left),
+            width(Group::Center),
+            width(Group::Right),
+        );
+        let left_end = self.render_group(&visible, Group::Left, area.left(), area, buf);
+        let right_start = area.right().saturating_sub(right).max(area.left());
+        self.render_group(&visible, Group::Right, right_start, area, buf);
+        if center > 0 {
+            let gap = |width| u16::from(width > 0);
+            let lo = left_end + gap(left);
+            let hi = right_start.saturating_sub(gap(right));
+            let centered = area.left() + area.width.saturating_sub(center) / 2;
+            let x = centered.min(hi.saturating_sub(center)).max(lo);
+            // Clip the centered group at the right-aligned one.
+            let clip = Rect::new(area.x, area.y, hi.saturating_sub(area.x), 1);
+            self.render_group(&visible, Group::Center, x, clip, buf);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tuxtui_core::assert_buffer_eq;
+
+    fn render(status: StatusLine<'_>, width: u16) -> Buffer {
+        let mut buf = Buffer::empty(Rect::new(0, 0, width, 1));
+        status.render(buf.area, &mut buf);
+        buf
+    }
+
+    #[test]
+    fn test_groups() {
+        let status = StatusLine::new()
+            .left("NORMAL")
+            .center("main.rs")
+            .right("4:2")
+            .right("utf-8");
+        assert_buffer_eq!(
+            render(status, 30),
+            Buffer::from_lines(&["NORMAL     main.rs   4:2 utf-8"])
+        );
+    }
+
+    #[test]
+    fn test_center_moves_aside() {
+        let status = StatusLine::new().left("a long mode").center("file");
+        assert_buffer_eq!(
+            render(status, 16),
+            Buffer::from_lines(&["a long mode file"])
+        );
+    }
+
+    #[test]
+    fn test_overflow_drops_low_priority() {
+        let status = StatusLine::new()
+            .left(StatusSegment::new("NORMAL").priority(2))
+            .center(StatusSegment::new("main.rs").priority(1))
+            .right("12:34")
+            .right(StatusSegment::new("4:2").priority(1));
+        assert_buffer_eq!(
+            render(status.clone(), 18),
+            Buffer::from_lines(&["NORMAL main.rs 4:2"])
+        );
+        assert_buffer_eq!(render(status, 12), Buffer::from_lines(&["NORMAL      "]));
+    }
+}
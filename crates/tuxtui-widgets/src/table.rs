@@ -1,28 +1,40 @@
 //! Table widget for rendering tabular data.
 
+use crate::pager::Pager;
+use crate::scroll::scroll_offset;
+use crate::source::ItemSource;
+use crate::wrap::wrap_chars;
+use alloc::borrow::Cow;
 use alloc::vec::Vec;
 use tuxtui_core::buffer::Buffer;
-use tuxtui_core::geometry::Rect;
+use tuxtui_core::geometry::{Position, Rect};
 use tuxtui_core::layout::Constraint;
 use tuxtui_core::style::{Style, Stylize};
+use tuxtui_core::symbols::LineStyle;
 use tuxtui_core::terminal::Widget;
-use tuxtui_core::text::Line;
+use tuxtui_core::text::{Text, Truncate};
+use tuxtui_core::viewport::ViewportState;
 
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
 
 /// A table row.
 ///
+/// Each cell holds a [`Text`], so cells may span several lines. Lines wider
+/// than their column wrap onto the following lines of the row, and anything
+/// that does not fit within the row height or column width is clipped.
+///
 /// # Example
 ///
 /// ```
 /// use tuxtui_widgets::table::Row;
 ///
 /// let row = Row::new(vec!["Cell 1", "Cell 2", "Cell 3"]);
+/// let tall = Row::new(vec!["Name", "First line\nSecond line"]).height(2);
 /// ```
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct Row<'a> {
-    cells: Vec<Line<'a>>,
+    cells: Vec<Text<'a>>,
     style: Style,
     height: u16,
 }
@@ -33,7 +45,7 @@ impl<'a> Row<'a> {
     pub fn new<T>(cells: T) -> Self
     where
         T: IntoIterator,
-        T::Item: Into<Line<'a>>,
+        T::Item: Into<Text<'a>>,
     {
         Self {
             cells: cells.into_iter().map(Into::into).collect(),
@@ -58,7 +70,7 @@ impl<'a> Row<'a> {
 
     /// Get the cells in this row.
     #[must_use]
-    pub fn cells(&self) -> &[Line<'a>] {
+    pub fn cells(&self) -> &[Text<'a>] {
         &self.cells
     }
 }
@@ -71,11 +83,23 @@ impl<'a> Stylize for Row<'a> {
 }
 
 /// Table state for tracking selection.
+///
+/// The selection and scroll offset live in a [`ViewportState`], which is
+/// updated with the number of rows and the number of visible rows whenever
+/// the table is rendered.
 #[derive(Debug, Clone, Default, PartialEq, Eq)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct TableState {
-    selected: Option<usize>,
-    offset: usize,
+    viewport: ViewportState,
+    page: usize,
+    scroll_padding: usize,
+    /// Index, top row relative to the table area, and height of each body
+    /// row drawn by the last render.
+    #[cfg_attr(feature = "serde", serde(skip))]
+    rendered: Vec<(usize, u16, u16)>,
+    /// Column spacing used by the last render.
+    #[cfg_attr(feature = "serde", serde(skip))]
+    column_spacing: u16,
 }
 
 impl TableState {
@@ -83,20 +107,34 @@ impl TableState {
     #[must_use]
     pub const fn new() -> Self {
         Self {
-            selected: None,
-            offset: 0,
+            viewport: ViewportState::new(),
+            page: 0,
+            scroll_padding: 0,
+            rendered: Vec::new(),
+            column_spacing: 0,
         }
     }
 
+    /// Get the viewport tracking the selection and scroll offset.
+    #[must_use]
+    pub const fn viewport(&self) -> &ViewportState {
+        &self.viewport
+    }
+
+    /// Get the viewport mutably.
+    pub const fn viewport_mut(&mut self) -> &mut ViewportState {
+        &mut self.viewport
+    }
+
     /// Get the selected row index.
     #[must_use]
     pub const fn selected(&self) -> Option<usize> {
-        self.selected
+        self.viewport.selected()
     }
 
     /// Select a row.
     pub fn select(&mut self, index: Option<usize>) {
-        self.selected = index;
+        self.viewport.select(index);
     }
 
     /// Select the next row.
@@ -104,10 +142,10 @@ impl TableState {
         if rows_len == 0 {
             return;
         }
-        self.selected = Some(match self.selected {
+        self.select(Some(match self.selected() {
             Some(i) => (i + 1) % rows_len,
             None => 0,
-        });
+        }));
     }
 
     /// Select the previous row.
@@ -115,27 +153,137 @@ impl TableState {
         if rows_len == 0 {
             return;
         }
-        self.selected = Some(match self.selected {
-            Some(i) => {
-                if i == 0 {
-                    rows_len - 1
-                } else {
-                    i - 1
-                }
-            }
-            None => rows_len - 1,
-        });
+        self.select(Some(match self.selected() {
+            Some(0) | None => rows_len - 1,
+            Some(i) => i - 1,
+        }));
+    }
+
+    /// Select the row a page below the selection.
+    ///
+    /// A page is the number of rows visible when the table was last rendered.
+    pub fn select_next_page(&mut self) {
+        self.viewport.select_next_page();
+    }
+
+    /// Select the row a page above the selection.
+    pub fn select_previous_page(&mut self) {
+        self.viewport.select_previous_page();
+    }
+
+    /// Scroll so that a row is visible.
+    pub fn ensure_visible(&mut self, index: usize) {
+        self.viewport.ensure_visible(index);
+    }
+
+    /// Get how far the table is scrolled, from 0.0 to 1.0.
+    #[must_use]
+    pub fn scroll_percentage(&self) -> f64 {
+        self.viewport.scroll_percentage()
     }
 
     /// Get the scroll offset.
     #[must_use]
     pub const fn offset(&self) -> usize {
-        self.offset
+        self.viewport.offset()
     }
 
     /// Set the scroll offset.
+    #[deprecated(note = "use `viewport_mut().set_offset()` instead")]
     pub fn set_offset(&mut self, offset: usize) {
-        self.offset = offset;
+        self.viewport.set_offset(offset);
+    }
+
+    /// Get the row and column of the cell drawn at `position`, such as a
+    /// mouse click.
+    ///
+    /// `area` is the area the table was last rendered into and `widths` are
+    /// the column constraints it was given. The header, footer and the
+    /// spacing between columns have no cell.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use tuxtui_core::prelude::*;
+    /// use tuxtui_widgets::table::{Row, Table, TableState};
+    ///
+    /// let widths = [Constraint::Length(3), Constraint::Fill(1)];
+    /// let area = Rect::new(0, 0, 10, 3);
+    /// let mut buf = Buffer::empty(area);
+    /// let mut state = TableState::new();
+    /// let rows = [Row::new(["a", "b"]), Row::new(["c", "d"])];
+    /// Table::new(rows, widths)
+    ///     .header(Row::new(["x", "y"]))
+    ///     .render_stateful(area, &mut buf, &mut state);
+    /// assert_eq!(state.cell_at(Position::new(5, 2), area, &widths), Some((1, 1)));
+    /// ```
+    #[must_use]
+    pub fn cell_at(
+        &self,
+        position: Position,
+        area: Rect,
+        widths: &[Constraint],
+    ) -> Option<(usize, usize)> {
+        if !area.contains(position) {
+            return None;
+        }
+        let y = position.y - area.y;
+        let row = self
+            .rendered
+            .iter()
+            .find(|&&(_, top, height)| (top..top + height).contains(&y))
+            .map(|&(index, _, _)| index)?;
+
+        let mut x = area.left();
+        for (column, width) in column_widths(widths, self.column_spacing, area.width)
+            .into_iter()
+            .enumerate()
+        {
+            if (x..x.saturating_add(width)).contains(&position.x) {
+                return Some((row, column));
+            }
+            x = x.saturating_add(width).saturating_add(self.column_spacing);
+        }
+        None
+    }
+
+    /// Keep up to `padding` items visible above and below the selection
+    /// when scrolling, like Vim's `scrolloff`.
+    ///
+    /// The padding shrinks near the ends of the table and when the viewport
+    /// is too small to fit it.
+    #[must_use]
+    pub const fn scroll_padding(mut self, padding: usize) -> Self {
+        self.scroll_padding = padding;
+        self
+    }
+
+    /// Set the scroll padding. See [`TableState::scroll_padding`].
+    pub fn set_scroll_padding(&mut self, padding: usize) {
+        self.scroll_padding = padding;
+    }
+
+    /// Get the current page of a paginated table.
+    #[must_use]
+    pub const fn page(&self) -> usize {
+        self.page
+    }
+
+    /// Set the current page of a paginated table.
+    ///
+    /// The page is clamped to the last page when the table is rendered.
+    pub fn set_page(&mut self, page: usize) {
+        self.page = page;
+    }
+
+    /// Move to the next page of a paginated table.
+    pub fn next_page(&mut self) {
+        self.page = self.page.saturating_add(1);
+    }
+
+    /// Move to the previous page of a paginated table.
+    pub fn prev_page(&mut self) {
+        self.page = self.page.saturating_sub(1);
     }
 }
 
@@ -155,16 +303,25 @@ impl TableState {
 /// ];
 ///
 /// let table = Table::new(rows, [Constraint::Length(10), Constraint::Fill(1), Constraint::Length(10)])
-///     .header(Row::new(vec!["Name", "Data", "Value"]));
+///     .header(Row::new(vec!["Name", "Data", "Value"]))
+///     .footer(Row::new(vec!["Total", "", "2"]))
+///     .column_separator(symbols::NORMAL.vertical)
+///     .header_separator(symbols::NORMAL);
 /// ```
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct Table<'a> {
-    rows: Vec<Row<'a>>,
+    rows: ItemSource<'a, Row<'a>>,
     widths: Vec<Constraint>,
     header: Option<Row<'a>>,
+    footer: Option<Row<'a>>,
     style: Style,
     highlight_style: Style,
     column_spacing: u16,
+    column_separator: Option<&'static str>,
+    header_separator: Option<LineStyle>,
+    page_size: Option<usize>,
+    truncate: Option<Truncate>,
+    ellipsis: &'static str,
 }
 
 impl<'a> Table<'a> {
@@ -176,17 +333,72 @@ impl<'a> Table<'a> {
         R::Item: Into<Row<'a>>,
         C: IntoIterator,
         C::Item: Into<Constraint>,
+    {
+        Self::from_source(
+            ItemSource::Owned(rows.into_iter().map(Into::into).collect()),
+            widths,
+        )
+    }
+
+    /// Create a table of `len` rows built on demand by `row_fn`.
+    ///
+    /// Only the rows in the visible window are built when rendering, so
+    /// this is suitable for very large datasets.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use tuxtui_core::prelude::*;
+    /// use tuxtui_widgets::table::{Row, Table};
+    ///
+    /// let table = Table::from_fn(1_000_000, [Constraint::Fill(1)], |i| {
+    ///     Row::new(vec![format!("Row {i}")])
+    /// });
+    /// assert_eq!(table.len(), 1_000_000);
+    /// ```
+    #[must_use]
+    pub fn from_fn<C, F>(len: usize, widths: C, row_fn: F) -> Self
+    where
+        C: IntoIterator,
+        C::Item: Into<Constraint>,
+        F: Fn(usize) -> Row<'a> + Send + Sync + 'a,
+    {
+        Self::from_source(ItemSource::lazy(len, row_fn), widths)
+    }
+
+    fn from_source<C>(rows: ItemSource<'a, Row<'a>>, widths: C) -> Self
+    where
+        C: IntoIterator,
+        C::Item: Into<Constraint>,
     {
         Self {
-            rows: rows.into_iter().map(Into::into).collect(),
+            rows,
             widths: widths.into_iter().map(Into::into).collect(),
             header: None,
+            footer: None,
             style: Style::default(),
             highlight_style: Style::default(),
             column_spacing: 1,
+            column_separator: None,
+            header_separator: None,
+            page_size: None,
+            truncate: None,
+            ellipsis: "…",
         }
     }
 
+    /// Get the number of rows, excluding the header and footer.
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.rows.len()
+    }
+
+    /// Check if the table has no rows.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.rows.is_empty()
+    }
+
     /// Set the table header.
     #[must_use]
     pub fn header(mut self, header: Row<'a>) -> Self {
@@ -194,6 +406,16 @@ impl<'a> Table<'a> {
         self
     }
 
+    /// Set the table footer.
+    ///
+    /// The footer is pinned to the bottom of the table area regardless of how
+    /// many rows are displayed.
+    #[must_use]
+    pub fn footer(mut self, footer: Row<'a>) -> Self {
+        self.footer = Some(footer);
+        self
+    }
+
     /// Set the overall style.
     #[must_use]
     pub const fn style(mut self, style: Style) -> Self {
@@ -215,44 +437,55 @@ impl<'a> Table<'a> {
         self
     }
 
-    fn calculate_column_widths(&self, available_width: u16) -> Vec<u16> {
-        let spacing_total = self
-            .column_spacing
-            .saturating_mul(self.widths.len().saturating_sub(1) as u16);
-        let available = available_width.saturating_sub(spacing_total);
-
-        let mut widths = Vec::with_capacity(self.widths.len());
-        let mut fixed_width = 0u16;
-        let mut fill_count = 0u32;
-
-        for constraint in &self.widths {
-            match constraint {
-                Constraint::Length(len) => {
-                    widths.push(*len);
-                    fixed_width = fixed_width.saturating_add(*len);
-                }
-                Constraint::Fill(weight) => {
-                    widths.push(0);
-                    fill_count += *weight as u32;
-                }
-                _ => {
-                    let width = constraint.apply(available);
-                    widths.push(width);
-                    fixed_width = fixed_width.saturating_add(width);
-                }
-            }
-        }
+    /// Draw a vertical separator glyph between columns.
+    ///
+    /// The glyph is drawn in the middle of the column spacing, so it has no
+    /// effect when the spacing is zero.
+    #[must_use]
+    pub const fn column_separator(mut self, symbol: &'static str) -> Self {
+        self.column_separator = Some(symbol);
+        self
+    }
 
-        let remaining = available.saturating_sub(fixed_width);
-        if fill_count > 0 {
-            for (i, constraint) in self.widths.iter().enumerate() {
-                if let Constraint::Fill(weight) = constraint {
-                    widths[i] = ((remaining as u32 * *weight as u32) / fill_count) as u16;
-                }
-            }
-        }
+    /// Draw a horizontal line below the header (and above the footer).
+    ///
+    /// Where the line meets a column separator, the line style's cross
+    /// symbol is used.
+    #[must_use]
+    pub const fn header_separator(mut self, line_style: LineStyle) -> Self {
+        self.header_separator = Some(line_style);
+        self
+    }
+
+    /// Truncate cell lines that are too wide, marking the cut with an ellipsis.
+    ///
+    /// By default, long cell lines wrap within the row height instead.
+    #[must_use]
+    pub const fn truncate(mut self, truncate: Truncate) -> Self {
+        self.truncate = Some(truncate);
+        self
+    }
+
+    /// Set the ellipsis used by [`Table::truncate`]. Defaults to "…".
+    #[must_use]
+    pub const fn ellipsis(mut self, ellipsis: &'static str) -> Self {
+        self.ellipsis = ellipsis;
+        self
+    }
+
+    /// Render one page of `page_size` rows at a time.
+    ///
+    /// The last line of the area shows a "page X of Y" label, and the page
+    /// is controlled with [`TableState::next_page`] and
+    /// [`TableState::prev_page`] instead of following the selection.
+    #[must_use]
+    pub const fn paginate(mut self, page_size: usize) -> Self {
+        self.page_size = Some(page_size);
+        self
+    }
 
-        widths
+    fn calculate_column_widths(&self, available_width: u16) -> Vec<u16> {
+        column_widths(&self.widths, self.column_spacing, available_width)
     }
 
     /// Render the table with state.
@@ -260,41 +493,114 @@ impl<'a> Table<'a> {
         if area.area() == 0 {
             return;
         }
+        buf.set_style(area, self.style);
+
+        let mut area = area;
+        let mut page = None;
+        if let Some(page_size) = self.page_size {
+            let pager = Pager::new(page_size, self.rows.len()).with_page(state.page);
+            state.page = pager.page();
+
+            let label_area = Rect::new(area.x, area.bottom() - 1, area.width, 1);
+            pager.render_label(label_area, buf, self.style);
+            area.height -= 1;
+            page = Some(pager.range());
+        }
 
         let widths = self.calculate_column_widths(area.width);
-        let mut y = area.top();
+        let separators = self.separator_positions(&widths, area);
+
+        if let Some(symbol) = self.column_separator {
+            for &x in &separators {
+                for y in area.top()..area.bottom() {
+                    buf.set(x, y, symbol, self.style);
+                }
+            }
+        }
+
+        let mut body = area;
 
         // Render header
         if let Some(header) = &self.header {
-            if y < area.bottom() {
-                self.render_row(
-                    &header.cells,
-                    &widths,
-                    area.left(),
-                    y,
-                    area.right(),
-                    self.style.patch(header.style),
-                    buf,
-                );
-                y += header.height;
+            let height = header.height.min(body.height);
+            let header_area = Rect::new(body.x, body.y, body.width, height);
+            self.render_row(
+                header,
+                &widths,
+                header_area,
+                self.style.patch(header.style),
+                buf,
+            );
+            body = Rect::new(body.x, body.y + height, body.width, body.height - height);
+
+            if let Some(line_style) = self.header_separator {
+                if body.height > 0 {
+                    self.render_separator_line(&separators, body, body.top(), line_style, buf);
+                    body = Rect::new(body.x, body.y + 1, body.width, body.height - 1);
+                }
             }
         }
 
-        // Adjust offset
-        if let Some(selected) = state.selected() {
-            if selected < state.offset {
-                state.offset = selected;
+        // Render footer
+        if let Some(footer) = &self.footer {
+            let height = footer.height.min(body.height);
+            let footer_area = Rect::new(body.x, body.bottom() - height, body.width, height);
+            self.render_row(
+                footer,
+                &widths,
+                footer_area,
+                self.style.patch(footer.style),
+                buf,
+            );
+            body.height -= height;
+
+            if let Some(line_style) = self.header_separator {
+                if body.height > 0 {
+                    let y = body.bottom() - 1;
+                    self.render_separator_line(&separators, body, y, line_style, buf);
+                    body.height -= 1;
+                }
             }
         }
 
+        let mut y = body.top();
+
+        let visible = if let Some(range) = page {
+            range
+        } else {
+            // Adjust offset to ensure selected row is visible
+            let mut offset = state.offset();
+            if let Some(selected) = state.selected().filter(|&i| i < self.rows.len()) {
+                offset = scroll_offset(
+                    offset,
+                    selected,
+                    self.rows.len(),
+                    state.scroll_padding,
+                    body.height as usize,
+                    0,
+                    |index| {
+                        self.rows
+                            .get(index)
+                            .map_or(1, |row| row.height.max(1) as usize)
+                    },
+                );
+            }
+            offset.min(self.rows.len())..self.rows.len()
+        };
+        let offset = visible.start;
+
         // Render rows
-        let visible_rows = &self.rows[state.offset.min(self.rows.len())..];
-        for (i, row) in visible_rows.iter().enumerate() {
-            if y >= area.bottom() {
+        state.rendered.clear();
+        state.column_spacing = self.column_spacing;
+        let mut shown = 0;
+        for row_index in visible {
+            if y >= body.bottom() {
                 break;
             }
+            let Some(row) = self.rows.get(row_index) else {
+                break;
+            };
 
-            let row_index = state.offset + i;
             let is_selected = state.selected() == Some(row_index);
             let row_style = if is_selected {
                 self.style.patch(self.highlight_style).patch(row.style)
@@ -302,48 +608,154 @@ impl<'a> Table<'a> {
                 self.style.patch(row.style)
             };
 
-            self.render_row(
-                &row.cells,
-                &widths,
-                area.left(),
-                y,
-                area.right(),
-                row_style,
-                buf,
-            );
-            y += row.height;
+            let height = row.height.min(body.bottom() - y);
+            let row_area = Rect::new(body.x, y, body.width, height);
+            if is_selected {
+                buf.set_style(row_area, row_style);
+            }
+            self.render_row(&row, &widths, row_area, row_style, buf);
+            state.rendered.push((row_index, y - area.top(), height));
+            y = y.saturating_add(row.height);
+            shown += 1;
+        }
+
+        state.viewport.set_content_length(self.rows.len());
+        state.viewport.set_viewport_height(shown);
+        state.viewport.set_offset(offset);
+    }
+
+    /// Get the x coordinates of the column separators.
+    fn separator_positions(&self, widths: &[u16], area: Rect) -> Vec<u16> {
+        let mut positions = Vec::new();
+        if self.column_spacing == 0 {
+            return positions;
+        }
+
+        let mut x = area.left();
+        for &width in widths.iter().take(widths.len().saturating_sub(1)) {
+            x = x.saturating_add(width);
+            let separator = x.saturating_add(self.column_spacing / 2);
+            if separator >= area.right() {
+                break;
+            }
+            positions.push(separator);
+            x = x.saturating_add(self.column_spacing);
+        }
+        positions
+    }
+
+    fn render_separator_line(
+        &self,
+        separators: &[u16],
+        area: Rect,
+        y: u16,
+        line_style: LineStyle,
+        buf: &mut Buffer,
+    ) {
+        for x in area.left()..area.right() {
+            let symbol = if self.column_separator.is_some() && separators.contains(&x) {
+                line_style.cross
+            } else {
+                line_style.horizontal
+            };
+            buf.set(x, y, symbol, self.style);
         }
     }
 
-    #[allow(clippy::too_many_arguments)]
     fn render_row(
         &self,
-        cells: &[Line<'a>],
+        row: &Row<'a>,
         widths: &[u16],
-        left: u16,
-        y: u16,
-        right: u16,
+        area: Rect,
         style: Style,
         buf: &mut Buffer,
     ) {
-        let mut x = left;
-        for (cell, &width) in cells.iter().zip(widths.iter()) {
-            if x >= right {
+        let (y, height) = (area.top(), area.height);
+        let mut x = area.left();
+        for (cell, &width) in row.cells.iter().zip(widths.iter()) {
+            if x >= area.right() {
                 break;
             }
 
-            let cell_width = width.min(right.saturating_sub(x));
-            let mut cell_x = x;
+            let cell_area = Rect::new(x, y, width.min(area.right() - x), height);
+            let truncation = self.truncate.map(|truncate| (truncate, self.ellipsis));
+            render_cell(cell, cell_area, style, truncation, buf);
 
-            for span in &cell.spans {
-                let span_style = style.patch(span.style);
-                cell_x = buf.set_string(cell_x, y, &span.content, span_style);
-                if cell_x >= x + cell_width {
-                    break;
-                }
+            x = x.saturating_add(width).saturating_add(self.column_spacing);
+        }
+    }
+}
+
+/// Resolve column constraints against the width available to a table.
+fn column_widths(constraints: &[Constraint], spacing: u16, available_width: u16) -> Vec<u16> {
+    let spacing_total = spacing.saturating_mul(constraints.len().saturating_sub(1) as u16);
+    let available = available_width.saturating_sub(spacing_total);
+
+    let mut widths = Vec::with_capacity(constraints.len());
+    let mut fixed_width = 0u16;
+    let mut fill_count = 0u32;
+
+    for constraint in constraints {
+        match constraint {
+            Constraint::Length(len) => {
+                widths.push(*len);
+                fixed_width = fixed_width.saturating_add(*len);
+            }
+            Constraint::Fill(weight) => {
+                widths.push(0);
+                fill_count += *weight as u32;
+            }
+            _ => {
+                let width = constraint.apply(available);
+                widths.push(width);
+                fixed_width = fixed_width.saturating_add(width);
             }
+        }
+    }
 
-            x = x.saturating_add(width).saturating_add(self.column_spacing);
+    let remaining = available.saturating_sub(fixed_width);
+    if let Some(fill_count) = core::num::NonZeroU32::new(fill_count) {
+        for (i, constraint) in constraints.iter().enumerate() {
+            if let Constraint::Fill(weight) = constraint {
+                widths[i] = ((remaining as u32 * *weight as u32) / fill_count) as u16;
+            }
+        }
+    }
+
+    widths
+}
+
+/// Render a cell's text into `area`, wrapping long lines and clipping to the area.
+fn render_cell(
+    text: &Text<'_>,
+    area: Rect,
+    style: Style,
+    truncation: Option<(Truncate, &str)>,
+    buf: &mut Buffer,
+) {
+    if area.area() == 0 {
+        return;
+    }
+
+    let cell_style = style.patch(text.style);
+    let mut y = area.top();
+    for line in &text.lines {
+        let line_style = cell_style.patch(line.style);
+        let line = match truncation {
+            Some((truncate, ellipsis)) => Cow::Owned(line.clone().truncated_with(
+                area.width as usize,
+                truncate,
+                ellipsis,
+            )),
+            None => Cow::Borrowed(line),
+        };
+        for mut fragment in wrap_chars(&line, area.width as usize, false) {
+            if y >= area.bottom() {
+                return;
+            }
+            fragment.style = line_style;
+            buf.set_text(Rect::new(area.x, y, area.width, 1), &Text::from(fragment));
+            y += 1;
         }
     }
 }
@@ -359,6 +771,7 @@ impl Widget for Table<'_> {
 mod tests {
     use super::*;
     use alloc::vec;
+    use tuxtui_core::text::Line;
 
     #[test]
     fn test_table_creation() {
@@ -367,6 +780,179 @@ mod tests {
         assert_eq!(table.rows.len(), 2);
     }
 
+    #[test]
+    fn test_multiline_cell() {
+        let rows = vec![Row::new(vec!["A", "one\ntwo"]).height(2)];
+        let table = Table::new(rows, [Constraint::Length(1), Constraint::Length(5)]);
+        let mut buf = Buffer::empty(Rect::new(0, 0, 7, 2));
+        table.render(buf.area, &mut buf);
+
+        assert_eq!(buf.get(2, 0).unwrap().symbol(), "o");
+        assert_eq!(buf.get(2, 1).unwrap().symbol(), "t");
+        assert_eq!(buf.get(4, 1).unwrap().symbol(), "o");
+    }
+
+    #[test]
+    fn test_rows_wider_than_area_are_clipped() {
+        let rows = vec![Row::new(vec!["abcdef", "你好世界"])];
+        let table = Table::new(rows, [Constraint::Length(6), Constraint::Length(8)]);
+        let mut buf = Buffer::from_lines(&["..........", ".........."]);
+        table.render(Rect::new(1, 0, 8, 1), &mut buf);
+
+        assert_eq!(buf, Buffer::from_lines(&[".abcdef...", ".........."]));
+    }
+
+    #[test]
+    fn test_cell_wraps_and_clips_to_column() {
+        let rows = vec![Row::new(vec!["abcdefgh", "X"]).height(2)];
+        let table = Table::new(rows, [Constraint::Length(3), Constraint::Length(1)]);
+        let mut buf = Buffer::empty(Rect::new(0, 0, 5, 2));
+        table.render(buf.area, &mut buf);
+
+        assert_eq!(buf.get(2, 0).unwrap().symbol(), "c");
+        assert_eq!(buf.get(0, 1).unwrap().symbol(), "d");
+        // The spacing column and neighbouring cell are untouched by the overflow.
+        assert_eq!(buf.get(3, 0).unwrap().symbol(), " ");
+        assert_eq!(buf.get(4, 0).unwrap().symbol(), "X");
+        assert_eq!(buf.get(3, 1).unwrap().symbol(), " ");
+    }
+
+    #[test]
+    fn test_cell_at() {
+        let widths = [Constraint::Length(2), Constraint::Fill(1)];
+        let rows = (0..5).map(|i| Row::new(vec!["a", "b"]).height(1 + i % 2));
+        let table = Table::new(rows, widths).header(Row::new(vec!["x", "y"]));
+        let area = Rect::new(1, 1, 6, 4);
+        let mut buf = Buffer::empty(Rect::new(0, 0, 8, 6));
+        let mut state = TableState::new();
+        state.select(Some(3));
+        table.render_stateful(area, &mut buf, &mut state);
+
+        let at = |x, y| state.cell_at(Position::new(x, y), area, &widths);
+        assert_eq!(state.offset(), 2);
+        assert_eq!(at(1, 1), None);
+        assert_eq!(at(2, 2), Some((2, 0)));
+        assert_eq!(at(3, 2), None);
+        assert_eq!(at(6, 4), Some((3, 1)));
+        assert_eq!(at(7, 4), None);
+    }
+
+    #[test]
+    fn test_truncated_cells() {
+        let rows = vec![Row::new(vec!["abcdefgh", "xy"])];
+        let table = Table::new(rows, [Constraint::Length(4), Constraint::Length(2)])
+            .truncate(Truncate::Tail)
+            .ellipsis(".");
+        let mut buf = Buffer::empty(Rect::new(0, 0, 7, 2));
+        table.render(buf.area, &mut buf);
+
+        let row: alloc::string::String = (0..7).map(|x| buf.get(x, 0).unwrap().symbol()).collect();
+        assert_eq!(row, "abc. xy");
+        assert_eq!(buf.get(0, 1).unwrap().symbol(), " ");
+    }
+
+    #[test]
+    fn test_wide_graphemes_are_aligned() {
+        let rows = vec![
+            Row::new(vec![Line::from("你好")]),
+            Row::new(vec![Line::from("你好").centered()]),
+            Row::new(vec![Line::from("你好").right_aligned()]),
+        ];
+        let table = Table::new(rows, [Constraint::Length(7)]);
+        let mut buf = Buffer::empty(Rect::new(0, 0, 7, 3));
+        table.render(buf.area, &mut buf);
+
+        assert_eq!(buf, Buffer::from_lines(&["你好   ", " 你好  ", "   你好"]));
+    }
+
+    #[test]
+    fn test_wide_grapheme_not_split() {
+        let rows = vec![Row::new(vec!["a你"])];
+        let table = Table::new(rows, [Constraint::Length(2)]);
+        let mut buf = Buffer::empty(Rect::new(0, 0, 4, 1));
+        table.render(buf.area, &mut buf);
+
+        assert_eq!(buf.get(0, 0).unwrap().symbol(), "a");
+        assert_eq!(buf.get(1, 0).unwrap().symbol(), " ");
+        assert_eq!(buf.get(2, 0).unwrap().symbol(), " ");
+    }
+
+    #[test]
+    fn test_footer_pinned_to_bottom() {
+        let rows = vec![Row::new(vec!["a"])];
+        let table = Table::new(rows, [Constraint::Length(5)])
+            .header(Row::new(vec!["head"]))
+            .footer(Row::new(vec!["foot"]));
+        let mut buf = Buffer::empty(Rect::new(0, 0, 5, 5));
+        table.render(buf.area, &mut buf);
+
+        assert_eq!(buf.get(0, 0).unwrap().symbol(), "h");
+        assert_eq!(buf.get(0, 1).unwrap().symbol(), "a");
+        assert_eq!(buf.get(0, 2).unwrap().symbol(), " ");
+        assert_eq!(buf.get(0, 4).unwrap().symbol(), "f");
+    }
+
+    #[test]
+    fn test_separators() {
+        use tuxtui_core::symbols::NORMAL;
+
+        let rows = vec![Row::new(vec!["a", "b"])];
+        let table = Table::new(rows, [Constraint::Length(1), Constraint::Length(1)])
+            .header(Row::new(vec!["H", "I"]))
+            .column_separator(NORMAL.vertical)
+            .header_separator(NORMAL);
+        let mut buf = Buffer::empty(Rect::new(0, 0, 3, 3));
+        table.render(buf.area, &mut buf);
+
+        assert_eq!(buf.get(1, 0).unwrap().symbol(), "│");
+        assert_eq!(buf.get(0, 1).unwrap().symbol(), "─");
+        assert_eq!(buf.get(1, 1).unwrap().symbol(), "┼");
+        assert_eq!(buf.get(0, 2).unwrap().symbol(), "a");
+        assert_eq!(buf.get(1, 2).unwrap().symbol(), "│");
+        assert_eq!(buf.get(2, 2).unwrap().symbol(), "b");
+    }
+
+    #[test]
+    fn test_from_fn_builds_only_visible_rows() {
+        use core::sync::atomic::{AtomicUsize, Ordering};
+
+        fn assert_send_sync<T: Send + Sync>(_: &T) {}
+
+        let built = AtomicUsize::new(0);
+        let table = Table::from_fn(1_000_000, [Constraint::Length(5)], |i| {
+            built.fetch_add(1, Ordering::Relaxed);
+            Row::new(vec![alloc::format!("{i}")])
+        });
+        assert_send_sync(&table);
+        let mut state = TableState::default();
+        state.viewport_mut().set_offset(10);
+        let mut buf = Buffer::empty(Rect::new(0, 0, 5, 4));
+        table.render_stateful(buf.area, &mut buf, &mut state);
+
+        assert_eq!(built.load(Ordering::Relaxed), 4);
+        assert_eq!(buf.get(0, 0).unwrap().symbol(), "1");
+        assert_eq!(buf.get(1, 3).unwrap().symbol(), "3");
+    }
+
+    #[test]
+    fn test_paginated_table() {
+        let rows = (0..5).map(|i| Row::new(vec![alloc::format!("{i}")]));
+        let table = Table::new(rows, [Constraint::Length(11)])
+            .header(Row::new(vec!["#"]))
+            .paginate(2);
+        let mut state = TableState::default();
+        state.next_page();
+        let mut buf = Buffer::empty(Rect::new(0, 0, 11, 4));
+        table.render_stateful(buf.area, &mut buf, &mut state);
+
+        assert_eq!(state.offset(), 2);
+        assert_eq!(buf.get(0, 0).unwrap().symbol(), "#");
+        assert_eq!(buf.get(0, 1).unwrap().symbol(), "2");
+        assert_eq!(buf.get(0, 2).unwrap().symbol(), "3");
+        assert_eq!(buf.get(5, 3).unwrap().symbol(), "2");
+        assert_eq!(buf.get(10, 3).unwrap().symbol(), "3");
+    }
+
     #[test]
     fn test_table_state() {
         let mut state = TableState::default();
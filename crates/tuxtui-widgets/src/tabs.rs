@@ -1,11 +1,15 @@
 //! Tabs widget for tabbed navigation.
 
+use alloc::boxed::Box;
 use alloc::vec::Vec;
+use core::fmt;
 use tuxtui_core::buffer::Buffer;
+use tuxtui_core::event::{KeyCode, KeyEvent, MouseButton, MouseEvent, MouseEventKind};
 use tuxtui_core::geometry::Rect;
 use tuxtui_core::style::{Style, Stylize};
 use tuxtui_core::terminal::Widget;
 use tuxtui_core::text::Line;
+use unicode_width::UnicodeWidthStr;
 
 /// A tabs widget for navigation.
 ///
@@ -104,13 +108,9 @@ impl Widget for Tabs<'_> {
             };
 
             // Render title
-            for span in &title.spans {
-                let span_style = tab_style.patch(span.style);
-                x = buf.set_string(x, y, &span.content, span_style);
-                if x >= area.right() {
-                    break;
-                }
-            }
+            let mut title = title.clone();
+            title.style = tab_style.patch(title.style);
+            (x, _) = buf.set_line(x, y, &title, area.right() - x);
 
             // Render divider (except after last tab)
             if i < self.titles.len() - 1 && x < area.right() {
@@ -120,10 +120,263 @@ impl Widget for Tabs<'_> {
     }
 }
 
+/// Draws the content of a [`TabView`] page.
+type PageFn<'a> = Box<dyn FnMut(Rect, &mut Buffer) + 'a>;
+
+/// State for a [`TabView`], holding the selected page and where the tab
+/// titles were drawn.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct TabViewState {
+    selected: usize,
+    len: usize,
+    title_areas: Vec<Rect>,
+}
+
+impl TabViewState {
+    /// Create a new state with the first page selected.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Get the selected page.
+    #[must_use]
+    pub const fn selected(&self) -> usize {
+        self.selected
+    }
+
+    /// Select a page.
+    ///
+    /// The index is clamped to the pages when the view is next rendered.
+    pub const fn select(&mut self, index: usize) {
+        self.selected = index;
+    }
+
+    /// Select the next page, wrapping around.
+    pub const fn select_next(&mut self) {
+        if self.len > 0 {
+            self.selected = (self.selected + 1) % self.len;
+        }
+    }
+
+    /// Select the previous page, wrapping around.
+    pub const fn select_previous(&mut self) {
+        if self.len > 0 {
+            self.selected = (self.selected + self.len - 1) % self.len;
+        }
+    }
+
+    /// Handle a key press.
+    ///
+    /// Tab and Back Tab cycle through the pages, and Alt with a digit
+    /// selects a page by its position, starting from 1. Returns `true` if
+    /// the key was consumed.
+    pub fn handle_key(&mut self, key: &KeyEvent) -> bool {
+        if key.is_release() {
+            return false;
+        }
+        match key.code {
+            KeyCode::Tab => self.select_next(),
+            KeyCode::BackTab => self.select_previous(),
+            KeyCode::Char(c @ '1'..='9') if key.modifiers.alt => {
+                let index = c as usize - '1' as usize;
+                if index >= self.len {
+                    return false;
+                }
+                self.selected = index;
+            }
+            _ => return false,
+        }
+        true
+    }
+
+    /// Handle a mouse event over the tab titles.
+    ///
+    /// A left click selects the clicked tab, and scrolling over the titles
+    /// cycles through the pages. Returns `true` if the event was consumed.
+    pub fn handle_mouse(&mut self, event: &MouseEvent) -> bool {
+        let over = |area: &Rect| {
+            event.column >= area.left()
+                && event.column < area.right()
+                && event.row >= area.top()
+                && event.row < area.bottom()
+        };
+        match event.kind {
+            MouseEventKind::Down(MouseButton::Left) => {
+                match self.title_areas.iter().position(over) {
+                    Some(index) => self.selected = index,
+                    None => return false,
+                }
+            }
+            MouseEventKind::ScrollDown | MouseEventKind::ScrollUp
+                if self.title_areas.iter().any(over) =>
+            {
+                if event.kind == MouseEventKind::ScrollDown {
+                    self.select_next();
+                } else {
+                    self.select_previous();
+                }
+            }
+            _ => return false,
+        }
+        true
+    }
+}
+
+/// A tab header with the content of the selected page below it.
+///
+/// Each page has a title and a closure drawing its content into the area
+/// under the header. Only the selected page is drawn by default; with
+/// [`lazy`](Self::lazy) turned off, the other pages are drawn off-screen
+/// as well, so any state they update stays current while hidden.
+///
+/// # Example
+///
+/// ```
+/// use tuxtui_core::prelude::*;
+/// use tuxtui_widgets::paragraph::Paragraph;
+/// use tuxtui_widgets::tabs::{TabView, TabViewState};
+///
+/// let mut state = TabViewState::new();
+/// let mut buf = Buffer::empty(Rect::new(0, 0, 20, 5));
+/// TabView::new()
+///     .page("Info", |area, buf| Paragraph::new("Hello").render(area, buf))
+///     .page("Logs", |area, buf| Paragraph::new("No logs").render(area, buf))
+///     .highlight_style(Style::default().fg(Color::Yellow))
+///     .render_stateful(buf.area, &mut buf, &mut state);
+/// ```
+pub struct TabView<'a> {
+    pages: Vec<(Line<'a>, PageFn<'a>)>,
+    style: Style,
+    highlight_style: Style,
+    divider: &'static str,
+    lazy: bool,
+}
+
+impl fmt::Debug for TabView<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("TabView")
+            .field(
+                "titles",
+                &self
+                    .pages
+                    .iter()
+                    .map(|(title, _)| title)
+                    .collect::<Vec<_>>(),
+            )
+            .field("style", &self.style)
+            .field("highlight_style", &self.highlight_style)
+            .field("divider", &self.divider)
+            .field("lazy", &self.lazy)
+            .finish_non_exhaustive()
+    }
+}
+
+impl Default for TabView<'_> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<'a> TabView<'a> {
+    /// Create a tab view without pages.
+    #[must_use]
+    pub fn new() -> Self {
+        Self {
+            pages: Vec::new(),
+            style: Style::default(),
+            highlight_style: Style::default(),
+            divider: " │ ",
+            lazy: true,
+        }
+    }
+
+    /// Add a page drawn by `content`.
+    #[must_use]
+    pub fn page<F>(mut self, title: impl Into<Line<'a>>, content: F) -> Self
+    where
+        F: FnMut(Rect, &mut Buffer) + 'a,
+    {
+        self.pages.push((title.into(), Box::new(content)));
+        self
+    }
+
+    /// Set the style of the header.
+    #[must_use]
+    pub const fn style(mut self, style: Style) -> Self {
+        self.style = style;
+        self
+    }
+
+    /// Set the highlight style for the selected tab.
+    #[must_use]
+    pub const fn highlight_style(mut self, style: Style) -> Self {
+        self.highlight_style = style;
+        self
+    }
+
+    /// Set the divider between tabs.
+    #[must_use]
+    pub const fn divider(mut self, divider: &'static str) -> Self {
+        self.divider = divider;
+        self
+    }
+
+    /// Set whether to draw only the selected page.
+    #[must_use]
+    pub const fn lazy(mut self, lazy: bool) -> Self {
+        self.lazy = lazy;
+        self
+    }
+
+    /// Render the view with state.
+    pub fn render_stateful(mut self, area: Rect, buf: &mut Buffer, state: &mut TabViewState) {
+        state.len = self.pages.len();
+        state.selected = state.selected.min(state.len.saturating_sub(1));
+        state.title_areas.clear();
+        if area.area() == 0 || self.pages.is_empty() {
+            return;
+        }
+
+        let header = Rect::new(area.x, area.y, area.width, 1);
+        let mut x = header.left();
+        for (title, _) in &self.pages {
+            let width = u16::try_from(title.width()).unwrap_or(u16::MAX);
+            let width = width.min(header.right().saturating_sub(x));
+            state.title_areas.push(Rect::new(x, header.y, width, 1));
+            x = x
+                .saturating_add(width)
+                .saturating_add(u16::try_from(self.divider.width()).unwrap_or(u16::MAX))
+                .min(header.right());
+        }
+        Tabs::new(self.pages.iter().map(|(title, _)| title.clone()))
+            .select(state.selected)
+            .style(self.style)
+            .highlight_style(self.highlight_style)
+            .divider(self.divider)
+            .render(header, buf);
+
+        let content = Rect::new(area.x, area.y + 1, area.width, area.height - 1);
+        if content.area() == 0 {
+            return;
+        }
+        let mut hidden = (!self.lazy).then(|| Buffer::empty(content));
+        for (i, (_, page)) in self.pages.iter_mut().enumerate() {
+            if i == state.selected {
+                page(content, buf);
+            } else if let Some(hidden) = &mut hidden {
+                hidden.clear();
+                page(content, hidden);
+            }
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
     use alloc::vec;
+    use tuxtui_core::event::KeyModifiers;
 
     #[test]
     fn test_tabs_creation() {
@@ -136,4 +389,73 @@ mod tests {
         let tabs = Tabs::new(vec!["Tab1", "Tab2"]).select(1);
         assert_eq!(tabs.selected, Some(1));
     }
+
+    #[test]
+    fn test_tab_view_renders_selected_page() {
+        let mut state = TabViewState::new();
+        let mut drawn = vec![];
+        let mut buf = Buffer::empty(Rect::new(0, 0, 12, 2));
+        {
+            let drawn = core::cell::RefCell::new(&mut drawn);
+            TabView::new()
+                .divider("|")
+                .page("One", |area, buf: &mut Buffer| {
+                    drawn.borrow_mut().push(1);
+                    buf.set_string(area.x, area.y, "first", Style::default());
+                })
+                .page("Two", |area, buf: &mut Buffer| {
+                    drawn.borrow_mut().push(2);
+                    buf.set_string(area.x, area.y, "second", Style::default());
+                })
+                .render_stateful(buf.area, &mut buf, &mut state);
+        }
+        tuxtui_core::assert_buffer_eq!(buf, Buffer::from_lines(&["One|Two     ", "first       "]));
+        assert_eq!(drawn, [1]);
+
+        state.select(5);
+        let mut buf = Buffer::empty(Rect::new(0, 0, 12, 2));
+        TabView::new()
+            .divider("|")
+            .lazy(false)
+            .page("One", |_, _| drawn.push(1))
+            .page("Two", |area, buf: &mut Buffer| {
+                buf.set_string(area.x, area.y, "second", Style::default());
+            })
+            .render_stateful(buf.area, &mut buf, &mut state);
+        assert_eq!(state.selected(), 1);
+        assert_eq!(drawn, [1, 1]);
+        assert_eq!(buf.get(0, 1).unwrap().symbol(), "s");
+    }
+
+    #[test]
+    fn test_tab_view_switching() {
+        let mut state = TabViewState::new();
+        let mut buf = Buffer::empty(Rect::new(0, 0, 20, 1));
+        TabView::new()
+            .page("A", |_, _| {})
+            .page("Bee", |_, _| {})
+            .page("C", |_, _| {})
+            .render_stateful(buf.area, &mut buf, &mut state);
+
+        assert!(state.handle_key(&KeyCode::BackTab.into()));
+        assert_eq!(state.selected(), 2);
+        assert!(state.handle_key(&KeyCode::Tab.into()));
+        assert_eq!(state.selected(), 0);
+        let alt_two = KeyEvent::new(KeyCode::Char('2'), KeyModifiers::ALT);
+        assert!(state.handle_key(&alt_two));
+        assert_eq!(state.selected(), 1);
+        assert!(!state.handle_key(&KeyEvent::new(KeyCode::Char('9'), KeyModifiers::ALT)));
+
+        // "A │ Bee │ C": the last title starts at column 10.
+        let click = MouseEvent::new(MouseEventKind::Down(MouseButton::Left), 10, 0);
+        assert!(state.handle_mouse(&click));
+        assert_eq!(state.selected(), 2);
+        assert!(!state.handle_mouse(&MouseEvent::new(
+            MouseEventKind::Down(MouseButton::Left),
+            2,
+            0
+        )));
+        assert!(state.handle_mouse(&MouseEvent::new(MouseEventKind::ScrollDown, 0, 0)));
+        assert_eq!(state.selected(), 0);
+    }
 }
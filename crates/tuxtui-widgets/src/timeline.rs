@@ -0,0 +1,603 @@
+//! Timeline widget showing labeled bars across a time axis, like a Gantt chart.
+
+use alloc::borrow::Cow;
+use alloc::string::String;
+use alloc::vec::Vec;
+use core::fmt::Write;
+use core::time::Duration;
+use tuxtui_core::buffer::Buffer;
+use tuxtui_core::event::{KeyCode, KeyEvent};
+use tuxtui_core::geometry::Rect;
+use tuxtui_core::style::{Color, Modifier, Style};
+use tuxtui_core::symbols;
+use tuxtui_core::terminal::Widget;
+use unicode_width::UnicodeWidthStr;
+
+/// The zoom levels, in seconds per cell and seconds between axis labels.
+const LEVELS: [(u64, u64); 18] = [
+    (1, 10),
+    (2, 20),
+    (5, 60),
+    (10, 120),
+    (15, 120),
+    (30, 300),
+    (60, 600),
+    (120, 1800),
+    (300, 3600),
+    (600, 7200),
+    (900, 7200),
+    (1800, 21600),
+    (3600, 43200),
+    (7200, 86400),
+    (21600, 172_800),
+    (43200, 604_800),
+    (86400, 604_800),
+    (172_800, 1_209_600),
+];
+
+/// The cells panned by Left and Right.
+const PAN_CELLS: u32 = 4;
+
+/// An item of a [`Timeline`], drawn as a bar from its start to its end.
+///
+/// Times are offsets from an origin of your choosing, such as the start of
+/// a pipeline or midnight.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TimelineItem<'a> {
+    /// The label, shown left of the axis
+    pub label: Cow<'a, str>,
+    /// When the item starts
+    pub start: Duration,
+    /// When the item ends
+    pub end: Duration,
+    /// The group the item is listed under
+    pub group: Option<Cow<'a, str>>,
+    /// The style of the bar, patched onto the timeline's bar style
+    pub style: Style,
+}
+
+impl<'a> TimelineItem<'a> {
+    /// Create an item from `start` to `end`.
+    #[must_use]
+    pub fn new(label: impl Into<Cow<'a, str>>, start: Duration, end: Duration) -> Self {
+        Self {
+            label: label.into(),
+            start,
+            end: end.max(start),
+            group: None,
+            style: Style::new(),
+        }
+    }
+
+    /// List the item under a group, such as a pipeline stage.
+    #[must_use]
+    pub fn group(mut self, group: impl Into<Cow<'a, str>>) -> Self {
+        self.group = Some(group.into());
+        self
+    }
+
+    /// Set the style of the bar.
+    #[must_use]
+    pub const fn style(mut self, style: Style) -> Self {
+        self.style = style;
+        self
+    }
+}
+
+/// State for a [`Timeline`], holding the zoom level and scrolling.
+///
+/// Until zoomed or panned, the timeline fits all items in view.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct TimelineState {
+    level: usize,
+    fit: bool,
+    start: Duration,
+    offset: usize,
+}
+
+impl Default for TimelineState {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl TimelineState {
+    /// Create a state fitting all items in view.
+    #[must_use]
+    pub const fn new() -> Self {
+        Self {
+            level: 0,
+            fit: true,
+            start: Duration::ZERO,
+            offset: 0,
+        }
+    }
+
+    /// Get the time each cell spans.
+    #[must_use]
+    pub const fn scale(&self) -> Duration {
+        Duration::from_secs(LEVELS[self.level].0)
+    }
+
+    /// Get the time at the left edge of the view.
+    #[must_use]
+    pub const fn start(&self) -> Duration {
+        self.start
+    }
+
+    /// Get the index of the first row shown.
+    #[must_use]
+    pub const fn offset(&self) -> usize {
+        self.offset
+    }
+
+    /// Show less time, keeping the left edge in place.
+    pub const fn zoom_in(&mut self) {
+        self.fit = false;
+        self.level = self.level.saturating_sub(1);
+    }
+
+    /// Show more time, keeping the left edge in place.
+    pub const fn zoom_out(&mut self) {
+        self.fit = false;
+        if self.level + 1 < LEVELS.len() {
+            self.level += 1;
+        }
+    }
+
+    /// Fit all items in view again.
+    pub const fn fit(&mut self) {
+        self.fit = true;
+    }
+
+    /// Move the view `cells` cells later, or earlier if negative.
+    pub fn pan(&mut self, cells: i32) {
+        self.fit = false;
+        let by = self.scale().saturating_mul(cells.unsigned_abs());
+        self.start = if cells < 0 {
+            self.start.saturating_sub(by)
+        } else {
+            self.start.saturating_add(by)
+        };
+    }
+
+    /// Handle a key press.
+    ///
+    /// `+` and `-` zoom, `0` fits all items in view, Left and Right pan,
+    /// and Up and Down scroll the rows. Returns `true` if the key was
+    /// consumed.
+    pub fn handle_key(&mut self, key: &KeyEvent) -> bool {
+        if key.is_release() {
+            return false;
+        }
+        match key.code {
+            KeyCode::Char('+' | '=') => self.zoom_in(),
+            KeyCode::Char('-') => self.zoom_out(),
+            KeyCode::Char('0') => self.fit(),
+            KeyCode::Left => self.pan(-(PAN_CELLS as i32)),
+            KeyCode::Right => self.pan(PAN_CELLS as i32),
+            KeyCode::Up => self.offset = self.offset.saturating_sub(1),
+            KeyCode::Down => self.offset += 1,
+            _ => return false,
+        }
+        true
+    }
+}
+
+/// A row of a timeline: a group header or an item.
+enum TimelineRow<'t, 'a> {
+    Group(&'t str, Duration, Duration),
+    Item(&'t TimelineItem<'a>, bool),
+}
+
+/// Labeled bars across a horizontal time axis, such as the jobs of a CI
+/// pipeline or the tasks of a scheduler.
+///
+/// Items sharing a group are listed together under the group's name, in
+/// the order groups first appear. Labels are shown left of the bars, the
+/// time axis is labeled along the top, and a marker can show the current
+/// time. A [`TimelineState`] holds the zoom level and scrolling.
+///
+/// # Example
+///
+/// ```
+/// use core::time::Duration;
+/// use tuxtui_core::prelude::*;
+/// use tuxtui_widgets::timeline::{Timeline, TimelineItem, TimelineState};
+///
+/// let minutes = |m: u64| Duration::from_secs(m * 60);
+/// let timeline = Timeline::new([
+///     TimelineItem::new("lint", minutes(0), minutes(2)).group("check"),
+///     TimelineItem::new("test", minutes(0), minutes(6)).group("check"),
+///     TimelineItem::new("deploy", minutes(6), minutes(9)),
+/// ])
+/// .now(minutes(7));
+///
+/// let mut state = TimelineState::new();
+/// let mut buf = Buffer::empty(Rect::new(0, 0, 40, 6));
+/// timeline.render_stateful(buf.area, &mut buf, &mut state);
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Timeline<'a> {
+    items: Vec<TimelineItem<'a>>,
+    now: Option<Duration>,
+    style: Style,
+    label_style: Style,
+    group_style: Style,
+    bar_style: Style,
+    axis_style: Style,
+    now_style: Style,
+}
+
+impl<'a> Timeline<'a> {
+    /// Create a timeline of `items`.
+    #[must_use]
+    pub fn new<T>(items: T) -> Self
+    where
+        T: IntoIterator<Item = TimelineItem<'a>>,
+    {
+        Self {
+            items: items.into_iter().collect(),
+            now: None,
+            style: Style::new(),
+            label_style: Style::new(),
+            group_style: Style::new().add_modifier(Modifier::BOLD),
+            bar_style: Style::new().fg(Color::Cyan),
+            axis_style: Style::new(),
+            now_style: Style::new().fg(Color::Red),
+        }
+    }
+
+    /// Mark the current time with a vertical line.
+    #[must_use]
+    pub const fn now(mut self, now: Duration) -> Self {
+        self.now = Some(now);
+        self
+    }
+
+    /// Set the overall style.
+    #[must_use]
+    pub const fn style(mut self, style: Style) -> Self {
+        self.style = style;
+        self
+    }
+
+    /// Set the style of item labels.
+    #[must_use]
+    pub const fn label_style(mut self, style: Style) -> Self {
+        self.label_style = style;
+        self
+    }
+
+    /// Set the style of group names and the spans of groups.
+    #[must_use]
+    pub const fn group_style(mut self, style: Style) -> Self {
+        self.group_style = style;
+        self
+    }
+
+    /// Set the style of bars.
+    #[must_use]
+    pub const fn bar_style(mut self, style: Style) -> Self {
+        self.bar_style = style;
+        self
+    }
+
+    /// Set the style of the time axis and its labels.
+    #[must_use]
+    pub const fn axis_style(mut self, style: Style) -> Self {
+        self.axis_style = style;
+        self
+    }
+
+    /// Set the style of the current time marker.
+    #[must_use]
+    pub const fn now_style(mut self, style: Style) -> Self {
+        self.now_style = style;
+        self
+    }
+
+    /// List the rows, with each group's items under its header.
+    fn rows(&self) -> Vec<TimelineRow<'_, 'a>> {
+        let mut rows = Vec::with_capacity(self.items.len());
+        let mut seen: Vec<&str> = Vec::new();
+        for item in &self.items {
+            let Some(group) = item.group.as_deref() else {
+                rows.push(TimelineRow::Item(item, false));
+                continue;
+            };
+            if seen.contains(&group) {
+                continue;
+            }
+            seen.push(group);
+            let members = || {
+                self.items
+                    .iter()
+                    .filter(move |item| item.group.as_deref() == Some(group))
+            };
+            let start = members().map(|item| item.start).min().unwrap_or_default();
+            let end = members().map(|item| item.end).max().unwrap_or_default();
+            rows.push(TimelineRow::Group(group, start, end));
+            rows.extend(members().map(|item| TimelineRow::Item(item, true)));
+        }
+        rows
+    }
+
+    /// Render the timeline with state.
+    pub fn render_stateful(self, area: Rect, buf: &mut Buffer, state: &mut TimelineState) {
+        if area.area() == 0 {
+            return;
+        }
+        buf.set_style(area, self.style);
+        let rows = self.rows();
+        let label_width = rows
+            .iter()
+            .map(|row| match row {
+                TimelineRow::Group(name, ..) => name.width(),
+                TimelineRow::Item(item, indent) => item.label.width() + usize::from(*indent),
+            })
+            .max()
+            .unwrap_or(0)
+            .min(usize::from(area.width / 3)) as u16;
+        let axis_x = area.left() + label_width;
+        if axis_x + 1 >= area.right() || area.height < 2 {
+            return;
+        }
+        let plot = Rect::new(
+            axis_x + 1,
+            area.top() + 1,
+            area.right() - axis_x - 1,
+            area.height - 1,
+        );
+
+        if state.fit {
+            self.fit(state, plot.width);
+        }
+        let (scale, tick) = LEVELS[state.level];
+        let scale = scale as f64;
+        let start = state.start.as_secs_f64();
+        let to_x = |time: Duration| (time.as_secs_f64() - start) / scale;
+
+        // The time axis, labeled at multiples of the tick.
+        let axis_style = self.style.patch(self.axis_style);
+        for y in area.top()..plot.bottom() {
+            buf.set(axis_x, y, symbols::NORMAL.vertical, axis_style);
+        }
+        let mut time = state.start.as_secs().div_ceil(tick).checked_mul(tick);
+        let mut free = plot.left();
+        while let Some(at) = time {
+            let x = plot
+                .left()
+                .saturating_add(round(to_x(Duration::from_secs(at))) as u16);
+            if x >= plot.right() {
+                break;
+            }
+            let label = format_time(Duration::from_secs(at));
+            if x >= free && x + label.width() as u16 <= plot.right() {
+                buf.set_string(x, area.top(), &label, axis_style);
+                free = x + label.width() as u16 + 1;
+            }
+            time = at.checked_add(tick);
+        }
+
+        let visible = usize::from(plot.height);
+        state.offset = state.offset.min(rows.len().saturating_sub(visible));
+        let label_style = self.style.patch(self.label_style);
+        let group_style = self.style.patch(self.group_style);
+        let bar_style = self.style.patch(self.bar_style);
+        let cells = |from: Duration, to: Duration| {
+            let left = floor(to_x(from));
+            let right = ceil(to_x(to)).max(left + 1.0);
+            let clip = |x: f64| x.clamp(0.0, f64::from(plot.width)) as u16;
+            (plot.left() + clip(left), plot.left() + clip(right))
+        };
+        for (row, y) in rows
+            .iter()
+            .skip(state.offset)
+            .zip(plot.top()..plot.bottom())
+        {
+            match row {
+                TimelineRow::Group(name, from, to) => {
+                    buf.set_line(area.left(), y, &(*name).into(), label_width);
+                    buf.set_style(Rect::new(area.left(), y, label_width, 1), group_style);
+                    let (left, right) = cells(*from, *to);
+                    for x in left..right {
+                        buf.set(x, y, symbols::NORMAL.horizontal, group_style);
+                    }
+                }
+                TimelineRow::Item(item, indent) => {
+                    let indent = u16::from(*indent).min(label_width);
+                    buf.set_line(
+                        area.left() + indent,
+                        y,
+                        &item.label.as_ref().into(),
+                        label_width - indent,
+                    );
+                    buf.set_style(Rect::new(area.left(), y, label_width, 1), label_style);
+                    let style = bar_style.patch(item.style);
+                    let (left, right) = cells(item.start, item.end);
+                    for x in left..right {
+                        buf.set(x, y, symbols::BAR_FULL, style);
+                    }
+                }
+            }
+        }
+
+        if let Some(now) = self.now {
+            let x = floor(to_x(now));
+            if (0.0..f64::from(plot.width)).contains(&x) {
+                let x = plot.left() + x as u16;
+                let now_style = self.style.patch(self.now_style);
+                buf.set(x, area.top(), "▼", now_style);
+                for y in plot.top()..plot.bottom() {
+                    if buf.get(x, y).is_some_and(|cell| cell.symbol() == " ") {
+                        buf.set(x, y, symbols::NORMAL.vertical, now_style);
+                    }
+                }
+            }
+        }
+    }
+
+    /// Pick the closest zoom level showing every item within `width` cells.
+    fn fit(&self, state: &mut TimelineState, width: u16) {
+        let first = self.items.iter().map(|item| item.start).min();
+        let last = self.items.iter().map(|item| item.end).max();
+        let (Some(first), Some(last)) = (first, last) else {
+            state.level = 0;
+            state.start = Duration::ZERO;
+            return;
+        };
+        let first = first.as_secs();
+        let last = last.as_secs_f64();
+        let level = LEVELS
+            .iter()
+            .position(|&(scale, tick)| {
+                let start = first / tick * tick;
+                last - start as f64 <= (scale * u64::from(width)) as f64
+            })
+            .unwrap_or(LEVELS.len() - 1);
+        let tick = LEVELS[level].1;
+        state.level = level;
+        state.start = Duration::from_secs(first / tick * tick);
+    }
+}
+
+impl Widget for Timeline<'_> {
+    fn render(self, area: Rect, buf: &mut Buffer) {
+        self.render_stateful(area, buf, &mut TimelineState::new());
+    }
+}
+
+/// Format a time compactly, such as `1h30m` or `45s`.
+fn format_time(time: Duration) -> String {
+    let secs = time.as_secs();
+    if secs == 0 {
+        return String::from("0");
+    }
+    let parts = [
+        (secs / 86400, "d"),
+        (secs / 3600 % 24, "h"),
+        (secs / 60 % 60, "m"),
+        (secs % 60, "s"),
+    ];
+    let mut label = String::new();
+    for (count, unit) in parts {
+        if count > 0 {
+            let _ = write!(label, "{count}{unit}");
+        }
+    }
+    label
+}
+
+// The inherent float methods need std, so these fall back to libm.
+
+/// Round to the nearest integer.
+fn round(value: f64) -> f64 {
+    #[cfg(feature = "std")]
+    return value.round();
+    #[cfg(not(feature = "std"))]
+    return libm::round(value);
+}
+
+/// Round down to an integer.
+fn floor(value: f64) -> f64 {
+    #[cfg(feature = "std")]
+    return value.floor();
+    #[cfg(not(feature = "std"))]
+    return libm::floor(value);
+}
+
+/// Round up to an integer.
+fn ceil(value: f64) -> f64 {
+    #[cfg(feature = "std")]
+    return value.ceil();
+    #[cfg(not(feature = "std"))]
+    return libm::ceil(value);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn minutes(m: u64) -> Duration {
+        Duration::from_secs(m * 60)
+    }
+
+    fn pipeline() -> Timeline<'static> {
+        Timeline::new([
+            TimelineItem::new("lint", minutes(0), minutes(2)).group("check"),
+            TimelineItem::new("deploy", minutes(6), minutes(9)),
+            TimelineItem::new("test", minutes(0), minutes(6)).group("check"),
+        ])
+    }
+
+    fn rows(buf: &Buffer) -> Vec<String> {
+        buf.to_string().lines().map(String::from).collect()
+    }
+
+    #[test]
+    fn test_render_groups_and_now() {
+        let mut buf = Buffer::empty(Rect::new(0, 0, 20, 5));
+        pipeline().now(minutes(7)).render(buf.area, &mut buf);
+        assert_eq!(
+            rows(&buf),
+            [
+                "      │0      ▼  10m",
+                "check │────── │     ",
+                " lint │██     │     ",
+                " test │██████ │     ",
+                "deploy│      ███    ",
+            ]
+        );
+        assert_eq!(buf.get(7, 2).unwrap().style.fg, Some(Color::Cyan));
+        assert_eq!(buf.get(14, 1).unwrap().style.fg, Some(Color::Red));
+    }
+
+    #[test]
+    fn test_zoom_pan_and_scroll() {
+        let mut state = TimelineState::new();
+        let area = Rect::new(0, 0, 20, 3);
+        let mut buf = Buffer::empty(area);
+        pipeline().render_stateful(area, &mut buf, &mut state);
+        assert_eq!((state.scale(), state.start()), (minutes(1), minutes(0)));
+
+        assert!(state.handle_key(&KeyCode::Char('+').into()));
+        assert!(state.handle_key(&KeyCode::Right.into()));
+        assert!(state.handle_key(&KeyCode::Down.into()));
+        assert!(state.handle_key(&KeyCode::Down.into()));
+        assert!(state.handle_key(&KeyCode::Down.into()));
+        pipeline().render_stateful(area, &mut buf, &mut state);
+        assert_eq!(state.scale(), Duration::from_secs(30));
+        assert_eq!(state.start(), minutes(2));
+        assert_eq!(state.offset(), 2);
+
+        assert!(state.handle_key(&KeyCode::Char('0').into()));
+        assert!(!state.handle_key(&KeyCode::Enter.into()));
+        pipeline().render_stateful(area, &mut buf, &mut state);
+        assert_eq!((state.scale(), state.start()), (minutes(1), minutes(0)));
+    }
+
+    #[test]
+    fn test_pan_to_the_end_of_time() {
+        let late = Timeline::new([TimelineItem::new(
+            "late",
+            Duration::from_secs(u64::MAX - 60),
+            Duration::MAX,
+        )]);
+        let mut state = TimelineState::new();
+        let area = Rect::new(0, 0, 20, 3);
+        let mut buf = Buffer::empty(area);
+        late.clone().render_stateful(area, &mut buf, &mut state);
+
+        state.pan(i32::MAX);
+        assert_eq!(state.start(), Duration::MAX);
+        late.render_stateful(area, &mut buf, &mut state);
+    }
+
+    #[test]
+    fn test_format_time() {
+        assert_eq!(format_time(Duration::ZERO), "0");
+        assert_eq!(format_time(Duration::from_secs(90)), "1m30s");
+        assert_eq!(format_time(Duration::from_secs(90_000)), "1d1h");
+    }
+}
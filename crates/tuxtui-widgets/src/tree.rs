@@ -1,12 +1,15 @@
 //! Tree widget for hierarchical data display with expand/collapse.
 
+use crate::scroll::scroll_offset;
+use alloc::collections::BTreeSet;
 use alloc::string::String;
 use alloc::vec::Vec;
 use tuxtui_core::buffer::Buffer;
-use tuxtui_core::geometry::Rect;
+use tuxtui_core::geometry::{Position, Rect};
 use tuxtui_core::style::Style;
 use tuxtui_core::terminal::Widget;
-use tuxtui_core::text::Line;
+use tuxtui_core::text::{Line, Span};
+use tuxtui_core::viewport::ViewportState;
 
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
@@ -18,10 +21,14 @@ pub struct TreeNode<'a> {
     pub content: Line<'a>,
     /// Child nodes
     pub children: Vec<TreeNode<'a>>,
-    /// Whether this node is expanded
+    /// Whether this node is always expanded, regardless of the tree state
     pub expanded: bool,
     /// Node identifier (for selection)
     pub id: String,
+    /// Style applied to the node's content
+    style: Style,
+    /// Optional icon drawn before the content
+    icon: Option<Span<'a>>,
 }
 
 impl<'a> TreeNode<'a> {
@@ -33,9 +40,25 @@ impl<'a> TreeNode<'a> {
             children: Vec::new(),
             expanded: false,
             id: id.into(),
+            style: Style::new(),
+            icon: None,
         }
     }
 
+    /// Set the style for this node's content.
+    #[must_use]
+    pub const fn style(mut self, style: Style) -> Self {
+        self.style = style;
+        self
+    }
+
+    /// Set an icon drawn between the expansion indicator and the content.
+    #[must_use]
+    pub fn icon<T: Into<Span<'a>>>(mut self, icon: T) -> Self {
+        self.icon = Some(icon.into());
+        self
+    }
+
     /// Add a child node.
     #[must_use]
     pub fn child(mut self, child: TreeNode<'a>) -> Self {
@@ -57,14 +80,78 @@ impl<'a> TreeNode<'a> {
     }
 }
 
+/// A node visible in the flattened tree, with its parent's ID.
+struct VisibleNode<'n, 'a> {
+    node: &'n TreeNode<'a>,
+    parent: Option<&'n str>,
+}
+
+/// Flatten the nodes that are visible given the expanded set.
+fn visible_nodes<'n, 'a>(
+    nodes: &'n [TreeNode<'a>],
+    expanded: &BTreeSet<String>,
+) -> Vec<VisibleNode<'n, 'a>> {
+    fn walk<'n, 'a>(
+        nodes: &'n [TreeNode<'a>],
+        parent: Option<&'n str>,
+        expanded: &BTreeSet<String>,
+        out: &mut Vec<VisibleNode<'n, 'a>>,
+    ) {
+        for node in nodes {
+            out.push(VisibleNode { node, parent });
+            if node.expanded || expanded.contains(&node.id) {
+                walk(&node.children, Some(&node.id), expanded, out);
+            }
+        }
+    }
+
+    let mut out = Vec::new();
+    walk(nodes, None, expanded, &mut out);
+    out
+}
+
 /// State for a tree widget.
+///
+/// The state owns the set of expanded node IDs, so the tree itself can be
+/// rebuilt every frame while expansion persists. Navigation methods take the
+/// tree's root nodes and move over the currently visible nodes.
+///
+/// The scroll offset lives in a [`ViewportState`], which is updated with the
+/// visible nodes and the position of the selected one whenever the tree is
+/// rendered.
+///
+/// # Example
+///
+/// ```
+/// use tuxtui_widgets::tree::{TreeNode, TreeState};
+///
+/// let nodes = vec![
+///     TreeNode::new("src", "src").child(TreeNode::new("lib.rs", "src/lib.rs")),
+///     TreeNode::new("Cargo.toml", "Cargo.toml"),
+/// ];
+///
+/// let mut state = TreeState::new();
+/// state.select_next(&nodes);
+/// assert_eq!(state.selected(), Some("src"));
+///
+/// state.select_first_child(&nodes);
+/// assert!(state.is_expanded("src"));
+/// assert_eq!(state.selected(), Some("src/lib.rs"));
+///
+/// state.select_parent(&nodes);
+/// assert_eq!(state.selected(), Some("src"));
+/// ```
 #[derive(Debug, Clone, Default, PartialEq, Eq)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct TreeState {
     /// Currently selected node ID
     selected: Option<String>,
-    /// Scroll offset
-    offset: usize,
+    /// Scroll offset and the visible nodes as of the last render
+    viewport: ViewportState,
+    /// Items kept visible around the selection
+    scroll_padding: usize,
+    /// IDs of expanded nodes
+    expanded: BTreeSet<String>,
 }
 
 impl TreeState {
@@ -73,7 +160,9 @@ impl TreeState {
     pub const fn new() -> Self {
         Self {
             selected: None,
-            offset: 0,
+            viewport: ViewportState::new(),
+            scroll_padding: 0,
+            expanded: BTreeSet::new(),
         }
     }
 
@@ -88,15 +177,211 @@ impl TreeState {
         self.selected = id;
     }
 
+    /// Get the viewport tracking the scroll offset.
+    #[must_use]
+    pub const fn viewport(&self) -> &ViewportState {
+        &self.viewport
+    }
+
+    /// Get the viewport mutably.
+    pub const fn viewport_mut(&mut self) -> &mut ViewportState {
+        &mut self.viewport
+    }
+
+    /// Scroll so that the visible node at `index` is shown.
+    pub fn ensure_visible(&mut self, index: usize) {
+        self.viewport.ensure_visible(index);
+    }
+
+    /// Get how far the tree is scrolled, from 0.0 to 1.0.
+    #[must_use]
+    pub fn scroll_percentage(&self) -> f64 {
+        self.viewport.scroll_percentage()
+    }
+
     /// Get the scroll offset.
     #[must_use]
     pub const fn offset(&self) -> usize {
-        self.offset
+        self.viewport.offset()
     }
 
     /// Set the scroll offset.
+    #[deprecated(note = "use `viewport_mut().set_offset()` instead")]
     pub fn set_offset(&mut self, offset: usize) {
-        self.offset = offset;
+        self.viewport.set_offset(offset);
+    }
+
+    /// Keep up to `padding` items visible above and below the selection
+    /// when scrolling, like Vim's `scrolloff`.
+    ///
+    /// The padding shrinks near the ends of the tree and when the viewport
+    /// is too small to fit it.
+    #[must_use]
+    pub const fn scroll_padding(mut self, padding: usize) -> Self {
+        self.scroll_padding = padding;
+        self
+    }
+
+    /// Set the scroll padding. See [`TreeState::scroll_padding`].
+    pub fn set_scroll_padding(&mut self, padding: usize) {
+        self.scroll_padding = padding;
+    }
+
+    /// Check if a node is expanded in this state.
+    ///
+    /// Nodes built with [`TreeNode::expanded`] are shown expanded even when
+    /// this returns `false`.
+    #[must_use]
+    pub fn is_expanded(&self, id: &str) -> bool {
+        self.expanded.contains(id)
+    }
+
+    /// Get the IDs of the expanded nodes.
+    #[must_use]
+    pub const fn expanded(&self) -> &BTreeSet<String> {
+        &self.expanded
+    }
+
+    /// Expand a node.
+    pub fn expand<S: Into<String>>(&mut self, id: S) {
+        self.expanded.insert(id.into());
+    }
+
+    /// Collapse a node.
+    pub fn collapse(&mut self, id: &str) {
+        self.expanded.remove(id);
+    }
+
+    /// Expand every node with children, at any depth.
+    pub fn expand_all(&mut self, nodes: &[TreeNode<'_>]) {
+        for node in nodes.iter().filter(|node| node.has_children()) {
+            self.expanded.insert(node.id.clone());
+            self.expand_all(&node.children);
+        }
+    }
+
+    /// Collapse every node.
+    ///
+    /// Nodes built with [`TreeNode::expanded`] are still shown expanded.
+    pub fn collapse_all(&mut self) {
+        self.expanded.clear();
+    }
+
+    /// Toggle whether a node is expanded.
+    pub fn toggle_expanded<S: Into<String>>(&mut self, id: S) {
+        let id = id.into();
+        if !self.expanded.remove(&id) {
+            self.expanded.insert(id);
+        }
+    }
+
+    /// Select the next visible node, wrapping around to the first.
+    pub fn select_next(&mut self, nodes: &[TreeNode<'_>]) {
+        let visible = visible_nodes(nodes, &self.expanded);
+        if visible.is_empty() {
+            return;
+        }
+        let next = match self.selected_position(&visible) {
+            Some(i) => (i + 1) % visible.len(),
+            None => 0,
+        };
+        self.selected = Some(visible[next].node.id.clone());
+    }
+
+    /// Select the previous visible node, wrapping around to the last.
+    pub fn select_previous(&mut self, nodes: &[TreeNode<'_>]) {
+        let visible = visible_nodes(nodes, &self.expanded);
+        if visible.is_empty() {
+            return;
+        }
+        let previous = match self.selected_position(&visible) {
+            Some(0) | None => visible.len() - 1,
+            Some(i) => i - 1,
+        };
+        self.selected = Some(visible[previous].node.id.clone());
+    }
+
+    /// Select the visible node a page below the selection, stopping at the
+    /// last.
+    ///
+    /// A page is the number of nodes visible when the tree was last rendered.
+    pub fn select_next_page(&mut self, nodes: &[TreeNode<'_>]) {
+        let visible = visible_nodes(nodes, &self.expanded);
+        let Some(last) = visible.len().checked_sub(1) else {
+            return;
+        };
+        let page = self.viewport.viewport_len().max(1);
+        let next = self
+            .selected_position(&visible)
+            .map_or(0, |i| i.saturating_add(page))
+            .min(last);
+        self.selected = Some(visible[next].node.id.clone());
+    }
+
+    /// Select the visible node a page above the selection, stopping at the
+    /// first.
+    pub fn select_previous_page(&mut self, nodes: &[TreeNode<'_>]) {
+        let visible = visible_nodes(nodes, &self.expanded);
+        if visible.is_empty() {
+            return;
+        }
+        let page = self.viewport.viewport_len().max(1);
+        let previous = self
+            .selected_position(&visible)
+            .map_or(0, |i| i.saturating_sub(page));
+        self.selected = Some(visible[previous].node.id.clone());
+    }
+
+    /// Get the ID of the node drawn at `position`, such as a mouse click.
+    ///
+    /// `area` is the area the tree was last rendered into, with one node per
+    /// row from the scroll offset.
+    #[must_use]
+    pub fn node_at<'n>(
+        &self,
+        nodes: &'n [TreeNode<'_>],
+        position: Position,
+        area: Rect,
+    ) -> Option<&'n str> {
+        if !area.contains(position) {
+            return None;
+        }
+        let index = self.offset() + usize::from(position.y - area.y);
+        visible_nodes(nodes, &self.expanded)
+            .get(index)
+            .map(|visible| visible.node.id.as_str())
+    }
+
+    /// Select the parent of the selected node.
+    ///
+    /// Does nothing if the selected node is at the top level.
+    pub fn select_parent(&mut self, nodes: &[TreeNode<'_>]) {
+        let visible = visible_nodes(nodes, &self.expanded);
+        if let Some(parent) = self
+            .selected_position(&visible)
+            .and_then(|i| visible[i].parent)
+        {
+            self.selected = Some(parent.into());
+        }
+    }
+
+    /// Expand the selected node and select its first child.
+    ///
+    /// Does nothing if the selected node has no children.
+    pub fn select_first_child(&mut self, nodes: &[TreeNode<'_>]) {
+        let visible = visible_nodes(nodes, &self.expanded);
+        let Some(node) = self.selected_position(&visible).map(|i| visible[i].node) else {
+            return;
+        };
+        if let Some(child) = node.children.first() {
+            self.expanded.insert(node.id.clone());
+            self.selected = Some(child.id.clone());
+        }
+    }
+
+    fn selected_position(&self, visible: &[VisibleNode<'_, '_>]) -> Option<usize> {
+        let selected = self.selected.as_deref()?;
+        visible.iter().position(|v| v.node.id == selected)
     }
 }
 
@@ -105,7 +390,7 @@ impl TreeState {
 pub struct TreeSymbols {
     /// Vertical line
     pub vertical: &'static str,
-    /// Horizontal line  
+    /// Horizontal line
     pub horizontal: &'static str,
     /// T-junction (├)
     pub branch: &'static str,
@@ -117,8 +402,10 @@ pub struct TreeSymbols {
     pub collapsed: &'static str,
 }
 
-impl Default for TreeSymbols {
-    fn default() -> Self {
+impl TreeSymbols {
+    /// Box-drawing symbols (the default).
+    #[must_use]
+    pub const fn unicode() -> Self {
         Self {
             vertical: "│",
             horizontal: "─",
@@ -128,6 +415,39 @@ impl Default for TreeSymbols {
             collapsed: "▶",
         }
     }
+
+    /// Plain ASCII symbols for terminals without Unicode support.
+    #[must_use]
+    pub const fn ascii() -> Self {
+        Self {
+            vertical: "|",
+            horizontal: "-",
+            branch: "|",
+            corner: "`",
+            expanded: "-",
+            collapsed: "+",
+        }
+    }
+
+    /// Check whether all symbols are ASCII.
+    fn is_ascii(&self) -> bool {
+        [
+            self.vertical,
+            self.horizontal,
+            self.branch,
+            self.corner,
+            self.expanded,
+            self.collapsed,
+        ]
+        .iter()
+        .all(|symbol| symbol.is_ascii())
+    }
+}
+
+impl Default for TreeSymbols {
+    fn default() -> Self {
+        Self::unicode()
+    }
 }
 
 /// A tree widget for hierarchical data.
@@ -187,91 +507,120 @@ impl<'a> Tree<'a> {
         self
     }
 
-    /// Flatten tree nodes for rendering.
+    /// Flatten the visible tree nodes into `(id, line)` pairs for rendering.
+    ///
+    /// `guides` holds, for each ancestor below the top level, whether that
+    /// ancestor was the last of its siblings.
     fn flatten_nodes(
         &self,
         nodes: &[TreeNode<'a>],
-        prefix: &str,
-        _is_last: bool,
-    ) -> Vec<(String, Line<'a>, usize)> {
-        let mut result = Vec::new();
-
+        expanded: &BTreeSet<String>,
+        guides: &mut Vec<bool>,
+        out: &mut Vec<(String, Line<'a>)>,
+    ) {
+        let depth = guides.len();
         for (idx, node) in nodes.iter().enumerate() {
-            let is_node_last = idx == nodes.len() - 1;
-
-            // Build the prefix for this node
-            let node_prefix = if prefix.is_empty() {
-                String::new()
-            } else {
-                let connector = if is_node_last {
+            let is_last = idx == nodes.len() - 1;
+            let is_expanded = node.expanded || expanded.contains(&node.id);
+
+            // Top-level nodes have no connector; deeper nodes get one guide
+            // column per ancestor level, each three cells wide.
+            let mut prefix = String::new();
+            if depth > 0 {
+                for &ancestor_is_last in &guides[1..] {
+                    if ancestor_is_last {
+                        prefix.push_str("   ");
+                    } else {
+                        prefix.push_str(self.symbols.vertical);
+                        prefix.push_str("  ");
+                    }
+                }
+                prefix.push_str(if is_last {
                     self.symbols.corner
                 } else {
                     self.symbols.branch
-                };
-                alloc::format!("{}{}{} ", prefix, connector, self.symbols.horizontal)
-            };
+                });
+                prefix.push_str(self.symbols.horizontal);
+                prefix.push(' ');
+            }
 
-            // Add expansion indicator if has children
-            let expansion = if node.has_children() {
-                if node.expanded {
-                    self.symbols.expanded
-                } else {
-                    self.symbols.collapsed
-                }
-            } else {
-                " "
+            let expansion = match (node.has_children(), is_expanded) {
+                (true, true) => self.symbols.expanded,
+                (true, false) => self.symbols.collapsed,
+                (false, _) => " ",
             };
+            prefix.push_str(expansion);
+            prefix.push(' ');
 
-            let display_line = alloc::format!("{}{} {}", node_prefix, expansion, node.content);
-            result.push((node.id.clone(), Line::from(display_line), prefix.len()));
-
-            // Add children if expanded
-            if node.expanded {
-                let child_prefix = if prefix.is_empty() {
-                    String::new()
-                } else {
-                    let continuation = if is_node_last {
-                        "  "
-                    } else {
-                        self.symbols.vertical
-                    };
-                    alloc::format!("{}{} ", prefix, continuation)
-                };
-                result.extend(self.flatten_nodes(&node.children, &child_prefix, is_node_last));
+            let mut spans = alloc::vec![Span::raw(prefix)];
+            if let Some(icon) = &node.icon {
+                spans.push(icon.clone());
+                spans.push(Span::raw(" "));
+            }
+            spans.extend(node.content.spans.iter().map(|span| {
+                let style = node.style.patch(node.content.style).patch(span.style);
+                Span::styled(span.content.clone(), style)
+            }));
+            out.push((node.id.clone(), Line::from(spans)));
+
+            if is_expanded {
+                guides.push(is_last);
+                self.flatten_nodes(&node.children, expanded, guides, out);
+                guides.pop();
             }
         }
-
-        result
     }
 
     /// Render the tree with state.
-    pub fn render_stateful(self, area: Rect, buf: &mut Buffer, state: &mut TreeState) {
+    pub fn render_stateful(mut self, area: Rect, buf: &mut Buffer, state: &mut TreeState) {
         if area.area() == 0 {
             return;
         }
+        if buf.settings.ascii_only && !self.symbols.is_ascii() {
+            self.symbols = TreeSymbols::ascii();
+        }
+        buf.set_style(area, self.style);
 
-        let flat_nodes = self.flatten_nodes(&self.nodes, "", false);
+        let mut flat_nodes = Vec::new();
+        self.flatten_nodes(
+            &self.nodes,
+            &state.expanded,
+            &mut Vec::new(),
+            &mut flat_nodes,
+        );
 
         // Adjust offset to ensure selected item is visible
-        if let Some(selected_id) = &state.selected {
-            if let Some(pos) = flat_nodes.iter().position(|(id, _, _)| id == selected_id) {
-                if pos < state.offset {
-                    state.offset = pos;
-                } else if pos >= state.offset + area.height as usize {
-                    state.offset = pos.saturating_sub(area.height as usize - 1);
-                }
-            }
+        let mut offset = state.offset();
+        let selected = state
+            .selected
+            .as_ref()
+            .and_then(|selected| flat_nodes.iter().position(|(id, _)| id == selected));
+        if let Some(pos) = selected {
+            offset = scroll_offset(
+                offset,
+                pos,
+                flat_nodes.len(),
+                state.scroll_padding,
+                area.height as usize,
+                0,
+                |_| 1,
+            );
         }
+        let viewport = state.viewport_mut();
+        viewport.set_content_length(flat_nodes.len());
+        viewport.set_viewport_height(area.height as usize);
+        viewport.set_offset(offset);
+        viewport.select(selected);
 
         // Render visible nodes
         let visible = flat_nodes
-            .iter()
-            .skip(state.offset)
+            .into_iter()
+            .skip(offset)
             .take(area.height as usize);
 
-        for (i, (id, line, _depth)) in visible.enumerate() {
+        for (i, (id, line)) in visible.enumerate() {
             let y = area.top() + i as u16;
-            let is_selected = state.selected.as_ref() == Some(id);
+            let is_selected = state.selected.as_ref() == Some(&id);
 
             let item_style = if is_selected {
                 self.style.patch(self.highlight_style)
@@ -279,14 +628,12 @@ impl<'a> Tree<'a> {
                 self.style
             };
 
-            let mut x = area.left();
-            for span in &line.spans {
-                let span_style = item_style.patch(span.style);
-                x = buf.set_string(x, y, &span.content, span_style);
-                if x >= area.right() {
-                    break;
-                }
+            if is_selected {
+                buf.set_style(Rect::new(area.x, y, area.width, 1), item_style);
             }
+            let mut line = line;
+            line.style = item_style.patch(line.style);
+            buf.set_line(area.left(), y, &line, area.width);
         }
     }
 }
@@ -302,6 +649,7 @@ impl Widget for Tree<'_> {
 mod tests {
     use super::*;
     use alloc::string::ToString;
+    use alloc::vec;
 
     #[test]
     fn test_tree_node_creation() {
@@ -317,6 +665,160 @@ mod tests {
         assert_eq!(node.children.len(), 1);
     }
 
+    #[test]
+    fn test_scroll_padding() {
+        let nodes = (0..10)
+            .map(|i| TreeNode::new(alloc::format!("{i}"), alloc::format!("{i}")))
+            .collect();
+        let mut state = TreeState::new().scroll_padding(1);
+        state.select(Some("4".to_string()));
+        let mut buf = Buffer::empty(Rect::new(0, 0, 5, 4));
+        Tree::new(nodes).render_stateful(buf.area, &mut buf, &mut state);
+        assert_eq!(state.offset(), 2);
+        assert_eq!(state.viewport().selected(), Some(4));
+    }
+
+    #[test]
+    fn test_node_at() {
+        let nodes = sample();
+        let mut state = TreeState::new();
+        state.expand("a");
+        let area = Rect::new(1, 1, 6, 2);
+        let mut buf = Buffer::empty(Rect::new(0, 0, 8, 4));
+        state.select(Some("a2".to_string()));
+        Tree::new(nodes.clone()).render_stateful(area, &mut buf, &mut state);
+
+        let at = |x, y| state.node_at(&nodes, Position::new(x, y), area);
+        assert_eq!(at(1, 1), Some("a1"));
+        assert_eq!(at(6, 2), Some("a2"));
+        assert_eq!(at(0, 2), None);
+        assert_eq!(at(1, 3), None);
+    }
+
+    #[test]
+    fn test_select_page() {
+        let nodes: Vec<_> = (0..10)
+            .map(|i| TreeNode::new(alloc::format!("{i}"), alloc::format!("{i}")))
+            .collect();
+        let mut state = TreeState::new();
+        let mut buf = Buffer::empty(Rect::new(0, 0, 5, 4));
+        Tree::new(nodes.clone()).render_stateful(buf.area, &mut buf, &mut state);
+        state.select_next_page(&nodes);
+        assert_eq!(state.selected(), Some("0"));
+        state.select_next_page(&nodes);
+        assert_eq!(state.selected(), Some("4"));
+        state.select_next_page(&nodes);
+        state.select_next_page(&nodes);
+        assert_eq!(state.selected(), Some("9"));
+        state.select_previous_page(&nodes);
+        assert_eq!(state.selected(), Some("5"));
+    }
+
+    fn sample() -> Vec<TreeNode<'static>> {
+        vec![
+            TreeNode::new("a", "a")
+                .child(TreeNode::new("a1", "a1").child(TreeNode::new("a1x", "a1x")))
+                .child(TreeNode::new("a2", "a2")),
+            TreeNode::new("b", "b"),
+        ]
+    }
+
+    #[test]
+    fn test_expand_and_collapse_all() {
+        let nodes = sample();
+        let mut state = TreeState::new();
+        state.expand_all(&nodes);
+        assert_eq!(
+            state
+                .expanded()
+                .iter()
+                .map(String::as_str)
+                .collect::<Vec<_>>(),
+            ["a", "a1"]
+        );
+        state.collapse_all();
+        assert!(state.expanded().is_empty());
+    }
+
+    #[test]
+    fn test_navigation_follows_expansion() {
+        let nodes = sample();
+        let mut state = TreeState::new();
+
+        state.select_next(&nodes);
+        state.select_next(&nodes);
+        assert_eq!(state.selected(), Some("b"));
+
+        state.toggle_expanded("a");
+        state.select_previous(&nodes);
+        assert_eq!(state.selected(), Some("a2"));
+
+        state.select_previous(&nodes);
+        state.select_first_child(&nodes);
+        assert_eq!(state.selected(), Some("a1x"));
+
+        state.select_parent(&nodes);
+        state.select_parent(&nodes);
+        assert_eq!(state.selected(), Some("a"));
+        state.select_parent(&nodes);
+        assert_eq!(state.selected(), Some("a"));
+
+        state.toggle_expanded("a");
+        state.select_next(&nodes);
+        assert_eq!(state.selected(), Some("b"));
+        state.select_next(&nodes);
+        assert_eq!(state.selected(), Some("a"));
+    }
+
+    fn rows(buf: &Buffer) -> Vec<String> {
+        (0..buf.area.height)
+            .map(|y| {
+                let row: String = (0..buf.area.width)
+                    .map(|x| buf.get(x, y).unwrap().symbol())
+                    .collect();
+                row.trim_end().to_string()
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_guides() {
+        let nodes = vec![
+            TreeNode::new("a", "a")
+                .expanded(true)
+                .child(
+                    TreeNode::new("b", "b")
+                        .expanded(true)
+                        .child(TreeNode::new("c", "c")),
+                )
+                .child(TreeNode::new("d", "d")),
+        ];
+        let mut buf = Buffer::empty(Rect::new(0, 0, 12, 4));
+        Tree::new(nodes).render(buf.area, &mut buf);
+
+        assert_eq!(rows(&buf), ["▼ a", "├─ ▼ b", "│  └─   c", "└─   d"]);
+    }
+
+    #[test]
+    fn test_ascii_icons_and_node_style() {
+        use tuxtui_core::style::Color;
+
+        let red = Style::new().fg(Color::Red);
+        let nodes = vec![
+            TreeNode::new("a", "a")
+                .expanded(true)
+                .child(TreeNode::new("b", "b").icon("*").style(red)),
+        ];
+        let mut buf = Buffer::empty(Rect::new(0, 0, 8, 2));
+        Tree::new(nodes)
+            .symbols(TreeSymbols::ascii())
+            .render(buf.area, &mut buf);
+
+        assert_eq!(rows(&buf), ["- a", "`-   * b"]);
+        assert_eq!(buf.get(7, 1).unwrap().style.fg, Some(Color::Red));
+        assert_eq!(buf.get(5, 1).unwrap().style.fg, None);
+    }
+
     #[test]
     fn test_tree_state() {
         let mut state = TreeState::new();
@@ -0,0 +1,670 @@
+//! Overlapping floating windows with focus, moving and resizing.
+
+use crate::block::{Block, BorderType};
+use alloc::boxed::Box;
+use alloc::string::String;
+use alloc::vec::Vec;
+use core::fmt;
+use tuxtui_core::buffer::Buffer;
+use tuxtui_core::event::{KeyCode, KeyEvent, MouseButton, MouseEvent, MouseEventKind};
+use tuxtui_core::geometry::{Position, Rect};
+use tuxtui_core::style::{Modifier, Style};
+use tuxtui_core::terminal::Widget;
+
+/// The smallest width and height of a window, fitting its borders and one
+/// cell of content.
+const MIN_SIZE: u16 = 3;
+
+/// What the user may do with a [`Window`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct WindowFlags {
+    /// Whether the window can be moved
+    pub movable: bool,
+    /// Whether the window can be resized
+    pub resizable: bool,
+    /// Whether the window can be minimized
+    pub minimizable: bool,
+}
+
+impl WindowFlags {
+    /// Allow everything.
+    pub const ALL: Self = Self {
+        movable: true,
+        resizable: true,
+        minimizable: true,
+    };
+
+    /// Allow nothing, keeping the window where it was placed.
+    pub const FIXED: Self = Self {
+        movable: false,
+        resizable: false,
+        minimizable: false,
+    };
+}
+
+impl Default for WindowFlags {
+    fn default() -> Self {
+        Self::ALL
+    }
+}
+
+/// A floating window managed by a [`WindowManager`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Window {
+    id: String,
+    title: String,
+    area: Rect,
+    flags: WindowFlags,
+    minimized: bool,
+}
+
+impl Window {
+    /// Create a window covering `area`, including its borders.
+    #[must_use]
+    pub fn new(id: impl Into<String>, title: impl Into<String>, area: Rect) -> Self {
+        Self {
+            id: id.into(),
+            title: title.into(),
+            area,
+            flags: WindowFlags::ALL,
+            minimized: false,
+        }
+    }
+
+    /// Set what the user may do with the window.
+    #[must_use]
+    pub const fn flags(mut self, flags: WindowFlags) -> Self {
+        self.flags = flags;
+        self
+    }
+
+    /// Get the ID.
+    #[must_use]
+    pub fn id(&self) -> &str {
+        &self.id
+    }
+
+    /// Get the title.
+    #[must_use]
+    pub fn title(&self) -> &str {
+        &self.title
+    }
+
+    /// Get the area, including the borders.
+    #[must_use]
+    pub const fn area(&self) -> Rect {
+        self.area
+    }
+
+    /// Get the area inside the borders.
+    #[must_use]
+    pub fn inner(&self) -> Rect {
+        Block::new().borders(BorderType::All).inner(self.area)
+    }
+
+    /// Get what the user may do with the window.
+    #[must_use]
+    pub const fn window_flags(&self) -> WindowFlags {
+        self.flags
+    }
+
+    /// Check if the window is minimized.
+    #[must_use]
+    pub const fn is_minimized(&self) -> bool {
+        self.minimized
+    }
+}
+
+/// What a mouse drag is doing to a window.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum DragKind {
+    Move,
+    Resize,
+}
+
+/// A mouse drag in progress.
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct Drag {
+    id: String,
+    kind: DragKind,
+    origin: Position,
+    start: Rect,
+}
+
+/// A stack of floating windows, ordered from the bottom to the top.
+///
+/// The topmost window that is not minimized has the focus. Windows are moved
+/// by dragging their title bar and resized by dragging their bottom-right
+/// corner, or from the keyboard with [`handle_key`](Self::handle_key). Once
+/// rendered, windows are kept within the area they were rendered into.
+///
+/// # Example
+///
+/// ```
+/// use tuxtui_core::prelude::*;
+/// use tuxtui_widgets::window::{Window, WindowManager, Windows};
+///
+/// let mut manager = WindowManager::new();
+/// manager.add(Window::new("log", "Log", Rect::new(0, 0, 20, 6)));
+/// manager.add(Window::new("help", "Help", Rect::new(10, 3, 20, 6)));
+/// assert_eq!(manager.focused(), Some("help"));
+///
+/// manager.focus("log");
+/// assert_eq!(manager.window_at(Position::new(12, 4)), Some("log"));
+///
+/// let mut buf = Buffer::empty(Rect::new(0, 0, 40, 12));
+/// Windows::new()
+///     .content("log", |area, buf: &mut Buffer| {
+///         buf.set_string(area.x, area.y, "started", Style::default());
+///     })
+///     .render_stateful(buf.area, &mut buf, &mut manager);
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct WindowManager {
+    windows: Vec<Window>,
+    drag: Option<Drag>,
+    bounds: Option<Rect>,
+}
+
+impl WindowManager {
+    /// Create an empty window manager.
+    #[must_use]
+    pub const fn new() -> Self {
+        Self {
+            windows: Vec::new(),
+            drag: None,
+            bounds: None,
+        }
+    }
+
+    /// Add a window on top of the others, replacing any with the same ID.
+    pub fn add(&mut self, window: Window) {
+        self.remove(&window.id);
+        let window = Window {
+            area: self.clamp(window.area),
+            ..window
+        };
+        self.windows.push(window);
+    }
+
+    /// Remove a window.
+    pub fn remove(&mut self, id: &str) -> Option<Window> {
+        let index = self.position(id)?;
+        if self.drag.as_ref().is_some_and(|drag| drag.id == id) {
+            self.drag = None;
+        }
+        Some(self.windows.remove(index))
+    }
+
+    /// Get a window.
+    #[must_use]
+    pub fn get(&self, id: &str) -> Option<&Window> {
+        self.windows.iter().find(|window| window.id == id)
+    }
+
+    /// Get the windows from the bottom to the top.
+    #[must_use]
+    pub fn windows(&self) -> &[Window] {
+        &self.windows
+    }
+
+    /// Get the number of windows.
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.windows.len()
+    }
+
+    /// Check if there are no windows.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.windows.is_empty()
+    }
+
+    /// Get the ID of the focused window, the topmost one not minimized.
+    #[must_use]
+    pub fn focused(&self) -> Option<&str> {
+        self.windows
+            .iter()
+            .rev()
+            .find(|window| !window.minimized)
+            .map(Window::id)
+    }
+
+    /// Raise a window to the top and focus it, restoring it if minimized.
+    pub fn focus(&mut self, id: &str) {
+        if let Some(index) = self.position(id) {
+            let mut window = self.windows.remove(index);
+            window.minimized = false;
+            self.windows.push(window);
+        }
+    }
+
+    /// Focus the next window, sending the focused one to the bottom.
+    pub fn focus_next(&mut self) {
+        let Some(index) = self.focused_position() else {
+            return;
+        };
+        let window = self.windows.remove(index);
+        self.windows.insert(0, window);
+    }
+
+    /// Focus the window that was focused before cycling with
+    /// [`focus_next`](Self::focus_next).
+    pub fn focus_previous(&mut self) {
+        let Some(index) = self.windows.iter().position(|window| !window.minimized) else {
+            return;
+        };
+        let window = self.windows.remove(index);
+        self.windows.push(window);
+    }
+
+    /// Minimize a window, moving the focus to the one below it.
+    pub fn minimize(&mut self, id: &str) {
+        if let Some(index) = self.position(id) {
+            let window = &mut self.windows[index];
+            if window.flags.minimizable {
+                window.minimized = true;
+            }
+        }
+    }
+
+    /// Restore a minimized window without raising it.
+    pub fn restore(&mut self, id: &str) {
+        if let Some(index) = self.position(id) {
+            self.windows[index].minimized = false;
+        }
+    }
+
+    /// Place a window, ignoring its flags.
+    pub fn set_area(&mut self, id: &str, area: Rect) {
+        let area = self.clamp(area);
+        if let Some(index) = self.position(id) {
+            self.windows[index].area = area;
+        }
+    }
+
+    /// Move a movable window by the given number of cells.
+    pub fn move_by(&mut self, id: &str, dx: i32, dy: i32) {
+        let Some(window) = self.get(id).filter(|window| window.flags.movable) else {
+            return;
+        };
+        let area = window.area;
+        let area = Rect::new(
+            offset(area.x, dx),
+            offset(area.y, dy),
+            area.width,
+            area.height,
+        );
+        self.set_area(id, area);
+    }
+
+    /// Resize a resizable window by the given number of cells, keeping its
+    /// top-left corner in place.
+    pub fn resize_by(&mut self, id: &str, dw: i32, dh: i32) {
+        let Some(window) = self.get(id).filter(|window| window.flags.resizable) else {
+            return;
+        };
+        let area = window.area;
+        let width = offset(area.width, dw).max(MIN_SIZE);
+        let height = offset(area.height, dh).max(MIN_SIZE);
+        let area = Rect::new(area.x, area.y, width, height);
+        self.set_area(id, self.shrink(area));
+    }
+
+    /// Get the ID of the topmost window shown at `position`.
+    #[must_use]
+    pub fn window_at(&self, position: Position) -> Option<&str> {
+        self.windows
+            .iter()
+            .rev()
+            .find(|window| !window.minimized && window.area.contains(position))
+            .map(Window::id)
+    }
+
+    /// Handle a key for the focused window.
+    ///
+    /// `F6` and `Shift+F6` cycle the focus, `Alt+arrows` move the window,
+    /// `Alt+Shift+arrows` resize it and `Alt+m` minimizes it. Returns `true`
+    /// if the key was consumed.
+    pub fn handle_key(&mut self, key: &KeyEvent) -> bool {
+        if key.is_release() {
+            return false;
+        }
+        let Some(id) = self.focused().map(String::from) else {
+            return false;
+        };
+        let (dx, dy) = match key.code {
+            KeyCode::F(6) if key.modifiers.shift => {
+                self.focus_previous();
+                return true;
+            }
+            KeyCode::F(6) => {
+                self.focus_next();
+                return true;
+            }
+            KeyCode::Char('m') if key.modifiers.alt => {
+                self.minimize(&id);
+                return true;
+            }
+            KeyCode::Left if key.modifiers.alt => (-1, 0),
+            KeyCode::Right if key.modifiers.alt => (1, 0),
+            KeyCode::Up if key.modifiers.alt => (0, -1),
+            KeyCode::Down if key.modifiers.alt => (0, 1),
+            _ => return false,
+        };
+        if key.modifiers.shift {
+            self.resize_by(&id, dx, dy);
+        } else {
+            self.move_by(&id, dx, dy);
+        }
+        true
+    }
+
+    /// Handle a mouse event.
+    ///
+    /// A left click focuses the window under the cursor. Dragging its top
+    /// border moves it and dragging its bottom-right corner resizes it.
+    /// Returns `true` if the event was consumed.
+    pub fn handle_mouse(&mut self, event: &MouseEvent) -> bool {
+        let position = Position::new(event.column, event.row);
+        match event.kind {
+            MouseEventKind::Down(MouseButton::Left) => {
+                let Some(id) = self.window_at(position).map(String::from) else {
+                    return false;
+                };
+                self.focus(&id);
+                let Some(window) = self.get(&id) else {
+                    return false;
+                };
+                let area = window.area;
+                let corner = Position::new(area.right() - 1, area.bottom() - 1);
+                let kind = if position == corner && window.flags.resizable {
+                    Some(DragKind::Resize)
+                } else if position.y == area.top() && window.flags.movable {
+                    Some(DragKind::Move)
+                } else {
+                    None
+                };
+                self.drag = kind.map(|kind| Drag {
+                    id,
+                    kind,
+                    origin: position,
+                    start: area,
+                });
+                true
+            }
+            MouseEventKind::Drag(MouseButton::Left) => {
+                let Some(drag) = &self.drag else {
+                    return false;
+                };
+                let dx = i32::from(position.x) - i32::from(drag.origin.x);
+                let dy = i32::from(position.y) - i32::from(drag.origin.y);
+                let start = drag.start;
+                let id = drag.id.clone();
+                let area = match drag.kind {
+                    DragKind::Move => Rect::new(
+                        offset(start.x, dx),
+                        offset(start.y, dy),
+                        start.width,
+                        start.height,
+                    ),
+                    DragKind::Resize => self.shrink(Rect::new(
+                        start.x,
+                        start.y,
+                        offset(start.width, dx).max(MIN_SIZE),
+                        offset(start.height, dy).max(MIN_SIZE),
+                    )),
+                };
+                self.set_area(&id, area);
+                true
+            }
+            MouseEventKind::Up(MouseButton::Left) => self.drag.take().is_some(),
+            _ => false,
+        }
+    }
+
+    fn position(&self, id: &str) -> Option<usize> {
+        self.windows.iter().position(|window| window.id == id)
+    }
+
+    fn focused_position(&self) -> Option<usize> {
+        self.windows.iter().rposition(|window| !window.minimized)
+    }
+
+    /// Shrink `area` so that it ends within the bounds.
+    fn shrink(&self, area: Rect) -> Rect {
+        let Some(bounds) = self.bounds else {
+            return area;
+        };
+        let width = area.width.min(bounds.right().saturating_sub(area.x));
+        let height = area.height.min(bounds.bottom().saturating_sub(area.y));
+        Rect::new(area.x, area.y, width.max(MIN_SIZE), height.max(MIN_SIZE))
+    }
+
+    /// Move `area` within the bounds, if the windows have been rendered.
+    fn clamp(&self, area: Rect) -> Rect {
+        self.bounds.map_or(area, |bounds| fit(area, bounds))
+    }
+}
+
+/// Move `area` within `bounds`, shrinking it if it is larger.
+fn fit(area: Rect, bounds: Rect) -> Rect {
+    let width = area.width.min(bounds.width);
+    let height = area.height.min(bounds.height);
+    let x = area.x.clamp(bounds.left(), bounds.right() - width);
+    let y = area.y.clamp(bounds.top(), bounds.bottom() - height);
+    Rect::new(x, y, width, height)
+}
+
+/// Add a signed `delta` to `value`, saturating at the bounds of `u16`.
+fn offset(value: u16, delta: i32) -> u16 {
+    u16::try_from((i32::from(value) + delta).max(0)).unwrap_or(u16::MAX)
+}
+
+/// Draws the content of a [`Window`].
+type ContentFn<'a> = Box<dyn FnMut(Rect, &mut Buffer) + 'a>;
+
+/// Renders the windows of a [`WindowManager`] with their content.
+///
+/// Windows are drawn from the bottom to the top, each clearing the area it
+/// covers, so only the visible parts of lower windows remain. Content is
+/// given the inside of its window.
+pub struct Windows<'a> {
+    contents: Vec<(&'a str, ContentFn<'a>)>,
+    style: Style,
+    focused_style: Style,
+}
+
+impl fmt::Debug for Windows<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Windows")
+            .field(
+                "contents",
+                &self.contents.iter().map(|(id, _)| id).collect::<Vec<_>>(),
+            )
+            .field("style", &self.style)
+            .field("focused_style", &self.focused_style)
+            .finish()
+    }
+}
+
+impl Default for Windows<'_> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<'a> Windows<'a> {
+    /// Create a renderer with no content for any window.
+    #[must_use]
+    pub fn new() -> Self {
+        Self {
+            contents: Vec::new(),
+            style: Style::default(),
+            focused_style: Style::default().add_modifier(Modifier::BOLD),
+        }
+    }
+
+    /// Set the closure drawing the content of the window with `id`.
+    ///
+    /// The closure must keep its writes inside the area it is given, for
+    /// example by writing through [`Buffer::view_mut`].
+    #[must_use]
+    pub fn content(mut self, id: &'a str, content: impl FnMut(Rect, &mut Buffer) + 'a) -> Self {
+        self.contents.push((id, Box::new(content)));
+        self
+    }
+
+    /// Set the style of the windows.
+    #[must_use]
+    pub const fn style(mut self, style: Style) -> Self {
+        self.style = style;
+        self
+    }
+
+    /// Set the style patched onto the border and title of the focused window.
+    #[must_use]
+    pub const fn focused_style(mut self, style: Style) -> Self {
+        self.focused_style = style;
+        self
+    }
+
+    /// Render the windows, keeping them within `area`.
+    pub fn render_stateful(mut self, area: Rect, buf: &mut Buffer, manager: &mut WindowManager) {
+        manager.bounds = Some(area);
+        for window in &mut manager.windows {
+            window.area = fit(window.area, area);
+        }
+        if area.area() == 0 {
+            return;
+        }
+
+        let focused = manager.focused().map(String::from);
+        for window in manager.windows.iter().filter(|window| !window.minimized) {
+            let window_area = area.intersection(window.area);
+            buf.clear_region(window_area);
+            let style = if focused.as_deref() == Some(window.id()) {
+                self.style.patch(self.focused_style)
+            } else {
+                self.style
+            };
+            Block::new()
+                .borders(BorderType::All)
+                .title(window.title.as_str())
+                .style(style)
+                .render(window_area, buf);
+            // Keep the focused style to the border and title.
+            let inner = area.intersection(window.inner());
+            buf.clear_region(inner);
+            buf.set_style(inner, self.style);
+
+            let content = self
+                .contents
+                .iter_mut()
+                .find(|(id, _)| *id == window.id)
+                .map(|(_, content)| content);
+            if let Some(content) = content {
+                content(inner, buf);
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tuxtui_core::assert_buffer_eq;
+    use tuxtui_core::event::KeyModifiers;
+
+    fn manager() -> WindowManager {
+        let mut manager = WindowManager::new();
+        manager.add(Window::new("a", "A", Rect::new(0, 0, 6, 4)));
+        manager.add(Window::new("b", "B", Rect::new(3, 2, 6, 4)));
+        manager
+    }
+
+    #[test]
+    fn test_focus_order() {
+        let mut manager = manager();
+        assert_eq!(manager.focused(), Some("b"));
+        assert_eq!(manager.window_at(Position::new(4, 3)), Some("b"));
+
+        manager.focus_next();
+        assert_eq!(manager.focused(), Some("a"));
+        assert_eq!(manager.window_at(Position::new(4, 3)), Some("a"));
+        manager.focus_previous();
+        assert_eq!(manager.focused(), Some("b"));
+
+        manager.minimize("b");
+        assert_eq!(manager.focused(), Some("a"));
+        assert_eq!(manager.window_at(Position::new(7, 4)), None);
+        manager.focus("b");
+        assert_eq!(manager.focused(), Some("b"));
+    }
+
+    #[test]
+    fn test_occlusion() {
+        let mut manager = manager();
+        let mut buf = Buffer::empty(Rect::new(0, 0, 10, 6));
+        Windows::new()
+            .focused_style(Style::default())
+            .content("a", |area, buf: &mut Buffer| {
+                buf.view_mut(area)
+                    .set_string(0, 0, "aaaaaaaa", Style::default());
+            })
+            .render_stateful(buf.area, &mut buf, &mut manager);
+        assert_buffer_eq!(
+            buf,
+            Buffer::from_lines(&[
+                "┌A───┐    ",
+                "│aaaa│    ",
+                "│  ┌B───┐ ",
+                "└──│    │ ",
+                "   │    │ ",
+                "   └────┘ ",
+            ])
+        );
+    }
+
+    #[test]
+    fn test_keyboard_move_and_resize() {
+        let mut manager = manager();
+        let mut buf = Buffer::empty(Rect::new(0, 0, 10, 6));
+        Windows::new().render_stateful(buf.area, &mut buf, &mut manager);
+
+        let alt = KeyModifiers::ALT;
+        let alt_shift = KeyModifiers {
+            shift: true,
+            ..KeyModifiers::ALT
+        };
+        assert!(manager.handle_key(&KeyEvent::new(KeyCode::Right, alt)));
+        assert!(manager.handle_key(&KeyEvent::new(KeyCode::Right, alt)));
+        assert_eq!(manager.get("b").unwrap().area(), Rect::new(4, 2, 6, 4));
+        assert!(manager.handle_key(&KeyEvent::new(KeyCode::Up, alt_shift)));
+        assert_eq!(manager.get("b").unwrap().area(), Rect::new(4, 2, 6, 3));
+        assert!(manager.handle_key(&KeyEvent::new(KeyCode::Up, alt_shift)));
+        assert_eq!(manager.get("b").unwrap().area(), Rect::new(4, 2, 6, 3));
+        assert!(!manager.handle_key(&KeyEvent::new(KeyCode::Up, KeyModifiers::NONE)));
+    }
+
+    #[test]
+    fn test_mouse_drag() {
+        let mut manager = manager();
+        let mut buf = Buffer::empty(Rect::new(0, 0, 10, 6));
+        Windows::new().render_stateful(buf.area, &mut buf, &mut manager);
+
+        let event = |kind, column, row| MouseEvent::new(kind, column, row);
+        let left = MouseButton::Left;
+        assert!(manager.handle_mouse(&event(MouseEventKind::Down(left), 1, 0)));
+        assert_eq!(manager.focused(), Some("a"));
+        assert!(manager.handle_mouse(&event(MouseEventKind::Drag(left), 3, 1)));
+        assert_eq!(manager.get("a").unwrap().area(), Rect::new(2, 1, 6, 4));
+        assert!(manager.handle_mouse(&event(MouseEventKind::Up(left), 3, 1)));
+
+        assert!(manager.handle_mouse(&event(MouseEventKind::Down(left), 7, 4)));
+        assert!(manager.handle_mouse(&event(MouseEventKind::Drag(left), 20, 20)));
+        assert_eq!(manager.get("a").unwrap().area(), Rect::new(2, 1, 8, 5));
+        assert!(manager.handle_mouse(&event(MouseEventKind::Up(left), 20, 20)));
+        assert!(!manager.handle_mouse(&event(MouseEventKind::Drag(left), 0, 0)));
+    }
+}
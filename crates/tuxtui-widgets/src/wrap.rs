@@ -0,0 +1,282 @@
+//! Styled line wrapping shared by the text widgets.
+//!
+//! Wrapping works on graphemes, so span styles survive and wide characters
+//! (CJK, emoji) are measured by their display width.
+
+use alloc::string::String;
+use alloc::vec::Vec;
+use tuxtui_core::style::Style;
+use tuxtui_core::text::{Line, Span};
+use tuxtui_core::util::unicode::grapheme_width;
+use unicode_segmentation::UnicodeSegmentation;
+
+/// A grapheme with the style of the span it came from.
+#[derive(Debug, Clone, Copy)]
+struct Grapheme<'l> {
+    symbol: &'l str,
+    style: Style,
+    width: usize,
+}
+
+impl Grapheme<'_> {
+    fn is_whitespace(&self) -> bool {
+        self.symbol.chars().all(char::is_whitespace)
+    }
+}
+
+fn graphemes<'l>(line: &'l Line<'_>) -> impl Iterator<Item = Grapheme<'l>> {
+    line.spans.iter().flat_map(|span| {
+        span.content.graphemes(true).map(move |symbol| Grapheme {
+            symbol,
+            style: span.style,
+            width: grapheme_width(symbol),
+        })
+    })
+}
+
+/// Builds wrapped lines from graphemes, merging runs of equal style.
+struct LineBuilder<'t, 'a> {
+    template: &'t Line<'a>,
+    lines: Vec<Line<'a>>,
+    spans: Vec<Span<'a>>,
+    width: usize,
+    first_limit: usize,
+    limit: usize,
+}
+
+impl<'t, 'a> LineBuilder<'t, 'a> {
+    fn new(template: &'t Line<'a>, widths: Widths) -> Self {
+        Self {
+            template,
+            lines: Vec::new(),
+            spans: Vec::new(),
+            width: 0,
+            first_limit: widths.first.max(1),
+            limit: widths.rest.max(1),
+        }
+    }
+
+    /// Get the width available to the line being built.
+    fn limit(&self) -> usize {
+        if self.lines.is_empty() {
+            self.first_limit
+        } else {
+            self.limit
+        }
+    }
+
+    fn push(&mut self, grapheme: Grapheme<'_>) {
+        match self.spans.last_mut() {
+            Some(span) if span.style == grapheme.style => {
+                span.content.to_mut().push_str(grapheme.symbol);
+            }
+            _ => {
+                let content = String::from(grapheme.symbol);
+                self.spans.push(Span::styled(content, grapheme.style));
+            }
+        }
+        self.width += grapheme.width;
+    }
+
+    fn finish_line(&mut self) {
+        self.lines.push(Line {
+            spans: core::mem::take(&mut self.spans),
+            alignment: self.template.alignment,
+            style: self.template.style,
+            direction: self.template.direction,
+        });
+        self.width = 0;
+    }
+
+    fn finish(mut self) -> Vec<Line<'a>> {
+        if !self.spans.is_empty() || self.lines.is_empty() {
+            self.finish_line();
+        }
+        self.lines
+    }
+}
+
+/// Widths available to the first wrapped line and to the following ones.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) struct Widths {
+    pub(crate) first: usize,
+    pub(crate) rest: usize,
+}
+
+impl From<usize> for Widths {
+    fn from(width: usize) -> Self {
+        Self {
+            first: width,
+            rest: width,
+        }
+    }
+}
+
+/// Wrap a line at word boundaries to fit in `width` columns.
+///
+/// Whitespace at a wrap point is dropped. Leading whitespace (indentation)
+/// of the line is kept unless `trim` is set. Words wider than the width are
+/// broken at grapheme boundaries. Always returns at least one line.
+#[cfg(any(feature = "paragraph", feature = "list"))]
+pub(crate) fn wrap_words<'a>(
+    line: &Line<'a>,
+    width: impl Into<Widths>,
+    trim: bool,
+) -> Vec<Line<'a>> {
+    let mut builder = LineBuilder::new(line, width.into());
+    let mut pending: Vec<Grapheme<'_>> = Vec::new();
+    let mut word: Vec<Grapheme<'_>> = Vec::new();
+    let mut at_start = true;
+
+    let flush_word = |builder: &mut LineBuilder<'_, 'a>,
+                      pending: &mut Vec<Grapheme<'_>>,
+                      word: &mut Vec<Grapheme<'_>>| {
+        if word.is_empty() {
+            return;
+        }
+        let pending_width: usize = pending.iter().map(|g| g.width).sum();
+        let word_width: usize = word.iter().map(|g| g.width).sum();
+        let width = builder.limit();
+
+        if builder.width + pending_width + word_width <= width {
+            pending.drain(..).for_each(|g| builder.push(g));
+        } else if word_width > width && builder.width + pending_width < width {
+            // The word is broken anyway, so start it on the current line.
+            pending.drain(..).for_each(|g| builder.push(g));
+        } else {
+            pending.clear();
+            if builder.width > 0 {
+                builder.finish_line();
+            }
+        }
+
+        for grapheme in word.drain(..) {
+            if builder.width > 0 && builder.width + grapheme.width > builder.limit() {
+                builder.finish_line();
+            }
+            builder.push(grapheme);
+        }
+    };
+
+    for grapheme in graphemes(line) {
+        if grapheme.is_whitespace() {
+            if at_start {
+                if !trim && builder.width + grapheme.width <= builder.limit() {
+                    builder.push(grapheme);
+                }
+                continue;
+            }
+            flush_word(&mut builder, &mut pending, &mut word);
+            pending.push(grapheme);
+        } else {
+            at_start = false;
+            word.push(grapheme);
+        }
+    }
+    flush_word(&mut builder, &mut pending, &mut word);
+
+    builder.finish()
+}
+
+/// Wrap a line at grapheme boundaries to fit in `width` columns.
+///
+/// With `trim`, whitespace at the start of each wrapped line is dropped.
+/// Graphemes wider than the width get a line of their own. Always returns
+/// at least one line.
+#[cfg(any(feature = "paragraph", feature = "table"))]
+pub(crate) fn wrap_chars<'a>(
+    line: &Line<'a>,
+    width: impl Into<Widths>,
+    trim: bool,
+) -> Vec<Line<'a>> {
+    let mut builder = LineBuilder::new(line, width.into());
+
+    for grapheme in graphemes(line) {
+        if builder.width > 0 && builder.width + grapheme.width > builder.limit() {
+            builder.finish_line();
+        }
+        if trim && builder.width == 0 && grapheme.is_whitespace() {
+            continue;
+        }
+        builder.push(grapheme);
+    }
+
+    builder.finish()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloc::string::ToString;
+    #[cfg(any(feature = "paragraph", feature = "list"))]
+    use alloc::vec;
+    #[cfg(any(feature = "paragraph", feature = "list"))]
+    use tuxtui_core::style::Color;
+
+    fn texts(lines: &[Line<'_>]) -> Vec<String> {
+        lines.iter().map(ToString::to_string).collect()
+    }
+
+    #[cfg(any(feature = "paragraph", feature = "list"))]
+    #[test]
+    fn test_word_wrap_keeps_styles() {
+        let red = Style::new().fg(Color::Red);
+        let line = Line::from(vec![Span::raw("hello "), Span::styled("big world", red)]);
+        let lines = wrap_words(&line, 9, false);
+
+        assert_eq!(texts(&lines), ["hello big", "world"]);
+        assert_eq!(lines[0].spans[1].content, "big");
+        assert_eq!(lines[0].spans[1].style, red);
+        assert_eq!(lines[1].spans[0].style, red);
+    }
+
+    #[cfg(any(feature = "paragraph", feature = "list"))]
+    #[test]
+    fn test_word_wrap_indentation_and_trim() {
+        let line = Line::from("    indented text");
+        assert_eq!(
+            texts(&wrap_words(&line, 12, false)),
+            ["    indented", "text"]
+        );
+        assert_eq!(texts(&wrap_words(&line, 12, true)), ["indented", "text"]);
+    }
+
+    #[cfg(any(feature = "paragraph", feature = "list"))]
+    #[test]
+    fn test_word_wrap_breaks_long_words() {
+        let line = Line::from("a abcdefgh");
+        assert_eq!(texts(&wrap_words(&line, 4, false)), ["a ab", "cdef", "gh"]);
+    }
+
+    #[cfg(any(feature = "paragraph", feature = "list"))]
+    #[test]
+    fn test_first_line_width() {
+        let line = Line::from("aa bb cc dd");
+        let widths = Widths { first: 2, rest: 5 };
+        assert_eq!(
+            texts(&wrap_words(&line, widths, false)),
+            ["aa", "bb cc", "dd"]
+        );
+    }
+
+    #[cfg(any(feature = "paragraph", feature = "list"))]
+    #[test]
+    fn test_word_wrap_wide_characters() {
+        let line = Line::from("你好世界 🦀🦀");
+        assert_eq!(
+            texts(&wrap_words(&line, 5, false)),
+            ["你好", "世界", "🦀🦀"]
+        );
+    }
+
+    #[cfg(any(feature = "paragraph", feature = "table"))]
+    #[test]
+    fn test_char_wrap_wide_characters() {
+        let line = Line::from("你好世界 🦀🦀");
+        assert_eq!(
+            texts(&wrap_chars(&line, 3, true)),
+            ["你", "好", "世", "界 ", "🦀", "🦀"]
+        );
+        assert_eq!(texts(&wrap_chars(&line, 1, false)).len(), 7);
+    }
+}
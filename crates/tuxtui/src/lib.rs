@@ -9,6 +9,7 @@
 //!
 //! ```no_run
 //! use tuxtui::prelude::*;
+//! use tuxtui::DefaultTerminal;
 //! use tuxtui::widgets::block::{Block, BorderType};
 //! use crossterm::event::{self, Event};
 //!
@@ -53,6 +54,13 @@
 //! - `all-widgets`: Enable all widgets
 //! - `widget-calendar`: Calendar widget (requires `time` crate)
 //! - `macros`: Convenience macros
+//! - `ratatui-compat`: Conversions to and from ratatui types and an adapter
+//!   for ratatui widgets, in [`compat`]
+//! - `png`: Headless rendering to PNG images with [`render_to_png`]
+//! - `remote`: A backend for terminals reached over SSH or another async
+//!   connection, in [`remote`]
+//! - `signals`: Resize, termination and suspend handling for Unix signals,
+//!   in [`signals`]
 //!
 //! ## Architecture
 //!
@@ -63,6 +71,7 @@
 //! - `tuxtui-crossterm`: Crossterm backend
 //! - `tuxtui-termion`: Termion backend
 //! - `tuxtui-termwiz`: Termwiz backend
+//! - `tuxtui-web`: Browser backend for xterm.js
 //! - `tuxtui-macros`: Procedural macros
 //!
 //! ## Migrating from ratatui
@@ -78,7 +87,8 @@
 //! ```
 //!
 //! Most code should work with minimal changes. Replace `ratatui::` with `tuxtui::`
-//! in your imports.
+//! in your imports. With the `ratatui-compat` feature, widgets that are not
+//! ported yet keep working through [`compat::RatatuiWidget`].
 
 #![cfg_attr(docsrs, feature(doc_cfg))]
 #![forbid(unsafe_code)]
@@ -86,10 +96,26 @@
 
 // Re-export core types
 pub use tuxtui_core::{
-    backend, buffer, geometry, layout, prelude as core_prelude, style, symbols, terminal, text,
-    theme, util,
+    app, backend, buffer, event, geometry, keymap, layout, narration, prelude as core_prelude,
+    router, settings, style, symbols, terminal, text, theme, util,
 };
 
+#[cfg(feature = "ratatui-compat")]
+pub use tuxtui_core::compat;
+
+mod panic;
+pub use panic::{BacktraceMode, PanicHookOptions, install_panic_hook};
+
+#[cfg(all(unix, feature = "signals"))]
+#[cfg_attr(docsrs, doc(cfg(all(unix, feature = "signals"))))]
+pub mod signals;
+
+// Re-export test assertions
+pub use tuxtui_core::assert_buffer_eq;
+
+// Re-export layout macros; `layout!` comes along with the `layout` module
+pub use tuxtui_core::constraints;
+
 // Re-export widgets
 pub use tuxtui_widgets as widgets;
 
@@ -98,6 +124,10 @@ pub use tuxtui_widgets as widgets;
 #[cfg_attr(docsrs, doc(cfg(feature = "crossterm")))]
 pub use tuxtui_crossterm::CrosstermBackend;
 
+#[cfg(feature = "remote")]
+#[cfg_attr(docsrs, doc(cfg(feature = "remote")))]
+pub use tuxtui_crossterm::remote;
+
 #[cfg(feature = "termion")]
 #[cfg_attr(docsrs, doc(cfg(feature = "termion")))]
 pub use tuxtui_termion::TermionBackend;
@@ -119,12 +149,13 @@ pub use tuxtui_macros::*;
 /// use tuxtui::prelude::*;
 /// ```
 pub mod prelude {
-    pub use crate::backend::{Backend, TestBackend};
+    pub use crate::backend::{Backend, Capabilities, TestBackend};
     pub use crate::buffer::{Buffer, Cell};
     pub use crate::geometry::{Alignment, Margin, Position, Rect};
     pub use crate::layout::{Constraint, Direction, Flex, Layout, Spacing};
+    pub use crate::settings::UiSettings;
     pub use crate::style::{Color, Modifier, Style, Stylize};
-    pub use crate::terminal::{Frame, Terminal, TerminalOptions, Widget};
+    pub use crate::terminal::{Frame, Terminal, TerminalOptions, Viewport, Widget, WidgetRef};
     pub use crate::text::{Line, Span, Text};
     pub use crate::theme::{PaletteTheme, Theme, WidgetTheme};
 
@@ -164,15 +195,20 @@ pub type DefaultTerminal = terminal::Terminal<TermwizBackend>;
 /// - Hides the cursor
 /// - Clears the terminal
 ///
+/// # Errors
+///
+/// Fails with [`std::io::ErrorKind::ResourceBusy`] while another fullscreen
+/// terminal is active, see [`Terminal::try_new`](terminal::Terminal::try_new),
+/// or if setting up the terminal fails.
+///
 /// # Panics
 ///
-/// A panic hook is installed to restore the terminal on panic.
+/// A panic hook is installed to restore the terminal on panic and print a
+/// report of it, see [`install_panic_hook`].
 ///
 /// # Example
 ///
 /// ```no_run
-/// use tuxtui;
-///
 /// fn main() -> std::io::Result<()> {
 ///     let mut terminal = tuxtui::init()?;
 ///     // Use terminal...
@@ -184,15 +220,15 @@ pub type DefaultTerminal = terminal::Terminal<TermwizBackend>;
 pub fn init() -> std::io::Result<DefaultTerminal> {
     use std::io::stdout;
 
-    // Install panic hook to restore terminal
-    let hook = std::panic::take_hook();
-    std::panic::set_hook(Box::new(move |info| {
-        let _ = restore();
-        hook(info);
-    }));
+    install_panic_hook(PanicHookOptions::default());
 
     let backend = CrosstermBackend::new(stdout());
-    terminal::Terminal::new(backend)
+    terminal::Terminal::try_new(backend).map_err(|error| match error {
+        terminal::TerminalError::Backend(error) => error,
+        in_use @ terminal::TerminalError::InUse => {
+            std::io::Error::new(std::io::ErrorKind::ResourceBusy, in_use.to_string())
+        }
+    })
 }
 
 /// Restore the terminal to its original state.
@@ -205,8 +241,6 @@ pub fn init() -> std::io::Result<DefaultTerminal> {
 /// # Example
 ///
 /// ```no_run
-/// use tuxtui;
-///
 /// fn main() -> std::io::Result<()> {
 ///     let mut terminal = tuxtui::init()?;
 ///     // Use terminal...
@@ -227,12 +261,89 @@ pub fn restore() -> std::io::Result<()> {
     Ok(())
 }
 
+/// Render a frame of the given size without a terminal.
+///
+/// This draws once on a [`TestBackend`](backend::TestBackend), for golden
+/// tests and documentation.
+///
+/// # Example
+///
+/// ```
+/// let buffer = tuxtui::render_to_buffer(5, 1, |frame| {
+///     frame.render_widget("hello", frame.area());
+/// });
+/// assert_eq!(buffer.get(4, 0).unwrap().symbol(), "o");
+/// ```
+///
+/// # Panics
+///
+/// Only if the test backend fails, which it does not.
+pub fn render_to_buffer(
+    width: u16,
+    height: u16,
+    render: impl FnOnce(&mut terminal::Frame<'_>),
+) -> buffer::Buffer {
+    let backend = backend::TestBackend::new(width, height);
+    let mut terminal = terminal::Terminal::new(backend).expect("the test backend cannot fail");
+    terminal.draw(render).expect("the test backend cannot fail");
+    terminal.backend().buffer().clone()
+}
+
+/// Render a frame of the given size to text with ANSI escape sequences.
+///
+/// Rows are separated by newlines, so the output can be printed or stored
+/// as a golden file. Use the `Display` output of [`render_to_buffer`] for
+/// text without styles.
+///
+/// # Example
+///
+/// ```
+/// use tuxtui::style::{Style, Stylize};
+/// use tuxtui::text::Span;
+///
+/// let output = tuxtui::render_to_string(2, 1, |frame| {
+///     frame.render_widget(Span::raw("hi").red(), frame.area());
+/// });
+/// assert_eq!(output, "\x1b[0;31mhi\x1b[0m");
+/// ```
+pub fn render_to_string(
+    width: u16,
+    height: u16,
+    render: impl FnOnce(&mut terminal::Frame<'_>),
+) -> String {
+    render_to_buffer(width, height, render).to_ansi_string()
+}
+
+/// Render a frame of the given size to a PNG image.
+///
+/// See [`Buffer::to_png`](buffer::Buffer::to_png) for how cells are drawn.
+///
+/// # Example
+///
+/// ```
+/// use tuxtui::widgets::block::{Block, BorderType};
+///
+/// let png = tuxtui::render_to_png(20, 3, |frame| {
+///     let block = Block::default().title("Demo").borders(BorderType::All);
+///     frame.render_widget(block, frame.area());
+/// });
+/// assert_eq!(&png[1..4], b"PNG");
+/// ```
+#[cfg(feature = "png")]
+#[cfg_attr(docsrs, doc(cfg(feature = "png")))]
+pub fn render_to_png(
+    width: u16,
+    height: u16,
+    render: impl FnOnce(&mut terminal::Frame<'_>),
+) -> Vec<u8> {
+    render_to_buffer(width, height, render).to_png()
+}
+
 /// A convenience type for the main frame rendering callback.
 pub type FrameDrawFn<'a> = Box<dyn FnMut(&mut terminal::Frame<'_>) + 'a>;
 
 #[cfg(test)]
 mod tests {
-    use super::*;
     use crate::prelude::*;
 
     #[test]
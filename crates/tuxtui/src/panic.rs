@@ -0,0 +1,219 @@
+//! A panic hook that restores the terminal and prints a readable report.
+
+use crate::buffer::Buffer;
+use crate::geometry::Rect;
+use crate::style::{Modifier, Style};
+use crate::text::{Line, Span};
+use crate::theme::PaletteTheme;
+use std::backtrace::{Backtrace, BacktraceStatus};
+use std::io::{IsTerminal, Write};
+use std::panic::{Location, PanicHookInfo};
+
+/// When a panic report includes a backtrace.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum BacktraceMode {
+    /// When enabled with `RUST_BACKTRACE` or `RUST_LIB_BACKTRACE`
+    #[default]
+    Auto,
+    /// Always
+    Always,
+    /// Never
+    Never,
+}
+
+/// Options for [`install_panic_hook`].
+#[derive(Debug, Clone)]
+pub struct PanicHookOptions {
+    /// Put the terminal back in a usable state before reporting, such as
+    /// [`restore`](crate::restore), which is the default with the
+    /// `crossterm` feature
+    pub restore: Option<fn()>,
+    /// When to include a backtrace
+    pub backtrace: BacktraceMode,
+    /// Colors of the report
+    pub palette: PaletteTheme,
+    /// A line printed after the report, such as where to report the bug
+    pub hint: Option<String>,
+    /// Also run the hook that was installed before, after the report
+    pub call_previous: bool,
+}
+
+impl Default for PanicHookOptions {
+    fn default() -> Self {
+        Self {
+            #[cfg(feature = "crossterm")]
+            restore: Some(|| {
+                let _ = crate::restore();
+            }),
+            #[cfg(not(feature = "crossterm"))]
+            restore: None,
+            backtrace: BacktraceMode::Auto,
+            palette: PaletteTheme::default(),
+            hint: None,
+            call_previous: false,
+        }
+    }
+}
+
+/// Install a panic hook that restores the terminal, then prints a report of
+/// the panic with its message, location and optionally a backtrace.
+///
+/// [`init`](crate::init) installs it with the default options; call this
+/// instead when setting up the terminal yourself, for example with another
+/// backend. The report is styled when stderr is a terminal and plain text
+/// otherwise.
+///
+/// # Example
+///
+/// ```no_run
+/// use tuxtui::{BacktraceMode, PanicHookOptions};
+///
+/// tuxtui::install_panic_hook(PanicHookOptions {
+///     backtrace: BacktraceMode::Always,
+///     hint: Some("Please report this at https://example.com/issues".into()),
+///     ..PanicHookOptions::default()
+/// });
+/// ```
+pub fn install_panic_hook(options: PanicHookOptions) {
+    let previous = std::panic::take_hook();
+    std::panic::set_hook(Box::new(move |info| {
+        if let Some(restore) = options.restore {
+            restore();
+        }
+        let backtrace = match options.backtrace {
+            BacktraceMode::Auto => Backtrace::capture(),
+            BacktraceMode::Always => Backtrace::force_capture(),
+            BacktraceMode::Never => Backtrace::disabled(),
+        };
+        let report = report(&message(info), info.location(), &backtrace, &options);
+        let mut stderr = std::io::stderr().lock();
+        let text = if stderr.is_terminal() {
+            report.to_ansi_string()
+        } else {
+            report.to_string()
+        };
+        let _ = writeln!(stderr, "{text}");
+        drop(stderr);
+        if options.call_previous {
+            previous(info);
+        }
+    }));
+}
+
+/// Get the message a panic was raised with.
+fn message(info: &PanicHookInfo<'_>) -> String {
+    let payload = info.payload();
+    payload
+        .downcast_ref::<&str>()
+        .map(|message| (*message).to_owned())
+        .or_else(|| payload.downcast_ref::<String>().cloned())
+        .unwrap_or_else(|| String::from("Box<dyn Any>"))
+}
+
+/// Lay out the report of a panic.
+fn report(
+    message: &str,
+    location: Option<&Location<'_>>,
+    backtrace: &Backtrace,
+    options: &PanicHookOptions,
+) -> Buffer {
+    let palette = &options.palette;
+    let muted = Style::default().fg(palette.muted);
+    let thread = std::thread::current();
+    let mut lines = vec![Line::from(vec![
+        Span::styled(
+            " PANIC ",
+            Style::default()
+                .fg(palette.background)
+                .bg(palette.error)
+                .add_modifier(Modifier::BOLD),
+        ),
+        Span::styled(
+            format!(
+                " thread '{}' panicked",
+                thread.name().unwrap_or("<unnamed>")
+            ),
+            Style::default().fg(palette.error),
+        ),
+    ])];
+    lines.extend(message.lines().map(|line| {
+        Line::from(Span::styled(
+            format!("  {line}"),
+            Style::default().add_modifier(Modifier::BOLD),
+        ))
+    }));
+    if let Some(location) = location {
+        lines.push(Line::from(vec![
+            Span::styled("  at ", muted),
+            Span::styled(location.to_string(), Style::default().fg(palette.secondary)),
+        ]));
+    }
+    match backtrace.status() {
+        BacktraceStatus::Captured => {
+            lines.push(Line::from(""));
+            lines.push(Line::from(Span::styled("Backtrace:", muted)));
+            lines.extend(
+                backtrace
+                    .to_string()
+                    .lines()
+                    .map(|line| Line::from(Span::styled(line.to_owned(), muted))),
+            );
+        }
+        BacktraceStatus::Disabled if options.backtrace == BacktraceMode::Auto => {
+            lines.push(Line::from(Span::styled(
+                "  Run with RUST_BACKTRACE=1 for a backtrace",
+                muted,
+            )));
+        }
+        _ => {}
+    }
+    if let Some(hint) = &options.hint {
+        lines.push(Line::from(""));
+        lines.push(Line::from(Span::styled(
+            hint.clone(),
+            Style::default().fg(palette.primary),
+        )));
+    }
+
+    let width = lines.iter().map(Line::width).max().unwrap_or(0);
+    let width = u16::try_from(width).unwrap_or(u16::MAX);
+    let height = u16::try_from(lines.len()).unwrap_or(u16::MAX);
+    let mut buffer = Buffer::empty(Rect::new(0, 0, width, height));
+    for (y, line) in (0..height).zip(&lines) {
+        buffer.set_line(0, y, line, width);
+    }
+    buffer
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::style::Color;
+
+    #[test]
+    fn test_report() {
+        let options = PanicHookOptions {
+            backtrace: BacktraceMode::Never,
+            hint: Some("report it".into()),
+            ..PanicHookOptions::default()
+        };
+        let location = Location::caller();
+        let buffer = report(
+            "oh no\nsecond",
+            Some(location),
+            &Backtrace::disabled(),
+            &options,
+        );
+        let text = buffer.to_string();
+        let lines: Vec<_> = text.lines().map(str::trim_end).collect();
+        assert!(lines[0].starts_with(" PANIC  thread '"));
+        assert_eq!(lines[1..3], ["  oh no", "  second"]);
+        assert_eq!(lines[3], format!("  at {location}"));
+        assert_eq!(lines[4..], ["", "report it"]);
+        assert_eq!(buffer.get(0, 0).unwrap().style.bg, Some(Color::Red));
+
+        let options = PanicHookOptions::default();
+        let text = report("oh no", None, &Backtrace::disabled(), &options).to_string();
+        assert!(text.contains("RUST_BACKTRACE=1"));
+    }
+}
@@ -0,0 +1,152 @@
+//! Unix signal integration: resizes, termination and job control.
+
+use crate::backend::Backend;
+use crate::event::Event;
+use crate::terminal::Terminal;
+use signal_hook::consts::{SIGCONT, SIGSTOP, SIGTERM, SIGTSTP, SIGWINCH};
+use signal_hook::iterator::Signals;
+use std::io;
+
+/// Handles the Unix signals a terminal application cares about.
+///
+/// Signals are collected as they arrive and acted on in
+/// [`SignalHandler::handle`], called from the application's loop:
+///
+/// - `SIGWINCH` becomes an [`Event::Resize`], whether or not the backend
+///   reports resizes with its input.
+/// - `SIGTERM` restores the terminal with [`Terminal::suspend`] and marks
+///   the handler [terminated](SignalHandler::terminated), so the loop can
+///   end and the application clean up.
+/// - `SIGTSTP`, sent by `kill -TSTP` or a job control shell, suspends the
+///   terminal, stops the process, and resumes the terminal once continued.
+///   In raw mode Ctrl+Z arrives as a key instead; pass it to
+///   [`suspend_process`] to get the same behavior.
+/// - `SIGCONT` after the process was stopped from outside resumes the
+///   terminal, as the shell may have changed its state.
+///
+/// # Example
+///
+/// ```no_run
+/// use tuxtui::signals::SignalHandler;
+///
+/// let mut terminal = tuxtui::init()?;
+/// let mut signals = SignalHandler::new()?;
+/// while !signals.terminated() {
+///     if signals.handle(&mut terminal)?.is_some() {
+///         // Redraw for the new size.
+///     }
+///     // Poll input and draw...
+/// }
+/// tuxtui::restore()?;
+/// # Ok::<(), std::io::Error>(())
+/// ```
+#[derive(Debug)]
+pub struct SignalHandler {
+    signals: Signals,
+    /// Whether the terminal was resumed after stopping, so the `SIGCONT`
+    /// that continued the process needs no more work.
+    resumed: bool,
+    terminated: bool,
+}
+
+impl SignalHandler {
+    /// Start collecting `SIGWINCH`, `SIGTERM`, `SIGTSTP` and `SIGCONT`.
+    ///
+    /// From then on, `SIGTERM` and `SIGTSTP` no longer terminate or stop the
+    /// process on their own.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the signal handlers cannot be registered.
+    pub fn new() -> io::Result<Self> {
+        Ok(Self {
+            signals: Signals::new([SIGWINCH, SIGTERM, SIGTSTP, SIGCONT])?,
+            resumed: false,
+            terminated: false,
+        })
+    }
+
+    /// Act on the signals that arrived since the last call.
+    ///
+    /// Returns an [`Event::Resize`] with the current size when the terminal
+    /// was resized or resumed, meaning the application should draw.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the backend fails.
+    pub fn handle<B: Backend>(
+        &mut self,
+        terminal: &mut Terminal<B>,
+    ) -> Result<Option<Event>, B::Error> {
+        let mut redraw = false;
+        for signal in self.signals.pending() {
+            match signal {
+                SIGWINCH => redraw = true,
+                SIGTERM => {
+                    self.terminated = true;
+                    terminal.suspend()?;
+                    return Ok(None);
+                }
+                SIGTSTP => {
+                    suspend_process(terminal)?;
+                    self.resumed = true;
+                    redraw = true;
+                }
+                SIGCONT if !std::mem::take(&mut self.resumed) => {
+                    terminal.resume()?;
+                    redraw = true;
+                }
+                _ => {}
+            }
+        }
+        if !redraw {
+            return Ok(None);
+        }
+        let size = terminal.size()?;
+        Ok(Some(Event::Resize(size.width, size.height)))
+    }
+
+    /// Check whether `SIGTERM` was received.
+    #[must_use]
+    pub const fn terminated(&self) -> bool {
+        self.terminated
+    }
+}
+
+/// Suspend the terminal, stop the process as Ctrl+Z does in a shell, and
+/// resume the terminal once the process is continued with `fg`.
+///
+/// # Errors
+///
+/// Returns an error if the backend fails.
+pub fn suspend_process<B: Backend>(terminal: &mut Terminal<B>) -> Result<(), B::Error> {
+    terminal.suspend()?;
+    // Stopping cannot fail for a running process.
+    let _ = signal_hook::low_level::raise(SIGSTOP);
+    terminal.resume()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::backend::TestBackend;
+
+    #[test]
+    fn test_handle_signals() {
+        let mut handler = SignalHandler::new().unwrap();
+        let mut terminal = Terminal::new(TestBackend::new(6, 2)).unwrap();
+        assert_eq!(handler.handle(&mut terminal).unwrap(), None);
+
+        terminal.backend_mut().resize(8, 3);
+        signal_hook::low_level::raise(SIGWINCH).unwrap();
+        assert_eq!(
+            handler.handle(&mut terminal).unwrap(),
+            Some(Event::Resize(8, 3))
+        );
+
+        signal_hook::low_level::raise(SIGTERM).unwrap();
+        assert_eq!(handler.handle(&mut terminal).unwrap(), None);
+        assert!(handler.terminated());
+        assert!(terminal.backend().is_cursor_visible());
+    }
+}
@@ -0,0 +1,189 @@
+//! Dashboard served over SSH, one independent TUI per session
+//!
+//! Needs `tuxtui` with the `remote` feature, plus `russh`, `tokio` and
+//! `tokio-util` (for `compat`). Connect with `ssh -p 2222 localhost`.
+
+use russh::keys::PrivateKey;
+use russh::server::{Auth, Msg, Server as _, Session};
+use russh::{Channel, ChannelId, server};
+use std::collections::HashMap;
+use std::sync::Arc;
+use std::time::Duration;
+use tokio::sync::Mutex;
+use tokio_util::compat::TokioAsyncWriteCompatExt;
+use tuxtui::event::{Event, KeyCode};
+use tuxtui::prelude::*;
+use tuxtui::remote::{RemoteBackend, RemoteInput, SharedSize};
+use tuxtui::widgets::{
+    block::{Block, BorderType},
+    gauge::Gauge,
+    sparkline::Sparkline,
+};
+
+/// What the server keeps for each session's channel.
+struct Client {
+    size: SharedSize,
+    input: RemoteInput,
+}
+
+#[derive(Clone, Default)]
+struct App {
+    /// Channels opened but still waiting for their pty request.
+    channels: Arc<Mutex<HashMap<ChannelId, Channel<Msg>>>>,
+    clients: Arc<Mutex<HashMap<ChannelId, Client>>>,
+}
+
+impl server::Server for App {
+    type Handler = Self;
+
+    fn new_client(&mut self, _: Option<std::net::SocketAddr>) -> Self {
+        self.clone()
+    }
+}
+
+impl server::Handler for App {
+    type Error = russh::Error;
+
+    async fn auth_none(&mut self, _: &str) -> Result<Auth, Self::Error> {
+        Ok(Auth::Accept)
+    }
+
+    async fn channel_open_session(
+        &mut self,
+        channel: Channel<Msg>,
+        _session: &mut Session,
+    ) -> Result<bool, Self::Error> {
+        self.channels.lock().await.insert(channel.id(), channel);
+        Ok(true)
+    }
+
+    async fn pty_request(
+        &mut self,
+        channel: ChannelId,
+        _term: &str,
+        width: u32,
+        height: u32,
+        _: u32,
+        _: u32,
+        _: &[(russh::Pty, u32)],
+        session: &mut Session,
+    ) -> Result<(), Self::Error> {
+        let Some(ssh_channel) = self.channels.lock().await.remove(&channel) else {
+            return Ok(());
+        };
+        let size = SharedSize::new(width as u16, height as u16);
+        let writer = ssh_channel.make_writer().compat_write();
+        let (backend, output) = RemoteBackend::new(writer, size.clone());
+        tokio::spawn(output);
+
+        let input = backend.input();
+        self.clients
+            .lock()
+            .await
+            .insert(channel, Client { size, input });
+        let handle = session.handle();
+        tokio::task::spawn_blocking(move || {
+            let _ = run(Terminal::new(backend).expect("terminal"));
+            let _ = tokio::runtime::Handle::current().block_on(handle.close(channel));
+        });
+        session.channel_success(channel)?;
+        Ok(())
+    }
+
+    async fn window_change_request(
+        &mut self,
+        channel: ChannelId,
+        width: u32,
+        height: u32,
+        _: u32,
+        _: u32,
+        _: &mut Session,
+    ) -> Result<(), Self::Error> {
+        if let Some(client) = self.clients.lock().await.get(&channel) {
+            client.size.set(width as u16, height as u16);
+        }
+        Ok(())
+    }
+
+    async fn data(
+        &mut self,
+        channel: ChannelId,
+        data: &[u8],
+        _: &mut Session,
+    ) -> Result<(), Self::Error> {
+        if let Some(client) = self.clients.lock().await.get(&channel) {
+            for c in String::from_utf8_lossy(data).chars() {
+                client.input.push_event(KeyCode::Char(c));
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Draw the dashboard until the client presses `q`.
+fn run(mut terminal: Terminal<RemoteBackend<SharedSize>>) -> std::io::Result<()> {
+    terminal.backend_mut().enter_alternate_screen()?;
+    terminal.hide_cursor()?;
+    let mut cpu: Vec<u64> = vec![10, 20, 30, 40, 30, 20];
+    let mut tick = 0_u64;
+    loop {
+        while let Some(event) = terminal.poll_event()? {
+            if event == Event::from(KeyCode::Char('q')) {
+                terminal.backend_mut().leave_alternate_screen()?;
+                return terminal.show_cursor();
+            }
+        }
+
+        tick += 1;
+        cpu.push((tick * 7) % 100);
+        if cpu.len() > 50 {
+            cpu.remove(0);
+        }
+        let memory = (50 + tick % 40) as u16;
+
+        terminal.draw(|frame| {
+            let mut layout = Layout::default()
+                .direction(Direction::Vertical)
+                .constraints([Constraint::Fill(1), Constraint::Length(3)]);
+            let chunks = layout.split(frame.area());
+
+            let cpu_block = Block::default()
+                .title("CPU Usage (q: quit)")
+                .borders(BorderType::All);
+            let cpu_inner = cpu_block.inner(chunks[0]);
+            frame.render_widget(cpu_block, chunks[0]);
+            frame.render_widget(
+                Sparkline::default()
+                    .data(&cpu)
+                    .style(Style::default().fg(Color::Green)),
+                cpu_inner,
+            );
+
+            let mem_block = Block::default().title("Memory").borders(BorderType::All);
+            let mem_inner = mem_block.inner(chunks[1]);
+            frame.render_widget(mem_block, chunks[1]);
+            frame.render_widget(
+                Gauge::default()
+                    .percent(memory)
+                    .label(format!("{memory}%"))
+                    .gauge_style(Style::default().fg(Color::Yellow)),
+                mem_inner,
+            );
+        })?;
+        std::thread::sleep(Duration::from_millis(250));
+    }
+}
+
+#[tokio::main]
+async fn main() -> std::io::Result<()> {
+    let key = PrivateKey::random(&mut rand::rngs::OsRng, russh::keys::Algorithm::Ed25519)
+        .expect("host key");
+    let config = server::Config {
+        keys: vec![key],
+        inactivity_timeout: Some(Duration::from_secs(3600)),
+        ..Default::default()
+    };
+    App::default()
+        .run_on_address(Arc::new(config), ("0.0.0.0", 2222))
+        .await
+}